@@ -0,0 +1,71 @@
+// LOCAL ENDPOINT FOR LOG STREAMS TO DELIVER TO. WEBHOOK BATCHES ARRIVE ON
+// POST /log-sink, SYSLOG MESSAGES ON 127.0.0.1:5514 OVER UDP OR TCP. THE LAST
+// BATCHES ARE KEPT FOR GET /log-sink/batches
+
+const dgram = require('dgram')
+const net = require('net')
+
+const SYSLOG_PORT = 5514
+const MAX_BATCHES = 50
+
+const batches = []
+
+const keep = (batch) => {
+    batches.unshift({ received_at: new Date().toISOString(), ...batch })
+    batches.splice(MAX_BATCHES)
+}
+
+const udp = dgram.createSocket('udp4')
+udp.on('message', (message) => keep({ source: 'syslog/udp', events: [message.toString()] }))
+udp.on('error', (err) => console.log(`log sink udp: ${err.message}`))
+udp.bind(SYSLOG_PORT, '127.0.0.1')
+
+// OCTET COUNTING FRAMING, RFC 6587
+const tcp = net.createServer((socket) => {
+    let data = ''
+    socket.on('data', chunk => data += chunk)
+    socket.on('end', () => {
+        const events = []
+        while (data.length > 0) {
+            const space = data.indexOf(' ')
+            const length = parseInt(data.slice(0, space), 10)
+            if (space < 0 || isNaN(length)) break
+            const rest = Buffer.from(data.slice(space + 1))
+            events.push(rest.subarray(0, length).toString())
+            data = rest.subarray(length).toString()
+        }
+        keep({ source: 'syslog/tcp', events })
+    })
+    socket.on('error', () => {})
+})
+tcp.on('error', (err) => console.log(`log sink tcp: ${err.message}`))
+tcp.listen(SYSLOG_PORT, '127.0.0.1')
+
+class ControllerLogSink {
+    static receive(req, res, next) {
+        console.log("log sink")
+        console.log(req.headers['content-type'], req.headers['authorization'])
+
+        let events = req.body
+        if (typeof events === 'string') {
+            try {
+                events = events.split('\n').filter(line => line.trim()).map(line => JSON.parse(line))
+            } catch (err) {
+                return res.status(400).json({ statusCode: 400, error: 'Bad Request', message: 'Every line must be a JSON object' })
+            }
+        }
+        if (!Array.isArray(events)) {
+            return res.status(400).json({ statusCode: 400, error: 'Bad Request', message: 'The body must be a JSON array or JSON lines' })
+        }
+        keep({ source: 'http', content_type: req.headers['content-type'], authorization: req.headers['authorization'] || '', events })
+        res.status(200).json({ received: events.length })
+    }
+
+    static batches(req, res, next) {
+        res.send(batches)
+    }
+}
+
+module.exports = {
+    ControllerLogSink,
+}
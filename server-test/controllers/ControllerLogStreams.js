@@ -0,0 +1,372 @@
+// LOCAL STAND-IN FOR THE LOG STREAMS API. ACTIVE STREAMS RECEIVE THE EVENTS
+// ControllerLogs GENERATES, IN BATCHES FOR A WEBHOOK AND ONE MESSAGE EACH FOR
+// SYSLOG, AND KEEP THEIR OWN HEALTH. POINT A STREAM AT THE LOCAL SINK,
+// http://127.0.0.1:8080/log-sink OR 127.0.0.1:5514, TO SEE WHAT IS SENT
+
+const http = require('http')
+const https = require('https')
+const dgram = require('dgram')
+const net = require('net')
+const tls = require('tls')
+const { makeLog, onLog } = require('./ControllerLogs')
+
+const DELIVERY_TIMEOUT = 5000
+
+// A STREAM FAILING THIS MANY TIMES IN A ROW IS SUSPENDED
+const MAX_CONSECUTIVE_FAILURES = 10
+
+// EVENTS WAITING FOR A BATCH, THE OLDEST ARE DROPPED PAST THIS
+const MAX_QUEUE = 1000
+
+// SAME GROUPS AS types/src/logs.rs
+const CATEGORY_CODES = {
+    login_success: ['s', 'ssa', 'scoa', 'sens'],
+    login_failure: ['f', 'fp', 'fu', 'fc', 'fco', 'fcoa', 'fens', 'fsa', 'fcpro', 'w'],
+    logout: ['slo', 'flo'],
+    signup: ['ss', 'fs', 'si', 'fi'],
+    token: [
+        'seacft', 'feacft', 'seccft', 'feccft', 'sertft', 'fertft', 'ferrt', 'srrt', 'sepft', 'fepft',
+        'seotpft', 'feotpft', 'seoobft', 'feoobft', 'sercft', 'fercft', 'sede', 'fede', 'fdeaz', 'fdeac',
+        'fdecc', 'sd', 'fd',
+    ],
+    passwordless: ['cls', 'cs', 'fcls'],
+    account: [
+        'scp', 'fcp', 'scpr', 'fcpr', 'scph', 'fcph', 'sce', 'fce', 'scu', 'fcu', 'scpn', 'fcpn', 'sv', 'fv',
+        'svr', 'fvr', 'du', 'sdu', 'fdu', 'ublkdu',
+    ],
+    mfa: [
+        'mfar', 'gd_start_enroll', 'gd_start_enroll_failed', 'gd_enrollment_complete',
+        'gd_webauthn_enrollment_failed', 'gd_unenroll', 'gd_update_device_account', 'gd_start_auth',
+        'gd_auth_succeed', 'gd_auth_failed', 'gd_auth_rejected', 'gd_webauthn_challenge_failed', 'gd_send_pn',
+        'gd_send_sms', 'gd_send_sms_failure', 'gd_send_voice', 'gd_send_voice_failure', 'gd_recovery_succeed',
+        'gd_recovery_failed', 'gd_otp_rate_limit_exceed', 'gd_recovery_rate_limit_exceed', 'gd_tenant_update',
+    ],
+    management_api: ['sapi', 'fapi', 'mgmt_api_read', 'sui', 'fui'],
+    rate_limit: [
+        'api_limit', 'limit_wc', 'limit_sul', 'limit_mu', 'limit_ui', 'limit_delegation', 'pla', 'pwd_leak',
+        'signup_pwd_leak', 'reset_pwd_leak',
+    ],
+    system: [
+        'depnote', 'fn', 'resource_cleanup', 'admin_update_launch', 'sys_update_start', 'sys_update_end',
+        'sys_os_update_start', 'sys_os_update_end',
+    ],
+}
+
+const categoryOf = (code) => Object.keys(CATEGORY_CODES).find(key => CATEGORY_CODES[key].includes(code)) || 'system'
+
+const CONTENT_TYPES = {
+    json_array: 'application/json',
+    json_lines: 'application/x-ndjson',
+}
+
+const newHealth = () => ({
+    delivered: 0,
+    failed: 0,
+    consecutive_failures: 0,
+    last_delivery_at: '',
+    last_error: '',
+    last_error_at: '',
+})
+
+const streams = [
+    {
+        id: 'lst_0000000000012345',
+        name: 'local-sink',
+        type: 'http',
+        status: 'active',
+        http: {
+            url: 'http://127.0.0.1:8080/log-sink',
+            authorization: 'Bearer local-sink-token',
+            format: 'json_array',
+            batch_size: 20,
+            batch_interval: 10,
+        },
+        syslog: { host: '', port: 514, protocol: 'udp' },
+        filters: ['login_success', 'login_failure', 'rate_limit'],
+        health: newHealth(),
+        created_at: new Date().toISOString(),
+    },
+]
+
+// NOT PART OF THE API, BY STREAM ID
+const queues = new Map()
+
+const findStream = (id) => streams.find(stream => stream.id === id)
+
+const queueOf = (stream) => {
+    if (!queues.has(stream.id)) queues.set(stream.id, { events: [], timer: null })
+    return queues.get(stream.id)
+}
+
+const clearQueue = (stream) => {
+    const queue = queues.get(stream.id)
+    if (queue) clearTimeout(queue.timer)
+    queues.delete(stream.id)
+}
+
+const sendHttp = (url, headers, body) => new Promise((resolve, reject) => {
+    const client = url.startsWith('https://') ? https : http
+    const request = client.request(url, { method: 'POST', headers: { ...headers, 'Content-Length': Buffer.byteLength(body) } }, (response) => {
+        let data = ''
+        response.on('data', chunk => data += chunk)
+        response.on('end', () => resolve({ status: response.statusCode, response: data }))
+    })
+    request.setTimeout(DELIVERY_TIMEOUT, () => request.destroy(new Error(`No answer after ${DELIVERY_TIMEOUT} ms`)))
+    request.on('error', reject)
+    request.end(body)
+})
+
+// RFC 5424, FACILITY local0, THE EVENT AS JSON
+const syslogMessage = (log) => {
+    const severity = /^(f|limit_|pwd_leak|gd_auth_failed)/.test(log.type_detail) ? 3 : 6
+    return `<${16 * 8 + severity}>1 ${log.date} ${log.hostname || '-'} telkom-logs - ${log.type_detail || '-'} - ${JSON.stringify(log)}`
+}
+
+const sendSyslog = ({ host, port, protocol }, messages) => new Promise((resolve, reject) => {
+    if (protocol === 'udp') {
+        const socket = dgram.createSocket(net.isIPv6(host) ? 'udp6' : 'udp4')
+        let pending = messages.length
+        const done = (err) => {
+            if (err) {
+                socket.close()
+                return reject(err)
+            }
+            if (--pending === 0) {
+                socket.close()
+                resolve()
+            }
+        }
+        messages.forEach(message => socket.send(message, port, host, done))
+        return
+    }
+    // OCTET COUNTING FRAMING, RFC 6587
+    const framed = messages.map(message => `${Buffer.byteLength(message)} ${message}`).join('')
+    const socket = protocol === 'tls'
+        ? tls.connect({ host, port, servername: net.isIP(host) ? undefined : host })
+        : net.connect({ host, port })
+    socket.setTimeout(DELIVERY_TIMEOUT, () => socket.destroy(new Error(`No answer after ${DELIVERY_TIMEOUT} ms`)))
+    socket.once(protocol === 'tls' ? 'secureConnect' : 'connect', () => socket.end(framed, resolve))
+    socket.on('error', reject)
+})
+
+// target OVERRIDES THE STREAM'S OWN SINK, A URL OR host:port
+const deliver = async (stream, events, target) => {
+    const started = Date.now()
+    const result = { delivered: false, target: '', payload: '', status: null, response: '', error: null, duration_ms: 0 }
+    try {
+        if (stream.type === 'syslog') {
+            const sink = { ...stream.syslog }
+            if (target) {
+                // "[::1]:514" KEEPS ITS BRACKETS UNTIL THE LAST COLON
+                const separator = target.lastIndexOf(':')
+                sink.host = target.slice(0, separator).replace(/^\[(.*)\]$/, '$1')
+                sink.port = parseInt(target.slice(separator + 1), 10)
+            }
+            const messages = events.map(syslogMessage)
+            const host = net.isIPv6(sink.host) ? `[${sink.host}]` : sink.host
+            result.target = `${sink.protocol}://${host}:${sink.port}`
+            result.payload = messages.join('\n')
+            await sendSyslog(sink, messages)
+            result.delivered = true
+        } else {
+            const url = target || stream.http.url
+            const format = stream.http.format === 'json_lines' ? 'json_lines' : 'json_array'
+            const headers = { 'Content-Type': CONTENT_TYPES[format] }
+            if (stream.http.authorization) headers['Authorization'] = stream.http.authorization
+            const body = format === 'json_lines'
+                ? events.map(event => JSON.stringify(event)).join('\n') + '\n'
+                : JSON.stringify(events)
+            result.target = url
+            result.payload = body
+            const { status, response } = await sendHttp(url, headers, body)
+            result.status = status
+            result.response = response.slice(0, 2000)
+            result.delivered = status >= 200 && status < 300
+            if (!result.delivered) result.error = `The endpoint answered ${status}`
+        }
+    } catch (err) {
+        result.error = `The sink could not be reached: ${err.message}`
+    }
+    result.duration_ms = Date.now() - started
+    return result
+}
+
+const flush = async (stream) => {
+    const queue = queueOf(stream)
+    clearTimeout(queue.timer)
+    queue.timer = null
+    const size = stream.type === 'syslog' ? queue.events.length : stream.http.batch_size
+    const events = queue.events.splice(0, size)
+    if (events.length === 0) return
+
+    const result = await deliver(stream, events)
+    // DELETED OR PAUSED WHILE SENDING
+    if (!findStream(stream.id)) return
+    const health = stream.health
+    if (result.delivered) {
+        health.delivered += events.length
+        health.consecutive_failures = 0
+        health.last_delivery_at = new Date().toISOString()
+    } else {
+        // A FAILED BATCH IS NOT RETRIED
+        health.failed += events.length
+        health.consecutive_failures += 1
+        health.last_error = result.error
+        health.last_error_at = new Date().toISOString()
+        if (health.consecutive_failures >= MAX_CONSECUTIVE_FAILURES && stream.status === 'active') {
+            console.log(`log stream ${stream.id} suspended`)
+            stream.status = 'suspended'
+            clearQueue(stream)
+            return
+        }
+    }
+    if (queueOf(stream).events.length > 0) schedule(stream)
+}
+
+const schedule = (stream) => {
+    const queue = queueOf(stream)
+    if (stream.type === 'syslog' || queue.events.length >= stream.http.batch_size) {
+        flush(stream)
+    } else if (!queue.timer) {
+        queue.timer = setTimeout(() => flush(stream), stream.http.batch_interval * 1000)
+    }
+}
+
+onLog((log) => {
+    const category = categoryOf(log.type_detail)
+    streams
+        .filter(stream => stream.status === 'active')
+        .filter(stream => stream.filters.length === 0 || stream.filters.includes(category))
+        .forEach(stream => {
+            const queue = queueOf(stream)
+            queue.events.push(log)
+            queue.events.splice(0, Math.max(queue.events.length - MAX_QUEUE, 0))
+            schedule(stream)
+        })
+})
+
+const validate = (body) => {
+    if (!body.name || !String(body.name).trim()) return 'name is required'
+    if (!['http', 'syslog'].includes(body.type)) return 'type must be http or syslog'
+    if (body.type === 'http' && !/^https?:\/\//.test((body.http || {}).url || '')) return 'http.url must be an http(s) URL'
+    if (body.type === 'syslog' && !(body.syslog || {}).host) return 'syslog.host is required'
+    const unknown = (body.filters || []).find(key => !CATEGORY_CODES[key])
+    if (unknown) return `${unknown} is not a log category`
+    return null
+}
+
+class ControllerLogStreams {
+    static get(req, res, next) {
+        console.log("get log streams")
+        res.send(streams)
+    }
+
+    static getOne(req, res, next) {
+        const stream = findStream(req.params.id)
+        if (!stream) {
+            return res.status(404).json({ statusCode: 404, error: 'Not Found', message: 'Log stream not found' })
+        }
+        res.send(stream)
+    }
+
+    static create(req, res, next) {
+        console.log("create log stream")
+        console.log(req.body)
+
+        const error = validate(req.body)
+        if (error) {
+            return res.status(400).json({ statusCode: 400, error: 'Bad Request', message: error })
+        }
+        const { name, type, http, syslog, filters } = req.body
+        const stream = {
+            id: `lst_${String(Date.now()).padStart(16, '0')}`,
+            name: name.trim(),
+            type,
+            status: 'active',
+            http,
+            syslog,
+            filters: filters || [],
+            health: newHealth(),
+            created_at: new Date().toISOString(),
+        }
+        streams.push(stream)
+        res.status(201).send(stream)
+    }
+
+    static update(req, res, next) {
+        console.log("update log stream")
+        console.log(req.params.id, req.body)
+
+        const stream = findStream(req.params.id)
+        if (!stream) {
+            return res.status(404).json({ statusCode: 404, error: 'Not Found', message: 'Log stream not found' })
+        }
+        const { status } = req.body
+        if (status !== undefined) {
+            if (!['active', 'paused'].includes(status)) {
+                return res.status(400).json({ statusCode: 400, error: 'Bad Request', message: 'status must be active or paused' })
+            }
+            // RESUMING A SUSPENDED STREAM GIVES IT A FRESH START
+            if (status === 'active') stream.health.consecutive_failures = 0
+            if (status === 'paused') clearQueue(stream)
+            stream.status = status
+            return res.send(stream)
+        }
+        const error = validate({ ...stream, ...req.body, type: stream.type })
+        if (error) {
+            return res.status(400).json({ statusCode: 400, error: 'Bad Request', message: error })
+        }
+        for (const key of ['name', 'http', 'syslog', 'filters']) {
+            if (req.body[key] !== undefined) stream[key] = req.body[key]
+        }
+        stream.name = stream.name.trim()
+        // PENDING EVENTS GO OUT WITH THE NEW SETTINGS
+        const queue = queues.get(stream.id)
+        if (queue && queue.events.length > 0) {
+            clearTimeout(queue.timer)
+            queue.timer = null
+            schedule(stream)
+        }
+        res.send(stream)
+    }
+
+    static delete(req, res, next) {
+        console.log("delete log stream")
+        console.log(req.params.id)
+
+        const index = streams.findIndex(stream => stream.id === req.params.id)
+        if (index < 0) {
+            return res.status(404).json({ statusCode: 404, error: 'Not Found', message: 'Log stream not found' })
+        }
+        clearQueue(streams[index])
+        streams.splice(index, 1)
+        res.status(204).send()
+    }
+
+    // NOT COUNTED IN THE HEALTH
+    static async test(req, res, next) {
+        console.log("test log stream")
+        console.log(req.params.id, req.body)
+
+        const stream = findStream(req.params.id)
+        if (!stream) {
+            return res.status(404).json({ statusCode: 404, error: 'Not Found', message: 'Log stream not found' })
+        }
+        const target = ((req.body || {}).target || '').trim()
+        if (target && stream.type === 'syslog' && !/^[\w.:-]+:\d+$/.test(target)) {
+            return res.status(400).json({ statusCode: 400, error: 'Bad Request', message: 'target must be host:port' })
+        }
+        const sample = {
+            ...makeLog(Date.now(), 0),
+            log_id: `test_${Date.now()}`,
+            type_detail: 'depnote',
+            description: 'Test event sent from the log stream settings',
+            details: { test: true, stream_id: stream.id },
+        }
+        res.send(await deliver(stream, [sample], target))
+    }
+}
+
+module.exports = {
+    ControllerLogStreams,
+}
@@ -0,0 +1,402 @@
+// LOCAL STAND-IN FOR THE LOGS API, A FIXED SET OF EVENTS OVER THE LAST 30 DAYS
+// SEARCHABLE WITH THE SAME LUCENE SUBSET THE DASHBOARD SENDS, PLUS A NEW EVENT
+// EVERY FEW SECONDS FOR THE LIVE STREAM
+
+const { issueStreamTicket } = require('../middlewares/authentication')
+
+const DAY = 24 * 60 * 60 * 1000
+
+const SEED_COUNT = 320
+
+// OLDEST EVENTS ARE FORGOTTEN PAST THIS, LIKE THE REAL RETENTION
+const MAX_LOGS = 5000
+
+// A NEW EVENT EVERY 2 TO 5 SECONDS
+const LIVE_MIN_DELAY = 2000
+const LIVE_MAX_DELAY = 5000
+
+const HEARTBEAT_DELAY = 15000
+
+// SAME SEED, SAME EVENTS ON EVERY START
+const random = (() => {
+    let seed = 20220131
+    return () => {
+        seed = (seed * 1103515245 + 12345) % 2147483648
+        return seed / 2147483648
+    }
+})()
+
+const pick = (items) => items[Math.floor(random() * items.length)]
+
+const EVENTS = [
+    { type_detail: 's', description: 'Successful Login', user: true },
+    { type_detail: 's', description: 'Successful Login', user: true },
+    { type_detail: 's', description: 'Successful Login', user: true },
+    { type_detail: 'f', description: 'Wrong email or password.', user: true },
+    { type_detail: 'fp', description: 'Wrong email or password.', user: true },
+    { type_detail: 'fu', description: 'Invalid username', user: true },
+    { type_detail: 'ss', description: 'Successful Signup', user: true },
+    { type_detail: 'fs', description: 'Password is too weak', user: true },
+    { type_detail: 'slo', description: 'Successful Logout', user: true },
+    { type_detail: 'seccft', description: 'Client Credentials for Access Token', user: false },
+    { type_detail: 'mgmt_api_read', description: 'Get a client', user: false },
+    { type_detail: 'sapi', description: 'Update a connection', user: false },
+    { type_detail: 'limit_wc', description: 'Too many failed login attempts from this IP', user: true },
+    { type_detail: 'cls', description: 'Passwordless login code sent', user: true },
+    { type_detail: 'scoa', description: 'Successful cross-origin authentication', user: true },
+]
+
+const USERS = [
+    { user_id: 'auth0|YXV0aDAlN0M2MTM3MTIyMTAxY2VmYTAwNzM0NzRmYmI', user_name: 'yeskahaganta3838@gmail.com', connection: 'Username-Password-Authentication', connection_id: 'con_Xa8vT2UqGz8mS1Rk', strategy: 'auth0' },
+    { user_id: 'auth0|YXV0aDAlN0M2MTM3MTIyMTAxY2VmYTAwNzM0NzRmYmK', user_name: 'fransyogabarus@gmail.com', connection: 'Username-Password-Authentication', connection_id: 'con_Xa8vT2UqGz8mS1Rk', strategy: 'auth0' },
+    { user_id: 'google-oauth2|114519841297346872283', user_name: 'Yeska Haganta', connection: 'google-oauth2', connection_id: 'con_mZOXCcKwOs7V0MGq', strategy: 'google-oauth2' },
+    { user_id: 'email|61fa7c1e5b2f0a0069a1c2d3', user_name: 'budi@telkom.co.id', connection: 'email', connection_id: 'con_P4ssw0rdl3ssEm41', strategy: 'email' },
+    { user_id: 'sms|61fa7c1e5b2f0a0069a1c2d4', user_name: '+6281234567890', connection: 'sms', connection_id: 'con_P4ssw0rdl3ssSm5x', strategy: 'sms' },
+]
+
+const CLIENTS = [
+    { client_id: 'AaiyAPdpYdesoKnqjj8HJqRn4T5titww', client_name: 'My application 1' },
+    { client_id: 'JklAbdpYdesokmZWay87AAqRn4T5titw', client_name: 'My application 2' },
+    { client_id: 'XMyKpNTUzI1izvSPY8JJ8ZIIO5izaJnH', client_name: 'My NextJs App' },
+]
+
+const PLACES = [
+    { ip: '190.257.209.19', city_name: 'Tangerang' },
+    { ip: '36.92.133.42', city_name: 'Jakarta' },
+    { ip: '114.124.205.7', city_name: 'Bandung' },
+    { ip: '180.252.88.150', city_name: 'Surabaya' },
+]
+
+const USER_AGENTS = [
+    'Chrome 97.0.4692 / Mac OS X 10.15.7',
+    'Firefox 96.0.0 / Windows 10.0.0',
+    'Mobile Safari 15.2.0 / iOS 15.2.1',
+]
+
+// WHAT THE REAL API PUTS IN details FOR A FEW EVENT TYPES
+const detailsFor = (event, user, client) => {
+    switch (event.type_detail) {
+        case 'mgmt_api_read':
+        case 'sapi':
+            return {
+                request: {
+                    method: event.type_detail === 'sapi' ? 'patch' : 'get',
+                    path: event.type_detail === 'sapi' ? '/api/v2/connections/con_Xa8vT2UqGz8mS1Rk' : `/api/v2/clients/${client.client_id}`,
+                    channel: 'https://manage.auth0.com/',
+                    auth: { user: { user_id: 'google-oauth2|114519841297346872283', name: 'Yeska Haganta' }, strategy: 'jwt', credentials: { scopes: ['read:clients', 'update:connections'] } },
+                },
+                response: { statusCode: 200 },
+            }
+        case 'f':
+        case 'fp':
+        case 'fu':
+            return { error: { message: event.description, oauthError: 'invalid_grant', type: 'request-error' } }
+        case 'limit_wc':
+            return { error: { message: 'Blocked after 10 failed attempts' }, ips: ['190.257.209.19'] }
+        case 'cls':
+            return { email: user ? user.user_name : '', send: 'code', authParams: {} }
+        case 'seccft':
+            return { grant_type: 'client_credentials', audience: 'https://dev-telkom.us.auth0.com/api/v2/' }
+        default:
+            return {}
+    }
+}
+
+const logId = (time, index) => `900${new Date(time).toISOString().replace(/\D/g, '')}${String(index).padStart(6, '0')}`
+
+const makeLog = (time, index) => {
+    const event = pick(EVENTS)
+    const user = event.user ? pick(USERS) : null
+    const client = pick(CLIENTS)
+    const place = pick(PLACES)
+    const userAgent = pick(USER_AGENTS)
+    return {
+        date: new Date(time).toISOString(),
+        type_detail: event.type_detail,
+        description: event.description,
+        connection: user ? user.connection : '',
+        connection_id: user ? user.connection_id : '',
+        client_id: client.client_id,
+        client_name: client.client_name,
+        ip: place.ip,
+        hostname: 'dev-telkom.us.auth0.com',
+        user_id: user ? user.user_id : '',
+        user_name: user ? user.user_name : '',
+        audience: user ? '' : 'https://dev-telkom.us.auth0.com/api/v2/',
+        scope: user ? 'openid profile email' : 'read:clients',
+        strategy: user ? user.strategy : '',
+        log_id: logId(time, index),
+        is_mobile: userAgent.startsWith('Mobile'),
+        details: detailsFor(event, user, client),
+        user_agent: userAgent,
+        location_info: {
+            country_code: 'ID',
+            country_code_3: 'IDN',
+            country_name: 'Indonesia',
+            city_name: place.city_name,
+            latitude: '',
+            longitude: '',
+            time_zone: 'Asia/Jakarta',
+            continent_code: 'AS',
+        },
+    }
+}
+
+const seedLogs = () => {
+    const now = Date.now()
+    const logs = []
+    for (let index = 0; index < SEED_COUNT; index++) {
+        logs.push(makeLog(now - Math.floor(random() * 30 * DAY), index))
+    }
+    return logs.sort((a, b) => a.date < b.date ? 1 : -1)
+}
+
+// NEWEST FIRST
+const logs = seedLogs()
+
+// OPEN /stream RESPONSES, EACH WITH ITS OWN FILTER
+const subscribers = new Set()
+
+// CALLED WITH EVERY NEW EVENT, EG. BY THE LOG STREAMS
+const listeners = []
+
+const onLog = (listener) => listeners.push(listener)
+
+const sendLog = (subscriber, log) => {
+    subscriber.res.write(`id: ${log.log_id}\nevent: log\ndata: ${JSON.stringify(log)}\n\n`)
+}
+
+let liveIndex = SEED_COUNT
+
+const emitLive = () => {
+    const log = makeLog(Date.now(), liveIndex++)
+    logs.unshift(log)
+    logs.length = Math.min(logs.length, MAX_LOGS)
+    subscribers.forEach(subscriber => {
+        if (subscriber.matches(log)) sendLog(subscriber, log)
+    })
+    listeners.forEach(listener => listener(log))
+    setTimeout(emitLive, LIVE_MIN_DELAY + Math.floor(random() * (LIVE_MAX_DELAY - LIVE_MIN_DELAY)))
+}
+
+setTimeout(emitLive, LIVE_MIN_DELAY)
+
+// LUCENE SUBSET: AND, OR, NOT, PARENTHESES, field:value, field:"phrase",
+// field:prefix*, field:(a OR b), field:[from TO to] AND BARE TERMS
+
+const tokenize = (query) => {
+    const tokens = []
+    const pattern = /\s*(\(|\)|"(?:[^"\\]|\\.)*"|\[[^\]]*\]|[^\s()"[]+(?:"(?:[^"\\]|\\.)*"|\[[^\]]*\])?)/gy
+    let end = 0
+    let match
+    while ((match = pattern.exec(query))) {
+        tokens.push(match[1])
+        end = pattern.lastIndex
+    }
+    if (query.slice(end).trim()) {
+        throw new Error(`Could not parse ${query.slice(end).trim()}`)
+    }
+    return tokens
+}
+
+const unquote = (value) => value.startsWith('"') ? value.slice(1, -1).replace(/\\(.)/g, '$1') : value
+
+const fieldValue = (log, field) => field.split('.').reduce((value, key) => value == null ? '' : value[key], log)
+
+const matchValue = (actual, expected) => {
+    const text = String(actual == null ? '' : actual).toLowerCase()
+    if (expected.quoted) return text === expected.value.toLowerCase()
+    if (expected.value.endsWith('*')) return text.startsWith(expected.value.slice(0, -1).toLowerCase())
+    return text === expected.value.toLowerCase()
+}
+
+// DATES COMPARE AS ISO STRINGS, A BARE DAY COVERS THE WHOLE DAY
+const matchRange = (actual, from, to) => {
+    const text = String(actual)
+    if (from !== '*' && text < from) return false
+    if (to !== '*' && text.slice(0, to.length) > to) return false
+    return true
+}
+
+const SEARCHED_FIELDS = ['description', 'type_detail', 'user_name', 'user_id', 'client_name', 'connection', 'ip']
+
+const parse = (tokens) => {
+    let position = 0
+    const peek = () => tokens[position]
+    const next = () => tokens[position++]
+
+    const parseOr = () => {
+        let left = parseAnd()
+        while (peek() === 'OR') {
+            next()
+            const right = parseAnd()
+            const l = left
+            left = log => l(log) || right(log)
+        }
+        return left
+    }
+    const parseAnd = () => {
+        let left = parseNot()
+        while (peek() && peek() !== 'OR' && peek() !== ')') {
+            if (peek() === 'AND') next()
+            const right = parseNot()
+            const l = left
+            left = log => l(log) && right(log)
+        }
+        return left
+    }
+    const parseNot = () => {
+        if (peek() === 'NOT') {
+            next()
+            const inner = parseTerm()
+            return log => !inner(log)
+        }
+        return parseTerm()
+    }
+    const parseTerm = () => {
+        const token = next()
+        if (token === undefined) throw new Error('Unexpected end of query')
+        if (token === '(') {
+            const inner = parseOr()
+            if (next() !== ')') throw new Error('Missing )')
+            return inner
+        }
+        const separator = token.startsWith('"') ? -1 : token.indexOf(':')
+        if (separator <= 0) {
+            const expected = { value: unquote(token).toLowerCase(), quoted: token.startsWith('"') }
+            return log => SEARCHED_FIELDS.some(field => String(fieldValue(log, field)).toLowerCase().includes(expected.value))
+        }
+        const field = token.slice(0, separator)
+        let value = token.slice(separator + 1)
+        if (value === '' && peek() === '(') {
+            next()
+            const values = []
+            while (peek() && peek() !== ')') {
+                const item = next()
+                if (item !== 'OR') values.push({ value: unquote(item), quoted: item.startsWith('"') })
+            }
+            if (next() !== ')') throw new Error('Missing )')
+            return log => values.some(expected => matchValue(fieldValue(log, field), expected))
+        }
+        if (value.startsWith('[')) {
+            const range = /^\[\s*(\S+)\s+TO\s+(\S+)\s*\]$/.exec(value)
+            if (!range) throw new Error(`Invalid range ${value}`)
+            return log => matchRange(fieldValue(log, field), range[1], range[2])
+        }
+        const expected = { value: unquote(value), quoted: value.startsWith('"') }
+        return log => matchValue(fieldValue(log, field), expected)
+    }
+
+    const matcher = parseOr()
+    if (position < tokens.length) throw new Error(`Unexpected ${tokens[position]}`)
+    return matcher
+}
+
+class ControllerLogs {
+    static get(req, res, next) {
+        console.log("get logs")
+        console.log(req.query)
+
+        const page = parseInt(req.query.page || '0', 10)
+        const per_page = Math.min(parseInt(req.query.per_page || '50', 10), 100)
+        const q = (req.query.q || '').trim()
+
+        let found = logs
+        if (q) {
+            try {
+                found = logs.filter(parse(tokenize(q)))
+            } catch (err) {
+                return res.status(400).json({ statusCode: 400, error: 'Bad Request', message: `Invalid query: ${err.message}` })
+            }
+        }
+        if (req.query.sort === 'date:1') {
+            found = [...found].reverse()
+        }
+
+        let start = page * per_page
+        let limit = per_page
+        // CHECKPOINT PAGING, ?from=<log_id>&take=N CONTINUES THE LIST AFTER THAT
+        // EVENT SO NEW EVENTS AT THE TOP DON'T SHIFT THE NEXT PAGE
+        if (req.query.from) {
+            const from = req.query.from
+            const newestFirst = req.query.sort !== 'date:1'
+            limit = Math.min(parseInt(req.query.take || '50', 10), 100)
+            start = found.findIndex(log => newestFirst ? log.log_id < from : log.log_id > from)
+            if (start === -1) start = found.length
+        }
+        const items = found.slice(start, start + limit)
+        // SLOW ENOUGH TO SEE THE SPINNER WHILE SCROLLING
+        setTimeout(() => {
+            if (req.query.include_totals === 'true') {
+                res.send({ start, limit, length: items.length, total: found.length, logs: items })
+            } else {
+                res.send(items)
+            }
+        }, 500)
+    }
+
+    // ONE TICKET PER CONNECTION, RECONNECTING ASKS FOR A NEW ONE
+    static streamTicket(req, res, next) {
+        console.log("issue log stream ticket")
+
+        res.status(201).send(issueStreamTicket(req.headers.access_token))
+    }
+
+    // SERVER-SENT EVENTS, OPENED WITH ?ticket= FROM /stream-ticket
+    // Events after ?from=<log_id> (or Last-Event-ID) are replayed first, oldest first
+    static stream(req, res, next) {
+        console.log("stream logs")
+        console.log(req.query.q, req.query.from)
+
+        const q = (req.query.q || '').trim()
+        let matches = () => true
+        if (q) {
+            try {
+                matches = parse(tokenize(q))
+            } catch (err) {
+                return res.status(400).json({ statusCode: 400, error: 'Bad Request', message: `Invalid query: ${err.message}` })
+            }
+        }
+
+        res.writeHead(200, {
+            'Content-Type': 'text/event-stream',
+            'Cache-Control': 'no-cache',
+            'Connection': 'keep-alive',
+        })
+        // THE PAGE RECONNECTS ITSELF, THIS ONLY APPLIES TO OTHER CLIENTS
+        res.write('retry: 5000\n\n')
+
+        const subscriber = { res, matches }
+        const from = req.query.from || req.headers['last-event-id'] || ''
+        if (from) {
+            logs.filter(log => log.log_id > from && matches(log))
+                .reverse()
+                .forEach(log => sendLog(subscriber, log))
+        }
+        subscribers.add(subscriber)
+
+        const heartbeat = setInterval(() => res.write(': heartbeat\n\n'), HEARTBEAT_DELAY)
+        req.on('close', () => {
+            clearInterval(heartbeat)
+            subscribers.delete(subscriber)
+        })
+    }
+
+    static getDetails(req, res, next) {
+        console.log("get log details")
+        console.log(req.params.id)
+
+        const log = logs.find(log => log.log_id === req.params.id)
+        if (!log) {
+            return res.status(404).json({ statusCode: 404, error: 'Not Found', message: 'Log not found' })
+        }
+        res.send(log)
+    }
+}
+
+module.exports = {
+    ControllerLogs,
+    logs,
+    makeLog,
+    onLog,
+}
@@ -42,7 +42,7 @@ class ControllerSettings {
                 enforce_client_authentication_on_passwordless_start: false,
                 enable_adfs_waad_email_verification: false,
                 revoke_refresh_token_grant: false,
-                dashboard_log_streams_next: false,
+                dashboard_log_streams_next: true,
                 dashboard_insights_view: false
             },
             friendly_name: "My Company",
@@ -115,7 +115,7 @@ class ControllerSettings {
                 enforce_client_authentication_on_passwordless_start: false,
                 enable_adfs_waad_email_verification: false,
                 revoke_refresh_token_grant: false,
-                dashboard_log_streams_next: false,
+                dashboard_log_streams_next: true,
                 dashboard_insights_view: false
             },
             friendly_name: "My Company Edited",
@@ -0,0 +1,43 @@
+// LOCAL STAND-IN FOR AN SMS OPERATOR API, POINT THE WEBHOOK PROVIDER AT
+// http://127.0.0.1:8080/sms-gateway TO SEE WHAT WOULD HAVE BEEN SENT
+
+const MAX_MESSAGES = 50
+
+const messages = []
+
+// ALSO USED BY THE LOCAL PASSWORDLESS START TO DELIVER ITS CODES
+const receive = (to, text) => {
+    const message = {
+        id: `local-${Date.now()}-${messages.length + 1}`,
+        to,
+        text,
+        received_at: new Date().toISOString(),
+    }
+    messages.unshift(message)
+    messages.splice(MAX_MESSAGES)
+    return message
+}
+
+class ControllerSmsGateway {
+    static send(req, res, next) {
+        console.log("sms gateway")
+        console.log(req.headers['content-type'], req.body)
+
+        const { to, text } = req.body || {}
+        if (!to || !text) {
+            return res.status(422).json({ status: 'rejected', message: 'to and text are required' })
+        }
+        const message = receive(to, text)
+        res.status(202).json({ status: 'queued', id: message.id })
+    }
+
+    static getMessages(req, res, next) {
+        const { to } = req.query
+        res.send(to ? messages.filter(message => message.to === to) : messages)
+    }
+}
+
+module.exports = {
+    ControllerSmsGateway,
+    receive,
+}
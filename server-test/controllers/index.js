@@ -3,6 +3,12 @@ const { ControllerSettings } = require("./ControllerSettings");
 const { ControllerApi } = require('./ControllerApi')
 const { ControllerApp } = require('./ControllerApp')
 const { ControllerRoles } = require('./ControllerRoles')
+const { ControllerConnections } = require('./ControllerConnections')
+const { ControllerSmsGateway } = require('./ControllerSmsGateway')
+const { ControllerPasswordless } = require('./ControllerPasswordless')
+const { ControllerLogs } = require('./ControllerLogs')
+const { ControllerLogStreams } = require('./ControllerLogStreams')
+const { ControllerLogSink } = require('./ControllerLogSink')
 
 module.exports = {
     ControllerApi,
@@ -10,4 +16,10 @@ module.exports = {
     ControllerUsers,
     ControllerSettings,
     ControllerRoles,
+    ControllerConnections,
+    ControllerSmsGateway,
+    ControllerPasswordless,
+    ControllerLogs,
+    ControllerLogStreams,
+    ControllerLogSink,
 };
\ No newline at end of file
@@ -37,6 +37,7 @@ app.post('/user', (req, res) => {
 const { router } = require('./routes')
 app.use(router)
 
-app.listen(port, () => {
+// THE SANDBOX OF THE SCRIPT TRY RUNNERS CAN STILL OPEN SOCKETS, KEEP THE MOCK OFF THE NETWORK
+app.listen(port, 'localhost', () => {
     console.log(`Example app listening at http://localhost:${port}`)
 })
\ No newline at end of file
@@ -1,3 +1,4 @@
+const crypto = require('crypto')
 
 const Authentication = (req, res, next) => {
     let { access_token } = req.headers
@@ -13,6 +14,38 @@ const Authentication = (req, res, next) => {
     }
 }
 
+// EventSource CANNOT SEND HEADERS, THE PAGE TRADES ITS TOKEN FOR A SHORT LIVED,
+// SINGLE USE TICKET SO THE TOKEN NEVER ENDS UP IN A URL OR AN ACCESS LOG
+const STREAM_TICKET_TTL = 30000
+
+const streamTickets = new Map()
+
+const issueStreamTicket = (access_token) => {
+    const now = Date.now()
+    streamTickets.forEach((issued, ticket) => {
+        if (issued.expires_at <= now) streamTickets.delete(ticket)
+    })
+    const ticket = crypto.randomBytes(24).toString('hex')
+    streamTickets.set(ticket, { access_token, expires_at: now + STREAM_TICKET_TTL })
+    return { ticket, expires_in: STREAM_TICKET_TTL / 1000 }
+}
+
+// REDEEMS ?ticket= FOR THE TOKEN IT WAS ISSUED FOR, Authentication STILL RUNS AFTER IT
+const StreamTicket = (req, res, next) => {
+    const { ticket } = req.query
+    const issued = ticket ? streamTickets.get(ticket) : undefined
+    if (issued) streamTickets.delete(ticket)
+    if (!issued || issued.expires_at <= Date.now()) {
+        return res.status(401).json({
+            message: "Stream ticket is missing, used or expired"
+        })
+    }
+    req.headers.access_token = issued.access_token
+    next()
+}
+
 module.exports={
-    Authentication
-}
\ No newline at end of file
+    Authentication,
+    issueStreamTicket,
+    StreamTicket,
+}
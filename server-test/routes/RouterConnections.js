@@ -0,0 +1,17 @@
+const express = require('express')
+const { ControllerConnections } = require('../controllers')
+const { Authentication } = require('../middlewares/authentication')
+
+const RouterConnections = express.Router()
+
+RouterConnections.get('/', Authentication, ControllerConnections.get)
+RouterConnections.post('/', Authentication, ControllerConnections.create)
+RouterConnections.get('/:id', Authentication, ControllerConnections.getOne)
+RouterConnections.patch('/:id', Authentication, ControllerConnections.update)
+RouterConnections.delete('/:id', Authentication, ControllerConnections.delete)
+RouterConnections.post('/:id/scripts/:script_name/try', Authentication, ControllerConnections.tryScript)
+RouterConnections.post('/:id/sms/try', Authentication, ControllerConnections.trySms)
+
+module.exports={
+    RouterConnections,
+}
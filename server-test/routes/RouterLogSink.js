@@ -0,0 +1,12 @@
+const express = require('express')
+const { ControllerLogSink } = require('../controllers')
+
+const RouterLogSink = express.Router()
+
+// JSON LINES ISN'T PARSED BY express.json()
+RouterLogSink.post('/', express.text({ type: 'application/x-ndjson' }), ControllerLogSink.receive)
+RouterLogSink.get('/batches', ControllerLogSink.batches)
+
+module.exports={
+    RouterLogSink,
+}
@@ -0,0 +1,16 @@
+const express = require('express')
+const { ControllerLogStreams } = require('../controllers')
+const { Authentication } = require('../middlewares/authentication')
+
+const RouterLogStreams = express.Router()
+
+RouterLogStreams.get('/', Authentication, ControllerLogStreams.get)
+RouterLogStreams.post('/', Authentication, ControllerLogStreams.create)
+RouterLogStreams.get('/:id', Authentication, ControllerLogStreams.getOne)
+RouterLogStreams.patch('/:id', Authentication, ControllerLogStreams.update)
+RouterLogStreams.delete('/:id', Authentication, ControllerLogStreams.delete)
+RouterLogStreams.post('/:id/test', Authentication, ControllerLogStreams.test)
+
+module.exports={
+    RouterLogStreams,
+}
@@ -0,0 +1,15 @@
+const express = require('express')
+const { ControllerLogs } = require('../controllers')
+const { Authentication, StreamTicket } = require('../middlewares/authentication')
+
+const RouterLogs = express.Router()
+
+RouterLogs.get('/', Authentication, ControllerLogs.get)
+// BEFORE /:id
+RouterLogs.post('/stream-ticket', Authentication, ControllerLogs.streamTicket)
+RouterLogs.get('/stream', StreamTicket, Authentication, ControllerLogs.stream)
+RouterLogs.get('/:id', Authentication, ControllerLogs.getDetails)
+
+module.exports={
+    RouterLogs,
+}
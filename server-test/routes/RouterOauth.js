@@ -0,0 +1,10 @@
+const express = require('express')
+const { ControllerPasswordless } = require('../controllers')
+
+const RouterOauth = express.Router()
+
+RouterOauth.post('/token', ControllerPasswordless.token)
+
+module.exports={
+    RouterOauth,
+}
@@ -0,0 +1,10 @@
+const express = require('express')
+const { ControllerPasswordless } = require('../controllers')
+
+const RouterPasswordless = express.Router()
+
+RouterPasswordless.post('/start', ControllerPasswordless.start)
+
+module.exports={
+    RouterPasswordless,
+}
@@ -0,0 +1,12 @@
+const express = require('express')
+const { ControllerSmsGateway } = require('../controllers')
+
+const RouterSmsGateway = express.Router()
+
+RouterSmsGateway.post('/', ControllerSmsGateway.send)
+RouterSmsGateway.put('/', ControllerSmsGateway.send)
+RouterSmsGateway.get('/messages', ControllerSmsGateway.getMessages)
+
+module.exports={
+    RouterSmsGateway,
+}
@@ -8,6 +8,13 @@ const { RouterUser } = require('./RouterUser')
     // const {RouterWishlist} = require('./RouterWishlist')
 const { RouterSettings } = require('./RouterSettings')
 const { RouterRoles } = require('./RouterRoles')
+const { RouterConnections } = require('./RouterConnections')
+const { RouterSmsGateway } = require('./RouterSmsGateway')
+const { RouterPasswordless } = require('./RouterPasswordless')
+const { RouterOauth } = require('./RouterOauth')
+const { RouterLogs } = require('./RouterLogs')
+const { RouterLogStreams } = require('./RouterLogStreams')
+const { RouterLogSink } = require('./RouterLogSink')
 
 const router = express.Router()
 
@@ -21,6 +28,13 @@ router.use('/users', RouterUser)
     // router.use('/wishlist', RouterWishlist)
 router.use('/tenant', RouterSettings)
 router.use('/roles', RouterRoles)
+router.use('/api/v2/connections', RouterConnections)
+router.use('/sms-gateway', RouterSmsGateway)
+router.use('/passwordless', RouterPasswordless)
+router.use('/oauth', RouterOauth)
+router.use('/api/v2/logs', RouterLogs)
+router.use('/api/v2/log-streams', RouterLogStreams)
+router.use('/log-sink', RouterLogSink)
 
 module.exports = {
     router
@@ -0,0 +1,86 @@
+const vm = require('vm')
+const {
+    seedUsers,
+    stubModules,
+    WrongUsernameOrPasswordError,
+    ValidationError,
+} = require('./databaseModules')
+
+// CHILD SIDE OF runScript, READS ONE JOB FROM stdin AND WRITES ONE RESULT TO stdout
+
+// A SCRIPT THAT NEVER CALLS BACK FAILS AFTER THIS
+const CALLBACK_TIMEOUT = 5000
+
+// GLOBALS EVERY KIND OF SCRIPT SEES BESIDES console
+const GLOBALS = {
+    database: (job) => {
+        const modules = stubModules(seedUsers())
+        return {
+            configuration: job.configuration || {},
+            require: (name) => {
+                if (!modules[name]) throw new Error(`Cannot find module '${name}'`)
+                return modules[name]
+            },
+            WrongUsernameOrPasswordError,
+            ValidationError,
+            URL,
+            encodeURIComponent,
+        }
+    },
+    // CUSTOM SMS PROVIDERS ONLY GET THEIR ARGUMENTS
+    sms: () => ({}),
+}
+
+const run = (job) => {
+    const logs = []
+    const format = (values) => values.map(value => typeof value === 'string' ? value : JSON.stringify(value)).join(' ')
+    let finished = false
+    let timer = null
+
+    const finish = (err, result) => {
+        if (finished) return
+        finished = true
+        clearTimeout(timer)
+        let output
+        try {
+            output = JSON.stringify({
+                result: err ? null : (result === undefined ? null : result),
+                error: err ? `${err.name || 'Error'}: ${err.message || err}` : null,
+                logs,
+            })
+        } catch (error) {
+            output = JSON.stringify({ result: null, error: `The result can't be sent as JSON: ${error.message}`, logs })
+        }
+        process.stdout.write(output, () => process.exit(0))
+    }
+
+    const globals = GLOBALS[job.kind]
+    if (!globals || !/^[A-Za-z_$][\w$]*$/.test(job.entry || '')) {
+        return finish(new Error('Unknown script'))
+    }
+    const sandbox = {
+        ...globals(job),
+        __args: [...(job.args || []), finish],
+        console: {
+            log: (...values) => logs.push(format(values)),
+            error: (...values) => logs.push(format(values)),
+        },
+    }
+    timer = setTimeout(() => finish(new Error('The script did not call the callback')), CALLBACK_TIMEOUT)
+    try {
+        vm.runInNewContext(`${job.script}\n;${job.entry}.apply(null, __args)`, sandbox, { timeout: 1000 })
+    } catch (err) {
+        finish(err)
+    }
+}
+
+let input = ''
+process.stdin.setEncoding('utf8')
+process.stdin.on('data', chunk => input += chunk)
+process.stdin.on('end', () => {
+    try {
+        run(JSON.parse(input))
+    } catch (err) {
+        process.stdout.write(JSON.stringify({ result: null, error: `The script could not be read: ${err.message}`, logs: [] }), () => process.exit(0))
+    }
+})
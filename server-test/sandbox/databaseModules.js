@@ -0,0 +1,142 @@
+// STUBS THE DATABASE ACTION SCRIPTS require, LOADED INSIDE THE SANDBOX CHILD
+
+const HASH_PREFIX = '$2b$10$'
+
+// FAKE LEGACY STORE SHARED BY THE pg, mysql AND request STUBS
+const seedUsers = () => [
+    {
+        id: 1,
+        nickname: 'jane',
+        email: 'jane@example.com',
+        password: HASH_PREFIX + 'Passw0rd!',
+        email_verified: false,
+    },
+]
+
+class WrongUsernameOrPasswordError extends Error {
+    constructor(email, message) {
+        super(message || 'Wrong email or password.')
+        this.name = 'WrongUsernameOrPasswordError'
+        this.email = email
+    }
+}
+
+class ValidationError extends Error {
+    constructor(code, message) {
+        super(message)
+        this.name = 'ValidationError'
+        this.code = code
+    }
+}
+
+// UNDERSTANDS THE QUERIES USED BY THE DASHBOARD TEMPLATES, NOT GENERAL SQL
+const runQuery = (users, sql, params) => {
+    const statement = sql.trim().toUpperCase()
+    if (statement.startsWith('SELECT')) {
+        const rows = users
+            .filter(user => user.email === params[0])
+            .map(({ id, nickname, email, password }) => ({ id, nickname, email, password }))
+        return { rows, affected: rows.length }
+    }
+    if (statement.startsWith('INSERT')) {
+        const values = Array.isArray(params) ? { email: params[0], password: params[1] } : params
+        if (users.some(user => user.email === values.email)) {
+            throw new Error(`duplicate key value violates unique constraint "users_email_key"`)
+        }
+        const id = users.reduce((max, user) => Math.max(max, user.id), 0) + 1
+        users.push({ id, nickname: values.email.split('@')[0], email_verified: false, ...values })
+        return { rows: [], affected: 1 }
+    }
+    if (statement.startsWith('UPDATE') && statement.includes('EMAIL_VERIFIED')) {
+        const user = users.find(user => user.email === params[0] && !user.email_verified)
+        if (user) user.email_verified = true
+        return { rows: [], affected: user ? 1 : 0 }
+    }
+    if (statement.startsWith('UPDATE')) {
+        const user = users.find(user => user.email === params[1])
+        if (user) user.password = params[0]
+        return { rows: [], affected: user ? 1 : 0 }
+    }
+    if (statement.startsWith('DELETE')) {
+        const index = users.findIndex(user => String(user.id) === String(params[0]))
+        if (index >= 0) users.splice(index, 1)
+        return { rows: [], affected: index >= 0 ? 1 : 0 }
+    }
+    throw new Error(`The mock database can't run: ${sql}`)
+}
+
+const stubModules = (users) => ({
+    bcrypt: {
+        hash: (password, rounds, callback) => callback(null, HASH_PREFIX + password),
+        compare: (password, hash, callback) => callback(null, hash === HASH_PREFIX + password),
+    },
+    pg: {
+        connect: (conString, callback) => {
+            const client = {
+                query: (sql, params, queryCallback) => {
+                    try {
+                        const { rows, affected } = runQuery(users, sql, params)
+                        queryCallback(null, { rows, rowCount: affected })
+                    } catch (err) {
+                        queryCallback(err)
+                    }
+                },
+            }
+            callback(null, client, () => {})
+        },
+    },
+    mysql: () => ({
+        connect: () => {},
+        end: () => {},
+        query: (sql, params, callback) => {
+            try {
+                const { rows, affected } = runQuery(users, sql, params)
+                callback(null, sql.trim().toUpperCase().startsWith('SELECT') ? rows : { affectedRows: affected })
+            } catch (err) {
+                callback(err)
+            }
+        },
+    }),
+    request: (() => {
+        const send = (method) => (options, callback) => {
+            const url = new URL(options.url)
+            const body = typeof options.json === 'object' ? options.json : {}
+            const reply = (statusCode, responseBody) => callback(null, { statusCode }, responseBody)
+            const path = url.pathname
+            if (method === 'POST' && path.endsWith('/users/authenticate')) {
+                const user = users.find(user => user.email === body.email && user.password === HASH_PREFIX + body.password)
+                return user ? reply(200, user) : reply(401, { message: 'Unauthorized' })
+            }
+            if (method === 'POST' && path.endsWith('/users/verify')) {
+                const { affected } = runQuery(users, 'UPDATE users SET email_verified = true', [body.email])
+                return reply(affected ? 200 : 404, {})
+            }
+            if (method === 'POST' && path.endsWith('/users')) {
+                if (users.some(user => user.email === body.email)) return reply(409, { message: 'Conflict' })
+                runQuery(users, 'INSERT', { email: body.email, password: HASH_PREFIX + body.password })
+                return reply(201, {})
+            }
+            if (method === 'PUT' && path.endsWith('/users/password')) {
+                const { affected } = runQuery(users, 'UPDATE users SET password', [HASH_PREFIX + body.password, body.email])
+                return reply(affected ? 200 : 404, {})
+            }
+            if (method === 'GET' && path.endsWith('/users')) {
+                const user = users.find(user => user.email === url.searchParams.get('email'))
+                return user ? reply(200, user) : reply(404, { message: 'Not Found' })
+            }
+            if (method === 'DELETE') {
+                const { affected } = runQuery(users, 'DELETE', [path.split('/').pop()])
+                return reply(affected ? 204 : 404, {})
+            }
+            return reply(404, { message: 'Not Found' })
+        }
+        return { get: send('GET'), post: send('POST'), put: send('PUT'), del: send('DELETE') }
+    })(),
+})
+
+module.exports = {
+    seedUsers,
+    stubModules,
+    WrongUsernameOrPasswordError,
+    ValidationError,
+}
@@ -0,0 +1,66 @@
+const { spawn } = require('child_process')
+const path = require('path')
+
+// RUNS SCRIPTS WRITTEN IN THE DASHBOARD OUTSIDE OF THE API PROCESS
+// vm IS NOT A SECURITY BOUNDARY, console.log.constructor('return process')()
+// REACHES THE HOST. EVERY RUN GETS ITS OWN node WITH AN EMPTY ENVIRONMENT,
+// THE PERMISSION MODEL (READS LIMITED TO THIS FOLDER, NO WRITES, NO CHILD
+// PROCESSES, NO WORKERS), A SMALL HEAP AND A HARD KILL. IT CAN STILL OPEN
+// SOCKETS, SO THE MOCK API LISTENS ON LOCALHOST ONLY
+
+const CHILD = path.join(__dirname, 'child.js')
+
+// THE CHILD FAILS A SCRIPT THAT NEVER CALLS BACK AFTER CALLBACK_TIMEOUT, THE
+// PROCESS ITSELF IS KILLED AFTER THIS
+const KILL_TIMEOUT = 8000
+
+const MAX_OUTPUT = 1024 * 1024
+
+// kind NAMES THE GLOBALS OF child.js, args ARE JSON, THE CALLBACK IS APPENDED
+// LAST. RESOLVES { result, error, logs }, NEVER REJECTS
+const runScript = ({ kind, script, entry, args, configuration }) => new Promise((resolve) => {
+    const child = spawn(process.execPath, [
+        '--experimental-permission',
+        `--allow-fs-read=${path.join(__dirname, '*')}`,
+        '--max-old-space-size=64',
+        '--disallow-code-generation-from-strings',
+        CHILD,
+    ], { env: {}, cwd: __dirname, stdio: ['pipe', 'pipe', 'ignore'] })
+
+    let output = ''
+    let failure = null
+    const timer = setTimeout(() => {
+        failure = 'The script was stopped after ' + KILL_TIMEOUT + ' ms'
+        child.kill('SIGKILL')
+    }, KILL_TIMEOUT)
+
+    child.stdout.setEncoding('utf8')
+    child.stdout.on('data', (chunk) => {
+        output += chunk
+        if (output.length > MAX_OUTPUT) {
+            failure = 'The script wrote more than 1 MB'
+            child.kill('SIGKILL')
+        }
+    })
+    child.on('error', (err) => {
+        failure = `The script could not be started: ${err.message}`
+    })
+    child.on('close', () => {
+        clearTimeout(timer)
+        if (failure) {
+            return resolve({ result: null, error: failure, logs: [] })
+        }
+        try {
+            const { result, error, logs } = JSON.parse(output)
+            resolve({ result: result === undefined ? null : result, error: error || null, logs: Array.isArray(logs) ? logs : [] })
+        } catch (err) {
+            resolve({ result: null, error: 'The script exited without calling the callback', logs: [] })
+        }
+    })
+    child.stdin.on('error', () => {})
+    child.stdin.end(JSON.stringify({ kind, script, entry, args, configuration }))
+})
+
+module.exports = {
+    runScript,
+}
@@ -51,13 +51,18 @@ use users_settings::UserSettings;
 use roles_home::RolesHome;
 use role_details::ViewDetail;
 
+use organizations_home::OrganizationsHome;
+use organization_details::OrganizationDetails;
+
 use database_create_db::DbCreate;
 use database_home::DatabaseHome;
 use database_settings::DatabaseSettings;
 
 use enterprise_home::EnterpriseHome;
-use google_apps::EnterpriseGoogle;
-use google_app_create::EnterpriseGoogleCreate;
+use google_apps::{EnterpriseGoogle, EnterpriseGoogleCreate, EnterpriseGoogleSettings};
+use azure_ad::{EnterpriseAzure, EnterpriseAzureCreate, EnterpriseAzureSettings};
+use saml::{EnterpriseSaml, EnterpriseSamlCreate, EnterpriseSamlSettings};
+use oidc::{EnterpriseOidc, EnterpriseOidcCreate, EnterpriseOidcSettings};
 
 use passwordless_home::AuthPasswordLess;
 
@@ -67,12 +72,20 @@ use social_settings::SocialSettings;
 
 use logs_home::LogsHome;
 use log_details::LogDetails;
+use log_streams_home::LogStreamsHome;
+use log_streams_create::LogStreamsCreate;
+use log_stream_settings::LogStreamSettings;
 
 use profile::Profile;
 
 use crate::pages::{
     applications::{
-        sso::{create_sso::CreateSso, home::SsoHome},
+        sso::{
+            configure_sso::ConfigureSso,
+            create_sso::CreateSso,
+            home::SsoHome,
+            settings::SsoSettings,
+        },
     },
     settings::home::SettingsHome,
 };
@@ -353,6 +366,22 @@ impl Component for App {
                         html! {<HomePage/>}
                     }
                 }
+                AppRoute::ConfigureSso { integration_id } => {
+                    if is_logged_in {
+                        html! {<ConfigureSso integration_id=integration_id />}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
+                AppRoute::SsoSettings { sso_id } => {
+                    if is_logged_in {
+                        html! {<SsoSettings sso_id=sso_id />}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
                 AppRoute::SocialHome => {
                     if is_logged_in {
                         html! {<SocialHome/>}
@@ -361,9 +390,9 @@ impl Component for App {
                         html! {<HomePage/>}
                     }
                 }
-                AppRoute::SocialSettings => {
+                AppRoute::SocialSettings { connection_id } => {
                     if is_logged_in {
-                        html! {<SocialSettings/>}
+                        html! {<SocialSettings connection_id=connection_id />}
                     } else {
                         route_service.set_route("/", ());
                         html! {<HomePage/>}
@@ -413,6 +442,22 @@ impl Component for App {
                         html! {<HomePage/>}
                     }
                 }
+                AppRoute::OrganizationsHome { tenant_id } => {
+                    if is_logged_in {
+                        html! {<OrganizationsHome tenant_id=tenant_id />}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
+                AppRoute::OrganizationSettings { tenant_id, org_id } => {
+                    if is_logged_in {
+                        html! {<OrganizationDetails tenant_id=tenant_id org_id=org_id />}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
                 AppRoute::EnterpriseHome => {
                     if is_logged_in {
                         html! {<EnterpriseHome/>}
@@ -437,6 +482,86 @@ impl Component for App {
                         html! {<HomePage/>}
                     }
                 }
+                AppRoute::EnterpriseGoogleSettings { connection_id } => {
+                    if is_logged_in {
+                        html! {<EnterpriseGoogleSettings connection_id=connection_id />}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
+                AppRoute::EnterpriseAzure => {
+                    if is_logged_in {
+                        html! {<EnterpriseAzure/>}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
+                AppRoute::EnterpriseAzureCreate => {
+                    if is_logged_in {
+                        html! {<EnterpriseAzureCreate/>}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
+                AppRoute::EnterpriseAzureSettings { connection_id } => {
+                    if is_logged_in {
+                        html! {<EnterpriseAzureSettings connection_id=connection_id />}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
+                AppRoute::EnterpriseSaml => {
+                    if is_logged_in {
+                        html! {<EnterpriseSaml/>}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
+                AppRoute::EnterpriseSamlCreate => {
+                    if is_logged_in {
+                        html! {<EnterpriseSamlCreate/>}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
+                AppRoute::EnterpriseSamlSettings { connection_id } => {
+                    if is_logged_in {
+                        html! {<EnterpriseSamlSettings connection_id=connection_id />}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
+                AppRoute::EnterpriseOidc => {
+                    if is_logged_in {
+                        html! {<EnterpriseOidc/>}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
+                AppRoute::EnterpriseOidcCreate => {
+                    if is_logged_in {
+                        html! {<EnterpriseOidcCreate/>}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
+                AppRoute::EnterpriseOidcSettings { connection_id } => {
+                    if is_logged_in {
+                        html! {<EnterpriseOidcSettings connection_id=connection_id />}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
                 AppRoute::SettingsHome => {
                     if is_logged_in {
                         html! {<SettingsHome/>}
@@ -461,9 +586,9 @@ impl Component for App {
                         html! {<HomePage/>}
                     }
                 }
-                AppRoute::DatabaseSettings => {
+                AppRoute::DatabaseSettings { connection_id } => {
                     if is_logged_in {
-                        html! {<DatabaseSettings/>}
+                        html! {<DatabaseSettings connection_id=connection_id />}
                     } else {
                         route_service.set_route("/", ());
                         html! {<HomePage/>}
@@ -485,6 +610,30 @@ impl Component for App {
                         html! {<HomePage/>}
                     }
                 }
+                AppRoute::LogStreamsHome { tenant_id } => {
+                    if is_logged_in {
+                        html! {<LogStreamsHome tenant_id=tenant_id/>}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
+                AppRoute::LogStreamsCreate { tenant_id } => {
+                    if is_logged_in {
+                        html! {<LogStreamsCreate tenant_id=tenant_id/>}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
+                AppRoute::LogStreamSettings { tenant_id, stream_id } => {
+                    if is_logged_in {
+                        html! {<LogStreamSettings tenant_id=tenant_id stream_id=stream_id/>}
+                    } else {
+                        route_service.set_route("/", ());
+                        html! {<HomePage/>}
+                    }
+                }
                 AppRoute::Profile { tenant_id } => {
                     if is_logged_in {
                         html! {<Profile tenant_id=tenant_id />}
@@ -0,0 +1,385 @@
+use yew::prelude::*;
+use loading::Loading;
+
+pub mod query;
+
+use query::query_params;
+
+pub const PER_PAGE_OPTIONS: [u32; 4] = [10, 25, 50, 100];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sort {
+    pub field: String,
+    pub descending: bool,
+}
+
+impl Sort {
+    pub fn asc(field: &str) -> Sort {
+        Sort { field: field.to_string(), descending: false }
+    }
+
+    pub fn desc(field: &str) -> Sort {
+        Sort { field: field.to_string(), descending: true }
+    }
+
+    // field:1 OR field:-1, ONLY sort_fields CAN BE SORTED ON SINCE THE FIELD
+    // IS PASSED AS IS TO THE API
+    fn parse(value: &str, sort_fields: &[&str]) -> Option<Sort> {
+        let mut parts = value.splitn(2, ':');
+        let field = parts.next().filter(|field| sort_fields.contains(field))?;
+        match parts.next() {
+            Some("-1") => Some(Sort::desc(field)),
+            Some("1") | None => Some(Sort::asc(field)),
+            Some(_) => None,
+        }
+    }
+
+    fn to_param(&self) -> String {
+        format!("{}:{}", self.field, if self.descending { "-1" } else { "1" })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pagination {
+    pub page: u32,
+    pub per_page: u32,
+    pub sort: Option<Sort>,
+    default_per_page: u32,
+    default_sort: Option<Sort>,
+}
+
+impl Pagination {
+    pub fn new(per_page: u32, sort: Option<Sort>) -> Pagination {
+        Pagination {
+            page: 0,
+            per_page,
+            sort: sort.clone(),
+            default_per_page: per_page,
+            default_sort: sort,
+        }
+    }
+
+    // PAGE, PER PAGE AND SORT FROM THE URL, FALLING BACK TO THE DEFAULTS
+    pub fn from_query(query: &str, per_page: u32, sort: Option<Sort>, sort_fields: &[&str]) -> Pagination {
+        let mut pagination = Pagination::new(per_page, sort);
+        for (key, value) in query_params(query) {
+            match key.as_str() {
+                "page" => {
+                    if let Ok(page) = value.parse::<u32>() {
+                        pagination.page = page;
+                    }
+                }
+                "per_page" => {
+                    if let Ok(per_page) = value.parse::<u32>() {
+                        if PER_PAGE_OPTIONS.contains(&per_page) {
+                            pagination.per_page = per_page;
+                        }
+                    }
+                }
+                "sort" => {
+                    if let Some(sort) = Sort::parse(&value, sort_fields) {
+                        pagination.sort = Some(sort);
+                    }
+                }
+                _ => {}
+            }
+        }
+        pagination
+    }
+
+    // ONLY VALUES THAT DIFFER FROM THE DEFAULTS ARE KEPT IN THE URL
+    pub fn url_params(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+        if self.page > 0 {
+            params.push((String::from("page"), self.page.to_string()));
+        }
+        if self.per_page != self.default_per_page {
+            params.push((String::from("per_page"), self.per_page.to_string()));
+        }
+        if self.sort != self.default_sort {
+            if let Some(sort) = &self.sort {
+                params.push((String::from("sort"), sort.to_param()));
+            }
+        }
+        params
+    }
+
+    pub fn api_params(&self) -> String {
+        let mut params = format!("page={}&per_page={}&include_totals=true", self.page, self.per_page);
+        if let Some(sort) = &self.sort {
+            params.push_str(&format!("&sort={}", sort.to_param()));
+        }
+        params
+    }
+
+    pub fn first_page(&self) -> Pagination {
+        Pagination { page: 0, ..self.clone() }
+    }
+
+    pub fn page_count(&self, total: u32) -> u32 {
+        if total == 0 {
+            1
+        } else {
+            (total - 1) / self.per_page.max(1) + 1
+        }
+    }
+
+    // THE PAGE COMES FROM THE URL, IT CAN BE PAST THE LAST ONE
+    pub fn clamp(&self, total: u32) -> Pagination {
+        Pagination { page: self.page.min(self.page_count(total) - 1), ..self.clone() }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Column {
+    pub label: String,
+    pub sort_field: Option<String>,
+}
+
+impl Column {
+    pub fn new(label: &str) -> Column {
+        Column { label: label.to_string(), sort_field: None }
+    }
+
+    pub fn sortable(label: &str, sort_field: &str) -> Column {
+        Column { label: label.to_string(), sort_field: Some(sort_field.to_string()) }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct PaginatedTableProps {
+    pub columns: Vec<Column>,
+    pub pagination: Pagination,
+    pub total: u32,
+    pub loading: bool,
+    #[prop_or_default]
+    pub error: Option<String>,
+    #[prop_or(String::from("There are no results to display."))]
+    pub empty_message: String,
+    pub on_change: Callback<Pagination>,
+    #[prop_or_default]
+    pub children: Children,
+}
+
+pub struct PaginatedTable {
+    props: PaginatedTableProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    Sort(String),
+    Page(u32),
+    PerPage(u32),
+}
+
+impl Component for PaginatedTable {
+    type Message = Msg;
+    type Properties = PaginatedTableProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        PaginatedTable {
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut pagination = self.props.pagination.clone();
+        match msg {
+            Msg::Sort(field) => {
+                let descending = match &pagination.sort {
+                    Some(sort) if sort.field == field => !sort.descending,
+                    _ => false,
+                };
+                pagination.sort = Some(Sort { field, descending });
+                pagination.page = 0;
+            }
+            Msg::Page(page) => {
+                pagination.page = page;
+            }
+            Msg::PerPage(per_page) => {
+                pagination.per_page = per_page;
+                pagination.page = 0;
+            }
+        }
+        self.props.on_change.emit(pagination);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            // A PAGE PAST THE END, eg. FROM AN OLD LINK, GOES BACK TO THE LAST ONE
+            let clamped = props.pagination.clamp(props.total);
+            if !props.loading && props.error.is_none() && clamped != props.pagination {
+                props.on_change.emit(clamped);
+            }
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let show_rows = !self.props.loading && self.props.error.is_none();
+        html! {
+            <>
+                <div class="mt-2 table-responsive-md table-responsive-lg">
+                    <table class="table">
+                        <thead>
+                            <tr>
+                                { for self.props.columns.iter().map(|column| self.view_column(column)) }
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {
+                                if show_rows {
+                                    html! { for self.props.children.iter() }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                        </tbody>
+                    </table>
+                </div>
+                {
+                    if self.props.loading {
+                        html! {
+                            <div style="position: relative; margin-top: 4rem;">
+                                <Loading width=45 />
+                            </div>
+                        }
+                    } else if let Some(error) = &self.props.error {
+                        html! {
+                            <div class="alert alert-warning mb-5" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { error.clone() }
+                            </div>
+                        }
+                    } else if self.props.children.is_empty() {
+                        html! {
+                            <p class="text-muted text-center py-4">{ self.props.empty_message.clone() }</p>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+                { self.view_pager() }
+            </>
+        }
+    }
+}
+
+impl PaginatedTable {
+    fn view_column(&self, column: &Column) -> Html {
+        match &column.sort_field {
+            Some(sort_field) => {
+                let icon = match &self.props.pagination.sort {
+                    Some(sort) if &sort.field == sort_field => {
+                        if sort.descending { "bi bi-caret-down-fill ms-1" } else { "bi bi-caret-up-fill ms-1" }
+                    }
+                    _ => "bi bi-arrow-down-up ms-1 text-muted",
+                };
+                let field = sort_field.clone();
+                html! {
+                    <th scope="col" style="cursor: pointer; white-space: nowrap;" onclick=self.link.callback(move |_| Msg::Sort(field.clone()))>
+                        { column.label.clone() }
+                        <i class=icon style="font-size: 11px;"></i>
+                    </th>
+                }
+            }
+            None => html! {
+                <th scope="col">{ column.label.clone() }</th>
+            },
+        }
+    }
+
+    fn view_pager(&self) -> Html {
+        let total = self.props.total;
+        let pagination = &self.props.pagination.clamp(total);
+        let page_count = pagination.page_count(total);
+        let first = if total == 0 { 0 } else { pagination.page.saturating_mul(pagination.per_page).saturating_add(1) };
+        let last = pagination.page.saturating_add(1).saturating_mul(pagination.per_page).min(total);
+        let page = pagination.page;
+        let disabled = self.props.loading;
+        html! {
+            <div class="d-flex align-items-center mt-3 mb-5">
+                <div class="text-muted flex-fill" style="font-size: 14px;">
+                    { format!("Showing {}–{} of {}", first, last, total) }
+                </div>
+                <div class="d-flex align-items-center">
+                    <select
+                        class="form-select form-select-sm w-auto me-3"
+                        disabled=disabled
+                        onchange=self.link.batch_callback(|e| {
+                            if let ChangeData::Select(select) = e {
+                                select.value().parse::<u32>().ok().map(Msg::PerPage)
+                            } else {
+                                None
+                            }
+                        })
+                    >
+                        {
+                            for PER_PAGE_OPTIONS.iter().map(|per_page| html! {
+                                <option value=per_page.to_string() selected={ *per_page == pagination.per_page }>
+                                    { format!("{} per page", per_page) }
+                                </option>
+                            })
+                        }
+                    </select>
+                    <button
+                        type="button"
+                        class="btn btn-sm btn-outline-secondary"
+                        disabled={ disabled || page == 0 }
+                        onclick=self.link.callback(move |_| Msg::Page(page.saturating_sub(1)))
+                    >
+                        <i class="bi bi-arrow-left"></i>
+                    </button>
+                    <span class="mx-3" style="font-size: 14px;">{ format!("Page {} of {}", page + 1, page_count) }</span>
+                    <button
+                        type="button"
+                        class="btn btn-sm btn-outline-secondary"
+                        disabled={ disabled || page + 1 >= page_count }
+                        onclick=self.link.callback(move |_| Msg::Page(page + 1))
+                    >
+                        <i class="bi bi-arrow-right"></i>
+                    </button>
+                </div>
+            </div>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_count_rounds_up() {
+        let pagination = Pagination::new(50, None);
+        assert_eq!(pagination.page_count(0), 1);
+        assert_eq!(pagination.page_count(50), 1);
+        assert_eq!(pagination.page_count(51), 2);
+        assert_eq!(pagination.page_count(u32::MAX), u32::MAX / 50 + 1);
+    }
+
+    #[test]
+    fn clamp_brings_a_page_past_the_end_back() {
+        let pagination = Pagination { page: u32::MAX, ..Pagination::new(50, None) };
+        assert_eq!(pagination.clamp(120).page, 2);
+        assert_eq!(pagination.clamp(0).page, 0);
+        let pagination = Pagination { page: 1, ..Pagination::new(50, None) };
+        assert_eq!(pagination.clamp(120), pagination);
+    }
+
+    #[test]
+    fn only_sortable_fields_are_parsed() {
+        let sort_fields = ["name", "last_login"];
+        assert_eq!(Sort::parse("last_login:-1", &sort_fields), Some(Sort::desc("last_login")));
+        assert_eq!(Sort::parse("name", &sort_fields), Some(Sort::asc("name")));
+        assert_eq!(Sort::parse("name:2", &sort_fields), None);
+        assert_eq!(Sort::parse("a&b:1", &sort_fields), None);
+        assert_eq!(Sort::parse("email:1", &sort_fields), None);
+        assert_eq!(Sort::parse("", &sort_fields), None);
+    }
+}
@@ -0,0 +1,51 @@
+use yew_router::service::RouteService;
+
+// URL QUERY STRING HELPERS, SHARED BY PAGES THAT KEEP THEIR STATE IN THE URL
+
+pub fn query_params(query: &str) -> Vec<(String, String)> {
+    query
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let mut pair = pair.splitn(2, '=');
+            let key = pair.next().unwrap_or_default();
+            let value = pair.next().unwrap_or_default();
+            (decode_uri_component(key), decode_uri_component(value))
+        })
+        .collect()
+}
+
+pub fn query_param(query: &str, key: &str) -> Option<String> {
+    query_params(query)
+        .into_iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value)
+}
+
+pub fn build_query(params: &[(String, String)]) -> String {
+    if params.is_empty() {
+        return String::from("");
+    }
+    let pairs: Vec<String> = params
+        .iter()
+        .map(|(key, value)| format!("{}={}", encode_uri_component(key), encode_uri_component(value)))
+        .collect();
+    format!("?{}", pairs.join("&"))
+}
+
+// REPLACES THE QUERY OF THE CURRENT PATH WITHOUT ADDING A HISTORY ENTRY
+pub fn replace_query(route_service: &mut RouteService, params: &[(String, String)]) {
+    let path = route_service.get_path();
+    route_service.replace_route(&format!("{}{}", path, build_query(params)), ());
+}
+
+pub fn encode_uri_component(value: &str) -> String {
+    String::from(js_sys::encode_uri_component(value))
+}
+
+pub fn decode_uri_component(value: &str) -> String {
+    js_sys::decode_uri_component(value)
+        .map(String::from)
+        .unwrap_or_else(|_| value.to_string())
+}
@@ -201,14 +201,16 @@ impl Component for Sidebar {
                                   </div>
                               </li>
                               <li class="jss74 nav-item">
-                                  <a title="Organizations" class="jss76 jss78" aria-current="page"
-                                      href="/dashboard/us/dev-test-telkom/organizations">
+                                  <Anchor
+                                    route=AppRoute::OrganizationsHome { tenant_id: tenant_id.clone() }
+                                    classes="jss76 text-decoration-none"
+                                  >
                                       <span class="jss80 jss81">
                                           <i class="fas fa-building"></i>
                                       </span>
                                       <span class="jss79">{"Organizations"}</span><span class="jss2 jss108 jss85 jss97 jss98 jss82"
                                           data-cosmos-key="label">{"New"}</span>
-                                  </a>
+                                  </Anchor>
                               </li>
                               <li class="jss74 jss104 nav-item">
                                   <button data-bs-toggle="collapse" data-bs-target="#menu_item3"
@@ -432,9 +434,15 @@ impl Component for Sidebar {
                                                             <span class="jss79">{"Logs"}</span>
                                                           </Anchor>
                                                       </li>
-                                                      <li class="jss74"><a title="Streams" class="jss76 jss77"
-                                                          href="/dashboard/us/dev-test-telkom/log-streams"><span class="jss80 jss81"></span><span
-                                                          class="jss79">{"Streams"}</span></a></li>
+                                                      <li class="jss74">
+                                                          <Anchor
+                                                            route=AppRoute::LogStreamsHome {tenant_id: tenant_id.clone()}
+                                                            classes="jss76 jss77 text-decoration-none"
+                                                          >
+                                                            <span class="jss80 jss81"></span>
+                                                            <span class="jss79">{"Streams"}</span>
+                                                          </Anchor>
+                                                      </li>
                                                   </ul>
                                               </div>
                                           </div>
@@ -0,0 +1,147 @@
+use yew::prelude::*;
+
+// LINE CHART OF ONE VALUE PER DAY AS INLINE SVG, THE viewBox SCALES IT TO
+// THE WIDTH OF THE CARD
+
+const WIDTH: f64 = 600.0;
+const HEIGHT: f64 = 200.0;
+const PADDING_TOP: f64 = 10.0;
+const PADDING_RIGHT: f64 = 12.0;
+const PADDING_BOTTOM: f64 = 26.0;
+const PADDING_LEFT: f64 = 40.0;
+
+const GRID_LINES: u32 = 4;
+
+// AT MOST THIS MANY DAYS ARE LABELED ON THE X AXIS
+const MAX_X_LABELS: usize = 8;
+
+const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+// days ARE YYYY-MM-DD, values HAS ONE ENTRY PER DAY
+pub fn view_chart(days: &[String], values: &[u32], color: &str, unit: &str) -> Html {
+    let top = axis_top(values.iter().copied().max().unwrap_or(0));
+    let plot_width = WIDTH - PADDING_LEFT - PADDING_RIGHT;
+    let plot_height = HEIGHT - PADDING_TOP - PADDING_BOTTOM;
+    let bottom = HEIGHT - PADDING_BOTTOM;
+
+    let x = |index: usize| {
+        if values.len() < 2 {
+            PADDING_LEFT + plot_width / 2.0
+        } else {
+            PADDING_LEFT + plot_width * index as f64 / (values.len() - 1) as f64
+        }
+    };
+    let y = |value: u32| bottom - plot_height * value as f64 / top as f64;
+
+    let points: Vec<(f64, f64)> = values.iter().enumerate().map(|(index, value)| (x(index), y(*value))).collect();
+    let line = points
+        .iter()
+        .enumerate()
+        .map(|(index, (px, py))| format!("{}{:.1} {:.1}", if index == 0 { "M" } else { "L" }, px, py))
+        .collect::<Vec<String>>()
+        .join(" ");
+    let area = match (points.first(), points.last()) {
+        (Some((first_x, _)), Some((last_x, _))) => format!("{} L{:.1} {:.1} L{:.1} {:.1} Z", line, last_x, bottom, first_x, bottom),
+        _ => String::new(),
+    };
+    let label_every = days.len().div_ceil(MAX_X_LABELS).max(1);
+
+    html! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            viewBox=format!("0 0 {} {}", WIDTH, HEIGHT)
+            width="100%"
+            role="img"
+            style="display: block;"
+        >
+            {
+                for (0..=GRID_LINES).map(|line| {
+                    let value = top / GRID_LINES * line;
+                    let grid_y = y(value);
+                    html! {
+                        <>
+                            <line
+                                x1=PADDING_LEFT.to_string()
+                                x2=(WIDTH - PADDING_RIGHT).to_string()
+                                y1=grid_y.to_string()
+                                y2=grid_y.to_string()
+                                stroke="#dee2e6"
+                                stroke-width="1"
+                                stroke-dasharray={ if line == 0 { "" } else { "4 4" } }
+                            />
+                            <text
+                                x=(PADDING_LEFT - 8.0).to_string()
+                                y=(grid_y + 4.0).to_string()
+                                text-anchor="end"
+                                font-size="11"
+                                fill="#6c757d"
+                            >
+                                { value }
+                            </text>
+                        </>
+                    }
+                })
+            }
+
+            <path d=area fill=color.to_string() fill-opacity="0.12" stroke="none" />
+            <path d=line fill="none" stroke=color.to_string() stroke-width="2" stroke-linejoin="round" stroke-linecap="round" />
+
+            {
+                for points.iter().zip(values.iter()).zip(days.iter()).enumerate().map(|(index, (((px, py), value), day))| {
+                    // THE LAST DAY IS ALWAYS LABELED, IT IS TODAY
+                    let labeled = (days.len() - 1 - index).is_multiple_of(label_every);
+                    html! {
+                        <>
+                            <circle cx=px.to_string() cy=py.to_string() r="3" fill="#fff" stroke=color.to_string() stroke-width="2">
+                                <title>{ format!("{}: {} {}", short_day(day), value, unit) }</title>
+                            </circle>
+                            {
+                                if labeled {
+                                    html! {
+                                        <text
+                                            x=px.to_string()
+                                            y=(HEIGHT - 8.0).to_string()
+                                            text-anchor="middle"
+                                            font-size="11"
+                                            fill="#6c757d"
+                                        >
+                                            { short_day(day) }
+                                        </text>
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                        </>
+                    }
+                })
+            }
+        </svg>
+    }
+}
+
+// A ROUND MAXIMUM SO EVERY GRID LINE FALLS ON A WHOLE NUMBER, eg. 37 GIVES 40
+fn axis_top(max: u32) -> u32 {
+    let step = max.div_ceil(GRID_LINES);
+    let mut magnitude = 1;
+    while magnitude * 10 <= step {
+        magnitude *= 10;
+    }
+    let nice_step = [1, 2, 5, 10]
+        .iter()
+        .map(|factor| factor * magnitude)
+        .find(|candidate| *candidate >= step)
+        .unwrap_or(magnitude * 10)
+        .max(1);
+    nice_step * GRID_LINES
+}
+
+// 2026-10-14 GIVES Oct 14
+pub fn short_day(day: &str) -> String {
+    let month = day.get(5..7).and_then(|month| month.parse::<usize>().ok()).unwrap_or(0);
+    let date = day.get(8..10).and_then(|date| date.parse::<u32>().ok()).unwrap_or(0);
+    match MONTHS.get(month.wrapping_sub(1)) {
+        Some(name) => format!("{} {}", name, date),
+        None => day.to_string(),
+    }
+}
@@ -1,19 +1,325 @@
-use yew::prelude::*;
+use yew::{
+    prelude::*,
+    format::{Json, Nothing},
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+    },
+};
+use wasm_bindgen::JsValue;
+use types::{
+    activity::{
+        ActivityEvent,
+        ActivityMetric,
+        ACTIVITY_PER_PAGE,
+        ACTIVITY_RANGES,
+        DEFAULT_ACTIVITY_RANGE,
+        MAX_ACTIVITY_EVENTS,
+    },
+    api::ApiTitle,
+    application::AppList,
+    organizations::ConnectionOption,
+    pagination::Page,
+    users::UserTitle,
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use loading::Loading;
+use configs::server::API_URL;
+use paginated_table::query::encode_uri_component;
 
-pub struct Activity {}
+mod chart;
 
-pub enum Msg {}
+use chart::{short_day, view_chart};
+
+const DAY: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+
+#[derive(Clone, Copy)]
+pub enum Count {
+    Users,
+    Applications,
+    Apis,
+    Connections,
+}
+
+// ONE CHART, ITS EVENTS ARE FETCHED PAGE BY PAGE FOR THE SELECTED RANGE
+pub struct Chart {
+    metric: ActivityMetric,
+    range: u32,
+    // YYYY-MM-DD, OLDEST FIRST, THE LAST ONE IS TODAY
+    days: Vec<String>,
+    events: Vec<ActivityEvent>,
+    page: u32,
+    // MATCHING EVENTS REPORTED BY THE API, CAN BE MORE THAN FETCHED
+    total: u32,
+    fetch_task: Option<FetchTask>,
+    loading: bool,
+    error: Option<String>,
+}
+
+impl Chart {
+    fn new(metric: ActivityMetric) -> Chart {
+        Chart {
+            metric,
+            range: DEFAULT_ACTIVITY_RANGE,
+            days: last_days(DEFAULT_ACTIVITY_RANGE),
+            events: vec![],
+            page: 0,
+            total: 0,
+            fetch_task: None,
+            loading: false,
+            error: None,
+        }
+    }
+
+    fn is_truncated(&self) -> bool {
+        (self.events.len() as u32) < self.total
+    }
+}
+
+pub struct Activity {
+    // SERVICES
+    link: ComponentLink<Self>,
+    fetch_task_counts: Vec<FetchTask>,
+
+    // DATA
+    access_token: String,
+    total_users: Option<u32>,
+    total_applications: Option<u32>,
+    total_apis: Option<u32>,
+    total_connections: Option<u32>,
+    charts: Vec<Chart>,
+
+    // LAYOUT STATE
+    error_counts: Option<String>,
+}
+
+pub enum StateError {
+    Counts,
+    Chart(usize),
+}
+
+pub enum Msg {
+    Refresh,
+    RequestCounts,
+    GetCount(Count, u32),
+    SelectRange(usize, u32),
+    RequestChart(usize),
+    RequestChartPage(usize),
+    GetChartPage(usize, Page<ActivityEvent>),
+    ResponseError(String, StateError),
+}
 
 impl Component for Activity {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
-        Activity {}
+    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        // GET LOCALSTORAGE
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        // UPDATE STATE
+        let mut access_token = String::from("");
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        Activity {
+            link,
+            fetch_task_counts: vec![],
+            access_token,
+            total_users: None,
+            total_applications: None,
+            total_apis: None,
+            total_connections: None,
+            charts: ActivityMetric::ALL.iter().map(|metric| Chart::new(*metric)).collect(),
+            error_counts: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::Refresh);
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        true
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Refresh => {
+                self.link.send_message(Msg::RequestCounts);
+                for (index, chart) in self.charts.iter_mut().enumerate() {
+                    // TODAY MAY HAVE CHANGED SINCE THE PAGE WAS OPENED
+                    chart.days = last_days(chart.range);
+                    self.link.send_message(Msg::RequestChart(index));
+                }
+                false
+            }
+            Msg::RequestCounts => {
+                // ONLY THE TOTAL IS NEEDED FROM THE USERS, NOT THE USERS THEMSELVES
+                let request_users = Request::get(format!("{}/api/v2/users?page=0&per_page=1&include_totals=true", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback_users = self.link.callback(
+                    |response: Response<Json<Result<Page<UserTitle>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetCount(Count::Users, dataok.total),
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::Counts),
+                        }
+                    },
+                );
+                let request_applications = Request::get(format!("{}/api/v2/clients", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback_applications = self.link.callback(
+                    |response: Response<Json<Result<Vec<AppList>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetCount(Count::Applications, dataok.len() as u32),
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::Counts),
+                        }
+                    },
+                );
+                let request_apis = Request::get(format!("{}/api/v2/resource-server", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback_apis = self.link.callback(
+                    |response: Response<Json<Result<Vec<ApiTitle>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetCount(Count::Apis, dataok.len() as u32),
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::Counts),
+                        }
+                    },
+                );
+                let request_connections = Request::get(format!("{}/api/v2/connections", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback_connections = self.link.callback(
+                    |response: Response<Json<Result<Vec<ConnectionOption>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetCount(Count::Connections, dataok.len() as u32),
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::Counts),
+                        }
+                    },
+                );
+                self.fetch_task_counts = vec![
+                    FetchService::fetch(request_users, callback_users).expect("failed to start request"),
+                    FetchService::fetch(request_applications, callback_applications).expect("failed to start request"),
+                    FetchService::fetch(request_apis, callback_apis).expect("failed to start request"),
+                    FetchService::fetch(request_connections, callback_connections).expect("failed to start request"),
+                ];
+                self.total_users = None;
+                self.total_applications = None;
+                self.total_apis = None;
+                self.total_connections = None;
+                self.error_counts = None;
+                true
+            }
+            Msg::GetCount(count, total) => {
+                match count {
+                    Count::Users => self.total_users = Some(total),
+                    Count::Applications => self.total_applications = Some(total),
+                    Count::Apis => self.total_apis = Some(total),
+                    Count::Connections => self.total_connections = Some(total),
+                }
+                true
+            }
+            Msg::SelectRange(index, range) => {
+                let chart = &mut self.charts[index];
+                chart.range = range;
+                chart.days = last_days(range);
+                self.link.send_message(Msg::RequestChart(index));
+                false
+            }
+            Msg::RequestChart(index) => {
+                // A NEW RANGE DROPS THE PAGES STILL LOADING FOR THE PREVIOUS ONE
+                let chart = &mut self.charts[index];
+                chart.page = 0;
+                chart.events = vec![];
+                chart.total = 0;
+                self.link.send_message(Msg::RequestChartPage(index));
+                false
+            }
+            Msg::RequestChartPage(index) => {
+                let chart = &mut self.charts[index];
+                let url = format!(
+                    "{}{}?page={}&per_page={}&include_totals=true&{}&q={}",
+                    API_URL,
+                    chart.metric.endpoint(),
+                    chart.page,
+                    ACTIVITY_PER_PAGE,
+                    chart.metric.api_params(),
+                    encode_uri_component(&chart.metric.lucene(&chart.days[0])),
+                );
+                let request = Request::get(url)
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    move |response: Response<Json<Result<Page<ActivityEvent>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetChartPage(index, dataok),
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::Chart(index)),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                chart.fetch_task = Some(task);
+                chart.error = None;
+                chart.loading = true;
+                true
+            }
+            Msg::GetChartPage(index, data) => {
+                let chart = &mut self.charts[index];
+                let fetched = (chart.page + 1) * ACTIVITY_PER_PAGE;
+                let is_last = data.length < ACTIVITY_PER_PAGE || fetched >= data.total || fetched >= MAX_ACTIVITY_EVENTS;
+                chart.events.extend(data.items);
+                chart.total = data.total;
+                if is_last {
+                    chart.page = 0;
+                    chart.loading = false;
+                    chart.fetch_task = None;
+                } else {
+                    chart.page += 1;
+                    self.link.send_message(Msg::RequestChartPage(index));
+                }
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::Counts => {
+                        self.error_counts = Some(message);
+                        self.fetch_task_counts = vec![];
+                    }
+                    StateError::Chart(index) => {
+                        let chart = &mut self.charts[index];
+                        chart.error = Some(message);
+                        chart.loading = false;
+                        chart.page = 0;
+                        chart.fetch_task = None;
+                    }
+                }
+                true
+            }
+        }
     }
 
     fn change(&mut self, _: Self::Properties) -> ShouldRender {
@@ -21,38 +327,188 @@ impl Component for Activity {
     }
 
     fn view(&self) -> Html {
+        let is_loading = !self.fetch_task_counts.is_empty() && self.error_counts.is_none()
+            || self.charts.iter().any(|chart| chart.loading);
         html! {
             <div
                 class="domain-content"
             >
-                <div class="flex-fill title"> {"Activity"}</div>
-                
+                <div class="d-flex align-items-center">
+                    <div class="flex-fill title"> {"Activity"}</div>
+                    <button
+                        type="button"
+                        class="btn btn-outline-secondary"
+                        onclick=self.link.callback(|_| Msg::Refresh)
+                        disabled=is_loading
+                    >
+                        <i class="bi bi-arrow-clockwise me-2"></i>
+                        {"Refresh"}
+                    </button>
+                </div>
+
                 <div class="card mt-3 me-2">
                     <div class="card-body">
                         <div class="container">
                             <div class="row">
-                                <div class="col">
-                                    <p class="text-muted">{"Total Users"}</p>
-                                    <p class="fw-bold">{"0"}</p>
-                                </div>
-                                <div class="col">
-                                    <p class="text-muted">{"Application"}</p>
-                                    <p class="fw-bold">{"8"}</p>
-                                </div>
-                                <div class="col">
-                                    <p class="text-muted">{"APIs"}</p>
-                                    <p class="fw-bold">{"3"}</p>
-                                </div>
-                                <div class="col">
-                                    <p class="text-muted">{"Connnection"}</p>
-                                    <p class="fw-bold">{"0"}</p>
-                                </div>
+                                { self.view_count("Total Users", self.total_users) }
+                                { self.view_count("Applications", self.total_applications) }
+                                { self.view_count("APIs", self.total_apis) }
+                                { self.view_count("Connections", self.total_connections) }
                             </div>
                         </div>
+                        {
+                            if let Some(message) = &self.error_counts {
+                                html! {
+                                    <div class="alert alert-warning mb-0 mt-2" role="alert">
+                                        <i class="bi bi-exclamation-triangle me-2"></i>
+                                        { message }
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
                     </div>
                 </div>
 
+                <div class="row mt-3 me-2 g-3">
+                    {
+                        for self.charts.iter().enumerate().map(|(index, chart)| html! {
+                            <div class="col-lg-6">
+                                { self.view_chart_card(index, chart) }
+                            </div>
+                        })
+                    }
+                </div>
+
+            </div>
+        }
+    }
+}
+
+impl Activity {
+    fn view_count(&self, label: &str, count: Option<u32>) -> Html {
+        html! {
+            <div class="col">
+                <p class="text-muted">{ label }</p>
+                {
+                    match count {
+                        Some(count) => html! { <p class="fw-bold">{ count }</p> },
+                        None if self.error_counts.is_some() => html! { <p class="fw-bold">{"-"}</p> },
+                        None => html! {
+                            <p>
+                                <span class="spinner-border spinner-border-sm text-muted" role="status"></span>
+                            </p>
+                        },
+                    }
+                }
             </div>
         }
     }
+
+    fn view_chart_card(&self, index: usize, chart: &Chart) -> Html {
+        let metric = chart.metric;
+        let values = metric.daily_counts(&chart.events, &chart.days);
+        let first_day = chart.days.first().map(|day| short_day(day)).unwrap_or_default();
+        // ONLY A MINIMUM WHEN THE OLDEST EVENTS COULDN'T BE FETCHED
+        let total = if chart.is_truncated() {
+            format!("{}+", metric.range_total(&chart.events, &chart.days))
+        } else {
+            metric.range_total(&chart.events, &chart.days).to_string()
+        };
+        html! {
+            <div class="card h-100">
+                <div class="card-body">
+                    <div class="d-flex align-items-start mb-3">
+                        <i class=classes!("bi", metric.icon(), "me-3") style=format!("font-size: 24px; color: {};", metric.color())></i>
+                        <div class="flex-fill">
+                            <p class="mb-0 fw-bold">{ metric.label() }</p>
+                            <p class="mb-0 text-muted" style="font-size: 13px;">{ metric.description() }</p>
+                        </div>
+                        <select
+                            class="form-select form-select-sm ms-3"
+                            style="width: auto;"
+                            onchange=self.link.callback(move |e| {
+                                if let ChangeData::Select(select) = e {
+                                    Msg::SelectRange(index, select.value().parse().unwrap_or(DEFAULT_ACTIVITY_RANGE))
+                                } else {
+                                    Msg::SelectRange(index, DEFAULT_ACTIVITY_RANGE)
+                                }
+                            })
+                        >
+                            {
+                                for ACTIVITY_RANGES.iter().map(|(days, label)| html! {
+                                    <option value=days.to_string() selected={ chart.range == *days }>{ *label }</option>
+                                })
+                            }
+                        </select>
+                    </div>
+
+                    {
+                        if chart.loading {
+                            html! {
+                                <div class="d-flex justify-content-center align-items-center" style="height: 230px;">
+                                    <Loading width=45 />
+                                </div>
+                            }
+                        } else if let Some(message) = &chart.error {
+                            html! {
+                                <div class="d-flex flex-column justify-content-center" style="height: 230px;">
+                                    <div class="alert alert-warning" role="alert">
+                                        <i class="bi bi-exclamation-triangle me-2"></i>
+                                        { message }
+                                    </div>
+                                    <button
+                                        type="button"
+                                        class="btn btn-sm btn-outline-secondary align-self-center"
+                                        onclick=self.link.callback(move |_| Msg::RequestChart(index))
+                                    >
+                                        {"Try again"}
+                                    </button>
+                                </div>
+                            }
+                        } else {
+                            html! {
+                                <>
+                                    <p class="mb-2">
+                                        <span class="fw-bold" style="font-size: 24px;">
+                                            { total }
+                                        </span>
+                                        <span class="text-muted ms-2">
+                                            { format!("{} since {}", metric.unit(), first_day) }
+                                        </span>
+                                    </p>
+                                    { view_chart(&chart.days, &values, metric.color(), metric.unit()) }
+                                    {
+                                        if chart.is_truncated() {
+                                            html! {
+                                                <p class="text-muted mb-0 mt-2" style="font-size: 13px;">
+                                                    <i class="bi bi-info-circle me-2"></i>
+                                                    { format!("Only the latest {} of {} events could be counted, the total is a minimum and the first days are incomplete.", chart.events.len(), chart.total) }
+                                                </p>
+                                            }
+                                        } else {
+                                            html! {}
+                                        }
+                                    }
+                                </>
+                            }
+                        }
+                    }
+                </div>
+            </div>
+        }
+    }
+}
+
+// THE LAST count UTC DAYS AS YYYY-MM-DD, OLDEST FIRST
+fn last_days(count: u32) -> Vec<String> {
+    let now = js_sys::Date::now();
+    (0..count)
+        .rev()
+        .map(|ago| {
+            let date = js_sys::Date::new(&JsValue::from_f64(now - ago as f64 * DAY));
+            String::from(date.to_iso_string()).chars().take(10).collect()
+        })
+        .collect()
 }
@@ -0,0 +1,293 @@
+use std::collections::BTreeMap;
+use yew::{
+    prelude::*,
+    format::Json,
+    services::{
+        ConsoleService,
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{ Area, StorageService },
+    },
+    agent::Bridged,
+    Bridge,
+};
+use yew_router::{
+    agent::RouteRequest::ChangeRoute,
+    prelude::*,
+};
+use router::AppRoute;
+use configs::server::API_URL;
+use types::{
+    sso::{
+        SsoCatalog,
+        SsoCatalogItem,
+        SsoField,
+        SsoIntegration,
+        SsoIntegrationCreate,
+        default_settings,
+        validate_settings,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use super::{
+    create_sso::view_logo,
+    form::SsoSchemaForm,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ConfigureSsoProps {
+    pub integration_id: String,
+}
+
+pub struct ConfigureSso {
+    access_token: String,
+    link: ComponentLink<Self>,
+    catalog: SsoCatalog,
+    integration: Option<SsoCatalogItem>,
+    schema: Vec<SsoField>,
+    name: String,
+    settings: BTreeMap<String, String>,
+    errors: BTreeMap<String, String>,
+    fetch_task: Option<FetchTask>,
+    loading_create: bool,
+    error_create: Option<String>,
+    route_agent: Box<dyn Bridge<RouteAgent>>,
+}
+
+pub enum Msg {
+    InputName(String),
+    InputSetting((String, String)),
+    Create,
+    RedirectToSettings(SsoIntegration),
+    ResponseError(String),
+    Ignore,
+}
+
+impl Component for ConfigureSso {
+    type Message = Msg;
+    type Properties = ConfigureSsoProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        let catalog = SsoCatalog::load();
+        let integration = catalog.find(&props.integration_id);
+        let schema = match &integration {
+            Some(integration) => catalog.schema(integration),
+            None => Vec::new(),
+        };
+        let settings = default_settings(&schema);
+        let name = integration
+            .as_ref()
+            .map(|integration| integration.name.clone())
+            .unwrap_or_default();
+
+        ConfigureSso {
+            access_token,
+            link: link.clone(),
+            catalog,
+            integration,
+            schema,
+            name,
+            settings,
+            errors: BTreeMap::new(),
+            fetch_task: None,
+            loading_create: false,
+            error_create: None,
+            route_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::InputName(name) => {
+                self.name = name;
+                true
+            }
+            Msg::InputSetting((key, value)) => {
+                self.settings.insert(key.clone(), value);
+                self.errors.remove(&key);
+                true
+            }
+            Msg::Create => {
+                let integration = match &self.integration {
+                    Some(integration) => integration.clone(),
+                    None => return false,
+                };
+                self.errors = validate_settings(&self.schema, &self.settings);
+                if self.name.trim().is_empty() {
+                    self.errors.insert(String::from("name"), String::from("Name is required"));
+                }
+                if !self.errors.is_empty() {
+                    return true;
+                }
+
+                let sso_create = SsoIntegrationCreate {
+                    integration_id: integration.id,
+                    name: self.name.trim().to_string(),
+                    protocol: integration.protocol,
+                    status: String::from("active"),
+                    settings: self.settings.clone(),
+                };
+                let request = Request::post(format!("{}/api/v2/sso-integrations", API_URL))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&sso_create))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<SsoIntegration, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => {
+                                ConsoleService::info(&format!("created sso integration = {:?}", dataok));
+                                Msg::RedirectToSettings(dataok)
+                            }
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.loading_create = true;
+                self.error_create = None;
+                true
+            }
+            Msg::RedirectToSettings(sso_integration) => {
+                self.fetch_task = None;
+                self.loading_create = false;
+                self.route_agent.send(ChangeRoute(AppRoute::SsoSettings { sso_id: sso_integration.id }.into()));
+                true
+            }
+            Msg::ResponseError(message) => {
+                self.fetch_task = None;
+                self.loading_create = false;
+                self.error_create = Some(message);
+                true
+            }
+            Msg::Ignore => false,
+        }
+    }
+
+    fn change(&mut self, _: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        html! {
+            <div class="col py-3">
+                <div class="mx-auto pt-5 pb-5 px-4" style="max-width: 1048px;">
+                    <div>
+                        <Anchor route=AppRoute::CreateSso classes="text-decoration-none text-muted">
+                            <i class="bi bi-arrow-left"></i>
+                            <span>{"Back To SSO Integrations"}</span>
+                        </Anchor>
+                    </div>
+                    {
+                        match &self.integration {
+                            Some(integration) => self.view_form(integration),
+                            None => html! {
+                                <div class="alert alert-warning mt-4" role="alert">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    {"This SSO integration is not available in the catalog."}
+                                </div>
+                            },
+                        }
+                    }
+                </div>
+            </div>
+        }
+    }
+}
+
+impl ConfigureSso {
+    fn view_form(&self, integration: &SsoCatalogItem) -> Html {
+        let name_error = self.errors.get("name").cloned();
+        html! {
+            <>
+                <div class="d-flex align-items-center mt-3 mb-4">
+                    { view_logo(integration) }
+                    <div class="ms-3">
+                        <h2 class="title mb-0">{ integration.name.clone() }</h2>
+                        <span class="text-muted">{ self.catalog.protocol_name(&integration.protocol) }</span>
+                    </div>
+                </div>
+                <p class="text-muted">{ integration.description.clone() }</p>
+
+                <form class="mt-4" style="max-width: 640px;">
+                    <div class="mb-4">
+                        <label for="sso-name" class="form-label fw-bold">
+                            {"Name"}
+                            <span class="text-danger">{" *"}</span>
+                        </label>
+                        <input
+                            id="sso-name"
+                            type="text"
+                            class=classes!("form-control", if name_error.is_some() { "is-invalid" } else { "" })
+                            value=self.name.clone()
+                            disabled=self.loading_create
+                            oninput=self.link.callback(|data: InputData| Msg::InputName(data.value))
+                        />
+                        {
+                            match name_error {
+                                Some(error) => html! { <div class="invalid-feedback d-block">{ error }</div> },
+                                None => html! {},
+                            }
+                        }
+                    </div>
+
+                    <SsoSchemaForm
+                        schema=self.schema.clone()
+                        settings=self.settings.clone()
+                        errors=self.errors.clone()
+                        disabled=self.loading_create
+                        on_change=self.link.callback(Msg::InputSetting)
+                    />
+
+                    <button
+                        type="button"
+                        class=format!("btn {} btn-primary position-relative", if self.loading_create {"loading"} else {""} )
+                        onclick=self.link.callback(|_| Msg::Create)
+                        disabled=self.loading_create
+                    >
+                        <div class="telkom-label">
+                            {"Create"}
+                        </div>
+                        <div class="telkom-spinner telkom-center">
+                            <div class="spinner-border spinner-border-sm" role="status"/>
+                        </div>
+                    </button>
+
+                    {
+                        match &self.error_create {
+                            Some(error) => html! {
+                                <div class="alert alert-warning mt-3" role="alert">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    { error.clone() }
+                                </div>
+                            },
+                            None => html! {},
+                        }
+                    }
+                </form>
+            </>
+        }
+    }
+}
@@ -1,21 +1,51 @@
 use yew::prelude::*;
-// use yew_router::components::RouterAnchor;
-// use crate::app::AppRoute;
-
-pub struct CreateSso {}
+use yew_router::components::RouterAnchor;
+use router::AppRoute;
+use types::sso::{
+    SsoCatalog,
+    SsoCatalogItem,
+};
+
+pub struct CreateSso {
+    link: ComponentLink<Self>,
+    catalog: SsoCatalog,
+    categories: Vec<String>,
+    search: String,
+    category: Option<String>,
+}
 
-pub enum Msg {}
+pub enum Msg {
+    InputSearch(String),
+    SelectCategory(Option<String>),
+}
 
 impl Component for CreateSso {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
-        CreateSso {}
+    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let catalog = SsoCatalog::load();
+        let categories = catalog.categories();
+        CreateSso {
+            link,
+            catalog,
+            categories,
+            search: String::from(""),
+            category: None,
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        true
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::InputSearch(search) => {
+                self.search = search;
+                true
+            }
+            Msg::SelectCategory(category) => {
+                self.category = category;
+                true
+            }
+        }
     }
 
     fn change(&mut self, _: Self::Properties) -> ShouldRender {
@@ -23,14 +53,21 @@ impl Component for CreateSso {
     }
 
     fn view(&self) -> Html {
-        // type Anchor = RouterAnchor<Route>;
+        type Anchor = RouterAnchor<AppRoute>;
+        let integrations = self.catalog.search(&self.search, &self.category);
         html! {
         <>
             <div class="col py-3">
                 <div>
                     <div class="mx-auto pt-5 pb-5 px-4" style="max-width: 1048px;">
                     <div class="mb-5">
-                        <div class="d-flex flex-row mb-3">
+                        <div>
+                            <Anchor route=AppRoute::SsoHome classes="text-decoration-none text-muted">
+                                <i class="bi bi-arrow-left"></i>
+                                <span>{"Back To Single Sign On"}</span>
+                            </Anchor>
+                        </div>
+                        <div class="d-flex flex-row mb-3 mt-2">
                         <div class="flex-fill fs-3 fw-bold">
                             {"New Single Sign On Integration"}
                         </div>
@@ -38,321 +75,51 @@ impl Component for CreateSso {
 
                     <div class="input-group pt-4">
                         <span class="input-group-text" id="basic-addon1">
-                            <svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" fill="currentColor" 
+                            <svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" fill="currentColor"
                             class="bi bi-search" viewBox="0 0 16 16">
                             <path
                                 d="M11.742 10.344a6.5 6.5 0 1 0-1.397 1.398h-.001c.03.04.062.078.098.115l3.85 3.85a1 1 0 0 0 1.415-1.414l-3.85-3.85a1.007 1.007 0 0 0-.115-.1zM12 6.5a5.5 5.5 0 1 1-11 0 5.5 5.5 0 0 1 11 0z">
                             </path>
                         </svg>
                         </span>
-                        <input type="text" class="form-control" id="ssoSearch" placeholder="Search for SSO Integrations"/>
+                        <input
+                            type="text"
+                            class="form-control"
+                            id="ssoSearch"
+                            placeholder="Search for SSO Integrations"
+                            value=self.search.clone()
+                            oninput=self.link.callback(|data: InputData| Msg::InputSearch(data.value))
+                        />
+                    </div>
+
+                    <div class="pt-3">
+                        <button
+                            type="button"
+                            class=if self.category.is_none() { "btn btn-sm btn-dark rounded-pill me-2 mb-2" } else { "btn btn-sm btn-outline-secondary rounded-pill me-2 mb-2" }
+                            onclick=self.link.callback(|_| Msg::SelectCategory(None))
+                        >
+                            {"All"}
+                        </button>
+                        { for self.categories.iter().map(|category| self.view_category(category)) }
                     </div>
 
                     <div class="py-5">
                         <div>
-                            <div class="row hidden-md-up">
-                                <div class="col-md-4">
-                                <div class="card" 
-                                    style="padding: 24px;
-                                    margin-bottom: 24px;
-                                            min-height: 160px;
-                                            max-height: 160px;">
-                                    <div class="card-block">
-                                    <div>
-                                        <div>
-                                        <div
-                                            style="
-                                            display: flex;
-                                            overflow: hidden;
-                                            position: relative;
-                                            font-size: 1rem;
-                                            align-items: center;
-                                            font-weight: 500;
-                                            text-transform: uppercase;
-                                            padding-bottom: 15px;
-                                            "
-                                        >
-                                            <img
-                                            src="https://cdn.auth0.com/marketplace/catalog/content/assets/creators/microsoft/microsoft-avatar.png"
-                                            class="jss6144 jss6146"
-                                            style="color: #65676e;
-                                            margin: 0px;
-                                            width: 2.5rem;
-                                            height: 2.5rem;
-                                            display: flex;
-                                            overflow: hidden;
-                                            position: relative;
-                                            font-size: 1rem;
-                                            align-items: center;
-                                            font-weight: 500;
-                                            text-transform: uppercase;
-                                            justify-content: center;"
-                                            />
-                                            
-                                            <h5 
-                                            style="padding: 0px 15px;">{"AD RMS"}</h5>
-                                            
-                                        </div>
-                                        </div>
-                                        <div>
-                                            <p
-                                            style=" max-height: calc(3.2em);
-                                            overflow: hidden;
-                                            display: -webkit-box;
-                                            -webkit-line-clamp: 2;
-                                            -webkit-box-orient: vertical;"
-                                            >{"Single sign-on for accounts using Active Directory RMS to secure their resources"}</p>
-                                        </div>
-                                    </div>
-                                    </div>
-                                </div>
-                                </div>
-                                <div class="col-md-4">
-                                <div class="card" style="padding: 24px;
-                                    margin-bottom: 24px;
-                                            min-height: 160px;
-                                            max-height: 160px;">
-                                    <div class="card-block">
-                                    <div>
-                                        <div>
-                                        <div style="
-                                            display: flex;
-                                            overflow: hidden;
-                                            position: relative;
-                                            font-size: 1rem;
-                                            align-items: center;
-                                            font-weight: 500;
-                                            text-transform: uppercase;
-                                            padding-bottom: 15px;
-                                            ">
-                                            <img
-                                            src="https://cdn.auth0.com/marketplace/catalog/content/assets/creators/adobe/adobe-avatar.png"
-                                            class="jss6144 jss6146" style="color: #65676e;
-                                            margin: 0px;
-                                            width: 2.5rem;
-                                            height: 2.5rem;
-                                            display: flex;
-                                            overflow: hidden;
-                                            position: relative;
-                                            font-size: 1rem;
-                                            align-items: center;
-                                            font-weight: 500;
-                                            text-transform: uppercase;
-                                            justify-content: center;" />
-
-                                            <h5 style="padding: 0px 15px;">{"Adobe Sign"}</h5>
-
-                                        </div>
-                                        </div>
-                                        <div>
-                                        <p style=" max-height: calc(3.2em);
-                                            overflow: hidden;
-                                            display: -webkit-box;
-                                            -webkit-line-clamp: 2;
-                                            -webkit-box-orient: vertical;">{"SSO for adobe Sign."}</p>
-                                        </div>
-                                    </div>
-                                    </div>
-                                </div>
-                                </div>
-                                <div class="col-md-4">
-                                <div class="card" style="padding: 24px;
-                                    margin-bottom: 24px;
-                                            min-height: 160px;
-                                            max-height: 160px;">
-                                    <div class="card-block">
-                                    <div>
-                                        <div>
-                                        <div style="
-                                            display: flex;
-                                            overflow: hidden;
-                                            position: relative;
-                                            font-size: 1rem;
-                                            align-items: center;
-                                            font-weight: 500;
-                                            text-transform: uppercase;
-                                            padding-bottom: 15px;
-                                            ">
-                                            <img
-                                            src="https://cdn.auth0.com/marketplace/catalog/content/assets/creators/aha/aha-avatar.png"
-                                            class="jss6144 jss6146" style="color: #65676e;
-                                            margin: 0px;
-                                            width: 2.5rem;
-                                            height: 2.5rem;
-                                            display: flex;
-                                            overflow: hidden;
-                                            position: relative;
-                                            font-size: 1rem;
-                                            align-items: center;
-                                            font-weight: 500;
-                                            text-transform: uppercase;
-                                            justify-content: center;" />
-
-                                            <h5 style="padding: 0px 15px;">{"Aha!"}</h5>
-
-                                        </div>
-                                        </div>
-                                        <div>
-                                        <p style=" max-height: calc(3.2em);
-                                            overflow: hidden;
-                                            display: -webkit-box;
-                                            -webkit-line-clamp: 2;
-                                            -webkit-box-orient: vertical;">{"The World's #1 Roadmap Software"}</p>
-                                        </div>
-                                    </div>
-                                    </div>
-                                </div>
-                                </div>
-                                <div class="col-md-4">
-                                <div class="card" style="padding: 24px;
-                                    margin-bottom: 24px;
-                                            min-height: 160px;
-                                            max-height: 160px;">
-                                    <div class="card-block">
-                                    <div>
-                                        <div>
-                                        <div style="
-                                            display: flex;
-                                            overflow: hidden;
-                                            position: relative;
-                                            font-size: 1rem;
-                                            align-items: center;
-                                            font-weight: 500;
-                                            text-transform: uppercase;
-                                            padding-bottom: 15px;
-                                            ">
-                                            <img
-                                            src="https://cdn.auth0.com/marketplace/catalog/content/assets/creators/airbrake/airbrake-avatar.png"
-                                            class="jss6144 jss6146" style="color: #65676e;
-                                            margin: 0px;
-                                            width: 2.5rem;
-                                            height: 2.5rem;
-                                            display: flex;
-                                            overflow: hidden;
-                                            position: relative;
-                                            font-size: 1rem;
-                                            align-items: center;
-                                            font-weight: 500;
-                                            text-transform: uppercase;
-                                            justify-content: center;" />
-
-                                            <h5 style="padding: 0px 15px;">{"Air Brake"}</h5>
-
-                                        </div>
-                                        </div>
-                                        <div>
-                                        <p style=" max-height: calc(3.2em);
-                                            overflow: hidden;
-                                            display: -webkit-box;
-                                            -webkit-line-clamp: 2;
-                                            -webkit-box-orient: vertical;">{"Frictionless Error Monitoring and Application
-                                            Performance Management"}</p>
-                                        </div>
-                                    </div>
-                                    </div>
-                                </div>
-                                </div>
-                                <div class="col-md-4">
-                                <div class="card" style="padding: 24px;
-                                    margin-bottom: 24px;
-                                            min-height: 160px;
-                                            max-height: 160px;">
-                                    <div class="card-block">
-                                    <div>
-                                        <div>
-                                        <div style="
-                                            display: flex;
-                                            overflow: hidden;
-                                            position: relative;
-                                            font-size: 1rem;
-                                            align-items: center;
-                                            font-weight: 500;
-                                            text-transform: uppercase;
-                                            padding-bottom: 15px;
-                                            ">
-                                            <img
-                                            src="https://cdn.auth0.com/marketplace/catalog/content/assets/creators/atatus/atatus-avatar.png"
-                                            class="jss6144 jss6146" style="color: #65676e;
-                                            margin: 0px;
-                                            width: 2.5rem;
-                                            height: 2.5rem;
-                                            display: flex;
-                                            overflow: hidden;
-                                            position: relative;
-                                            font-size: 1rem;
-                                            align-items: center;
-                                            font-weight: 500;
-                                            text-transform: uppercase;
-                                            justify-content: center;" />
-
-                                            <h5 style="padding: 0px 15px;">{"Atatus"}</h5>
-
-                                        </div>
-                                        </div>
-                                        <div>
-                                        <p style=" max-height: calc(3.2em);
-                                            overflow: hidden;
-                                            display: -webkit-box;
-                                            -webkit-line-clamp: 2;
-                                            -webkit-box-orient: vertical;">{"Seamlessly monitor your entire software stack"}</p>
-                                        </div>
-                                    </div>
-                                    </div>
-                                </div>
-                                </div>
-                                <div class="col-md-4">
-                                <div class="card" style="padding: 24px;
-                                    margin-bottom: 24px;
-                                            min-height: 160px;
-                                            max-height: 160px;">
-                                    <div class="card-block">
-                                    <div>
-                                        <div>
-                                        <div style="
-                                            display: flex;
-                                            overflow: hidden;
-                                            position: relative;
-                                            font-size: 1rem;
-                                            align-items: center;
-                                            font-weight: 500;
-                                            text-transform: uppercase;
-                                            padding-bottom: 15px;
-                                            ">
-                                            <img
-                                            src="https://cdn.auth0.com/marketplace/catalog/content/assets/creators/blocksedit/blocksedit-avatar.png"
-                                            class="jss6144 jss6146" style="color: #65676e;
-                                            margin: 0px;
-                                            width: 2.5rem;
-                                            height: 2.5rem;
-                                            display: flex;
-                                            overflow: hidden;
-                                            position: relative;
-                                            font-size: 1rem;
-                                            align-items: center;
-                                            font-weight: 500;
-                                            text-transform: uppercase;
-                                            justify-content: center;" />
-
-                                            <h5 style="padding: 0px 15px;">{"Blocks Edit"}</h5>
-
-                                        </div>
-                                        </div>
-                                        <div>
-                                        <p style=" max-height: calc(3.2em);
-                                            overflow: hidden;
-                                            display: -webkit-box;
-                                            -webkit-line-clamp: 2;
-                                            -webkit-box-orient: vertical;">{"Create on-brand email campaigns faster and easier"}</p>
-                                        </div>
-                                    </div>
-                                    </div>
-                                </div>
-                                </div>
-                            </div>
-                        
+                            {
+                                if integrations.is_empty() {
+                                    html! {
+                                        <p class="text-muted text-center">{"No SSO integrations match your search."}</p>
+                                    }
+                                } else {
+                                    html! {
+                                        <div class="row hidden-md-up">
+                                            { for integrations.iter().map(|integration| self.view_card(integration)) }
+                                        </div>
+                                    }
+                                }
+                            }
                         </div>
                     </div>
-
                     </div>
                 </div>
                 </div>
@@ -361,3 +128,92 @@ impl Component for CreateSso {
         }
     }
 }
+
+impl CreateSso {
+    fn view_category(&self, category: &str) -> Html {
+        let is_selected = self.category.as_deref() == Some(category);
+        let selected = category.to_string();
+        html! {
+            <button
+                type="button"
+                class=if is_selected { "btn btn-sm btn-dark rounded-pill me-2 mb-2" } else { "btn btn-sm btn-outline-secondary rounded-pill me-2 mb-2" }
+                onclick=self.link.callback(move |_| Msg::SelectCategory(Some(selected.clone())))
+            >
+                { category }
+            </button>
+        }
+    }
+
+    fn view_card(&self, integration: &SsoCatalogItem) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        html! {
+            <div class="col-md-4">
+                <Anchor
+                    route=AppRoute::ConfigureSso { integration_id: integration.id.clone() }
+                    classes="text-decoration-none text-reset"
+                >
+                    <div class="card"
+                        style="padding: 24px;
+                            margin-bottom: 24px;
+                            min-height: 160px;
+                            max-height: 160px;"
+                    >
+                        <div class="card-block">
+                            <div
+                                style="
+                                display: flex;
+                                overflow: hidden;
+                                position: relative;
+                                font-size: 1rem;
+                                align-items: center;
+                                font-weight: 500;
+                                text-transform: uppercase;
+                                padding-bottom: 15px;
+                                "
+                            >
+                                { view_logo(integration) }
+                                <h5 style="padding: 0px 15px;">{ integration.name.clone() }</h5>
+                            </div>
+                            <div>
+                                <p
+                                    style=" max-height: calc(3.2em);
+                                    overflow: hidden;
+                                    display: -webkit-box;
+                                    -webkit-line-clamp: 2;
+                                    -webkit-box-orient: vertical;"
+                                >{ integration.description.clone() }</p>
+                            </div>
+                        </div>
+                    </div>
+                </Anchor>
+            </div>
+        }
+    }
+}
+
+pub fn view_logo(integration: &SsoCatalogItem) -> Html {
+    let style = "color: #65676e;
+        margin: 0px;
+        width: 2.5rem;
+        height: 2.5rem;
+        min-width: 2.5rem;
+        display: flex;
+        overflow: hidden;
+        position: relative;
+        font-size: 1rem;
+        align-items: center;
+        font-weight: 500;
+        text-transform: uppercase;
+        justify-content: center;";
+    if integration.logo.is_empty() {
+        html! {
+            <div class="rounded-circle bg-light border" style=style>
+                { integration.name.chars().next().unwrap_or_default() }
+            </div>
+        }
+    } else {
+        html! {
+            <img src=integration.logo.clone() style=style />
+        }
+    }
+}
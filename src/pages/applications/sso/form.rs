@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+use yew::prelude::*;
+use types::sso::SsoField;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct SsoSchemaFormProps {
+    pub schema: Vec<SsoField>,
+    pub settings: BTreeMap<String, String>,
+    #[prop_or_default]
+    pub errors: BTreeMap<String, String>,
+    #[prop_or_default]
+    pub disabled: bool,
+    pub on_change: Callback<(String, String)>,
+}
+
+pub struct SsoSchemaForm {
+    props: SsoSchemaFormProps,
+}
+
+impl Component for SsoSchemaForm {
+    type Message = ();
+    type Properties = SsoSchemaFormProps;
+
+    fn create(props: Self::Properties, _: ComponentLink<Self>) -> Self {
+        SsoSchemaForm {
+            props,
+        }
+    }
+
+    fn update(&mut self, _: Self::Message) -> ShouldRender {
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                { for self.props.schema.iter().map(|field| self.view_field(field)) }
+            </>
+        }
+    }
+}
+
+impl SsoSchemaForm {
+    fn view_field(&self, field: &SsoField) -> Html {
+        let value = self.props.settings.get(&field.key).cloned().unwrap_or_default();
+        let error = self.props.errors.get(&field.key).cloned();
+        let input_class = if error.is_some() { "is-invalid" } else { "" };
+        let id = format!("sso-{}", field.key);
+        let key = field.key.clone();
+        let on_change = self.props.on_change.clone();
+
+        let input = match field.field_type.as_str() {
+            "select" => html! {
+                <select
+                    id=id.clone()
+                    class=classes!("form-select", input_class)
+                    disabled=self.props.disabled
+                    onchange=Callback::from(move |e| {
+                        if let ChangeData::Select(select) = e {
+                            on_change.emit((key.clone(), select.value()));
+                        }
+                    })
+                >
+                    {
+                        for field.options.iter().map(|option| html! {
+                            <option value=option.clone() selected={ *option == value }>{ option.clone() }</option>
+                        })
+                    }
+                </select>
+            },
+            "checkbox" => {
+                let checked = value == "true";
+                html! {
+                    <div class="form-check form-switch">
+                        <input
+                            id=id.clone()
+                            class="form-check-input"
+                            type="checkbox"
+                            checked=checked
+                            disabled=self.props.disabled
+                            onclick=Callback::from(move |_| on_change.emit((key.clone(), (!checked).to_string())))
+                        />
+                    </div>
+                }
+            }
+            "textarea" => html! {
+                <textarea
+                    id=id.clone()
+                    class=classes!("form-control", input_class)
+                    rows="4"
+                    value=value
+                    disabled=self.props.disabled
+                    oninput=Callback::from(move |data: InputData| on_change.emit((key.clone(), data.value)))
+                />
+            },
+            field_type => html! {
+                <input
+                    id=id.clone()
+                    type=field_type.to_string()
+                    class=classes!("form-control", input_class)
+                    value=value
+                    disabled=self.props.disabled
+                    oninput=Callback::from(move |data: InputData| on_change.emit((key.clone(), data.value)))
+                />
+            },
+        };
+
+        html! {
+            <div class="mb-4">
+                <label for=id class="form-label fw-bold">
+                    { field.label.clone() }
+                    {
+                        if field.required {
+                            html! { <span class="text-danger">{" *"}</span> }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </label>
+                { input }
+                {
+                    match error {
+                        Some(error) => html! { <div class="invalid-feedback d-block">{ error }</div> },
+                        None => html! {},
+                    }
+                }
+                <div class="form-text">{ field.description.clone() }</div>
+            </div>
+        }
+    }
+}
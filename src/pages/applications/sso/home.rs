@@ -1,22 +1,123 @@
-use yew::prelude::*;
+use yew::{
+    prelude::*,
+    format::{ Json, Nothing },
+    services::{
+        ConsoleService,
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{ Area, StorageService },
+    },
+};
 use yew_router::components::RouterAnchor;
-// use crate::app::AppRoute;
 use router::AppRoute;
+use configs::server::API_URL;
+use types::{
+    sso::{
+        SsoCatalog,
+        SsoIntegration,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use loading::Loading;
+use super::{
+    create_sso::view_logo,
+};
 
-pub struct SsoHome {}
+pub struct SsoHome {
+    access_token: String,
+    link: ComponentLink<Self>,
+    catalog: SsoCatalog,
+    fetch_task: Option<FetchTask>,
+    sso_integrations: Vec<SsoIntegration>,
+    loading_request: bool,
+    error_request: Option<String>,
+}
 
-pub enum Msg {}
+pub enum Msg {
+    RequestSsoIntegrations,
+    GetSsoIntegrations(Vec<SsoIntegration>),
+    ResponseError(String),
+}
 
 impl Component for SsoHome {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
-        SsoHome {}
+    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        SsoHome {
+            access_token,
+            link,
+            catalog: SsoCatalog::load(),
+            fetch_task: None,
+            sso_integrations: Vec::new(),
+            loading_request: false,
+            error_request: None,
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        true
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestSsoIntegrations);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestSsoIntegrations => {
+                let request = Request::get(format!("{}/api/v2/sso-integrations", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<SsoIntegration>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => {
+                                ConsoleService::info(&format!("sso integrations = {:?}", dataok));
+                                Msg::GetSsoIntegrations(dataok)
+                            }
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.loading_request = true;
+                self.error_request = None;
+                true
+            }
+            Msg::GetSsoIntegrations(data) => {
+                self.sso_integrations = data;
+                self.fetch_task = None;
+                self.loading_request = false;
+                true
+            }
+            Msg::ResponseError(message) => {
+                self.fetch_task = None;
+                self.loading_request = false;
+                self.error_request = Some(message);
+                true
+            }
+        }
     }
 
     fn change(&mut self, _: Self::Properties) -> ShouldRender {
@@ -35,55 +136,169 @@ impl Component for SsoHome {
                                     <div class="flex-fill fs-3 fw-bold">
                                         {"Single Sign On"}
                                     </div>
+                                    {
+                                        if self.sso_integrations.is_empty() {
+                                            html! {}
+                                        } else {
+                                            html! {
+                                                <div>
+                                                    <Anchor
+                                                        route=AppRoute::CreateSso
+                                                        classes="btn btn-primary"
+                                                    >
+                                                        <i class="bi bi-plus me-2"></i>
+                                                        {"Create SSO Integration"}
+                                                    </Anchor>
+                                                </div>
+                                            }
+                                        }
+                                    }
                                 </div>
 
-                                <div style="
-                                        display: flex;
-                                        text-align: center;
-                                        align-items: center;
-                                        flex-direction: column;
-                                        margin-top: 60px;
-                                        padding: 40px;
-                                        border-radius: 6px;
-                                        border: 1px solid #e3e4e6;
-                                    "
-                                >
-
-                                    <img width="150" height=""
-                                        src="https://assets-global.website-files.com/60058af53d79fbd8e14841ea/602e971e34a1e12c00b8c9ab_sso.svg"
-                                    />
-                    
-                                    <h4 
-                                        style="padding-top: 20px;"
-                                    > 
-                                        {"You don't have any SSO integrations yet."}
-                                    </h4>
-                                    <div>
-                                        <p>{"SSO enables users to authenticate at Auth0 with one set of credentials to access any number of service provider applications."}</p>
-                                    </div>
-                                    <button
-                                        style=" color: #fff;
-                                                background-color: #635dff;
-                                                box-shadow: none;
-                                                border-radius: 4px;
-                                                padding: 8px 16px;
-                                                margin: 20px"
-                                    >
-                                        <Anchor
-                                            route=AppRoute::CreateSso
-                                            classes="text-decoration-none text-light px-2 link-primary pe-auto"
-                                        >
-                                            {"+ Create SSO Integration"}
-                                        </Anchor>
-                                    </button>
-                                    <a href="https://auth0.com/docs/sso/single-sign-on" target="_blank">{"Learn More"}</a>
-                                </div>
+                                {
+                                    if self.loading_request {
+                                        html! {
+                                            <div
+                                                style="
+                                                    position: relative;
+                                                    margin-top: 8rem;
+                                                "
+                                            >
+                                                <Loading width=45 />
+                                            </div>
+                                        }
+                                    } else if self.error_request.is_some() {
+                                        html! {
+                                            <div class="alert alert-warning mb-5" role="alert">
+                                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                                { self.error_request.clone().unwrap() }
+                                            </div>
+                                        }
+                                    } else if self.sso_integrations.is_empty() {
+                                        self.view_empty()
+                                    } else {
+                                        self.view_sso_integrations()
+                                    }
+                                }
                             </div>
                         </div>
-            
+
                     </div>
                 </div>
             </>
         }
     }
 }
+
+impl SsoHome {
+    fn view_empty(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        html! {
+            <div style="
+                    display: flex;
+                    text-align: center;
+                    align-items: center;
+                    flex-direction: column;
+                    margin-top: 60px;
+                    padding: 40px;
+                    border-radius: 6px;
+                    border: 1px solid #e3e4e6;
+                "
+            >
+
+                <img width="150" height=""
+                    src="https://assets-global.website-files.com/60058af53d79fbd8e14841ea/602e971e34a1e12c00b8c9ab_sso.svg"
+                />
+
+                <h4
+                    style="padding-top: 20px;"
+                >
+                    {"You don't have any SSO integrations yet."}
+                </h4>
+                <div>
+                    <p>{"SSO enables users to authenticate at Auth0 with one set of credentials to access any number of service provider applications."}</p>
+                </div>
+                <button
+                    style=" color: #fff;
+                            background-color: #635dff;
+                            box-shadow: none;
+                            border-radius: 4px;
+                            padding: 8px 16px;
+                            margin: 20px"
+                >
+                    <Anchor
+                        route=AppRoute::CreateSso
+                        classes="text-decoration-none text-light px-2 link-primary pe-auto"
+                    >
+                        {"+ Create SSO Integration"}
+                    </Anchor>
+                </button>
+                <a href="https://auth0.com/docs/sso/single-sign-on" target="_blank">{"Learn More"}</a>
+            </div>
+        }
+    }
+
+    fn view_sso_integrations(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        html! {
+            <table class="table">
+                <thead>
+                    <tr>
+                        <th scope="col">{"Name"}</th>
+                        <th scope="col">{"Type"}</th>
+                        <th scope="col">{"Status"}</th>
+                        <th scope="col">{"Created At"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {
+                        for self.sso_integrations.iter().map(|sso_integration| {
+                            let catalog_item = self.catalog.find(&sso_integration.integration_id);
+                            html! {
+                                <tr>
+                                    <td class="align-middle">
+                                        <div class="d-flex align-items-center">
+                                            {
+                                                match &catalog_item {
+                                                    Some(catalog_item) => view_logo(catalog_item),
+                                                    None => html! {},
+                                                }
+                                            }
+                                            <div class="ms-3">
+                                                <Anchor
+                                                    route=AppRoute::SsoSettings { sso_id: sso_integration.id.clone() }
+                                                    classes="text-decoration-none fw-bold"
+                                                >
+                                                    { sso_integration.name.clone() }
+                                                </Anchor>
+                                                <div class="text-muted" style="font-size: 13px;">
+                                                    {
+                                                        catalog_item
+                                                            .as_ref()
+                                                            .map(|catalog_item| catalog_item.name.clone())
+                                                            .unwrap_or_else(|| sso_integration.integration_id.clone())
+                                                    }
+                                                </div>
+                                            </div>
+                                        </div>
+                                    </td>
+                                    <td class="align-middle">{ self.catalog.protocol_name(&sso_integration.protocol) }</td>
+                                    <td class="align-middle">{ view_status(sso_integration) }</td>
+                                    <td class="align-middle">{ sso_integration.created_at.clone() }</td>
+                                </tr>
+                            }
+                        })
+                    }
+                </tbody>
+            </table>
+        }
+    }
+}
+
+pub fn view_status(sso_integration: &SsoIntegration) -> Html {
+    if sso_integration.is_active() {
+        html! { <span class="badge bg-success">{"Active"}</span> }
+    } else {
+        html! { <span class="badge bg-secondary">{"Inactive"}</span> }
+    }
+}
@@ -0,0 +1,72 @@
+use configs::server::API_URL;
+use types::sso::SsoIntegration;
+
+pub fn login_url(integration: &SsoIntegration) -> String {
+    format!("{}/{}/{}", API_URL, integration.protocol, integration.id)
+}
+
+pub fn metadata_url(integration: &SsoIntegration) -> String {
+    match integration.protocol.as_str() {
+        "wsfed" => format!("{}/wsfed/{}/FederationMetadata/2007-06/FederationMetadata.xml", API_URL, integration.id),
+        _ => format!("{}/samlp/metadata/{}", API_URL, integration.id),
+    }
+}
+
+// IDENTITY PROVIDER METADATA TO BE UPLOADED TO THE SERVICE PROVIDER
+pub fn generate_metadata(integration: &SsoIntegration) -> String {
+    match integration.protocol.as_str() {
+        "wsfed" => generate_wsfed_metadata(integration),
+        _ => generate_saml_metadata(integration),
+    }
+}
+
+fn generate_saml_metadata(integration: &SsoIntegration) -> String {
+    let name_identifier_format = integration.settings
+        .get("name_identifier_format")
+        .cloned()
+        .unwrap_or_else(|| String::from("urn:oasis:names:tc:SAML:1.1:nameid-format:unspecified"));
+    let login_url = escape_xml(&login_url(integration));
+    format!(
+r#"<EntityDescriptor xmlns="urn:oasis:names:tc:SAML:2.0:metadata" entityID="{entity_id}">
+  <IDPSSODescriptor protocolSupportEnumeration="urn:oasis:names:tc:SAML:2.0:protocol" WantAuthnRequestsSigned="false">
+    <SingleLogoutService Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect" Location="{login_url}/logout"/>
+    <SingleLogoutService Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST" Location="{login_url}/logout"/>
+    <NameIDFormat>{name_identifier_format}</NameIDFormat>
+    <SingleSignOnService Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect" Location="{login_url}"/>
+    <SingleSignOnService Binding="urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST" Location="{login_url}"/>
+  </IDPSSODescriptor>
+</EntityDescriptor>"#,
+        entity_id = escape_xml(&metadata_url(integration)),
+        login_url = login_url,
+        name_identifier_format = escape_xml(&name_identifier_format),
+    )
+}
+
+fn generate_wsfed_metadata(integration: &SsoIntegration) -> String {
+    format!(
+r#"<EntityDescriptor xmlns="urn:oasis:names:tc:SAML:2.0:metadata" entityID="{entity_id}">
+  <RoleDescriptor xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns:fed="http://docs.oasis-open.org/wsfed/federation/200706" xsi:type="fed:SecurityTokenServiceType" protocolSupportEnumeration="http://docs.oasis-open.org/wsfed/federation/200706">
+    <fed:TokenTypesOffered>
+      <fed:TokenType Uri="urn:oasis:names:tc:SAML:2.0:assertion"/>
+      <fed:TokenType Uri="urn:oasis:names:tc:SAML:1.0:assertion"/>
+    </fed:TokenTypesOffered>
+    <fed:PassiveRequestorEndpoint>
+      <EndpointReference xmlns="http://www.w3.org/2005/08/addressing">
+        <Address>{login_url}</Address>
+      </EndpointReference>
+    </fed:PassiveRequestorEndpoint>
+  </RoleDescriptor>
+</EntityDescriptor>"#,
+        entity_id = escape_xml(&metadata_url(integration)),
+        login_url = escape_xml(&login_url(integration)),
+    )
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
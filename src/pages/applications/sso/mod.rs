@@ -1,2 +1,6 @@
 pub mod home;
-pub mod create_sso;
\ No newline at end of file
+pub mod create_sso;
+pub mod configure_sso;
+pub mod settings;
+pub mod form;
+pub mod metadata;
@@ -0,0 +1,548 @@
+use std::collections::BTreeMap;
+use yew::{
+    prelude::*,
+    format::{ Json, Nothing },
+    services::{
+        ConsoleService,
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{ Area, StorageService },
+    },
+    agent::Bridged,
+    Bridge,
+};
+use yew_router::{
+    agent::RouteRequest::ChangeRoute,
+    prelude::*,
+};
+use router::AppRoute;
+use configs::server::API_URL;
+use types::{
+    sso::{
+        SsoCatalog,
+        SsoField,
+        SsoIntegration,
+        SsoIntegrationUpdate,
+        validate_settings,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use loading::Loading;
+use super::{
+    create_sso::view_logo,
+    form::SsoSchemaForm,
+    home::view_status,
+    metadata::{
+        generate_metadata,
+        login_url,
+        metadata_url,
+    },
+};
+
+pub enum Content {
+    Settings,
+    Metadata,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct SsoSettingsProps {
+    pub sso_id: String,
+}
+
+pub enum StateError {
+    Request,
+    Update,
+    Delete,
+}
+
+pub struct SsoSettings {
+    access_token: String,
+    link: ComponentLink<Self>,
+    sso_id: String,
+    content: Content,
+    catalog: SsoCatalog,
+    sso_integration: SsoIntegration,
+    schema: Vec<SsoField>,
+    errors: BTreeMap<String, String>,
+    fetch_task: Option<FetchTask>,
+    loading_request: bool,
+    error_request: Option<String>,
+    loading_update: bool,
+    error_update: Option<String>,
+    message_update: Option<String>,
+    loading_delete: bool,
+    error_delete: Option<String>,
+    route_agent: Box<dyn Bridge<RouteAgent>>,
+}
+
+pub enum Msg {
+    ChangeContent(Content),
+    Request,
+    GetSsoIntegration(SsoIntegration),
+    InputName(String),
+    InputSetting((String, String)),
+    ToggleStatus,
+    Update,
+    Delete,
+    RedirectToSsoHome,
+    ResponseError(String, StateError),
+    Ignore,
+}
+
+impl Component for SsoSettings {
+    type Message = Msg;
+    type Properties = SsoSettingsProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        SsoSettings {
+            access_token,
+            link: link.clone(),
+            sso_id: props.sso_id,
+            content: Content::Settings,
+            catalog: SsoCatalog::load(),
+            sso_integration: SsoIntegration::new(),
+            schema: Vec::new(),
+            errors: BTreeMap::new(),
+            fetch_task: None,
+            loading_request: false,
+            error_request: None,
+            loading_update: false,
+            error_update: None,
+            message_update: None,
+            loading_delete: false,
+            error_delete: None,
+            route_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::Request);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ChangeContent(content) => {
+                self.content = content;
+                true
+            }
+            Msg::Request => {
+                let request = Request::get(format!("{}/api/v2/sso-integrations/{}", API_URL, self.sso_id.clone()))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<SsoIntegration, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => {
+                                ConsoleService::info(&format!("sso integration = {:?}", dataok));
+                                Msg::GetSsoIntegration(dataok)
+                            }
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::Request),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.loading_request = true;
+                self.error_request = None;
+                true
+            }
+            Msg::GetSsoIntegration(data) => {
+                self.schema = match self.catalog.find(&data.integration_id) {
+                    Some(catalog_item) => self.catalog.schema(&catalog_item),
+                    None => Vec::new(),
+                };
+                if self.loading_update {
+                    self.message_update = Some(String::from("Settings have been saved"));
+                }
+                self.sso_integration = data;
+                self.fetch_task = None;
+                self.loading_request = false;
+                self.loading_update = false;
+                true
+            }
+            Msg::InputName(name) => {
+                self.sso_integration.name = name;
+                self.message_update = None;
+                true
+            }
+            Msg::InputSetting((key, value)) => {
+                self.sso_integration.settings.insert(key.clone(), value);
+                self.errors.remove(&key);
+                self.message_update = None;
+                true
+            }
+            Msg::ToggleStatus => {
+                self.sso_integration.status = if self.sso_integration.is_active() {
+                    String::from("inactive")
+                } else {
+                    String::from("active")
+                };
+                self.link.send_message(Msg::Update);
+                true
+            }
+            Msg::Update => {
+                self.errors = validate_settings(&self.schema, &self.sso_integration.settings);
+                if self.sso_integration.name.trim().is_empty() {
+                    self.errors.insert(String::from("name"), String::from("Name is required"));
+                }
+                if !self.errors.is_empty() {
+                    return true;
+                }
+
+                let sso_update = SsoIntegrationUpdate {
+                    name: self.sso_integration.name.trim().to_string(),
+                    status: self.sso_integration.status.clone(),
+                    settings: self.sso_integration.settings.clone(),
+                };
+                let request = Request::patch(format!("{}/api/v2/sso-integrations/{}", API_URL, self.sso_id.clone()))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&sso_update))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<SsoIntegration, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetSsoIntegration(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::Update),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.loading_update = true;
+                self.error_update = None;
+                self.message_update = None;
+                true
+            }
+            Msg::Delete => {
+                let request = Request::delete(format!("{}/api/v2/sso-integrations/{}", API_URL, self.sso_id.clone()))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(|response: Response<Json<Result<(), anyhow::Error>>>| {
+                    let (meta, Json(data)) = response.into_parts();
+                    let status_number = meta.status.as_u16();
+
+                    match status_number {
+                        204 => {
+                            Msg::RedirectToSsoHome
+                        }
+                        _ => {
+                            match data {
+                                Ok(_) => {
+                                    Msg::RedirectToSsoHome
+                                }
+                                Err(error) => {
+                                    Msg::ResponseError(error.to_string(), StateError::Delete)
+                                }
+                            }
+                        }
+                    }
+                });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.loading_delete = true;
+                self.error_delete = None;
+                true
+            }
+            Msg::RedirectToSsoHome => {
+                self.fetch_task = None;
+                self.loading_delete = false;
+                self.route_agent.send(ChangeRoute(AppRoute::SsoHome.into()));
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::Request => {
+                        self.loading_request = false;
+                        self.error_request = Some(message);
+                    }
+                    StateError::Update => {
+                        self.loading_update = false;
+                        self.error_update = Some(message);
+                    }
+                    StateError::Delete => {
+                        self.loading_delete = false;
+                        self.error_delete = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => false,
+        }
+    }
+
+    fn change(&mut self, _: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        if self.loading_request {
+            html! {
+                <div
+                    style="
+                        position: relative;
+                        margin-top: 8rem;
+                    "
+                >
+                    <Loading width=45 />
+                </div>
+            }
+        } else if self.error_request.is_some() {
+            html! {
+                <div class="domain-content">
+                    <div class="alert alert-warning mb-5" role="alert">
+                        <i class="bi bi-exclamation-triangle me-2"></i>
+                        { self.error_request.clone().unwrap() }
+                    </div>
+                </div>
+            }
+        } else {
+            self.view_content()
+        }
+    }
+}
+
+impl SsoSettings {
+    fn view_content(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        let catalog_item = self.catalog.find(&self.sso_integration.integration_id);
+        html! {
+            <div class="domain-content">
+                <div>
+                    <Anchor route=AppRoute::SsoHome classes="text-decoration-none text-muted">
+                        <i class="bi bi-arrow-left"></i>
+                        <span>{"Back To Single Sign On"}</span>
+                    </Anchor>
+                </div>
+
+                <div class="d-flex align-items-center mt-3">
+                    {
+                        match &catalog_item {
+                            Some(catalog_item) => view_logo(catalog_item),
+                            None => html! {},
+                        }
+                    }
+                    <div class="ms-3">
+                        <h2 class="title mb-0">{ self.sso_integration.name.clone() }</h2>
+                        <span class="text-muted me-2">{ self.catalog.protocol_name(&self.sso_integration.protocol) }</span>
+                        { view_status(&self.sso_integration) }
+                    </div>
+                </div>
+
+                <div class="mt-4">
+                    <ul class="nav nav-tabs">
+                        <li onclick=self.link.callback(|_| Msg::ChangeContent(Content::Settings)) class="nav-item">
+                            <a class={
+                                    match self.content {
+                                        Content::Settings => "nav-link active",
+                                        _ => "nav-link"
+                                    }
+                                }
+                                aria-current="page"
+                            >{"Settings"}</a>
+                        </li>
+                        <li onclick=self.link.callback(|_| Msg::ChangeContent(Content::Metadata)) class="nav-item">
+                            <a class={
+                                    match self.content {
+                                        Content::Metadata => "nav-link active",
+                                        _ => "nav-link"
+                                    }
+                                }
+                            >{"Metadata"}</a>
+                        </li>
+                    </ul>
+                </div>
+
+                {
+                    match self.content {
+                        Content::Settings => self.view_settings(),
+                        Content::Metadata => self.view_metadata(),
+                    }
+                }
+            </div>
+        }
+    }
+
+    fn view_settings(&self) -> Html {
+        let name_error = self.errors.get("name").cloned();
+        html! {
+            <>
+            <div class="p-4">
+                <form style="max-width: 640px;">
+                    <div class="mb-4">
+                        <label class="form-label fw-bold">{"Status"}</label>
+                        <div class="form-check form-switch">
+                            <input
+                                class="form-check-input"
+                                type="checkbox"
+                                id="sso-status"
+                                checked=self.sso_integration.is_active()
+                                disabled=self.loading_update
+                                onclick=self.link.callback(|_| Msg::ToggleStatus)
+                            />
+                            <label class="form-check-label" for="sso-status">
+                                {"Users can sign in to this integration while it is active."}
+                            </label>
+                        </div>
+                    </div>
+
+                    <div class="mb-4">
+                        <label for="sso-name" class="form-label fw-bold">
+                            {"Name"}
+                            <span class="text-danger">{" *"}</span>
+                        </label>
+                        <input
+                            id="sso-name"
+                            type="text"
+                            class=classes!("form-control", if name_error.is_some() { "is-invalid" } else { "" })
+                            value=self.sso_integration.name.clone()
+                            disabled=self.loading_update
+                            oninput=self.link.callback(|data: InputData| Msg::InputName(data.value))
+                        />
+                        {
+                            match name_error {
+                                Some(error) => html! { <div class="invalid-feedback d-block">{ error }</div> },
+                                None => html! {},
+                            }
+                        }
+                    </div>
+
+                    <SsoSchemaForm
+                        schema=self.schema.clone()
+                        settings=self.sso_integration.settings.clone()
+                        errors=self.errors.clone()
+                        disabled=self.loading_update
+                        on_change=self.link.callback(Msg::InputSetting)
+                    />
+
+                    <button
+                        type="button"
+                        class=format!("btn {} btn-primary position-relative", if self.loading_update {"loading"} else {""} )
+                        onclick=self.link.callback(|_| Msg::Update)
+                        disabled=self.loading_update
+                    >
+                        <div class="telkom-label">
+                            {"Save"}
+                        </div>
+                        <div class="telkom-spinner telkom-center">
+                            <div class="spinner-border spinner-border-sm" role="status"/>
+                        </div>
+                    </button>
+
+                    {
+                        if self.error_update.is_some() {
+                            html! {
+                                <div class="alert alert-warning mt-3" role="alert">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    { self.error_update.clone().unwrap() }
+                                </div>
+                            }
+                        } else if self.message_update.is_some() {
+                            html! {
+                                <div class="alert alert-success mt-3" role="alert">
+                                    { self.message_update.clone().unwrap() }
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </form>
+            </div>
+
+            <div class="mt-2 p-4 pt-0">
+                <p class="fw-bold fs-5">{"Danger Zone"}</p>
+
+                <div class="alert alert-danger" role="alert">
+                    <div class="row">
+                        <div class="col">
+                            <p class="text-danger fw-bold m-0">{"Delete SSO Integration"}</p>
+                            <p class="text-danger m-0">{"Users will no longer be able to sign in to this application. Once confirmed, this operation can't be undone!"}</p>
+                        </div>
+                        <div class="col d-flex justify-content-end">
+                            <button
+                                type="button"
+                                class=format!("btn {} btn-danger position-relative", if self.loading_delete {"loading"} else {""} )
+                                onclick=self.link.callback(|_| Msg::Delete)
+                                disabled=self.loading_delete
+                            >
+                                <div class="telkom-label">
+                                    {"Delete"}
+                                </div>
+                                <div class="telkom-spinner telkom-center">
+                                    <div class="spinner-border spinner-border-sm" role="status"/>
+                                </div>
+                            </button>
+                        </div>
+                    </div>
+                    {
+                        if self.error_delete.is_some() {
+                            html! {
+                                <div class="alert alert-warning mt-3 mb-0" role="alert">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    { self.error_delete.clone().unwrap() }
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </div>
+            </div>
+            </>
+        }
+    }
+
+    fn view_metadata(&self) -> Html {
+        html! {
+            <div class="p-4" style="max-width: 800px;">
+                <div class="mb-4">
+                    <label class="form-label fw-bold">{"Login URL"}</label>
+                    <input type="text" class="form-control" value=login_url(&self.sso_integration) readonly=true />
+                    <div class="form-text">{"Send users to this URL to start an identity provider initiated sign in."}</div>
+                </div>
+                <div class="mb-4">
+                    <label class="form-label fw-bold">{"Metadata URL"}</label>
+                    <input type="text" class="form-control" value=metadata_url(&self.sso_integration) readonly=true />
+                </div>
+                <div class="mb-4">
+                    <label class="form-label fw-bold">{"Identity Provider Metadata"}</label>
+                    <textarea
+                        class="form-control font-monospace"
+                        style="font-size: 12px;"
+                        rows="16"
+                        readonly=true
+                        value=generate_metadata(&self.sso_integration)
+                    />
+                    <div class="form-text">{"Upload this metadata to the service provider to complete the integration."}</div>
+                </div>
+            </div>
+        }
+    }
+}
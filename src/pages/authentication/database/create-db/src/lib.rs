@@ -1,27 +1,175 @@
-use yew::prelude::*;
-use yew_router::components::RouterAnchor;
+use yew::{
+    prelude::*,
+    format::Json,
+    services::{
+        ConsoleService,
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{ Area, StorageService },
+    },
+    agent::Bridged,
+    Bridge,
+};
+use yew_router::{
+    agent::RouteRequest::ChangeRoute,
+    components::RouterAnchor,
+    prelude::*,
+};
 // use crate::app::AppRoute;
 use router::AppRoute;
+use configs::server::API_URL;
+use types::{
+    database::{
+        DatabaseConnection,
+        DatabaseConnectionCreate,
+        validate_connection_name,
+        validate_username_length,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
 
+pub enum Data {
+    Name,
+    UsernameMin,
+    UsernameMax,
+}
 
 pub struct DbCreate {
-    // link: ComponentLink<Self>
+    access_token: String,
+    link: ComponentLink<Self>,
+    fetch_task: Option<FetchTask>,
+    new_connection: DatabaseConnectionCreate,
+    loading_create_connection: bool,
+    error_create_connection: Option<String>,
+    route_agent: Box<dyn Bridge<RouteAgent>>,
 }
 
-pub enum Msg {}
+pub enum Msg {
+    Input(String, Data),
+    ToggleRequiresUsername,
+    ToggleDisableSignup,
+    CreateConnection,
+    RedirectToSettings(DatabaseConnection),
+    ResponseError(String),
+    Ignore,
+}
 
 impl Component for DbCreate {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_: Self::Properties, _link: ComponentLink<Self>) -> Self {
+    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
         DbCreate {
-            // link
+            access_token,
+            fetch_task: None,
+            new_connection: DatabaseConnectionCreate::new(),
+            loading_create_connection: false,
+            error_create_connection: None,
+            route_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            link,
         }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        true
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(value, data) => {
+                match data {
+                    Data::Name => {
+                        self.new_connection.name = value;
+                        return false;
+                    }
+                    Data::UsernameMin => {
+                        if let Ok(min) = value.parse::<u32>() {
+                            self.new_connection.options.validation.username.min = min;
+                        }
+                    }
+                    Data::UsernameMax => {
+                        if let Ok(max) = value.parse::<u32>() {
+                            self.new_connection.options.validation.username.max = max;
+                        }
+                    }
+                }
+                true
+            }
+            Msg::ToggleRequiresUsername => {
+                self.new_connection.options.requires_username = !self.new_connection.options.requires_username;
+                true
+            }
+            Msg::ToggleDisableSignup => {
+                self.new_connection.options.disable_signup = !self.new_connection.options.disable_signup;
+                true
+            }
+            Msg::CreateConnection => {
+                // VALIDATION
+                if let Err(message) = validate_connection_name(&self.new_connection.name) {
+                    self.link.send_message(Msg::ResponseError(message));
+                    return false;
+                }
+                if self.new_connection.options.requires_username {
+                    if let Err(message) = validate_username_length(&self.new_connection.options.validation.username) {
+                        self.link.send_message(Msg::ResponseError(message));
+                        return false;
+                    }
+                }
+
+                let request = Request::post(format!("{}/api/v2/connections", API_URL))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&self.new_connection))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<DatabaseConnection, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => {
+                                ConsoleService::info(&format!("{:?}", dataok));
+                                Msg::RedirectToSettings(dataok)
+                            }
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_create_connection = None;
+                self.loading_create_connection = true;
+                true
+            }
+            Msg::RedirectToSettings(connection) => {
+                self.loading_create_connection = false;
+                self.fetch_task = None;
+                self.route_agent.send(ChangeRoute(AppRoute::DatabaseSettings { connection_id: connection.id }.into()));
+                true
+            }
+            Msg::ResponseError(message) => {
+                self.loading_create_connection = false;
+                self.error_create_connection = Some(message);
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => {
+                false
+            }
+        }
     }
 
     fn change(&mut self, _: Self::Properties) -> ShouldRender {
@@ -30,6 +178,7 @@ impl Component for DbCreate {
 
     fn view(&self) -> Html {
         type Anchor = RouterAnchor<AppRoute>;
+        let options = self.new_connection.options.clone();
         html! {
             <div class="py-5 px-4 m-auto" style="max-width: 1048px; font-size:14px;">
                 <Anchor route=AppRoute::DatabaseHome classes="text-decoration-none domain-link-dark">
@@ -50,8 +199,13 @@ impl Component for DbCreate {
                                         {"Name"}
                                     </p>
                                     <div class="input-group mb-2">
-                                        <input type="text" class="form-control bg-input-grey"
-                                            aria-label="Dollar amount (with dot and two decimal places)" placeholder="Connection name" />
+                                        <input
+                                            type="text"
+                                            class="form-control bg-input-grey"
+                                            placeholder="Connection name"
+                                            disabled={ self.loading_create_connection }
+                                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::Name))
+                                        />
                                     </div>
                                     <p class="text-color-disabled">
                                         {"Must start and end with an alphanumeric character and can only contain alphanumeric characters and
@@ -79,7 +233,13 @@ impl Component for DbCreate {
                                                     </div>
                                                 </div>
                                                 <div class="form-check form-switch fs-4">
-                                                    <input class="form-check-input" type="checkbox" id="flexSwitchCheckDefault" />
+                                                    <input
+                                                        class="form-check-input"
+                                                        type="checkbox"
+                                                        id="createRequiresUsername"
+                                                        checked=options.requires_username
+                                                        onclick=self.link.callback(|_| Msg::ToggleRequiresUsername)
+                                                    />
                                                 </div>
                                             </div>
                                             <div class="d-flex list-hover justify-content-between align-items-center">
@@ -111,7 +271,15 @@ impl Component for DbCreate {
                                                                         ">
                                                                     {"Min"}
                                                                     </span>
-                                                                    <input type="number" class="form-control" min="1" value="1" width="50px"/>
+                                                                    <input
+                                                                        type="number"
+                                                                        class="form-control"
+                                                                        min="1"
+                                                                        value=options.validation.username.min.to_string()
+                                                                        width="50px"
+                                                                        disabled={ !options.requires_username }
+                                                                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::UsernameMin))
+                                                                    />
                                                                 </div>
                                                                 <div class="d-grid m-2">
                                                                     <span class="fw-bold m-2" style="
@@ -119,7 +287,15 @@ impl Component for DbCreate {
                                                                         ">
                                                                     {"Max"}
                                                                     </span>
-                                                                    <input type="number" class="form-control" min="1" value="15" width="50px"/>
+                                                                    <input
+                                                                        type="number"
+                                                                        class="form-control"
+                                                                        min="1"
+                                                                        value=options.validation.username.max.to_string()
+                                                                        width="50px"
+                                                                        disabled={ !options.requires_username }
+                                                                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::UsernameMax))
+                                                                    />
                                                                 </div>
                                                             </div>
                                                         </div>
@@ -151,7 +327,13 @@ impl Component for DbCreate {
                                                         </div>
                                                     </div>
                                                     <div class="form-check form-switch fs-4">
-                                                        <input class="form-check-input" type="checkbox" id="flexSwitchCheckDefault" />
+                                                        <input
+                                                            class="form-check-input"
+                                                            type="checkbox"
+                                                            id="createDisableSignup"
+                                                            checked=options.disable_signup
+                                                            onclick=self.link.callback(|_| Msg::ToggleDisableSignup)
+                                                        />
                                                     </div>
                                                 </div>
                                             </div>
@@ -159,13 +341,35 @@ impl Component for DbCreate {
                                         <div class="card db-input mb-1 mt-4 mx-0 border-0">
                                             <div class="card-body-db-input p-2 px-4 ">
                                                 <div class="d-flex list-hover justify-content-start align-items-center">
-                                                    <div class="btn btn-primary d-flex align-items-center mx-2">
-                                                    <span>{"Create"}</span>
-                                                    </div>
-                                                    <div class="btn btn-secondary d-flex align-items-center mx-2">
-                                                    <span>{"Cancel"}</span>
-                                                    </div>
+                                                    <button
+                                                        type="button"
+                                                        class=format!("btn {} btn-primary position-relative d-flex align-items-center mx-2", if self.loading_create_connection {"loading"} else {""} )
+                                                        onclick=self.link.callback(|_| Msg::CreateConnection)
+                                                        disabled={ self.loading_create_connection }
+                                                    >
+                                                        <div class="telkom-label">
+                                                            {"Create"}
+                                                        </div>
+                                                        <div class="telkom-spinner telkom-center">
+                                                            <div class="spinner-border spinner-border-sm" role="status"/>
+                                                        </div>
+                                                    </button>
+                                                    <Anchor route=AppRoute::DatabaseHome classes="btn btn-secondary d-flex align-items-center mx-2">
+                                                        <span>{"Cancel"}</span>
+                                                    </Anchor>
                                                 </div>
+                                                {
+                                                    if self.error_create_connection.is_some() {
+                                                        html! {
+                                                            <div class="alert alert-warning mt-3 mx-2" role="alert">
+                                                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                                                { self.error_create_connection.clone().unwrap() }
+                                                            </div>
+                                                        }
+                                                    } else {
+                                                        html! {}
+                                                    }
+                                                }
                                             </div>
                                         </div>
                                     </div>
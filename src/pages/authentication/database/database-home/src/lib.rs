@@ -1,15 +1,41 @@
-use yew::prelude::*;
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+    },
+};
 use yew_router::components::RouterAnchor;
 use router::AppRoute;
+use types::{
+    database::{DatabaseConnection, DATABASE_STRATEGY},
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use configs::server::API_URL;
+use loading::Loading;
+
+pub enum StateError {
+    RequestConnections,
+}
 
 pub struct DatabaseHome {
     learn_more: bool,
+    access_token: String,
+    connections: Vec<DatabaseConnection>,
     link: ComponentLink<Self>,
+    fetch_task: Option<FetchTask>,
+    loading_request_connections: bool,
+    error_request_connections: Option<String>,
 }
 
 pub enum Msg {
     LearnMore,
-    HideDetails
+    HideDetails,
+    RequestConnections,
+    GetConnections(Vec<DatabaseConnection>),
+    ResponseError(String, StateError),
 }
 
 impl Component for DatabaseHome {
@@ -17,9 +43,39 @@ impl Component for DatabaseHome {
     type Properties = ();
 
     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
         DatabaseHome {
             learn_more: false,
+            access_token,
+            connections: vec![],
             link,
+            fetch_task: None,
+            loading_request_connections: false,
+            error_request_connections: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestConnections);
         }
     }
 
@@ -33,6 +89,44 @@ impl Component for DatabaseHome {
                 self.learn_more = false;
                 true
             }
+            Msg::RequestConnections => {
+                let request = Request::get(format!("{}/api/v2/connections?strategy={}", API_URL, DATABASE_STRATEGY))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<DatabaseConnection>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetConnections(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::RequestConnections)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_connections = None;
+                self.loading_request_connections = true;
+                true
+            }
+            Msg::GetConnections(data) => {
+                self.connections = data;
+                self.fetch_task = None;
+                self.loading_request_connections = false;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::RequestConnections => {
+                        self.loading_request_connections = false;
+                        self.error_request_connections = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
         }
     }
 
@@ -194,91 +288,142 @@ impl Component for DatabaseHome {
                     }
                 </div>
 
-                     <div>
+                {
+                    if self.loading_request_connections {
+                        html! {
+                            <div
+                                style="
+                                    position: relative;
+                                    margin-top: 8rem;
+                                "
+                            >
+                                <Loading width=45 />
+                            </div>
+                        }
+                    } else if self.error_request_connections.is_some() {
+                        html! {
+                            <div class="alert alert-warning mb-5" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_request_connections.clone().unwrap() }
+                            </div>
+                        }
+                    } else if self.connections.is_empty() {
+                        html! {
+                            <div class="mt-5 text-center text-muted">
+                                <i class="bi bi-server fs-1"></i>
+                                <p class="mt-3">{"You don't have any database connections yet."}</p>
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            <div>
+                                { self.view_list() }
+                            </div>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+}
 
+impl DatabaseHome {
+    fn view_list(&self) -> Vec<Html> {
+        type Anchor = RouterAnchor<AppRoute>;
+        self.connections.iter().map(|connection| {
+            let DatabaseConnection {
+                id,
+                name,
+                strategy: _,
+                enabled_clients,
+                options: _,
+            } = connection.clone();
+            html! {
+                <div
+                    class="d-flex border-bottom border-1 list-hover"
+                >
                     <div
-                        class="d-flex border-bottom border-1 list-hover"
+                        class="p-3 d-flex"
+                        style="width: 40%;"
                     >
                         <div
-                            class="p-3 d-flex"
-                            style="width: 40%;"
+                            style="flex: 0 0 auto; width: 40px; height: 40px;"
+                            class="d-flex justify-content-center align-items-center rounded me-3 border"
                         >
-                            <div
-                                style="flex: 0 0 auto; width: 40px; height: 40px;"
-                                class="d-flex justify-content-center align-items-center rounded me-3 border"
-                            >
-                                <i class="bi bi-files"></i>
-                            </div>
+                            <i class="bi bi-files"></i>
+                        </div>
 
-                            <div
-                                class="d-grid"
-                                style="min-width: 40px;"
+                        <div
+                            class="d-grid"
+                            style="min-width: 40px;"
+                        >
+                            <Anchor
+                                route=AppRoute::DatabaseSettings { connection_id: id.clone() }
+                                classes="text-decoration-none fw-bold mb-0"
                             >
-                                <Anchor
-                                    route=AppRoute::DatabaseSettings
-                                    classes="text-decoration-none fw-bold mb-0"
-                                >
-                                    <span
-                                        style="
-                                            white-space: nowrap;
-                                            text-overflow: ellipsis;
-                                            overflow: hidden;
-                                            font-size: 14px;
-                                            text-decoration: none;
-                                        "
-                                    >
-                                        {"Username-Password-Authentication"}
-                                    </span>
-                                </Anchor>
-                                <p
-                                    class="mb-0 text-muted"
+                                <span
                                     style="
                                         white-space: nowrap;
                                         text-overflow: ellipsis;
                                         overflow: hidden;
                                         font-size: 14px;
+                                        text-decoration: none;
                                     "
                                 >
-                                    {"Database"}
-                                </p>
-                            </div>
-            
-                        </div>
-
-                        <div
-                            class="p-3 d-flex flex-fill align-items-center text-muted"
-                        >
-                            
-                        </div>
-
-                        <div
-                            class="p-3 d-flex align-items-center dropdown"
-                        >
-                            <button
-                                type="button"
-                                style="flex: 0 0 auto; width: 30px; height: 30px;"
-                                class="btn d-flex justify-content-center align-items-center rounded border"
-                                role="button"
-                                id="dropdownMenuButton1"
-                                data-bs-toggle="dropdown"
-                                aria-expanded="false"
+                                    { name.clone() }
+                                </span>
+                            </Anchor>
+                            <p
+                                class="mb-0 text-muted"
+                                style="
+                                    white-space: nowrap;
+                                    text-overflow: ellipsis;
+                                    overflow: hidden;
+                                    font-size: 14px;
+                                "
                             >
-                                <i class="bi bi-three-dots"></i>
-                            </button>
-                            <ul class="dropdown-menu" aria-labelledby="dropdownMenuButton1">
-                                <li>
-                                    <Anchor route=AppRoute::DatabaseSettings classes="dropdown-item fs-7">
-                                        {"Settings"}
-                                    </Anchor>
-                                </li>
-                            </ul>
+                                {"Database"}
+                            </p>
                         </div>
+                    </div>
 
+                    <div
+                        class="p-3 d-flex flex-fill align-items-center text-muted"
+                        style="font-size: 14px;"
+                    >
+                        {
+                            match enabled_clients.len() {
+                                0 => String::from("No applications enabled"),
+                                1 => String::from("1 application enabled"),
+                                count => format!("{} applications enabled", count),
+                            }
+                        }
                     </div>
 
+                    <div
+                        class="p-3 d-flex align-items-center dropdown"
+                    >
+                        <button
+                            type="button"
+                            style="flex: 0 0 auto; width: 30px; height: 30px;"
+                            class="btn d-flex justify-content-center align-items-center rounded border"
+                            role="button"
+                            data-bs-toggle="dropdown"
+                            aria-expanded="false"
+                        >
+                            <i class="bi bi-three-dots"></i>
+                        </button>
+                        <ul class="dropdown-menu">
+                            <li>
+                                <Anchor route=AppRoute::DatabaseSettings { connection_id: id.clone() } classes="dropdown-item fs-7">
+                                    {"Settings"}
+                                </Anchor>
+                            </li>
+                        </ul>
+                    </div>
                 </div>
-                
-            </div>
-        }
+            }
+        })
+        .collect()
     }
 }
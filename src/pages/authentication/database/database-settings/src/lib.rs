@@ -1,32 +1,98 @@
-use yew::prelude::*;
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+        ConsoleService,
+    },
+};
 use yew_router::components::RouterAnchor;
 // use crate::app::AppRoute;
 use router::AppRoute;
+use types::{
+    database::DatabaseConnection,
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use configs::server::API_URL;
+use loading::Loading;
 use database_tab_applications::ApplicationsTab;
+use database_tab_custom_db::CustomDbTab;
 use database_tab_settings::TabSettings;
 
 pub enum Content {
     Settings,
+    CustomDatabase,
     Applications
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct DatabaseSettingsProps {
+    pub connection_id: String,
+}
+
+pub enum StateError {
+    RequestConnection,
+}
+
 pub struct DatabaseSettings {
+    access_token: String,
     content: Content,
-    link: ComponentLink<Self>
+    connection_id: String,
+    connection: DatabaseConnection,
+    link: ComponentLink<Self>,
+    fetch_task: Option<FetchTask>,
+    loading_request_connection: bool,
+    error_request_connection: Option<String>,
 }
 
 pub enum Msg {
-    ChangeContent(Content)
+    ChangeContent(Content),
+    RequestConnection,
+    GetConnection(DatabaseConnection),
+    ResponseError(String, StateError),
 }
 
 impl Component for DatabaseSettings {
     type Message = Msg;
-    type Properties = ();
+    type Properties = DatabaseSettingsProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
 
-    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
         DatabaseSettings {
+            access_token,
             content: Content::Settings,
-            link
+            connection_id: props.connection_id,
+            connection: DatabaseConnection::new(),
+            link,
+            fetch_task: None,
+            loading_request_connection: false,
+            error_request_connection: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestConnection);
         }
     }
 
@@ -36,6 +102,50 @@ impl Component for DatabaseSettings {
                 self.content = content;
                 true
             }
+            Msg::RequestConnection => {
+                let request = Request::get(format!("{}/api/v2/connections/{}", API_URL, self.connection_id.clone()))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<DatabaseConnection, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => {
+                                ConsoleService::info(&format!("connection details = {:?}", dataok));
+                                Msg::GetConnection(dataok)
+                            }
+                            Err(error) => Msg::ResponseError(
+                                error.to_string(),
+                                StateError::RequestConnection,
+                            ),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_connection = None;
+                self.loading_request_connection = true;
+                true
+            }
+            Msg::GetConnection(data) => {
+                // ALSO RECEIVES THE SAVED CONNECTION FROM THE TABS
+                // SO SWITCHING TABS DOESN'T SHOW STALE SETTINGS
+                self.connection = data;
+                self.fetch_task = None;
+                self.loading_request_connection = false;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::RequestConnection => {
+                        self.loading_request_connection = false;
+                        self.error_request_connection = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
         }
     }
 
@@ -44,6 +154,34 @@ impl Component for DatabaseSettings {
     }
 
     fn view(&self) -> Html {
+        if self.loading_request_connection {
+            html! {
+                <div
+                    style="
+                        position: relative;
+                        margin-top: 8rem;
+                    "
+                >
+                    <Loading width=45 />
+                </div>
+            }
+        } else if self.error_request_connection.is_some() {
+            html! {
+                <div class="domain-content">
+                    <div class="alert alert-warning mb-5" role="alert">
+                        <i class="bi bi-exclamation-triangle me-2"></i>
+                        { self.error_request_connection.clone().unwrap() }
+                    </div>
+                </div>
+            }
+        } else {
+            self.view_content()
+        }
+    }
+}
+
+impl DatabaseSettings {
+    fn view_content(&self) -> Html {
         type Anchor = RouterAnchor<AppRoute>;
         html! {
             <div
@@ -75,7 +213,7 @@ impl Component for DatabaseSettings {
                     >
                         <h2
                             class="title"
-                        >{"Username-Password-Authentication"}</h2>
+                        >{ self.connection.name.clone() }</h2>
                         <div
                             class="text-muted"
                         >
@@ -99,7 +237,7 @@ impl Component for DatabaseSettings {
                                     font-family: 'Roboto Mono', monospace;
                                 "
                             >
-                                {"con_qgZPycWvQ4BBRzcY"}
+                                { self.connection.id.clone() }
                             </span>
                         </div>
                     </div>
@@ -126,6 +264,18 @@ impl Component for DatabaseSettings {
                         >
                             {"Settings"}</a>
                         </li>
+                        <li
+                            onclick=self.link.callback(|_| Msg::ChangeContent(Content::CustomDatabase))
+                            class="nav-item">
+                        <a
+                            class={
+                                match self.content {
+                                    Content::CustomDatabase => "nav-link active",
+                                    _ => "nav-link"
+                                }
+                            }
+                            href="#">{"Custom Database"}</a>
+                        </li>
                         <li
                             onclick=self.link.callback(|_| Msg::ChangeContent(Content::Applications))
                             class="nav-item">
@@ -142,13 +292,26 @@ impl Component for DatabaseSettings {
                     </ul>
                 </div>
 
-                // <Quickstart/>
-                // <TabSettings/>
-
                 {
                     match self.content {
-                        Content::Applications => html! { <ApplicationsTab/> },
-                        Content::Settings => html! { <TabSettings/> }
+                        Content::Applications => html! {
+                            <ApplicationsTab
+                                connection=self.connection.clone()
+                                on_update=self.link.callback(Msg::GetConnection)
+                            />
+                        },
+                        Content::CustomDatabase => html! {
+                            <CustomDbTab
+                                connection=self.connection.clone()
+                                on_update=self.link.callback(Msg::GetConnection)
+                            />
+                        },
+                        Content::Settings => html! {
+                            <TabSettings
+                                connection=self.connection.clone()
+                                on_update=self.link.callback(Msg::GetConnection)
+                            />
+                        },
                     }
                 }
             </div>
@@ -1,21 +1,192 @@
-use yew::prelude::*;
-pub struct ApplicationsTab {}
-pub enum Msg {}
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+    },
+};
+use configs::server::API_URL;
+use types::{
+    application::AppList,
+    database::{DatabaseConnection, DatabaseConnectionUpdate},
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use loading::Loading;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ApplicationsTabProps {
+    pub connection: DatabaseConnection,
+    pub on_update: Callback<DatabaseConnection>,
+}
+
+pub enum StateError {
+    RequestApplications,
+    Update,
+}
+
+pub struct ApplicationsTab {
+    access_token: String,
+    link: ComponentLink<Self>,
+    connection: DatabaseConnection,
+    on_update: Callback<DatabaseConnection>,
+    applications: Vec<AppList>,
+    fetch_task: Option<FetchTask>,
+    loading_request_applications: bool,
+    error_request_applications: Option<String>,
+    // CLIENT BEING ENABLED / DISABLED, ONE AT A TIME
+    loading_update: Option<String>,
+    error_update: Option<String>,
+}
+
+pub enum Msg {
+    RequestApplications,
+    GetApplications(Vec<AppList>),
+    ToggleClient(String),
+    GetConnection(DatabaseConnection),
+    ResponseError(String, StateError),
+}
 
 impl Component for ApplicationsTab {
     type Message = Msg;
-    type Properties = ();
+    type Properties = ApplicationsTabProps;
 
-    fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
-        ApplicationsTab {}
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        ApplicationsTab {
+            access_token,
+            link,
+            connection: props.connection,
+            on_update: props.on_update,
+            applications: vec![],
+            fetch_task: None,
+            loading_request_applications: false,
+            error_request_applications: None,
+            loading_update: None,
+            error_update: None,
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        true
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestApplications);
+        }
     }
 
-    fn change(&mut self, _: Self::Properties) -> ShouldRender {
-        false
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestApplications => {
+                let request = Request::get(format!("{}/api/v2/clients", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<AppList>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetApplications(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::RequestApplications)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_applications = None;
+                self.loading_request_applications = true;
+                true
+            }
+            Msg::GetApplications(data) => {
+                self.applications = data;
+                self.fetch_task = None;
+                self.loading_request_applications = false;
+                true
+            }
+            Msg::ToggleClient(client_id) => {
+                if self.loading_update.is_some() {
+                    return false;
+                }
+                let mut data_update_connection = DatabaseConnectionUpdate::from(&self.connection);
+                if data_update_connection.enabled_clients.contains(&client_id) {
+                    data_update_connection.enabled_clients.retain(|id| *id != client_id);
+                } else {
+                    data_update_connection.enabled_clients.push(client_id.clone());
+                }
+
+                let request = Request::patch(format!("{}/api/v2/connections/{}", API_URL, self.connection.id.clone()))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_update_connection))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<DatabaseConnection, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetConnection(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::Update)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_update = None;
+                self.loading_update = Some(client_id);
+                true
+            }
+            Msg::GetConnection(data) => {
+                self.connection = data.clone();
+                self.on_update.emit(data);
+                self.loading_update = None;
+                self.fetch_task = None;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::RequestApplications => {
+                        self.loading_request_applications = false;
+                        self.error_request_applications = Some(message);
+                    }
+                    StateError::Update => {
+                        self.loading_update = None;
+                        self.error_update = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.on_update = props.on_update;
+        if self.connection.id != props.connection.id {
+            self.connection = props.connection;
+            true
+        } else {
+            false
+        }
     }
 
     fn view(&self) -> Html {
@@ -24,200 +195,143 @@ impl Component for ApplicationsTab {
                 <p class="p-2 m-2">
                 {"Applications using this connection."}
                 </p>
-                <div class="card m-2">
-                  <div>
-                    <div class="d-flex border-bottom border-1 list-hover">
-                      <div class="p-3 d-flex" style="width: 40%;">
-                        <div style="flex: 0 0 auto; width: 40px; height: 40px; background-color: #eff0f2;"
-                          class="d-flex justify-content-center align-items-center rounded me-3">
-                          <img src="https://cdn.auth0.com/manhattan/versions/1.3226.0/assets/non_interactive.svg" style=" color: transparent;
-                                                          width: 100%;
-                                                          height: 100%;
-                                                          object-fit: cover;
-                                                          text-align: center;
-                                                          text-indent: 10000px;" />
-                        </div>
-                  
-                        <div class="d-grid" style="min-width: 40px;">
-                          <a class="fw-bold mb-0" style="
-                                                                  white-space: nowrap;
-                                                                  text-overflow: ellipsis;
-                                                                  overflow: hidden;
-                                                                  font-size: 14px;
-                                                                  text-decoration: none;
-                                                              " href="#">
-                            {"Management API (Test Application)"}
-                          </a>
-                          <p class="mb-0 text-muted" style="
-                                                                  white-space: nowrap;
-                                                                  text-overflow: ellipsis;
-                                                                  overflow: hidden;
-                                                                  font-size: 14px;
-                                                              ">
-                            {"Machine to Machine"}
-                          </p>
-                        </div>
-                  
-                      </div>
-                  
-                      <div class="p-3 d-flex flex-fill align-items-center text-muted">
-                      </div>
-                  
-                      <div class="p-3 d-flex align-items-center dropdown">
-                        <div class="form-check form-switch fs-4">
-                          <input class="form-check-input" type="checkbox" id="flexSwitchCheckDefault" />
-                        </div>
-                      </div>
-                  
+                {
+                    if self.error_update.is_some() {
+                        html! {
+                            <div class="alert alert-warning m-2" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_update.clone().unwrap() }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+                {
+                    if self.loading_request_applications {
+                        html! {
+                            <div
+                                style="
+                                    position: relative;
+                                    margin-top: 8rem;
+                                "
+                            >
+                                <Loading width=45 />
+                            </div>
+                        }
+                    } else if self.error_request_applications.is_some() {
+                        html! {
+                            <div class="alert alert-warning m-2" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_request_applications.clone().unwrap() }
+                            </div>
+                        }
+                    } else if self.applications.is_empty() {
+                        html! {
+                            <div class="mt-5 text-center text-muted">
+                                <p>{"You don't have any applications yet."}</p>
+                            </div>
+                        }
+                    } else {
+                        html! { for self.applications.iter().map(|application| self.view_application(application)) }
+                    }
+                }
+            </>
+        }
+    }
+}
+
+impl ApplicationsTab {
+    fn view_application(&self, application: &AppList) -> Html {
+        let AppList {
+            name,
+            client_id,
+            app_type,
+        } = application.clone();
+        let enabled = self.connection.enabled_clients.contains(&client_id);
+        let loading = self.loading_update.as_ref() == Some(&client_id);
+        html! {
+            <div class="card m-2">
+              <div>
+                <div class="d-flex border-bottom border-1 list-hover">
+                  <div class="p-3 d-flex" style="width: 40%;">
+                    <div style="flex: 0 0 auto; width: 40px; height: 40px; background-color: #eff0f2;"
+                      class="d-flex justify-content-center align-items-center rounded me-3">
+                      <img src=app_type_icon(&app_type) style=" color: transparent;
+                                                      width: 100%;
+                                                      height: 100%;
+                                                      object-fit: cover;
+                                                      text-align: center;
+                                                      text-indent: 10000px;" />
                     </div>
-                  </div>
-                </div>
 
-                <div class="card m-2">
-                  <div>
-                    <div class="d-flex border-bottom border-1 list-hover">
-                      <div class="p-3 d-flex" style="width: 40%;">
-                        <div style="flex: 0 0 auto; width: 40px; height: 40px; background-color: #eff0f2;"
-                          class="d-flex justify-content-center align-items-center rounded me-3">
-                          <img src="https://cdn.auth0.com/manhattan/versions/1.3226.0/assets/none.svg" style=" color: transparent;
-                                                          width: 100%;
-                                                          height: 100%;
-                                                          object-fit: cover;
-                                                          text-align: center;
-                                                          text-indent: 10000px;" />
-                        </div>
-                  
-                        <div class="d-grid" style="min-width: 40px;">
-                          <a class="fw-bold mb-0" style="
-                                                                  white-space: nowrap;
-                                                                  text-overflow: ellipsis;
-                                                                  overflow: hidden;
-                                                                  font-size: 14px;
-                                                                  text-decoration: none;
-                                                              " href="#">
-                            {"Default App"}
-                          </a>
-                          <p class="mb-0 text-muted" style="
-                                                                  white-space: nowrap;
-                                                                  text-overflow: ellipsis;
-                                                                  overflow: hidden;
-                                                                  font-size: 14px;
-                                                              ">
-                            {"Generic"}
-                          </p>
-                        </div>
-                  
-                      </div>
-                  
-                      <div class="p-3 d-flex flex-fill align-items-center text-muted">
-                        
-                      </div>
-                  
-                      <div class="p-3 d-flex align-items-center dropdown">
-                        <div class="form-check form-switch fs-4">
-                          <input class="form-check-input" type="checkbox" id="flexSwitchCheckDefault" />
-                        </div>
-                      </div>
-                  
+                    <div class="d-grid" style="min-width: 40px;">
+                      <span class="fw-bold mb-0" style="
+                                                              white-space: nowrap;
+                                                              text-overflow: ellipsis;
+                                                              overflow: hidden;
+                                                              font-size: 14px;
+                                                              text-decoration: none;
+                                                          ">
+                        { name }
+                      </span>
+                      <p class="mb-0 text-muted" style="
+                                                              white-space: nowrap;
+                                                              text-overflow: ellipsis;
+                                                              overflow: hidden;
+                                                              font-size: 14px;
+                                                          ">
+                        { app_type_label(&app_type) }
+                      </p>
                     </div>
                   </div>
-                </div>
 
-                <div class="card m-2">
-                  <div>
-                    <div class="d-flex border-bottom border-1 list-hover">
-                      <div class="p-3 d-flex" style="width: 40%;">
-                        <div style="flex: 0 0 auto; width: 40px; height: 40px; background-color: #eff0f2;"
-                          class="d-flex justify-content-center align-items-center rounded me-3">
-                          <img src="https://cdn.auth0.com/manhattan/versions/1.3226.0/assets/spa.svg" style=" color: transparent;
-                                                          width: 100%;
-                                                          height: 100%;
-                                                          object-fit: cover;
-                                                          text-align: center;
-                                                          text-indent: 10000px;" />
-                        </div>
-                  
-                        <div class="d-grid" style="min-width: 40px;">
-                          <a class="fw-bold mb-0" style="
-                                                                  white-space: nowrap;
-                                                                  text-overflow: ellipsis;
-                                                                  overflow: hidden;
-                                                                  font-size: 14px;
-                                                                  text-decoration: none;
-                                                              " href="#">
-                            {"My App"}
-                          </a>
-                          <p class="mb-0 text-muted" style="
-                                                                  white-space: nowrap;
-                                                                  text-overflow: ellipsis;
-                                                                  overflow: hidden;
-                                                                  font-size: 14px;
-                                                              ">
-                            {"Single Page Application"}
-                          </p>
-                        </div>
-                      </div>
-                  
-                      <div class="p-3 d-flex flex-fill align-items-center text-muted">
-                      </div>
-                  
-                      <div class="p-3 d-flex align-items-center dropdown">
-                        <div class="form-check form-switch fs-4">
-                          <input class="form-check-input" type="checkbox" id="flexSwitchCheckDefault" />
-                        </div>
-                      </div>
-                    </div>
+                  <div class="p-3 d-flex flex-fill align-items-center text-muted">
                   </div>
-                </div>
 
-                <div class="card m-2">
-                  <div>
-                    <div class="d-flex border-bottom border-1 list-hover">
-                      <div class="p-3 d-flex" style="width: 40%;">
-                        <div style="flex: 0 0 auto; width: 40px; height: 40px; background-color: #eff0f2;"
-                          class="d-flex justify-content-center align-items-center rounded me-3">
-                          <img src="https://cdn.auth0.com/manhattan/versions/1.3226.0/assets/non_interactive.svg" style=" color: transparent;
-                                                          width: 100%;
-                                                          height: 100%;
-                                                          object-fit: cover;
-                                                          text-align: center;
-                                                          text-indent: 10000px;" />
-                        </div>
-                  
-                        <div class="d-grid" style="min-width: 40px;">
-                          <a class="fw-bold mb-0" style="
-                                                                  white-space: nowrap;
-                                                                  text-overflow: ellipsis;
-                                                                  overflow: hidden;
-                                                                  font-size: 14px;
-                                                                  text-decoration: none;
-                                                              " href="#">
-                            {"Test (Test Application)"}
-                          </a>
-                          <p class="mb-0 text-muted" style="
-                                                                  white-space: nowrap;
-                                                                  text-overflow: ellipsis;
-                                                                  overflow: hidden;
-                                                                  font-size: 14px;
-                                                              ">
-                            {"Machine To Machine"}
-                          </p>
-                        </div>
-                      </div>
-                  
-                      <div class="p-3 d-flex flex-fill align-items-center text-muted">
-                        
-                      </div>
-                  
-                      <div class="p-3 d-flex align-items-center dropdown">
-                        <div class="form-check form-switch fs-4">
-                          <input class="form-check-input" type="checkbox" id="flexSwitchCheckDefault" />
-                        </div>
-                      </div>
+                  <div class="p-3 d-flex align-items-center dropdown">
+                    {
+                        if loading {
+                            html! {
+                                <div class="spinner-border spinner-border-sm text-muted me-2" role="status"/>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    <div class="form-check form-switch fs-4">
+                      <input
+                        class="form-check-input"
+                        type="checkbox"
+                        checked=enabled
+                        disabled={ self.loading_update.is_some() }
+                        onclick=self.link.callback(move |_| Msg::ToggleClient(client_id.clone()))
+                      />
                     </div>
                   </div>
                 </div>
-            </>
+              </div>
+            </div>
         }
     }
-}
\ No newline at end of file
+}
+
+fn app_type_icon(app_type: &str) -> &'static str {
+    match app_type {
+        "non_interactive" => "https://cdn.auth0.com/manhattan/versions/1.3226.0/assets/non_interactive.svg",
+        "spa" => "https://cdn.auth0.com/manhattan/versions/1.3226.0/assets/spa.svg",
+        "regular_web" => "https://cdn.auth0.com/manhattan/versions/1.3226.0/assets/regular_web.svg",
+        "native" => "https://cdn.auth0.com/manhattan/versions/1.3226.0/assets/native.svg",
+        _ => "https://cdn.auth0.com/manhattan/versions/1.3226.0/assets/none.svg",
+    }
+}
+
+fn app_type_label(app_type: &str) -> &'static str {
+    match app_type {
+        "non_interactive" => "Machine to Machine",
+        "spa" => "Single Page Application",
+        "regular_web" => "Regular Web Application",
+        "native" => "Native",
+        _ => "Generic",
+    }
+}
@@ -0,0 +1,535 @@
+use std::collections::BTreeMap;
+use serde_json::Value;
+use yew::{
+    format::Json,
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+        ConsoleService,
+    },
+};
+use configs::server::API_URL;
+use types::{
+    database::{
+        DatabaseConnection,
+        DatabaseConnectionUpdate,
+        ScriptTry,
+        ScriptTryResult,
+        DATABASE_SCRIPTS,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+
+mod templates;
+
+use templates::{template, STORES};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CustomDbTabProps {
+    pub connection: DatabaseConnection,
+    pub on_update: Callback<DatabaseConnection>,
+}
+
+pub enum StateError {
+    Update,
+    Try,
+}
+
+pub struct CustomDbTab {
+    access_token: String,
+    link: ComponentLink<Self>,
+    connection: DatabaseConnection,
+    on_update: Callback<DatabaseConnection>,
+    selected_script: String,
+    // PARAMETER NAME -> VALUE TYPED IN THE TRY PANEL
+    try_params: BTreeMap<String, String>,
+    try_result: Option<ScriptTryResult>,
+    fetch_task: Option<FetchTask>,
+    try_task: Option<FetchTask>,
+    loading_update: bool,
+    error_update: Option<String>,
+    message_update: Option<String>,
+    loading_try: bool,
+    error_try: Option<String>,
+}
+
+pub enum Msg {
+    ToggleCustomization,
+    SelectScript(String),
+    InputScript(String),
+    LoadTemplate(String),
+    InputParam(String, String),
+    Update,
+    GetConnection(DatabaseConnection),
+    Try,
+    GetTryResult(ScriptTryResult),
+    ResponseError(String, StateError),
+}
+
+impl Component for CustomDbTab {
+    type Message = Msg;
+    type Properties = CustomDbTabProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        let (first_script, _, _) = DATABASE_SCRIPTS[0];
+
+        CustomDbTab {
+            access_token,
+            link,
+            connection: props.connection,
+            on_update: props.on_update,
+            selected_script: String::from(first_script),
+            try_params: BTreeMap::new(),
+            try_result: None,
+            fetch_task: None,
+            try_task: None,
+            loading_update: false,
+            error_update: None,
+            message_update: None,
+            loading_try: false,
+            error_try: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ToggleCustomization => {
+                let options = &mut self.connection.options;
+                options.enabled_database_customization = !options.enabled_database_customization;
+                self.message_update = None;
+                true
+            }
+            Msg::SelectScript(name) => {
+                self.selected_script = name;
+                self.try_params = BTreeMap::new();
+                self.try_result = None;
+                self.error_try = None;
+                true
+            }
+            Msg::InputScript(script) => {
+                self.connection.options.custom_scripts.insert(self.selected_script.clone(), script);
+                self.message_update = None;
+                false
+            }
+            Msg::LoadTemplate(store) => {
+                if let Some(script) = template(&store, &self.selected_script) {
+                    self.connection.options.custom_scripts.insert(self.selected_script.clone(), String::from(script));
+                    self.message_update = None;
+                }
+                true
+            }
+            Msg::InputParam(name, value) => {
+                self.try_params.insert(name, value);
+                false
+            }
+            Msg::Update => {
+                // VALIDATION
+                // LOGIN IS THE ONLY SCRIPT EVERY CUSTOM DATABASE NEEDS
+                let login_script = self.connection.options.custom_scripts.get("login").cloned().unwrap_or_default();
+                if self.connection.options.enabled_database_customization && login_script.trim().is_empty() {
+                    self.link.send_message(Msg::ResponseError(
+                        String::from("The Login script is required to use your own database"),
+                        StateError::Update,
+                    ));
+                    return false;
+                }
+
+                let data_update_connection = DatabaseConnectionUpdate::from(&self.connection);
+                let request = Request::patch(format!("{}/api/v2/connections/{}", API_URL, self.connection.id.clone()))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_update_connection))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<DatabaseConnection, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetConnection(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::Update)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_update = None;
+                self.message_update = None;
+                self.loading_update = true;
+                true
+            }
+            Msg::GetConnection(data) => {
+                self.connection = data.clone();
+                self.on_update.emit(data);
+                self.loading_update = false;
+                self.message_update = Some(String::from("Scripts have been saved"));
+                self.fetch_task = None;
+                true
+            }
+            Msg::Try => {
+                let script = self.connection.options.custom_scripts.get(&self.selected_script).cloned().unwrap_or_default();
+                if script.trim().is_empty() {
+                    self.link.send_message(Msg::ResponseError(String::from("Write a script or load a template first"), StateError::Try));
+                    return false;
+                }
+                let params = match self.script_params() {
+                    Ok(params) => params,
+                    Err(message) => {
+                        self.link.send_message(Msg::ResponseError(message, StateError::Try));
+                        return false;
+                    }
+                };
+
+                // RUNS THE UNSAVED SCRIPT, SO CHANGES CAN BE TRIED BEFORE SAVING
+                let data_try = ScriptTry {
+                    script,
+                    params,
+                };
+                let request = Request::post(format!(
+                    "{}/api/v2/connections/{}/scripts/{}/try",
+                    API_URL,
+                    self.connection.id.clone(),
+                    self.selected_script.clone()
+                ))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_try))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<ScriptTryResult, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetTryResult(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::Try)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.try_task = Some(task);
+                self.try_result = None;
+                self.error_try = None;
+                self.loading_try = true;
+                true
+            }
+            Msg::GetTryResult(data) => {
+                ConsoleService::info(&format!("try result = {:?}", data));
+                self.try_result = Some(data);
+                self.loading_try = false;
+                self.try_task = None;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::Update => {
+                        self.loading_update = false;
+                        self.error_update = Some(message);
+                        self.fetch_task = None;
+                    }
+                    StateError::Try => {
+                        self.loading_try = false;
+                        self.error_try = Some(message);
+                        self.try_task = None;
+                    }
+                }
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.on_update = props.on_update;
+        if self.connection.id != props.connection.id {
+            self.connection = props.connection;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let enabled = self.connection.options.enabled_database_customization;
+        html! {
+            <>
+                <p class="p-2 m-2">
+                {"Use your own database to store users. Domain runs the scripts below, in a Node 12 runtime, to authenticate and manage users in your legacy store."}
+                </p>
+
+                <div class="card p-2 m-4">
+                <div class="d-flex border-1 list-hover justify-content-between align-items-center">
+                    <div class="p-4 d-flex" style="width: 60%;">
+                    <div class="d-grid" style="min-width: 40px;">
+                        <span class="fw-bold" style="font-size: 14px;">
+                        {"Use my own database"}
+                        </span>
+                        <p class="mb-0 text-muted" style="font-size: 14px;">
+                        {"Enable to authenticate users against your own store. Users will not be stored in the Domain database unless Import Users is enabled."}
+                        </p>
+                    </div>
+                    </div>
+                    <div class="form-check form-switch fs-4">
+                    <input
+                        class="form-check-input"
+                        type="checkbox"
+                        id="customDatabaseEnabled"
+                        checked=enabled
+                        disabled={ self.loading_update }
+                        onclick=self.link.callback(|_| Msg::ToggleCustomization)
+                    />
+                    </div>
+                </div>
+                </div>
+
+                {
+                    if enabled {
+                        self.view_scripts()
+                    } else {
+                        html! {}
+                    }
+                }
+
+                <div class="p-2 m-4">
+                    <button
+                        type="button"
+                        class=format!("btn {} btn-primary position-relative", if self.loading_update {"loading"} else {""} )
+                        onclick=self.link.callback(|_| Msg::Update)
+                        disabled={ self.loading_update }
+                    >
+                        <div class="telkom-label">
+                            {"Save"}
+                        </div>
+                        <div class="telkom-spinner telkom-center">
+                            <div class="spinner-border spinner-border-sm" role="status"/>
+                        </div>
+                    </button>
+
+                    {
+                        if self.error_update.is_some() {
+                            html! {
+                                <div class="alert alert-warning mt-3" role="alert">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    { self.error_update.clone().unwrap() }
+                                </div>
+                            }
+                        } else if self.message_update.is_some() {
+                            html! {
+                                <div class="alert alert-success mt-3" role="alert">
+                                    { self.message_update.clone().unwrap() }
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </div>
+            </>
+        }
+    }
+}
+
+impl CustomDbTab {
+    fn view_scripts(&self) -> Html {
+        let script = self.connection.options.custom_scripts.get(&self.selected_script).cloned().unwrap_or_default();
+        html! {
+            <div class="card p-2 m-4">
+                <ul class="nav nav-pills p-2">
+                    {
+                        for DATABASE_SCRIPTS.iter().map(|(name, label, _)| {
+                            let name = name.to_string();
+                            let active = self.selected_script == name;
+                            html! {
+                                <li class="nav-item">
+                                    <a
+                                        class={ if active { "nav-link active" } else { "nav-link" } }
+                                        href="javascript: void(0)"
+                                        onclick=self.link.callback(move |_| Msg::SelectScript(name.clone()))
+                                    >
+                                        { *label }
+                                    </a>
+                                </li>
+                            }
+                        })
+                    }
+                </ul>
+
+                <div class="d-flex justify-content-end align-items-center px-2 pb-2">
+                    <span class="text-muted me-2" style="font-size: 14px;">{"Load template"}</span>
+                    <select
+                        class="form-select form-select-sm"
+                        style="width: 160px;"
+                        onchange=self.link.callback(|e| {
+                            if let ChangeData::Select(select) = e {
+                                let value = select.value();
+                                select.set_value("");
+                                Msg::LoadTemplate(value)
+                            } else {
+                                Msg::LoadTemplate(String::from(""))
+                            }
+                        })
+                    >
+                        <option value="" selected=true>{"Select"}</option>
+                        {
+                            for STORES.iter().map(|(id, label)| html! {
+                                <option value=id.to_string()>{ *label }</option>
+                            })
+                        }
+                    </select>
+                </div>
+
+                <div class="px-2">
+                    <textarea
+                        class="form-control"
+                        rows="20"
+                        spellcheck="false"
+                        placeholder="Load a template for your legacy store, or write your own script."
+                        style="font-family: 'Roboto Mono', monospace; font-size: 13px; background-color: #272822; color: #f8f8f2;"
+                        value=script
+                        oninput=self.link.callback(|data: InputData| Msg::InputScript(data.value))
+                    />
+                </div>
+
+                { self.view_try() }
+            </div>
+        }
+    }
+
+    fn view_try(&self) -> Html {
+        let params = self.param_names();
+        html! {
+            <div class="p-2 mt-2">
+                <p class="fw-bold mb-2" style="font-size: 14px;">{"Try"}</p>
+                {
+                    for params.iter().map(|param| {
+                        let name = param.to_string();
+                        let placeholder = if *param == "user" {
+                            "{ \"email\": \"jane@example.com\", \"password\": \"Passw0rd!\" }"
+                        } else {
+                            ""
+                        };
+                        html! {
+                            <div class="mb-2">
+                                <label class="form-label mb-1" style="font-size: 13px; font-family: 'Roboto Mono', monospace;">{ *param }</label>
+                                <input
+                                    type="text"
+                                    class="form-control form-control-sm"
+                                    placeholder=placeholder
+                                    value=self.try_params.get(*param).cloned().unwrap_or_default()
+                                    oninput=self.link.callback(move |data: InputData| Msg::InputParam(name.clone(), data.value))
+                                />
+                            </div>
+                        }
+                    })
+                }
+                <button
+                    type="button"
+                    class=format!("btn {} btn-outline-primary position-relative", if self.loading_try {"loading"} else {""} )
+                    onclick=self.link.callback(|_| Msg::Try)
+                    disabled={ self.loading_try }
+                >
+                    <div class="telkom-label">
+                        {"Try"}
+                    </div>
+                    <div class="telkom-spinner telkom-center">
+                        <div class="spinner-border spinner-border-sm" role="status"/>
+                    </div>
+                </button>
+                { self.view_try_result() }
+            </div>
+        }
+    }
+
+    fn view_try_result(&self) -> Html {
+        if let Some(error) = &self.error_try {
+            return html! {
+                <div class="alert alert-warning mt-3" role="alert">
+                    <i class="bi bi-exclamation-triangle me-2"></i>
+                    { error }
+                </div>
+            };
+        }
+        match &self.try_result {
+            Some(result) => html! {
+                <div class="mt-3">
+                    {
+                        match &result.error {
+                            Some(error) => html! {
+                                <div class="alert alert-danger" role="alert">
+                                    <i class="bi bi-x-circle me-2"></i>
+                                    { error }
+                                </div>
+                            },
+                            None => html! {
+                                <pre class="border rounded p-3 mb-2" style="background-color: #eff0f2; font-size: 13px;">
+                                    { serde_json::to_string_pretty(&result.result).unwrap_or_default() }
+                                </pre>
+                            },
+                        }
+                    }
+                    {
+                        if result.logs.is_empty() {
+                            html! {}
+                        } else {
+                            html! {
+                                <>
+                                    <p class="text-muted mb-1" style="font-size: 13px;">{"Console output"}</p>
+                                    <pre class="border rounded p-3" style="font-size: 13px;">
+                                        { result.logs.join("\n") }
+                                    </pre>
+                                </>
+                            }
+                        }
+                    }
+                </div>
+            },
+            None => html! {},
+        }
+    }
+
+    fn param_names(&self) -> Vec<&'static str> {
+        DATABASE_SCRIPTS
+            .iter()
+            .find(|(name, _, _)| *name == self.selected_script)
+            .map(|(_, _, params)| params.to_vec())
+            .unwrap_or_default()
+    }
+
+    // "user" IS AN OBJECT, EVERY OTHER PARAMETER IS SENT AS A STRING
+    fn script_params(&self) -> Result<BTreeMap<String, Value>, String> {
+        let mut params = BTreeMap::new();
+        for param in self.param_names() {
+            let value = self.try_params.get(param).cloned().unwrap_or_default();
+            let value = if param == "user" {
+                match serde_json::from_str::<Value>(&value) {
+                    Ok(Value::Object(user)) => Value::Object(user),
+                    _ => return Err(String::from("user must be a JSON object, for example { \"email\": \"jane@example.com\", \"password\": \"Passw0rd!\" }")),
+                }
+            } else {
+                Value::String(value)
+            };
+            params.insert(param.to_string(), value);
+        }
+        Ok(params)
+    }
+}
@@ -0,0 +1,31 @@
+// LEGACY STORES WITH A TEMPLATE FOR EVERY SCRIPT IN DATABASE_SCRIPTS, (id, label)
+pub const STORES: [(&str, &str); 3] = [
+    ("postgres", "PostgreSQL"),
+    ("mysql", "MySQL"),
+    ("rest", "REST API"),
+];
+
+pub fn template(store: &str, script: &str) -> Option<&'static str> {
+    let template = match (store, script) {
+        ("postgres", "login") => include_str!("templates/postgres/login.js"),
+        ("postgres", "create") => include_str!("templates/postgres/create.js"),
+        ("postgres", "verify") => include_str!("templates/postgres/verify.js"),
+        ("postgres", "change_password") => include_str!("templates/postgres/change_password.js"),
+        ("postgres", "get_user") => include_str!("templates/postgres/get_user.js"),
+        ("postgres", "delete") => include_str!("templates/postgres/delete.js"),
+        ("mysql", "login") => include_str!("templates/mysql/login.js"),
+        ("mysql", "create") => include_str!("templates/mysql/create.js"),
+        ("mysql", "verify") => include_str!("templates/mysql/verify.js"),
+        ("mysql", "change_password") => include_str!("templates/mysql/change_password.js"),
+        ("mysql", "get_user") => include_str!("templates/mysql/get_user.js"),
+        ("mysql", "delete") => include_str!("templates/mysql/delete.js"),
+        ("rest", "login") => include_str!("templates/rest/login.js"),
+        ("rest", "create") => include_str!("templates/rest/create.js"),
+        ("rest", "verify") => include_str!("templates/rest/verify.js"),
+        ("rest", "change_password") => include_str!("templates/rest/change_password.js"),
+        ("rest", "get_user") => include_str!("templates/rest/get_user.js"),
+        ("rest", "delete") => include_str!("templates/rest/delete.js"),
+        _ => return None,
+    };
+    Some(template)
+}
@@ -0,0 +1,27 @@
+function changePassword(email, newPassword, callback) {
+  // This script should change the password stored for the current user in your
+  // database. It is executed when the user clicks on the confirmation link
+  // after a reset password request.
+  const mysql = require('mysql');
+  const bcrypt = require('bcrypt');
+
+  const connection = mysql({
+    host: configuration.MYSQL_HOST,
+    user: configuration.MYSQL_USER,
+    password: configuration.MYSQL_PASSWORD,
+    database: configuration.MYSQL_DATABASE
+  });
+
+  connection.connect();
+
+  const query = 'UPDATE users SET password = ? WHERE email = ?';
+
+  bcrypt.hash(newPassword, 10, function (err, hash) {
+    if (err) return callback(err);
+
+    connection.query(query, [hash, email], function (err, results) {
+      if (err) return callback(err);
+      callback(null, results.affectedRows > 0);
+    });
+  });
+}
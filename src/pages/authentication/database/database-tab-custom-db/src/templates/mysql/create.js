@@ -0,0 +1,31 @@
+function create(user, callback) {
+  // This script should create a user entry in your existing database. It will
+  // be executed when a user attempts to sign up, or when a user is created
+  // through the dashboard or API.
+  const mysql = require('mysql');
+  const bcrypt = require('bcrypt');
+
+  const connection = mysql({
+    host: configuration.MYSQL_HOST,
+    user: configuration.MYSQL_USER,
+    password: configuration.MYSQL_PASSWORD,
+    database: configuration.MYSQL_DATABASE
+  });
+
+  connection.connect();
+
+  const query = 'INSERT INTO users SET ?';
+
+  bcrypt.hash(user.password, 10, function (err, hash) {
+    if (err) return callback(err);
+
+    const insert = {
+      password: hash,
+      email: user.email
+    };
+
+    connection.query(query, insert, function (err) {
+      callback(err);
+    });
+  });
+}
@@ -0,0 +1,21 @@
+function remove(id, callback) {
+  // This script should remove a user from your existing database. It is
+  // executed whenever a user is deleted from the API or the dashboard.
+  const mysql = require('mysql');
+
+  const connection = mysql({
+    host: configuration.MYSQL_HOST,
+    user: configuration.MYSQL_USER,
+    password: configuration.MYSQL_PASSWORD,
+    database: configuration.MYSQL_DATABASE
+  });
+
+  connection.connect();
+
+  const query = 'DELETE FROM users WHERE id = ?';
+
+  connection.query(query, [id], function (err) {
+    if (err) return callback(err);
+    callback(null);
+  });
+}
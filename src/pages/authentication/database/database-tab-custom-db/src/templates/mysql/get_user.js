@@ -0,0 +1,29 @@
+function getByEmail(email, callback) {
+  // This script should retrieve a user profile from your existing database,
+  // without authenticating the user. It is used to check if a user exists
+  // before executing flows that do not require authentication (signup and
+  // password reset).
+  const mysql = require('mysql');
+
+  const connection = mysql({
+    host: configuration.MYSQL_HOST,
+    user: configuration.MYSQL_USER,
+    password: configuration.MYSQL_PASSWORD,
+    database: configuration.MYSQL_DATABASE
+  });
+
+  connection.connect();
+
+  const query = 'SELECT id, nickname, email FROM users WHERE email = ?';
+
+  connection.query(query, [email], function (err, results) {
+    if (err || results.length === 0) return callback(err || null);
+
+    const user = results[0];
+    callback(null, {
+      user_id: user.id.toString(),
+      nickname: user.nickname,
+      email: user.email
+    });
+  });
+}
@@ -0,0 +1,32 @@
+function login(email, password, callback) {
+  // This script should authenticate a user against the credentials stored in
+  // your database. It is executed when a user attempts to log in.
+  const mysql = require('mysql');
+  const bcrypt = require('bcrypt');
+
+  const connection = mysql({
+    host: configuration.MYSQL_HOST,
+    user: configuration.MYSQL_USER,
+    password: configuration.MYSQL_PASSWORD,
+    database: configuration.MYSQL_DATABASE
+  });
+
+  connection.connect();
+
+  const query = 'SELECT id, nickname, email, password FROM users WHERE email = ?';
+  connection.query(query, [email], function (err, results) {
+    if (err) return callback(err);
+    if (results.length === 0) return callback(new WrongUsernameOrPasswordError(email));
+    const user = results[0];
+
+    bcrypt.compare(password, user.password, function (err, isValid) {
+      if (err || !isValid) return callback(err || new WrongUsernameOrPasswordError(email));
+
+      callback(null, {
+        user_id: user.id.toString(),
+        nickname: user.nickname,
+        email: user.email
+      });
+    });
+  });
+}
@@ -0,0 +1,22 @@
+function verify(email, callback) {
+  // This script should mark the current user's email address as verified in
+  // your database. It is executed when the user clicks the verification link.
+  const mysql = require('mysql');
+
+  const connection = mysql({
+    host: configuration.MYSQL_HOST,
+    user: configuration.MYSQL_USER,
+    password: configuration.MYSQL_PASSWORD,
+    database: configuration.MYSQL_DATABASE
+  });
+
+  connection.connect();
+
+  const query = 'UPDATE users SET email_verified = true WHERE email_verified = false AND email = ?';
+
+  connection.query(query, [email], function (err, results) {
+    if (err) return callback(err);
+
+    callback(null, results.affectedRows > 0);
+  });
+}
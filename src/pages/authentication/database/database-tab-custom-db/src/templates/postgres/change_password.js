@@ -0,0 +1,24 @@
+function changePassword(email, newPassword, callback) {
+  // This script should change the password stored for the current user in your
+  // database. It is executed when the user clicks on the confirmation link
+  // after a reset password request.
+  const bcrypt = require('bcrypt');
+  const postgres = require('pg');
+
+  const conString = configuration.DATABASE_URL;
+  postgres.connect(conString, function (err, client, done) {
+    if (err) return callback(err);
+
+    bcrypt.hash(newPassword, 10, function (err, hash) {
+      if (err) return callback(err);
+
+      const query = 'UPDATE users SET password = $1 WHERE email = $2';
+      client.query(query, [hash, email], function (err, result) {
+        // NOTE: always call `done()` to release the client back to the pool
+        done();
+
+        return callback(err, result && result.rowCount > 0);
+      });
+    });
+  });
+}
@@ -0,0 +1,24 @@
+function create(user, callback) {
+  // This script should create a user entry in your existing database. It will
+  // be executed when a user attempts to sign up, or when a user is created
+  // through the dashboard or API.
+  const bcrypt = require('bcrypt');
+  const postgres = require('pg');
+
+  const conString = configuration.DATABASE_URL;
+  postgres.connect(conString, function (err, client, done) {
+    if (err) return callback(err);
+
+    bcrypt.hash(user.password, 10, function (err, hashedPassword) {
+      if (err) return callback(err);
+
+      const query = 'INSERT INTO users(email, password) VALUES ($1, $2)';
+      client.query(query, [user.email, hashedPassword], function (err, result) {
+        // NOTE: always call `done()` to release the client back to the pool
+        done();
+
+        return callback(err);
+      });
+    });
+  });
+}
@@ -0,0 +1,18 @@
+function remove(id, callback) {
+  // This script should remove a user from your existing database. It is executed
+  // whenever a user is deleted from the API or the dashboard.
+  const postgres = require('pg');
+
+  const conString = configuration.DATABASE_URL;
+  postgres.connect(conString, function (err, client, done) {
+    if (err) return callback(err);
+
+    const query = 'DELETE FROM users WHERE id = $1';
+    client.query(query, [id], function (err) {
+      // NOTE: always call `done()` to release the client back to the pool
+      done();
+
+      return callback(err);
+    });
+  });
+}
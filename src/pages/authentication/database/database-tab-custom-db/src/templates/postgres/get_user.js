@@ -0,0 +1,28 @@
+function getByEmail(email, callback) {
+  // This script should retrieve a user profile from your existing database,
+  // without authenticating the user. It is used to check if a user exists
+  // before executing flows that do not require authentication (signup and
+  // password reset).
+  const postgres = require('pg');
+
+  const conString = configuration.DATABASE_URL;
+  postgres.connect(conString, function (err, client, done) {
+    if (err) return callback(err);
+
+    const query = 'SELECT id, nickname, email FROM users WHERE email = $1';
+    client.query(query, [email], function (err, result) {
+      // NOTE: always call `done()` to release the client back to the pool
+      done();
+
+      if (err || result.rows.length === 0) return callback(err);
+
+      const user = result.rows[0];
+
+      return callback(null, {
+        user_id: user.id,
+        nickname: user.nickname,
+        email: user.email
+      });
+    });
+  });
+}
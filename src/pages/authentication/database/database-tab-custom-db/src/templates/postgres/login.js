@@ -0,0 +1,31 @@
+function login(email, password, callback) {
+  // This script should authenticate a user against the credentials stored in
+  // your database. It is executed when a user attempts to log in.
+  const bcrypt = require('bcrypt');
+  const postgres = require('pg');
+
+  const conString = configuration.DATABASE_URL;
+  postgres.connect(conString, function (err, client, done) {
+    if (err) return callback(err);
+
+    const query = 'SELECT id, nickname, email, password FROM users WHERE email = $1';
+    client.query(query, [email], function (err, result) {
+      // NOTE: always call `done()` to release the client back to the pool
+      done();
+
+      if (err || result.rows.length === 0) return callback(err || new WrongUsernameOrPasswordError(email));
+
+      const user = result.rows[0];
+
+      bcrypt.compare(password, user.password, function (err, isValid) {
+        if (err || !isValid) return callback(err || new WrongUsernameOrPasswordError(email));
+
+        return callback(null, {
+          user_id: user.id,
+          nickname: user.nickname,
+          email: user.email
+        });
+      });
+    });
+  });
+}
@@ -0,0 +1,18 @@
+function verify(email, callback) {
+  // This script should mark the current user's email address as verified in
+  // your database. It is executed when the user clicks the verification link.
+  const postgres = require('pg');
+
+  const conString = configuration.DATABASE_URL;
+  postgres.connect(conString, function (err, client, done) {
+    if (err) return callback(err);
+
+    const query = 'UPDATE users SET email_verified = true WHERE email_verified = false AND email = $1';
+    client.query(query, [email], function (err, result) {
+      // NOTE: always call `done()` to release the client back to the pool
+      done();
+
+      return callback(err, result && result.rowCount > 0);
+    });
+  });
+}
@@ -0,0 +1,18 @@
+function changePassword(email, newPassword, callback) {
+  // This script should change the password stored for the current user in your
+  // user store. It is executed when the user clicks on the confirmation link
+  // after a reset password request.
+  const request = require('request');
+
+  request.put({
+    url: configuration.API_URL + '/users/password',
+    json: {
+      email: email,
+      password: newPassword
+    }
+  }, function (err, response) {
+    if (err) return callback(err);
+
+    callback(null, response.statusCode === 200);
+  });
+}
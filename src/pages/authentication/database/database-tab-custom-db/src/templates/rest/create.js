@@ -0,0 +1,19 @@
+function create(user, callback) {
+  // This script should create a user entry in your user store. It will be
+  // executed when a user attempts to sign up, or when a user is created
+  // through the dashboard or API.
+  const request = require('request');
+
+  request.post({
+    url: configuration.API_URL + '/users',
+    json: {
+      email: user.email,
+      password: user.password
+    }
+  }, function (err, response) {
+    if (err) return callback(err);
+    if (response.statusCode === 409) return callback(new ValidationError('user_exists', 'The user already exists.'));
+
+    callback(null);
+  });
+}
@@ -0,0 +1,11 @@
+function remove(id, callback) {
+  // This script should remove a user from your user store. It is executed
+  // whenever a user is deleted from the API or the dashboard.
+  const request = require('request');
+
+  request.del({
+    url: configuration.API_URL + '/users/' + id
+  }, function (err) {
+    callback(err);
+  });
+}
@@ -0,0 +1,21 @@
+function getByEmail(email, callback) {
+  // This script should retrieve a user profile from your user store, without
+  // authenticating the user. It is used to check if a user exists before
+  // executing flows that do not require authentication (signup and password
+  // reset).
+  const request = require('request');
+
+  request.get({
+    url: configuration.API_URL + '/users?email=' + encodeURIComponent(email),
+    json: true
+  }, function (err, response, body) {
+    if (err) return callback(err);
+    if (response.statusCode === 404) return callback(null);
+
+    callback(null, {
+      user_id: body.id.toString(),
+      nickname: body.nickname,
+      email: body.email
+    });
+  });
+}
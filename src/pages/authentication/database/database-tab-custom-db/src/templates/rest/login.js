@@ -0,0 +1,22 @@
+function login(email, password, callback) {
+  // This script should authenticate a user against the credentials stored in
+  // your user store. It is executed when a user attempts to log in.
+  const request = require('request');
+
+  request.post({
+    url: configuration.API_URL + '/users/authenticate',
+    json: {
+      email: email,
+      password: password
+    }
+  }, function (err, response, body) {
+    if (err) return callback(err);
+    if (response.statusCode === 401) return callback(new WrongUsernameOrPasswordError(email));
+
+    callback(null, {
+      user_id: body.id.toString(),
+      nickname: body.nickname,
+      email: body.email
+    });
+  });
+}
@@ -0,0 +1,16 @@
+function verify(email, callback) {
+  // This script should mark the current user's email address as verified in
+  // your user store. It is executed when the user clicks the verification link.
+  const request = require('request');
+
+  request.post({
+    url: configuration.API_URL + '/users/verify',
+    json: {
+      email: email
+    }
+  }, function (err, response) {
+    if (err) return callback(err);
+
+    callback(null, response.statusCode === 200);
+  });
+}
@@ -0,0 +1,404 @@
+use std::collections::HashSet;
+use serde_json::{Map, Value};
+
+// A SINGLE IMPORT JOB ACCEPTS FILES UP TO 500KB, SPLIT LARGER STORES
+pub const MAX_FILE_BYTES: usize = 500 * 1024;
+
+pub const HASH_ALGORITHMS: [&str; 3] = ["bcrypt", "argon2", "pbkdf2"];
+
+const ALLOWED_FIELDS: [&str; 15] = [
+    "email",
+    "email_verified",
+    "user_id",
+    "username",
+    "given_name",
+    "family_name",
+    "name",
+    "nickname",
+    "picture",
+    "blocked",
+    "password_hash",
+    "custom_password_hash",
+    "app_metadata",
+    "user_metadata",
+    "mfa_factors",
+];
+
+const BOOLEAN_FIELDS: [&str; 2] = ["email_verified", "blocked"];
+const OBJECT_FIELDS: [&str; 2] = ["app_metadata", "user_metadata"];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImportRules {
+    pub requires_username: bool,
+    pub username_min: u32,
+    pub username_max: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordError {
+    // 1-BASED POSITION OF THE RECORD IN THE FILE
+    pub record: usize,
+    pub email: String,
+    pub field: String,
+    pub message: String,
+}
+
+pub fn parse_file(file_name: &str, content: &str) -> Result<Vec<Value>, String> {
+    if content.len() > MAX_FILE_BYTES {
+        return Err(format!("The file is larger than {}KB, split it into several imports", MAX_FILE_BYTES / 1024));
+    }
+    let records = if file_name.to_lowercase().ends_with(".csv") {
+        parse_csv(content)?
+    } else {
+        match serde_json::from_str::<Value>(content) {
+            Ok(Value::Array(records)) => records,
+            Ok(_) => return Err(String::from("The JSON file must contain an array of users")),
+            Err(error) => return Err(format!("Invalid JSON: {}", error)),
+        }
+    };
+    if records.is_empty() {
+        return Err(String::from("The file does not contain any user"));
+    }
+    Ok(records)
+}
+
+// FIRST ROW IS THE HEADER, DOTTED COLUMNS (custom_password_hash.hash.value) BECOME NESTED OBJECTS
+fn parse_csv(content: &str) -> Result<Vec<Value>, String> {
+    let mut rows = csv_rows(content)?.into_iter();
+    let header = rows.next().ok_or_else(|| String::from("The CSV file is empty"))?;
+    let mut records = Vec::new();
+    for row in rows {
+        if row.iter().all(|cell| cell.is_empty()) {
+            continue;
+        }
+        let mut record = Map::new();
+        for (column, cell) in header.iter().zip(row) {
+            if cell.is_empty() {
+                continue;
+            }
+            let column = column.trim();
+            let value = if BOOLEAN_FIELDS.contains(&column) {
+                match cell.to_lowercase().as_str() {
+                    "true" | "1" => Value::Bool(true),
+                    "false" | "0" => Value::Bool(false),
+                    _ => Value::String(cell),
+                }
+            } else if OBJECT_FIELDS.contains(&column) {
+                serde_json::from_str(&cell).unwrap_or(Value::String(cell))
+            } else {
+                Value::String(cell)
+            };
+            insert_path(&mut record, column, value);
+        }
+        records.push(Value::Object(record));
+    }
+    Ok(records)
+}
+
+fn insert_path(record: &mut Map<String, Value>, path: &str, value: Value) {
+    match path.split_once('.') {
+        Some((key, rest)) => {
+            let child = record
+                .entry(key.to_string())
+                .or_insert_with(|| Value::Object(Map::new()));
+            if let Value::Object(child) = child {
+                insert_path(child, rest, value);
+            }
+        }
+        None => {
+            record.insert(path.to_string(), value);
+        }
+    }
+}
+
+fn csv_rows(content: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = content.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => quoted = false,
+                _ => cell.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if cell.is_empty() => quoted = true,
+            ',' => row.push(std::mem::take(&mut cell)),
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut cell));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => cell.push(c),
+        }
+    }
+    if quoted {
+        return Err(String::from("The CSV file has an unterminated quoted value"));
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+pub fn validate(records: &[Value], rules: &ImportRules) -> Vec<RecordError> {
+    let mut errors = Vec::new();
+    let mut emails = HashSet::new();
+    for (index, record) in records.iter().enumerate() {
+        let email = record.get("email").and_then(Value::as_str).unwrap_or_default().to_string();
+        let mut error = |field: &str, message: String| {
+            errors.push(RecordError {
+                record: index + 1,
+                email: email.clone(),
+                field: field.to_string(),
+                message,
+            });
+        };
+        let object = match record.as_object() {
+            Some(object) => object,
+            None => {
+                error("", String::from("Every user must be an object"));
+                continue;
+            }
+        };
+
+        for key in object.keys() {
+            if !ALLOWED_FIELDS.contains(&key.as_str()) {
+                error(key, String::from("Unknown field"));
+            }
+        }
+
+        if email.is_empty() {
+            error("email", String::from("Email is required"));
+        } else if !is_email(&email) {
+            error("email", String::from("Invalid email address"));
+        } else if !emails.insert(email.to_lowercase()) {
+            error("email", String::from("Duplicate email in this file"));
+        }
+
+        match object.get("username") {
+            Some(Value::String(username)) => {
+                let length = username.chars().count() as u32;
+                if length < rules.username_min || length > rules.username_max {
+                    error(
+                        "username",
+                        format!("Username must have between {} and {} characters", rules.username_min, rules.username_max),
+                    );
+                }
+                if !username.chars().all(is_username_char) {
+                    error("username", String::from("Username contains characters that are not allowed"));
+                }
+            }
+            Some(_) => error("username", String::from("Username must be a string")),
+            None if rules.requires_username => error("username", String::from("This connection requires a username")),
+            None => {}
+        }
+
+        for field in BOOLEAN_FIELDS.iter() {
+            if let Some(value) = object.get(*field) {
+                if !value.is_boolean() {
+                    error(field, String::from("Must be true or false"));
+                }
+            }
+        }
+        for field in OBJECT_FIELDS.iter() {
+            if let Some(value) = object.get(*field) {
+                if !value.is_object() {
+                    error(field, String::from("Must be a JSON object"));
+                }
+            }
+        }
+
+        match (object.get("password_hash"), object.get("custom_password_hash")) {
+            (Some(_), Some(_)) => error(
+                "password_hash",
+                String::from("Use either password_hash or custom_password_hash, not both"),
+            ),
+            (Some(hash), None) => match hash.as_str() {
+                Some(hash) if is_bcrypt(hash) => {}
+                _ => error("password_hash", String::from("password_hash must be a bcrypt hash ($2a$, $2b$ or $2y$)")),
+            },
+            (None, Some(custom)) => {
+                if let Err(message) = validate_custom_hash(custom) {
+                    error("custom_password_hash", message);
+                }
+            }
+            (None, None) => {}
+        }
+    }
+    errors
+}
+
+fn validate_custom_hash(custom: &Value) -> Result<(), String> {
+    let algorithm = custom
+        .get("algorithm")
+        .and_then(Value::as_str)
+        .ok_or_else(|| String::from("algorithm is required"))?;
+    let hash = custom
+        .get("hash")
+        .and_then(|hash| hash.get("value"))
+        .and_then(Value::as_str)
+        .ok_or_else(|| String::from("hash.value is required"))?;
+    let valid = match algorithm {
+        "bcrypt" => is_bcrypt(hash),
+        "argon2" => is_argon2(hash),
+        "pbkdf2" => is_pbkdf2(hash),
+        _ => {
+            return Err(format!(
+                "Unsupported algorithm {}, use one of {}",
+                algorithm,
+                HASH_ALGORITHMS.join(", ")
+            ))
+        }
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("hash.value is not a valid {} hash", algorithm))
+    }
+}
+
+fn is_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && domain.contains('.')
+                && !email.contains(char::is_whitespace)
+                && !domain.contains('@')
+        }
+        None => false,
+    }
+}
+
+fn is_username_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "_'.-+!#^`~@".contains(c)
+}
+
+// $2b$10$ + 53 CHARACTERS OF SALT AND HASH
+fn is_bcrypt(hash: &str) -> bool {
+    hash.len() == 60
+        && hash.is_ascii()
+        && (hash.starts_with("$2a$") || hash.starts_with("$2b$") || hash.starts_with("$2y$"))
+        && hash[4..6].chars().all(|c| c.is_ascii_digit())
+        && &hash[6..7] == "$"
+}
+
+// PHC STRING FORMAT: $argon2id$v=19$m=65536,t=2,p=1$<salt>$<hash>
+fn is_argon2(hash: &str) -> bool {
+    let parts: Vec<&str> = hash.split('$').collect();
+    parts.len() >= 5
+        && parts[0].is_empty()
+        && ["argon2i", "argon2d", "argon2id"].contains(&parts[1])
+        && !parts[parts.len() - 1].is_empty()
+        && !parts[parts.len() - 2].is_empty()
+}
+
+// PHC STRING FORMAT: $pbkdf2-sha512$i=100000,l=64$<salt>$<hash>
+fn is_pbkdf2(hash: &str) -> bool {
+    let parts: Vec<&str> = hash.split('$').collect();
+    parts.len() == 5
+        && parts[0].is_empty()
+        && ["pbkdf2-sha1", "pbkdf2-sha256", "pbkdf2-sha512", "pbkdf2-md4", "pbkdf2-md5"].contains(&parts[1])
+        && parts[2].split(',').all(|param| param.starts_with("i=") || param.starts_with("l="))
+        && !parts[3].is_empty()
+        && !parts[4].is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const BCRYPT: &str = "$2b$10$nRa7bKZ9Tc2yU6fQ3Q1ZVu0y8s1v3p8pQ0v2o7cR0cS4a1b2c3d4e";
+
+    fn rules(requires_username: bool) -> ImportRules {
+        ImportRules {
+            requires_username,
+            username_min: 1,
+            username_max: 15,
+        }
+    }
+
+    fn fields(errors: &[RecordError]) -> Vec<(usize, &str)> {
+        errors.iter().map(|error| (error.record, error.field.as_str())).collect()
+    }
+
+    #[test]
+    fn csv_rows_become_nested_typed_records() {
+        let content = "\u{feff}email,email_verified,user_metadata,custom_password_hash.algorithm,name\r\n\
+            jane@acme.com,TRUE,\"{\"\"plan\"\":\"\"pro\"\"}\",argon2,\"Doe, Jane\"\r\n\
+            ,,,,\r\n\
+            john@acme.com,0,,,\n";
+        let records = parse_file("users.CSV", content).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[0],
+            json!({
+                "email": "jane@acme.com",
+                "email_verified": true,
+                "user_metadata": { "plan": "pro" },
+                "custom_password_hash": { "algorithm": "argon2" },
+                "name": "Doe, Jane"
+            })
+        );
+        assert_eq!(records[1], json!({ "email": "john@acme.com", "email_verified": false }));
+    }
+
+    #[test]
+    fn rejects_files_that_cannot_be_imported() {
+        assert!(parse_file("users.csv", "email\n\"jane@acme.com\n").is_err());
+        assert!(parse_file("users.json", "{\"email\":\"jane@acme.com\"}").is_err());
+        assert!(parse_file("users.json", "[").is_err());
+        assert!(parse_file("users.json", "[]").is_err());
+        assert!(parse_file("users.json", &" ".repeat(MAX_FILE_BYTES + 1)).is_err());
+    }
+
+    #[test]
+    fn validates_every_record_against_the_connection() {
+        let records = vec![
+            json!({ "email": "jane@acme.com", "username": "jane", "password_hash": BCRYPT }),
+            json!({ "email": "JANE@acme.com", "username": "jane" }),
+            json!({ "email": "john@acme", "phone": "+1" }),
+            json!({ "email": "ann@acme.com", "username": "ann doe", "blocked": "no" }),
+            json!("jane@acme.com"),
+        ];
+        assert_eq!(
+            fields(&validate(&records, &rules(true))),
+            vec![
+                (2, "email"),
+                (3, "phone"),
+                (3, "email"),
+                (3, "username"),
+                (4, "username"),
+                (4, "blocked"),
+                (5, ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn checks_the_password_hashes() {
+        let records = vec![
+            json!({ "email": "a@acme.com", "password_hash": "plain" }),
+            json!({ "email": "b@acme.com", "password_hash": BCRYPT, "custom_password_hash": {} }),
+            json!({ "email": "c@acme.com", "custom_password_hash": { "algorithm": "md5", "hash": { "value": "x" } } }),
+            json!({ "email": "d@acme.com", "custom_password_hash": { "algorithm": "argon2", "hash": { "value": "$argon2id$v=19$m=65536,t=2,p=1$c2FsdA$aGFzaA" } } }),
+            json!({ "email": "e@acme.com", "custom_password_hash": { "algorithm": "pbkdf2", "hash": { "value": "$pbkdf2-sha512$i=100000,l=64$c2FsdA$aGFzaA" } } }),
+        ];
+        assert_eq!(
+            fields(&validate(&records, &rules(false))),
+            vec![(1, "password_hash"), (2, "password_hash"), (3, "custom_password_hash")]
+        );
+    }
+}
@@ -0,0 +1,554 @@
+use std::time::Duration;
+use serde_json::Value;
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        reader::{File, FileData, ReaderService, ReaderTask},
+        storage::{StorageService, Area},
+        timeout::{TimeoutService, TimeoutTask},
+    },
+};
+use configs::server::API_URL;
+use types::{
+    jobs::{Job, JobError, UsersImportCreate, JOB_FAILED},
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use loading::Loading;
+use crate::import_file::{
+    parse_file,
+    validate,
+    ImportRules,
+    RecordError,
+    HASH_ALGORITHMS,
+};
+
+const POLL_MILLIS: u64 = 2000;
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ImportUsersProps {
+    pub connection_id: String,
+    pub rules: ImportRules,
+}
+
+pub struct ImportUsers {
+    props: ImportUsersProps,
+    link: ComponentLink<Self>,
+    access_token: String,
+    show_modal: bool,
+    file_name: String,
+    records: Vec<Value>,
+    record_errors: Vec<RecordError>,
+    upsert: bool,
+    send_completion_email: bool,
+    reader_task: Option<ReaderTask>,
+    fetch_task: Option<FetchTask>,
+    poll_task: Option<TimeoutTask>,
+    job: Option<Job>,
+    job_errors: Vec<JobError>,
+    loading_create_job: bool,
+    loading_job_errors: bool,
+    error_file: Option<String>,
+    error_import: Option<String>,
+}
+
+pub enum StateError {
+    CreateJob,
+    GetJob,
+    GetJobErrors,
+}
+
+pub enum Msg {
+    ShowModal(bool),
+    SelectFile(File),
+    ReadFile(FileData),
+    ToggleUpsert,
+    ToggleCompletionEmail,
+    CreateJob,
+    RequestJob,
+    GetJob(Job),
+    RequestJobErrors,
+    GetJobErrors(Vec<JobError>),
+    ResponseError(String, StateError),
+    Ignore,
+}
+
+impl Component for ImportUsers {
+    type Message = Msg;
+    type Properties = ImportUsersProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        // GET LOCALSTORAGE
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+        let access_token = localstorage_data.token.unwrap_or_default();
+
+        ImportUsers {
+            props,
+            link,
+            access_token,
+            show_modal: false,
+            file_name: String::from(""),
+            records: Vec::new(),
+            record_errors: Vec::new(),
+            upsert: false,
+            send_completion_email: true,
+            reader_task: None,
+            fetch_task: None,
+            poll_task: None,
+            job: None,
+            job_errors: Vec::new(),
+            loading_create_job: false,
+            loading_job_errors: false,
+            error_file: None,
+            error_import: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ShowModal(state) => {
+                self.show_modal = state;
+                if state {
+                    self.file_name = String::from("");
+                    self.records = Vec::new();
+                    self.record_errors = Vec::new();
+                    self.job = None;
+                    self.job_errors = Vec::new();
+                    self.error_file = None;
+                    self.error_import = None;
+                } else {
+                    // THE JOB KEEPS RUNNING ON THE SERVER, ONLY STOP WATCHING IT
+                    self.poll_task = None;
+                    self.fetch_task = None;
+                }
+                true
+            }
+            Msg::SelectFile(file) => {
+                self.file_name = file.name();
+                self.error_file = None;
+                match ReaderService::read_file(file, self.link.callback(Msg::ReadFile)) {
+                    Ok(task) => self.reader_task = Some(task),
+                    Err(error) => self.error_file = Some(error.to_string()),
+                }
+                true
+            }
+            Msg::ReadFile(data) => {
+                self.reader_task = None;
+                self.records = Vec::new();
+                self.record_errors = Vec::new();
+                let FileData { name, content } = data;
+                let parsed = String::from_utf8(content)
+                    .map_err(|_| String::from("The file must be UTF-8 encoded"))
+                    .and_then(|content| parse_file(&name, &content));
+                match parsed {
+                    Ok(records) => {
+                        self.record_errors = validate(&records, &self.props.rules);
+                        self.records = records;
+                    }
+                    Err(error) => self.error_file = Some(error),
+                }
+                true
+            }
+            Msg::ToggleUpsert => {
+                self.upsert = !self.upsert;
+                true
+            }
+            Msg::ToggleCompletionEmail => {
+                self.send_completion_email = !self.send_completion_email;
+                true
+            }
+            Msg::CreateJob => {
+                let data_import = UsersImportCreate {
+                    connection_id: self.props.connection_id.clone(),
+                    upsert: self.upsert,
+                    send_completion_email: self.send_completion_email,
+                    users: self.records.clone(),
+                };
+                let request = Request::post(format!("{}/api/v2/jobs/users-imports", API_URL))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_import))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Job, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetJob(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::CreateJob),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_import = None;
+                self.loading_create_job = true;
+                true
+            }
+            Msg::RequestJob => {
+                let job_id = match &self.job {
+                    Some(job) => job.id.clone(),
+                    None => return false,
+                };
+                let request = Request::get(format!("{}/api/v2/jobs/{}", API_URL, job_id))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Job, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetJob(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::GetJob),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                false
+            }
+            Msg::GetJob(job) => {
+                self.fetch_task = None;
+                self.loading_create_job = false;
+                let finished = job.is_finished();
+                let has_failures = job.status == JOB_FAILED
+                    || job.summary.as_ref().map(|summary| summary.failed > 0).unwrap_or(false);
+                self.job = Some(job);
+                if !finished {
+                    let task = TimeoutService::spawn(
+                        Duration::from_millis(POLL_MILLIS),
+                        self.link.callback(|_| Msg::RequestJob),
+                    );
+                    self.poll_task = Some(task);
+                } else if has_failures {
+                    self.link.send_message(Msg::RequestJobErrors);
+                }
+                true
+            }
+            Msg::RequestJobErrors => {
+                let job_id = match &self.job {
+                    Some(job) => job.id.clone(),
+                    None => return false,
+                };
+                let request = Request::get(format!("{}/api/v2/jobs/{}/errors", API_URL, job_id))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<JobError>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetJobErrors(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::GetJobErrors),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.loading_job_errors = true;
+                true
+            }
+            Msg::GetJobErrors(job_errors) => {
+                self.job_errors = job_errors;
+                self.loading_job_errors = false;
+                self.fetch_task = None;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::CreateJob => {
+                        self.loading_create_job = false;
+                    }
+                    StateError::GetJob => {}
+                    StateError::GetJobErrors => {
+                        self.loading_job_errors = false;
+                    }
+                }
+                self.error_import = Some(message);
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => false,
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            // RULES CHANGED, CHECK THE SELECTED FILE AGAIN
+            self.record_errors = validate(&self.records, &self.props.rules);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <button
+                    type="button"
+                    class="btn btn-outline-primary p-2 mx-2"
+                    onclick=self.link.callback(|_| Msg::ShowModal(true))
+                >
+                    {"Import Users"}
+                </button>
+                <div class=format!("modal fade {}", if self.show_modal {"show"} else {""})>
+                    <div class="modal-dialog modal-dialog-centered modal-dialog-scrollable modal-lg">
+                        <div class="modal-content">
+                            <div class="modal-header">
+                                <h5 class="modal-title">{"Import Users"}</h5>
+                                <button
+                                    type="button"
+                                    class="btn-close"
+                                    aria-label="Close"
+                                    onclick=self.link.callback(|_| Msg::ShowModal(false))
+                                ></button>
+                            </div>
+                            <div class="modal-body">
+                                {
+                                    match &self.job {
+                                        Some(job) => self.view_job(job),
+                                        None => self.view_file(),
+                                    }
+                                }
+                                {
+                                    match &self.error_import {
+                                        Some(error) => html! {
+                                            <div class="alert alert-warning mt-3 mb-0" role="alert">
+                                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                                { error.clone() }
+                                            </div>
+                                        },
+                                        None => html! {},
+                                    }
+                                }
+                            </div>
+                            <div class="modal-footer">
+                                { self.view_footer() }
+                            </div>
+                        </div>
+                    </div>
+                </div>
+                <div class=format!("modal-backdrop fade {}", if self.show_modal {"show"} else {""}) />
+            </>
+        }
+    }
+}
+
+impl ImportUsers {
+    fn view_file(&self) -> Html {
+        html! {
+            <>
+                <p style="font-size: 14px;">
+                    {"Upload a JSON array or a CSV file with a header row. Passwords can be imported as "}
+                    <code>{"password_hash"}</code>
+                    {" (bcrypt) or as "}
+                    <code>{"custom_password_hash"}</code>
+                    { format!(" ({}).", HASH_ALGORITHMS.join(", ")) }
+                </p>
+                <input
+                    type="file"
+                    class=classes!("form-control", if self.error_file.is_some() { "is-invalid" } else { "" })
+                    accept=".json,.csv,application/json,text/csv"
+                    onchange=self.link.callback(|value| {
+                        if let ChangeData::Files(files) = value {
+                            match files.get(0) {
+                                Some(file) => Msg::SelectFile(file),
+                                None => Msg::Ignore,
+                            }
+                        } else {
+                            Msg::Ignore
+                        }
+                    })
+                />
+                {
+                    match &self.error_file {
+                        Some(error) => html! { <div class="invalid-feedback">{ error.clone() }</div> },
+                        None => html! {},
+                    }
+                }
+                <div class="form-check mt-3">
+                    <input
+                        class="form-check-input"
+                        type="checkbox"
+                        id="importUpsert"
+                        checked=self.upsert
+                        onclick=self.link.callback(|_| Msg::ToggleUpsert)
+                    />
+                    <label class="form-check-label" for="importUpsert" style="font-size: 14px;">
+                        {"Update existing users (upsert)"}
+                    </label>
+                </div>
+                <div class="form-check">
+                    <input
+                        class="form-check-input"
+                        type="checkbox"
+                        id="importCompletionEmail"
+                        checked=self.send_completion_email
+                        onclick=self.link.callback(|_| Msg::ToggleCompletionEmail)
+                    />
+                    <label class="form-check-label" for="importCompletionEmail" style="font-size: 14px;">
+                        {"Send a completion email to tenant owners"}
+                    </label>
+                </div>
+                {
+                    if self.records.is_empty() {
+                        html! {}
+                    } else if self.record_errors.is_empty() {
+                        html! {
+                            <div class="alert alert-success mt-3 mb-0" role="alert" style="font-size: 14px;">
+                                <i class="bi bi-check-circle me-2"></i>
+                                { format!("{} users in {} are ready to be imported.", self.records.len(), self.file_name) }
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            <>
+                                <div class="alert alert-warning mt-3" role="alert" style="font-size: 14px;">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    { format!("{} problems found in {} users, fix the file and select it again.", self.record_errors.len(), self.records.len()) }
+                                </div>
+                                { view_error_table("Record", self.record_errors.iter().map(|error| (error.record.to_string(), error.email.clone(), error.field.clone(), error.message.clone())).collect()) }
+                            </>
+                        }
+                    }
+                }
+            </>
+        }
+    }
+
+    fn view_job(&self, job: &Job) -> Html {
+        let summary = job.summary.clone().unwrap_or_default();
+        html! {
+            <>
+                <div class="d-flex align-items-center mb-3" style="font-size: 14px;">
+                    <span class="me-3">{"Job"}</span>
+                    <span
+                        class="rounded me-3"
+                        style="background-color: #eff0f2; padding: 2px 6px; font-family: 'Roboto Mono', monospace;"
+                    >
+                        { job.id.clone() }
+                    </span>
+                    {
+                        if job.is_finished() {
+                            html! { <span class=classes!("badge", if job.status == JOB_FAILED { "bg-danger" } else { "bg-success" })>{ job.status.clone() }</span> }
+                        } else {
+                            html! {
+                                <>
+                                    <span class="spinner-border spinner-border-sm text-secondary me-2" role="status"></span>
+                                    <span class="text-muted">{ job.status.clone() }</span>
+                                </>
+                            }
+                        }
+                    }
+                </div>
+                {
+                    if job.summary.is_some() {
+                        html! {
+                            <div class="d-flex mb-3" style="font-size: 14px;">
+                                <span class="me-4">{ format!("Total: {}", summary.total) }</span>
+                                <span class="me-4 text-success">{ format!("Inserted: {}", summary.inserted) }</span>
+                                <span class="me-4">{ format!("Updated: {}", summary.updated) }</span>
+                                <span class="text-danger">{ format!("Failed: {}", summary.failed) }</span>
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+                {
+                    if self.loading_job_errors {
+                        html! {
+                            <div style="position: relative; margin-top: 2rem; margin-bottom: 2rem;">
+                                <Loading width=45 />
+                            </div>
+                        }
+                    } else if !self.job_errors.is_empty() {
+                        view_error_table("Code", self.job_errors.iter().flat_map(|job_error| {
+                            let email = job_error.user.get("email").and_then(Value::as_str).unwrap_or_default().to_string();
+                            job_error.errors.iter().map(move |detail| {
+                                (detail.code.clone(), email.clone(), detail.path.clone(), detail.message.clone())
+                            })
+                        }).collect())
+                    } else {
+                        html! {}
+                    }
+                }
+            </>
+        }
+    }
+
+    fn view_footer(&self) -> Html {
+        if self.job.is_some() {
+            return html! {
+                <button type="button" class="btn btn-primary" onclick=self.link.callback(|_| Msg::ShowModal(false))>
+                    {"Close"}
+                </button>
+            };
+        }
+        let disabled = self.loading_create_job || self.records.is_empty() || !self.record_errors.is_empty();
+        html! {
+            <>
+                <button type="button" class="btn btn-outline-secondary" onclick=self.link.callback(|_| Msg::ShowModal(false))>
+                    {"Cancel"}
+                </button>
+                <button
+                    type="button"
+                    class=format!("btn {} btn-primary position-relative", if self.loading_create_job {"loading"} else {""})
+                    onclick=self.link.callback(|_| Msg::CreateJob)
+                    disabled=disabled
+                >
+                    <div class="telkom-label">
+                        { format!("Import {} Users", self.records.len()) }
+                    </div>
+                    <div class="telkom-spinner telkom-center">
+                        <div class="spinner-border spinner-border-sm" role="status"/>
+                    </div>
+                </button>
+            </>
+        }
+    }
+}
+
+// ROWS OF (record number OR error code, email, field, message)
+fn view_error_table(first_column: &str, rows: Vec<(String, String, String, String)>) -> Html {
+    html! {
+        <div class="table-responsive" style="max-height: 320px; overflow-y: auto;">
+            <table class="table table-sm" style="font-size: 14px;">
+                <thead>
+                    <tr>
+                        <th scope="col">{ first_column }</th>
+                        <th scope="col">{"Email"}</th>
+                        <th scope="col">{"Field"}</th>
+                        <th scope="col">{"Problem"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {
+                        for rows.into_iter().map(|(record, email, field, message)| html! {
+                            <tr>
+                                <td class="text-muted">{ record }</td>
+                                <td>{ email }</td>
+                                <td><code>{ field }</code></td>
+                                <td>{ message }</td>
+                            </tr>
+                        })
+                    }
+                </tbody>
+            </table>
+        </div>
+    }
+}
@@ -1,24 +1,303 @@
-use yew::prelude::*;
-pub struct TabSettings {}
-pub enum Msg {}
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+        ConsoleService,
+    },
+    agent::Bridged,
+    Bridge,
+};
+use yew_router::{
+    agent::RouteRequest::ChangeRoute,
+    prelude::*,
+};
+use router::AppRoute;
+use configs::server::API_URL;
+use types::{
+    database::{
+        DatabaseConnection,
+        DatabaseConnectionUpdate,
+        PasswordPolicy,
+        validate_password_policy,
+        validate_username_length,
+        SYNC_ON_EACH_LOGIN,
+        SYNC_ON_FIRST_LOGIN,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+
+mod import_file;
+mod import_users;
+mod password_policy;
+
+use import_file::ImportRules;
+use import_users::ImportUsers;
+use password_policy::PasswordPolicyEditor;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TabSettingsProps {
+    pub connection: DatabaseConnection,
+    pub on_update: Callback<DatabaseConnection>,
+}
+
+pub enum Data {
+    UsernameMin,
+    UsernameMax,
+}
+
+pub enum Toggle {
+    RequiresUsername,
+    ImportMode,
+    DisableSignup,
+    SyncUserProfile,
+}
+
+pub enum StateError {
+    Update,
+    Delete,
+}
+
+pub struct TabSettings {
+    access_token: String,
+    link: ComponentLink<Self>,
+    connection: DatabaseConnection,
+    // THE IMPORT IS VALIDATED AGAINST THE SAVED SETTINGS, NOT THE UNSAVED FORM
+    saved_connection: DatabaseConnection,
+    on_update: Callback<DatabaseConnection>,
+    fetch_task: Option<FetchTask>,
+    loading_update: bool,
+    error_update: Option<String>,
+    message_update: Option<String>,
+    loading_delete: bool,
+    error_delete: Option<String>,
+    route_agent: Box<dyn Bridge<RouteAgent>>,
+}
+
+pub enum Msg {
+    Input(String, Data),
+    Toggle(Toggle),
+    InputPasswordPolicy(PasswordPolicy),
+    Update,
+    GetConnection(DatabaseConnection),
+    Delete,
+    RedirectToConnections,
+    ResponseError(String, StateError),
+    Ignore,
+}
 
 impl Component for TabSettings {
     type Message = Msg;
-    type Properties = ();
+    type Properties = TabSettingsProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
 
-    fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
-        TabSettings {}
+        TabSettings {
+            access_token,
+            connection: props.connection.clone(),
+            saved_connection: props.connection,
+            on_update: props.on_update,
+            fetch_task: None,
+            loading_update: false,
+            error_update: None,
+            message_update: None,
+            loading_delete: false,
+            error_delete: None,
+            route_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            link,
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        true
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(value, data) => {
+                let username = &mut self.connection.options.validation.username;
+                match data {
+                    Data::UsernameMin => {
+                        if let Ok(min) = value.parse::<u32>() {
+                            username.min = min;
+                        }
+                    }
+                    Data::UsernameMax => {
+                        if let Ok(max) = value.parse::<u32>() {
+                            username.max = max;
+                        }
+                    }
+                }
+                self.message_update = None;
+                true
+            }
+            Msg::Toggle(toggle) => {
+                let options = &mut self.connection.options;
+                match toggle {
+                    Toggle::RequiresUsername => {
+                        options.requires_username = !options.requires_username;
+                    }
+                    Toggle::ImportMode => {
+                        options.import_mode = !options.import_mode;
+                    }
+                    Toggle::DisableSignup => {
+                        options.disable_signup = !options.disable_signup;
+                    }
+                    Toggle::SyncUserProfile => {
+                        options.set_user_root_attributes = if options.set_user_root_attributes == SYNC_ON_EACH_LOGIN {
+                            String::from(SYNC_ON_FIRST_LOGIN)
+                        } else {
+                            String::from(SYNC_ON_EACH_LOGIN)
+                        };
+                    }
+                }
+                self.message_update = None;
+                true
+            }
+            Msg::InputPasswordPolicy(policy) => {
+                self.connection.options.password_policy = policy;
+                self.message_update = None;
+                true
+            }
+            Msg::Update => {
+                // VALIDATION
+                if let Err(message) = validate_password_policy(&self.connection.options.password_policy) {
+                    self.link.send_message(Msg::ResponseError(message, StateError::Update));
+                    return false;
+                }
+                if self.connection.options.requires_username {
+                    if let Err(message) = validate_username_length(&self.connection.options.validation.username) {
+                        self.link.send_message(Msg::ResponseError(message, StateError::Update));
+                        return false;
+                    }
+                }
+
+                let data_update_connection = DatabaseConnectionUpdate::from(&self.connection);
+                let request = Request::patch(format!("{}/api/v2/connections/{}", API_URL, self.connection.id.clone()))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_update_connection))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<DatabaseConnection, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetConnection(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::Update)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_update = None;
+                self.message_update = None;
+                self.loading_update = true;
+                true
+            }
+            Msg::GetConnection(data) => {
+                ConsoleService::info(&format!("connection = {:?}", data));
+                self.connection = data.clone();
+                self.saved_connection = data.clone();
+                self.on_update.emit(data);
+                self.loading_update = false;
+                self.message_update = Some(String::from("Connection settings have been saved"));
+                self.fetch_task = None;
+                true
+            }
+            Msg::Delete => {
+                let request = Request::delete(format!("{}/api/v2/connections/{}", API_URL, self.connection.id.clone()))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(|response: Response<Json<Result<(), anyhow::Error>>>| {
+                    let (meta, Json(data)) = response.into_parts();
+                    let status_number = meta.status.as_u16();
+
+                    match status_number {
+                        204 => {
+                            Msg::RedirectToConnections
+                        }
+                        _ => {
+                            match data {
+                                Ok(_) => {
+                                    Msg::RedirectToConnections
+                                }
+                                Err(error) => {
+                                    Msg::ResponseError(error.to_string(), StateError::Delete)
+                                }
+                            }
+                        }
+                    }
+                });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.loading_delete = true;
+                self.error_delete = None;
+                self.fetch_task = Some(task);
+                true
+            }
+            Msg::RedirectToConnections => {
+                self.loading_delete = false;
+                self.fetch_task = None;
+                self.route_agent.send(ChangeRoute(AppRoute::DatabaseHome.into()));
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::Update => {
+                        self.loading_update = false;
+                        self.error_update = Some(message);
+                    }
+                    StateError::Delete => {
+                        self.loading_delete = false;
+                        self.error_delete = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => {
+                false
+            }
+        }
     }
 
-    fn change(&mut self, _: Self::Properties) -> ShouldRender {
-        false
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.on_update = props.on_update;
+        if self.connection.id != props.connection.id {
+            self.connection = props.connection.clone();
+            self.saved_connection = props.connection;
+            true
+        } else {
+            false
+        }
     }
 
     fn view(&self) -> Html {
+        let options = self.connection.options.clone();
+        let saved_options = &self.saved_connection.options;
+        let rules = ImportRules {
+            requires_username: saved_options.requires_username,
+            username_min: saved_options.validation.username.min,
+            username_max: saved_options.validation.username.max,
+        };
         html! {
             <>
                 <p class="p-2 m-2">
@@ -44,7 +323,14 @@ impl Component for TabSettings {
                     </div>
                     </div>
                     <div class="form-check form-switch fs-4">
-                    <input class="form-check-input" type="checkbox" id="flexSwitchCheckDefault" />
+                    <input
+                        class="form-check-input"
+                        type="checkbox"
+                        id="settingsRequiresUsername"
+                        checked=options.requires_username
+                        disabled={ self.loading_update }
+                        onclick=self.link.callback(|_| Msg::Toggle(Toggle::RequiresUsername))
+                    />
                     </div>
                 </div>
                 <div class="d-flex list-hover justify-content-between align-items-center">
@@ -76,7 +362,15 @@ impl Component for TabSettings {
                                                                                         ">
                                 {"Min"}
                             </span>
-                            <input type="number" class="form-control" min="1" value="1" width="50px" />
+                            <input
+                                type="number"
+                                class="form-control"
+                                min="1"
+                                value=options.validation.username.min.to_string()
+                                width="50px"
+                                disabled={ self.loading_update || !options.requires_username }
+                                oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::UsernameMin))
+                            />
                             </div>
                             <div class="d-grid m-2">
                             <span class="fw-bold m-2" style="
@@ -84,7 +378,15 @@ impl Component for TabSettings {
                                                                                         ">
                                 {"Max"}
                             </span>
-                            <input type="number" class="form-control" min="1" value="15" width="50px" />
+                            <input
+                                type="number"
+                                class="form-control"
+                                min="1"
+                                value=options.validation.username.max.to_string()
+                                width="50px"
+                                disabled={ self.loading_update || !options.requires_username }
+                                oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::UsernameMax))
+                            />
                             </div>
                         </div>
                         </div>
@@ -93,6 +395,12 @@ impl Component for TabSettings {
                 </div>
                 </div>
                 
+                <PasswordPolicyEditor
+                    policy=options.password_policy.clone()
+                    disabled=self.loading_update
+                    on_change=self.link.callback(Msg::InputPasswordPolicy)
+                />
+
                 <div class="card p-2 m-4">
                 <div class="d-flex border-1 list-hover justify-content-between align-items-center">
                     <div class="p-4 d-flex" style="width: 40%;">
@@ -111,8 +419,18 @@ impl Component for TabSettings {
                         </p>
                     </div>
                     </div>
+                    <div class="d-flex align-items-center">
+                    <ImportUsers connection_id=self.saved_connection.id.clone() rules=rules />
                     <div class="form-check form-switch fs-4">
-                    <input class="form-check-input" type="checkbox" id="flexSwitchCheckDefault" />
+                    <input
+                        class="form-check-input"
+                        type="checkbox"
+                        id="settingsImportMode"
+                        checked=options.import_mode
+                        disabled={ self.loading_update }
+                        onclick=self.link.callback(|_| Msg::Toggle(Toggle::ImportMode))
+                    />
+                    </div>
                     </div>
                 </div>
                 </div>
@@ -137,7 +455,14 @@ impl Component for TabSettings {
                     </div>
                     </div>
                     <div class="form-check form-switch fs-4">
-                    <input class="form-check-input" type="checkbox" id="flexSwitchCheckDefault" />
+                    <input
+                        class="form-check-input"
+                        type="checkbox"
+                        id="settingsDisableSignup"
+                        checked=options.disable_signup
+                        disabled={ self.loading_update }
+                        onclick=self.link.callback(|_| Msg::Toggle(Toggle::DisableSignup))
+                    />
                     </div>
                 </div>
                 </div>
@@ -161,11 +486,53 @@ impl Component for TabSettings {
                     </div>
                     </div>
                     <div class="form-check form-switch fs-4">
-                    <input class="form-check-input" type="checkbox" id="flexSwitchCheckDefault" />
+                    <input
+                        class="form-check-input"
+                        type="checkbox"
+                        id="settingsSyncUserProfile"
+                        checked={ options.set_user_root_attributes == SYNC_ON_EACH_LOGIN }
+                        disabled={ self.loading_update }
+                        onclick=self.link.callback(|_| Msg::Toggle(Toggle::SyncUserProfile))
+                    />
                     </div>
                 </div>
                 </div>
 
+                <div class="p-2 m-4">
+                    <button
+                        type="button"
+                        class=format!("btn {} btn-primary position-relative", if self.loading_update {"loading"} else {""} )
+                        onclick=self.link.callback(|_| Msg::Update)
+                        disabled={ self.loading_update }
+                    >
+                        <div class="telkom-label">
+                            {"Save"}
+                        </div>
+                        <div class="telkom-spinner telkom-center">
+                            <div class="spinner-border spinner-border-sm" role="status"/>
+                        </div>
+                    </button>
+
+                    {
+                        if self.error_update.is_some() {
+                            html! {
+                                <div class="alert alert-warning mt-3" role="alert">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    { self.error_update.clone().unwrap() }
+                                </div>
+                            }
+                        } else if self.message_update.is_some() {
+                            html! {
+                                <div class="alert alert-success mt-3" role="alert">
+                                    { self.message_update.clone().unwrap() }
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </div>
+
                 <span class="fw-bold p-2 m-4" style="
                                                                             width: 200%;
                                                                             font-size: 20px;
@@ -181,18 +548,42 @@ impl Component for TabSettings {
                                                                             width: 200%;
                                                                             font-size: 16px;
                                                                             ">
-                        {"Sync user profile attributes at each login"}
+                        {"Delete Connection"}
                         </span>
                         <p class="mb-0" style="
                                                                             width: 200%;
                                                                             font-size: 16px;
                                                                             ">
-                        {"Update the user profile upon each login for custom databases where import mode is disabled."}
+                        {"All users stored in this connection will be removed and applications will no longer be able to use it. Once confirmed, this operation can't be undone!"}
                         </p>
                     </div>
                     </div>
-                    <button type="button" class="btn btn-danger p-2 mx-2">{"Delete"}</button>
+                    <button
+                        type="button"
+                        class=format!("btn {} btn-danger position-relative p-2 mx-2", if self.loading_delete {"loading"} else {""} )
+                        onclick=self.link.callback(|_| Msg::Delete)
+                        disabled={ self.loading_delete }
+                    >
+                        <div class="telkom-label">
+                            {"Delete"}
+                        </div>
+                        <div class="telkom-spinner telkom-center">
+                            <div class="spinner-border spinner-border-sm" role="status"/>
+                        </div>
+                    </button>
                 </div>
+                {
+                    if self.error_delete.is_some() {
+                        html! {
+                            <div class="alert alert-warning mt-3 mb-0" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_delete.clone().unwrap() }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
                 </div>
                 
             </>
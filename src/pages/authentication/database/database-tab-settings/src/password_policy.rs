@@ -0,0 +1,336 @@
+use yew::prelude::*;
+use types::database::{
+    password_strength,
+    personal_info_from_email,
+    PasswordPolicy,
+    PasswordStrength,
+    PASSWORD_HISTORY_MAX_SIZE,
+    PASSWORD_MAX_LENGTH,
+};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct PasswordPolicyProps {
+    pub policy: PasswordPolicy,
+    pub disabled: bool,
+    pub on_change: Callback<PasswordPolicy>,
+}
+
+pub enum Data {
+    MinLength,
+    HistorySize,
+    Dictionary,
+    TryPassword,
+    TryEmail,
+}
+
+#[derive(Clone, Copy)]
+pub enum Toggle {
+    Lowercase,
+    Uppercase,
+    Number,
+    Special,
+    History,
+    Dictionary,
+    PersonalInfo,
+}
+
+pub struct PasswordPolicyEditor {
+    props: PasswordPolicyProps,
+    link: ComponentLink<Self>,
+    // ONE DISALLOWED PASSWORD PER LINE, KEPT AS TYPED
+    dictionary_text: String,
+    try_password: String,
+    try_email: String,
+}
+
+pub enum Msg {
+    Input(String, Data),
+    Toggle(Toggle),
+}
+
+impl Component for PasswordPolicyEditor {
+    type Message = Msg;
+    type Properties = PasswordPolicyProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let dictionary_text = props.policy.dictionary.dictionary.join("\n");
+        PasswordPolicyEditor {
+            props,
+            link,
+            dictionary_text,
+            try_password: String::from(""),
+            try_email: String::from(""),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut policy = self.props.policy.clone();
+        match msg {
+            Msg::Input(value, data) => {
+                match data {
+                    Data::MinLength => {
+                        if let Ok(min_length) = value.parse::<u32>() {
+                            policy.min_length = min_length;
+                        }
+                    }
+                    Data::HistorySize => {
+                        if let Ok(size) = value.parse::<u32>() {
+                            policy.history.size = size;
+                        }
+                    }
+                    Data::Dictionary => {
+                        policy.dictionary.dictionary = value
+                            .lines()
+                            .map(|line| line.trim().to_string())
+                            .filter(|line| !line.is_empty())
+                            .collect();
+                        self.dictionary_text = value;
+                    }
+                    Data::TryPassword => {
+                        self.try_password = value;
+                        return true;
+                    }
+                    Data::TryEmail => {
+                        self.try_email = value;
+                        return true;
+                    }
+                }
+            }
+            Msg::Toggle(toggle) => {
+                match toggle {
+                    Toggle::Lowercase => policy.require_lowercase = !policy.require_lowercase,
+                    Toggle::Uppercase => policy.require_uppercase = !policy.require_uppercase,
+                    Toggle::Number => policy.require_number = !policy.require_number,
+                    Toggle::Special => policy.require_special = !policy.require_special,
+                    Toggle::History => policy.history.enable = !policy.history.enable,
+                    Toggle::Dictionary => policy.dictionary.enable = !policy.dictionary.enable,
+                    Toggle::PersonalInfo => policy.no_personal_info = !policy.no_personal_info,
+                }
+            }
+        }
+        self.props.on_change.emit(policy);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let policy = self.props.policy.clone();
+        let disabled = self.props.disabled;
+        html! {
+            <div class="card p-2 m-4">
+                <div class="p-4">
+                    <span class="fw-bold" style="font-size: 14px;">
+                        {"Password Policy"}
+                    </span>
+                    <p class="mb-0 text-muted" style="font-size: 14px;">
+                        {"Rules a password must follow when users sign up or change their password on this connection."}
+                    </p>
+                </div>
+
+                <div class="px-4 pb-3 border-bottom">
+                    <label class="fw-bold mb-2" style="font-size: 14px;">{"Minimum Length"}</label>
+                    <input
+                        type="number"
+                        class="form-control"
+                        style="width: 120px;"
+                        min="1"
+                        max=PASSWORD_MAX_LENGTH.to_string()
+                        value=policy.min_length.to_string()
+                        disabled=disabled
+                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::MinLength))
+                    />
+                </div>
+
+                <div class="px-4 py-3 border-bottom">
+                    <p class="fw-bold mb-2" style="font-size: 14px;">{"Require at least one of"}</p>
+                    { self.view_checkbox("policyLowercase", "Lower case letters (a-z)", policy.require_lowercase, Toggle::Lowercase) }
+                    { self.view_checkbox("policyUppercase", "Upper case letters (A-Z)", policy.require_uppercase, Toggle::Uppercase) }
+                    { self.view_checkbox("policyNumber", "Numbers (0-9)", policy.require_number, Toggle::Number) }
+                    { self.view_checkbox("policySpecial", "Special characters (e.g. !@#$%^&*)", policy.require_special, Toggle::Special) }
+                </div>
+
+                { self.view_switch(
+                    "Password History",
+                    "Prevent users from reusing their most recent passwords.",
+                    policy.history.enable,
+                    Toggle::History,
+                ) }
+                {
+                    if policy.history.enable {
+                        html! {
+                            <div class="px-4 pb-3 border-bottom">
+                                <label class="mb-2" style="font-size: 14px;">{"Number of previous passwords"}</label>
+                                <input
+                                    type="number"
+                                    class="form-control"
+                                    style="width: 120px;"
+                                    min="1"
+                                    max=PASSWORD_HISTORY_MAX_SIZE.to_string()
+                                    value=policy.history.size.to_string()
+                                    disabled=disabled
+                                    oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::HistorySize))
+                                />
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+
+                { self.view_switch(
+                    "Password Dictionary",
+                    "Reject commonly used passwords and the custom entries below.",
+                    policy.dictionary.enable,
+                    Toggle::Dictionary,
+                ) }
+                {
+                    if policy.dictionary.enable {
+                        html! {
+                            <div class="px-4 pb-3 border-bottom">
+                                <label class="mb-2" style="font-size: 14px;">{"Disallowed passwords, one per line"}</label>
+                                <textarea
+                                    class="form-control"
+                                    rows="4"
+                                    style="font-family: 'Roboto Mono', monospace; font-size: 13px;"
+                                    value=self.dictionary_text.clone()
+                                    disabled=disabled
+                                    oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::Dictionary))
+                                />
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+
+                { self.view_switch(
+                    "Personal Data",
+                    "Disallow passwords that contain the user's name, username or parts of their email.",
+                    policy.no_personal_info,
+                    Toggle::PersonalInfo,
+                ) }
+
+                { self.view_checker() }
+            </div>
+        }
+    }
+}
+
+impl PasswordPolicyEditor {
+    fn view_checkbox(&self, id: &'static str, label: &'static str, checked: bool, toggle: Toggle) -> Html {
+        let callback = self.link.callback(move |_| Msg::Toggle(toggle));
+        html! {
+            <div class="form-check">
+                <input
+                    class="form-check-input"
+                    type="checkbox"
+                    id=id
+                    checked=checked
+                    disabled=self.props.disabled
+                    onclick=callback
+                />
+                <label class="form-check-label" for=id style="font-size: 14px;">{ label }</label>
+            </div>
+        }
+    }
+
+    fn view_switch(&self, title: &'static str, description: &'static str, checked: bool, toggle: Toggle) -> Html {
+        let callback = self.link.callback(move |_| Msg::Toggle(toggle));
+        html! {
+            <div class="d-flex list-hover justify-content-between align-items-center">
+                <div class="p-4 d-flex" style="width: 60%;">
+                    <div class="d-grid" style="min-width: 40px;">
+                        <span class="fw-bold" style="font-size: 14px;">
+                            { title }
+                        </span>
+                        <p class="mb-0 text-muted" style="font-size: 14px;">
+                            { description }
+                        </p>
+                    </div>
+                </div>
+                <div class="form-check form-switch fs-4 me-3">
+                    <input
+                        class="form-check-input"
+                        type="checkbox"
+                        checked=checked
+                        disabled=self.props.disabled
+                        onclick=callback
+                    />
+                </div>
+            </div>
+        }
+    }
+
+    fn view_checker(&self) -> Html {
+        let strength = password_strength(&self.try_password);
+        let violations = self.props.policy.violations(&self.try_password, &personal_info_from_email(&self.try_email));
+        let bar_class = match strength {
+            PasswordStrength::VeryWeak | PasswordStrength::Weak => "bg-danger",
+            PasswordStrength::Fair => "bg-warning",
+            PasswordStrength::Good | PasswordStrength::Strong => "bg-success",
+        };
+        html! {
+            <div class="px-4 py-3 border-top" style="background-color: #f8f9fa;">
+                <p class="fw-bold mb-2" style="font-size: 14px;">{"Try the policy"}</p>
+                <div class="d-flex mb-2">
+                    <input
+                        type="text"
+                        class="form-control me-2"
+                        placeholder="Email (optional)"
+                        value=self.try_email.clone()
+                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::TryEmail))
+                    />
+                    <input
+                        type="text"
+                        class="form-control"
+                        placeholder="Password"
+                        value=self.try_password.clone()
+                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::TryPassword))
+                    />
+                </div>
+                {
+                    if self.try_password.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <>
+                                <div class="progress mb-1" style="height: 6px;">
+                                    <div
+                                        class=format!("progress-bar {}", bar_class)
+                                        role="progressbar"
+                                        style=format!("width: {}%;", strength.percent())
+                                    />
+                                </div>
+                                <p class="text-muted mb-2" style="font-size: 13px;">{ strength.label() }</p>
+                                {
+                                    if violations.is_empty() {
+                                        html! {
+                                            <p class="text-success mb-0" style="font-size: 13px;">
+                                                <i class="bi bi-check-circle me-2"></i>
+                                                {"This password meets the policy"}
+                                            </p>
+                                        }
+                                    } else {
+                                        html! {
+                                            <ul class="text-danger mb-0 ps-3" style="font-size: 13px;">
+                                                { for violations.iter().map(|violation| html! { <li>{ violation }</li> }) }
+                                            </ul>
+                                        }
+                                    }
+                                }
+                            </>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use yew::prelude::*;
+use types::enterprise::{
+    AzureAdOptions,
+    AZURE_IDENTITY_APIS,
+};
+
+// GENERAL SETTINGS OF AN AZURE AD CONNECTION, SHARED BY THE CREATE PAGE
+// AND THE SETTINGS TAB
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct AzureAdFormProps {
+    pub options: AzureAdOptions,
+    pub disabled: bool,
+    pub on_change: Callback<AzureAdOptions>,
+}
+
+pub enum Data {
+    Domain,
+    ClientId,
+    ClientSecret,
+    IdentityApi,
+}
+
+#[derive(Clone, Copy)]
+pub enum Toggle {
+    ExtendedProfile,
+    Groups,
+    NestedGroups,
+}
+
+pub struct AzureAdForm {
+    props: AzureAdFormProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    Input(String, Data),
+    Toggle(Toggle),
+}
+
+impl Component for AzureAdForm {
+    type Message = Msg;
+    type Properties = AzureAdFormProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        AzureAdForm {
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut options = self.props.options.clone();
+        match msg {
+            Msg::Input(value, data) => {
+                match data {
+                    Data::Domain => options.domain = value.trim().to_string(),
+                    Data::ClientId => options.client_id = value.trim().to_string(),
+                    Data::ClientSecret => options.client_secret = value,
+                    Data::IdentityApi => options.identity_api = value,
+                }
+            }
+            Msg::Toggle(toggle) => {
+                match toggle {
+                    Toggle::ExtendedProfile => options.ext_profile = !options.ext_profile,
+                    Toggle::Groups => {
+                        options.ext_groups = !options.ext_groups;
+                        if !options.ext_groups {
+                            options.ext_nested_groups = false;
+                        }
+                    }
+                    Toggle::NestedGroups => options.ext_nested_groups = !options.ext_nested_groups,
+                }
+            }
+        }
+        self.props.on_change.emit(options);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let options = self.props.options.clone();
+        let disabled = self.props.disabled;
+        html! {
+            <>
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Microsoft Azure AD Domain"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="text"
+                            class="form-control"
+                            placeholder="contoso.onmicrosoft.com"
+                            value=options.domain.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::Domain))
+                        />
+                    </div>
+                    <p
+                        class="text-color-disabled"
+                    >
+                        {"Your Azure AD domain name. You can find it in the Overview page of your directory."}
+                    </p>
+                </div>
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Client ID"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="text"
+                            class="form-control"
+                            placeholder="00000000-0000-0000-0000-000000000000"
+                            value=options.client_id.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::ClientId))
+                        />
+                    </div>
+                    <a
+                        href="https://learn.microsoft.com/en-us/azure/active-directory/develop/quickstart-register-app"
+                        target="_blank"
+                        class="text-decoration-none"
+                    >
+                        <span
+                            style="
+                                white-space: nowrap;
+                                text-overflow: ellipsis;
+                                overflow: hidden;
+                                font-size: 14px;
+                                text-decoration: none;
+                            "
+                        >
+                            {"How to obtain a Client ID?"}
+                        </span>
+                    </a>
+                </div>
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Client Secret"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="password"
+                            class="form-control"
+                            autocomplete="new-password"
+                            value=options.client_secret.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::ClientSecret))
+                        />
+                    </div>
+                    <p
+                        class="text-color-disabled"
+                    >
+                        {"A client secret of the app registration, from the Certificates & secrets page."}
+                    </p>
+                </div>
+
+                <div
+                    class="mb-5"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Identity API"}
+                    </p>
+                    <select
+                        class="form-select"
+                        disabled=disabled
+                        onchange=self.link.callback(|e| {
+                            if let ChangeData::Select(select) = e {
+                                Msg::Input(select.value(), Data::IdentityApi)
+                            } else {
+                                Msg::Input(String::from(""), Data::IdentityApi)
+                            }
+                        })
+                    >
+                        {
+                            for AZURE_IDENTITY_APIS.iter().map(|(value, label)| html! {
+                                <option value=*value selected={ options.identity_api == *value }>{ *label }</option>
+                            })
+                        }
+                    </select>
+                    <p
+                        class="text-color-disabled mt-2"
+                    >
+                        {"Azure Active Directory (v1) is only needed by app registrations that don't support the Microsoft Identity Platform yet."}
+                    </p>
+                </div>
+
+                <div
+                    class="mb-5"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Attributes"}
+                    </p>
+                    <div>
+                        <div class="form-check mb-2 d-flex align-items-center d-inline-flex" style="width: 49%;">
+                            <input
+                                class="form-check-input me-2 mt-0"
+                                style="font-size: 16px;"
+                                type="checkbox"
+                                checked=true
+                                disabled=true
+                            />
+                            <label class="form-check-label" style="font-size: 14px;">
+                                {"Basic Profile "}
+                                <span class="badge bg-light text-dark" style="text-transform: uppercase; letter-spacing: 1px;">{"REQUIRED"}</span>
+                            </label>
+                        </div>
+                        { self.view_checkbox("Extended Profile", "Job title, department, office and manager.", options.ext_profile, false, Toggle::ExtendedProfile) }
+                        { self.view_checkbox("Groups", "Sync the groups the user belongs to at each login. Requires the Directory.Read.All permission.", options.ext_groups, false, Toggle::Groups) }
+                        { self.view_checkbox("Nested Groups", "Also sync the groups that contain the user's groups.", options.ext_nested_groups, !options.ext_groups, Toggle::NestedGroups) }
+                    </div>
+                </div>
+            </>
+        }
+    }
+}
+
+impl AzureAdForm {
+    fn view_checkbox(&self, label: &'static str, description: &'static str, checked: bool, locked: bool, toggle: Toggle) -> Html {
+        html! {
+            <div
+                class="form-check mb-2 d-flex align-items-center d-inline-flex"
+                style="width: 49%;"
+                title=description
+            >
+                <input
+                    class="form-check-input me-2 mt-0"
+                    style="font-size: 16px;"
+                    type="checkbox"
+                    checked=checked
+                    disabled={ locked || self.props.disabled }
+                    onclick=self.link.callback(move |_| Msg::Toggle(toggle))
+                />
+                <label class="form-check-label" style="font-size: 14px;">
+                    { label }
+                    <i class="bi bi-question-circle ms-1"></i>
+                </label>
+            </div>
+        }
+    }
+}
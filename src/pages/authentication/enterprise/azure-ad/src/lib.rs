@@ -0,0 +1,55 @@
+use yew::prelude::*;
+use router::AppRoute;
+use types::enterprise::AzureAdOptions;
+use enterprise_connection::{
+    create::ConnectionCreate,
+    list::ConnectionList,
+    settings::ConnectionSettings,
+    Icon,
+    Strategy,
+};
+
+pub mod form;
+
+use form::AzureAdForm;
+
+pub struct AzureAd;
+
+impl Strategy for AzureAd {
+    type Options = AzureAdOptions;
+
+    const LABEL: &'static str = "Microsoft Azure AD";
+    const ICON: Icon = Icon::Image("/assets/icons/azure-avatar.png");
+    const NAME_PLACEHOLDER: &'static str = "contoso-azure-ad";
+    const DOCS_URL: &'static str = "https://auth0.com/docs/connections/enterprise/azure-active-directory/v2";
+
+    fn list_route() -> AppRoute {
+        AppRoute::EnterpriseAzure
+    }
+
+    fn create_route() -> AppRoute {
+        AppRoute::EnterpriseAzureCreate
+    }
+
+    fn settings_route(connection_id: String) -> AppRoute {
+        AppRoute::EnterpriseAzureSettings { connection_id }
+    }
+
+    fn summary(options: &AzureAdOptions) -> String {
+        options.domain.clone()
+    }
+
+    fn view_form(options: AzureAdOptions, disabled: bool, on_change: Callback<AzureAdOptions>) -> Html {
+        html! {
+            <AzureAdForm
+                options=options
+                disabled=disabled
+                on_change=on_change
+            />
+        }
+    }
+}
+
+pub type EnterpriseAzure = ConnectionList<AzureAd>;
+pub type EnterpriseAzureCreate = ConnectionCreate<AzureAd>;
+pub type EnterpriseAzureSettings = ConnectionSettings<AzureAd>;
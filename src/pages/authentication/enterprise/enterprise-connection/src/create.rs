@@ -0,0 +1,329 @@
+use yew::{
+    prelude::*,
+    format::{Json, Text},
+    services::{
+        ConsoleService,
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{ Area, StorageService },
+    },
+    agent::Bridged,
+    Bridge,
+};
+use yew_router::{
+    agent::RouteRequest::ChangeRoute,
+    components::RouterAnchor,
+    prelude::*,
+};
+use router::AppRoute;
+use configs::server::API_URL;
+use types::{
+    database::{validate_connection_name, SYNC_ON_EACH_LOGIN, SYNC_ON_FIRST_LOGIN},
+    enterprise::{
+        validate_unclaimed_domains,
+        EnterpriseConnection,
+        StrategyConnection,
+        StrategyConnectionCreate,
+        StrategyOptions,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use home_realm_discovery::{domains::DomainsEditor, request_connections};
+use crate::{read_connection, Strategy};
+
+
+pub struct ConnectionCreate<S: Strategy> {
+    access_token: String,
+    link: ComponentLink<Self>,
+    new_connection: StrategyConnectionCreate<S::Options>,
+    fetch_task: Option<FetchTask>,
+    loading_create_connection: bool,
+    error_create_connection: Option<String>,
+    route_agent: Box<dyn Bridge<RouteAgent>>,
+}
+
+pub enum Msg<O> {
+    InputName(String),
+    InputOptions(O),
+    InputDomains(Vec<String>),
+    ToggleSyncUserProfile,
+    CreateConnection,
+    CheckDomains(Result<Vec<EnterpriseConnection>, String>),
+    RedirectToSettings(StrategyConnection<O>),
+    ResponseError(String),
+    Ignore,
+}
+
+impl<S: Strategy> Component for ConnectionCreate<S> {
+    type Message = Msg<S::Options>;
+    type Properties = ();
+
+    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        ConnectionCreate {
+            access_token,
+            new_connection: StrategyConnectionCreate::default(),
+            fetch_task: None,
+            loading_create_connection: false,
+            error_create_connection: None,
+            route_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::InputName(value) => {
+                self.new_connection.name = value;
+                false
+            }
+            Msg::InputOptions(options) => {
+                self.new_connection.options = options;
+                true
+            }
+            Msg::InputDomains(domains) => {
+                *self.new_connection.options.domain_aliases_mut() = domains;
+                true
+            }
+            Msg::ToggleSyncUserProfile => {
+                let set_user_root_attributes = self.new_connection.options.set_user_root_attributes_mut();
+                *set_user_root_attributes = if *set_user_root_attributes == SYNC_ON_FIRST_LOGIN {
+                    String::from(SYNC_ON_EACH_LOGIN)
+                } else {
+                    String::from(SYNC_ON_FIRST_LOGIN)
+                };
+                true
+            }
+            Msg::CreateConnection => {
+                // VALIDATION
+                let validation = validate_connection_name(&self.new_connection.name)
+                    .and_then(|_| self.new_connection.options.validate());
+                if let Err(message) = validation {
+                    self.link.send_message(Msg::ResponseError(message));
+                    return false;
+                }
+
+                self.fetch_task = Some(request_connections(&self.access_token, &self.link, Msg::CheckDomains));
+                self.error_create_connection = None;
+                self.loading_create_connection = true;
+                true
+            }
+            Msg::CheckDomains(data) => {
+                let validation = data
+                    .map_err(|error| format!("The domains couldn't be checked against the other connections: {}", error))
+                    .and_then(|connections| {
+                        validate_unclaimed_domains(self.new_connection.options.domain_aliases(), "", &connections)
+                    });
+                if let Err(message) = validation {
+                    self.link.send_message(Msg::ResponseError(message));
+                    return false;
+                }
+
+                let request = Request::post(format!("{}/api/v2/connections", API_URL))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&self.new_connection))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(|response: Response<Text>| {
+                    match read_connection(response) {
+                        Ok(dataok) => {
+                            ConsoleService::info(&format!("{:?}", dataok));
+                            Msg::RedirectToSettings(dataok)
+                        }
+                        Err(error) => Msg::ResponseError(error),
+                    }
+                });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                true
+            }
+            Msg::RedirectToSettings(connection) => {
+                self.loading_create_connection = false;
+                self.fetch_task = None;
+                self.route_agent.send(ChangeRoute(S::settings_route(connection.id).into()));
+                true
+            }
+            Msg::ResponseError(message) => {
+                self.loading_create_connection = false;
+                self.error_create_connection = Some(message);
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => {
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, _: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        let options = self.new_connection.options.clone();
+        html! {
+
+            <div
+                class="domain-content"
+            >
+                <Anchor
+                    route=S::list_route()
+                    classes="text-decoration-none domain-link-dark"
+                >
+                    <i class="bi bi-arrow-left me-2"></i>
+                    { format!("Back to {}", S::LABEL) }
+                </Anchor>
+
+                <div
+                    class="d-flex mb-5 mt-3 align-items-center"
+                >
+                    <div
+                        style="flex: 0 0 auto; width: 64px; height: 64px;"
+                        class="d-flex justify-content-center align-items-center rounded me-3 border"
+                    >
+                        { S::ICON.view("w-75", "fs-2") }
+                    </div>
+
+                    <div
+                        class="d-flex flex-column"
+                    >
+                        <h2
+                            class="title"
+                        >{ format!("New {} Connection", S::LABEL) }</h2>
+                    </div>
+                </div>
+
+                <div>
+                    <div
+                        class="container border rounded p-4 d-flex flex-column mb-5"
+                        style="font-size: 14px;"
+                    >
+                        <div
+                            class="row border-bottom"
+                        >
+                            <div
+                                class="col-lg-5 text-color-primary fw-bold mb-4"
+                            >
+                                {"General"}
+                            </div>
+                            <div
+                                class="col-lg-7"
+                            >
+                                <div
+                                    class="mb-4"
+                                >
+                                    <p class="mb-2 fw-bold">
+                                        {"Connection Name"}
+                                    </p>
+                                    <div class="input-group mb-2">
+                                        <input
+                                            type="text"
+                                            class="form-control bg-input-grey"
+                                            placeholder=S::NAME_PLACEHOLDER
+                                            value=self.new_connection.name.clone()
+                                            disabled=self.loading_create_connection
+                                            oninput=self.link.callback(|data: InputData| Msg::InputName(data.value))
+                                        />
+                                    </div>
+                                    <p
+                                        class="text-color-disabled"
+                                    >
+                                        {"Must start and end with an alphanumeric character and can only contain alphanumeric characters and '-'. Can't have more than 35 characters."}
+                                    </p>
+                                </div>
+
+                                { S::view_form(options.clone(), self.loading_create_connection, self.link.callback(Msg::InputOptions)) }
+
+                                <DomainsEditor
+                                    connection_id=String::from("")
+                                    domains=options.domain_aliases().to_vec()
+                                    disabled=self.loading_create_connection
+                                    on_change=self.link.callback(Msg::InputDomains)
+                                />
+
+                            </div>
+                        </div>
+
+                        <div
+                            class="row border-bottom mt-5"
+                        >
+                            <div
+                                class="col-lg-5 text-color-primary fw-bold mb-4"
+                            >
+                                {"Advanced"}
+                            </div>
+                            <div
+                                class="col-lg-7"
+                            >
+                                <div
+                                    class="mb-4"
+                                >
+                                    <p class="mb-2 fw-bold">
+                                        {"Sync user profile attributes at each login"}
+                                    </p>
+                                    <div class="form-check form-switch fs-3 mb-4">
+                                        <input
+                                            class="form-check-input"
+                                            type="checkbox"
+                                            checked={ options.set_user_root_attributes() != SYNC_ON_FIRST_LOGIN }
+                                            disabled=self.loading_create_connection
+                                            onclick=self.link.callback(|_| Msg::ToggleSyncUserProfile)
+                                        />
+                                    </div>
+                                </div>
+
+                                <button
+                                    type="button"
+                                    class=format!("btn {} btn-primary position-relative mb-5 mt-3", if self.loading_create_connection {"loading"} else {""} )
+                                    onclick=self.link.callback(|_| Msg::CreateConnection)
+                                    disabled=self.loading_create_connection
+                                >
+                                    <div class="telkom-label">
+                                        {"Create"}
+                                    </div>
+                                    <div class="telkom-spinner telkom-center">
+                                        <div class="spinner-border spinner-border-sm" role="status"/>
+                                    </div>
+                                </button>
+                                {
+                                    if self.error_create_connection.is_some() {
+                                        html! {
+                                            <div class="alert alert-warning mb-5" role="alert">
+                                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                                { self.error_create_connection.clone().unwrap() }
+                                            </div>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+
+                            </div>
+                        </div>
+
+                    </div>
+                </div>
+
+            </div>
+        }
+    }
+}
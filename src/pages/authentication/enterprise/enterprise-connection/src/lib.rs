@@ -0,0 +1,84 @@
+use yew::{
+    format::Text,
+    prelude::*,
+    services::fetch::Response,
+};
+use serde_json::Value;
+use router::AppRoute;
+use types::enterprise::{StrategyConnection, StrategyOptions};
+
+pub mod create;
+pub mod list;
+pub mod settings;
+pub mod tab_settings;
+
+// THE LIST, CREATE AND SETTINGS PAGES OF AN ENTERPRISE STRATEGY, ONLY THE
+// IDENTITY PROVIDER FORM AND THE LABELS DIFFER, eg.
+// pub type EnterpriseAzure = list::ConnectionList<AzureAd>;
+pub trait Strategy: 'static {
+    type Options: StrategyOptions;
+
+    const LABEL: &'static str;
+    const ICON: Icon;
+    const NAME_PLACEHOLDER: &'static str;
+    const DOCS_URL: &'static str;
+    // TITLE OF THE SECTION WITH THE FORM
+    const FORM_TITLE: &'static str = "General";
+
+    fn list_route() -> AppRoute;
+    fn create_route() -> AppRoute;
+    fn settings_route(connection_id: String) -> AppRoute;
+
+    // SECOND LINE OF THE CONNECTION IN THE LIST
+    fn summary(options: &Self::Options) -> String;
+
+    // IDENTITY PROVIDER SETTINGS, SHARED BY THE CREATE PAGE AND THE SETTINGS TAB
+    fn view_form(options: Self::Options, disabled: bool, on_change: Callback<Self::Options>) -> Html;
+
+    // AN EXTRA TAB OF THE SETTINGS PAGE, BETWEEN SETTINGS AND APPLICATIONS
+    fn extra_tab() -> Option<&'static str> {
+        None
+    }
+
+    fn view_extra_tab(_connection: &StrategyConnection<Self::Options>) -> Html {
+        html! {}
+    }
+}
+
+pub enum Icon {
+    // PATH OF AN IMAGE IN /assets
+    Image(&'static str),
+    // BOOTSTRAP ICON CLASS
+    Bootstrap(&'static str),
+}
+
+impl Icon {
+    // image_class SIZES AN IMAGE, icon_class A BOOTSTRAP ICON
+    pub fn view(&self, image_class: &'static str, icon_class: &'static str) -> Html {
+        match self {
+            Icon::Image(src) => html! {
+                <img
+                    src=*src
+                    class=image_class
+                />
+            },
+            Icon::Bootstrap(icon) => html! {
+                <i class=classes!("bi", *icon, icon_class)></i>
+            },
+        }
+    }
+}
+
+// THE CONNECTION A CREATE OR UPDATE RETURNED, OR THE MESSAGE THE API REJECTED
+// IT WITH, eg. A DOMAIN ANOTHER CONNECTION CLAIMED IN THE MEANTIME
+pub(crate) fn read_connection<O: StrategyOptions>(response: Response<Text>) -> Result<StrategyConnection<O>, String> {
+    let (meta, body) = response.into_parts();
+    let body = body.map_err(|error| error.to_string())?;
+    if meta.status.is_success() {
+        return serde_json::from_str(&body).map_err(|error| error.to_string());
+    }
+    Err(serde_json::from_str::<Value>(&body)
+        .ok()
+        .and_then(|data| data.get("message").and_then(Value::as_str).map(String::from))
+        .unwrap_or_else(|| format!("The request failed ({})", meta.status)))
+}
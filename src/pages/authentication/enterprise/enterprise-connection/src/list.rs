@@ -0,0 +1,346 @@
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+    },
+};
+use yew_router::components::RouterAnchor;
+use router::AppRoute;
+use types::{
+    enterprise::{StrategyConnection, StrategyOptions},
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use configs::server::API_URL;
+use loading::Loading;
+use crate::Strategy;
+
+type Connections<O> = Vec<StrategyConnection<O>>;
+
+pub enum StateError {
+    RequestConnections,
+}
+
+pub struct ConnectionList<S: Strategy> {
+    access_token: String,
+    connections: Connections<S::Options>,
+    link: ComponentLink<Self>,
+    fetch_task: Option<FetchTask>,
+    loading_request_connections: bool,
+    error_request_connections: Option<String>,
+}
+
+pub enum Msg<O> {
+    RequestConnections,
+    GetConnections(Connections<O>),
+    ResponseError(String, StateError),
+}
+
+impl<S: Strategy> Component for ConnectionList<S> {
+    type Message = Msg<S::Options>;
+    type Properties = ();
+
+    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        ConnectionList {
+            access_token,
+            connections: vec![],
+            link,
+            fetch_task: None,
+            loading_request_connections: false,
+            error_request_connections: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestConnections);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestConnections => {
+                let request = Request::get(format!("{}/api/v2/connections?strategy={}", API_URL, S::Options::STRATEGY))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Connections<S::Options>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetConnections(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::RequestConnections)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_connections = None;
+                self.loading_request_connections = true;
+                true
+            }
+            Msg::GetConnections(data) => {
+                self.connections = data;
+                self.fetch_task = None;
+                self.loading_request_connections = false;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::RequestConnections => {
+                        self.loading_request_connections = false;
+                        self.error_request_connections = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, _: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        html! {
+            <div
+                class="domain-content"
+            >
+                <Anchor
+                    route=AppRoute::EnterpriseHome
+                    classes="text-decoration-none domain-link-dark"
+                >
+                    <i class="bi bi-arrow-left me-2"></i>
+                    {"Back to Enterprise Connections"}
+                </Anchor>
+
+                <div
+                    class="d-flex flex-row mb-4 mt-3"
+                >
+                    <div
+                        class="flex-fill"
+                    >
+                        <h2>{ S::LABEL }</h2>
+                    </div>
+                    {
+                        if self.connections.is_empty() {
+                            html! {}
+                        } else {
+                            html! {
+                                <div>
+                                    <Anchor
+                                        route=S::create_route()
+                                        classes="btn btn-primary d-flex align-items-center"
+                                    >
+                                        <i class="bi bi-plus me-2" style="margin-left: -5px;"></i>
+                                        <span>{"Create Connection"}</span>
+                                    </Anchor>
+                                </div>
+                            }
+                        }
+                    }
+                </div>
+
+                {
+                    if self.loading_request_connections {
+                        html! {
+                            <div
+                                style="
+                                    position: relative;
+                                    margin-top: 8rem;
+                                "
+                            >
+                                <Loading width=45 />
+                            </div>
+                        }
+                    } else if self.error_request_connections.is_some() {
+                        html! {
+                            <div class="alert alert-warning mb-5" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_request_connections.clone().unwrap() }
+                            </div>
+                        }
+                    } else if self.connections.is_empty() {
+                        self.view_empty()
+                    } else {
+                        html! {
+                            <div>
+                                { self.view_list() }
+                            </div>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+}
+
+impl<S: Strategy> ConnectionList<S> {
+    fn view_empty(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        html! {
+            <div style="
+                display: flex;
+                text-align: center;
+                align-items: center;
+                flex-direction: column;
+                padding: 40px;
+                border-radius: 6px;
+                border: 1px solid #e3e4e6;
+                "
+            >
+
+                <i class="bi bi-briefcase text-color-secondary" style="font-size:150px; opacity:.5;"></i>
+
+                <div>
+                    {"No items have been added to this section."}
+                </div>
+                <button
+                    class="btn btn-primary"
+                    style=" color: #fff;
+                            background-color: #635dff;
+                            box-shadow: none;
+                            border-radius: 4px;
+                            padding: 8px 16px;
+                            margin: 20px"
+                >
+                    <Anchor
+                        route=S::create_route()
+                        classes="text-decoration-none text-light px-2 link-primary pe-auto"
+                        >
+                            {"+ Create Connection"}
+                    </Anchor>
+                </button>
+                <a
+                    href=S::DOCS_URL
+                    target="_blank"
+                    style="text-decoration: none;"
+                >{"Learn More"}</a>
+            </div>
+        }
+    }
+
+    fn view_list(&self) -> Vec<Html> {
+        type Anchor = RouterAnchor<AppRoute>;
+        self.connections.iter().map(|connection| {
+            let StrategyConnection {
+                id,
+                name,
+                strategy: _,
+                enabled_clients,
+                options,
+            } = connection.clone();
+            html! {
+                <div
+                    class="d-flex border-bottom border-1 list-hover"
+                >
+                    <div
+                        class="p-3 d-flex"
+                        style="width: 40%;"
+                    >
+                        <div
+                            style="flex: 0 0 auto; width: 40px; height: 40px;"
+                            class="d-flex justify-content-center align-items-center rounded me-3 border"
+                        >
+                            { S::ICON.view("w-50", "") }
+                        </div>
+
+                        <div
+                            class="d-grid"
+                            style="min-width: 40px;"
+                        >
+                            <Anchor
+                                route=S::settings_route(id.clone())
+                                classes="text-decoration-none fw-bold mb-0"
+                            >
+                                <span
+                                    style="
+                                        white-space: nowrap;
+                                        text-overflow: ellipsis;
+                                        overflow: hidden;
+                                        font-size: 14px;
+                                        text-decoration: none;
+                                    "
+                                >
+                                    { name.clone() }
+                                </span>
+                            </Anchor>
+                            <p
+                                class="mb-0 text-muted"
+                                style="
+                                    white-space: nowrap;
+                                    text-overflow: ellipsis;
+                                    overflow: hidden;
+                                    font-size: 14px;
+                                "
+                            >
+                                { S::summary(&options) }
+                            </p>
+                        </div>
+                    </div>
+
+                    <div
+                        class="p-3 d-flex flex-fill align-items-center text-muted"
+                        style="font-size: 14px;"
+                    >
+                        {
+                            match enabled_clients.len() {
+                                0 => String::from("No applications enabled"),
+                                1 => String::from("1 application enabled"),
+                                count => format!("{} applications enabled", count),
+                            }
+                        }
+                    </div>
+
+                    <div
+                        class="p-3 d-flex align-items-center dropdown"
+                    >
+                        <button
+                            type="button"
+                            style="flex: 0 0 auto; width: 30px; height: 30px;"
+                            class="btn d-flex justify-content-center align-items-center rounded border"
+                            role="button"
+                            data-bs-toggle="dropdown"
+                            aria-expanded="false"
+                        >
+                            <i class="bi bi-three-dots"></i>
+                        </button>
+                        <ul class="dropdown-menu">
+                            <li>
+                                <Anchor route=S::settings_route(id.clone()) classes="dropdown-item fs-7">
+                                    {"Settings"}
+                                </Anchor>
+                            </li>
+                        </ul>
+                    </div>
+                </div>
+            }
+        })
+        .collect()
+    }
+}
@@ -0,0 +1,327 @@
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+        ConsoleService,
+    },
+};
+use yew_router::components::RouterAnchor;
+use router::AppRoute;
+use types::{
+    enterprise::{StrategyConnection, StrategyOptions},
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use configs::server::API_URL;
+use loading::Loading;
+use enterprise_applications::EnterpriseApplications;
+use crate::{
+    tab_settings::TabSettings,
+    Strategy,
+};
+
+pub enum Content {
+    Settings,
+    // Strategy::extra_tab
+    Extra,
+    Applications
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ConnectionSettingsProps {
+    pub connection_id: String,
+}
+
+pub enum StateError {
+    RequestConnection,
+}
+
+pub struct ConnectionSettings<S: Strategy> {
+    access_token: String,
+    content: Content,
+    connection_id: String,
+    connection: StrategyConnection<S::Options>,
+    link: ComponentLink<Self>,
+    fetch_task: Option<FetchTask>,
+    loading_request_connection: bool,
+    error_request_connection: Option<String>,
+}
+
+pub enum Msg<O> {
+    ChangeContent(Content),
+    RequestConnection,
+    GetConnection(StrategyConnection<O>),
+    GetEnabledClients(Vec<String>),
+    ResponseError(String, StateError),
+}
+
+impl<S: Strategy> Component for ConnectionSettings<S> {
+    type Message = Msg<S::Options>;
+    type Properties = ConnectionSettingsProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        ConnectionSettings {
+            access_token,
+            content: Content::Settings,
+            connection_id: props.connection_id,
+            connection: StrategyConnection::default(),
+            link,
+            fetch_task: None,
+            loading_request_connection: false,
+            error_request_connection: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestConnection);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ChangeContent(content) => {
+                self.content = content;
+                true
+            }
+            Msg::RequestConnection => {
+                let request = Request::get(format!("{}/api/v2/connections/{}", API_URL, self.connection_id.clone()))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<StrategyConnection<S::Options>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => {
+                                ConsoleService::info(&format!("connection details = {:?}", dataok));
+                                Msg::GetConnection(dataok)
+                            }
+                            Err(error) => Msg::ResponseError(
+                                error.to_string(),
+                                StateError::RequestConnection,
+                            ),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_connection = None;
+                self.loading_request_connection = true;
+                true
+            }
+            Msg::GetConnection(data) => {
+                // ALSO RECEIVES THE SAVED CONNECTION FROM THE SETTINGS TAB
+                self.connection = data;
+                self.fetch_task = None;
+                self.loading_request_connection = false;
+                true
+            }
+            Msg::GetEnabledClients(enabled_clients) => {
+                self.connection.enabled_clients = enabled_clients;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::RequestConnection => {
+                        self.loading_request_connection = false;
+                        self.error_request_connection = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, _: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        if self.loading_request_connection {
+            html! {
+                <div
+                    style="
+                        position: relative;
+                        margin-top: 8rem;
+                    "
+                >
+                    <Loading width=45 />
+                </div>
+            }
+        } else if self.error_request_connection.is_some() {
+            html! {
+                <div class="domain-content">
+                    <div class="alert alert-warning mb-5" role="alert">
+                        <i class="bi bi-exclamation-triangle me-2"></i>
+                        { self.error_request_connection.clone().unwrap() }
+                    </div>
+                </div>
+            }
+        } else {
+            self.view_content()
+        }
+    }
+}
+
+impl<S: Strategy> ConnectionSettings<S> {
+    fn view_content(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        // A CONNECTION THAT CAN'T SIGN USERS IN STAYS DISABLED FOR EVERY APPLICATION
+        let problems = self.connection.options.problems();
+        html! {
+            <div
+                class="domain-content"
+            >
+                <Anchor
+                    route=S::list_route()
+                    classes="text-decoration-none domain-link-dark"
+                >
+                    <i class="bi bi-arrow-left me-2"></i>
+                    { format!("Back to {}", S::LABEL) }
+                </Anchor>
+
+                <div
+                    class="d-flex mb-5 mt-3"
+                >
+                    <div
+                        style="flex: 0 0 auto; width: 64px; height: 64px;"
+                        class="d-flex justify-content-center align-items-center rounded me-4 border"
+                    >
+                        { S::ICON.view("w-50", "fs-3") }
+                    </div>
+
+                    <div
+                        class="d-flex flex-column"
+                    >
+                        <h2
+                            class="title"
+                        >{ self.connection.name.clone() }</h2>
+                        <div
+                            class="text-muted"
+                        >
+                            <span
+                                class="me-4"
+                            >
+                                { S::LABEL }
+                            </span>
+                            <span>
+                                {"Identifier"}
+                            </span>
+                            <span
+                                class="rounded ms-2"
+                                style="
+                                    background-color: #eff0f2;
+                                    white-space: nowrap;
+                                    text-overflow: ellipsis;
+                                    overflow: hidden;
+                                    font-size: 14px;
+                                    padding: 2px 6px;
+                                    font-family: 'Roboto Mono', monospace;
+                                "
+                            >
+                                { self.connection.id.clone() }
+                            </span>
+                        </div>
+                    </div>
+                </div>
+
+                <div
+                    class="mb-4"
+                >
+                    <ul class="nav nav-tabs">
+                        <li
+                            onclick=self.link.callback(|_| Msg::ChangeContent(Content::Settings))
+                            class="nav-item"
+                        >
+                        <a
+                            class={
+                                match self.content {
+                                    Content::Settings => "nav-link active",
+                                    _ => "nav-link"
+                                }
+                            }
+                            aria-current="page"
+                            href="#"
+                        >
+                            {"Settings"}</a>
+                        </li>
+                        {
+                            match S::extra_tab() {
+                                Some(label) => html! {
+                                    <li
+                                        onclick=self.link.callback(|_| Msg::ChangeContent(Content::Extra))
+                                        class="nav-item">
+                                    <a
+                                        class={
+                                            match self.content {
+                                                Content::Extra => "nav-link active",
+                                                _ => "nav-link"
+                                            }
+                                        }
+                                        href="#">{ label }</a>
+                                    </li>
+                                },
+                                None => html! {},
+                            }
+                        }
+                        <li
+                            onclick=self.link.callback(|_| Msg::ChangeContent(Content::Applications))
+                            class="nav-item">
+                        <a
+                            class={
+                                match self.content {
+                                    Content::Applications => "nav-link active",
+                                    _ => "nav-link"
+                                }
+                            }
+                            href="#">{"Applications"}</a>
+                        </li>
+                    </ul>
+                </div>
+
+                {
+                    match self.content {
+                        Content::Applications => html! {
+                            <EnterpriseApplications
+                                connection_id=self.connection.id.clone()
+                                enabled_clients=self.connection.enabled_clients.clone()
+                                problems=problems
+                                on_update=self.link.callback(Msg::GetEnabledClients)
+                            />
+                        },
+                        Content::Extra => S::view_extra_tab(&self.connection),
+                        Content::Settings => html! {
+                            <TabSettings<S>
+                                connection=self.connection.clone()
+                                on_update=self.link.callback(Msg::GetConnection)
+                            />
+                        },
+                    }
+                }
+            </div>
+        }
+    }
+}
@@ -0,0 +1,418 @@
+use yew::{
+    format::{Json, Nothing, Text},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+        ConsoleService,
+    },
+    agent::Bridged,
+    Bridge,
+};
+use yew_router::{
+    agent::RouteRequest::ChangeRoute,
+    prelude::*,
+};
+use configs::server::API_URL;
+use types::{
+    database::{SYNC_ON_EACH_LOGIN, SYNC_ON_FIRST_LOGIN},
+    enterprise::{
+        validate_unclaimed_domains,
+        EnterpriseConnection,
+        StrategyConnection,
+        StrategyConnectionUpdate,
+        StrategyOptions,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use home_realm_discovery::{domains::DomainsEditor, request_connections};
+use crate::{read_connection, Strategy};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TabSettingsProps<O: StrategyOptions> {
+    pub connection: StrategyConnection<O>,
+    pub on_update: Callback<StrategyConnection<O>>,
+}
+
+pub enum StateError {
+    Update,
+    Delete,
+}
+
+pub struct TabSettings<S: Strategy> {
+    access_token: String,
+    link: ComponentLink<Self>,
+    connection: StrategyConnection<S::Options>,
+    on_update: Callback<StrategyConnection<S::Options>>,
+    fetch_task: Option<FetchTask>,
+    loading_update: bool,
+    error_update: Option<String>,
+    message_update: Option<String>,
+    loading_delete: bool,
+    error_delete: Option<String>,
+    route_agent: Box<dyn Bridge<RouteAgent>>,
+}
+
+pub enum Msg<O> {
+    InputOptions(O),
+    InputDomains(Vec<String>),
+    ToggleSyncUserProfile,
+    Update,
+    CheckDomains(Result<Vec<EnterpriseConnection>, String>),
+    GetConnection(StrategyConnection<O>),
+    Delete,
+    RedirectToConnections,
+    ResponseError(String, StateError),
+    Ignore,
+}
+
+impl<S: Strategy> Component for TabSettings<S> {
+    type Message = Msg<S::Options>;
+    type Properties = TabSettingsProps<S::Options>;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        TabSettings {
+            access_token,
+            connection: props.connection,
+            on_update: props.on_update,
+            fetch_task: None,
+            loading_update: false,
+            error_update: None,
+            message_update: None,
+            loading_delete: false,
+            error_delete: None,
+            route_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::InputOptions(options) => {
+                self.connection.options = options;
+                self.message_update = None;
+                true
+            }
+            Msg::InputDomains(domains) => {
+                *self.connection.options.domain_aliases_mut() = domains;
+                self.message_update = None;
+                true
+            }
+            Msg::ToggleSyncUserProfile => {
+                let set_user_root_attributes = self.connection.options.set_user_root_attributes_mut();
+                *set_user_root_attributes = if *set_user_root_attributes == SYNC_ON_FIRST_LOGIN {
+                    String::from(SYNC_ON_EACH_LOGIN)
+                } else {
+                    String::from(SYNC_ON_FIRST_LOGIN)
+                };
+                self.message_update = None;
+                true
+            }
+            Msg::Update => {
+                // VALIDATION
+                if let Err(message) = self.connection.options.validate() {
+                    self.link.send_message(Msg::ResponseError(message, StateError::Update));
+                    return false;
+                }
+
+                self.fetch_task = Some(request_connections(&self.access_token, &self.link, Msg::CheckDomains));
+                self.error_update = None;
+                self.message_update = None;
+                self.loading_update = true;
+                true
+            }
+            Msg::CheckDomains(data) => {
+                let validation = data
+                    .map_err(|error| format!("The domains couldn't be checked against the other connections: {}", error))
+                    .and_then(|connections| {
+                        validate_unclaimed_domains(self.connection.options.domain_aliases(), &self.connection.id, &connections)
+                    });
+                if let Err(message) = validation {
+                    self.link.send_message(Msg::ResponseError(message, StateError::Update));
+                    return false;
+                }
+
+                let data_update_connection = StrategyConnectionUpdate::from(&self.connection);
+                let request = Request::patch(format!("{}/api/v2/connections/{}", API_URL, self.connection.id.clone()))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_update_connection))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(|response: Response<Text>| {
+                    match read_connection(response) {
+                        Ok(dataok) => Msg::GetConnection(dataok),
+                        Err(error) => Msg::ResponseError(error, StateError::Update),
+                    }
+                });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                true
+            }
+            Msg::GetConnection(data) => {
+                ConsoleService::info(&format!("connection = {:?}", data));
+                self.connection = data.clone();
+                self.on_update.emit(data);
+                self.loading_update = false;
+                self.message_update = Some(String::from("Connection settings have been saved"));
+                self.fetch_task = None;
+                true
+            }
+            Msg::Delete => {
+                let request = Request::delete(format!("{}/api/v2/connections/{}", API_URL, self.connection.id.clone()))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(|response: Response<Json<Result<(), anyhow::Error>>>| {
+                    let (meta, Json(data)) = response.into_parts();
+                    let status_number = meta.status.as_u16();
+
+                    match status_number {
+                        204 => {
+                            Msg::RedirectToConnections
+                        }
+                        _ => {
+                            match data {
+                                Ok(_) => {
+                                    Msg::RedirectToConnections
+                                }
+                                Err(error) => {
+                                    Msg::ResponseError(error.to_string(), StateError::Delete)
+                                }
+                            }
+                        }
+                    }
+                });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.loading_delete = true;
+                self.error_delete = None;
+                self.fetch_task = Some(task);
+                true
+            }
+            Msg::RedirectToConnections => {
+                self.loading_delete = false;
+                self.fetch_task = None;
+                self.route_agent.send(ChangeRoute(S::list_route().into()));
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::Update => {
+                        self.loading_update = false;
+                        self.error_update = Some(message);
+                    }
+                    StateError::Delete => {
+                        self.loading_delete = false;
+                        self.error_delete = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => {
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.on_update = props.on_update;
+        if self.connection.id != props.connection.id {
+            self.connection = props.connection;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let options = self.connection.options.clone();
+        html! {
+            <div>
+
+                <div
+                    class="container border rounded p-4 d-flex flex-column mb-5"
+                    style="font-size: 14px;"
+                >
+                    <div
+                        class="row border-bottom"
+                    >
+                        <div
+                            class="col-lg-5 text-color-primary fw-bold mb-4"
+                            style="width: 40%;"
+                        >
+                            { S::FORM_TITLE }
+                        </div>
+                        <div
+                            class="col-lg-7"
+                        >
+                            <div
+                                class="mb-4"
+                            >
+                                <p class="mb-2 fw-bold">
+                                    {"Connection Name"}
+                                </p>
+                                <div class="input-group mb-2">
+                                    <input
+                                        type="text"
+                                        class="form-control bg-input-grey"
+                                        value=self.connection.name.clone()
+                                        readonly=true
+                                    />
+                                </div>
+                                <p
+                                    class="text-color-disabled"
+                                >
+                                    {"If you are triggering a login manually, this is the identifier you would use on the connection parameter."}
+                                </p>
+                            </div>
+
+                            { S::view_form(options.clone(), self.loading_update, self.link.callback(Msg::InputOptions)) }
+
+                            <DomainsEditor
+                                connection_id=self.connection.id.clone()
+                                domains=options.domain_aliases().to_vec()
+                                disabled=self.loading_update
+                                on_change=self.link.callback(Msg::InputDomains)
+                            />
+
+                        </div>
+                    </div>
+
+                    <div
+                        class="row border-bottom mt-5"
+                    >
+                        <div
+                            class="col-lg-5 text-color-primary fw-bold mb-4"
+                            style="width: 40%;"
+                        >
+                            {"Advanced"}
+                        </div>
+                        <div
+                            class="col-lg-7"
+                        >
+                            <div
+                                class="mb-4"
+                            >
+                                <p class="mb-2 fw-bold">
+                                    {"Sync user profile attributes at each login"}
+                                </p>
+                                <div class="form-check form-switch fs-3 mb-4">
+                                    <input
+                                        class="form-check-input"
+                                        type="checkbox"
+                                        checked={ options.set_user_root_attributes() != SYNC_ON_FIRST_LOGIN }
+                                        disabled={ self.loading_update }
+                                        onclick=self.link.callback(|_| Msg::ToggleSyncUserProfile)
+                                    />
+                                </div>
+                            </div>
+
+                            <button
+                                type="button"
+                                class=format!("btn {} btn-primary position-relative mb-5 mt-3", if self.loading_update {"loading"} else {""} )
+                                onclick=self.link.callback(|_| Msg::Update)
+                                disabled={ self.loading_update }
+                            >
+                                <div class="telkom-label">
+                                    {"Save Changes"}
+                                </div>
+                                <div class="telkom-spinner telkom-center">
+                                    <div class="spinner-border spinner-border-sm" role="status"/>
+                                </div>
+                            </button>
+                            {
+                                if self.error_update.is_some() {
+                                    html! {
+                                        <div class="alert alert-warning mb-5" role="alert">
+                                            <i class="bi bi-exclamation-triangle me-2"></i>
+                                            { self.error_update.clone().unwrap() }
+                                        </div>
+                                    }
+                                } else if self.message_update.is_some() {
+                                    html! {
+                                        <div class="alert alert-success mb-5" role="alert">
+                                            <i class="bi bi-check-circle me-2"></i>
+                                            { self.message_update.clone().unwrap() }
+                                        </div>
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
+
+                        </div>
+                    </div>
+
+                </div>
+
+                <div
+                    style="font-size: 14px;"
+                >
+                    <p
+                        class="fw-bold"
+                    >
+                        {"Danger Zone"}
+                    </p>
+
+                    <div class="alert alert-danger d-flex flex-row justify-content-between" role="alert">
+                        <div>
+                            <p
+                                class="fw-bold"
+                            >
+                                {"Delete Connection"}
+                            </p>
+                            {"Once confirmed, this operation can't be undone!"}
+                        </div>
+                        <div>
+                            <button
+                                type="button"
+                                class=format!("btn {} btn-danger position-relative", if self.loading_delete {"loading"} else {""} )
+                                onclick=self.link.callback(|_| Msg::Delete)
+                                disabled={ self.loading_delete }
+                            >
+                                <div class="telkom-label">
+                                    {"Delete"}
+                                </div>
+                                <div class="telkom-spinner telkom-center">
+                                    <div class="spinner-border spinner-border-sm" role="status"/>
+                                </div>
+                            </button>
+                        </div>
+                    </div>
+                    {
+                        if self.error_delete.is_some() {
+                            html! {
+                                <div class="alert alert-warning" role="alert">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    { self.error_delete.clone().unwrap() }
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </div>
+            </div>
+        }
+    }
+}
@@ -2,6 +2,7 @@ use yew::prelude::*;
 use yew_router::components::RouterAnchor;
 // use crate::app::AppRoute;
 use router::AppRoute;
+use home_realm_discovery::preview::HomeRealmPreview;
 
 pub struct EnterpriseHome {
     learn_more: bool,
@@ -188,6 +189,8 @@ impl Component for EnterpriseHome {
                     {"This feature is not included in your current plan. Upgrade your Subscription Enterprise connections in production."}
                 </div>
 
+                <HomeRealmPreview/>
+
                 <div>
                     <Anchor
                         route=AppRoute::EnterpriseGoogle
@@ -252,7 +255,7 @@ impl Component for EnterpriseHome {
                     </Anchor>
 
                     <Anchor
-                        route=AppRoute::EnterpriseGoogle
+                        route=AppRoute::EnterpriseAzure
                         classes="d-flex border-bottom border-1 list-hover justify-content-between pe-auto text-decoration-none"
                     >
                         <div
@@ -304,7 +307,125 @@ impl Component for EnterpriseHome {
                             </button>
                             <ul class="dropdown-menu" aria-labelledby="dropdownMenuButton1">
                                 <li>
-                                    <Anchor route=AppRoute::EnterpriseGoogle classes="dropdown-item fs-7">
+                                    <Anchor route=AppRoute::EnterpriseAzure classes="dropdown-item fs-7">
+                                        {"Settings"}
+                                    </Anchor>
+                                </li>
+                            </ul>
+                        </div>
+
+                    </Anchor>
+
+                    <Anchor
+                        route=AppRoute::EnterpriseSaml
+                        classes="d-flex border-bottom border-1 list-hover justify-content-between pe-auto text-decoration-none"
+                    >
+                        <div
+                            class="p-3 d-flex"
+                            style="width: 40%;"
+                        >
+                            <div
+                                style="flex: 0 0 auto; width: 40px; height: 40px;"
+                                class="d-flex justify-content-center align-items-center rounded me-3 border"
+                            >
+                                <i class="bi bi-shield-lock"></i>
+                            </div>
+
+                            <div
+                                class="d-grid align-items-center"
+                                style="min-width: 40px;"
+                            >
+                                <span
+                                    class="fw-bold mb-0"
+                                    style="
+                                        white-space: nowrap;
+                                        text-overflow: ellipsis;
+                                        overflow: hidden;
+                                        font-size: 14px;
+                                    "
+                                >
+                                    {"SAML"}
+                                </span>
+                            </div>
+            
+                        </div>
+
+                        <div
+                            class="p-3 d-flex align-items-center dropdown"
+                        >
+                            <button
+                                type="button"
+                                style="flex: 0 0 auto; width: 30px; height: 30px;"
+                                class="btn d-flex justify-content-center align-items-center rounded border"
+                                role="button"
+                                id="dropdownMenuButton3"
+                                data-bs-toggle="dropdown"
+                                aria-expanded="false"
+                            >
+                                <i class="bi bi-plus"></i>
+                            </button>
+                            <ul class="dropdown-menu" aria-labelledby="dropdownMenuButton3">
+                                <li>
+                                    <Anchor route=AppRoute::EnterpriseSaml classes="dropdown-item fs-7">
+                                        {"Settings"}
+                                    </Anchor>
+                                </li>
+                            </ul>
+                        </div>
+
+                    </Anchor>
+
+                    <Anchor
+                        route=AppRoute::EnterpriseOidc
+                        classes="d-flex border-bottom border-1 list-hover justify-content-between pe-auto text-decoration-none"
+                    >
+                        <div
+                            class="p-3 d-flex"
+                            style="width: 40%;"
+                        >
+                            <div
+                                style="flex: 0 0 auto; width: 40px; height: 40px;"
+                                class="d-flex justify-content-center align-items-center rounded me-3 border"
+                            >
+                                <i class="bi bi-person-badge"></i>
+                            </div>
+
+                            <div
+                                class="d-grid align-items-center"
+                                style="min-width: 40px;"
+                            >
+                                <span
+                                    class="fw-bold mb-0"
+                                    style="
+                                        white-space: nowrap;
+                                        text-overflow: ellipsis;
+                                        overflow: hidden;
+                                        font-size: 14px;
+                                    "
+                                >
+                                    {"OpenID Connect"}
+                                </span>
+                            </div>
+            
+                        </div>
+
+                        <div
+                            class="p-3 d-flex align-items-center dropdown"
+                        >
+                            <button
+                                type="button"
+                                style="flex: 0 0 auto; width: 30px; height: 30px;"
+                                class="btn d-flex justify-content-center align-items-center rounded border"
+                                role="button"
+                                id="dropdownMenuButton4"
+                                data-bs-toggle="dropdown"
+                                aria-expanded="false"
+                            >
+                                <i class="bi bi-plus"></i>
+                            </button>
+                            <ul class="dropdown-menu" aria-labelledby="dropdownMenuButton4">
+                                <li>
+                                    <Anchor route=AppRoute::EnterpriseOidc classes="dropdown-item fs-7">
                                         {"Settings"}
                                     </Anchor>
                                 </li>
@@ -0,0 +1,216 @@
+use yew::prelude::*;
+use types::enterprise::GoogleAppsOptions;
+
+// GENERAL SETTINGS OF A GOOGLE WORKSPACE CONNECTION, SHARED BY THE CREATE
+// PAGE AND THE SETTINGS TAB
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct GoogleAppsFormProps {
+    pub options: GoogleAppsOptions,
+    pub disabled: bool,
+    pub on_change: Callback<GoogleAppsOptions>,
+}
+
+pub enum Data {
+    Domain,
+    ClientId,
+    ClientSecret,
+}
+
+#[derive(Clone, Copy)]
+pub enum Toggle {
+    Groups,
+    Suspended,
+}
+
+pub struct GoogleAppsForm {
+    props: GoogleAppsFormProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    Input(String, Data),
+    Toggle(Toggle),
+}
+
+impl Component for GoogleAppsForm {
+    type Message = Msg;
+    type Properties = GoogleAppsFormProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        GoogleAppsForm {
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut options = self.props.options.clone();
+        match msg {
+            Msg::Input(value, data) => {
+                match data {
+                    Data::Domain => options.domain = value.trim().to_string(),
+                    Data::ClientId => options.client_id = value.trim().to_string(),
+                    Data::ClientSecret => options.client_secret = value,
+                }
+            }
+            Msg::Toggle(toggle) => {
+                match toggle {
+                    Toggle::Groups => options.ext_groups = !options.ext_groups,
+                    Toggle::Suspended => options.ext_is_suspended = !options.ext_is_suspended,
+                }
+            }
+        }
+        self.props.on_change.emit(options);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let options = self.props.options.clone();
+        let disabled = self.props.disabled;
+        html! {
+            <>
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Google Workspace Domain"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="text"
+                            class="form-control"
+                            placeholder="acme.com"
+                            value=options.domain.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::Domain))
+                        />
+                    </div>
+                    <p
+                        class="text-color-disabled"
+                    >
+                        {"The primary domain of your Google Workspace account."}
+                    </p>
+                </div>
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Client ID"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="text"
+                            class="form-control"
+                            placeholder="Leave blank to use telkom-dev keys"
+                            value=options.client_id.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::ClientId))
+                        />
+                    </div>
+                    <a
+                        href="https://developers.google.com/identity/protocols/oauth2/web-server#creatingcred"
+                        target="_blank"
+                        class="text-decoration-none"
+                    >
+                        <span
+                            style="
+                                white-space: nowrap;
+                                text-overflow: ellipsis;
+                                overflow: hidden;
+                                font-size: 14px;
+                                text-decoration: none;
+                            "
+                        >
+                            {"How to obtain a Client ID?"}
+                        </span>
+                    </a>
+                </div>
+
+                <div
+                    class="mb-5"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Client Secret"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="password"
+                            class="form-control"
+                            autocomplete="new-password"
+                            placeholder="Leave blank to use telkom-dev keys"
+                            value=options.client_secret.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::ClientSecret))
+                        />
+                    </div>
+                    <p
+                        class="text-color-disabled"
+                    >
+                        {"The development keys are for testing only, set your own before going to production."}
+                    </p>
+                </div>
+
+                <div
+                    class="mb-5"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Attributes"}
+                    </p>
+                    <div>
+                        <div class="form-check mb-2 d-flex align-items-center d-inline-flex" style="width: 49%;">
+                            <input
+                                class="form-check-input me-2 mt-0"
+                                style="font-size: 16px;"
+                                type="checkbox"
+                                checked=true
+                                disabled=true
+                            />
+                            <label class="form-check-label" style="font-size: 14px;">
+                                {"Basic Profile "}
+                                <span class="badge bg-light text-dark" style="text-transform: uppercase; letter-spacing: 1px;">{"REQUIRED"}</span>
+                            </label>
+                        </div>
+                        { self.view_checkbox("Groups", "Sync the groups the user belongs to at each login. Requires the Admin SDK API.", options.ext_groups, Toggle::Groups) }
+                        { self.view_checkbox("Block Suspended Users", "Users suspended in Google Workspace can't log in.", options.ext_is_suspended, Toggle::Suspended) }
+                    </div>
+                </div>
+            </>
+        }
+    }
+}
+
+impl GoogleAppsForm {
+    fn view_checkbox(&self, label: &'static str, description: &'static str, checked: bool, toggle: Toggle) -> Html {
+        html! {
+            <div
+                class="form-check mb-2 d-flex align-items-center d-inline-flex"
+                style="width: 49%;"
+                title=description
+            >
+                <input
+                    class="form-check-input me-2 mt-0"
+                    style="font-size: 16px;"
+                    type="checkbox"
+                    checked=checked
+                    disabled=self.props.disabled
+                    onclick=self.link.callback(move |_| Msg::Toggle(toggle))
+                />
+                <label class="form-check-label" style="font-size: 14px;">
+                    { label }
+                    <i class="bi bi-question-circle ms-1"></i>
+                </label>
+            </div>
+        }
+    }
+}
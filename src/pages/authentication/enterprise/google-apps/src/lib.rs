@@ -1,96 +1,55 @@
 use yew::prelude::*;
-use yew_router::components::RouterAnchor;
-// use crate::app::AppRoute;
 use router::AppRoute;
+use types::enterprise::GoogleAppsOptions;
+use enterprise_connection::{
+    create::ConnectionCreate,
+    list::ConnectionList,
+    settings::ConnectionSettings,
+    Icon,
+    Strategy,
+};
 
-pub struct EnterpriseGoogle {
-    // link: ComponentLink<Self>
-}
+pub mod form;
 
-pub enum Msg {}
+use form::GoogleAppsForm;
 
-impl Component for EnterpriseGoogle {
-    type Message = Msg;
-    type Properties = ();
+pub struct GoogleApps;
 
-    fn create(_: Self::Properties, _link: ComponentLink<Self>) -> Self {
-        EnterpriseGoogle {
-            // link
-        }
-    }
+impl Strategy for GoogleApps {
+    type Options = GoogleAppsOptions;
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        true
-    }
+    const LABEL: &'static str = "Google Workspace";
+    const ICON: Icon = Icon::Image("/assets/icons/google-avatar.png");
+    const NAME_PLACEHOLDER: &'static str = "acme-google-workspace";
+    const DOCS_URL: &'static str = "https://auth0.com/docs/authenticate/identity-providers/enterprise-identity-providers/google-apps";
 
-    fn change(&mut self, _: Self::Properties) -> ShouldRender {
-        false
+    fn list_route() -> AppRoute {
+        AppRoute::EnterpriseGoogle
     }
 
-    fn view(&self) -> Html {
-        type Anchor = RouterAnchor<AppRoute>;
-        html! {
-            <div
-                class="domain-content"
-            >
-                <Anchor
-                    route=AppRoute::EnterpriseHome
-                    classes="text-decoration-none domain-link-dark"
-                >
-                    <i class="bi bi-arrow-left me-2"></i>
-                    {"Back to Enterprise Connections"}
-                </Anchor>
-
-                <div
-                    class="d-flex mb-4 mt-3"
-                >
-                    <div
-                        class="d-flex flex-column"
-                    >
-                        <h2>{"Google Workspace"}</h2>
-                    </div>
-                </div>
-
-                <div style="
-                    display: flex;
-                    text-align: center;
-                    align-items: center;
-                    flex-direction: column;
-                    padding: 40px;
-                    border-radius: 6px;
-                    border: 1px solid #e3e4e6;
-                    "
-                >
+    fn create_route() -> AppRoute {
+        AppRoute::EnterpriseGoogleCreate
+    }
 
-                    <i class="bi bi-briefcase text-color-secondary" style="font-size:150px; opacity:.5;"></i>
+    fn settings_route(connection_id: String) -> AppRoute {
+        AppRoute::EnterpriseGoogleSettings { connection_id }
+    }
 
-                    <div>
-                        {"No items have been added to this section."}
-                    </div>
-                    <button
-                        class="btn btn-primary"
-                        style=" color: #fff;
-                                background-color: #635dff;
-                                box-shadow: none;
-                                border-radius: 4px;
-                                padding: 8px 16px;
-                                margin: 20px"
-                    >
-                        <Anchor
-                            route=AppRoute::EnterpriseGoogleCreate
-                            classes="text-decoration-none text-light px-2 link-primary pe-auto"
-                            >
-                                {"+ Create Connection"}
-                        </Anchor>
-                    </button>
-                    <a
-                        href="https://auth0.com/docs/sso/single-sign-on"
-                        target="_blank"
-                        style="text-decoration: none;"
-                    >{"Learn More"}</a>
-                </div>
+    fn summary(options: &GoogleAppsOptions) -> String {
+        options.domain.clone()
+    }
 
-            </div>
+    fn view_form(options: GoogleAppsOptions, disabled: bool, on_change: Callback<GoogleAppsOptions>) -> Html {
+        html! {
+            <GoogleAppsForm
+                options=options
+                disabled=disabled
+                on_change=on_change
+            />
         }
     }
 }
+
+pub type EnterpriseGoogle = ConnectionList<GoogleApps>;
+pub type EnterpriseGoogleCreate = ConnectionCreate<GoogleApps>;
+pub type EnterpriseGoogleSettings = ConnectionSettings<GoogleApps>;
@@ -0,0 +1,226 @@
+use yew::{
+    format::Json,
+    prelude::*,
+    services::{
+        fetch::FetchTask,
+        storage::{Area, StorageService},
+    },
+};
+use types::{
+    enterprise::{
+        domain_claimed_by,
+        normalize_domain,
+        validate_domain,
+        EnterpriseConnection,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use crate::request_connections;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct DomainsEditorProps {
+    pub connection_id: String,
+    pub domains: Vec<String>,
+    pub disabled: bool,
+    pub on_change: Callback<Vec<String>>,
+}
+
+pub struct DomainsEditor {
+    access_token: String,
+    props: DomainsEditorProps,
+    link: ComponentLink<Self>,
+    domain: String,
+    // OTHER CONNECTIONS, TO REFUSE A DOMAIN THAT IS ALREADY CLAIMED
+    connections: Vec<EnterpriseConnection>,
+    fetch_task: Option<FetchTask>,
+    loading_request_connections: bool,
+    error_request_connections: Option<String>,
+    error_domain: Option<String>,
+}
+
+pub enum Msg {
+    RequestConnections,
+    GetConnections(Result<Vec<EnterpriseConnection>, String>),
+    InputDomain(String),
+    AddDomain,
+    RemoveDomain(String),
+}
+
+impl Component for DomainsEditor {
+    type Message = Msg;
+    type Properties = DomainsEditorProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        DomainsEditor {
+            access_token,
+            props,
+            link,
+            domain: String::from(""),
+            connections: vec![],
+            fetch_task: None,
+            loading_request_connections: false,
+            error_request_connections: None,
+            error_domain: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestConnections);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestConnections => {
+                self.fetch_task = Some(request_connections(&self.access_token, &self.link, Msg::GetConnections));
+                self.error_request_connections = None;
+                self.loading_request_connections = true;
+                true
+            }
+            Msg::GetConnections(data) => {
+                match data {
+                    Ok(connections) => self.connections = connections,
+                    Err(error) => self.error_request_connections = Some(error),
+                }
+                self.fetch_task = None;
+                self.loading_request_connections = false;
+                true
+            }
+            Msg::InputDomain(value) => {
+                self.domain = value;
+                self.error_domain = None;
+                true
+            }
+            Msg::AddDomain => {
+                let domain = normalize_domain(&self.domain);
+                if !validate_domain(&domain) {
+                    self.error_domain = Some(format!("{} is not a valid domain", self.domain.trim()));
+                } else if self.props.domains.contains(&domain) {
+                    self.error_domain = Some(format!("{} is already listed", domain));
+                } else if let Some(connection) = domain_claimed_by(&domain, &self.props.connection_id, &self.connections) {
+                    self.error_domain = Some(format!("{} is already claimed by the {} connection", domain, connection.name));
+                } else {
+                    let mut domains = self.props.domains.clone();
+                    domains.push(domain);
+                    self.domain = String::from("");
+                    self.error_domain = None;
+                    self.props.on_change.emit(domains);
+                }
+                true
+            }
+            Msg::RemoveDomain(domain) => {
+                let domains = self.props.domains.iter().filter(|item| **item != domain).cloned().collect();
+                self.props.on_change.emit(domains);
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        // ADDING WAITS FOR THE OTHER CONNECTIONS, OTHERWISE CONFLICTS GO UNNOTICED
+        let disabled = self.props.disabled || self.loading_request_connections || self.error_request_connections.is_some();
+        html! {
+            <div
+                class="mb-4"
+            >
+                <p class="mb-2 fw-bold">
+                    {"Home Realm Discovery"}
+                </p>
+                <p
+                    class="text-color-disabled"
+                >
+                    {"Users whose email belongs to these domains log in with this connection. A domain can only be claimed by one connection."}
+                </p>
+                <div class="mb-2">
+                    {
+                        for self.props.domains.iter().map(|domain| {
+                            let removed = domain.clone();
+                            html! {
+                                <span class="badge bg-light text-dark border me-2 mb-2" style="font-size: 13px;">
+                                    { domain }
+                                    <button
+                                        type="button"
+                                        class="btn-close ms-2"
+                                        style="font-size: 9px;"
+                                        aria-label="Remove"
+                                        disabled=self.props.disabled
+                                        onclick=self.link.callback(move |_| Msg::RemoveDomain(removed.clone()))
+                                    ></button>
+                                </span>
+                            }
+                        })
+                    }
+                </div>
+                <div class="input-group mb-2">
+                    <input
+                        type="text"
+                        class=classes!("form-control", if self.error_domain.is_some() { "is-invalid" } else { "" })
+                        placeholder="example.com"
+                        value=self.domain.clone()
+                        disabled=disabled
+                        oninput=self.link.callback(|data: InputData| Msg::InputDomain(data.value))
+                        onkeypress=self.link.batch_callback(|event: KeyboardEvent| {
+                            if event.key() == "Enter" { Some(Msg::AddDomain) } else { None }
+                        })
+                    />
+                    <button
+                        type="button"
+                        class="btn btn-outline-primary"
+                        disabled={ disabled || self.domain.trim().is_empty() }
+                        onclick=self.link.callback(|_| Msg::AddDomain)
+                    >
+                        {"Add"}
+                    </button>
+                    {
+                        match &self.error_domain {
+                            Some(error) => html! { <div class="invalid-feedback">{ error.clone() }</div> },
+                            None => html! {},
+                        }
+                    }
+                </div>
+                {
+                    if self.error_request_connections.is_some() {
+                        html! {
+                            <p class="text-danger mb-0">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { format!("Domains can't be added, the other connections couldn't be loaded: {}", self.error_request_connections.clone().unwrap()) }
+                            </p>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+        }
+    }
+}
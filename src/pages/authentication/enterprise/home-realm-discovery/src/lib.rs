@@ -0,0 +1,31 @@
+use yew::{
+    format::{Json, Nothing},
+    services::fetch::{FetchService, FetchTask, Request, Response},
+    ComponentLink,
+    Component,
+};
+use configs::server::API_URL;
+use types::enterprise::{EnterpriseConnection, ENTERPRISE_STRATEGIES};
+
+pub mod domains;
+pub mod preview;
+
+// EVERY ENTERPRISE CONNECTION OF THE TENANT, WHATEVER ITS STRATEGY
+pub fn request_connections<COMP, F>(access_token: &str, link: &ComponentLink<COMP>, to_msg: F) -> FetchTask
+where
+    COMP: Component,
+    F: Fn(Result<Vec<EnterpriseConnection>, String>) -> COMP::Message + 'static,
+{
+    let strategies: Vec<&str> = ENTERPRISE_STRATEGIES.iter().map(|(strategy, _)| *strategy).collect();
+    let request = Request::get(format!("{}/api/v2/connections?strategy={}", API_URL, strategies.join(",")))
+        .header("access_token", access_token)
+        .body(Nothing)
+        .expect("Could not build request.");
+    let callback = link.callback(
+        move |response: Response<Json<Result<Vec<EnterpriseConnection>, anyhow::Error>>>| {
+            let Json(data) = response.into_body();
+            to_msg(data.map_err(|error| error.to_string()))
+        },
+    );
+    FetchService::fetch(request, callback).expect("failed to start request")
+}
@@ -0,0 +1,194 @@
+use yew::{
+    format::Json,
+    prelude::*,
+    services::{
+        fetch::FetchTask,
+        storage::{Area, StorageService},
+    },
+};
+use yew_router::components::RouterAnchor;
+use router::AppRoute;
+use types::{
+    enterprise::{
+        email_domain,
+        enterprise_strategy_label,
+        home_realm,
+        EnterpriseConnection,
+        AZURE_AD_STRATEGY,
+        OIDC_STRATEGY,
+        SAML_STRATEGY,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use crate::request_connections;
+
+// TRY AN EMAIL AGAINST THE DOMAINS CLAIMED BY EVERY ENTERPRISE CONNECTION
+pub struct HomeRealmPreview {
+    access_token: String,
+    link: ComponentLink<Self>,
+    email: String,
+    connections: Vec<EnterpriseConnection>,
+    fetch_task: Option<FetchTask>,
+    loading_request_connections: bool,
+    error_request_connections: Option<String>,
+}
+
+pub enum Msg {
+    RequestConnections,
+    GetConnections(Result<Vec<EnterpriseConnection>, String>),
+    InputEmail(String),
+}
+
+impl Component for HomeRealmPreview {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        HomeRealmPreview {
+            access_token,
+            link,
+            email: String::from(""),
+            connections: vec![],
+            fetch_task: None,
+            loading_request_connections: false,
+            error_request_connections: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestConnections);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestConnections => {
+                self.fetch_task = Some(request_connections(&self.access_token, &self.link, Msg::GetConnections));
+                self.error_request_connections = None;
+                self.loading_request_connections = true;
+                true
+            }
+            Msg::GetConnections(data) => {
+                match data {
+                    Ok(connections) => self.connections = connections,
+                    Err(error) => self.error_request_connections = Some(error),
+                }
+                self.fetch_task = None;
+                self.loading_request_connections = false;
+                true
+            }
+            Msg::InputEmail(value) => {
+                self.email = value;
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, _: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div
+                class="border rounded p-4 mb-5"
+                style="font-size: 14px;"
+            >
+                <p class="fw-bold mb-2">{"Home Realm Discovery"}</p>
+                <p class="text-color-disabled">
+                    {"Type an email to see which enterprise connection its login is routed to."}
+                </p>
+                <div class="input-group mb-3">
+                    <span class="input-group-text"><i class="bi bi-envelope"></i></span>
+                    <input
+                        type="email"
+                        class="form-control"
+                        placeholder="jane@example.com"
+                        value=self.email.clone()
+                        disabled={ self.loading_request_connections }
+                        oninput=self.link.callback(|data: InputData| Msg::InputEmail(data.value))
+                    />
+                </div>
+                {
+                    if self.error_request_connections.is_some() {
+                        html! {
+                            <div class="alert alert-warning mb-0" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_request_connections.clone().unwrap() }
+                            </div>
+                        }
+                    } else {
+                        self.view_result()
+                    }
+                }
+            </div>
+        }
+    }
+}
+
+impl HomeRealmPreview {
+    fn view_result(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        if self.email.trim().is_empty() {
+            return html! {};
+        }
+        let domain = match email_domain(&self.email) {
+            Some(domain) => domain,
+            None => return html! {
+                <p class="text-muted mb-0">{"Type a full email address, eg. jane@example.com"}</p>
+            },
+        };
+        match home_realm(&self.email, &self.connections) {
+            Some(connection) => html! {
+                <p class="text-success mb-0">
+                    <i class="bi bi-arrow-right-circle me-2"></i>
+                    { format!("{} is routed to ", domain) }
+                    <Anchor
+                        route=settings_route(connection)
+                        classes="fw-bold text-decoration-none"
+                    >
+                        { connection.name.clone() }
+                    </Anchor>
+                    { format!(" ({})", enterprise_strategy_label(&connection.strategy)) }
+                </p>
+            },
+            None => html! {
+                <p class="text-muted mb-0">
+                    <i class="bi bi-info-circle me-2"></i>
+                    { format!("No enterprise connection claims {}, the user chooses how to log in.", domain) }
+                </p>
+            },
+        }
+    }
+}
+
+fn settings_route(connection: &EnterpriseConnection) -> AppRoute {
+    let connection_id = connection.id.clone();
+    match connection.strategy.as_str() {
+        AZURE_AD_STRATEGY => AppRoute::EnterpriseAzureSettings { connection_id },
+        SAML_STRATEGY => AppRoute::EnterpriseSamlSettings { connection_id },
+        OIDC_STRATEGY => AppRoute::EnterpriseOidcSettings { connection_id },
+        _ => AppRoute::EnterpriseGoogle,
+    }
+}
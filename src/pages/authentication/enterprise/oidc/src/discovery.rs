@@ -0,0 +1,270 @@
+use yew::{
+    format::{Nothing, Text},
+    prelude::*,
+    services::fetch::{FetchService, FetchTask, Request, Response},
+};
+use types::enterprise::{
+    oidc_discovery_url,
+    OidcDiscovery,
+    OIDC_TYPES,
+};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct DiscoveryImportProps {
+    pub issuer: String,
+    pub disabled: bool,
+    // THE PARSED DOCUMENT AND THE URL IT WAS FETCHED FROM, EMPTY WHEN PASTED
+    pub on_import: Callback<(OidcDiscovery, String)>,
+}
+
+pub struct DiscoveryImport {
+    props: DiscoveryImportProps,
+    link: ComponentLink<Self>,
+    issuer: String,
+    document: String,
+    fetch_task: Option<FetchTask>,
+    loading_fetch: bool,
+    error_import: Option<String>,
+    // LAST PARSED DOCUMENT WITH ITS PROBLEMS, ONLY IMPORTED WITHOUT PROBLEMS
+    discovery: Option<(OidcDiscovery, Vec<String>)>,
+}
+
+pub enum Msg {
+    InputIssuer(String),
+    InputDocument(String),
+    Fetch,
+    GetDocument(String, String),
+    Parse,
+    ResponseError(String),
+}
+
+impl Component for DiscoveryImport {
+    type Message = Msg;
+    type Properties = DiscoveryImportProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        DiscoveryImport {
+            issuer: props.issuer.clone(),
+            props,
+            link,
+            document: String::from(""),
+            fetch_task: None,
+            loading_fetch: false,
+            error_import: None,
+            discovery: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::InputIssuer(value) => {
+                self.issuer = value.trim().to_string();
+                false
+            }
+            Msg::InputDocument(value) => {
+                self.document = value;
+                self.error_import = None;
+                false
+            }
+            Msg::Fetch => {
+                let url = oidc_discovery_url(&self.issuer);
+                let request = Request::get(url.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(move |response: Response<Text>| {
+                    let (meta, data) = response.into_parts();
+                    match data {
+                        Ok(body) if meta.status.is_success() => Msg::GetDocument(body, url.clone()),
+                        _ => Msg::ResponseError(format!(
+                            "Could not fetch {}, the provider may not allow requests from the browser. Paste the document instead.",
+                            url,
+                        )),
+                    }
+                });
+                match FetchService::fetch(request, callback) {
+                    Ok(task) => {
+                        self.fetch_task = Some(task);
+                        self.loading_fetch = true;
+                        self.error_import = None;
+                        self.discovery = None;
+                    }
+                    Err(error) => self.error_import = Some(error.to_string()),
+                }
+                true
+            }
+            Msg::GetDocument(document, url) => {
+                self.fetch_task = None;
+                self.loading_fetch = false;
+                self.document = document;
+                self.import(url);
+                true
+            }
+            Msg::Parse => {
+                self.discovery = None;
+                self.import(String::from(""));
+                true
+            }
+            Msg::ResponseError(message) => {
+                self.fetch_task = None;
+                self.loading_fetch = false;
+                self.error_import = Some(message);
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            if self.issuer.is_empty() {
+                self.issuer = props.issuer.clone();
+            }
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let disabled = self.props.disabled || self.loading_fetch;
+        html! {
+            <div class="border rounded p-3 mb-4" style="background-color: #f8f9fa;">
+                <p class="fw-bold mb-2">{"Discovery"}</p>
+                <p class="text-color-disabled mb-2">
+                    {"Enter the issuer to read its OpenID configuration, or paste the .well-known/openid-configuration document."}
+                </p>
+                <div class="input-group mb-2">
+                    <input
+                        type="url"
+                        class="form-control"
+                        placeholder="https://idp.example.com"
+                        value=self.issuer.clone()
+                        disabled=disabled
+                        oninput=self.link.callback(|data: InputData| Msg::InputIssuer(data.value))
+                    />
+                    <button
+                        type="button"
+                        class=format!("btn {} btn-outline-primary position-relative", if self.loading_fetch {"loading"} else {""} )
+                        disabled=disabled
+                        onclick=self.link.callback(|_| Msg::Fetch)
+                    >
+                        <div class="telkom-label">
+                            {"Fetch"}
+                        </div>
+                        <div class="telkom-spinner telkom-center">
+                            <div class="spinner-border spinner-border-sm" role="status"/>
+                        </div>
+                    </button>
+                </div>
+                <textarea
+                    class="form-control mb-2"
+                    rows="4"
+                    style="font-family: 'Roboto Mono', monospace; font-size: 13px;"
+                    placeholder="{ \"issuer\": \"https://idp.example.com\", \"authorization_endpoint\": ... }"
+                    value=self.document.clone()
+                    disabled=disabled
+                    oninput=self.link.callback(|data: InputData| Msg::InputDocument(data.value))
+                />
+                <button
+                    type="button"
+                    class="btn btn-outline-primary btn-sm"
+                    disabled=disabled
+                    onclick=self.link.callback(|_| Msg::Parse)
+                >
+                    <i class="bi bi-file-earmark-code me-2"></i>
+                    {"Parse Document"}
+                </button>
+                {
+                    if self.error_import.is_some() {
+                        html! {
+                            <p class="text-danger mt-3 mb-0">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_import.clone().unwrap() }
+                            </p>
+                        }
+                    } else {
+                        self.view_discovery()
+                    }
+                }
+            </div>
+        }
+    }
+}
+
+impl DiscoveryImport {
+    fn import(&mut self, url: String) {
+        let discovery = match OidcDiscovery::parse(&self.document) {
+            Ok(discovery) => discovery,
+            Err(error) => {
+                self.error_import = Some(error);
+                return;
+            }
+        };
+        let mut problems = discovery.problems();
+        // OPENID CONNECT DISCOVERY REQUIRES THE ISSUER TO MATCH THE ONE ASKED FOR
+        let expected = self.issuer.trim_end_matches('/');
+        if !url.is_empty() && !expected.is_empty() && discovery.issuer.trim_end_matches('/') != expected {
+            problems.push(format!("The document is issued by {}, not {}", discovery.issuer, self.issuer));
+        }
+        self.error_import = None;
+        if problems.is_empty() {
+            self.props.on_import.emit((discovery.clone(), url));
+        }
+        self.discovery = Some((discovery, problems));
+    }
+
+    fn view_discovery(&self) -> Html {
+        let (discovery, problems) = match &self.discovery {
+            Some(discovery) => discovery,
+            None => return html! {},
+        };
+        let list = |values: &[String]| {
+            if values.is_empty() {
+                String::from("not declared")
+            } else {
+                values.join(", ")
+            }
+        };
+        let types: Vec<String> = OIDC_TYPES
+            .iter()
+            .filter(|(oidc_type, _, _)| discovery.supports(oidc_type))
+            .map(|(_, label, _)| label.to_string())
+            .collect();
+        html! {
+            <div class="mt-3">
+                {
+                    if problems.is_empty() {
+                        html! {
+                            <p class="text-success mb-2">
+                                <i class="bi bi-check-circle me-2"></i>
+                                { format!("The settings of {} have been filled, save to apply them", discovery.issuer) }
+                            </p>
+                        }
+                    } else {
+                        html! {
+                            <ul class="text-danger mb-2 ps-3">
+                                { for problems.iter().map(|problem| html! { <li>{ problem }</li> }) }
+                            </ul>
+                        }
+                    }
+                }
+                <table class="table table-sm mb-0" style="font-size: 13px;">
+                    <tbody>
+                        <tr>
+                            <td class="fw-bold" style="width: 160px;">{"Scopes"}</td>
+                            <td>{ list(&discovery.scopes_supported) }</td>
+                        </tr>
+                        <tr>
+                            <td class="fw-bold">{"Signing Algorithms"}</td>
+                            <td>{ list(&discovery.id_token_signing_alg_values_supported) }</td>
+                        </tr>
+                        <tr>
+                            <td class="fw-bold">{"Connection Types"}</td>
+                            <td>{ list(&types) }</td>
+                        </tr>
+                    </tbody>
+                </table>
+            </div>
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use yew::prelude::*;
+use types::enterprise::{
+    OidcDiscovery,
+    OidcOptions,
+    OIDC_BACK_CHANNEL,
+    OIDC_TYPES,
+};
+use crate::discovery::DiscoveryImport;
+
+// PROVIDER SETTINGS OF AN OPENID CONNECT CONNECTION, SHARED BY THE CREATE PAGE
+// AND THE SETTINGS TAB
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct OidcFormProps {
+    pub options: OidcOptions,
+    pub disabled: bool,
+    pub on_change: Callback<OidcOptions>,
+}
+
+#[derive(Clone, Copy)]
+pub enum Data {
+    Issuer,
+    ClientId,
+    ClientSecret,
+    Scope,
+    AuthorizationEndpoint,
+    TokenEndpoint,
+    UserinfoEndpoint,
+    JwksUri,
+}
+
+pub struct OidcForm {
+    props: OidcFormProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    Input(String, Data),
+    SelectType(String),
+    Import((OidcDiscovery, String)),
+}
+
+impl Component for OidcForm {
+    type Message = Msg;
+    type Properties = OidcFormProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        OidcForm {
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut options = self.props.options.clone();
+        match msg {
+            Msg::Input(value, data) => {
+                match data {
+                    Data::Issuer => options.issuer = value.trim().to_string(),
+                    Data::ClientId => options.client_id = value.trim().to_string(),
+                    Data::ClientSecret => options.client_secret = value,
+                    Data::Scope => options.scope = value,
+                    Data::AuthorizationEndpoint => options.authorization_endpoint = value.trim().to_string(),
+                    Data::TokenEndpoint => options.token_endpoint = value.trim().to_string(),
+                    Data::UserinfoEndpoint => options.userinfo_endpoint = value.trim().to_string(),
+                    Data::JwksUri => options.jwks_uri = value.trim().to_string(),
+                }
+            }
+            Msg::SelectType(oidc_type) => {
+                options.oidc_type = oidc_type;
+            }
+            Msg::Import((discovery, url)) => {
+                options = discovery.apply(&options, &url);
+            }
+        }
+        self.props.on_change.emit(options);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let options = self.props.options.clone();
+        let disabled = self.props.disabled;
+        let back_channel = options.oidc_type == OIDC_BACK_CHANNEL;
+        html! {
+            <>
+                <DiscoveryImport
+                    issuer=options.issuer.clone()
+                    disabled=disabled
+                    on_import=self.link.callback(Msg::Import)
+                />
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Type"}
+                    </p>
+                    {
+                        for OIDC_TYPES.iter().map(|(value, label, description)| {
+                            let oidc_type = value.to_string();
+                            html! {
+                                <div class="form-check mb-2">
+                                    <input
+                                        class="form-check-input"
+                                        type="radio"
+                                        name="oidc-type"
+                                        checked={ options.oidc_type == *value }
+                                        disabled=disabled
+                                        onclick=self.link.callback(move |_| Msg::SelectType(oidc_type.clone()))
+                                    />
+                                    <label class="form-check-label" style="font-size: 14px;">
+                                        { *label }
+                                        <p class="text-color-disabled mb-0">{ *description }</p>
+                                    </label>
+                                </div>
+                            }
+                        })
+                    }
+                </div>
+
+                { self.view_input("Issuer URL", "https://idp.example.com", &options.issuer, false, Data::Issuer) }
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Client ID"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="text"
+                            class="form-control"
+                            value=options.client_id.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::ClientId))
+                        />
+                    </div>
+                </div>
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Client Secret"}
+                        {
+                            if back_channel {
+                                html! { <span class="text-danger ms-1">{"*"}</span> }
+                            } else {
+                                html! {}
+                            }
+                        }
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="password"
+                            class="form-control"
+                            autocomplete="new-password"
+                            placeholder={ if back_channel { "" } else { "Not used by front channel connections" } }
+                            value=options.client_secret.clone()
+                            disabled={ disabled || !back_channel }
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::ClientSecret))
+                        />
+                    </div>
+                    <p
+                        class="text-color-disabled"
+                    >
+                        {"Back channel connections exchange the authorization code with this secret."}
+                    </p>
+                </div>
+
+                <div
+                    class="mb-5"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Scopes"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="text"
+                            class="form-control"
+                            placeholder="openid profile email"
+                            value=options.scope.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::Scope))
+                        />
+                    </div>
+                    <p
+                        class="text-color-disabled"
+                    >
+                        {"Space separated, must contain openid."}
+                    </p>
+                </div>
+
+                <p class="mb-3 fw-bold">
+                    {"Endpoints"}
+                </p>
+                { self.view_input("Authorization Endpoint", "https://", &options.authorization_endpoint, false, Data::AuthorizationEndpoint) }
+                { self.view_input("Token Endpoint", "https://", &options.token_endpoint, !back_channel, Data::TokenEndpoint) }
+                { self.view_input("UserInfo Endpoint", "https://", &options.userinfo_endpoint, true, Data::UserinfoEndpoint) }
+                { self.view_input("JWKS URI", "https://", &options.jwks_uri, false, Data::JwksUri) }
+            </>
+        }
+    }
+}
+
+impl OidcForm {
+    // OPTIONAL ENDPOINTS ARE ONLY MARKED, THEY STAY EDITABLE
+    fn view_input(&self, label: &'static str, placeholder: &'static str, value: &str, optional: bool, data: Data) -> Html {
+        html! {
+            <div
+                class="mb-4"
+            >
+                <p class="mb-2 fw-bold">
+                    { label }
+                    {
+                        if optional {
+                            html! { <span class="text-color-disabled fw-normal ms-1">{"(optional)"}</span> }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </p>
+                <div class="input-group mb-2">
+                    <input
+                        type="url"
+                        class="form-control"
+                        placeholder=placeholder
+                        value=value.to_string()
+                        disabled=self.props.disabled
+                        oninput=self.link.callback(move |data_input: InputData| Msg::Input(data_input.value, data))
+                    />
+                </div>
+            </div>
+        }
+    }
+}
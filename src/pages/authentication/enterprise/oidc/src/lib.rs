@@ -0,0 +1,56 @@
+use yew::prelude::*;
+use router::AppRoute;
+use types::enterprise::OidcOptions;
+use enterprise_connection::{
+    create::ConnectionCreate,
+    list::ConnectionList,
+    settings::ConnectionSettings,
+    Icon,
+    Strategy,
+};
+
+mod discovery;
+pub mod form;
+
+use form::OidcForm;
+
+pub struct Oidc;
+
+impl Strategy for Oidc {
+    type Options = OidcOptions;
+
+    const LABEL: &'static str = "OpenID Connect";
+    const ICON: Icon = Icon::Bootstrap("bi-person-badge");
+    const NAME_PLACEHOLDER: &'static str = "acme-oidc";
+    const DOCS_URL: &'static str = "https://auth0.com/docs/authenticate/identity-providers/enterprise-identity-providers/oidc";
+
+    fn list_route() -> AppRoute {
+        AppRoute::EnterpriseOidc
+    }
+
+    fn create_route() -> AppRoute {
+        AppRoute::EnterpriseOidcCreate
+    }
+
+    fn settings_route(connection_id: String) -> AppRoute {
+        AppRoute::EnterpriseOidcSettings { connection_id }
+    }
+
+    fn summary(options: &OidcOptions) -> String {
+        options.issuer.clone()
+    }
+
+    fn view_form(options: OidcOptions, disabled: bool, on_change: Callback<OidcOptions>) -> Html {
+        html! {
+            <OidcForm
+                options=options
+                disabled=disabled
+                on_change=on_change
+            />
+        }
+    }
+}
+
+pub type EnterpriseOidc = ConnectionList<Oidc>;
+pub type EnterpriseOidcCreate = ConnectionCreate<Oidc>;
+pub type EnterpriseOidcSettings = ConnectionSettings<Oidc>;
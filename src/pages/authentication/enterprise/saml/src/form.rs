@@ -0,0 +1,293 @@
+use yew::prelude::*;
+use types::enterprise::{
+    SamlOptions,
+    NAME_ID_FORMATS,
+    SAML_BINDINGS,
+    SAML_USER_ATTRIBUTES,
+};
+use crate::{
+    import_metadata::MetadataImport,
+    metadata::IdpMetadata,
+};
+
+// IDENTITY PROVIDER SETTINGS OF A SAML CONNECTION, SHARED BY THE CREATE PAGE
+// AND THE SETTINGS TAB
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct SamlFormProps {
+    pub options: SamlOptions,
+    pub disabled: bool,
+    pub on_change: Callback<SamlOptions>,
+}
+
+pub enum Data {
+    SignInEndpoint,
+    SignOutEndpoint,
+    SigningCert,
+    NameIdFormat,
+    ProtocolBinding,
+}
+
+pub struct SamlForm {
+    props: SamlFormProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    Input(String, Data),
+    InputMapping(String, String),
+    ToggleSignRequest,
+    Import(IdpMetadata),
+}
+
+impl Component for SamlForm {
+    type Message = Msg;
+    type Properties = SamlFormProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        SamlForm {
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut options = self.props.options.clone();
+        match msg {
+            Msg::Input(value, data) => {
+                match data {
+                    Data::SignInEndpoint => options.sign_in_endpoint = value.trim().to_string(),
+                    Data::SignOutEndpoint => options.sign_out_endpoint = value.trim().to_string(),
+                    Data::SigningCert => options.signing_cert = value,
+                    Data::NameIdFormat => options.name_id_format = value,
+                    Data::ProtocolBinding => options.protocol_binding = value,
+                }
+            }
+            Msg::InputMapping(attribute, claim) => {
+                options.fields_map.insert(attribute, claim.trim().to_string());
+            }
+            Msg::ToggleSignRequest => {
+                options.sign_saml_request = !options.sign_saml_request;
+            }
+            Msg::Import(metadata) => {
+                options = metadata.apply(&options);
+            }
+        }
+        self.props.on_change.emit(options);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let options = self.props.options.clone();
+        let disabled = self.props.disabled;
+        html! {
+            <>
+                <MetadataImport
+                    disabled=disabled
+                    on_import=self.link.callback(Msg::Import)
+                />
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Sign In URL"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="url"
+                            class="form-control"
+                            placeholder="https://idp.example.com/sso/saml"
+                            value=options.sign_in_endpoint.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::SignInEndpoint))
+                        />
+                    </div>
+                    <p
+                        class="text-color-disabled"
+                    >
+                        {"Single sign on URL of the identity provider, users are sent there to log in."}
+                    </p>
+                </div>
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Sign Out URL"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="url"
+                            class="form-control"
+                            placeholder="Optional"
+                            value=options.sign_out_endpoint.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::SignOutEndpoint))
+                        />
+                    </div>
+                    <p
+                        class="text-color-disabled"
+                    >
+                        {"Single logout URL of the identity provider. Leave it empty if the identity provider doesn't support single logout."}
+                    </p>
+                </div>
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Signing Certificate"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <textarea
+                            class="form-control"
+                            rows="6"
+                            style="font-family: 'Roboto Mono', monospace; font-size: 13px;"
+                            placeholder="-----BEGIN CERTIFICATE-----"
+                            value=options.signing_cert.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::SigningCert))
+                        ></textarea>
+                    </div>
+                    <p
+                        class="text-color-disabled"
+                    >
+                        {"X.509 certificate in PEM format, used to verify the SAML responses of the identity provider."}
+                    </p>
+                </div>
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"NameID Format"}
+                    </p>
+                    <select
+                        class="form-select"
+                        disabled=disabled
+                        onchange=self.link.callback(|e| {
+                            if let ChangeData::Select(select) = e {
+                                Msg::Input(select.value(), Data::NameIdFormat)
+                            } else {
+                                Msg::Input(String::from(""), Data::NameIdFormat)
+                            }
+                        })
+                    >
+                        {
+                            for NAME_ID_FORMATS.iter().map(|(value, label)| html! {
+                                <option value=*value selected={ options.name_id_format == *value }>{ *label }</option>
+                            })
+                        }
+                        {
+                            // A FORMAT IMPORTED FROM METADATA THAT ISN'T IN THE LIST
+                            if NAME_ID_FORMATS.iter().any(|(value, _)| *value == options.name_id_format) {
+                                html! {}
+                            } else {
+                                html! {
+                                    <option value=options.name_id_format.clone() selected=true>{ options.name_id_format.clone() }</option>
+                                }
+                            }
+                        }
+                    </select>
+                </div>
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Protocol Binding"}
+                    </p>
+                    <select
+                        class="form-select"
+                        disabled=disabled
+                        onchange=self.link.callback(|e| {
+                            if let ChangeData::Select(select) = e {
+                                Msg::Input(select.value(), Data::ProtocolBinding)
+                            } else {
+                                Msg::Input(String::from(""), Data::ProtocolBinding)
+                            }
+                        })
+                    >
+                        {
+                            for SAML_BINDINGS.iter().map(|(value, label)| html! {
+                                <option value=*value selected={ options.protocol_binding == *value }>{ *label }</option>
+                            })
+                        }
+                    </select>
+                </div>
+
+                <div
+                    class="mb-4"
+                >
+                    <div class="form-check d-flex align-items-center">
+                        <input
+                            class="form-check-input me-2 mt-0"
+                            style="font-size: 16px;"
+                            type="checkbox"
+                            checked=options.sign_saml_request
+                            disabled=disabled
+                            onclick=self.link.callback(|_| Msg::ToggleSignRequest)
+                        />
+                        <label class="form-check-label fw-bold" style="font-size: 14px;">
+                            {"Sign Request"}
+                        </label>
+                    </div>
+                    <p
+                        class="text-color-disabled mt-2"
+                    >
+                        {"Sign the SAML authentication requests, when the identity provider requires it."}
+                    </p>
+                </div>
+
+                <div
+                    class="mb-5"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Attribute Mapping"}
+                    </p>
+                    <p class="text-color-disabled">
+                        {"SAML attribute of the assertion that fills each user attribute."}
+                    </p>
+                    {
+                        for SAML_USER_ATTRIBUTES.iter().map(|(attribute, label, required)| {
+                            let key = attribute.to_string();
+                            html! {
+                                <div class="input-group input-group-sm mb-2">
+                                    <span class="input-group-text" style="width: 140px;">
+                                        { *label }
+                                        {
+                                            if *required {
+                                                html! { <span class="text-danger ms-1">{"*"}</span> }
+                                            } else {
+                                                html! {}
+                                            }
+                                        }
+                                    </span>
+                                    <input
+                                        type="text"
+                                        class="form-control"
+                                        style="font-family: 'Roboto Mono', monospace;"
+                                        placeholder="Not mapped"
+                                        value=options.fields_map.get(*attribute).cloned().unwrap_or_default()
+                                        disabled=disabled
+                                        oninput=self.link.callback(move |data: InputData| Msg::InputMapping(key.clone(), data.value))
+                                    />
+                                </div>
+                            }
+                        })
+                    }
+                </div>
+            </>
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use yew::{
+    prelude::*,
+    services::reader::{File, FileData, ReaderService, ReaderTask},
+};
+use crate::metadata::{parse_idp_metadata, IdpMetadata};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct MetadataImportProps {
+    pub disabled: bool,
+    pub on_import: Callback<IdpMetadata>,
+}
+
+pub struct MetadataImport {
+    props: MetadataImportProps,
+    link: ComponentLink<Self>,
+    metadata_xml: String,
+    reader_task: Option<ReaderTask>,
+    error_import: Option<String>,
+    message_import: Option<String>,
+}
+
+pub enum Msg {
+    InputMetadata(String),
+    SelectFile(File),
+    ReadFile(FileData),
+    Import,
+    Ignore,
+}
+
+impl Component for MetadataImport {
+    type Message = Msg;
+    type Properties = MetadataImportProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        MetadataImport {
+            props,
+            link,
+            metadata_xml: String::from(""),
+            reader_task: None,
+            error_import: None,
+            message_import: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::InputMetadata(value) => {
+                self.metadata_xml = value;
+                self.error_import = None;
+                self.message_import = None;
+                true
+            }
+            Msg::SelectFile(file) => {
+                self.error_import = None;
+                self.message_import = None;
+                match ReaderService::read_file(file, self.link.callback(Msg::ReadFile)) {
+                    Ok(task) => self.reader_task = Some(task),
+                    Err(error) => self.error_import = Some(error.to_string()),
+                }
+                true
+            }
+            Msg::ReadFile(data) => {
+                self.reader_task = None;
+                match String::from_utf8(data.content) {
+                    Ok(content) => {
+                        self.metadata_xml = content;
+                        self.link.send_message(Msg::Import);
+                    }
+                    Err(_) => self.error_import = Some(String::from("The metadata file must be UTF-8 encoded")),
+                }
+                true
+            }
+            Msg::Import => {
+                match parse_idp_metadata(&self.metadata_xml) {
+                    Ok(metadata) => {
+                        self.message_import = Some(if metadata.entity_id.is_empty() {
+                            String::from("Settings have been filled from the metadata, save to apply them")
+                        } else {
+                            format!("Settings of {} have been filled from the metadata, save to apply them", metadata.entity_id)
+                        });
+                        self.error_import = None;
+                        self.props.on_import.emit(metadata);
+                    }
+                    Err(error) => {
+                        self.message_import = None;
+                        self.error_import = Some(error);
+                    }
+                }
+                true
+            }
+            Msg::Ignore => {
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let disabled = self.props.disabled || self.reader_task.is_some();
+        html! {
+            <div class="border rounded p-3 mb-4" style="background-color: #f8f9fa;">
+                <p class="fw-bold mb-2">{"Import Identity Provider Metadata"}</p>
+                <p class="text-color-disabled mb-2">
+                    {"Upload or paste the metadata XML of the identity provider to fill the sign in URL, sign out URL, signing certificate and NameID format."}
+                </p>
+                <input
+                    type="file"
+                    class="form-control mb-2"
+                    accept=".xml,application/xml,text/xml,application/samlmetadata+xml"
+                    disabled=disabled
+                    onchange=self.link.callback(|value| {
+                        if let ChangeData::Files(files) = value {
+                            match files.get(0) {
+                                Some(file) => Msg::SelectFile(file),
+                                None => Msg::Ignore,
+                            }
+                        } else {
+                            Msg::Ignore
+                        }
+                    })
+                />
+                <textarea
+                    class="form-control mb-2"
+                    rows="4"
+                    style="font-family: 'Roboto Mono', monospace; font-size: 13px;"
+                    placeholder="<EntityDescriptor xmlns=\"urn:oasis:names:tc:SAML:2.0:metadata\" ...>"
+                    value=self.metadata_xml.clone()
+                    disabled=disabled
+                    oninput=self.link.callback(|data: InputData| Msg::InputMetadata(data.value))
+                />
+                <button
+                    type="button"
+                    class="btn btn-outline-primary btn-sm"
+                    disabled={ disabled || self.metadata_xml.trim().is_empty() }
+                    onclick=self.link.callback(|_| Msg::Import)
+                >
+                    <i class="bi bi-file-earmark-arrow-down me-2"></i>
+                    {"Import"}
+                </button>
+                {
+                    if self.error_import.is_some() {
+                        html! {
+                            <p class="text-danger mt-3 mb-0">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_import.clone().unwrap() }
+                            </p>
+                        }
+                    } else if self.message_import.is_some() {
+                        html! {
+                            <p class="text-success mt-3 mb-0">
+                                <i class="bi bi-check-circle me-2"></i>
+                                { self.message_import.clone().unwrap() }
+                            </p>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use yew::prelude::*;
+use router::AppRoute;
+use types::enterprise::{SamlConnection, SamlOptions};
+use enterprise_connection::{
+    create::ConnectionCreate,
+    list::ConnectionList,
+    settings::ConnectionSettings,
+    Icon,
+    Strategy,
+};
+
+pub mod form;
+mod import_metadata;
+pub mod metadata;
+pub mod sp_metadata;
+
+use form::SamlForm;
+use sp_metadata::SpMetadata;
+
+pub struct Saml;
+
+impl Strategy for Saml {
+    type Options = SamlOptions;
+
+    const LABEL: &'static str = "SAML";
+    const ICON: Icon = Icon::Bootstrap("bi-shield-lock");
+    const NAME_PLACEHOLDER: &'static str = "acme-saml";
+    const DOCS_URL: &'static str = "https://auth0.com/docs/authenticate/protocols/saml/saml-sso-integrations";
+    const FORM_TITLE: &'static str = "Identity Provider";
+
+    fn list_route() -> AppRoute {
+        AppRoute::EnterpriseSaml
+    }
+
+    fn create_route() -> AppRoute {
+        AppRoute::EnterpriseSamlCreate
+    }
+
+    fn settings_route(connection_id: String) -> AppRoute {
+        AppRoute::EnterpriseSamlSettings { connection_id }
+    }
+
+    fn summary(options: &SamlOptions) -> String {
+        options.sign_in_endpoint.clone()
+    }
+
+    fn view_form(options: SamlOptions, disabled: bool, on_change: Callback<SamlOptions>) -> Html {
+        html! {
+            <SamlForm
+                options=options
+                disabled=disabled
+                on_change=on_change
+            />
+        }
+    }
+
+    // WHAT THE IDENTITY PROVIDER NEEDS TO TRUST THIS CONNECTION
+    fn extra_tab() -> Option<&'static str> {
+        Some("Setup")
+    }
+
+    fn view_extra_tab(connection: &SamlConnection) -> Html {
+        html! {
+            <SpMetadata
+                connection_name=connection.name.clone()
+                options=connection.options.clone()
+            />
+        }
+    }
+}
+
+pub type EnterpriseSaml = ConnectionList<Saml>;
+pub type EnterpriseSamlCreate = ConnectionCreate<Saml>;
+pub type EnterpriseSamlSettings = ConnectionSettings<Saml>;
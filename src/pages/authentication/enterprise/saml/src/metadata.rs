@@ -0,0 +1,454 @@
+use configs::server::API_URL;
+use types::enterprise::{
+    certificate_to_pem,
+    SamlOptions,
+    SAML_BINDING_POST,
+    SAML_BINDING_REDIRECT,
+};
+
+// SETTINGS READ FROM THE METADATA DOCUMENT OF AN IDENTITY PROVIDER
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdpMetadata {
+    pub entity_id: String,
+    pub sign_in_endpoint: String,
+    pub sign_in_binding: String,
+    pub sign_out_endpoint: String,
+    pub signing_cert: String,
+    pub name_id_format: String,
+}
+
+impl IdpMetadata {
+    // THE ATTRIBUTE MAPPING ISN'T PART OF THE METADATA AND IS KEPT
+    pub fn apply(&self, options: &SamlOptions) -> SamlOptions {
+        let mut options = options.clone();
+        options.sign_in_endpoint = self.sign_in_endpoint.clone();
+        options.protocol_binding = self.sign_in_binding.clone();
+        options.sign_out_endpoint = self.sign_out_endpoint.clone();
+        options.signing_cert = self.signing_cert.clone();
+        if !self.name_id_format.is_empty() {
+            options.name_id_format = self.name_id_format.clone();
+        }
+        options
+    }
+}
+
+enum Token {
+    Open {
+        name: String,
+        attributes: Vec<(String, String)>,
+        closed: bool,
+    },
+    Close(String),
+    Text(String),
+}
+
+pub fn parse_idp_metadata(xml: &str) -> Result<IdpMetadata, String> {
+    let tokens = tokenize(xml)?;
+    let mut metadata = IdpMetadata {
+        entity_id: String::from(""),
+        sign_in_endpoint: String::from(""),
+        sign_in_binding: String::from(""),
+        sign_out_endpoint: String::from(""),
+        signing_cert: String::from(""),
+        name_id_format: String::from(""),
+    };
+    // (binding, location)
+    let mut sign_in_services: Vec<(String, String)> = Vec::new();
+    let mut sign_out_services: Vec<(String, String)> = Vec::new();
+    let mut stack: Vec<String> = Vec::new();
+    let mut found_idp = false;
+    // USE OF THE KEY DESCRIPTOR BEING READ, CERTIFICATES WITHOUT A USE SIGN TOO
+    let mut key_use = String::from("");
+
+    for token in tokens {
+        match token {
+            Token::Open { name, attributes, closed } => {
+                let in_idp = stack.iter().any(|element| element == "IDPSSODescriptor");
+                let attribute = |key: &str| {
+                    attributes
+                        .iter()
+                        .find(|(name, _)| name == key)
+                        .map(|(_, value)| value.clone())
+                        .unwrap_or_default()
+                };
+                match name.as_str() {
+                    "EntityDescriptor" if metadata.entity_id.is_empty() => {
+                        metadata.entity_id = attribute("entityID");
+                    }
+                    "IDPSSODescriptor" => found_idp = true,
+                    "SingleSignOnService" if in_idp => {
+                        sign_in_services.push((attribute("Binding"), attribute("Location")));
+                    }
+                    "SingleLogoutService" if in_idp => {
+                        sign_out_services.push((attribute("Binding"), attribute("Location")));
+                    }
+                    "KeyDescriptor" if in_idp => key_use = attribute("use"),
+                    _ => {}
+                }
+                if !closed {
+                    stack.push(name);
+                }
+            }
+            Token::Close(name) => {
+                match stack.pop() {
+                    Some(open) if open == name => {}
+                    Some(open) => return Err(format!("The metadata is not valid XML, </{}> closes <{}>", name, open)),
+                    None => return Err(format!("The metadata is not valid XML, </{}> was never opened", name)),
+                }
+            }
+            Token::Text(text) => {
+                let in_idp = stack.iter().any(|element| element == "IDPSSODescriptor");
+                match stack.last().map(|name| name.as_str()) {
+                    Some("X509Certificate")
+                        if in_idp
+                            && key_use != "encryption"
+                            && stack.iter().any(|element| element == "KeyDescriptor")
+                            && metadata.signing_cert.is_empty() =>
+                    {
+                        metadata.signing_cert = certificate_to_pem(&text);
+                    }
+                    Some("NameIDFormat") if in_idp && metadata.name_id_format.is_empty() => {
+                        metadata.name_id_format = text;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    if let Some(open) = stack.pop() {
+        return Err(format!("The metadata is not valid XML, <{}> is never closed", open));
+    }
+    if !found_idp {
+        return Err(String::from("The metadata doesn't describe an identity provider, it has no IDPSSODescriptor"));
+    }
+    match pick_service(&sign_in_services) {
+        Some((binding, location)) => {
+            metadata.sign_in_binding = binding;
+            metadata.sign_in_endpoint = location;
+        }
+        None => return Err(String::from("The metadata has no SingleSignOnService with an HTTP-Redirect or HTTP-POST binding")),
+    }
+    if let Some((_, location)) = pick_service(&sign_out_services) {
+        metadata.sign_out_endpoint = location;
+    }
+    if metadata.signing_cert.is_empty() {
+        return Err(String::from("The metadata has no signing certificate"));
+    }
+    Ok(metadata)
+}
+
+// HTTP-REDIRECT IS PREFERRED, SOAP AND ARTIFACT BINDINGS AREN'T SUPPORTED
+fn pick_service(services: &[(String, String)]) -> Option<(String, String)> {
+    [SAML_BINDING_REDIRECT, SAML_BINDING_POST]
+        .iter()
+        .find_map(|binding| {
+            services
+                .iter()
+                .find(|(service_binding, location)| service_binding == binding && !location.is_empty())
+        })
+        .cloned()
+}
+
+fn tokenize(xml: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = xml.trim_start_matches('\u{feff}');
+    while !rest.is_empty() {
+        let start = match rest.find('<') {
+            Some(start) => start,
+            None => {
+                push_text(&mut tokens, rest);
+                break;
+            }
+        };
+        push_text(&mut tokens, &rest[..start]);
+        rest = &rest[start..];
+
+        if rest.starts_with("<!--") {
+            rest = skip_past(rest, "-->")?;
+        } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata.find("]]>").ok_or_else(|| String::from("The metadata is not valid XML, a CDATA section is never closed"))?;
+            let text = cdata[..end].trim();
+            if !text.is_empty() {
+                tokens.push(Token::Text(text.to_string()));
+            }
+            rest = &cdata[end + 3..];
+        } else if rest.starts_with("<?") {
+            rest = skip_past(rest, "?>")?;
+        } else if rest.starts_with("<!") {
+            rest = skip_past(rest, ">")?;
+        } else {
+            let end = tag_end(rest).ok_or_else(|| String::from("The metadata is not valid XML, a tag is never closed"))?;
+            let tag = &rest[1..end];
+            rest = &rest[end + 1..];
+            if let Some(name) = tag.strip_prefix('/') {
+                tokens.push(Token::Close(local_name(name.trim()).to_string()));
+            } else {
+                let closed = tag.ends_with('/');
+                let tag = tag.trim_end_matches('/');
+                let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+                let name = local_name(&tag[..name_end]).to_string();
+                if name.is_empty() {
+                    return Err(String::from("The metadata is not valid XML, a tag has no name"));
+                }
+                tokens.push(Token::Open {
+                    name,
+                    attributes: parse_attributes(&tag[name_end..])?,
+                    closed,
+                });
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn push_text(tokens: &mut Vec<Token>, text: &str) {
+    let text = text.trim();
+    if !text.is_empty() {
+        tokens.push(Token::Text(unescape(text)));
+    }
+}
+
+fn skip_past<'a>(rest: &'a str, end: &str) -> Result<&'a str, String> {
+    rest.find(end)
+        .map(|position| &rest[position + end.len()..])
+        .ok_or_else(|| String::from("The metadata is not valid XML, a declaration is never closed"))
+}
+
+// POSITION OF THE '>' CLOSING THE TAG, IGNORING THE ONES IN QUOTED VALUES
+fn tag_end(rest: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    for (position, c) in rest.char_indices() {
+        match quote {
+            Some(open) if c == open => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '>' => return Some(position),
+            None => {}
+        }
+    }
+    None
+}
+
+fn parse_attributes(source: &str) -> Result<Vec<(String, String)>, String> {
+    let mut attributes = Vec::new();
+    let mut rest = source.trim_start();
+    while !rest.is_empty() {
+        let equal = rest.find('=').ok_or_else(|| format!("The metadata is not valid XML, {} has no value", rest.trim()))?;
+        let name = local_name(rest[..equal].trim()).to_string();
+        let value_source = rest[equal + 1..].trim_start();
+        let quote = value_source.chars().next().filter(|c| *c == '"' || *c == '\'');
+        let quote = quote.ok_or_else(|| format!("The metadata is not valid XML, the value of {} isn't quoted", name))?;
+        let end = value_source[1..]
+            .find(quote)
+            .ok_or_else(|| format!("The metadata is not valid XML, the value of {} is never closed", name))?;
+        attributes.push((name, unescape(&value_source[1..end + 1])));
+        rest = value_source[end + 2..].trim_start();
+    }
+    Ok(attributes)
+}
+
+// NAMESPACE PREFIXES ARE IGNORED, "md:EntityDescriptor" IS "EntityDescriptor"
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+// ONE PASS SO "&amp;lt;" STAYS "&lt;", UNKNOWN OR BROKEN REFERENCES ARE KEPT AS WRITTEN
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "amp" => Some('&'),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(|decimal| decimal.parse::<u32>()))
+                    .and_then(|code| code.ok())
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                unescaped.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// SERVICE PROVIDER DETAILS THE CUSTOMER REGISTERS IN THEIR IDENTITY PROVIDER
+
+pub fn sp_entity_id(connection_name: &str) -> String {
+    let host = API_URL.splitn(2, "://").last().unwrap_or(API_URL).trim_end_matches('/');
+    format!("urn:{}:{}", host, connection_name)
+}
+
+pub fn sp_acs_url(connection_name: &str) -> String {
+    format!("{}/login/callback?connection={}", API_URL, connection_name)
+}
+
+pub fn sp_logout_url(connection_name: &str) -> String {
+    format!("{}/logout?connection={}", API_URL, connection_name)
+}
+
+pub fn sp_metadata(connection_name: &str, options: &SamlOptions) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<EntityDescriptor xmlns=\"urn:oasis:names:tc:SAML:2.0:metadata\" entityID=\"{}\">\n",
+        escape(&sp_entity_id(connection_name)),
+    ));
+    xml.push_str(&format!(
+        "  <SPSSODescriptor protocolSupportEnumeration=\"urn:oasis:names:tc:SAML:2.0:protocol\" AuthnRequestsSigned=\"{}\" WantAssertionsSigned=\"true\">\n",
+        options.sign_saml_request,
+    ));
+    xml.push_str(&format!(
+        "    <SingleLogoutService Binding=\"{}\" Location=\"{}\"/>\n",
+        SAML_BINDING_REDIRECT,
+        escape(&sp_logout_url(connection_name)),
+    ));
+    if !options.name_id_format.is_empty() {
+        xml.push_str(&format!("    <NameIDFormat>{}</NameIDFormat>\n", escape(&options.name_id_format)));
+    }
+    xml.push_str(&format!(
+        "    <AssertionConsumerService Binding=\"{}\" Location=\"{}\" index=\"0\" isDefault=\"true\"/>\n",
+        SAML_BINDING_POST,
+        escape(&sp_acs_url(connection_name)),
+    ));
+    xml.push_str("  </SPSSODescriptor>\n");
+    xml.push_str("</EntityDescriptor>\n");
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CERT: &str = "MIIC8DCCAdigAwIBAgIQ";
+
+    fn idp_metadata(descriptor: &str) -> String {
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+            <!-- EXPORTED BY THE IDENTITY PROVIDER -->\n\
+            <md:EntityDescriptor xmlns:md=\"urn:oasis:names:tc:SAML:2.0:metadata\" entityID=\"https://idp.acme.com/saml?app=1&amp;env=prod\">\n\
+            {}\n\
+            </md:EntityDescriptor>",
+            descriptor,
+        )
+    }
+
+    #[test]
+    fn unescape_decodes_named_and_numeric_references() {
+        assert_eq!(unescape("a &lt;b&gt; &quot;c&quot; &apos;d&apos;"), "a <b> \"c\" 'd'");
+        assert_eq!(unescape("&#38; &#x26; &#X3C;&#60;"), "& & <<");
+        assert_eq!(unescape("caf&#233; &#x1F600;"), "caf\u{e9} \u{1F600}");
+        // DECODED ONCE, THE RESULT ISN'T DECODED AGAIN
+        assert_eq!(unescape("&amp;lt; &#38;#60;"), "&lt; &#60;");
+    }
+
+    #[test]
+    fn unescape_keeps_what_it_cannot_decode() {
+        assert_eq!(unescape("R&D"), "R&D");
+        assert_eq!(unescape("&nbsp; &#xZZ; &#; &#x110000;"), "&nbsp; &#xZZ; &#; &#x110000;");
+        assert_eq!(unescape("a & b; &lt;"), "a & b; <");
+        assert_eq!(unescape("trailing &"), "trailing &");
+    }
+
+    #[test]
+    fn reads_the_identity_provider_settings() {
+        let xml = idp_metadata(&format!(
+            "<md:IDPSSODescriptor protocolSupportEnumeration=\"urn:oasis:names:tc:SAML:2.0:protocol\">\n\
+                <md:KeyDescriptor use=\"encryption\"><ds:KeyInfo><ds:X509Data><ds:X509Certificate>ENCRYPTION</ds:X509Certificate></ds:X509Data></ds:KeyInfo></md:KeyDescriptor>\n\
+                <md:KeyDescriptor><ds:KeyInfo><ds:X509Data><ds:X509Certificate>\n  {}\n</ds:X509Certificate></ds:X509Data></ds:KeyInfo></md:KeyDescriptor>\n\
+                <md:SingleLogoutService Binding=\"{}\" Location=\"https://idp.acme.com/logout\"/>\n\
+                <md:NameIDFormat>urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress</md:NameIDFormat>\n\
+                <md:SingleSignOnService Binding=\"urn:oasis:names:tc:SAML:2.0:bindings:SOAP\" Location=\"https://idp.acme.com/soap\"/>\n\
+                <md:SingleSignOnService Binding=\"{}\" Location=\"https://idp.acme.com/post\"/>\n\
+                <md:SingleSignOnService Binding='{}' Location='https://idp.acme.com/sso?a=1&#38;b=&#x32;'/>\n\
+            </md:IDPSSODescriptor>",
+            CERT, SAML_BINDING_POST, SAML_BINDING_POST, SAML_BINDING_REDIRECT,
+        ));
+        let metadata = parse_idp_metadata(&xml).unwrap();
+        assert_eq!(metadata.entity_id, "https://idp.acme.com/saml?app=1&env=prod");
+        assert_eq!(metadata.sign_in_endpoint, "https://idp.acme.com/sso?a=1&b=2");
+        assert_eq!(metadata.sign_in_binding, SAML_BINDING_REDIRECT);
+        assert_eq!(metadata.sign_out_endpoint, "https://idp.acme.com/logout");
+        assert_eq!(metadata.signing_cert, certificate_to_pem(CERT));
+        assert_eq!(metadata.name_id_format, "urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress");
+    }
+
+    #[test]
+    fn apply_keeps_the_attribute_mapping() {
+        let mut options = SamlOptions::default();
+        options.fields_map.insert(String::from("email"), String::from("mail"));
+        let metadata = IdpMetadata {
+            entity_id: String::from("urn:idp"),
+            sign_in_endpoint: String::from("https://idp.acme.com/sso"),
+            sign_in_binding: String::from(SAML_BINDING_POST),
+            sign_out_endpoint: String::from(""),
+            signing_cert: certificate_to_pem(CERT),
+            name_id_format: String::from(""),
+        };
+        let applied = metadata.apply(&options);
+        assert_eq!(applied.sign_in_endpoint, "https://idp.acme.com/sso");
+        assert_eq!(applied.protocol_binding, SAML_BINDING_POST);
+        assert_eq!(applied.name_id_format, options.name_id_format);
+        assert_eq!(applied.fields_map, options.fields_map);
+    }
+
+    #[test]
+    fn rejects_documents_it_cannot_use() {
+        let sso = format!("<SingleSignOnService Binding=\"{}\" Location=\"https://idp.acme.com/sso\"/>", SAML_BINDING_POST);
+        let key = format!("<KeyDescriptor><X509Certificate>{}</X509Certificate></KeyDescriptor>", CERT);
+        let cases = [
+            (idp_metadata("<SPSSODescriptor></SPSSODescriptor>"), "no IDPSSODescriptor"),
+            (idp_metadata(&format!("<IDPSSODescriptor>{}</IDPSSODescriptor>", key)), "no SingleSignOnService"),
+            (idp_metadata(&format!("<IDPSSODescriptor>{}</IDPSSODescriptor>", sso)), "no signing certificate"),
+            (idp_metadata(&format!("<IDPSSODescriptor>{}{}</KeyDescriptor>", sso, key)), "</KeyDescriptor> closes <IDPSSODescriptor>"),
+            (idp_metadata(&format!("<IDPSSODescriptor>{}{}", sso, key)), "</EntityDescriptor> closes <IDPSSODescriptor>"),
+            (String::from("<EntityDescriptor entityID=\"urn:idp\""), "is never closed"),
+        ];
+        for (xml, message) in cases.iter() {
+            let error = parse_idp_metadata(xml).unwrap_err();
+            assert!(error.contains(message), "{:?} should contain {:?}", error, message);
+        }
+    }
+
+    #[test]
+    fn sp_metadata_escapes_what_it_writes() {
+        let options = SamlOptions {
+            name_id_format: String::from("urn:format&<custom>"),
+            sign_saml_request: true,
+            ..SamlOptions::default()
+        };
+        let xml = sp_metadata("acme", &options);
+        assert!(xml.contains("AuthnRequestsSigned=\"true\""));
+        assert!(xml.contains("<NameIDFormat>urn:format&amp;&lt;custom&gt;</NameIDFormat>"));
+        assert!(xml.contains(&format!("Location=\"{}\"", escape(&sp_acs_url("acme")))));
+        assert_eq!(unescape(&escape("a&b<c>\"d\"")), "a&b<c>\"d\"");
+    }
+}
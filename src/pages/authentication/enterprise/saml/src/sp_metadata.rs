@@ -0,0 +1,166 @@
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+use types::enterprise::SamlOptions;
+use crate::metadata::{
+    sp_acs_url,
+    sp_entity_id,
+    sp_logout_url,
+    sp_metadata,
+};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct SpMetadataProps {
+    pub connection_name: String,
+    pub options: SamlOptions,
+}
+
+pub struct SpMetadata {
+    props: SpMetadataProps,
+    link: ComponentLink<Self>,
+    error_download: Option<String>,
+}
+
+pub enum Msg {
+    Download,
+}
+
+impl Component for SpMetadata {
+    type Message = Msg;
+    type Properties = SpMetadataProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        SpMetadata {
+            props,
+            link,
+            error_download: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Download => {
+                let xml = sp_metadata(&self.props.connection_name, &self.props.options);
+                let file_name = format!("{}-metadata.xml", self.props.connection_name);
+                self.error_download = download(&xml, &file_name).err();
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let name = self.props.connection_name.clone();
+        html! {
+            <div
+                class="container border rounded p-4 d-flex flex-column mb-5"
+                style="font-size: 14px;"
+            >
+                <div
+                    class="row"
+                >
+                    <div
+                        class="col-lg-5 text-color-primary fw-bold mb-4"
+                        style="width: 40%;"
+                    >
+                        {"Service Provider"}
+                        <p class="text-color-disabled fw-normal mt-2">
+                            {"Give these details to the administrator of the identity provider to register this connection."}
+                        </p>
+                    </div>
+                    <div
+                        class="col-lg-7"
+                    >
+                        { view_value("Entity ID", sp_entity_id(&name)) }
+                        { view_value("Assertion Consumer Service URL", sp_acs_url(&name)) }
+                        { view_value("Single Logout URL", sp_logout_url(&name)) }
+
+                        <div
+                            class="mb-4"
+                        >
+                            <p class="mb-2 fw-bold">
+                                {"Metadata"}
+                            </p>
+                            <textarea
+                                class="form-control bg-input-grey mb-2"
+                                rows="12"
+                                style="font-family: 'Roboto Mono', monospace; font-size: 12px;"
+                                value=sp_metadata(&name, &self.props.options)
+                                readonly=true
+                            />
+                            <button
+                                type="button"
+                                class="btn btn-outline-primary btn-sm"
+                                onclick=self.link.callback(|_| Msg::Download)
+                            >
+                                <i class="bi bi-download me-2"></i>
+                                {"Download Metadata"}
+                            </button>
+                            {
+                                if self.error_download.is_some() {
+                                    html! {
+                                        <p class="text-danger mt-2 mb-0">
+                                            <i class="bi bi-exclamation-triangle me-2"></i>
+                                            { self.error_download.clone().unwrap() }
+                                        </p>
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+fn view_value(label: &'static str, value: String) -> Html {
+    html! {
+        <div
+            class="mb-4"
+        >
+            <p class="mb-2 fw-bold">
+                { label }
+            </p>
+            <div class="input-group mb-2">
+                <input
+                    type="text"
+                    class="form-control bg-input-grey"
+                    style="font-family: 'Roboto Mono', monospace;"
+                    value=value
+                    readonly=true
+                />
+            </div>
+        </div>
+    }
+}
+
+fn download(content: &str, file_name: &str) -> Result<(), String> {
+    let parts = js_sys::Array::new();
+    parts.push(&content.into());
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("application/samlmetadata+xml");
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)
+        .map_err(|_| String::from("Could not create the file"))?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)
+        .map_err(|_| String::from("Could not create the file"))?;
+    let anchor = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.create_element("a").ok())
+        .and_then(|element| element.dyn_into::<web_sys::HtmlAnchorElement>().ok())
+        .ok_or_else(|| String::from("Could not start the download"))?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+    let _ = web_sys::Url::revoke_object_url(&url);
+    Ok(())
+}
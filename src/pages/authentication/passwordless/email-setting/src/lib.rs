@@ -1,19 +1,239 @@
-use yew::prelude::*;
+use std::collections::BTreeMap;
+use yew::{
+    format::{Json, Nothing, Text},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+        ConsoleService,
+    },
+};
+use configs::server::API_URL;
+use types::{
+    passwordless::{
+        validate_passwordless_email_options,
+        EmailTemplate,
+        PasswordlessEmailConnection,
+        PasswordlessEmailConnectionCreate,
+        PasswordlessEmailConnectionUpdate,
+        OTP_LENGTH_MAX,
+        OTP_LENGTH_MIN,
+        PASSWORDLESS_EMAIL_STRATEGY,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
 
-pub struct EmailSettings {}
+pub mod template;
 
-pub enum Msg {}
+use template::TemplateEditor;
+
+pub enum Data {
+    OtpExpiry,
+    OtpLength,
+}
+
+pub struct EmailSettings {
+    access_token: String,
+    link: ComponentLink<Self>,
+    connection: PasswordlessEmailConnection,
+    // AS RETURNED BY THE API, FOR "Reset to last saved"
+    saved: PasswordlessEmailConnection,
+    auth_params: String,
+    error_auth_params: Option<String>,
+    fetch_task: Option<FetchTask>,
+    loading_request_connection: bool,
+    error_request_connection: Option<String>,
+    loading_update: bool,
+    error_update: Option<String>,
+    message_update: Option<String>,
+}
+
+pub enum Msg {
+    RequestConnection,
+    GetConnection(Vec<PasswordlessEmailConnection>),
+    InputTemplate(EmailTemplate),
+    InputAuthParams(String),
+    InputNumber(String, Data),
+    ToggleDisableSignup,
+    Update,
+    GetUpdatedConnection(PasswordlessEmailConnection),
+    ResponseError(String),
+}
 
 impl Component for EmailSettings {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
-        EmailSettings {}
+    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        EmailSettings {
+            access_token,
+            link,
+            connection: PasswordlessEmailConnection::new(),
+            saved: PasswordlessEmailConnection::new(),
+            auth_params: String::from("{}"),
+            error_auth_params: None,
+            fetch_task: None,
+            loading_request_connection: false,
+            error_request_connection: None,
+            loading_update: false,
+            error_update: None,
+            message_update: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestConnection);
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        true
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestConnection => {
+                let request = Request::get(format!("{}/api/v2/connections?strategy={}", API_URL, PASSWORDLESS_EMAIL_STRATEGY))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<PasswordlessEmailConnection>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetConnection(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_connection = None;
+                self.loading_request_connection = true;
+                true
+            }
+            Msg::GetConnection(data) => {
+                // NO EMAIL CONNECTION YET, THE DEFAULTS ARE CREATED ON SAVE
+                let connection = data.into_iter().next().unwrap_or_else(PasswordlessEmailConnection::new);
+                self.set_connection(connection);
+                self.loading_request_connection = false;
+                self.fetch_task = None;
+                true
+            }
+            Msg::InputTemplate(template) => {
+                self.connection.options.email = template;
+                self.message_update = None;
+                true
+            }
+            Msg::InputAuthParams(value) => {
+                match serde_json::from_str::<BTreeMap<String, String>>(&value) {
+                    Ok(auth_params) => {
+                        self.connection.options.auth_params = auth_params;
+                        self.error_auth_params = None;
+                    }
+                    Err(_) => {
+                        self.error_auth_params = Some(String::from("Must be a JSON object of strings, eg. {\"scope\": \"openid email\"}"));
+                    }
+                }
+                self.auth_params = value;
+                self.message_update = None;
+                true
+            }
+            Msg::InputNumber(value, data) => {
+                let number = value.trim().parse::<u32>().unwrap_or(0);
+                match data {
+                    Data::OtpExpiry => self.connection.options.totp.time_step = number,
+                    Data::OtpLength => self.connection.options.totp.length = number,
+                }
+                self.message_update = None;
+                true
+            }
+            Msg::ToggleDisableSignup => {
+                self.connection.options.disable_signup = !self.connection.options.disable_signup;
+                self.message_update = None;
+                true
+            }
+            Msg::Update => {
+                // VALIDATION
+                if let Some(message) = self.error_auth_params.clone() {
+                    self.error_update = Some(format!("Authentication Parameter: {}", message));
+                    return true;
+                }
+                if let Err(message) = validate_passwordless_email_options(&self.connection.options) {
+                    self.error_update = Some(message);
+                    return true;
+                }
+
+                // THE FIRST SAVE CREATES THE CONNECTION
+                let (request, body): (_, Text) = if self.connection.id.is_empty() {
+                    (
+                        Request::post(format!("{}/api/v2/connections", API_URL)),
+                        Json(&PasswordlessEmailConnectionCreate::from(&self.connection)).into(),
+                    )
+                } else {
+                    (
+                        Request::patch(format!("{}/api/v2/connections/{}", API_URL, self.connection.id.clone())),
+                        Json(&PasswordlessEmailConnectionUpdate::from(&self.connection)).into(),
+                    )
+                };
+                let request = request
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(body)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<PasswordlessEmailConnection, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetUpdatedConnection(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_update = None;
+                self.message_update = None;
+                self.loading_update = true;
+                true
+            }
+            Msg::GetUpdatedConnection(data) => {
+                ConsoleService::info(&format!("connection = {:?}", data));
+                self.set_connection(data);
+                self.loading_update = false;
+                self.message_update = Some(String::from("Email settings have been saved"));
+                self.fetch_task = None;
+                true
+            }
+            Msg::ResponseError(message) => {
+                if self.loading_update {
+                    self.loading_update = false;
+                    self.error_update = Some(message);
+                } else {
+                    self.loading_request_connection = false;
+                    self.error_request_connection = Some(message);
+                }
+                self.fetch_task = None;
+                true
+            }
+        }
     }
 
     fn change(&mut self, _: Self::Properties) -> ShouldRender {
@@ -21,52 +241,74 @@ impl Component for EmailSettings {
     }
 
     fn view(&self) -> Html {
+        if self.loading_request_connection {
+            return html! {
+                <div class="d-flex justify-content-center p-5">
+                    <div class="spinner-border text-primary" role="status">
+                        <span class="visually-hidden">{"Loading..."}</span>
+                    </div>
+                </div>
+            };
+        }
+        if self.error_request_connection.is_some() {
+            return html! {
+                <div class="alert alert-warning" role="alert">
+                    <i class="bi bi-exclamation-triangle me-2"></i>
+                    { self.error_request_connection.clone().unwrap() }
+                </div>
+            };
+        }
+
+        let options = self.connection.options.clone();
+        let disabled = self.loading_update;
         html! {
             <div class="p-2" style="font-size: 14px;">
                 <div class="mb-3">
                     <label class="form-label">{"Connection"}</label>
-                    <input class="form-control" type="text" value="email" aria-label="Disabled input example" disabled=true readonly=true/>
+                    <input class="form-control" type="text" value=self.connection.name.clone() aria-label="Disabled input example" disabled=true readonly=true/>
                     <p>{"If you are triggering a login manually, this is the identifier you would use on the connection parameter"}</p>
                 </div>
 
-                <div class="mb-3">
-                    <label class="form-label">{"From"}</label>
-                    <input class="form-control" type="text" placeholder="myname@mycompany.com" aria-label="from input example"/>
-                </div>
-
-                <div class="mb-3">
-                    <label class="form-label">{"Subject"}</label>
-                    <input class="form-control" type="text" placeholder="Welcome to {{application name}}" aria-label="app subject input example"/>
-                </div>
-
-                <div class="mb-3 w-75">
-                    <label class="form-label">{"Body"}</label>
-                    <p>{"The content of the email your users are going to receive."}</p>
-                    <div class="mb-2 row 2-50">
-                        <div class="col">
-                        <button  type="button" class="text-color-primary btn btn-secondary btn-default:hover" style="background-color:#96989d !important; font-size:12px; ">{"Reset to last saved"}</button>
-                        </div>
-                        <div class="col">
-                        <button type="button" class="text-color-primary btn btn-secondary btn-default:hover" style="background-color:#96989d !important; font-size:12px;">{"Reset to default"}</button>
-                        </div>
-                    </div>
-                </div>
+                <TemplateEditor
+                    template=options.email.clone()
+                    saved=self.saved.options.email.clone()
+                    disabled=disabled
+                    on_change=self.link.callback(Msg::InputTemplate)
+                />
 
                 <div class="mb-3">
                     <label class="form-label">{"Authentication Parameter"}</label>
                     <div class="form-floating">
-                        <textarea class="form-control text-light pt-1" placeholder="Your verification code is: @@password@@" style="background-color:rgb(47,56,61); height:100px;" id="floatingTextarea2"></textarea>
+                        <textarea
+                            class=classes!("form-control", "text-light", "pt-1", if self.error_auth_params.is_some() { "is-invalid" } else { "" })
+                            placeholder="{\"scope\": \"openid email\"}"
+                            style="background-color:rgb(47,56,61); height:100px;"
+                            value=self.auth_params.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::InputAuthParams(data.value))
+                        />
+                        {
+                            match &self.error_auth_params {
+                                Some(error) => html! { <div class="invalid-feedback">{ error.clone() }</div> },
+                                None => html! {},
+                            }
+                        }
                     </div>
-                    <p>{"Query string parameters to be included as part of the generated link.
-
-                    "}</p>
+                    <p>{"Query string parameters to be included as part of the generated link."}</p>
                 </div>
 
-
-                 <div class="mb-3">
+                <div class="mb-3">
                     <label class="form-label">{"OTP Expiry"}</label>
                     <div class="input-group mb-3">
-                        <input type="number" class="form-control" min="1" aria-label="Recipient's username" aria-describedby="otpExpiry"/>
+                        <input
+                            type="number"
+                            class="form-control"
+                            min="1"
+                            aria-describedby="otpExpiry"
+                            value=options.totp.time_step.to_string()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::InputNumber(data.value, Data::OtpExpiry))
+                        />
                         <span class="input-group-text" id="otpExpiry">{"seconds"}</span>
                     </div>
                     <p>{"The time step, in seconds, between new passwords."}</p>
@@ -75,8 +317,17 @@ impl Component for EmailSettings {
                 <div class="mb-3">
                     <label class="form-label">{"OTP Length"}</label>
                     <div class="input-group mb-3">
-                        <input type="number" class="form-control" min="1" aria-label="Recipient's username" aria-describedby="otpLength"/>
-                        <span class="input-group-text" id="otpLength">{"seconds"}</span>
+                        <input
+                            type="number"
+                            class="form-control"
+                            min=OTP_LENGTH_MIN.to_string()
+                            max=OTP_LENGTH_MAX.to_string()
+                            aria-describedby="otpLength"
+                            value=options.totp.length.to_string()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::InputNumber(data.value, Data::OtpLength))
+                        />
+                        <span class="input-group-text" id="otpLength">{"digits"}</span>
                     </div>
                     <p>{"The length of the resulting one-time password."}</p>
                 </div>
@@ -84,16 +335,63 @@ impl Component for EmailSettings {
                 <div class="mb-3">
                     <label class="form-label">{"Disable Sign Ups"}</label>
                     <div class="form-check form-switch">
-                        <input class="form-check-input" type="checkbox" id="flexSwitchCheckDefault"/>
+                        <input
+                            class="form-check-input"
+                            type="checkbox"
+                            checked=options.disable_signup
+                            disabled=disabled
+                            onclick=self.link.callback(|_| Msg::ToggleDisableSignup)
+                        />
                     </div>
                     <p class="text-muted">{"Check this if you want to prevent sign ups to your application. You will still be able to create users with your API credentials."}</p>
                 </div>
 
+                {
+                    if self.error_update.is_some() {
+                        html! {
+                            <div class="alert alert-warning" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_update.clone().unwrap() }
+                            </div>
+                        }
+                    } else if self.message_update.is_some() {
+                        html! {
+                            <div class="alert alert-success" role="alert">
+                                <i class="bi bi-check-circle me-2"></i>
+                                { self.message_update.clone().unwrap() }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+
                 <div class="modal-footer">
-                    <button type="button" class="btn btn-primary">{"Save"}</button>
+                    <button
+                        type="button"
+                        class=format!("btn {} btn-primary position-relative", if self.loading_update {"loading"} else {""} )
+                        onclick=self.link.callback(|_| Msg::Update)
+                        disabled=disabled
+                    >
+                        <div class="telkom-label">
+                            {"Save"}
+                        </div>
+                        <div class="telkom-spinner telkom-center">
+                            <div class="spinner-border spinner-border-sm" role="status"/>
+                        </div>
+                    </button>
                 </div>
 
             </div>
         }
     }
 }
+
+impl EmailSettings {
+    fn set_connection(&mut self, connection: PasswordlessEmailConnection) {
+        self.auth_params = serde_json::to_string_pretty(&connection.options.auth_params).unwrap_or_else(|_| String::from("{}"));
+        self.error_auth_params = None;
+        self.saved = connection.clone();
+        self.connection = connection;
+    }
+}
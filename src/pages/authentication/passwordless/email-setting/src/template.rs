@@ -0,0 +1,207 @@
+use yew::prelude::*;
+use types::passwordless::{
+    render_template,
+    template_samples,
+    template_variables,
+    unknown_template_variables,
+    EmailTemplate,
+    Escape,
+    DEFAULT_EMAIL_BODY,
+    EMAIL_TEMPLATE_VARIABLES,
+};
+
+// FROM, SUBJECT AND BODY OF THE EMAIL, WITH A PREVIEW RENDERED FROM SAMPLE VALUES
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TemplateEditorProps {
+    pub template: EmailTemplate,
+    // LAST TEMPLATE RETURNED BY THE API, FOR "Reset to last saved"
+    pub saved: EmailTemplate,
+    pub disabled: bool,
+    pub on_change: Callback<EmailTemplate>,
+}
+
+#[derive(Clone, Copy)]
+pub enum Data {
+    From,
+    Subject,
+    Body,
+}
+
+pub struct TemplateEditor {
+    props: TemplateEditorProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    Input(String, Data),
+    InsertVariable(&'static str),
+    ResetToSaved,
+    ResetToDefault,
+}
+
+impl Component for TemplateEditor {
+    type Message = Msg;
+    type Properties = TemplateEditorProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        TemplateEditor {
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut template = self.props.template.clone();
+        match msg {
+            Msg::Input(value, data) => {
+                match data {
+                    Data::From => template.from = value,
+                    Data::Subject => template.subject = value,
+                    Data::Body => template.body = value,
+                }
+            }
+            Msg::InsertVariable(name) => {
+                template.body.push_str(&format!("{{{{ {} }}}}", name));
+            }
+            Msg::ResetToSaved => {
+                template.body = self.props.saved.body.clone();
+            }
+            Msg::ResetToDefault => {
+                template.body = String::from(DEFAULT_EMAIL_BODY);
+            }
+        }
+        self.props.on_change.emit(template);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let template = self.props.template.clone();
+        let disabled = self.props.disabled;
+        let samples = template_samples(&EMAIL_TEMPLATE_VARIABLES);
+        html! {
+            <>
+                { self.view_input("From", "{{ application.name }} <root@mycompany.com>", &template.from, Data::From) }
+                { self.view_input("Subject", "Welcome to {{ application.name }}", &template.subject, Data::Subject) }
+
+                <div class="mb-3">
+                    <label class="form-label">{"Body"}</label>
+                    <p>{"The content of the email your users are going to receive. Click a variable to append it."}</p>
+                    <div class="mb-2">
+                        {
+                            for EMAIL_TEMPLATE_VARIABLES.iter().map(|(name, description, _)| {
+                                let name = *name;
+                                html! {
+                                    <button
+                                        type="button"
+                                        class="btn btn-sm btn-outline-secondary me-2 mb-2"
+                                        title=description.to_string()
+                                        disabled=disabled
+                                        onclick=self.link.callback(move |_| Msg::InsertVariable(name))
+                                    >
+                                        { format!("{{{{ {} }}}}", name) }
+                                    </button>
+                                }
+                            })
+                        }
+                    </div>
+                    <textarea
+                        class=classes!("form-control", "text-light", "font-monospace", if self.has_problem(&template.body) { "is-invalid" } else { "" })
+                        style="background-color:rgb(47,56,61); height:240px; font-size: 12px;"
+                        value=template.body.clone()
+                        disabled=disabled
+                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::Body))
+                    />
+                    { self.view_problems(&template.body) }
+                    <div class="mt-2 row">
+                        <div class="col">
+                            <button
+                                type="button"
+                                class="text-color-primary btn btn-secondary btn-default:hover"
+                                style="background-color:#96989d !important; font-size:12px;"
+                                disabled={ disabled || template.body == self.props.saved.body }
+                                onclick=self.link.callback(|_| Msg::ResetToSaved)
+                            >
+                                {"Reset to last saved"}
+                            </button>
+                        </div>
+                        <div class="col">
+                            <button
+                                type="button"
+                                class="text-color-primary btn btn-secondary btn-default:hover"
+                                style="background-color:#96989d !important; font-size:12px;"
+                                disabled={ disabled || template.body == DEFAULT_EMAIL_BODY }
+                                onclick=self.link.callback(|_| Msg::ResetToDefault)
+                            >
+                                {"Reset to default"}
+                            </button>
+                        </div>
+                    </div>
+                </div>
+
+                <div class="mb-3">
+                    <label class="form-label">{"Preview"}</label>
+                    <p class="text-muted mb-1">
+                        { format!("Subject: {}", render_template(&template.subject, &samples, Escape::None)) }
+                    </p>
+                    // NO SCRIPTS, FORMS OR NAVIGATION OUT OF THE PREVIEW
+                    <iframe
+                        class="border rounded w-100 bg-white"
+                        style="height: 360px;"
+                        title="Email preview"
+                        sandbox=""
+                        srcdoc=render_template(&template.body, &samples, Escape::Html)
+                    />
+                </div>
+            </>
+        }
+    }
+}
+
+impl TemplateEditor {
+    fn has_problem(&self, template: &str) -> bool {
+        template_variables(template).is_err() || !unknown_template_variables(template, &EMAIL_TEMPLATE_VARIABLES).is_empty()
+    }
+
+    fn view_problems(&self, template: &str) -> Html {
+        if let Err(message) = template_variables(template) {
+            return html! { <div class="invalid-feedback">{ message }</div> };
+        }
+        let unknown = unknown_template_variables(template, &EMAIL_TEMPLATE_VARIABLES);
+        if unknown.is_empty() {
+            html! {}
+        } else {
+            html! {
+                <div class="invalid-feedback">
+                    { format!("Unknown variables: {}", unknown.join(", ")) }
+                </div>
+            }
+        }
+    }
+
+    fn view_input(&self, label: &'static str, placeholder: &'static str, value: &str, data: Data) -> Html {
+        html! {
+            <div class="mb-3">
+                <label class="form-label">{ label }</label>
+                <input
+                    class=classes!("form-control", if self.has_problem(value) { "is-invalid" } else { "" })
+                    type="text"
+                    placeholder=placeholder
+                    value=value.to_string()
+                    disabled=self.props.disabled
+                    oninput=self.link.callback(move |data_input: InputData| Msg::Input(data_input.value, data))
+                />
+                { self.view_problems(value) }
+            </div>
+        }
+    }
+}
@@ -1,19 +1,112 @@
-use yew::prelude::*;
-
-pub struct EmailTry {}
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+    },
+};
+use configs::server::API_URL;
+use passwordless_try_flow::TryFlow;
+use types::{
+    passwordless::{
+        PasswordlessEmailConnection,
+        PASSWORDLESS_EMAIL_STRATEGY,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+
+pub struct EmailTry {
+    access_token: String,
+    link: ComponentLink<Self>,
+    connection: Option<PasswordlessEmailConnection>,
+    fetch_task: Option<FetchTask>,
+    loading_request_connection: bool,
+    error_request_connection: Option<String>,
+}
 
-pub enum Msg {}
+pub enum Msg {
+    RequestConnection,
+    GetConnection(Vec<PasswordlessEmailConnection>),
+    ResponseError(String),
+}
 
 impl Component for EmailTry {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
-        EmailTry {}
+    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        EmailTry {
+            access_token,
+            link,
+            connection: None,
+            fetch_task: None,
+            loading_request_connection: false,
+            error_request_connection: None,
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        true
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestConnection);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestConnection => {
+                let request = Request::get(format!("{}/api/v2/connections?strategy={}", API_URL, PASSWORDLESS_EMAIL_STRATEGY))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<PasswordlessEmailConnection>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetConnection(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_connection = None;
+                self.loading_request_connection = true;
+                true
+            }
+            Msg::GetConnection(data) => {
+                self.connection = data.into_iter().next();
+                self.loading_request_connection = false;
+                self.fetch_task = None;
+                true
+            }
+            Msg::ResponseError(message) => {
+                self.loading_request_connection = false;
+                self.error_request_connection = Some(message);
+                self.fetch_task = None;
+                true
+            }
+        }
     }
 
     fn change(&mut self, _: Self::Properties) -> ShouldRender {
@@ -21,65 +114,41 @@ impl Component for EmailTry {
     }
 
     fn view(&self) -> Html {
-        html! {
-           <div class="p-2" style="font-size: 14px;">
-                
-                <div class="mt-2 mb-3">
-                    <div class="alert alert-primary d-flex align-items-center" role="alert">
-                        <svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" fill="currentColor" class="bi bi-exclamation-triangle-fill flex-shrink-0 me-2" viewBox="0 0 16 16" role="img" aria-label="Warning:">
-                            <path d="M8.982 1.566a1.13 1.13 0 0 0-1.96 0L.165 13.233c-.457.778.091 1.767.98 1.767h13.713c.889 0 1.438-.99.98-1.767L8.982 1.566zM8 5c.535 0 .954.462.9.995l-.35 3.507a.552.552 0 0 1-1.1 0L7.1 5.995A.905.905 0 0 1 8 5zm.002 6a1 1 0 1 1 0 2 1 1 0 0 1 0-2z"/>
-                        </svg>
-                        <div>
-                            {"You need to enable one application at least in order to be able to try this connection."}
-                        </div>
-                    </div>    
-                </div>
-
-                <div class="mb-3">
-                    <p class="text-muted">{"Try this connection by specifying an application and a recipient."}</p>
-                </div>
-
-                <div class="mb-3">
-                    <label class="form-label text-muted">{"Application"}</label>
-                    <select class="form-select" aria-label="Disabled select example" disabled=true>
-                        <option selected=true>{""}</option>
-                        <option value="1">{""}</option>
-                        <option value="2">{""}</option>
-                        <option value="3">{""}</option>
-                    </select>
-                    <div class="pt-1">
-                        <p class="text-muted">{"The application on which you want to try this connection."}</p>
+        if self.loading_request_connection {
+            return html! {
+                <div class="d-flex justify-content-center p-5">
+                    <div class="spinner-border text-primary" role="status">
+                        <span class="visually-hidden">{"Loading..."}</span>
                     </div>
                 </div>
-
-
-                <div class="mb-3">
-                    <label class="form-label text-muted">{"Email recipient"}</label>
-                    <input type="text" id="disabledTextInput" class="form-control" placeholder="yourmail@mail.com" disabled=true />
-                    <div class="pt-1">
-                        <p class="text-muted">{"The email address which will receive the test email."}</p>
-                    </div>
+            };
+        }
+        if self.error_request_connection.is_some() {
+            return html! {
+                <div class="alert alert-warning" role="alert">
+                    <i class="bi bi-exclamation-triangle me-2"></i>
+                    { self.error_request_connection.clone().unwrap() }
                 </div>
-
-
-                <div class="mb-3">
-                    <label class="form-label text-muted">{"Mode"}</label>
-                    <select class="form-select text-muted" aria-label="Disabled select example" disabled=true>
-                        <option selected=true value="code">{"code"}</option>
-                        <option value="1">{""}</option>
-                        <option value="2">{""}</option>
-                        <option value="3">{""}</option>
-                    </select>
-                    <div class="pt-1">
-                        <p class="text-muted">{"Specify whether you want to receive a magic link or a code."}</p>
-                    </div>
+            };
+        }
+        let connection = match &self.connection {
+            Some(connection) => connection,
+            None => return html! {
+                <div class="alert alert-primary" role="alert">
+                    <i class="bi bi-info-circle me-2"></i>
+                    {"Save the email settings first, then come back to try them."}
                 </div>
+            },
+        };
 
-
-
-           </div>
-        
+        html! {
+            <div class="p-2" style="font-size: 14px;">
+                <TryFlow
+                    strategy=PASSWORDLESS_EMAIL_STRATEGY.to_string()
+                    connection=connection.name.clone()
+                    enabled_clients=connection.enabled_clients.clone()
+                />
+            </div>
         }
-    }   
-
+    }
 }
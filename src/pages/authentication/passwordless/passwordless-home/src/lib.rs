@@ -126,10 +126,10 @@ impl Component for AuthPasswordLess {
                     aria-labelledby="exampleModalLabel"
                     aria-hidden="true"
                 >
-                    <div class="modal-dialog modal-dialog-scrollable">
+                    <div class="modal-dialog modal-lg modal-dialog-scrollable">
                         <div class="modal-content">
                             <div class="modal-header">
-                                <h5 class="modal-title" id="exampleModalLabel">{"SMS (Twilio)"}</h5>
+                                <h5 class="modal-title" id="exampleModalLabel">{"SMS"}</h5>
                                 <button type="button" class="btn-close" data-bs-dismiss="modal" aria-label="Close"></button>
                             </div>
                             <div class="modal-body" style="font-size: 14px;">
@@ -219,7 +219,7 @@ impl Component for AuthPasswordLess {
                     aria-hidden="true"
                     // style="overflow: hidden;"
                 >
-                    <div class="modal-dialog modal-dialog-scrollable">
+                    <div class="modal-dialog modal-lg modal-dialog-scrollable">
                         <div class="modal-content">
                             <div class="modal-header">
                                 <h5 class="modal-title" id="exampleModalLabel">{"Email"}</h5>
@@ -0,0 +1,561 @@
+use yew::{
+    format::{Json, Nothing, Text},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+        ConsoleService,
+    },
+};
+use configs::server::API_URL;
+use types::{
+    application::AppList,
+    passwordless::{
+        validate_otp,
+        validate_passwordless_recipient,
+        PasswordlessStart,
+        PasswordlessVerify,
+        PASSWORDLESS_SEND_CODE,
+        PASSWORDLESS_SEND_MODES,
+        PASSWORDLESS_SMS_STRATEGY,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+
+pub mod timeline;
+
+use timeline::{error_message, now, view_timeline, TryEvent};
+
+// STARTS A REAL PASSWORDLESS LOGIN ON AN ENABLED APPLICATION, THEN VERIFIES THE
+// RECEIVED CODE, SHARED BY THE EMAIL AND SMS TRY TABS
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TryFlowProps {
+    // email OR sms
+    pub strategy: String,
+    pub connection: String,
+    pub enabled_clients: Vec<String>,
+}
+
+// THE LOGIN WAITING FOR ITS CODE
+#[derive(Clone, Debug)]
+pub struct Started {
+    client_id: String,
+    recipient: String,
+    send: String,
+}
+
+pub struct TryFlow {
+    access_token: String,
+    link: ComponentLink<Self>,
+    props: TryFlowProps,
+    applications: Vec<AppList>,
+    client_id: String,
+    recipient: String,
+    send: String,
+    otp: String,
+    started: Option<Started>,
+    events: Vec<TryEvent>,
+    fetch_task: Option<FetchTask>,
+    loading_request_applications: bool,
+    error_request_applications: Option<String>,
+    loading_start: bool,
+    loading_verify: bool,
+    error_try: Option<String>,
+}
+
+pub enum Msg {
+    RequestApplications,
+    GetApplications(Vec<AppList>),
+    SelectApplication(String),
+    InputRecipient(String),
+    SelectSend(String),
+    InputOtp(String),
+    Start,
+    GetStartResponse(u16, String),
+    Verify,
+    GetVerifyResponse(u16, String),
+    ResponseError(String),
+    ClearTimeline,
+    Ignore,
+}
+
+impl Component for TryFlow {
+    type Message = Msg;
+    type Properties = TryFlowProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        TryFlow {
+            access_token,
+            link,
+            props,
+            applications: vec![],
+            client_id: String::from(""),
+            recipient: String::from(""),
+            send: String::from(PASSWORDLESS_SEND_CODE),
+            otp: String::from(""),
+            started: None,
+            events: vec![],
+            fetch_task: None,
+            loading_request_applications: false,
+            error_request_applications: None,
+            loading_start: false,
+            loading_verify: false,
+            error_try: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestApplications);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestApplications => {
+                let request = Request::get(format!("{}/api/v2/clients", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<AppList>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetApplications(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_applications = None;
+                self.loading_request_applications = true;
+                true
+            }
+            Msg::GetApplications(data) => {
+                // ONLY THE APPLICATIONS THIS CONNECTION IS ENABLED FOR CAN LOG IN WITH IT
+                self.applications = data
+                    .into_iter()
+                    .filter(|application| self.props.enabled_clients.contains(&application.client_id))
+                    .collect();
+                if let Some(application) = self.applications.first() {
+                    self.client_id = application.client_id.clone();
+                }
+                self.loading_request_applications = false;
+                self.fetch_task = None;
+                true
+            }
+            Msg::SelectApplication(client_id) => {
+                self.client_id = client_id;
+                true
+            }
+            Msg::InputRecipient(value) => {
+                self.recipient = value.trim().to_string();
+                self.error_try = None;
+                true
+            }
+            Msg::SelectSend(send) => {
+                self.send = send;
+                true
+            }
+            Msg::InputOtp(value) => {
+                self.otp = value.trim().to_string();
+                self.error_try = None;
+                true
+            }
+            Msg::Start => {
+                if let Err(message) = validate_passwordless_recipient(&self.props.strategy, &self.recipient) {
+                    self.error_try = Some(message);
+                    return true;
+                }
+                let data_start = PasswordlessStart::new(
+                    &self.client_id,
+                    &self.props.strategy,
+                    &self.props.connection,
+                    &self.recipient,
+                    &self.send,
+                );
+                let url = format!("{}/passwordless/start", API_URL);
+                self.events.push(TryEvent {
+                    time: now(),
+                    title: String::from("Start"),
+                    detail: format!("Asking for a {} to be sent to {}", self.send, self.recipient),
+                    ok: true,
+                    request: Some(format!("POST {}\n\n{}", url, serde_json::to_string_pretty(&data_start).unwrap_or_default())),
+                    status: None,
+                    response: None,
+                });
+                let request = Request::post(url)
+                    .header("Content-Type", "application/json")
+                    .body(Json(&data_start))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(|response: Response<Text>| {
+                    let (meta, body) = response.into_parts();
+                    Msg::GetStartResponse(meta.status.as_u16(), body.unwrap_or_default())
+                });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.started = Some(Started {
+                    client_id: self.client_id.clone(),
+                    recipient: self.recipient.clone(),
+                    send: self.send.clone(),
+                });
+                self.otp = String::from("");
+                self.error_try = None;
+                self.loading_start = true;
+                true
+            }
+            Msg::GetStartResponse(status, body) => {
+                ConsoleService::info(&format!("passwordless start = {} {}", status, body));
+                let ok = (200..300).contains(&status);
+                let started = self.started.clone();
+                let detail = match (&started, ok) {
+                    (Some(started), true) if started.send == PASSWORDLESS_SEND_CODE => {
+                        format!("A code was sent to {}, type it below to finish the login", started.recipient)
+                    }
+                    (Some(started), true) => {
+                        format!("A magic link was sent to {}, open it to finish the login", started.recipient)
+                    }
+                    _ => error_message(&body).unwrap_or_else(|| String::from("The login could not be started")),
+                };
+                self.events.push(TryEvent {
+                    time: now(),
+                    title: String::from(if ok { "Sent" } else { "Start failed" }),
+                    detail,
+                    ok,
+                    request: None,
+                    status: Some(status),
+                    response: Some(body),
+                });
+                if !ok {
+                    self.started = None;
+                }
+                self.loading_start = false;
+                self.fetch_task = None;
+                true
+            }
+            Msg::Verify => {
+                let started = match &self.started {
+                    Some(started) => started.clone(),
+                    None => return false,
+                };
+                if let Err(message) = validate_otp(&self.otp) {
+                    self.error_try = Some(message);
+                    return true;
+                }
+                let data_verify = PasswordlessVerify::new(&started.client_id, &self.props.connection, &started.recipient, &self.otp);
+                let url = format!("{}/oauth/token", API_URL);
+                self.events.push(TryEvent {
+                    time: now(),
+                    title: String::from("Verify"),
+                    detail: format!("Exchanging the code {} for tokens", self.otp),
+                    ok: true,
+                    request: Some(format!("POST {}\n\n{}", url, serde_json::to_string_pretty(&data_verify).unwrap_or_default())),
+                    status: None,
+                    response: None,
+                });
+                let request = Request::post(url)
+                    .header("Content-Type", "application/json")
+                    .body(Json(&data_verify))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(|response: Response<Text>| {
+                    let (meta, body) = response.into_parts();
+                    Msg::GetVerifyResponse(meta.status.as_u16(), body.unwrap_or_default())
+                });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_try = None;
+                self.loading_verify = true;
+                true
+            }
+            Msg::GetVerifyResponse(status, body) => {
+                ConsoleService::info(&format!("passwordless verify = {} {}", status, body));
+                let ok = (200..300).contains(&status);
+                self.events.push(TryEvent {
+                    time: now(),
+                    title: String::from(if ok { "Logged in" } else { "Verify failed" }),
+                    detail: if ok {
+                        String::from("Tokens were issued, the round trip works")
+                    } else {
+                        error_message(&body).unwrap_or_else(|| String::from("The code was refused"))
+                    },
+                    ok,
+                    request: None,
+                    status: Some(status),
+                    response: Some(body),
+                });
+                // A CODE CAN ONLY BE USED ONCE
+                if ok {
+                    self.started = None;
+                }
+                self.otp = String::from("");
+                self.loading_verify = false;
+                self.fetch_task = None;
+                true
+            }
+            Msg::ResponseError(message) => {
+                self.loading_request_applications = false;
+                self.error_request_applications = Some(message);
+                self.fetch_task = None;
+                true
+            }
+            Msg::ClearTimeline => {
+                self.events = vec![];
+                true
+            }
+            Msg::Ignore => false,
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            self.link.send_message(Msg::RequestApplications);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        if self.loading_request_applications {
+            return html! {
+                <div class="d-flex justify-content-center p-5">
+                    <div class="spinner-border text-primary" role="status">
+                        <span class="visually-hidden">{"Loading..."}</span>
+                    </div>
+                </div>
+            };
+        }
+        if self.error_request_applications.is_some() {
+            return html! {
+                <div class="alert alert-warning" role="alert">
+                    <i class="bi bi-exclamation-triangle me-2"></i>
+                    { self.error_request_applications.clone().unwrap() }
+                </div>
+            };
+        }
+
+        let sms = self.props.strategy == PASSWORDLESS_SMS_STRATEGY;
+        let no_application = self.applications.is_empty();
+        let busy = self.loading_start || self.loading_verify;
+        let disabled = no_application || busy;
+        html! {
+            <div>
+                {
+                    if no_application {
+                        html! {
+                            <div class="mt-2 mb-3">
+                                <div class="alert alert-primary d-flex align-items-center" role="alert">
+                                    <i class="bi bi-exclamation-triangle-fill flex-shrink-0 me-2"></i>
+                                    <div>
+                                        {"You need to enable one application at least in order to be able to try this connection."}
+                                    </div>
+                                </div>
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+
+                <div class="mb-3">
+                    <p class="text-muted">
+                        {
+                            if sms {
+                                "Try this connection by specifying an application and a recipient. An SMS will be sent to the specified number."
+                            } else {
+                                "Try this connection by specifying an application and a recipient."
+                            }
+                        }
+                    </p>
+                </div>
+
+                <div class="mb-3">
+                    <label class="form-label text-muted">{"Application"}</label>
+                    <select
+                        class="form-select"
+                        disabled=disabled
+                        onchange=self.link.callback(|data: ChangeData| match data {
+                            ChangeData::Select(select) => Msg::SelectApplication(select.value()),
+                            _ => Msg::Ignore,
+                        })
+                    >
+                        {
+                            for self.applications.iter().map(|application| html! {
+                                <option
+                                    value=application.client_id.clone()
+                                    selected={ application.client_id == self.client_id }
+                                >
+                                    { application.name.clone() }
+                                </option>
+                            })
+                        }
+                    </select>
+                    <div class="pt-1">
+                        <p class="text-muted">{"The application on which you want to try this connection."}</p>
+                    </div>
+                </div>
+
+                <div class="mb-3">
+                    <label class="form-label text-muted">{ if sms { "SMS recipient" } else { "Email recipient" } }</label>
+                    <input
+                        type={ if sms { "tel" } else { "email" } }
+                        class="form-control"
+                        placeholder={ if sms { "+6281234567890" } else { "yourmail@mail.com" } }
+                        value=self.recipient.clone()
+                        disabled=disabled
+                        oninput=self.link.callback(|data: InputData| Msg::InputRecipient(data.value))
+                    />
+                    <div class="pt-1">
+                        <p class="text-muted">
+                            { if sms { "The cellphone number to receive the test sms." } else { "The email address which will receive the test email." } }
+                        </p>
+                    </div>
+                </div>
+
+                {
+                    if sms {
+                        html! {}
+                    } else {
+                        html! {
+                            <div class="mb-3">
+                                <label class="form-label text-muted">{"Mode"}</label>
+                                <select
+                                    class="form-select"
+                                    disabled=disabled
+                                    onchange=self.link.callback(|data: ChangeData| match data {
+                                        ChangeData::Select(select) => Msg::SelectSend(select.value()),
+                                        _ => Msg::Ignore,
+                                    })
+                                >
+                                    {
+                                        for PASSWORDLESS_SEND_MODES.iter().map(|(value, label)| html! {
+                                            <option value=value.to_string() selected={ self.send == *value }>{ *label }</option>
+                                        })
+                                    }
+                                </select>
+                                <div class="pt-1">
+                                    <p class="text-muted">{"Specify whether you want to receive a magic link or a code."}</p>
+                                </div>
+                            </div>
+                        }
+                    }
+                }
+
+                <div class="mb-3">
+                    <button
+                        type="button"
+                        class=format!("btn {} btn-primary position-relative", if self.loading_start {"loading"} else {""} )
+                        onclick=self.link.callback(|_| Msg::Start)
+                        disabled={ disabled || self.recipient.is_empty() }
+                    >
+                        <div class="telkom-label">
+                            {"Try"}
+                        </div>
+                        <div class="telkom-spinner telkom-center">
+                            <div class="spinner-border spinner-border-sm" role="status"/>
+                        </div>
+                    </button>
+                </div>
+
+                { self.view_verify() }
+
+                {
+                    if self.error_try.is_some() {
+                        html! {
+                            <div class="alert alert-warning" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_try.clone().unwrap() }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+
+                { view_timeline(&self.events) }
+                {
+                    if self.events.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <button
+                                type="button"
+                                class="btn btn-sm btn-outline-secondary mb-3"
+                                disabled=busy
+                                onclick=self.link.callback(|_| Msg::ClearTimeline)
+                            >
+                                {"Clear timeline"}
+                            </button>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+}
+
+impl TryFlow {
+    // ONLY ONCE A CODE WAS SENT, MAGIC LINKS ARE OPENED FROM THE INBOX
+    fn view_verify(&self) -> Html {
+        let started = match &self.started {
+            Some(started) if started.send == PASSWORDLESS_SEND_CODE && !self.loading_start => started,
+            _ => return html! {},
+        };
+        html! {
+            <div class="mb-3 border rounded p-3">
+                <label class="form-label text-muted">{ format!("Code sent to {}", started.recipient) }</label>
+                <div class="input-group">
+                    <input
+                        type="text"
+                        inputmode="numeric"
+                        autocomplete="one-time-code"
+                        class="form-control"
+                        placeholder="482913"
+                        value=self.otp.clone()
+                        disabled=self.loading_verify
+                        oninput=self.link.callback(|data: InputData| Msg::InputOtp(data.value))
+                        onkeypress=self.link.batch_callback(|event: KeyboardEvent| {
+                            if event.key() == "Enter" { Some(Msg::Verify) } else { None }
+                        })
+                    />
+                    <button
+                        type="button"
+                        class=format!("btn {} btn-outline-primary position-relative", if self.loading_verify {"loading"} else {""} )
+                        onclick=self.link.callback(|_| Msg::Verify)
+                        disabled={ self.loading_verify || self.otp.is_empty() }
+                    >
+                        <div class="telkom-label">
+                            {"Verify"}
+                        </div>
+                        <div class="telkom-spinner telkom-center">
+                            <div class="spinner-border spinner-border-sm" role="status"/>
+                        </div>
+                    </button>
+                </div>
+            </div>
+        }
+    }
+}
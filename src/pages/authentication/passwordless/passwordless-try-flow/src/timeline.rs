@@ -0,0 +1,91 @@
+use yew::prelude::*;
+use serde_json::Value;
+
+// ONE EXCHANGE WITH THE AUTHENTICATION API, NEWEST FIRST IN THE TIMELINE
+#[derive(Clone, Debug, PartialEq)]
+pub struct TryEvent {
+    // HH:MM:SS, LOCAL TIME
+    pub time: String,
+    pub title: String,
+    pub detail: String,
+    pub ok: bool,
+    pub request: Option<String>,
+    pub status: Option<u16>,
+    pub response: Option<String>,
+}
+
+pub fn now() -> String {
+    let date = js_sys::Date::new_0();
+    format!("{:02}:{:02}:{:02}", date.get_hours(), date.get_minutes(), date.get_seconds())
+}
+
+// JSON BODIES ARE INDENTED, ANYTHING ELSE IS SHOWN AS RECEIVED
+pub fn pretty_body(body: &str) -> String {
+    match serde_json::from_str::<Value>(body) {
+        Ok(value) => serde_json::to_string_pretty(&value).unwrap_or_else(|_| body.to_string()),
+        Err(_) => body.to_string(),
+    }
+}
+
+// error_description OR message OF AN ERROR BODY
+pub fn error_message(body: &str) -> Option<String> {
+    let value = serde_json::from_str::<Value>(body).ok()?;
+    ["error_description", "message", "error"]
+        .iter()
+        .find_map(|key| value.get(*key).and_then(Value::as_str).map(String::from))
+}
+
+pub fn view_timeline(events: &[TryEvent]) -> Html {
+    if events.is_empty() {
+        return html! {};
+    }
+    html! {
+        <div class="mb-3">
+            <p class="fw-bold mb-2">{"Timeline"}</p>
+            <ul class="list-unstyled border-start ps-3 mb-0">
+                { for events.iter().rev().map(view_event) }
+            </ul>
+        </div>
+    }
+}
+
+fn view_event(event: &TryEvent) -> Html {
+    html! {
+        <li class="mb-3">
+            <div class="d-flex align-items-center">
+                <i class=classes!("bi", "me-2", if event.ok { "bi-check-circle text-success" } else { "bi-x-circle text-danger" })></i>
+                <span class="text-muted me-2">{ event.time.clone() }</span>
+                <span class="fw-bold">{ event.title.clone() }</span>
+                {
+                    match event.status {
+                        Some(status) => html! { <span class="badge bg-secondary ms-2">{ status }</span> },
+                        None => html! {},
+                    }
+                }
+            </div>
+            <p class="mb-1">{ event.detail.clone() }</p>
+            {
+                match &event.request {
+                    Some(request) => html! {
+                        <details class="mb-1">
+                            <summary class="text-muted">{"Request"}</summary>
+                            <pre class="border rounded bg-light p-2 mb-0" style="font-size: 12px; white-space: pre-wrap;">{ request.clone() }</pre>
+                        </details>
+                    },
+                    None => html! {},
+                }
+            }
+            {
+                match &event.response {
+                    Some(response) => html! {
+                        <details>
+                            <summary class="text-muted">{"Response"}</summary>
+                            <pre class="border rounded bg-light p-2 mb-0" style="font-size: 12px; white-space: pre-wrap;">{ pretty_body(response) }</pre>
+                        </details>
+                    },
+                    None => html! {},
+                }
+            }
+        </li>
+    }
+}
@@ -0,0 +1,82 @@
+use yew::prelude::*;
+use types::passwordless::{
+    CustomCodeSettings,
+    DEFAULT_CUSTOM_SMS_SCRIPT,
+};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CustomCodeFormProps {
+    pub settings: CustomCodeSettings,
+    pub disabled: bool,
+    pub on_change: Callback<CustomCodeSettings>,
+}
+
+pub struct CustomCodeForm {
+    props: CustomCodeFormProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    InputScript(String),
+    ResetToDefault,
+}
+
+impl Component for CustomCodeForm {
+    type Message = Msg;
+    type Properties = CustomCodeFormProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        CustomCodeForm {
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let script = match msg {
+            Msg::InputScript(value) => value,
+            Msg::ResetToDefault => String::from(DEFAULT_CUSTOM_SMS_SCRIPT),
+        };
+        self.props.on_change.emit(CustomCodeSettings { script });
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div class="mb-3">
+                <label class="form-label">{"Send SMS Script"}</label>
+                <p>
+                    {"Runs for every message with the recipient, the rendered text and a context holding "}
+                    <code>{"code"}</code>{" and "}<code>{"application"}</code>
+                    {". Call "}<code>{"callback(error)"}</code>{" when sending fails."}
+                </p>
+                <textarea
+                    class="form-control text-light font-monospace"
+                    style="background-color:rgb(47,56,61); height:240px; font-size: 12px;"
+                    spellcheck="false"
+                    value=self.props.settings.script.clone()
+                    disabled=self.props.disabled
+                    oninput=self.link.callback(|data: InputData| Msg::InputScript(data.value))
+                />
+                <button
+                    type="button"
+                    class="text-color-primary btn btn-secondary btn-default:hover mt-2"
+                    style="background-color:#96989d !important; font-size:12px;"
+                    disabled={ self.props.disabled || self.props.settings.script == DEFAULT_CUSTOM_SMS_SCRIPT }
+                    onclick=self.link.callback(|_| Msg::ResetToDefault)
+                >
+                    {"Reset to default"}
+                </button>
+            </div>
+        }
+    }
+}
@@ -1,18 +1,230 @@
-use yew::prelude::*;
+use yew::{
+    format::{Json, Nothing, Text},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+        ConsoleService,
+    },
+};
+use configs::server::API_URL;
+use types::{
+    passwordless::{
+        render_sms_template,
+        template_samples,
+        validate_passwordless_sms_options,
+        validate_sms_template,
+        CustomCodeSettings,
+        PasswordlessSmsConnection,
+        PasswordlessSmsConnectionCreate,
+        PasswordlessSmsConnectionUpdate,
+        TwilioSettings,
+        WebhookSettings,
+        OTP_LENGTH_MAX,
+        OTP_LENGTH_MIN,
+        PASSWORDLESS_SMS_STRATEGY,
+        SMS_PASSWORD_MACRO,
+        SMS_PROVIDERS,
+        SMS_PROVIDER_CUSTOM,
+        SMS_PROVIDER_TWILIO,
+        SMS_PROVIDER_WEBHOOK,
+        SMS_SYNTAXES,
+        SMS_SYNTAX_MARKDOWN,
+        SMS_TEMPLATE_VARIABLES,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
 
-pub struct SmsSetting {}
+pub mod custom_code;
+pub mod twilio;
+pub mod webhook;
 
-pub enum Msg {}
+use custom_code::CustomCodeForm;
+use twilio::TwilioForm;
+use webhook::WebhookForm;
+
+pub enum Data {
+    Template,
+    OtpExpiry,
+    OtpLength,
+}
+
+pub struct SmsSetting {
+    access_token: String,
+    link: ComponentLink<Self>,
+    connection: PasswordlessSmsConnection,
+    fetch_task: Option<FetchTask>,
+    loading_request_connection: bool,
+    error_request_connection: Option<String>,
+    loading_update: bool,
+    error_update: Option<String>,
+    message_update: Option<String>,
+}
+
+pub enum Msg {
+    RequestConnection,
+    GetConnection(Vec<PasswordlessSmsConnection>),
+    SelectProvider(String),
+    InputTwilio(TwilioSettings),
+    InputWebhook(WebhookSettings),
+    InputCustom(CustomCodeSettings),
+    SelectSyntax(String),
+    Input(String, Data),
+    ToggleDisableSignup,
+    Update,
+    GetUpdatedConnection(PasswordlessSmsConnection),
+    ResponseError(String),
+}
 
 impl Component for SmsSetting {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
-        SmsSetting {}
+    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        SmsSetting {
+            access_token,
+            link,
+            connection: PasswordlessSmsConnection::new(),
+            fetch_task: None,
+            loading_request_connection: false,
+            error_request_connection: None,
+            loading_update: false,
+            error_update: None,
+            message_update: None,
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestConnection);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let options = &mut self.connection.options;
+        match msg {
+            Msg::RequestConnection => {
+                let request = Request::get(format!("{}/api/v2/connections?strategy={}", API_URL, PASSWORDLESS_SMS_STRATEGY))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<PasswordlessSmsConnection>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetConnection(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_connection = None;
+                self.loading_request_connection = true;
+                return true;
+            }
+            Msg::GetConnection(data) => {
+                // NO SMS CONNECTION YET, THE DEFAULTS ARE CREATED ON SAVE
+                self.connection = data.into_iter().next().unwrap_or_else(PasswordlessSmsConnection::new);
+                self.loading_request_connection = false;
+                self.fetch_task = None;
+                return true;
+            }
+            Msg::SelectProvider(provider) => options.provider = provider,
+            Msg::InputTwilio(settings) => options.twilio = settings,
+            Msg::InputWebhook(settings) => options.webhook = settings,
+            Msg::InputCustom(settings) => options.custom = settings,
+            Msg::SelectSyntax(syntax) => options.syntax = syntax,
+            Msg::Input(value, data) => {
+                match data {
+                    Data::Template => options.template = value,
+                    Data::OtpExpiry => options.totp.time_step = value.trim().parse::<u32>().unwrap_or(0),
+                    Data::OtpLength => options.totp.length = value.trim().parse::<u32>().unwrap_or(0),
+                }
+            }
+            Msg::ToggleDisableSignup => options.disable_signup = !options.disable_signup,
+            Msg::Update => {
+                // VALIDATION
+                if let Err(message) = validate_passwordless_sms_options(&self.connection.options) {
+                    self.error_update = Some(message);
+                    return true;
+                }
+
+                // THE FIRST SAVE CREATES THE CONNECTION
+                let (request, body): (_, Text) = if self.connection.id.is_empty() {
+                    (
+                        Request::post(format!("{}/api/v2/connections", API_URL)),
+                        Json(&PasswordlessSmsConnectionCreate::from(&self.connection)).into(),
+                    )
+                } else {
+                    (
+                        Request::patch(format!("{}/api/v2/connections/{}", API_URL, self.connection.id.clone())),
+                        Json(&PasswordlessSmsConnectionUpdate::from(&self.connection)).into(),
+                    )
+                };
+                let request = request
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(body)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<PasswordlessSmsConnection, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetUpdatedConnection(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_update = None;
+                self.message_update = None;
+                self.loading_update = true;
+                return true;
+            }
+            Msg::GetUpdatedConnection(data) => {
+                ConsoleService::info(&format!("connection = {:?}", data));
+                self.connection = data;
+                self.loading_update = false;
+                self.message_update = Some(String::from("SMS settings have been saved"));
+                self.fetch_task = None;
+                return true;
+            }
+            Msg::ResponseError(message) => {
+                if self.loading_update {
+                    self.loading_update = false;
+                    self.error_update = Some(message);
+                } else {
+                    self.loading_request_connection = false;
+                    self.error_request_connection = Some(message);
+                }
+                self.fetch_task = None;
+                return true;
+            }
+        }
+        // EVERY INPUT LANDS HERE
+        self.message_update = None;
         true
     }
 
@@ -21,63 +233,123 @@ impl Component for SmsSetting {
     }
 
     fn view(&self) -> Html {
+        if self.loading_request_connection {
+            return html! {
+                <div class="d-flex justify-content-center p-5">
+                    <div class="spinner-border text-primary" role="status">
+                        <span class="visually-hidden">{"Loading..."}</span>
+                    </div>
+                </div>
+            };
+        }
+        if self.error_request_connection.is_some() {
+            return html! {
+                <div class="alert alert-warning" role="alert">
+                    <i class="bi bi-exclamation-triangle me-2"></i>
+                    { self.error_request_connection.clone().unwrap() }
+                </div>
+            };
+        }
+
+        let options = self.connection.options.clone();
+        let disabled = self.loading_update;
         html! {
             <div class="p-2" style="font-size: 14px;">
-                <p>{ "Twilio SMS connection is a service that allows your users to use one-time password authentication via text messages. Learn more about integrating " }<a href="">{"SMS connections."}</a></p>
+                <p>{ "The SMS connection lets your users log in with a one-time password sent by text message, through the provider of your choice. Learn more about integrating " }<a href="">{"SMS connections."}</a></p>
                 <div class="mt-2 mb-3">
                     <label class="form-label">{"Connections"}</label>
-                    <input class="form-control" type="text" value="sms" aria-label="Disabled input example" disabled=true readonly=true/>
+                    <input class="form-control" type="text" value=self.connection.name.clone() aria-label="Disabled input example" disabled=true readonly=true/>
                     <p>{"If you are triggering a login manually, this is the identifier you would use on the connection parameter"}</p>
                 </div>
+
                 <div class="mb-3">
-                    <label class="form-label">{"Twilio SID"}</label>
-                    <input class="form-control" type="text" placeholder="YOUR_TWILIO_SID" aria-label="twilio input example"/>
-                    <p>{"Learn more: "}<a href="">{"How to create an Application SID."}</a></p>
-                </div>
-                <div class="mb-3">
-                    <label class="form-label d-block">{"SMS Source"}</label>
-                    <div class="form-check form-check-inline">
-                        <input class="form-check-input" type="radio" name="inlineRadioOptions" id="smsSourceUms" value="Use Messaging Service"/>
-                        <label class="form-check-label" for="smsSourceUms">{"Use Messaging Service"}</label>
-                    </div>
-                    <div class="form-check form-check-inline">
-                        <input class="form-check-input" type="radio" name="inlineRadioOptions" id="smsSourceUseFrom" value="Use From"/>
-                        <label class="form-check-label" for="smsSourceUseFrom">{"Use From"}</label>
-                    </div>
-                </div>
-                <div class="mb-3">
-                    <label class="form-label">{"Messaging Service SID"}</label>
-                    <input class="form-control" type="text" placeholder="YOUR_TWILIO_MESSAGING_SERVICE_SID" aria-label="Messaging service SID example" disabled=true/>
-                    <p>{"Learn more: "}<a href="">{"Sending Messages with the Messaging Service."}</a></p>
-                </div>
-                <div class="mb-3">
-                    <label class="form-label">{"From"}</label>
-                    <input class="form-control" type="text" placeholder="+15555555" aria-label="default input example"/>
+                    <label class="form-label d-block">{"SMS Provider"}</label>
+                    {
+                        for SMS_PROVIDERS.iter().map(|(value, label, description)| {
+                            let provider = value.to_string();
+                            html! {
+                                <div class="form-check mb-2">
+                                    <input
+                                        class="form-check-input"
+                                        type="radio"
+                                        name="smsProvider"
+                                        checked={ options.provider == *value }
+                                        disabled=disabled
+                                        onclick=self.link.callback(move |_| Msg::SelectProvider(provider.clone()))
+                                    />
+                                    <label class="form-check-label">
+                                        { *label }
+                                        <p class="text-muted mb-0">{ *description }</p>
+                                    </label>
+                                </div>
+                            }
+                        })
+                    }
                 </div>
+
+                {
+                    match options.provider.as_str() {
+                        SMS_PROVIDER_TWILIO => html! {
+                            <TwilioForm
+                                settings=options.twilio.clone()
+                                disabled=disabled
+                                on_change=self.link.callback(Msg::InputTwilio)
+                            />
+                        },
+                        SMS_PROVIDER_WEBHOOK => html! {
+                            <WebhookForm
+                                settings=options.webhook.clone()
+                                disabled=disabled
+                                on_change=self.link.callback(Msg::InputWebhook)
+                            />
+                        },
+                        SMS_PROVIDER_CUSTOM => html! {
+                            <CustomCodeForm
+                                settings=options.custom.clone()
+                                disabled=disabled
+                                on_change=self.link.callback(Msg::InputCustom)
+                            />
+                        },
+                        _ => html! {},
+                    }
+                }
+
                 <div class="mb-3">
                     <label class="form-label d-block">{"SMS Syntax"}</label>
-                    <div class="form-check form-check-inline">
-                        <input class="form-check-input" type="radio" name="inlineRadioOptions" id="smsSyntaxLiquid" value="Liquid"/>
-                        <label class="form-check-label" for="smsSyntaxLiquid">{"Use Messaging Service"}</label>
-                    </div>
-                    <div class="form-check form-check-inline">
-                        <input class="form-check-input" type="radio" name="inlineRadioOptions" id="smsSyntaxMarkdown" value="Markdown"/>
-                        <label class="form-check-label" for="smsSyntaxMarkdown">{"Use From"}</label>
-                    </div>
+                    {
+                        for SMS_SYNTAXES.iter().map(|(value, label)| {
+                            let syntax = value.to_string();
+                            html! {
+                                <div class="form-check form-check-inline">
+                                    <input
+                                        class="form-check-input"
+                                        type="radio"
+                                        name="smsSyntax"
+                                        checked={ options.syntax == *value }
+                                        disabled=disabled
+                                        onclick=self.link.callback(move |_| Msg::SelectSyntax(syntax.clone()))
+                                    />
+                                    <label class="form-check-label">{ *label }</label>
+                                </div>
+                            }
+                        })
+                    }
                 </div>
 
-                <div class="mb-3">
-                    <label class="form-label d-block">{"Message"}</label>
-                    <div class="form-floating">
-                        <textarea class="form-control text-light pt-1" placeholder="Your verification code is: @@password@@" style="background-color:rgb(47,56,61); height:100px;" id="floatingTextarea2"></textarea>
-                    </div>
-                    <p>{"You can use @@password@@ as a placeholder of where the password value should be placed."}</p>
-                </div>
+                { self.view_message() }
 
                 <div class="mb-3">
                     <label class="form-label">{"OTP Expiry"}</label>
                     <div class="input-group mb-3">
-                        <input type="number" class="form-control" min="1" aria-label="Recipient's username" aria-describedby="otpExpiry"/>
+                        <input
+                            type="number"
+                            class="form-control"
+                            min="1"
+                            aria-describedby="otpExpiry"
+                            value=options.totp.time_step.to_string()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::OtpExpiry))
+                        />
                         <span class="input-group-text" id="otpExpiry">{"seconds"}</span>
                     </div>
                     <p>{"The time step, in seconds, between new passwords."}</p>
@@ -85,22 +357,108 @@ impl Component for SmsSetting {
                 <div class="mb-3">
                     <label class="form-label">{"OTP Length"}</label>
                     <div class="input-group mb-3">
-                        <input type="number" class="form-control" min="1" aria-label="Recipient's username" aria-describedby="otpLength"/>
-                        <span class="input-group-text" id="otpLength">{"seconds"}</span>
+                        <input
+                            type="number"
+                            class="form-control"
+                            min=OTP_LENGTH_MIN.to_string()
+                            max=OTP_LENGTH_MAX.to_string()
+                            aria-describedby="otpLength"
+                            value=options.totp.length.to_string()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::OtpLength))
+                        />
+                        <span class="input-group-text" id="otpLength">{"digits"}</span>
                     </div>
                     <div class="pt-1">{"The length of the resulting one-time password."}</div>
                 </div>
                 <div class="mb-3">
                     <label class="form-label">{"Disable Sign Ups"}</label>
                     <div class="form-check form-switch">
-                        <input class="form-check-input" type="checkbox" id="flexSwitchCheckDefault"/>
+                        <input
+                            class="form-check-input"
+                            type="checkbox"
+                            checked=options.disable_signup
+                            disabled=disabled
+                            onclick=self.link.callback(|_| Msg::ToggleDisableSignup)
+                        />
                     </div>
                     <p class="text-muted">{"Check this if you want to prevent sign ups to your application. You will still be able to create users with your API credentials."}</p>
                 </div>
 
+                {
+                    if self.error_update.is_some() {
+                        html! {
+                            <div class="alert alert-warning" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_update.clone().unwrap() }
+                            </div>
+                        }
+                    } else if self.message_update.is_some() {
+                        html! {
+                            <div class="alert alert-success" role="alert">
+                                <i class="bi bi-check-circle me-2"></i>
+                                { self.message_update.clone().unwrap() }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+
                 <div class="modal-footer">
-                    <button type="button" class="btn btn-primary" >{"Save"}</button>
+                    <button
+                        type="button"
+                        class=format!("btn {} btn-primary position-relative", if self.loading_update {"loading"} else {""} )
+                        onclick=self.link.callback(|_| Msg::Update)
+                        disabled=disabled
+                    >
+                        <div class="telkom-label">
+                            {"Save"}
+                        </div>
+                        <div class="telkom-spinner telkom-center">
+                            <div class="spinner-border spinner-border-sm" role="status"/>
+                        </div>
+                    </button>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl SmsSetting {
+    fn view_message(&self) -> Html {
+        let options = &self.connection.options;
+        let problem = validate_sms_template(&options.syntax, &options.template).err();
+        let help = if options.syntax == SMS_SYNTAX_MARKDOWN {
+            format!("You can use {} as a placeholder of where the password value should be placed.", SMS_PASSWORD_MACRO)
+        } else {
+            let variables: Vec<String> = SMS_TEMPLATE_VARIABLES.iter().map(|(name, _, _)| format!("{{{{ {} }}}}", name)).collect();
+            format!("Variables: {}", variables.join(", "))
+        };
+        html! {
+            <div class="mb-3">
+                <label class="form-label d-block">{"Message"}</label>
+                <div class="form-floating">
+                    <textarea
+                        class=classes!("form-control", "text-light", "pt-1", if problem.is_some() { "is-invalid" } else { "" })
+                        placeholder="Your verification code is: {{ code }}"
+                        style="background-color:rgb(47,56,61); height:100px;"
+                        value=options.template.clone()
+                        disabled=self.loading_update
+                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::Template))
+                    />
+                    {
+                        match problem {
+                            Some(problem) => html! { <div class="invalid-feedback">{ problem }</div> },
+                            None => html! {},
+                        }
+                    }
                 </div>
+                <p class="mb-1">{ help }</p>
+                <p class="text-muted">
+                    <i class="bi bi-phone me-1"></i>
+                    { render_sms_template(&options.syntax, &options.template, &template_samples(&SMS_TEMPLATE_VARIABLES)) }
+                </p>
             </div>
         }
     }
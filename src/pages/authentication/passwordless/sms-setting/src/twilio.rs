@@ -0,0 +1,149 @@
+use yew::prelude::*;
+use types::passwordless::{
+    TwilioSettings,
+    TWILIO_SOURCES,
+    TWILIO_SOURCE_FROM,
+    TWILIO_SOURCE_MESSAGING_SERVICE,
+};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TwilioFormProps {
+    pub settings: TwilioSettings,
+    pub disabled: bool,
+    pub on_change: Callback<TwilioSettings>,
+}
+
+#[derive(Clone, Copy)]
+pub enum Data {
+    Sid,
+    Token,
+    MessagingServiceSid,
+    From,
+}
+
+pub struct TwilioForm {
+    props: TwilioFormProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    Input(String, Data),
+    SelectSource(String),
+}
+
+impl Component for TwilioForm {
+    type Message = Msg;
+    type Properties = TwilioFormProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        TwilioForm {
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut settings = self.props.settings.clone();
+        match msg {
+            Msg::Input(value, data) => {
+                match data {
+                    Data::Sid => settings.twilio_sid = value.trim().to_string(),
+                    Data::Token => settings.twilio_token = value,
+                    Data::MessagingServiceSid => settings.messaging_service_sid = value.trim().to_string(),
+                    Data::From => settings.from = value.trim().to_string(),
+                }
+            }
+            Msg::SelectSource(source) => {
+                settings.source = source;
+            }
+        }
+        self.props.on_change.emit(settings);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let settings = self.props.settings.clone();
+        let disabled = self.props.disabled;
+        html! {
+            <>
+                <div class="mb-3">
+                    <label class="form-label">{"Twilio SID"}</label>
+                    <input
+                        class="form-control"
+                        type="text"
+                        placeholder="YOUR_TWILIO_SID"
+                        value=settings.twilio_sid.clone()
+                        disabled=disabled
+                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::Sid))
+                    />
+                    <p>{"Learn more: "}<a href="https://www.twilio.com/docs/iam/api/account" target="_blank">{"How to find your Account SID."}</a></p>
+                </div>
+                <div class="mb-3">
+                    <label class="form-label">{"Twilio Auth Token"}</label>
+                    <input
+                        class="form-control"
+                        type="password"
+                        autocomplete="new-password"
+                        placeholder="YOUR_TWILIO_AUTH_TOKEN"
+                        value=settings.twilio_token.clone()
+                        disabled=disabled
+                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::Token))
+                    />
+                </div>
+                <div class="mb-3">
+                    <label class="form-label d-block">{"SMS Source"}</label>
+                    {
+                        for TWILIO_SOURCES.iter().map(|(value, label)| {
+                            let source = value.to_string();
+                            html! {
+                                <div class="form-check form-check-inline">
+                                    <input
+                                        class="form-check-input"
+                                        type="radio"
+                                        name="twilioSource"
+                                        checked={ settings.source == *value }
+                                        disabled=disabled
+                                        onclick=self.link.callback(move |_| Msg::SelectSource(source.clone()))
+                                    />
+                                    <label class="form-check-label">{ *label }</label>
+                                </div>
+                            }
+                        })
+                    }
+                </div>
+                <div class="mb-3">
+                    <label class="form-label">{"Messaging Service SID"}</label>
+                    <input
+                        class="form-control"
+                        type="text"
+                        placeholder="YOUR_TWILIO_MESSAGING_SERVICE_SID"
+                        value=settings.messaging_service_sid.clone()
+                        disabled={ disabled || settings.source != TWILIO_SOURCE_MESSAGING_SERVICE }
+                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::MessagingServiceSid))
+                    />
+                    <p>{"Learn more: "}<a href="https://www.twilio.com/docs/messaging/services" target="_blank">{"Sending Messages with the Messaging Service."}</a></p>
+                </div>
+                <div class="mb-3">
+                    <label class="form-label">{"From"}</label>
+                    <input
+                        class="form-control"
+                        type="text"
+                        placeholder="+15555555"
+                        value=settings.from.clone()
+                        disabled={ disabled || settings.source != TWILIO_SOURCE_FROM }
+                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::From))
+                    />
+                </div>
+            </>
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use yew::prelude::*;
+use types::passwordless::{
+    render_template,
+    template_samples,
+    unknown_template_variables,
+    webhook_escape,
+    WebhookHeader,
+    WebhookSettings,
+    WEBHOOK_CONTENT_TYPES,
+    WEBHOOK_METHODS,
+    WEBHOOK_PAYLOAD_VARIABLES,
+};
+
+// A GATEWAY THAT RECEIVES EVERY MESSAGE AS AN HTTP REQUEST
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct WebhookFormProps {
+    pub settings: WebhookSettings,
+    pub disabled: bool,
+    pub on_change: Callback<WebhookSettings>,
+}
+
+pub struct WebhookForm {
+    props: WebhookFormProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    InputUrl(String),
+    SelectMethod(String),
+    SelectContentType(String),
+    AddHeader,
+    InputHeaderName(usize, String),
+    InputHeaderValue(usize, String),
+    RemoveHeader(usize),
+    InputPayload(String),
+    Ignore,
+}
+
+impl Component for WebhookForm {
+    type Message = Msg;
+    type Properties = WebhookFormProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        WebhookForm {
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut settings = self.props.settings.clone();
+        match msg {
+            Msg::InputUrl(value) => settings.url = value.trim().to_string(),
+            Msg::SelectMethod(method) => settings.method = method,
+            Msg::SelectContentType(content_type) => settings.content_type = content_type,
+            Msg::AddHeader => {
+                settings.headers.push(WebhookHeader {
+                    name: String::from(""),
+                    value: String::from(""),
+                });
+            }
+            Msg::InputHeaderName(index, value) => settings.headers[index].name = value,
+            Msg::InputHeaderValue(index, value) => settings.headers[index].value = value,
+            Msg::RemoveHeader(index) => {
+                settings.headers.remove(index);
+            }
+            Msg::InputPayload(value) => settings.payload = value,
+            Msg::Ignore => return false,
+        }
+        self.props.on_change.emit(settings);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let settings = self.props.settings.clone();
+        let disabled = self.props.disabled;
+        let unknown = unknown_template_variables(&settings.payload, &WEBHOOK_PAYLOAD_VARIABLES);
+        html! {
+            <>
+                <div class="mb-3">
+                    <label class="form-label">{"Webhook URL"}</label>
+                    <div class="input-group">
+                        <select
+                            class="form-select flex-grow-0 w-auto"
+                            disabled=disabled
+                            onchange=self.link.callback(|data: ChangeData| match data {
+                                ChangeData::Select(select) => Msg::SelectMethod(select.value()),
+                                _ => Msg::Ignore,
+                            })
+                        >
+                            {
+                                for WEBHOOK_METHODS.iter().map(|method| html! {
+                                    <option value=method.to_string() selected={ settings.method == *method }>{ *method }</option>
+                                })
+                            }
+                        </select>
+                        <input
+                            class="form-control"
+                            type="url"
+                            placeholder="https://sms-gateway.example.id/send"
+                            value=settings.url.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::InputUrl(data.value))
+                        />
+                    </div>
+                    <p>{"Plain http:// is only accepted for a gateway on localhost, eg. http://127.0.0.1:8080/sms-gateway for local testing."}</p>
+                </div>
+
+                <div class="mb-3">
+                    <label class="form-label">{"Headers"}</label>
+                    {
+                        for settings.headers.iter().enumerate().map(|(index, header)| html! {
+                            <div class="input-group mb-2">
+                                <input
+                                    class="form-control"
+                                    type="text"
+                                    placeholder="Authorization"
+                                    value=header.name.clone()
+                                    disabled=disabled
+                                    oninput=self.link.callback(move |data: InputData| Msg::InputHeaderName(index, data.value))
+                                />
+                                <input
+                                    class="form-control"
+                                    type="text"
+                                    placeholder="Bearer YOUR_GATEWAY_KEY"
+                                    value=header.value.clone()
+                                    disabled=disabled
+                                    oninput=self.link.callback(move |data: InputData| Msg::InputHeaderValue(index, data.value))
+                                />
+                                <button
+                                    type="button"
+                                    class="btn btn-outline-secondary"
+                                    disabled=disabled
+                                    onclick=self.link.callback(move |_| Msg::RemoveHeader(index))
+                                >
+                                    <i class="bi bi-trash"></i>
+                                </button>
+                            </div>
+                        })
+                    }
+                    <button
+                        type="button"
+                        class="btn btn-sm btn-outline-primary"
+                        disabled=disabled
+                        onclick=self.link.callback(|_| Msg::AddHeader)
+                    >
+                        <i class="bi bi-plus me-1"></i>
+                        {"Add Header"}
+                    </button>
+                </div>
+
+                <div class="mb-3">
+                    <label class="form-label d-block">{"Content Type"}</label>
+                    {
+                        for WEBHOOK_CONTENT_TYPES.iter().map(|content_type| {
+                            let selected = content_type.to_string();
+                            html! {
+                                <div class="form-check form-check-inline">
+                                    <input
+                                        class="form-check-input"
+                                        type="radio"
+                                        name="webhookContentType"
+                                        checked={ settings.content_type == *content_type }
+                                        disabled=disabled
+                                        onclick=self.link.callback(move |_| Msg::SelectContentType(selected.clone()))
+                                    />
+                                    <label class="form-check-label">{ *content_type }</label>
+                                </div>
+                            }
+                        })
+                    }
+                </div>
+
+                <div class="mb-3">
+                    <label class="form-label">{"Payload"}</label>
+                    <textarea
+                        class=classes!("form-control", "text-light", "font-monospace", if unknown.is_empty() { "" } else { "is-invalid" })
+                        style="background-color:rgb(47,56,61); height:120px; font-size: 12px;"
+                        value=settings.payload.clone()
+                        disabled=disabled
+                        oninput=self.link.callback(|data: InputData| Msg::InputPayload(data.value))
+                    />
+                    {
+                        if unknown.is_empty() {
+                            html! {}
+                        } else {
+                            html! { <div class="invalid-feedback">{ format!("Unknown variables: {}", unknown.join(", ")) }</div> }
+                        }
+                    }
+                    <p class="mb-1">
+                        {"Variables: "}
+                        {
+                            for WEBHOOK_PAYLOAD_VARIABLES.iter().map(|(name, description, _)| html! {
+                                <code class="me-2" title=description.to_string()>{ format!("{{{{ {} }}}}", name) }</code>
+                            })
+                        }
+                    </p>
+                    <pre class="border rounded bg-light p-2 mb-0" style="font-size: 12px; white-space: pre-wrap;">
+                        { render_template(&settings.payload, &template_samples(&WEBHOOK_PAYLOAD_VARIABLES), webhook_escape(&settings.content_type)) }
+                    </pre>
+                </div>
+            </>
+        }
+    }
+}
@@ -1,19 +1,181 @@
-use yew::prelude::*;
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+        ConsoleService,
+    },
+};
+use configs::server::API_URL;
+use passwordless_try_flow::TryFlow;
+use types::{
+    passwordless::{
+        sms_provider_label,
+        validate_phone_number,
+        PasswordlessSmsConnection,
+        SmsTry as SmsTryData,
+        SmsTryResult,
+        PASSWORDLESS_SMS_STRATEGY,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
 
-pub struct SmsTry {}
+pub struct SmsTry {
+    access_token: String,
+    link: ComponentLink<Self>,
+    connection: Option<PasswordlessSmsConnection>,
+    recipient: String,
+    fetch_task: Option<FetchTask>,
+    loading_request_connection: bool,
+    error_request_connection: Option<String>,
+    try_result: Option<SmsTryResult>,
+    loading_try: bool,
+    error_try: Option<String>,
+}
 
-pub enum Msg {}
+pub enum Msg {
+    RequestConnection,
+    GetConnection(Vec<PasswordlessSmsConnection>),
+    InputRecipient(String),
+    Try,
+    GetTryResult(SmsTryResult),
+    ResponseError(String),
+}
 
 impl Component for SmsTry {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
-        SmsTry {}
+    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        SmsTry {
+            access_token,
+            link,
+            connection: None,
+            recipient: String::from(""),
+            fetch_task: None,
+            loading_request_connection: false,
+            error_request_connection: None,
+            try_result: None,
+            loading_try: false,
+            error_try: None,
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        true
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestConnection);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestConnection => {
+                let request = Request::get(format!("{}/api/v2/connections?strategy={}", API_URL, PASSWORDLESS_SMS_STRATEGY))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<PasswordlessSmsConnection>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetConnection(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_connection = None;
+                self.loading_request_connection = true;
+                true
+            }
+            Msg::GetConnection(data) => {
+                self.connection = data.into_iter().next();
+                self.loading_request_connection = false;
+                self.fetch_task = None;
+                true
+            }
+            Msg::InputRecipient(value) => {
+                self.recipient = value.trim().to_string();
+                self.error_try = None;
+                true
+            }
+            Msg::Try => {
+                let connection = match &self.connection {
+                    Some(connection) => connection,
+                    None => return false,
+                };
+                if let Err(message) = validate_phone_number(&self.recipient) {
+                    self.error_try = Some(message);
+                    return true;
+                }
+
+                // THE SAVED PROVIDER SETTINGS, UNSAVED CHANGES ARE NOT TRIED
+                let data_try = SmsTryData {
+                    recipient: self.recipient.clone(),
+                    options: connection.options.clone(),
+                };
+                let request = Request::post(format!("{}/api/v2/connections/{}/sms/try", API_URL, connection.id.clone()))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_try))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<SmsTryResult, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetTryResult(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.try_result = None;
+                self.error_try = None;
+                self.loading_try = true;
+                true
+            }
+            Msg::GetTryResult(data) => {
+                ConsoleService::info(&format!("sms try result = {:?}", data));
+                self.try_result = Some(data);
+                self.loading_try = false;
+                self.fetch_task = None;
+                true
+            }
+            Msg::ResponseError(message) => {
+                if self.loading_try {
+                    self.loading_try = false;
+                    self.error_try = Some(message);
+                } else {
+                    self.loading_request_connection = false;
+                    self.error_request_connection = Some(message);
+                }
+                self.fetch_task = None;
+                true
+            }
+        }
     }
 
     fn change(&mut self, _: Self::Properties) -> ShouldRender {
@@ -21,45 +183,167 @@ impl Component for SmsTry {
     }
 
     fn view(&self) -> Html {
-        html! {
-            <div class="p-2" style="font-size: 14px;">
-                <div class="mt-2 mb-3">
-                    <div class="alert alert-primary d-flex align-items-center" role="alert">
-                        <svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" fill="currentColor" class="bi bi-exclamation-triangle-fill flex-shrink-0 me-2" viewBox="0 0 16 16" role="img" aria-label="Warning:">
-                            <path d="M8.982 1.566a1.13 1.13 0 0 0-1.96 0L.165 13.233c-.457.778.091 1.767.98 1.767h13.713c.889 0 1.438-.99.98-1.767L8.982 1.566zM8 5c.535 0 .954.462.9.995l-.35 3.507a.552.552 0 0 1-1.1 0L7.1 5.995A.905.905 0 0 1 8 5zm.002 6a1 1 0 1 1 0 2 1 1 0 0 1 0-2z"/>
-                        </svg>
-                        <div>
-                            {"You need to enable one application at least in order to be able to try this connection."}
-                        </div>
+        if self.loading_request_connection {
+            return html! {
+                <div class="d-flex justify-content-center p-5">
+                    <div class="spinner-border text-primary" role="status">
+                        <span class="visually-hidden">{"Loading..."}</span>
                     </div>
                 </div>
-                <div class="mb-3">
-                    <p class="text-muted">{"Try this connection by specifying an application and a recipient. An SMS will be sent to the specified number."}</p>
+            };
+        }
+        if self.error_request_connection.is_some() {
+            return html! {
+                <div class="alert alert-warning" role="alert">
+                    <i class="bi bi-exclamation-triangle me-2"></i>
+                    { self.error_request_connection.clone().unwrap() }
+                </div>
+            };
+        }
+        let connection = match &self.connection {
+            Some(connection) => connection,
+            None => return html! {
+                <div class="alert alert-primary" role="alert">
+                    <i class="bi bi-info-circle me-2"></i>
+                    {"Save the SMS settings first, then come back to try them."}
                 </div>
+            },
+        };
+
+        html! {
+            <div class="p-2" style="font-size: 14px;">
                 <div class="mb-3">
-                    <label class="form-label text-muted">{"Application"}</label>
-                    <select class="form-select" aria-label="Disabled select example" disabled=true>
-                        <option selected=true>{""}</option>
-                        <option value="1">{""}</option>
-                        <option value="2">{""}</option>
-                        <option value="3">{""}</option>
-                    </select>
-                    <div class="pt-1">
-                        <p class="text-muted">{"The application on which you want to try this connection."}</p>
-                    </div>
+                    <p class="text-muted">
+                        { format!("Send a test message through {} with the saved settings. No login is started.", sms_provider_label(&connection.options.provider)) }
+                    </p>
                 </div>
                 <div class="mb-3">
                     <label class="form-label text-muted">{"SMS recipient"}</label>
-                    <input type="text" id="disabledTextInput" class="form-control" placeholder="+15555555" disabled=true />
+                    <input
+                        type="tel"
+                        class="form-control"
+                        placeholder="+6281234567890"
+                        value=self.recipient.clone()
+                        disabled=self.loading_try
+                        oninput=self.link.callback(|data: InputData| Msg::InputRecipient(data.value))
+                    />
                     <div class="pt-1">
                         <p class="text-muted">{"The cellphone number to receive the test sms."}</p>
                     </div>
                 </div>
-                
+
+                {
+                    if self.error_try.is_some() {
+                        html! {
+                            <div class="alert alert-warning" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_try.clone().unwrap() }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+
+                { self.view_try_result() }
+
                 <div class="modal-footer">
-                    <button type="button" class="btn btn-primary">{"Try"}</button>
+                    <button
+                        type="button"
+                        class=format!("btn {} btn-primary position-relative", if self.loading_try {"loading"} else {""} )
+                        onclick=self.link.callback(|_| Msg::Try)
+                        disabled={ self.loading_try || self.recipient.is_empty() }
+                    >
+                        <div class="telkom-label">
+                            {"Try"}
+                        </div>
+                        <div class="telkom-spinner telkom-center">
+                            <div class="spinner-border spinner-border-sm" role="status"/>
+                        </div>
+                    </button>
                 </div>
 
+                <hr/>
+                <p class="fw-bold mb-1">{"Try a login"}</p>
+                <TryFlow
+                    strategy=PASSWORDLESS_SMS_STRATEGY.to_string()
+                    connection=connection.name.clone()
+                    enabled_clients=connection.enabled_clients.clone()
+                />
+            </div>
+        }
+    }
+}
+
+impl SmsTry {
+    fn view_try_result(&self) -> Html {
+        let result = match &self.try_result {
+            Some(result) => result,
+            None => return html! {},
+        };
+        html! {
+            <div class="mb-3">
+                {
+                    match &result.error {
+                        Some(error) => html! {
+                            <div class="alert alert-danger" role="alert">
+                                <i class="bi bi-x-circle me-2"></i>
+                                { error.clone() }
+                            </div>
+                        },
+                        None => html! {
+                            <div class="alert alert-success" role="alert">
+                                <i class="bi bi-check-circle me-2"></i>
+                                { format!("Sent through {}", sms_provider_label(&result.provider)) }
+                            </div>
+                        },
+                    }
+                }
+                <p class="fw-bold mb-1">{"Message"}</p>
+                <p class="border rounded p-2">{ result.message.clone() }</p>
+                {
+                    match &result.request {
+                        Some(request) => html! {
+                            <>
+                                <p class="fw-bold mb-1">{"Request"}</p>
+                                <pre class="border rounded bg-light p-2" style="font-size: 12px; white-space: pre-wrap;">
+                                    { format!("{} {}\n", request.method, request.url) }
+                                    {
+                                        for request.headers.iter().map(|(name, value)| format!("{}: {}\n", name, value))
+                                    }
+                                    { format!("\n{}", request.body) }
+                                </pre>
+                            </>
+                        },
+                        None => html! {},
+                    }
+                }
+                <p class="fw-bold mb-1">
+                    {"Response"}
+                    {
+                        match result.status {
+                            Some(status) => html! { <span class="badge bg-secondary ms-2">{ status }</span> },
+                            None => html! {},
+                        }
+                    }
+                </p>
+                <pre class="border rounded bg-light p-2" style="font-size: 12px; white-space: pre-wrap;">
+                    { serde_json::to_string_pretty(&result.response).unwrap_or_default() }
+                </pre>
+                {
+                    if result.logs.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <>
+                                <p class="fw-bold mb-1">{"Console"}</p>
+                                <pre class="border rounded text-light p-2" style="background-color:rgb(47,56,61); font-size: 12px; white-space: pre-wrap;">
+                                    { result.logs.join("\n") }
+                                </pre>
+                            </>
+                        }
+                    }
+                }
             </div>
         }
     }
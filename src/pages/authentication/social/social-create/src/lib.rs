@@ -1,27 +1,181 @@
-use yew::prelude::*;
-use yew_router::components::RouterAnchor;
+use yew::{
+    prelude::*,
+    format::Json,
+    services::{
+        ConsoleService,
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{ Area, StorageService },
+    },
+    agent::Bridged,
+    Bridge,
+};
+use yew_router::{
+    agent::RouteRequest::ChangeRoute,
+    components::RouterAnchor,
+    prelude::*,
+};
 // use crate::app::AppRoute;
 use router::AppRoute;
+use configs::server::API_URL;
+use types::{
+    database::validate_connection_name,
+    social::{
+        SocialCatalog,
+        SocialConnection,
+        SocialConnectionCreate,
+        SocialProvider,
+        CUSTOM_STRATEGY,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
 
 
 pub struct SocialCreate {
-    // link: ComponentLink<Self>
+    access_token: String,
+    link: ComponentLink<Self>,
+    catalog: SocialCatalog,
+    search: String,
+    // CUSTOM CONNECTIONS AREN'T NAMED AFTER THEIR STRATEGY, SEVERAL CAN EXIST
+    custom_name: Option<String>,
+    fetch_task: Option<FetchTask>,
+    // STRATEGY OF THE CONNECTION BEING CREATED
+    loading_create_connection: Option<String>,
+    error_create_connection: Option<String>,
+    route_agent: Box<dyn Bridge<RouteAgent>>,
 }
 
-pub enum Msg {}
+pub enum Msg {
+    InputSearch(String),
+    SelectProvider(String),
+    InputCustomName(String),
+    CancelCustom,
+    CreateConnection(String),
+    RedirectToSettings(SocialConnection),
+    ResponseError(String),
+    Ignore,
+}
 
 impl Component for SocialCreate {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_: Self::Properties, _link: ComponentLink<Self>) -> Self {
+    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
         SocialCreate {
-            // link
+            access_token,
+            catalog: SocialCatalog::load(),
+            search: String::from(""),
+            custom_name: None,
+            fetch_task: None,
+            loading_create_connection: None,
+            error_create_connection: None,
+            route_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            link,
         }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        true
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::InputSearch(value) => {
+                self.search = value;
+                true
+            }
+            Msg::SelectProvider(strategy) => {
+                if strategy == CUSTOM_STRATEGY {
+                    if self.custom_name.is_none() {
+                        self.custom_name = Some(String::from(""));
+                        self.error_create_connection = None;
+                        return true;
+                    }
+                    return false;
+                }
+                self.link.send_message(Msg::CreateConnection(strategy));
+                false
+            }
+            Msg::InputCustomName(value) => {
+                self.custom_name = Some(value);
+                false
+            }
+            Msg::CancelCustom => {
+                self.custom_name = None;
+                self.error_create_connection = None;
+                true
+            }
+            Msg::CreateConnection(strategy) => {
+                if self.loading_create_connection.is_some() {
+                    return false;
+                }
+                let provider = match self.catalog.find(&strategy) {
+                    Some(provider) => provider,
+                    None => return false,
+                };
+                let mut new_connection = SocialConnectionCreate::from(&provider);
+                if strategy == CUSTOM_STRATEGY {
+                    let name = self.custom_name.clone().unwrap_or_default();
+                    if let Err(message) = validate_connection_name(&name) {
+                        self.link.send_message(Msg::ResponseError(message));
+                        return false;
+                    }
+                    new_connection.name = name;
+                }
+                let request = Request::post(format!("{}/api/v2/connections", API_URL))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&new_connection))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<SocialConnection, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => {
+                                ConsoleService::info(&format!("{:?}", dataok));
+                                Msg::RedirectToSettings(dataok)
+                            }
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_create_connection = None;
+                self.loading_create_connection = Some(strategy);
+                true
+            }
+            Msg::RedirectToSettings(connection) => {
+                self.loading_create_connection = None;
+                self.fetch_task = None;
+                self.route_agent.send(ChangeRoute(AppRoute::SocialSettings { connection_id: connection.id }.into()));
+                true
+            }
+            Msg::ResponseError(message) => {
+                self.loading_create_connection = None;
+                self.error_create_connection = Some(message);
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => {
+                false
+            }
+        }
     }
 
     fn change(&mut self, _: Self::Properties) -> ShouldRender {
@@ -30,6 +184,7 @@ impl Component for SocialCreate {
 
     fn view(&self) -> Html {
         type Anchor = RouterAnchor<AppRoute>;
+        let providers = self.catalog.search(&self.search);
         html! {
             <div
                 class="py-5 px-4 m-auto"
@@ -51,10 +206,10 @@ impl Component for SocialCreate {
                         class="d-flex flex-column"
                     >
                         <h2>{"New Social Connection"}</h2>
-                        
+
                     </div>
                 </div>
-                
+
                 <div class="input-group mb-5">
                     <span class="input-group-text"><i class="bi bi-search"></i></span>
                     <input
@@ -62,196 +217,179 @@ impl Component for SocialCreate {
                         class="form-control"
                         style="font-size: 14px;"
                         placeholder="Search for social connection"
-                        aria-label="Username"
-                        aria-describedby="basic-addon1"
+                        aria-label="Search"
+                        value=self.search.clone()
+                        oninput=self.link.callback(|data: InputData| Msg::InputSearch(data.value))
                     />
                 </div>
 
-                <div class="row">
-
-
-                    <div class="col-sm-6 mb-4">
-                        <div class="card card-hover" style="cursor: pointer;">
-                            <div class="card-body p-4">
-
-                                <div
-                                    class="d-flex mb-3"
-                                >
-                                    <div
-                                        style="flex: 0 0 auto; width: 40px; height: 40px;"
-                                        class="d-flex justify-content-center align-items-center me-3"
-                                    >
-                                        <img
-                                            src="/assets/icons/google-avatar.png"
-                                            class="w-75"
-                                        />
-                                    </div>
-
-                                    <div
-                                        class="d-grid"
-                                        style="min-width: 40px;"
-                                    >
-                                        <div
-                                            class="text-decoration-none fw-bold mb-0"
-                                        >
-                                            <span
-                                                class="fw-bold"
-                                                style="
-                                                    white-space: nowrap;
-                                                    text-overflow: ellipsis;
-                                                    overflow: hidden;
-                                                    font-size: 16px;
-                                                    text-decoration: none;
-                                                "
-                                            >
-                                                {"Google / Gmail"}
-                                            </span>
-                                        </div>
-                                        <p
-                                            class="mb-0 text-muted"
-                                            style="
-                                                white-space: nowrap;
-                                                text-overflow: ellipsis;
-                                                overflow: hidden;
-                                                font-size: 12px;
-                                                text-transform: uppercase;
-                                                letter-spacing: 1px;
-                                            "
-                                        >
-                                            {"social connection"}
-                                        </p>
-                                    </div>
-                    
-                                </div>
-                            <p class="card-text">{"Allow your users to login with their Google Account"}</p>
+                {
+                    if self.error_create_connection.is_some() {
+                        html! {
+                            <div class="alert alert-warning mb-4" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_create_connection.clone().unwrap() }
                             </div>
-                        </div>
-                    </div>
-
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
 
+                {
+                    if providers.is_empty() {
+                        html! {
+                            <div class="mt-5 text-center text-muted">
+                                <i class="bi bi-search fs-1"></i>
+                                <p class="mt-3">{ format!("No social connection matches \"{}\"", self.search.trim()) }</p>
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            <div class="row">
+                                { for providers.iter().map(|provider| self.view_provider(provider)) }
+                            </div>
+                        }
+                    }
+                }
 
-                    <div class="col-sm-6 mb-4">
-                        <div class="card card-hover" style="cursor: pointer;">
-                            <div class="card-body p-4">
+            </div>
+        }
+    }
+}
 
-                                <div
-                                    class="d-flex mb-3"
-                                >
-                                    <div
-                                        style="flex: 0 0 auto; width: 40px; height: 40px;"
-                                        class="d-flex justify-content-center align-items-center me-3"
-                                    >
-                                        <img
-                                            src="/assets/icons/facebook-avatar.png"
-                                            class="w-75"
-                                        />
-                                    </div>
+impl SocialCreate {
+    fn view_provider(&self, provider: &SocialProvider) -> Html {
+        let strategy = provider.strategy.clone();
+        let loading = self.loading_create_connection.as_ref() == Some(&provider.strategy);
+        html! {
+            <div class="col-sm-6 mb-4">
+                <div
+                    class="card card-hover"
+                    style="cursor: pointer;"
+                    onclick=self.link.callback(move |_| Msg::SelectProvider(strategy.clone()))
+                >
+                    <div class="card-body p-4">
 
-                                    <div
-                                        class="d-grid"
-                                        style="min-width: 40px;"
-                                    >
-                                        <div
-                                            class="text-decoration-none fw-bold mb-0"
-                                        >
-                                            <span
-                                                class="fw-bold"
-                                                style="
-                                                    white-space: nowrap;
-                                                    text-overflow: ellipsis;
-                                                    overflow: hidden;
-                                                    font-size: 16px;
-                                                    text-decoration: none;
-                                                "
-                                            >
-                                                {"Facebook"}
-                                            </span>
-                                        </div>
-                                        <p
-                                            class="mb-0 text-muted"
-                                            style="
-                                                white-space: nowrap;
-                                                text-overflow: ellipsis;
-                                                overflow: hidden;
-                                                font-size: 12px;
-                                                text-transform: uppercase;
-                                                letter-spacing: 1px;
-                                            "
-                                        >
-                                            {"social connection"}
-                                        </p>
-                                    </div>
-                    
-                                </div>
-                            <p class="card-text">{"Allow your users to login with their Google Account"}</p>
+                        <div
+                            class="d-flex mb-3"
+                        >
+                            <div
+                                style="flex: 0 0 auto; width: 40px; height: 40px;"
+                                class="d-flex justify-content-center align-items-center me-3"
+                            >
+                                {
+                                    if provider.logo.is_empty() {
+                                        html! { <i class=format!("bi {} fs-3", provider.icon)></i> }
+                                    } else {
+                                        html! { <img src=provider.logo.clone() class="w-75" /> }
+                                    }
+                                }
                             </div>
-                        </div>
-                    </div>
-
-
-                    <div class="col-sm-6 mb-4">
-                        <div class="card card-hover" style="cursor: pointer;">
-                            <div class="card-body p-4">
 
+                            <div
+                                class="d-grid flex-fill"
+                                style="min-width: 40px;"
+                            >
                                 <div
-                                    class="d-flex mb-3"
+                                    class="text-decoration-none fw-bold mb-0"
                                 >
-                                    <div
-                                        style="flex: 0 0 auto; width: 40px; height: 40px;"
-                                        class="d-flex justify-content-center align-items-center rounded me-3 border"
+                                    <span
+                                        class="fw-bold"
+                                        style="
+                                            white-space: nowrap;
+                                            text-overflow: ellipsis;
+                                            overflow: hidden;
+                                            font-size: 16px;
+                                            text-decoration: none;
+                                        "
                                     >
-                                        <img
-                                            src="/assets/icons/facebook-avatar.png"
-                                            class="w-50"
-                                        />
-                                    </div>
-
-                                    <div
-                                        class="d-grid"
-                                        style="min-width: 40px;"
-                                    >
-                                        <div
-                                            class="text-decoration-none fw-bold mb-0"
-                                        >
-                                            <span
-                                                class="fw-bold"
-                                                style="
-                                                    white-space: nowrap;
-                                                    text-overflow: ellipsis;
-                                                    overflow: hidden;
-                                                    font-size: 16px;
-                                                    text-decoration: none;
-                                                "
-                                            >
-                                                {"Facebook"}
-                                            </span>
-                                        </div>
-                                        <p
-                                            class="mb-0 text-muted"
-                                            style="
-                                                white-space: nowrap;
-                                                text-overflow: ellipsis;
-                                                overflow: hidden;
-                                                font-size: 12px;
-                                                text-transform: uppercase;
-                                                letter-spacing: 1px;
-                                            "
-                                        >
-                                            {"social connection"}
-                                        </p>
-                                    </div>
-                    
+                                        { provider.name.clone() }
+                                    </span>
                                 </div>
-                            <p class="card-text">{"Allow your users to login with their Google Account"}</p>
+                                <p
+                                    class="mb-0 text-muted"
+                                    style="
+                                        white-space: nowrap;
+                                        text-overflow: ellipsis;
+                                        overflow: hidden;
+                                        font-size: 12px;
+                                        text-transform: uppercase;
+                                        letter-spacing: 1px;
+                                    "
+                                >
+                                    {"social connection"}
+                                </p>
                             </div>
+
+                            {
+                                if loading {
+                                    html! {
+                                        <div class="spinner-border spinner-border-sm text-secondary" role="status"/>
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
                         </div>
+                    <p class="card-text">{ provider.description.clone() }</p>
+                    {
+                        if provider.strategy == CUSTOM_STRATEGY && self.custom_name.is_some() {
+                            self.view_custom_name()
+                        } else {
+                            html! {}
+                        }
+                    }
                     </div>
-
-
-
-
                 </div>
+            </div>
+        }
+    }
 
+    fn view_custom_name(&self) -> Html {
+        let loading = self.loading_create_connection.is_some();
+        html! {
+            // CLICKS IN THE FORM DON'T SELECT THE CARD AGAIN
+            <div onclick=Callback::from(|event: MouseEvent| event.stop_propagation())>
+                <p class="mb-2 fw-bold">
+                    {"Name"}
+                </p>
+                <div class="input-group mb-2">
+                    <input
+                        type="text"
+                        class="form-control bg-input-grey"
+                        placeholder="my-identity-provider"
+                        value=self.custom_name.clone().unwrap_or_default()
+                        disabled=loading
+                        oninput=self.link.callback(|data: InputData| Msg::InputCustomName(data.value))
+                    />
+                </div>
+                <p class="text-color-disabled">
+                    {"Must start and end with an alphanumeric character and can only contain alphanumeric characters and '-'. Can't have more than 35 characters."}
+                </p>
+                <div class="d-flex">
+                    <button
+                        type="button"
+                        class=format!("btn {} btn-primary position-relative d-flex align-items-center me-2", if loading {"loading"} else {""} )
+                        onclick=self.link.callback(|_| Msg::CreateConnection(String::from(CUSTOM_STRATEGY)))
+                        disabled=loading
+                    >
+                        <div class="telkom-label">
+                            {"Create"}
+                        </div>
+                        <div class="telkom-spinner telkom-center">
+                            <div class="spinner-border spinner-border-sm" role="status"/>
+                        </div>
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-secondary"
+                        onclick=self.link.callback(|_| Msg::CancelCustom)
+                        disabled=loading
+                    >
+                        {"Cancel"}
+                    </button>
+                </div>
             </div>
         }
     }
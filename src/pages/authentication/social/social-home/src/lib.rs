@@ -1,16 +1,43 @@
-use yew::prelude::*;
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+    },
+};
 use yew_router::components::RouterAnchor;
 // use crate::app::AppRoute;
 use router::AppRoute;
+use types::{
+    social::{SocialCatalog, SocialConnection, SocialProvider},
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use configs::server::API_URL;
+use loading::Loading;
+
+pub enum StateError {
+    RequestConnections,
+}
 
 pub struct SocialHome {
     learn_more: bool,
+    access_token: String,
+    catalog: SocialCatalog,
+    connections: Vec<SocialConnection>,
     link: ComponentLink<Self>,
+    fetch_task: Option<FetchTask>,
+    loading_request_connections: bool,
+    error_request_connections: Option<String>,
 }
 
 pub enum Msg {
     LearnMore,
-    HideDetails
+    HideDetails,
+    RequestConnections,
+    GetConnections(Vec<SocialConnection>),
+    ResponseError(String, StateError),
 }
 
 impl Component for SocialHome {
@@ -18,9 +45,40 @@ impl Component for SocialHome {
     type Properties = ();
 
     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
         SocialHome {
             learn_more: false,
+            access_token,
+            catalog: SocialCatalog::load(),
+            connections: vec![],
             link,
+            fetch_task: None,
+            loading_request_connections: false,
+            error_request_connections: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestConnections);
         }
     }
 
@@ -34,13 +92,53 @@ impl Component for SocialHome {
                 self.learn_more = false;
                 true
             }
+            Msg::RequestConnections => {
+                // A SINGLE REQUEST COVERING EVERY STRATEGY OF THE CATALOG
+                let request = Request::get(format!("{}/api/v2/connections?strategy={}", API_URL, self.catalog.strategies().join(",")))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<SocialConnection>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetConnections(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::RequestConnections)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_connections = None;
+                self.loading_request_connections = true;
+                true
+            }
+            Msg::GetConnections(data) => {
+                // PROVIDERS REMOVED FROM THE CATALOG CAN'T BE CONFIGURED ANYMORE
+                let strategies = self.catalog.strategies();
+                self.connections = data
+                    .into_iter()
+                    .filter(|connection| strategies.contains(&connection.strategy))
+                    .collect();
+                self.fetch_task = None;
+                self.loading_request_connections = false;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::RequestConnections => {
+                        self.loading_request_connections = false;
+                        self.error_request_connections = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
         }
     }
 
-    fn change(&mut self, _: Self::Properties) -> ShouldRender {
-        false
-    }
-
     fn view(&self) -> Html {
         type Anchor = RouterAnchor<AppRoute>;
         html! {
@@ -182,183 +280,162 @@ impl Component for SocialHome {
                     }
                 </div>
 
-
-
-                <div>
-
-                    <div
-                        class="d-flex border-bottom border-1 list-hover"
-                    >
-                        <div
-                            class="p-3 d-flex"
-                            style="width: 40%;"
-                        >
+                {
+                    if self.loading_request_connections {
+                        html! {
                             <div
-                                style="flex: 0 0 auto; width: 40px; height: 40px;"
-                                class="d-flex justify-content-center align-items-center rounded me-3 border"
+                                style="
+                                    position: relative;
+                                    margin-top: 8rem;
+                                "
                             >
-                                <img
-                                    src="/assets/icons/facebook-avatar.png"
-                                    class="w-50"
-                                />
+                                <Loading width=45 />
                             </div>
-
-                            <div
-                                class="d-grid"
-                                style="min-width: 40px;"
-                            >
-                                <p
-                                    class="m-0"
-                                    style="
-                                        white-space: nowrap;
-                                        text-overflow: ellipsis;
-                                        overflow: hidden;
-                                        font-size: 14px;
-                                        text-decoration: none;
-                                    "
-                                >
-                                    <Anchor
-                                        route=AppRoute::SocialSettings
-                                        classes="text-decoration-none fw-bold mb-0"
-                                    >
-                                        {"facebook"}
-                                    </Anchor>
-                                </p>
-                                <p
-                                    class="mb-0 text-muted"
-                                    style="
-                                        white-space: nowrap;
-                                        text-overflow: ellipsis;
-                                        overflow: hidden;
-                                        font-size: 14px;
-                                    "
-                                >
-                                    {"Facebook"}
-                                </p>
+                        }
+                    } else if self.error_request_connections.is_some() {
+                        html! {
+                            <div class="alert alert-warning mb-5" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_request_connections.clone().unwrap() }
                             </div>
-            
-                        </div>
+                        }
+                    } else if self.connections.is_empty() {
+                        html! {
+                            <div class="mt-5 text-center text-muted">
+                                <i class="bi bi-people fs-1"></i>
+                                <p class="mt-3">{"You don't have any social connections yet."}</p>
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            <div>
+                                { self.view_list() }
+                            </div>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+}
 
+impl SocialHome {
+    fn view_list(&self) -> Vec<Html> {
+        type Anchor = RouterAnchor<AppRoute>;
+        self.connections.iter().map(|connection| {
+            let SocialConnection {
+                id,
+                name,
+                strategy,
+                enabled_clients,
+                options: _,
+            } = connection.clone();
+            let provider = self.catalog.find(&strategy);
+            html! {
+                <div
+                    class="d-flex border-bottom border-1 list-hover"
+                >
+                    <div
+                        class="p-3 d-flex"
+                        style="width: 40%;"
+                    >
                         <div
-                            class="p-3 d-flex flex-fill align-items-center text-muted"
+                            style="flex: 0 0 auto; width: 40px; height: 40px;"
+                            class="d-flex justify-content-center align-items-center rounded me-3 border"
                         >
-                            <i class="bi bi-dot fs-2"></i>
-                            {"No applications enabled"}
+                            { view_logo(&provider) }
                         </div>
 
                         <div
-                            class="p-3 d-flex align-items-center dropdown"
+                            class="d-grid"
+                            style="min-width: 40px;"
                         >
-                            <button
-                                type="button"
-                                style="flex: 0 0 auto; width: 30px; height: 30px;"
-                                class="btn d-flex justify-content-center align-items-center rounded border"
-                                role="button"
-                                id="dropdownMenuButton1"
-                                data-bs-toggle="dropdown"
-                                aria-expanded="false"
+                            <p
+                                class="m-0"
+                                style="
+                                    white-space: nowrap;
+                                    text-overflow: ellipsis;
+                                    overflow: hidden;
+                                    font-size: 14px;
+                                    text-decoration: none;
+                                "
+                            >
+                                <Anchor
+                                    route=AppRoute::SocialSettings { connection_id: id.clone() }
+                                    classes="text-decoration-none fw-bold mb-0"
+                                >
+                                    { name.clone() }
+                                </Anchor>
+                            </p>
+                            <p
+                                class="mb-0 text-muted"
+                                style="
+                                    white-space: nowrap;
+                                    text-overflow: ellipsis;
+                                    overflow: hidden;
+                                    font-size: 14px;
+                                "
                             >
-                                <i class="bi bi-three-dots"></i>
-                            </button>
-                            <ul class="dropdown-menu" aria-labelledby="dropdownMenuButton1">
-                                <li>
-                                    <Anchor route=AppRoute::SocialSettings classes="dropdown-item fs-7">
-                                        {"Settings"}
-                                    </Anchor>
-                                </li>
-                            </ul>
+                                { provider.map(|provider| provider.name).unwrap_or(strategy) }
+                            </p>
                         </div>
 
                     </div>
 
                     <div
-                        class="d-flex border-bottom border-1 list-hover"
+                        class="p-3 d-flex flex-fill align-items-center text-muted"
                     >
-                        <div
-                            class="p-3 d-flex"
-                            style="width: 40%;"
-                        >
-                            <div
-                                style="flex: 0 0 auto; width: 40px; height: 40px;"
-                                class="d-flex justify-content-center align-items-center rounded me-3 border"
-                            >
-                                <img
-                                    src="/assets/icons/google-avatar.png"
-                                    class="w-50"
-                                />
-                            </div>
-
-                            <div
-                                class="d-grid"
-                                style="min-width: 40px;"
-                            >
-                                <p
-                                    class="m-0"
-                                    style="
-                                        white-space: nowrap;
-                                        text-overflow: ellipsis;
-                                        overflow: hidden;
-                                        font-size: 14px;
-                                        text-decoration: none;
-                                    "
-                                >
-                                    <Anchor
-                                        route=AppRoute::SocialSettings
-                                        classes="text-decoration-none fw-bold mb-0"
-                                    >
-                                        {"google-oauth2"}
-                                    </Anchor>
-                                </p>
-                                <p
-                                    class="mb-0 text-muted"
-                                    style="
-                                        white-space: nowrap;
-                                        text-overflow: ellipsis;
-                                        overflow: hidden;
-                                        font-size: 14px;
-                                    "
-                                >
-                                    {"Google / Mail"}
-                                </p>
-                            </div>
-            
-                        </div>
-
-                        <div
-                            class="p-3 d-flex flex-fill align-items-center text-muted"
-                        >
-                            <i class="bi bi-dot fs-2"></i>
-                            {"No applications enabled"}
-                        </div>
+                        <i class="bi bi-dot fs-2"></i>
+                        {
+                            match enabled_clients.len() {
+                                0 => String::from("No applications enabled"),
+                                1 => String::from("1 application enabled"),
+                                count => format!("{} applications enabled", count),
+                            }
+                        }
+                    </div>
 
-                        <div
-                            class="p-3 d-flex align-items-center dropdown"
+                    <div
+                        class="p-3 d-flex align-items-center dropdown"
+                    >
+                        <button
+                            type="button"
+                            style="flex: 0 0 auto; width: 30px; height: 30px;"
+                            class="btn d-flex justify-content-center align-items-center rounded border"
+                            role="button"
+                            data-bs-toggle="dropdown"
+                            aria-expanded="false"
                         >
-                            <button
-                                type="button"
-                                style="flex: 0 0 auto; width: 30px; height: 30px;"
-                                class="btn d-flex justify-content-center align-items-center rounded border"
-                                role="button"
-                                id="dropdownMenuButton1"
-                                data-bs-toggle="dropdown"
-                                aria-expanded="false"
-                            >
-                                <i class="bi bi-three-dots"></i>
-                            </button>
-                            <ul class="dropdown-menu" aria-labelledby="dropdownMenuButton1">
-                                <li>
-                                    <Anchor route=AppRoute::SocialSettings classes="dropdown-item fs-7">
-                                        {"Settings"}
-                                    </Anchor>
-                                </li>
-                            </ul>
-                        </div>
-
+                            <i class="bi bi-three-dots"></i>
+                        </button>
+                        <ul class="dropdown-menu">
+                            <li>
+                                <Anchor route=AppRoute::SocialSettings { connection_id: id.clone() } classes="dropdown-item fs-7">
+                                    {"Settings"}
+                                </Anchor>
+                            </li>
+                        </ul>
                     </div>
 
                 </div>
-                
-            </div>
-        }
+            }
+        }).collect()
+    }
+}
+
+fn view_logo(provider: &Option<SocialProvider>) -> Html {
+    match provider {
+        Some(provider) if !provider.logo.is_empty() => html! {
+            <img
+                src=provider.logo.clone()
+                class="w-50"
+            />
+        },
+        Some(provider) => html! {
+            <i class=format!("bi {} fs-5", provider.icon)></i>
+        },
+        None => html! {
+            <i class="bi bi-people fs-5"></i>
+        },
     }
 }
@@ -1,7 +1,22 @@
-use yew::prelude::*;
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+        ConsoleService,
+    },
+};
 use yew_router::components::RouterAnchor;
 // use crate::app::AppRoute;
 use router::AppRoute;
+use types::{
+    social::{SocialCatalog, SocialConnection},
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use configs::server::API_URL;
+use loading::Loading;
 use social_applications::SocialApplications;
 use social_tab_settings::TabSettings;
 
@@ -10,23 +25,74 @@ pub enum Content {
     Applications
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct SocialSettingsProps {
+    pub connection_id: String,
+}
+
+pub enum StateError {
+    RequestConnection,
+}
+
 pub struct SocialSettings {
+    access_token: String,
     content: Content,
-    link: ComponentLink<Self>
+    connection_id: String,
+    connection: SocialConnection,
+    catalog: SocialCatalog,
+    link: ComponentLink<Self>,
+    fetch_task: Option<FetchTask>,
+    loading_request_connection: bool,
+    error_request_connection: Option<String>,
 }
 
 pub enum Msg {
-    ChangeContent(Content)
+    ChangeContent(Content),
+    RequestConnection,
+    GetConnection(SocialConnection),
+    ResponseError(String, StateError),
 }
 
 impl Component for SocialSettings {
     type Message = Msg;
-    type Properties = ();
+    type Properties = SocialSettingsProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
 
-    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
         SocialSettings {
+            access_token,
             content: Content::Settings,
-            link
+            connection_id: props.connection_id,
+            connection: SocialConnection::new(),
+            catalog: SocialCatalog::load(),
+            link,
+            fetch_task: None,
+            loading_request_connection: false,
+            error_request_connection: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestConnection);
         }
     }
 
@@ -36,6 +102,50 @@ impl Component for SocialSettings {
                 self.content = content;
                 true
             }
+            Msg::RequestConnection => {
+                let request = Request::get(format!("{}/api/v2/connections/{}", API_URL, self.connection_id.clone()))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<SocialConnection, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => {
+                                ConsoleService::info(&format!("connection details = {:?}", dataok));
+                                Msg::GetConnection(dataok)
+                            }
+                            Err(error) => Msg::ResponseError(
+                                error.to_string(),
+                                StateError::RequestConnection,
+                            ),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_connection = None;
+                self.loading_request_connection = true;
+                true
+            }
+            Msg::GetConnection(data) => {
+                // ALSO RECEIVES THE SAVED CONNECTION FROM THE TABS
+                // SO SWITCHING TABS DOESN'T SHOW STALE SETTINGS
+                self.connection = data;
+                self.fetch_task = None;
+                self.loading_request_connection = false;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::RequestConnection => {
+                        self.loading_request_connection = false;
+                        self.error_request_connection = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
         }
     }
 
@@ -44,7 +154,36 @@ impl Component for SocialSettings {
     }
 
     fn view(&self) -> Html {
+        if self.loading_request_connection {
+            html! {
+                <div
+                    style="
+                        position: relative;
+                        margin-top: 8rem;
+                    "
+                >
+                    <Loading width=45 />
+                </div>
+            }
+        } else if self.error_request_connection.is_some() {
+            html! {
+                <div class="domain-content">
+                    <div class="alert alert-warning mb-5" role="alert">
+                        <i class="bi bi-exclamation-triangle me-2"></i>
+                        { self.error_request_connection.clone().unwrap() }
+                    </div>
+                </div>
+            }
+        } else {
+            self.view_content()
+        }
+    }
+}
+
+impl SocialSettings {
+    fn view_content(&self) -> Html {
         type Anchor = RouterAnchor<AppRoute>;
+        let provider = self.catalog.find(&self.connection.strategy);
         html! {
             <div
                 class="domain-content"
@@ -64,10 +203,22 @@ impl Component for SocialSettings {
                         style="flex: 0 0 auto; width: 64px; height: 64px;"
                         class="d-flex justify-content-center align-items-center rounded me-4 border"
                     >
-                        <img
-                            src="/assets/icons/google-avatar.png"
-                            class="w-50"
-                        />
+                        {
+                            match &provider {
+                                Some(provider) if !provider.logo.is_empty() => html! {
+                                    <img
+                                        src=provider.logo.clone()
+                                        class="w-50"
+                                    />
+                                },
+                                Some(provider) => html! {
+                                    <i class=format!("bi {} fs-3", provider.icon)></i>
+                                },
+                                None => html! {
+                                    <i class="bi bi-people fs-3"></i>
+                                },
+                            }
+                        }
                     </div>
 
                     <div
@@ -75,14 +226,14 @@ impl Component for SocialSettings {
                     >
                         <h2
                             class="title"
-                        >{"google-oauth2"}</h2>
+                        >{ self.connection.name.clone() }</h2>
                         <div
                             class="text-muted"
                         >
                             <span
                                 class="me-4"
                             >
-                                {"Google / Gmail"}
+                                { provider.map(|provider| provider.name).unwrap_or_else(|| self.connection.strategy.clone()) }
                             </span>
                             <span>
                                 {"Identifier"}
@@ -99,7 +250,7 @@ impl Component for SocialSettings {
                                     font-family: 'Roboto Mono', monospace;
                                 "
                             >
-                                {"con_qgZPycWvQ4BBRzcY"}
+                                { self.connection.id.clone() }
                             </span>
                         </div>
                     </div>
@@ -142,13 +293,20 @@ impl Component for SocialSettings {
                     </ul>
                 </div>
 
-                // <Quickstart/>
-                // <TabSettings/>
-
                 {
                     match self.content {
-                        Content::Applications => html! { <SocialApplications/> },
-                        Content::Settings => html! { <TabSettings/> }
+                        Content::Applications => html! {
+                            <SocialApplications
+                                connection=self.connection.clone()
+                                on_update=self.link.callback(Msg::GetConnection)
+                            />
+                        },
+                        Content::Settings => html! {
+                            <TabSettings
+                                connection=self.connection.clone()
+                                on_update=self.link.callback(Msg::GetConnection)
+                            />
+                        },
                     }
                 }
             </div>
@@ -1,26 +1,278 @@
-use yew::prelude::*;
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+        ConsoleService,
+    },
+    agent::Bridged,
+    Bridge,
+};
+use yew_router::{
+    agent::RouteRequest::ChangeRoute,
+    prelude::*,
+};
+use std::collections::BTreeMap;
+use router::AppRoute;
+use configs::server::API_URL;
+use types::{
+    database::{SYNC_ON_EACH_LOGIN, SYNC_ON_FIRST_LOGIN},
+    social::{
+        SocialCatalog,
+        SocialConnection,
+        SocialConnectionUpdate,
+        SocialField,
+        SocialPermission,
+        SocialProvider,
+        CUSTOM_STRATEGY,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
 
-pub struct TabSettings {}
+mod profile_mapping;
 
-pub enum Msg {}
+use profile_mapping::ProfileMappingEditor;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct TabSettingsProps {
+    pub connection: SocialConnection,
+    pub on_update: Callback<SocialConnection>,
+}
+
+pub enum StateError {
+    Update,
+    Delete,
+}
+
+pub struct TabSettings {
+    access_token: String,
+    link: ComponentLink<Self>,
+    catalog: SocialCatalog,
+    connection: SocialConnection,
+    on_update: Callback<SocialConnection>,
+    fetch_task: Option<FetchTask>,
+    loading_update: bool,
+    error_update: Option<String>,
+    message_update: Option<String>,
+    loading_delete: bool,
+    error_delete: Option<String>,
+    route_agent: Box<dyn Bridge<RouteAgent>>,
+}
+
+pub enum Msg {
+    InputSetting(String, String),
+    ToggleAttribute(String),
+    ToggleScope(String),
+    ToggleSyncUserProfile,
+    InputProfileMapping(BTreeMap<String, String>),
+    Update,
+    GetConnection(SocialConnection),
+    Delete,
+    RedirectToConnections,
+    ResponseError(String, StateError),
+    Ignore,
+}
 
 impl Component for TabSettings {
     type Message = Msg;
-    type Properties = ();
+    type Properties = TabSettingsProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
 
-    fn create(_: Self::Properties, _: ComponentLink<Self>) -> Self {
-        TabSettings {}
+        TabSettings {
+            access_token,
+            catalog: SocialCatalog::load(),
+            connection: props.connection,
+            on_update: props.on_update,
+            fetch_task: None,
+            loading_update: false,
+            error_update: None,
+            message_update: None,
+            loading_delete: false,
+            error_delete: None,
+            route_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            link,
+        }
     }
 
-    fn update(&mut self, _msg: Self::Message) -> ShouldRender {
-        true
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::InputSetting(key, value) => {
+                self.connection.options.settings.insert(key, value);
+                self.message_update = None;
+                true
+            }
+            Msg::InputProfileMapping(mapping) => {
+                self.connection.options.profile_mapping = mapping;
+                self.message_update = None;
+                true
+            }
+            Msg::ToggleAttribute(key) => {
+                toggle(&mut self.connection.options.attributes, key);
+                self.message_update = None;
+                true
+            }
+            Msg::ToggleScope(key) => {
+                toggle(&mut self.connection.options.scopes, key);
+                self.message_update = None;
+                true
+            }
+            Msg::ToggleSyncUserProfile => {
+                let options = &mut self.connection.options;
+                options.set_user_root_attributes = if options.set_user_root_attributes == SYNC_ON_FIRST_LOGIN {
+                    String::from(SYNC_ON_EACH_LOGIN)
+                } else {
+                    String::from(SYNC_ON_FIRST_LOGIN)
+                };
+                self.message_update = None;
+                true
+            }
+            Msg::Update => {
+                // VALIDATION
+                if let Some(provider) = self.catalog.find(&self.connection.strategy) {
+                    if let Err(message) = provider.validate(&self.connection.options) {
+                        self.link.send_message(Msg::ResponseError(message, StateError::Update));
+                        return false;
+                    }
+                    self.connection.options = provider.with_required_attributes(&self.connection.options);
+                }
+
+                let data_update_connection = SocialConnectionUpdate::from(&self.connection);
+                let request = Request::patch(format!("{}/api/v2/connections/{}", API_URL, self.connection.id.clone()))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_update_connection))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<SocialConnection, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetConnection(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::Update)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_update = None;
+                self.message_update = None;
+                self.loading_update = true;
+                true
+            }
+            Msg::GetConnection(data) => {
+                ConsoleService::info(&format!("connection = {:?}", data));
+                self.connection = data.clone();
+                self.on_update.emit(data);
+                self.loading_update = false;
+                self.message_update = Some(String::from("Connection settings have been saved"));
+                self.fetch_task = None;
+                true
+            }
+            Msg::Delete => {
+                let request = Request::delete(format!("{}/api/v2/connections/{}", API_URL, self.connection.id.clone()))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(|response: Response<Json<Result<(), anyhow::Error>>>| {
+                    let (meta, Json(data)) = response.into_parts();
+                    let status_number = meta.status.as_u16();
+
+                    match status_number {
+                        204 => {
+                            Msg::RedirectToConnections
+                        }
+                        _ => {
+                            match data {
+                                Ok(_) => {
+                                    Msg::RedirectToConnections
+                                }
+                                Err(error) => {
+                                    Msg::ResponseError(error.to_string(), StateError::Delete)
+                                }
+                            }
+                        }
+                    }
+                });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.loading_delete = true;
+                self.error_delete = None;
+                self.fetch_task = Some(task);
+                true
+            }
+            Msg::RedirectToConnections => {
+                self.loading_delete = false;
+                self.fetch_task = None;
+                self.route_agent.send(ChangeRoute(AppRoute::SocialHome.into()));
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::Update => {
+                        self.loading_update = false;
+                        self.error_update = Some(message);
+                    }
+                    StateError::Delete => {
+                        self.loading_delete = false;
+                        self.error_delete = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => {
+                false
+            }
+        }
     }
 
-    fn change(&mut self, _: Self::Properties) -> ShouldRender {
-        false
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.on_update = props.on_update;
+        if self.connection.id != props.connection.id {
+            self.connection = props.connection;
+            true
+        } else {
+            false
+        }
     }
 
     fn view(&self) -> Html {
+        match self.catalog.find(&self.connection.strategy) {
+            Some(provider) => self.view_settings(&provider),
+            None => html! {
+                <div class="alert alert-warning mb-5" role="alert" style="font-size: 14px;">
+                    <i class="bi bi-exclamation-triangle me-2"></i>
+                    { format!("The provider {} is not available anymore, its settings can't be edited.", self.connection.strategy) }
+                </div>
+            },
+        }
+    }
+}
+
+impl TabSettings {
+    fn view_settings(&self, provider: &SocialProvider) -> Html {
+        let options = self.connection.options.clone();
         html! {
             <div>
 
@@ -50,177 +302,93 @@ impl Component for TabSettings {
                                     <input
                                         type="text"
                                         class="form-control bg-input-grey"
-                                        aria-label="Dollar amount (with dot and two decimal places)"
-                                        value="google-oauth2"
-                                    />   
-                                </div>
-                                <p
-                                    class="text-color-disabled"
-                                >
-                                    {"If you are triggering a login manually, this is the identifier you would use on the connection parameter."}
-                                </p>
-                            </div>
-            
-                            <div
-                                class="mb-4"
-                            >
-                                <p class="mb-2 fw-bold">
-                                    {"Client ID"}
-                                </p>
-                                <div class="input-group mb-2">
-                                    <input
-                                        type="text"
-                                        class="form-control"
-                                        aria-label="Dollar amount (with dot and two decimal places)"
-                                        placeholder="Leave blank to use telkom-dev keys"
-                                    />   
-                                </div>
-                                <a
-                                    href="javascript: void(0)"
-                                    class="text-decoration-none"
-                                >
-                                    <span
-                                        style="
-                                            white-space: nowrap;
-                                            text-overflow: ellipsis;
-                                            overflow: hidden;
-                                            font-size: 14px;
-                                            text-decoration: none;
-                                        "
-                                    >
-                                        {"How to obtain a Client ID?"}
-                                    </span>
-                                </a>
-                            </div>
-            
-                            <div
-                                class="mb-5"
-                            >
-                                <p class="mb-2 fw-bold">
-                                    {"Client Secret"}
-                                </p>
-                                <div class="input-group mb-2">
-                                    <input
-                                        type="text"
-                                        class="form-control"
-                                        aria-label="Dollar amount (with dot and two decimal places)"
-                                        placeholder="Leave blank to use telkom-dev keys"
+                                        value=self.connection.name.clone()
+                                        readonly=true
                                     />
                                 </div>
                                 <p
                                     class="text-color-disabled"
                                 >
-                                    {"For security purposes, we don’t show your existing Client Secret."}
+                                    {"If you are triggering a login manually, this is the identifier you would use on the connection parameter."}
                                 </p>
                             </div>
 
-                            <div
-                                class="mb-5"
-                            >
-                                <p class="mb-2 fw-bold">
-                                    {"Allowed Mobile Client IDs"}
-                                </p>
-                                <div class="input-group mb-2">
-                                    <textarea
-                                        class="form-control"
-                                        rows="4"
-                                    ></textarea>
-                                </div>
-                                <p
-                                    class="text-color-disabled"
-                                >
-                                    {"You can specify multiple valid client IDs by comma-separating them"}
-                                </p>
-                            </div>
+                            { for provider.fields.iter().map(|field| self.view_field(field)) }
 
-                            <div
-                                class="mb-5"
-                            >
-                                <p class="mb-2 fw-bold">
-                                    {"Attributes"}
-                                </p>
-                                <div
-                                    class="d-flex"
-                                >
-                                    <div class="form-check mb-2 d-flex align-items-center w-50">
-                                        <input
-                                            class="form-check-input me-2 mt-0" style="font-size: 16px;" type="checkbox"
-                                            checked=true
-                                            disabled=true
-                                        />
-                                        <label class="form-check-label" style="font-size: 14px;" for="flexCheckCheckedDisabled">
-                                            {"Basic Profile "}
-                                            <i class="bi bi-question-circle"></i>
-                                            <span class="badge bg-light text-dark" style="text-transform: uppercase; letter-spacing: 1px;">{"REQUIRED"}</span>
-                                        </label>
-                                    </div>
-
-                                    <div class="form-check mb-2 d-flex align-items-center w-50">
-                                        <input
-                                            class="form-check-input me-2 mt-0"
-                                            style="font-size: 16px;"
-                                            type="checkbox"
-                                            checked=true
-                                            disabled=true
+                            {
+                                if provider.strategy == CUSTOM_STRATEGY {
+                                    html! {
+                                        <ProfileMappingEditor
+                                            provider=provider.clone()
+                                            options=options.clone()
+                                            disabled=self.loading_update
+                                            on_change=self.link.callback(Msg::InputProfileMapping)
                                         />
-                                        <label class="form-check-label" style="font-size: 14px;" for="flexCheckCheckedDisabled">
-                                            {"Extended Profile "}
-                                            <i class="bi bi-question-circle"></i>
-                                            <span class="badge bg-light text-dark" style="text-transform: uppercase; letter-spacing: 1px;">{"REQUIRED"}</span>
-                                        </label>
-                                    </div>
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
 
-                                </div>
-                            </div>
+                            {
+                                if provider.attributes.is_empty() {
+                                    html! {}
+                                } else {
+                                    html! {
+                                        <div
+                                            class="mb-5"
+                                        >
+                                            <p class="mb-2 fw-bold">
+                                                {"Attributes"}
+                                            </p>
+                                            <div>
+                                                {
+                                                    for provider.attributes.iter().map(|attribute| {
+                                                        let key = attribute.key.clone();
+                                                        self.view_permission(
+                                                            attribute,
+                                                            options.attributes.contains(&attribute.key),
+                                                            self.link.callback(move |_| Msg::ToggleAttribute(key.clone())),
+                                                        )
+                                                    })
+                                                }
+                                            </div>
+                                        </div>
+                                    }
+                                }
+                            }
 
-                            <div
-                                class="mb-5"
-                            >
-                                <p class="mb-2 fw-bold">
-                                    {"Permissions"}
-                                </p>
-                                <div
-                                    class="d-flexs"
-                                >
-                                    <div
-                                        class="form-check mb-2 d-flex align-items-center d-inline-flex"
-                                        style="width: 49%;"
-                                    >
-                                        <input class="form-check-input me-2 mt-0" style="font-size: 16px;" type="checkbox"/>
-                                        <label class="form-check-label" style="font-size: 14px;" for="flexCheckCheckedDisabled">
-                                            {"Contacts"}
-                                            <i class="bi bi-question-circle ms-1"></i>
-                                        </label>
-                                    </div>
-
-                                    <div
-                                        class="form-check mb-2 d-flex align-items-center w-50 d-inline-flex"
-                                        style="width: 49%;"
-                                    >
-                                        <input class="form-check-input me-2 mt-0" style="font-size: 16px;" type="checkbox"/>
-                                        <label class="form-check-label" style="font-size: 14px;" for="flexCheckCheckedDisabled">
-                                            {"Blogger"}
-                                            <i class="bi bi-question-circle ms-1"></i>
-                                        </label>
-                                    </div>
-
-                                    <div
-                                        class="form-check mb-2 d-flex align-items-center w-50 d-inline-flex"
-                                        style="width: 49%;"    
-                                    >
-                                        <input class="form-check-input me-2 mt-0" style="font-size: 16px;" type="checkbox"/>
-                                        <label class="form-check-label" style="font-size: 14px;" for="flexCheckCheckedDisabled">
-                                            {"Calendar"}
-                                            <i class="bi bi-question-circle ms-1"></i>
-                                        </label>
-                                    </div>
+                            {
+                                if provider.scopes.is_empty() {
+                                    html! {}
+                                } else {
+                                    html! {
+                                        <div
+                                            class="mb-5"
+                                        >
+                                            <p class="mb-2 fw-bold">
+                                                {"Permissions"}
+                                            </p>
+                                            <div>
+                                                {
+                                                    for provider.scopes.iter().map(|scope| {
+                                                        let key = scope.key.clone();
+                                                        self.view_permission(
+                                                            scope,
+                                                            options.scopes.contains(&scope.key),
+                                                            self.link.callback(move |_| Msg::ToggleScope(key.clone())),
+                                                        )
+                                                    })
+                                                }
+                                            </div>
+                                        </div>
+                                    }
+                                }
+                            }
 
-                                </div>
-                            </div>
-            
                         </div>
-                    </div>    
-            
+                    </div>
+
                     <div
                         class="row border-bottom mt-5"
                     >
@@ -240,16 +408,52 @@ impl Component for TabSettings {
                                     {"Sync user profile attributes at each login"}
                                 </p>
                                 <div class="form-check form-switch fs-3 mb-4">
-                                    <input class="form-check-input" type="checkbox" id="flexSwitchCheckDefault"/>
+                                    <input
+                                        class="form-check-input"
+                                        type="checkbox"
+                                        checked={ options.set_user_root_attributes != SYNC_ON_FIRST_LOGIN }
+                                        disabled={ self.loading_update }
+                                        onclick=self.link.callback(|_| Msg::ToggleSyncUserProfile)
+                                    />
                                 </div>
                             </div>
-            
-                            <button type="button" class="btn btn-primary mb-5 mt-3">{"Save Changes"}</button>
-            
-            
+
+                            <button
+                                type="button"
+                                class=format!("btn {} btn-primary position-relative mb-5 mt-3", if self.loading_update {"loading"} else {""} )
+                                onclick=self.link.callback(|_| Msg::Update)
+                                disabled={ self.loading_update }
+                            >
+                                <div class="telkom-label">
+                                    {"Save Changes"}
+                                </div>
+                                <div class="telkom-spinner telkom-center">
+                                    <div class="spinner-border spinner-border-sm" role="status"/>
+                                </div>
+                            </button>
+                            {
+                                if self.error_update.is_some() {
+                                    html! {
+                                        <div class="alert alert-warning mb-5" role="alert">
+                                            <i class="bi bi-exclamation-triangle me-2"></i>
+                                            { self.error_update.clone().unwrap() }
+                                        </div>
+                                    }
+                                } else if self.message_update.is_some() {
+                                    html! {
+                                        <div class="alert alert-success mb-5" role="alert">
+                                            <i class="bi bi-check-circle me-2"></i>
+                                            { self.message_update.clone().unwrap() }
+                                        </div>
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
+
                         </div>
                     </div>
-            
+
                 </div>
 
                 <div
@@ -273,12 +477,169 @@ impl Component for TabSettings {
                         <div>
                             <button
                                 type="button"
-                                class="btn btn-danger"
-                            >{"Delete"}</button>
+                                class=format!("btn {} btn-danger position-relative", if self.loading_delete {"loading"} else {""} )
+                                onclick=self.link.callback(|_| Msg::Delete)
+                                disabled={ self.loading_delete }
+                            >
+                                <div class="telkom-label">
+                                    {"Delete"}
+                                </div>
+                                <div class="telkom-spinner telkom-center">
+                                    <div class="spinner-border spinner-border-sm" role="status"/>
+                                </div>
+                            </button>
                         </div>
                     </div>
+                    {
+                        if self.error_delete.is_some() {
+                            html! {
+                                <div class="alert alert-warning" role="alert">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    { self.error_delete.clone().unwrap() }
+                                </div>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </div>
+            </div>
+        }
+    }
+
+    fn view_field(&self, field: &SocialField) -> Html {
+        let key = field.key.clone();
+        let value = self.connection.options.settings.get(&field.key).cloned().unwrap_or_default();
+        let oninput = self.link.callback(move |data: InputData| Msg::InputSetting(key.clone(), data.value));
+        html! {
+            <div
+                class="mb-4"
+            >
+                <p class="mb-2 fw-bold">
+                    { field.label.clone() }
+                    {
+                        if field.required {
+                            html! { <span class="text-danger ms-1">{"*"}</span> }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </p>
+                <div class="input-group mb-2">
+                    {
+                        match field.field_type.as_str() {
+                            "textarea" => html! {
+                                <textarea
+                                    class="form-control"
+                                    rows="4"
+                                    value=value
+                                    disabled={ self.loading_update }
+                                    oninput=oninput
+                                ></textarea>
+                            },
+                            "url" => html! {
+                                <input
+                                    type="url"
+                                    class="form-control"
+                                    placeholder="https://"
+                                    value=value
+                                    disabled={ self.loading_update }
+                                    oninput=oninput
+                                />
+                            },
+                            "secret" => html! {
+                                <input
+                                    type="password"
+                                    class="form-control"
+                                    autocomplete="new-password"
+                                    value=value
+                                    disabled={ self.loading_update }
+                                    oninput=oninput
+                                />
+                            },
+                            _ => html! {
+                                <input
+                                    type="text"
+                                    class="form-control"
+                                    value=value
+                                    disabled={ self.loading_update }
+                                    oninput=oninput
+                                />
+                            },
+                        }
+                    }
                 </div>
+                <p
+                    class="text-color-disabled mb-1"
+                >
+                    { field.description.clone() }
+                </p>
+                {
+                    if field.help_url.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <a
+                                href=field.help_url.clone()
+                                target="_blank"
+                                class="text-decoration-none"
+                            >
+                                <span
+                                    style="
+                                        white-space: nowrap;
+                                        text-overflow: ellipsis;
+                                        overflow: hidden;
+                                        font-size: 14px;
+                                        text-decoration: none;
+                                    "
+                                >
+                                    { format!("How to obtain a {}?", field.label) }
+                                </span>
+                            </a>
+                        }
+                    }
+                }
             </div>
         }
     }
+
+    fn view_permission(&self, permission: &SocialPermission, checked: bool, onclick: Callback<MouseEvent>) -> Html {
+        html! {
+            <div
+                class="form-check mb-2 d-flex align-items-center d-inline-flex"
+                style="width: 49%;"
+                title=permission.description.clone()
+            >
+                <input
+                    class="form-check-input me-2 mt-0"
+                    style="font-size: 16px;"
+                    type="checkbox"
+                    checked={ checked || permission.required }
+                    disabled={ permission.required || self.loading_update }
+                    onclick=onclick
+                />
+                <label class="form-check-label" style="font-size: 14px;">
+                    { permission.label.clone() }
+                    <i class="bi bi-question-circle ms-1"></i>
+                    {
+                        if permission.required {
+                            html! {
+                                <span class="badge bg-light text-dark ms-1" style="text-transform: uppercase; letter-spacing: 1px;">{"REQUIRED"}</span>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </label>
+            </div>
+        }
+    }
+}
+
+fn toggle(keys: &mut Vec<String>, key: String) {
+    if let Some(index) = keys.iter().position(|item| item == &key) {
+        keys.remove(index);
+    } else {
+        keys.push(key);
+    }
 }
@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+use serde_json::Value;
+use yew::prelude::*;
+use types::social::{
+    map_profile,
+    SocialConnectionOptions,
+    SocialProvider,
+    PROFILE_ATTRIBUTES,
+};
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ProfileMappingProps {
+    pub provider: SocialProvider,
+    pub options: SocialConnectionOptions,
+    pub disabled: bool,
+    pub on_change: Callback<BTreeMap<String, String>>,
+}
+
+pub struct Validation {
+    problems: Vec<String>,
+    // USER ATTRIBUTES READ FROM THE SAMPLE PROFILE, EMPTY WITHOUT A SAMPLE
+    mapped: Vec<(String, Option<Value>)>,
+}
+
+pub struct ProfileMappingEditor {
+    props: ProfileMappingProps,
+    link: ComponentLink<Self>,
+    sample_profile: String,
+    validation: Option<Validation>,
+}
+
+pub enum Msg {
+    InputMapping(String, String),
+    InputSample(String),
+    Validate,
+}
+
+impl Component for ProfileMappingEditor {
+    type Message = Msg;
+    type Properties = ProfileMappingProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        ProfileMappingEditor {
+            props,
+            link,
+            sample_profile: String::from(""),
+            validation: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::InputMapping(attribute, path) => {
+                let mut mapping = self.props.options.profile_mapping.clone();
+                mapping.insert(attribute, path.trim().to_string());
+                self.props.on_change.emit(mapping);
+                false
+            }
+            Msg::InputSample(value) => {
+                self.sample_profile = value;
+                self.validation = None;
+                true
+            }
+            Msg::Validate => {
+                let mapping = &self.props.options.profile_mapping;
+                let mut problems = self.props.provider.problems(&self.props.options);
+                let mut mapped = Vec::new();
+                if !self.sample_profile.trim().is_empty() {
+                    match serde_json::from_str::<Value>(&self.sample_profile) {
+                        Ok(profile) if profile.is_object() => {
+                            mapped = map_profile(mapping, &profile);
+                            for (attribute, label, required) in PROFILE_ATTRIBUTES.iter() {
+                                let missing = mapped
+                                    .iter()
+                                    .any(|(key, value)| key == attribute && value.is_none());
+                                if *required && missing {
+                                    problems.push(format!("{} is not in the sample profile", label));
+                                }
+                            }
+                        }
+                        _ => problems.push(String::from("The sample profile must be a JSON object")),
+                    }
+                }
+                self.validation = Some(Validation { problems, mapped });
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            // THE LAST RESULT DOESN'T DESCRIBE THE EDITED SETTINGS ANYMORE
+            if self.props.options != props.options {
+                self.validation = None;
+            }
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let mapping = self.props.options.profile_mapping.clone();
+        html! {
+            <div class="mb-5">
+                <p class="mb-2 fw-bold">
+                    {"Profile Mapping"}
+                </p>
+                <p class="text-color-disabled">
+                    {"Path of each user attribute in the profile returned by the User Info URL, eg. \"emails.0.value\"."}
+                </p>
+                {
+                    for PROFILE_ATTRIBUTES.iter().map(|(attribute, label, required)| {
+                        let key = attribute.to_string();
+                        html! {
+                            <div class="input-group input-group-sm mb-2">
+                                <span class="input-group-text" style="width: 140px;">
+                                    { *label }
+                                    {
+                                        if *required {
+                                            html! { <span class="text-danger ms-1">{"*"}</span> }
+                                        } else {
+                                            html! {}
+                                        }
+                                    }
+                                </span>
+                                <input
+                                    type="text"
+                                    class="form-control"
+                                    style="font-family: 'Roboto Mono', monospace;"
+                                    placeholder="Not mapped"
+                                    value=mapping.get(*attribute).cloned().unwrap_or_default()
+                                    disabled=self.props.disabled
+                                    oninput=self.link.callback(move |data: InputData| Msg::InputMapping(key.clone(), data.value))
+                                />
+                            </div>
+                        }
+                    })
+                }
+
+                <div class="border rounded p-3 mt-4" style="background-color: #f8f9fa;">
+                    <p class="fw-bold mb-2">{"Validate the connection"}</p>
+                    <p class="text-color-disabled mb-2">
+                        {"Checks the URLs and the mapping. Paste a profile returned by the provider to see how it is mapped. The connection can't be enabled for applications until it is valid."}
+                    </p>
+                    <textarea
+                        class="form-control mb-2"
+                        rows="5"
+                        style="font-family: 'Roboto Mono', monospace; font-size: 13px;"
+                        placeholder="{ \"sub\": \"248289761001\", \"email\": \"jane@example.com\" }"
+                        value=self.sample_profile.clone()
+                        oninput=self.link.callback(|data: InputData| Msg::InputSample(data.value))
+                    />
+                    <button
+                        type="button"
+                        class="btn btn-outline-primary btn-sm"
+                        onclick=self.link.callback(|_| Msg::Validate)
+                    >
+                        <i class="bi bi-check2-circle me-2"></i>
+                        {"Validate"}
+                    </button>
+                    { self.view_validation() }
+                </div>
+            </div>
+        }
+    }
+}
+
+impl ProfileMappingEditor {
+    fn view_validation(&self) -> Html {
+        let validation = match &self.validation {
+            Some(validation) => validation,
+            None => return html! {},
+        };
+        html! {
+            <div class="mt-3">
+                {
+                    if validation.problems.is_empty() {
+                        html! {
+                            <p class="text-success mb-2">
+                                <i class="bi bi-check-circle me-2"></i>
+                                {"The connection settings are valid"}
+                            </p>
+                        }
+                    } else {
+                        html! {
+                            <ul class="text-danger mb-2 ps-3">
+                                { for validation.problems.iter().map(|problem| html! { <li>{ problem }</li> }) }
+                            </ul>
+                        }
+                    }
+                }
+                {
+                    if validation.mapped.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <table class="table table-sm mb-0" style="font-size: 13px;">
+                                <tbody>
+                                    {
+                                        for validation.mapped.iter().map(|(attribute, value)| html! {
+                                            <tr>
+                                                <td class="fw-bold" style="width: 140px;">{ attribute }</td>
+                                                <td style="font-family: 'Roboto Mono', monospace;">
+                                                    {
+                                                        match value {
+                                                            Some(value) => html! { <span>{ value.to_string() }</span> },
+                                                            None => html! { <span class="text-muted">{"missing"}</span> },
+                                                        }
+                                                    }
+                                                </td>
+                                            </tr>
+                                        })
+                                    }
+                                </tbody>
+                            </table>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+}
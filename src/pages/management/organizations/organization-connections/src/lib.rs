@@ -0,0 +1,526 @@
+use yew::{
+    prelude::*,
+    format::{Json, Nothing},
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{ StorageService, Area }
+    },
+};
+use configs::server::API_URL;
+use types::{
+    organizations::{ ConnectionOption, OrganizationConnection, OrganizationConnectionCreate },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use serde::Serialize;
+use loading::Loading;
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct OrganizationTabConnectionsProps {
+    pub org_id: String,
+}
+
+pub struct OrganizationTabConnections {
+    // SERVICES
+    link: ComponentLink<Self>,
+    fetch_task: Option<FetchTask>,
+
+    // DATA
+    access_token: String,
+    org_id: String,
+    enabled_connections: Vec<OrganizationConnection>,
+    connection_options: Vec<ConnectionOption>,
+    new_connection: OrganizationConnectionCreate,
+
+    // LAYOUT STATE
+    loading_get_connections: bool,
+    error_get_connections: Option<String>,
+    show_modal_enable: bool,
+    loading_update: bool,
+    error_update: Option<String>,
+}
+
+pub enum StateError {
+    GetConnections,
+    Update,
+}
+
+pub enum Msg {
+    RequestEnabledConnections,
+    GetEnabledConnections(Vec<OrganizationConnection>),
+    ShowModalEnable(bool),
+    RequestConnectionOptions,
+    GetConnectionOptions(Vec<ConnectionOption>),
+    SelectConnection(String),
+    ToggleNewAssignMembership,
+    RequestEnable,
+    ToggleAssignMembership(usize),
+    RequestDisable(usize),
+    ResponseError(String, StateError),
+    Ignore,
+}
+
+impl Component for OrganizationTabConnections {
+    type Message = Msg;
+    type Properties = OrganizationTabConnectionsProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        // GET LOCALSTORAGE
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        // UPDATE STATE
+        let mut access_token = String::from("");
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        OrganizationTabConnections {
+            link,
+            fetch_task: None,
+            access_token,
+            org_id: props.org_id,
+            enabled_connections: vec![],
+            connection_options: vec![],
+            new_connection: OrganizationConnectionCreate {
+                connection_id: String::from(""),
+                assign_membership_on_login: false,
+            },
+            loading_get_connections: false,
+            error_get_connections: None,
+            show_modal_enable: false,
+            loading_update: false,
+            error_update: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestEnabledConnections);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestEnabledConnections => {
+                // DEFAULT STATE
+                self.show_modal_enable = false;
+                self.loading_update = false;
+                self.new_connection.connection_id = String::from("");
+                self.new_connection.assign_membership_on_login = false;
+
+                let request = Request::get(format!("{}/api/v2/organizations/{}/enabled_connections", API_URL, self.org_id))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<OrganizationConnection>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetEnabledConnections(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::GetConnections)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_get_connections = None;
+                self.loading_get_connections = true;
+                true
+            }
+            Msg::GetEnabledConnections(data) => {
+                self.enabled_connections = data;
+                self.fetch_task = None;
+                self.loading_get_connections = false;
+                true
+            }
+            Msg::ShowModalEnable(state) => {
+                self.show_modal_enable = state;
+                self.error_update = None;
+                if state {
+                    self.link.send_message(Msg::RequestConnectionOptions);
+                }
+                true
+            }
+            Msg::RequestConnectionOptions => {
+                let request = Request::get(format!("{}/api/v2/connections", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<ConnectionOption>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetConnectionOptions(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::Update),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                true
+            }
+            Msg::GetConnectionOptions(data) => {
+                self.connection_options = data;
+                self.fetch_task = None;
+                true
+            }
+            Msg::SelectConnection(connection_id) => {
+                self.new_connection.connection_id = connection_id;
+                false
+            }
+            Msg::ToggleNewAssignMembership => {
+                self.new_connection.assign_membership_on_login = !self.new_connection.assign_membership_on_login;
+                true
+            }
+            Msg::RequestEnable => {
+                // VALIDATION
+                if self.new_connection.connection_id.is_empty() {
+                    self.link.send_message(Msg::ResponseError(String::from("Select a connection to enable"), StateError::Update));
+                    return false;
+                }
+                let request = Request::post(format!("{}/api/v2/organizations/{}/enabled_connections", API_URL, self.org_id))
+                    .header("access_token", self.access_token.clone())
+                    .header("Content-Type", "application/json")
+                    .body(Json(&self.new_connection))
+                    .expect("Could not build request");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<OrganizationConnection, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(_) => Msg::RequestEnabledConnections,
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::Update),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_update = None;
+                self.loading_update = true;
+                true
+            }
+            Msg::ToggleAssignMembership(index) => {
+                #[derive(Serialize, Debug, Clone)]
+                struct DataUpdateConnection {
+                    assign_membership_on_login: bool,
+                }
+                let connection = self.enabled_connections[index].clone();
+                let data_update_connection = DataUpdateConnection {
+                    assign_membership_on_login: !connection.assign_membership_on_login,
+                };
+                let request = Request::patch(format!(
+                        "{}/api/v2/organizations/{}/enabled_connections/{}",
+                        API_URL,
+                        self.org_id,
+                        connection.connection_id
+                    ))
+                    .header("access_token", self.access_token.clone())
+                    .header("Content-Type", "application/json")
+                    .body(Json(&data_update_connection))
+                    .expect("Could not build request");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<OrganizationConnection, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(_) => Msg::RequestEnabledConnections,
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::Update),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_update = None;
+                self.loading_update = true;
+                true
+            }
+            Msg::RequestDisable(index) => {
+                let request = Request::delete(format!(
+                        "{}/api/v2/organizations/{}/enabled_connections/{}",
+                        API_URL,
+                        self.org_id,
+                        self.enabled_connections[index].connection_id
+                    ))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request");
+                let callback = self.link.callback(|response: Response<Json<Result<(), anyhow::Error>>>| {
+                    let (meta, Json(data)) = response.into_parts();
+                    let status_number = meta.status.as_u16();
+
+                    match status_number {
+                        204 => Msg::RequestEnabledConnections,
+                        _ => {
+                            match data {
+                                Ok(_) => Msg::RequestEnabledConnections,
+                                Err(error) => Msg::ResponseError(error.to_string(), StateError::Update),
+                            }
+                        }
+                    }
+                });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_update = None;
+                self.loading_update = true;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::GetConnections => {
+                        self.loading_get_connections = false;
+                        self.error_get_connections = Some(message);
+                    }
+                    StateError::Update => {
+                        self.loading_update = false;
+                        self.error_update = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => {
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, _: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+            <div class="mt-4">
+                <div class="row">
+                    <div class="col text-center d-flex justify-content-start m-0">
+                        <p>{"Connections that members of this organization can use to log in."}</p>
+                    </div>
+                    <div class="col-auto d-flex justify-content-end">
+                        <button
+                            type="button"
+                            class="btn btn-primary"
+                            onclick=self.link.callback(|_| Msg::ShowModalEnable(true))
+                        >
+                            {"Enable Connections"}
+                        </button>
+                    </div>
+                </div>
+
+                {
+                    if self.error_update.is_some() && !self.show_modal_enable {
+                        html! {
+                            <div class="alert alert-warning mt-3" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_update.clone().unwrap() }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+
+                <div class="mt-4 table-responsive">
+                    {
+                        if self.loading_get_connections {
+                            html! {
+                                <div style="position: relative; margin-top:4rem;">
+                                    <Loading width = 45 />
+                                </div>
+                            }
+                        } else if self.error_get_connections.is_some() {
+                            html! {
+                                <div class="alert alert-warning mb-5" role="alert">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    { self.error_get_connections.clone().unwrap() }
+                                </div>
+                            }
+                        } else if self.enabled_connections.is_empty() {
+                            html! {
+                                <p class="text-muted text-center mt-5">{"No connection is enabled for this organization."}</p>
+                            }
+                        } else {
+                            html! {
+                                <table class="table">
+                                    <thead>
+                                        <tr>
+                                            <th scope="col">{"Connection"}</th>
+                                            <th scope="col">{"Type"}</th>
+                                            <th scope="col">{"Auto-Membership"}</th>
+                                            <th scope="col"></th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        { self.view_enabled_connections() }
+                                    </tbody>
+                                </table>
+                            }
+                        }
+                    }
+                </div>
+
+                { self.view_modal_enable() }
+            </div>
+
+            <div
+                class=format!("modal-backdrop fade {}", if self.show_modal_enable {"show"} else {""})
+                onclick=self.link.callback(|_| Msg::ShowModalEnable(false))
+            />
+            </>
+        }
+    }
+}
+
+impl OrganizationTabConnections {
+    fn view_enabled_connections(&self) -> Vec<Html> {
+        self.enabled_connections
+        .iter()
+        .enumerate()
+        .map(|(i, enabled_connection)| {
+            let OrganizationConnection {
+                connection_id: _,
+                assign_membership_on_login,
+                connection,
+            } = enabled_connection.clone();
+            html! {
+                <tr>
+                    <th scope="row" class="align-middle">{ connection.name }</th>
+                    <td class="align-middle">{ connection.strategy }</td>
+                    <td class="align-middle">
+                        <div class="form-check form-switch">
+                            <input
+                                class="form-check-input"
+                                type="checkbox"
+                                checked=assign_membership_on_login
+                                disabled=self.loading_update
+                                onclick=self.link.callback(move |_| Msg::ToggleAssignMembership(i))
+                            />
+                        </div>
+                    </td>
+                    <td class="text-end align-middle">
+                        <button
+                            type="button"
+                            class="btn btn-outline-secondary px-2 py-1"
+                            disabled=self.loading_update
+                            onclick=self.link.callback(move |_| Msg::RequestDisable(i))
+                        >
+                            <i class="bi bi-trash"></i>
+                        </button>
+                    </td>
+                </tr>
+            }
+        }).collect()
+    }
+
+    fn view_modal_enable(&self) -> Html {
+        html! {
+            <div class=format!("modal fade {}", if self.show_modal_enable {"show"} else {""})>
+                <div class="modal-dialog modal-dialog-centered">
+                    <div class="modal-content">
+                        <div class="modal-header">
+                            <h5 class="modal-title">{"Enable Connection"}</h5>
+                            <button
+                                type="button"
+                                class="btn-close"
+                                aria-label="Close"
+                                onclick=self.link.callback(|_| Msg::ShowModalEnable(false))
+                            ></button>
+                        </div>
+                        <div class="modal-body">
+                            <div class="mb-3">
+                                <label class="form-label">{"Connection"}</label>
+                                <select
+                                    class="form-select"
+                                    onchange=self.link.callback(|e| {
+                                        if let ChangeData::Select(select) = e {
+                                            Msg::SelectConnection(select.value())
+                                        } else {
+                                            Msg::Ignore
+                                        }
+                                    })
+                                >
+                                    <option value="">{"Select"}</option>
+                                    {
+                                        for self.connection_options
+                                        .iter()
+                                        .filter(|option| {
+                                            !self.enabled_connections.iter().any(|enabled| enabled.connection_id == option.id)
+                                        })
+                                        .map(|option| {
+                                            html! {
+                                                <option
+                                                    value=option.id.clone()
+                                                    selected=option.id == self.new_connection.connection_id
+                                                >
+                                                    { format!("{} ({})", option.name, option.strategy) }
+                                                </option>
+                                            }
+                                        })
+                                    }
+                                </select>
+                            </div>
+                            <div class="form-check form-switch">
+                                <input
+                                    class="form-check-input"
+                                    type="checkbox"
+                                    id="assignMembershipOnLogin"
+                                    checked=self.new_connection.assign_membership_on_login
+                                    onclick=self.link.callback(|_| Msg::ToggleNewAssignMembership)
+                                />
+                                <label class="form-check-label" for="assignMembershipOnLogin">{"Enable Auto-Membership"}</label>
+                            </div>
+                            <div class="form-text">{"Users logging in with this connection will automatically be added as members of this organization."}</div>
+                        </div>
+                        <div class="modal-footer">
+                            <button
+                                type="button"
+                                class="btn btn-outline-secondary"
+                                onclick=self.link.callback(|_| Msg::ShowModalEnable(false))
+                            >
+                                {"Cancel"}
+                            </button>
+                            <button
+                                type="button"
+                                class=format!("btn {} btn-primary position-relative", if self.loading_update {"loading"} else {""} )
+                                onclick=self.link.callback(|_| Msg::RequestEnable)
+                                disabled={ self.loading_update }
+                            >
+                                <div class="telkom-label">
+                                    {"Enable"}
+                                </div>
+                                <div class="telkom-spinner telkom-center">
+                                    <div class="spinner-border spinner-border-sm" role="status"/>
+                                </div>
+                            </button>
+                        </div>
+                        {
+                            if self.error_update.is_some() {
+                                html! {
+                                    <div class="modal-footer">
+                                        <div class="alert alert-warning" role="alert">
+                                            <i class="bi bi-exclamation-triangle me-2"></i>
+                                            { self.error_update.clone().unwrap() }
+                                        </div>
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
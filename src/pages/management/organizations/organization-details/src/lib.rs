@@ -0,0 +1,267 @@
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{ FetchService, FetchTask, Request, Response },
+        storage::{ Area, StorageService },
+        ConsoleService,
+    },
+};
+use yew_router::components::RouterAnchor;
+use router::AppRoute;
+use types::{
+    organizations::Organization,
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use configs::server::API_URL;
+use organization_settings::OrganizationTabSettings;
+use organization_members::OrganizationTabMembers;
+use organization_invitations::OrganizationTabInvitations;
+use organization_connections::OrganizationTabConnections;
+use loading::Loading;
+
+pub enum Content {
+    Settings,
+    Members,
+    Invitations,
+    Connections,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct OrganizationDetailsProps {
+    pub tenant_id: String,
+    pub org_id: String,
+}
+
+pub enum StateError {
+    RequestOrganization,
+}
+
+pub struct OrganizationDetails {
+    access_token: String,
+    content: Content,
+    link: ComponentLink<Self>,
+    tenant_id: String,
+    org_id: String,
+    fetch_task: Option<FetchTask>,
+    organization: Organization,
+    loading_request_organization: bool,
+    error_request_organization: Option<String>,
+}
+
+pub enum Msg {
+    ChangeContent(Content),
+    RequestOrganization,
+    GetOrganization(Organization),
+    ResponseError(String, StateError),
+}
+
+impl Component for OrganizationDetails {
+    type Message = Msg;
+    type Properties = OrganizationDetailsProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        OrganizationDetails {
+            access_token,
+            content: Content::Settings,
+            link,
+            tenant_id: props.tenant_id,
+            org_id: props.org_id,
+            fetch_task: None,
+            organization: Organization::new(),
+            loading_request_organization: false,
+            error_request_organization: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestOrganization);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ChangeContent(content) => {
+                self.content = content;
+                true
+            }
+            Msg::RequestOrganization => {
+                let request = Request::get(format!("{}/api/v2/organizations/{}", API_URL, self.org_id.clone()))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Organization, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => {
+                                ConsoleService::info(&format!("organization details = {:?}", dataok));
+                                Msg::GetOrganization(dataok)
+                            }
+                            Err(error) => Msg::ResponseError(
+                                error.to_string(),
+                                StateError::RequestOrganization,
+                            ),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_organization = None;
+                self.loading_request_organization = true;
+                true
+            }
+            Msg::GetOrganization(data) => {
+                self.organization = data;
+                self.fetch_task = None;
+                self.loading_request_organization = false;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::RequestOrganization => {
+                        self.loading_request_organization = false;
+                        self.error_request_organization = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, _: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        if self.loading_request_organization {
+            html! {
+                <div
+                    style="
+                        position: relative;
+                        margin-top: 8rem;
+                    "
+                >
+                    <Loading width=45 />
+                </div>
+            }
+        } else if self.error_request_organization.is_some() {
+            html! {
+                <div class="domain-content">
+                    <div class="alert alert-warning mb-5" role="alert">
+                        <i class="bi bi-exclamation-triangle me-2"></i>
+                        { self.error_request_organization.clone().unwrap() }
+                    </div>
+                </div>
+            }
+        } else {
+            self.view_content()
+        }
+    }
+}
+
+impl OrganizationDetails {
+    fn view_content(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        let Organization {
+            id,
+            name,
+            display_name,
+            branding: _,
+        } = self.organization.clone();
+        html! {
+            <>
+            <div class="domain-content">
+                <div>
+                    <Anchor route=AppRoute::OrganizationsHome { tenant_id: self.tenant_id.clone() } classes="text-decoration-none text-muted">
+                        <i class="bi bi-arrow-left"></i>
+                        <span>{"Back To Organizations"}</span>
+                    </Anchor>
+                </div>
+
+                <div class="mt-2">
+                    <h2 class="title">{ if display_name.is_empty() { name.clone() } else { display_name.clone() } }</h2>
+                    <div class="pt-2">
+                        <span class="text-muted">{"Organization ID"}</span>
+                        <code class="text-dark ms-2" style="background-color: #eff0f2; font-family: Roboto, sans-serif;">{ id.clone() }</code>
+                    </div>
+                </div>
+
+                <div class="mt-4">
+                    <ul class="nav nav-tabs">
+                        <li onclick=self.link.callback(|_|Msg::ChangeContent(Content::Settings)) class="nav-item">
+                            <a class={
+                                    match self.content {
+                                        Content::Settings => "nav-link active",
+                                        _ => "nav-link"
+                                    }
+                                }
+                                aria-current="page"
+                            >{"Settings"}</a>
+                        </li>
+                        <li onclick=self.link.callback(|_|Msg::ChangeContent(Content::Members)) class="nav-item">
+                            <a class={
+                                    match self.content {
+                                        Content::Members => "nav-link active",
+                                        _ => "nav-link"
+                                    }
+                                }
+                            >{"Members"}</a>
+                        </li>
+                        <li onclick=self.link.callback(|_|Msg::ChangeContent(Content::Invitations)) class="nav-item">
+                            <a class={
+                                    match self.content {
+                                        Content::Invitations => "nav-link active",
+                                        _ => "nav-link"
+                                    }
+                                }
+                            >{"Invitations"}</a>
+                        </li>
+                        <li onclick=self.link.callback(|_|Msg::ChangeContent(Content::Connections)) class="nav-item">
+                            <a class={
+                                    match self.content {
+                                        Content::Connections => "nav-link active",
+                                        _ => "nav-link"
+                                    }
+                                }
+                            >{"Connections"}</a>
+                        </li>
+                    </ul>
+                </div>
+
+                {
+                    match self.content {
+                        Content::Settings => html! { <OrganizationTabSettings tenant_id=self.tenant_id.clone() organization=self.organization.clone() /> },
+                        Content::Members => html! { <OrganizationTabMembers org_id=self.org_id.clone() /> },
+                        Content::Invitations => html! { <OrganizationTabInvitations org_id=self.org_id.clone() /> },
+                        Content::Connections => html! { <OrganizationTabConnections org_id=self.org_id.clone() /> },
+                    }
+                }
+
+            </div>
+            </>
+        }
+    }
+}
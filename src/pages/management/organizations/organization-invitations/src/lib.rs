@@ -0,0 +1,664 @@
+use yew::{
+    prelude::*,
+    format::{Json, Nothing},
+    services::{
+        ConsoleService,
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{ StorageService, Area }
+    },
+};
+use configs::server::API_URL;
+use types::{
+    application::AppList,
+    organizations::{ InvitationCreate, OrganizationConnection, OrganizationInvitation },
+    roles::Role,
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use loading::Loading;
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct OrganizationTabInvitationsProps {
+    pub org_id: String,
+}
+
+pub enum DataInvitation {
+    InviterName,
+    InviteeEmail,
+    ClientId,
+    ConnectionId,
+    Role(String),
+    SendEmail,
+}
+
+pub struct OrganizationTabInvitations {
+    // SERVICES
+    link: ComponentLink<Self>,
+    fetch_task: Option<FetchTask>,
+    fetch_task_options: Vec<FetchTask>,
+
+    // DATA
+    access_token: String,
+    org_id: String,
+    invitations: Vec<OrganizationInvitation>,
+    new_invitation: InvitationCreate,
+    applications: Vec<AppList>,
+    connections: Vec<OrganizationConnection>,
+    roles: Vec<Role>,
+    index_invitation_selected: Option<usize>,
+
+    // LAYOUT STATE
+    loading_get_invitations: bool,
+    error_get_invitations: Option<String>,
+    show_modal_invite: bool,
+    loading_invite: bool,
+    error_invite: Option<String>,
+    loading_revoke: bool,
+    error_revoke: Option<String>,
+}
+
+pub enum StateError {
+    GetInvitations,
+    Invite,
+    Revoke,
+}
+
+pub enum Msg {
+    RequestInvitations,
+    GetInvitations(Vec<OrganizationInvitation>),
+    ShowModalInvite(bool),
+    GetApplications(Vec<AppList>),
+    GetConnections(Vec<OrganizationConnection>),
+    GetRoles(Vec<Role>),
+    Input(String, DataInvitation),
+    RequestInvite,
+    RequestRevoke(usize),
+    ResponseError(String, StateError),
+    Ignore,
+}
+
+impl Component for OrganizationTabInvitations {
+    type Message = Msg;
+    type Properties = OrganizationTabInvitationsProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        // GET LOCALSTORAGE
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        // UPDATE STATE
+        let mut access_token = String::from("");
+        let mut new_invitation = InvitationCreate::new();
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+        if let Some(username) = localstorage_data.username {
+            new_invitation.inviter.name = username;
+        } else {}
+
+        OrganizationTabInvitations {
+            link,
+            fetch_task: None,
+            fetch_task_options: vec![],
+            access_token,
+            org_id: props.org_id,
+            invitations: vec![],
+            new_invitation,
+            applications: vec![],
+            connections: vec![],
+            roles: vec![],
+            index_invitation_selected: None,
+            loading_get_invitations: false,
+            error_get_invitations: None,
+            show_modal_invite: false,
+            loading_invite: false,
+            error_invite: None,
+            loading_revoke: false,
+            error_revoke: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestInvitations);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestInvitations => {
+                // DEFAULT STATE
+                self.show_modal_invite = false;
+                self.loading_invite = false;
+                self.loading_revoke = false;
+                self.index_invitation_selected = None;
+
+                let request = Request::get(format!("{}/api/v2/organizations/{}/invitations", API_URL, self.org_id))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<OrganizationInvitation>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetInvitations(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::GetInvitations)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_get_invitations = None;
+                self.loading_get_invitations = true;
+                true
+            }
+            Msg::GetInvitations(data) => {
+                self.invitations = data;
+                self.fetch_task = None;
+                self.loading_get_invitations = false;
+                true
+            }
+            Msg::ShowModalInvite(state) => {
+                self.show_modal_invite = state;
+                self.error_invite = None;
+                if state {
+                    // OPTIONS FOR THE INVITATION FORM
+                    let request_applications = Request::get(format!("{}/api/v2/clients", API_URL))
+                        .header("access_token", self.access_token.clone())
+                        .body(Nothing)
+                        .expect("Could not build request.");
+                    let callback_applications = self.link.callback(
+                        |response: Response<Json<Result<Vec<AppList>, anyhow::Error>>>| {
+                            let Json(data) = response.into_body();
+                            match data {
+                                Ok(dataok) => Msg::GetApplications(dataok),
+                                Err(error) => Msg::ResponseError(error.to_string(), StateError::Invite),
+                            }
+                        },
+                    );
+                    let request_connections = Request::get(format!("{}/api/v2/organizations/{}/enabled_connections", API_URL, self.org_id))
+                        .header("access_token", self.access_token.clone())
+                        .body(Nothing)
+                        .expect("Could not build request.");
+                    let callback_connections = self.link.callback(
+                        |response: Response<Json<Result<Vec<OrganizationConnection>, anyhow::Error>>>| {
+                            let Json(data) = response.into_body();
+                            match data {
+                                Ok(dataok) => Msg::GetConnections(dataok),
+                                Err(error) => Msg::ResponseError(error.to_string(), StateError::Invite),
+                            }
+                        },
+                    );
+                    let request_roles = Request::get(format!("{}/api/v2/roles", API_URL))
+                        .header("access_token", self.access_token.clone())
+                        .body(Nothing)
+                        .expect("Could not build request.");
+                    let callback_roles = self.link.callback(
+                        |response: Response<Json<Result<Vec<Role>, anyhow::Error>>>| {
+                            let Json(data) = response.into_body();
+                            match data {
+                                Ok(dataok) => Msg::GetRoles(dataok),
+                                Err(error) => Msg::ResponseError(error.to_string(), StateError::Invite),
+                            }
+                        },
+                    );
+                    self.fetch_task_options = vec![
+                        FetchService::fetch(request_applications, callback_applications).expect("failed to start request"),
+                        FetchService::fetch(request_connections, callback_connections).expect("failed to start request"),
+                        FetchService::fetch(request_roles, callback_roles).expect("failed to start request"),
+                    ];
+                }
+                true
+            }
+            Msg::GetApplications(data) => {
+                self.applications = data;
+                true
+            }
+            Msg::GetConnections(data) => {
+                self.connections = data;
+                true
+            }
+            Msg::GetRoles(data) => {
+                self.roles = data;
+                true
+            }
+            Msg::Input(value, data) => {
+                match data {
+                    DataInvitation::InviterName => {
+                        self.new_invitation.inviter.name = value;
+                    }
+                    DataInvitation::InviteeEmail => {
+                        self.new_invitation.invitee.email = value;
+                    }
+                    DataInvitation::ClientId => {
+                        self.new_invitation.client_id = value;
+                    }
+                    DataInvitation::ConnectionId => {
+                        self.new_invitation.connection_id = value;
+                    }
+                    DataInvitation::Role(role_id) => {
+                        if self.new_invitation.roles.contains(&role_id) {
+                            self.new_invitation.roles.retain(|id| *id != role_id);
+                        } else {
+                            self.new_invitation.roles.push(role_id);
+                        }
+                    }
+                    DataInvitation::SendEmail => {
+                        self.new_invitation.send_invitation_email = !self.new_invitation.send_invitation_email;
+                    }
+                }
+                true
+            }
+            Msg::RequestInvite => {
+                // VALIDATION
+                let email = self.new_invitation.invitee.email.clone();
+                if self.new_invitation.inviter.name.is_empty() {
+                    self.link.send_message(Msg::ResponseError(String::from("Inviter name is required"), StateError::Invite));
+                    return false;
+                }
+                if !email.contains('@') || email.starts_with('@') || email.ends_with('@') {
+                    self.link.send_message(Msg::ResponseError(String::from("Invitee email is not valid"), StateError::Invite));
+                    return false;
+                }
+                if self.new_invitation.client_id.is_empty() {
+                    self.link.send_message(Msg::ResponseError(String::from("Select the application the user is invited to"), StateError::Invite));
+                    return false;
+                }
+
+                ConsoleService::info(&format!("invitation = {:?}", self.new_invitation));
+                let request = Request::post(format!("{}/api/v2/organizations/{}/invitations", API_URL, self.org_id))
+                    .header("access_token", self.access_token.clone())
+                    .header("Content-Type", "application/json")
+                    .body(Json(&self.new_invitation))
+                    .expect("Could not build request");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<OrganizationInvitation, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(_) => Msg::RequestInvitations,
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::Invite),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_invite = None;
+                self.loading_invite = true;
+                true
+            }
+            Msg::RequestRevoke(index) => {
+                let request = Request::delete(format!(
+                        "{}/api/v2/organizations/{}/invitations/{}",
+                        API_URL,
+                        self.org_id,
+                        self.invitations[index].id
+                    ))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request");
+                let callback = self.link.callback(|response: Response<Json<Result<(), anyhow::Error>>>| {
+                    let (meta, Json(data)) = response.into_parts();
+                    let status_number = meta.status.as_u16();
+
+                    match status_number {
+                        204 => Msg::RequestInvitations,
+                        _ => {
+                            match data {
+                                Ok(_) => Msg::RequestInvitations,
+                                Err(error) => Msg::ResponseError(error.to_string(), StateError::Revoke),
+                            }
+                        }
+                    }
+                });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.index_invitation_selected = Some(index);
+                self.error_revoke = None;
+                self.loading_revoke = true;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::GetInvitations => {
+                        self.loading_get_invitations = false;
+                        self.error_get_invitations = Some(message);
+                    }
+                    StateError::Invite => {
+                        self.loading_invite = false;
+                        self.error_invite = Some(message);
+                    }
+                    StateError::Revoke => {
+                        self.loading_revoke = false;
+                        self.error_revoke = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => {
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, _: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+            <div class="mt-4">
+                <div class="row">
+                    <div class="col text-center d-flex justify-content-start m-0">
+                        <p>{"Pending invitations to join this organization. Invitations are removed once they are accepted or expired."}</p>
+                    </div>
+                    <div class="col-auto d-flex justify-content-end">
+                        <button
+                            type="button"
+                            class="btn btn-primary"
+                            onclick=self.link.callback(|_| Msg::ShowModalInvite(true))
+                        >
+                            {"Invite Members"}
+                        </button>
+                    </div>
+                </div>
+
+                {
+                    if self.error_revoke.is_some() {
+                        html! {
+                            <div class="alert alert-warning mt-3" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_revoke.clone().unwrap() }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+
+                <div class="mt-4 table-responsive">
+                    {
+                        if self.loading_get_invitations {
+                            html! {
+                                <div style="position: relative; margin-top:4rem;">
+                                    <Loading width = 45 />
+                                </div>
+                            }
+                        } else if self.error_get_invitations.is_some() {
+                            html! {
+                                <div class="alert alert-warning mb-5" role="alert">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    { self.error_get_invitations.clone().unwrap() }
+                                </div>
+                            }
+                        } else if self.invitations.is_empty() {
+                            html! {
+                                <p class="text-muted text-center mt-5">{"There are no pending invitations."}</p>
+                            }
+                        } else {
+                            html! {
+                                <table class="table">
+                                    <thead>
+                                        <tr>
+                                            <th scope="col">{"Invitee"}</th>
+                                            <th scope="col">{"Invited By"}</th>
+                                            <th scope="col">{"Created"}</th>
+                                            <th scope="col">{"Expires"}</th>
+                                            <th scope="col"></th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        { self.view_invitations() }
+                                    </tbody>
+                                </table>
+                            }
+                        }
+                    }
+                </div>
+
+                { self.view_modal_invite() }
+            </div>
+
+            <div
+                class=format!("modal-backdrop fade {}", if self.show_modal_invite {"show"} else {""})
+                onclick=self.link.callback(|_| Msg::ShowModalInvite(false))
+            />
+            </>
+        }
+    }
+}
+
+impl OrganizationTabInvitations {
+    fn view_invitations(&self) -> Vec<Html> {
+        self.invitations
+        .iter()
+        .enumerate()
+        .map(|(i, invitation)| {
+            let OrganizationInvitation {
+                id: _,
+                organization_id: _,
+                inviter,
+                invitee,
+                invitation_url,
+                created_at,
+                expires_at,
+                client_id: _,
+                connection_id: _,
+                roles,
+            } = invitation.clone();
+            let is_revoking = self.loading_revoke && self.index_invitation_selected == Some(i);
+            html! {
+                <tr>
+                    <th scope="row" class="align-middle">
+                        <p class="m-0">{ invitee.email }</p>
+                        <p class="m-0 text-muted fw-normal" style="font-size: 13px;">
+                            { format!("{} role(s)", roles.len()) }
+                        </p>
+                    </th>
+                    <td class="align-middle">{ inviter.name }</td>
+                    <td class="align-middle">{ created_at }</td>
+                    <td class="align-middle">{ expires_at }</td>
+                    <td class="text-end align-middle">
+                        <a
+                            class="btn btn-outline-secondary px-2 py-1 me-2"
+                            href=invitation_url
+                            target="_blank"
+                            title="Open invitation link"
+                        >
+                            <i class="bi bi-link-45deg"></i>
+                        </a>
+                        <button
+                            type="button"
+                            class=format!("btn {} btn-outline-danger px-2 py-1 position-relative", if is_revoking {"loading"} else {""})
+                            disabled=self.loading_revoke
+                            onclick=self.link.callback(move |_| Msg::RequestRevoke(i))
+                        >
+                            <div class="telkom-label">
+                                {"Revoke"}
+                            </div>
+                            <div class="telkom-spinner telkom-center">
+                                <div class="spinner-border spinner-border-sm" role="status"/>
+                            </div>
+                        </button>
+                    </td>
+                </tr>
+            }
+        }).collect()
+    }
+
+    fn view_modal_invite(&self) -> Html {
+        html! {
+            <div class=format!("modal fade {}", if self.show_modal_invite {"show"} else {""})>
+                <div class="modal-dialog modal-dialog-centered modal-dialog-scrollable">
+                    <div class="modal-content">
+                        <div class="modal-header">
+                            <h5 class="modal-title">{"Invite Member"}</h5>
+                            <button
+                                type="button"
+                                class="btn-close"
+                                aria-label="Close"
+                                onclick=self.link.callback(|_| Msg::ShowModalInvite(false))
+                            ></button>
+                        </div>
+                        <div class="modal-body" style="font-size: 14px;">
+                            <div class="mb-3">
+                                <label class="form-label">{"Inviter"} <span class="text-danger">{"*"}</span></label>
+                                <input
+                                    type="text"
+                                    class="form-control"
+                                    value=self.new_invitation.inviter.name.clone()
+                                    oninput=self.link.callback(|data: InputData| Msg::Input(data.value, DataInvitation::InviterName))
+                                />
+                            </div>
+                            <div class="mb-3">
+                                <label class="form-label">{"Invitee Email"} <span class="text-danger">{"*"}</span></label>
+                                <input
+                                    type="email"
+                                    class="form-control"
+                                    value=self.new_invitation.invitee.email.clone()
+                                    oninput=self.link.callback(|data: InputData| Msg::Input(data.value, DataInvitation::InviteeEmail))
+                                />
+                            </div>
+                            <div class="mb-3">
+                                <label class="form-label">{"Application"} <span class="text-danger">{"*"}</span></label>
+                                <select
+                                    class="form-select"
+                                    onchange=self.link.callback(|e| {
+                                        if let ChangeData::Select(select) = e {
+                                            Msg::Input(select.value(), DataInvitation::ClientId)
+                                        } else {
+                                            Msg::Ignore
+                                        }
+                                    })
+                                >
+                                    <option value="">{"Select"}</option>
+                                    {
+                                        for self.applications.iter().map(|application| {
+                                            html! {
+                                                <option
+                                                    value=application.client_id.clone()
+                                                    selected=application.client_id == self.new_invitation.client_id
+                                                >
+                                                    { application.name.clone() }
+                                                </option>
+                                            }
+                                        })
+                                    }
+                                </select>
+                                <div class="form-text">{"The invitee will be redirected to the login route of this application."}</div>
+                            </div>
+                            <div class="mb-3">
+                                <label class="form-label">{"Connection"}</label>
+                                <select
+                                    class="form-select"
+                                    onchange=self.link.callback(|e| {
+                                        if let ChangeData::Select(select) = e {
+                                            Msg::Input(select.value(), DataInvitation::ConnectionId)
+                                        } else {
+                                            Msg::Ignore
+                                        }
+                                    })
+                                >
+                                    <option value="">{"Any enabled connection"}</option>
+                                    {
+                                        for self.connections.iter().map(|connection| {
+                                            html! {
+                                                <option
+                                                    value=connection.connection_id.clone()
+                                                    selected=connection.connection_id == self.new_invitation.connection_id
+                                                >
+                                                    { connection.connection.name.clone() }
+                                                </option>
+                                            }
+                                        })
+                                    }
+                                </select>
+                            </div>
+                            <div class="mb-3">
+                                <label class="form-label">{"Roles"}</label>
+                                <ul class="list-group">
+                                    {
+                                        for self.roles.iter().map(|role| {
+                                            let role_id = role.id.clone();
+                                            html! {
+                                                <li class="list-group-item">
+                                                    <input
+                                                        class="form-check-input me-2"
+                                                        type="checkbox"
+                                                        checked=self.new_invitation.roles.contains(&role.id)
+                                                        onclick=self.link.callback(move |_| Msg::Input(String::from(""), DataInvitation::Role(role_id.clone())))
+                                                    />
+                                                    { role.name.clone() }
+                                                </li>
+                                            }
+                                        })
+                                    }
+                                </ul>
+                                <div class="form-text">{"Roles that will be assigned to the user in this organization once the invitation is accepted."}</div>
+                            </div>
+                            <div class="form-check form-switch">
+                                <input
+                                    class="form-check-input"
+                                    type="checkbox"
+                                    id="sendInvitationEmail"
+                                    checked=self.new_invitation.send_invitation_email
+                                    onclick=self.link.callback(|_| Msg::Input(String::from(""), DataInvitation::SendEmail))
+                                />
+                                <label class="form-check-label" for="sendInvitationEmail">{"Send invitation email"}</label>
+                            </div>
+                        </div>
+                        <div class="modal-footer">
+                            <button
+                                type="button"
+                                class="btn btn-outline-secondary"
+                                onclick=self.link.callback(|_| Msg::ShowModalInvite(false))
+                            >
+                                {"Cancel"}
+                            </button>
+                            <button
+                                type="button"
+                                class=format!("btn {} btn-primary position-relative", if self.loading_invite {"loading"} else {""} )
+                                onclick=self.link.callback(|_| Msg::RequestInvite)
+                                disabled={ self.loading_invite }
+                            >
+                                <div class="telkom-label">
+                                    {"Send Invitation"}
+                                </div>
+                                <div class="telkom-spinner telkom-center">
+                                    <div class="spinner-border spinner-border-sm" role="status"/>
+                                </div>
+                            </button>
+                        </div>
+                        {
+                            if self.error_invite.is_some() {
+                                html! {
+                                    <div class="modal-footer">
+                                        <div class="alert alert-warning" role="alert">
+                                            <i class="bi bi-exclamation-triangle me-2"></i>
+                                            { self.error_invite.clone().unwrap() }
+                                        </div>
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
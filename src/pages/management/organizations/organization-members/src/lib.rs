@@ -0,0 +1,859 @@
+use yew::{
+    prelude::*,
+    format::{Json, Nothing},
+    services::{
+        ConsoleService,
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{ StorageService, Area }
+    },
+};
+use configs::server::API_URL;
+use types::{
+    organizations::{ OrganizationMember, OrganizationMemberRole },
+    roles::Role,
+    users::UserTitle,
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use serde::Serialize;
+use loading::Loading;
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct OrganizationTabMembersProps {
+    pub org_id: String,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+struct DataMembers {
+    members: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+struct DataMemberRoles {
+    roles: Vec<String>,
+}
+
+pub struct OrganizationTabMembers {
+    // SERVICES
+    link: ComponentLink<Self>,
+    fetch_task: Option<FetchTask>,
+    fetch_task_roles: Option<FetchTask>,
+
+    // DATA
+    access_token: String,
+    org_id: String,
+    members: Vec<OrganizationMember>,
+    users: Vec<UserTitle>,
+    selected_users: Vec<String>,
+    index_member_selected: Option<usize>,
+    roles: Vec<Role>,
+    member_roles: Vec<OrganizationMemberRole>,
+
+    // LAYOUT STATE
+    loading_get_members: bool,
+    error_get_members: Option<String>,
+    show_modal_add_members: bool,
+    loading_get_users: bool,
+    loading_add_members: bool,
+    error_add_members: Option<String>,
+    show_modal_remove_member: bool,
+    loading_remove_member: bool,
+    error_remove_member: Option<String>,
+    show_modal_member_roles: bool,
+    loading_get_member_roles: bool,
+    loading_update_member_roles: bool,
+    error_member_roles: Option<String>,
+}
+
+pub enum StateError {
+    GetMembers,
+    GetUsers,
+    AddMembers,
+    RemoveMember,
+    MemberRoles,
+}
+
+pub enum Msg {
+    RequestMembers,
+    GetMembers(Vec<OrganizationMember>),
+
+    ShowModalAddMembers(bool),
+    RequestUsers,
+    GetUsers(Vec<UserTitle>),
+    ToggleUser(String),
+    RequestAddMembers,
+
+    ShowModalRemoveMember(bool, Option<usize>),
+    RequestRemoveMember,
+
+    ShowModalMemberRoles(bool, Option<usize>),
+    RequestRoles,
+    GetRoles(Vec<Role>),
+    RequestMemberRoles,
+    GetMemberRoles(Vec<OrganizationMemberRole>),
+    AssignMemberRole(String),
+    RemoveMemberRole(String),
+
+    ResponseError(String, StateError),
+}
+
+impl Component for OrganizationTabMembers {
+    type Message = Msg;
+    type Properties = OrganizationTabMembersProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        // GET LOCALSTORAGE
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        // UPDATE STATE
+        let mut access_token = String::from("");
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        OrganizationTabMembers {
+            link,
+            fetch_task: None,
+            fetch_task_roles: None,
+            access_token,
+            org_id: props.org_id,
+            members: vec![],
+            users: vec![],
+            selected_users: vec![],
+            index_member_selected: None,
+            roles: vec![],
+            member_roles: vec![],
+            loading_get_members: false,
+            error_get_members: None,
+            show_modal_add_members: false,
+            loading_get_users: false,
+            loading_add_members: false,
+            error_add_members: None,
+            show_modal_remove_member: false,
+            loading_remove_member: false,
+            error_remove_member: None,
+            show_modal_member_roles: false,
+            loading_get_member_roles: false,
+            loading_update_member_roles: false,
+            error_member_roles: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestMembers);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestMembers => {
+                // DEFAULT STATE
+                self.show_modal_add_members = false;
+                self.show_modal_remove_member = false;
+                self.loading_add_members = false;
+                self.loading_remove_member = false;
+                self.selected_users = vec![];
+
+                let request = Request::get(format!("{}/api/v2/organizations/{}/members", API_URL, self.org_id))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<OrganizationMember>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetMembers(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::GetMembers)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_get_members = None;
+                self.loading_get_members = true;
+                true
+            }
+            Msg::GetMembers(data) => {
+                self.members = data;
+                self.fetch_task = None;
+                self.loading_get_members = false;
+                true
+            }
+            Msg::ShowModalAddMembers(state) => {
+                self.show_modal_add_members = state;
+                self.error_add_members = None;
+                self.selected_users = vec![];
+                if state {
+                    self.link.send_message(Msg::RequestUsers);
+                }
+                true
+            }
+            Msg::RequestUsers => {
+                let request = Request::get(format!("{}/api/v2/users", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<UserTitle>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetUsers(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::GetUsers)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.loading_get_users = true;
+                true
+            }
+            Msg::GetUsers(data) => {
+                self.users = data;
+                self.fetch_task = None;
+                self.loading_get_users = false;
+                true
+            }
+            Msg::ToggleUser(user_id) => {
+                if self.selected_users.contains(&user_id) {
+                    self.selected_users.retain(|id| *id != user_id);
+                } else {
+                    self.selected_users.push(user_id);
+                }
+                true
+            }
+            Msg::RequestAddMembers => {
+                // VALIDATION
+                if self.selected_users.is_empty() {
+                    self.link.send_message(Msg::ResponseError(String::from("There is no user to add"), StateError::AddMembers));
+                    return false;
+                }
+                let data_members = DataMembers {
+                    members: self.selected_users.clone(),
+                };
+                let request = Request::post(format!("{}/api/v2/organizations/{}/members", API_URL, self.org_id))
+                    .header("access_token", self.access_token.clone())
+                    .header("Content-Type", "application/json")
+                    .body(Json(&data_members))
+                    .expect("Could not build request");
+                let callback = self.link.callback(|response: Response<Json<Result<(), anyhow::Error>>>| {
+                    let (meta, Json(data)) = response.into_parts();
+                    let status_number = meta.status.as_u16();
+
+                    match status_number {
+                        204 => Msg::RequestMembers,
+                        _ => {
+                            match data {
+                                Ok(_) => Msg::RequestMembers,
+                                Err(error) => {
+                                    Msg::ResponseError(error.to_string(), StateError::AddMembers)
+                                }
+                            }
+                        }
+                    }
+                });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_add_members = None;
+                self.loading_add_members = true;
+                true
+            }
+            Msg::ShowModalRemoveMember(state, index) => {
+                self.show_modal_remove_member = state;
+                self.index_member_selected = index;
+                self.error_remove_member = None;
+                true
+            }
+            Msg::RequestRemoveMember => {
+                if let Some(index) = self.index_member_selected {
+                    let data_members = DataMembers {
+                        members: vec![self.members[index].user_id.clone()],
+                    };
+                    let request = Request::delete(format!("{}/api/v2/organizations/{}/members", API_URL, self.org_id))
+                        .header("access_token", self.access_token.clone())
+                        .header("Content-Type", "application/json")
+                        .body(Json(&data_members))
+                        .expect("Could not build request");
+                    let callback = self.link.callback(|response: Response<Json<Result<(), anyhow::Error>>>| {
+                        let (meta, Json(data)) = response.into_parts();
+                        let status_number = meta.status.as_u16();
+
+                        match status_number {
+                            204 => Msg::RequestMembers,
+                            _ => {
+                                match data {
+                                    Ok(_) => Msg::RequestMembers,
+                                    Err(error) => {
+                                        Msg::ResponseError(error.to_string(), StateError::RemoveMember)
+                                    }
+                                }
+                            }
+                        }
+                    });
+                    let task = FetchService::fetch(request, callback).expect("failed to start request");
+                    self.fetch_task = Some(task);
+                    self.loading_remove_member = true;
+                } else {
+                    self.link.send_message(Msg::ResponseError(String::from("No member has been selected"), StateError::RemoveMember));
+                }
+                true
+            }
+            Msg::ShowModalMemberRoles(state, index) => {
+                self.show_modal_member_roles = state;
+                self.index_member_selected = index;
+                self.error_member_roles = None;
+                self.member_roles = vec![];
+                if state {
+                    self.link.send_message(Msg::RequestRoles);
+                    self.link.send_message(Msg::RequestMemberRoles);
+                }
+                true
+            }
+            Msg::RequestRoles => {
+                let request = Request::get(format!("{}/api/v2/roles", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<Role>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetRoles(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::MemberRoles)
+                            }
+                        }
+                    }
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                true
+            }
+            Msg::GetRoles(data) => {
+                self.roles = data;
+                self.fetch_task = None;
+                true
+            }
+            Msg::RequestMemberRoles => {
+                if let Some(index) = self.index_member_selected {
+                    let request = Request::get(format!(
+                            "{}/api/v2/organizations/{}/members/{}/roles",
+                            API_URL,
+                            self.org_id,
+                            self.members[index].user_id
+                        ))
+                        .header("access_token", self.access_token.clone())
+                        .body(Nothing)
+                        .expect("Could not build request");
+                    let callback = self.link.callback(
+                        |response: Response<Json<Result<Vec<OrganizationMemberRole>, anyhow::Error>>>| {
+                            let Json(data) = response.into_body();
+                            match data {
+                                Ok(dataok) => Msg::GetMemberRoles(dataok),
+                                Err(error) => {
+                                    Msg::ResponseError(error.to_string(), StateError::MemberRoles)
+                                }
+                            }
+                        }
+                    );
+                    let task = FetchService::fetch(request, callback).expect("failed to start request");
+                    self.fetch_task_roles = Some(task);
+                    self.loading_get_member_roles = true;
+                }
+                true
+            }
+            Msg::GetMemberRoles(data) => {
+                ConsoleService::info(&format!("member roles = {:?}", data));
+                self.member_roles = data;
+                self.fetch_task_roles = None;
+                self.loading_get_member_roles = false;
+                self.loading_update_member_roles = false;
+                true
+            }
+            Msg::AssignMemberRole(role_id) => {
+                self.request_update_member_role(role_id, true);
+                true
+            }
+            Msg::RemoveMemberRole(role_id) => {
+                self.request_update_member_role(role_id, false);
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::GetMembers => {
+                        self.loading_get_members = false;
+                        self.error_get_members = Some(message);
+                    }
+                    StateError::GetUsers => {
+                        self.loading_get_users = false;
+                        self.error_add_members = Some(message);
+                    }
+                    StateError::AddMembers => {
+                        self.loading_add_members = false;
+                        self.error_add_members = Some(message);
+                    }
+                    StateError::RemoveMember => {
+                        self.loading_remove_member = false;
+                        self.error_remove_member = Some(message);
+                    }
+                    StateError::MemberRoles => {
+                        self.loading_get_member_roles = false;
+                        self.loading_update_member_roles = false;
+                        self.fetch_task_roles = None;
+                        self.error_member_roles = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, _: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+            <div class="mt-4">
+                <div class="row">
+                    <div class="col text-center d-flex justify-content-start m-0">
+                        <p>{"Users that are members of this organization. Roles assigned here only apply when the user logs in through this organization."}</p>
+                    </div>
+                    <div class="col-auto d-flex justify-content-end">
+                        <button
+                            type="button"
+                            class="btn btn-primary"
+                            onclick=self.link.callback(|_| Msg::ShowModalAddMembers(true))
+                        >
+                            {"Add Members"}
+                        </button>
+                    </div>
+                </div>
+
+                <div class="mt-4 table-responsive">
+                    {
+                        if self.loading_get_members {
+                            html! {
+                                <div style="position: relative; margin-top:4rem;">
+                                    <Loading width = 45 />
+                                </div>
+                            }
+                        } else if self.error_get_members.is_some() {
+                            html! {
+                                <div class="alert alert-warning mb-5" role="alert">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    { self.error_get_members.clone().unwrap() }
+                                </div>
+                            }
+                        } else if self.members.is_empty() {
+                            html! {
+                                <p class="text-muted text-center mt-5">{"This organization has no members yet."}</p>
+                            }
+                        } else {
+                            html! {
+                                <table class="table">
+                                    <thead>
+                                        <tr>
+                                            <th scope="col">{"Name"}</th>
+                                            <th scope="col"></th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        { self.view_members() }
+                                    </tbody>
+                                </table>
+                            }
+                        }
+                    }
+                </div>
+
+                { self.view_modal_add_members() }
+                { self.view_modal_remove_member() }
+                { self.view_modal_member_roles() }
+            </div>
+
+            <div
+                class=format!("modal-backdrop fade {}", if self.show_modal_add_members || self.show_modal_remove_member || self.show_modal_member_roles {"show"} else {""})
+            />
+            </>
+        }
+    }
+}
+
+impl OrganizationTabMembers {
+    fn request_update_member_role(&mut self, role_id: String, assign: bool) {
+        if let Some(index) = self.index_member_selected {
+            let data_member_roles = DataMemberRoles {
+                roles: vec![role_id],
+            };
+            let url = format!(
+                "{}/api/v2/organizations/{}/members/{}/roles",
+                API_URL,
+                self.org_id,
+                self.members[index].user_id
+            );
+            let request = if assign {
+                Request::post(url)
+            } else {
+                Request::delete(url)
+            }
+                .header("access_token", self.access_token.clone())
+                .header("Content-Type", "application/json")
+                .body(Json(&data_member_roles))
+                .expect("Could not build request");
+            let callback = self.link.callback(|response: Response<Json<Result<(), anyhow::Error>>>| {
+                let (meta, Json(data)) = response.into_parts();
+                let status_number = meta.status.as_u16();
+
+                match status_number {
+                    204 => Msg::RequestMemberRoles,
+                    _ => {
+                        match data {
+                            Ok(_) => Msg::RequestMemberRoles,
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::MemberRoles)
+                            }
+                        }
+                    }
+                }
+            });
+            let task = FetchService::fetch(request, callback).expect("failed to start request");
+            self.fetch_task_roles = Some(task);
+            self.error_member_roles = None;
+            self.loading_update_member_roles = true;
+        }
+    }
+
+    fn view_members(&self) -> Vec<Html> {
+        self.members
+        .iter()
+        .enumerate()
+        .map(|(i, member)| {
+            let OrganizationMember {
+                user_id: _,
+                email,
+                picture,
+                name,
+            } = member.clone();
+            html! {
+                <tr>
+                    <th scope="row" class="align-middle">
+                        <div class="d-flex flex-row align-items-center">
+                            <img
+                                src=picture
+                                class="rounded-circle me-3"
+                                style="width: 32px; height: 32px;"
+                            />
+                            <div>
+                                <p class="m-0 fw-bold">{ name }</p>
+                                <p class="m-0 text-muted fw-normal">{ email }</p>
+                            </div>
+                        </div>
+                    </th>
+                    <td class="text-end align-middle">
+                        <button
+                            type="button"
+                            class="btn btn-outline-secondary px-2 py-1 me-2"
+                            onclick=self.link.callback(move |_| Msg::ShowModalMemberRoles(true, Some(i)))
+                        >
+                            <i class="bi bi-person-check me-1"></i>
+                            {"Roles"}
+                        </button>
+                        <button
+                            type="button"
+                            class="btn btn-outline-secondary px-2 py-1"
+                            onclick=self.link.callback(move |_| Msg::ShowModalRemoveMember(true, Some(i)))
+                        >
+                            <i class="bi bi-trash"></i>
+                        </button>
+                    </td>
+                </tr>
+            }
+        }).collect()
+    }
+
+    fn view_modal_add_members(&self) -> Html {
+        html! {
+            <div class=format!("modal fade {}", if self.show_modal_add_members {"show"} else {""})>
+                <div class="modal-dialog modal-dialog-centered modal-dialog-scrollable">
+                    <div class="modal-content">
+                        <div class="modal-header">
+                            <h5 class="modal-title">{"Add Members"}</h5>
+                            <button
+                                type="button"
+                                class="btn-close"
+                                aria-label="Close"
+                                onclick=self.link.callback(|_| Msg::ShowModalAddMembers(false))
+                            ></button>
+                        </div>
+                        <div class="modal-body">
+                            <p>{"Select the users to add to this organization."}</p>
+                            {
+                                if self.loading_get_users {
+                                    html! {
+                                        <div style="position: relative; margin-top: 2rem; margin-bottom: 2rem;">
+                                            <Loading width=45 />
+                                        </div>
+                                    }
+                                } else {
+                                    html! {
+                                        <ul class="list-group">
+                                            { self.view_option_users() }
+                                        </ul>
+                                    }
+                                }
+                            }
+                        </div>
+                        <div class="modal-footer">
+                            <button
+                                type="button"
+                                class="btn btn-outline-secondary"
+                                onclick=self.link.callback(|_| Msg::ShowModalAddMembers(false))
+                            >
+                                {"Cancel"}
+                            </button>
+                            <button
+                                type="button"
+                                class=format!("btn {} btn-primary position-relative", if self.loading_add_members {"loading"} else {""} )
+                                onclick=self.link.callback(|_| Msg::RequestAddMembers)
+                                disabled={ self.loading_add_members }
+                            >
+                                <div class="telkom-label">
+                                    {"Add"}
+                                </div>
+                                <div class="telkom-spinner telkom-center">
+                                    <div class="spinner-border spinner-border-sm" role="status"/>
+                                </div>
+                            </button>
+                        </div>
+                        {
+                            if self.error_add_members.is_some() {
+                                html! {
+                                    <div class="modal-footer">
+                                        <div class="alert alert-warning" role="alert">
+                                            <i class="bi bi-exclamation-triangle me-2"></i>
+                                            { self.error_add_members.clone().unwrap() }
+                                        </div>
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    fn view_option_users(&self) -> Vec<Html> {
+        let options: Vec<Html> = self.users
+        .iter()
+        .filter(|user| {
+            // ONLY USERS THAT ARE NOT MEMBERS YET
+            !self.members.iter().any(|member| member.user_id == user.user_id)
+        })
+        .map(|user| {
+            let user_id = user.user_id.clone();
+            let checked = self.selected_users.contains(&user.user_id);
+            html! {
+                <li class="list-group-item">
+                    <input
+                        class="form-check-input me-2"
+                        type="checkbox"
+                        checked=checked
+                        onclick=self.link.callback(move |_| Msg::ToggleUser(user_id.clone()))
+                    />
+                    <span class="fw-bold me-2">{ user.name.clone() }</span>
+                    <span class="text-muted">{ user.email.clone() }</span>
+                </li>
+            }
+        })
+        .collect();
+
+        if options.is_empty() {
+            vec![html! {
+                <li class="list-group-item text-muted">{"There is no user available"}</li>
+            }]
+        } else {
+            options
+        }
+    }
+
+    fn view_modal_remove_member(&self) -> Html {
+        html! {
+            <div class=format!("modal fade {}", if self.show_modal_remove_member {"show"} else {""})>
+                <div class="modal-dialog modal-dialog-centered">
+                    <div class="modal-content">
+                        <div class="modal-header">
+                            <h5 class="modal-title">{"Remove from Organization?"}</h5>
+                            <button
+                                type="button"
+                                class="btn-close"
+                                aria-label="Close"
+                                onclick=self.link.callback(|_| Msg::ShowModalRemoveMember(false, None))
+                            ></button>
+                        </div>
+                        <div class="modal-body">
+                            {
+                                format!(
+                                    "Are you sure that you want to remove {} from this organization? Their roles in this organization will be removed as well.",
+                                    if let Some(index) = self.index_member_selected { self.members[index].email.clone() }
+                                    else { String::from("") }
+                                )
+                            }
+                        </div>
+                        <div class="modal-footer">
+                            <button
+                                type="button"
+                                class="btn btn-outline-secondary"
+                                onclick=self.link.callback(|_| Msg::ShowModalRemoveMember(false, None))
+                            >
+                                {"Cancel"}
+                            </button>
+                            <button
+                                type="button"
+                                class=format!("btn {} btn-danger position-relative", if self.loading_remove_member {"loading"} else {""} )
+                                onclick=self.link.callback(|_| Msg::RequestRemoveMember)
+                                disabled={ self.loading_remove_member }
+                            >
+                                <div class="telkom-label">
+                                    {"Yes, remove"}
+                                </div>
+                                <div class="telkom-spinner telkom-center">
+                                    <div class="spinner-border spinner-border-sm" role="status"/>
+                                </div>
+                            </button>
+                        </div>
+                        {
+                            if self.error_remove_member.is_some() {
+                                html! {
+                                    <div class="modal-footer">
+                                        <div class="alert alert-warning" role="alert">
+                                            <i class="bi bi-exclamation-triangle me-2"></i>
+                                            { self.error_remove_member.clone().unwrap() }
+                                        </div>
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    fn view_modal_member_roles(&self) -> Html {
+        html! {
+            <div class=format!("modal fade {}", if self.show_modal_member_roles {"show"} else {""})>
+                <div class="modal-dialog modal-dialog-centered modal-dialog-scrollable">
+                    <div class="modal-content">
+                        <div class="modal-header">
+                            <h5 class="modal-title">
+                                {
+                                    format!(
+                                        "Roles of {} in this organization",
+                                        if let Some(index) = self.index_member_selected { self.members[index].email.clone() }
+                                        else { String::from("") }
+                                    )
+                                }
+                            </h5>
+                            <button
+                                type="button"
+                                class="btn-close"
+                                aria-label="Close"
+                                onclick=self.link.callback(|_| Msg::ShowModalMemberRoles(false, None))
+                            ></button>
+                        </div>
+                        <div class="modal-body">
+                            {
+                                if self.loading_get_member_roles {
+                                    html! {
+                                        <div style="position: relative; margin-top: 2rem; margin-bottom: 2rem;">
+                                            <Loading width=45 />
+                                        </div>
+                                    }
+                                } else {
+                                    html! {
+                                        <ul class="list-group">
+                                            { self.view_option_member_roles() }
+                                        </ul>
+                                    }
+                                }
+                            }
+                        </div>
+                        {
+                            if self.error_member_roles.is_some() {
+                                html! {
+                                    <div class="modal-footer">
+                                        <div class="alert alert-warning" role="alert">
+                                            <i class="bi bi-exclamation-triangle me-2"></i>
+                                            { self.error_member_roles.clone().unwrap() }
+                                        </div>
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    fn view_option_member_roles(&self) -> Vec<Html> {
+        if self.roles.is_empty() {
+            return vec![html! {
+                <li class="list-group-item text-muted">{"There is no role available"}</li>
+            }];
+        }
+        self.roles
+        .iter()
+        .map(|role| {
+            let role_id = role.id.clone();
+            let is_assigned = self.member_roles.iter().any(|member_role| member_role.id == role.id);
+            html! {
+                <li class="list-group-item d-flex flex-row align-items-center">
+                    <input
+                        class="form-check-input me-2"
+                        type="checkbox"
+                        checked=is_assigned
+                        disabled=self.loading_update_member_roles
+                        onclick=self.link.callback(move |_| {
+                            if is_assigned {
+                                Msg::RemoveMemberRole(role_id.clone())
+                            } else {
+                                Msg::AssignMemberRole(role_id.clone())
+                            }
+                        })
+                    />
+                    <div>
+                        <p class="m-0 fw-bold">{ role.name.clone() }</p>
+                        <p class="m-0 text-muted" style="font-size: 13px;">{ role.description.clone() }</p>
+                    </div>
+                </li>
+            }
+        })
+        .collect()
+    }
+}
@@ -0,0 +1,543 @@
+use yew::{
+    prelude::*,
+    format::{ Json, Nothing },
+    services::{
+        ConsoleService,
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{ Area, StorageService },
+    },
+    agent::Bridged,
+    Bridge,
+    ComponentLink,
+};
+use yew_router::{
+    agent::RouteRequest::ChangeRoute,
+    prelude::*,
+};
+use router::AppRoute;
+use configs::server::API_URL;
+use types::{
+    organizations::{
+        Organization,
+        OrganizationUpdate,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct OrganizationTabSettingsProps {
+    pub tenant_id: String,
+    pub organization: Organization,
+}
+
+pub enum Data {
+    Name,
+    DisplayName,
+    LogoUrl,
+    PrimaryColor,
+    PageBackground,
+}
+
+pub enum StateError {
+    Update,
+    Delete,
+}
+
+pub struct OrganizationTabSettings {
+    access_token: String,
+    tenant_id: String,
+    organization: Organization,
+    link: ComponentLink<Self>,
+    fetch_task: Option<FetchTask>,
+    loading_update: bool,
+    error_update: Option<String>,
+    message_update: Option<String>,
+    show_modal_delete: bool,
+    loading_delete: bool,
+    error_delete: Option<String>,
+    route_agent: Box<dyn Bridge<RouteAgent>>,
+}
+
+pub enum Msg {
+    Input(String, Data),
+    Update,
+    GetOrganization(Organization),
+    ShowModalDelete(bool),
+    Delete,
+    RedirectToOrganizations,
+    ResponseError(String, StateError),
+    Ignore,
+}
+
+impl Component for OrganizationTabSettings {
+    type Message = Msg;
+    type Properties = OrganizationTabSettingsProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        OrganizationTabSettings {
+            access_token,
+            tenant_id: props.tenant_id,
+            organization: props.organization,
+            fetch_task: None,
+            loading_update: false,
+            error_update: None,
+            message_update: None,
+            show_modal_delete: false,
+            loading_delete: false,
+            error_delete: None,
+            route_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Input(value, data) => {
+                match data {
+                    Data::Name => {
+                        self.organization.name = value;
+                    }
+                    Data::DisplayName => {
+                        self.organization.display_name = value;
+                    }
+                    Data::LogoUrl => {
+                        self.organization.branding.logo_url = value;
+                    }
+                    Data::PrimaryColor => {
+                        self.organization.branding.colors.primary = value;
+                    }
+                    Data::PageBackground => {
+                        self.organization.branding.colors.page_background = value;
+                    }
+                }
+                self.message_update = None;
+                // RENDER TO UPDATE BRANDING PREVIEW
+                true
+            }
+            Msg::Update => {
+                // VALIDATION
+                let colors = self.organization.branding.colors.clone();
+                if !is_hex_color(&colors.primary) || !is_hex_color(&colors.page_background) {
+                    self.link.send_message(Msg::ResponseError(
+                        String::from("Colors must be a hex value, for example #0059d6"),
+                        StateError::Update,
+                    ));
+                    return false;
+                }
+                let logo_url = self.organization.branding.logo_url.clone();
+                if !logo_url.is_empty() && !logo_url.starts_with("https://") {
+                    self.link.send_message(Msg::ResponseError(
+                        String::from("Logo URL must use https"),
+                        StateError::Update,
+                    ));
+                    return false;
+                }
+
+                let data_update_organization = OrganizationUpdate {
+                    name: self.organization.name.clone(),
+                    display_name: self.organization.display_name.clone(),
+                    branding: self.organization.branding.clone(),
+                };
+                let request = Request::patch(format!("{}/api/v2/organizations/{}", API_URL, self.organization.id.clone()))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_update_organization))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Organization, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetOrganization(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::Update)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_update = None;
+                self.message_update = None;
+                self.loading_update = true;
+                true
+            }
+            Msg::GetOrganization(data) => {
+                ConsoleService::info(&format!("organization = {:?}", data));
+                self.organization = data;
+                self.loading_update = false;
+                self.message_update = Some(String::from("Organization settings have been saved"));
+                self.fetch_task = None;
+                true
+            }
+            Msg::ShowModalDelete(state) => {
+                if !self.loading_delete {
+                    self.show_modal_delete = state;
+                    self.error_delete = None;
+                }
+                true
+            }
+            Msg::Delete => {
+                let request = Request::delete(format!("{}/api/v2/organizations/{}", API_URL, self.organization.id.clone()))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(|response: Response<Json<Result<(), anyhow::Error>>>| {
+                    let (meta, Json(data)) = response.into_parts();
+                    let status_number = meta.status.as_u16();
+
+                    match status_number {
+                        204 => {
+                            Msg::RedirectToOrganizations
+                        }
+                        _ => {
+                            match data {
+                                Ok(_) => {
+                                    Msg::RedirectToOrganizations
+                                }
+                                Err(error) => {
+                                    Msg::ResponseError(error.to_string(), StateError::Delete)
+                                }
+                            }
+                        }
+                    }
+                });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.loading_delete = true;
+                self.error_delete = None;
+                self.fetch_task = Some(task);
+                true
+            }
+            Msg::RedirectToOrganizations => {
+                self.loading_delete = false;
+                self.show_modal_delete = false;
+                self.fetch_task = None;
+                self.route_agent.send(ChangeRoute(AppRoute::OrganizationsHome { tenant_id: self.tenant_id.clone() }.into()));
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::Update => {
+                        self.loading_update = false;
+                        self.error_update = Some(message);
+                    }
+                    StateError::Delete => {
+                        self.loading_delete = false;
+                        self.error_delete = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => {
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, _: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let Organization {
+            id: _,
+            name,
+            display_name,
+            branding,
+        } = self.organization.clone();
+        html! {
+            <>
+            <div class="mt-4 p-4">
+                <form>
+                    <div class="mb-3">
+                        <label for="organizationName" class="form-label">{"Name"}</label>
+                        <input
+                            type="text"
+                            class="form-control w-50"
+                            id="organizationName"
+                            value={ name.clone() }
+                            disabled={ self.loading_update }
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::Name))
+                        />
+                        <div class="form-text">{"This is any identifier you want to reference the organization with."}</div>
+                    </div>
+                    <div class="mb-3">
+                        <label for="organizationDisplayName" class="form-label">{"Display Name"}</label>
+                        <input
+                            type="text"
+                            class="form-control w-50"
+                            id="organizationDisplayName"
+                            value={ display_name.clone() }
+                            disabled={ self.loading_update }
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::DisplayName))
+                        />
+                        <div class="form-text">{"If set, this is the name that will be displayed to end-users for this organization in any interaction with them."}</div>
+                    </div>
+
+                    <p class="fw-bold fs-5 mt-5">{"Branding"}</p>
+                    <div class="row">
+                        <div class="col-6">
+                            <div class="mb-3">
+                                <label for="organizationLogo" class="form-label">{"Organization Logo"}</label>
+                                <input
+                                    type="text"
+                                    class="form-control"
+                                    id="organizationLogo"
+                                    placeholder="https://example.com/logo.png"
+                                    value={ branding.logo_url.clone() }
+                                    disabled={ self.loading_update }
+                                    oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::LogoUrl))
+                                />
+                                <div class="form-text">{"If set, this will be shown on the login page for this organization. Recommended size is 150x150 pixels."}</div>
+                            </div>
+                            <div class="mb-3">
+                                <label for="organizationPrimaryColor" class="form-label">{"Primary Color"}</label>
+                                <div class="input-group w-75">
+                                    <input
+                                        type="color"
+                                        class="form-control form-control-color"
+                                        value={ branding.colors.primary.clone() }
+                                        disabled={ self.loading_update }
+                                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::PrimaryColor))
+                                    />
+                                    <input
+                                        type="text"
+                                        class="form-control"
+                                        id="organizationPrimaryColor"
+                                        value={ branding.colors.primary.clone() }
+                                        disabled={ self.loading_update }
+                                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::PrimaryColor))
+                                    />
+                                </div>
+                            </div>
+                            <div class="mb-3">
+                                <label for="organizationPageBackground" class="form-label">{"Page Background Color"}</label>
+                                <div class="input-group w-75">
+                                    <input
+                                        type="color"
+                                        class="form-control form-control-color"
+                                        value={ branding.colors.page_background.clone() }
+                                        disabled={ self.loading_update }
+                                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::PageBackground))
+                                    />
+                                    <input
+                                        type="text"
+                                        class="form-control"
+                                        id="organizationPageBackground"
+                                        value={ branding.colors.page_background.clone() }
+                                        disabled={ self.loading_update }
+                                        oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::PageBackground))
+                                    />
+                                </div>
+                            </div>
+                        </div>
+                        <div class="col-6">
+                            <label class="form-label">{"Preview"}</label>
+                            { self.view_branding_preview() }
+                        </div>
+                    </div>
+
+                    <div class="mt-3">
+                        <button
+                            type="button"
+                            class=format!("btn {} btn-primary position-relative", if self.loading_update {"loading"} else {""} )
+                            onclick=self.link.callback(|_| Msg::Update)
+                            disabled={ self.loading_update }
+                        >
+                            <div class="telkom-label">
+                                {"Save"}
+                            </div>
+                            <div class="telkom-spinner telkom-center">
+                                <div class="spinner-border spinner-border-sm" role="status"/>
+                            </div>
+                        </button>
+
+                        {
+                            if self.error_update.is_some() {
+                                html! {
+                                    <div class="alert alert-warning mt-3" role="alert">
+                                        <i class="bi bi-exclamation-triangle me-2"></i>
+                                        { self.error_update.clone().unwrap() }
+                                    </div>
+                                }
+                            } else if self.message_update.is_some() {
+                                html! {
+                                    <div class="alert alert-success mt-3" role="alert">
+                                        { self.message_update.clone().unwrap() }
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                    </div>
+                </form>
+            </div>
+
+            <div class="mt-2 p-4 pt-0">
+                <p class="fw-bold fs-5">{"Danger Zone"}</p>
+
+                <div class="alert alert-danger" role="alert">
+                    <div class="row">
+                        <div class="col">
+                            <p class="text-danger fw-bold m-0">{"Delete Organization"}</p>
+                            <p class="text-danger m-0">{"Members, invitations and enabled connections of this organization will be removed. Once confirmed, this operation can't be undone!"}</p>
+                        </div>
+                        <div class="col d-flex justify-content-end">
+                            <button
+                                type="button"
+                                class="btn btn-danger"
+                                onclick=self.link.callback(|_| Msg::ShowModalDelete(true))
+                            >
+                                {"Delete"}
+                            </button>
+                        </div>
+                    </div>
+                </div>
+            </div>
+
+            // MODAL DELETE ORGANIZATION
+            <div
+                class=format!("modal fade {}", if self.show_modal_delete {"show"} else {""})
+            >
+                <div class="modal-dialog modal-dialog-centered">
+                    <div class="modal-content">
+                        <div class="modal-header">
+                            <h5 class="modal-title">{"Delete Organization?"}</h5>
+                            <button
+                                type="button"
+                                class="btn-close"
+                                aria-label="Close"
+                                onclick=self.link.callback(|_| Msg::ShowModalDelete(false))
+                            ></button>
+                        </div>
+                        <div class="modal-body">
+                            {
+                                format!(
+                                    "Are you sure that you want to delete {}? Its members, invitations and enabled connections will be removed as well.",
+                                    self.organization.name
+                                )
+                            }
+                        </div>
+                        <div class="modal-footer">
+                            <button
+                                type="button"
+                                class="btn btn-outline-secondary"
+                                onclick=self.link.callback(|_| Msg::ShowModalDelete(false))
+                                disabled={ self.loading_delete }
+                            >
+                                {"Cancel"}
+                            </button>
+                            <button
+                                type="button"
+                                class=format!("btn {} btn-danger position-relative", if self.loading_delete {"loading"} else {""} )
+                                onclick=self.link.callback(|_| Msg::Delete)
+                                disabled={ self.loading_delete }
+                            >
+                                <div class="telkom-label">
+                                    {"Yes, delete"}
+                                </div>
+                                <div class="telkom-spinner telkom-center">
+                                    <div class="spinner-border spinner-border-sm" role="status"/>
+                                </div>
+                            </button>
+                        </div>
+                        {
+                            if self.error_delete.is_some() {
+                                html! {
+                                    <div class="modal-footer">
+                                        <div class="alert alert-warning" role="alert">
+                                            <i class="bi bi-exclamation-triangle me-2"></i>
+                                            { self.error_delete.clone().unwrap() }
+                                        </div>
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                    </div>
+                </div>
+            </div>
+
+            <div
+                class=format!("modal-backdrop fade {}", if self.show_modal_delete {"show"} else {""})
+                onclick=self.link.callback(|_| Msg::ShowModalDelete(false))
+            />
+            </>
+        }
+    }
+}
+
+impl OrganizationTabSettings {
+    fn view_branding_preview(&self) -> Html {
+        let Organization {
+            id: _,
+            name,
+            display_name,
+            branding,
+        } = self.organization.clone();
+        html! {
+            <div
+                class="rounded border d-flex justify-content-center align-items-center p-4"
+                style=format!("background-color: {}; min-height: 280px;", branding.colors.page_background)
+            >
+                <div class="card" style="width: 240px;">
+                    <div class="card-body text-center">
+                        {
+                            if branding.logo_url.is_empty() {
+                                html! {
+                                    <i class="fas fa-building fs-1 text-muted"></i>
+                                }
+                            } else {
+                                html! {
+                                    <img
+                                        src=branding.logo_url.clone()
+                                        style="max-width: 64px; max-height: 64px;"
+                                    />
+                                }
+                            }
+                        }
+                        <p class="mt-2 mb-3" style="font-size: 13px;">
+                            { format!("Log in to {}", if display_name.is_empty() { name.clone() } else { display_name.clone() }) }
+                        </p>
+                        <div class="form-control form-control-sm mb-2 text-start text-muted">{"Email address"}</div>
+                        <button
+                            type="button"
+                            class="btn btn-sm w-100 text-white"
+                            style=format!("background-color: {};", branding.colors.primary)
+                        >
+                            {"Continue"}
+                        </button>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+fn is_hex_color(value: &str) -> bool {
+    value.len() == 7
+        && value.starts_with('#')
+        && value.chars().skip(1).all(|c| c.is_ascii_hexdigit())
+}
@@ -0,0 +1,425 @@
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+        ConsoleService,
+    },
+};
+use yew_router::components::RouterAnchor;
+use router::AppRoute;
+use types::{
+    organizations::{Organization, OrganizationCreate},
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use configs::server::API_URL;
+use loading::Loading;
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct OrganizationsHomeProps {
+    pub tenant_id: String,
+}
+
+pub enum StateError {
+    RequestOrganizations,
+    CreateOrganization,
+}
+
+pub enum DataOrganization {
+    Name,
+    DisplayName,
+}
+
+pub struct OrganizationsHome {
+    tenant_id: String,
+    access_token: String,
+    organizations: Vec<Organization>,
+    link: ComponentLink<Self>,
+    fetch_task: Option<FetchTask>,
+    loading_request_organizations: bool,
+    error_request_organizations: Option<String>,
+    new_organization: OrganizationCreate,
+    loading_create_organization: bool,
+    error_create_organization: Option<String>,
+}
+
+pub enum Msg {
+    RequestOrganizations,
+    GetOrganizations(Vec<Organization>),
+    CreateOrganization,
+    InputOrganization(String, DataOrganization),
+    ResponseError(String, StateError),
+}
+
+impl Component for OrganizationsHome {
+    type Message = Msg;
+    type Properties = OrganizationsHomeProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        OrganizationsHome {
+            tenant_id: props.tenant_id,
+            access_token,
+            organizations: vec![],
+            link,
+            fetch_task: None,
+            loading_request_organizations: false,
+            error_request_organizations: None,
+            new_organization: OrganizationCreate::new(),
+            loading_create_organization: false,
+            error_create_organization: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestOrganizations);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestOrganizations => {
+                let request = Request::get(format!("{}/api/v2/organizations", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<Organization>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetOrganizations(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::RequestOrganizations)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_organizations = None;
+                self.loading_request_organizations = true;
+                true
+            }
+            Msg::GetOrganizations(data) => {
+                self.organizations = data;
+                self.fetch_task = None;
+                self.loading_request_organizations = false;
+                self.error_request_organizations = None;
+                self.loading_create_organization = false;
+                self.error_create_organization = None;
+                true
+            }
+            Msg::InputOrganization(value, data) => {
+                match data {
+                    DataOrganization::Name => {
+                        self.new_organization.name = value;
+                    }
+                    DataOrganization::DisplayName => {
+                        self.new_organization.display_name = value;
+                    }
+                }
+                false
+            }
+            Msg::CreateOrganization => {
+                // VALIDATION
+                // ORGANIZATION NAME IS USED AS IDENTIFIER ON LOGIN, ONLY LOWERCASE, NUMBERS, - AND _
+                let name = self.new_organization.name.clone();
+                if name.is_empty() {
+                    self.link.send_message(Msg::ResponseError(String::from("Name is required"), StateError::CreateOrganization));
+                    return false;
+                }
+                if !name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_') {
+                    self.link.send_message(Msg::ResponseError(
+                        String::from("Name can only contain lowercase letters, numbers, '-' and '_'"),
+                        StateError::CreateOrganization,
+                    ));
+                    return false;
+                }
+
+                let request = Request::post(format!("{}/api/v2/organizations", API_URL))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&self.new_organization))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Organization, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => {
+                                ConsoleService::info(&format!("{:?}", dataok));
+                                Msg::RequestOrganizations
+                            }
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::CreateOrganization)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_create_organization = None;
+                self.loading_create_organization = true;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::RequestOrganizations => {
+                        self.loading_request_organizations = false;
+                        self.error_request_organizations = Some(message);
+                    }
+                    StateError::CreateOrganization => {
+                        self.loading_create_organization = false;
+                        self.error_create_organization = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, _: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <div class="domain-content">
+
+                    <div class="row">
+                        <div class="col-9">
+                            <h2 class="title">{"Organizations"}</h2>
+                        </div>
+
+                        <div class="col-3 d-flex justify-content-end">
+                            <button type="button" data-bs-toggle="modal" data-bs-target="#addOrganizationModal" class="btn btn-primary text-center">
+                                <i class="bi bi-plus me-2" style="margin-left: -5px;"></i>
+                                <span>{"Create Organization"}</span>
+                            </button>
+                        </div>
+                    </div>
+
+                    <div class="mt-3">
+                        <p class="text-muted fs-6">{"Represent the teams, business customers, and partner companies that access your applications as organizations. Each organization has its own members, roles, login connections and branding."}</p>
+                    </div>
+
+                    {
+                        if self.loading_request_organizations {
+                            html! {
+                                <div
+                                    style="
+                                        position: relative;
+                                        margin-top: 8rem;
+                                    "
+                                >
+                                    <Loading width=45 />
+                                </div>
+                            }
+                        } else if self.error_request_organizations.is_some() {
+                            html! {
+                                <div class="alert alert-warning mb-5" role="alert">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    { self.error_request_organizations.clone().unwrap() }
+                                </div>
+                            }
+                        } else if self.organizations.is_empty() {
+                            html! {
+                                <div class="mt-5 text-center text-muted">
+                                    <i class="fas fa-building fs-1"></i>
+                                    <p class="mt-3">{"You don't have any organizations yet."}</p>
+                                </div>
+                            }
+                        } else {
+                            html! { self.view_content() }
+                        }
+                    }
+
+                </div>
+
+                <div class="modal fade" id="addOrganizationModal" tabindex="-1" aria-labelledby="addOrganizationModalLabel" aria-hidden="true">
+                    <div class="modal-dialog modal-dialog-scrollable" role="document">
+                        <div class="modal-content">
+                            <div class="modal-header">
+                                <h5 class="modal-title" id="addOrganizationModalLabel">{"New Organization"}</h5>
+                                <button type="button" class="btn-close" data-bs-dismiss="modal" aria-label="Close">
+                                </button>
+                            </div>
+                            <div class="modal-body">
+                                <div class="form-group">
+                                    <label for="organizationName">{"Name "} <span style="color:red">{"*"}</span></label>
+                                    <input
+                                        type="text"
+                                        class="form-control"
+                                        id="organizationName"
+                                        oninput=self.link.callback(|data: InputData| Msg::InputOrganization(data.value, DataOrganization::Name))
+                                    />
+                                    <div class="form-text">{"This is any identifier you want to reference the organization with, for example: acme-corp"}</div>
+                                </div>
+                                <div class="form-group mt-3 mb-3">
+                                    <label for="organizationDisplayName">{"Display Name"}</label>
+                                    <input
+                                        type="text"
+                                        class="form-control"
+                                        id="organizationDisplayName"
+                                        oninput=self.link.callback(|data: InputData| Msg::InputOrganization(data.value, DataOrganization::DisplayName))
+                                    />
+                                    <div class="form-text">{"The name that will be shown to users on the login page."}</div>
+                                </div>
+                            </div>
+                            <div class="modal-footer">
+                                <button
+                                    type="button"
+                                    class="btn btn-secondary"
+                                    data-bs-dismiss="modal"
+                                    disabled={ self.loading_create_organization }
+                                >
+                                    {"Cancel"}
+                                </button>
+
+                                <button
+                                    type="button"
+                                    class=format!("btn {} btn-primary position-relative", if self.loading_create_organization {"loading"} else {""} )
+                                    onclick=self.link.callback(|_| Msg::CreateOrganization)
+                                    disabled={ self.loading_create_organization }
+                                >
+                                    <div class="telkom-label">
+                                        {"Create"}
+                                    </div>
+                                    <div class="telkom-spinner telkom-center">
+                                        <div class="spinner-border spinner-border-sm" role="status"/>
+                                    </div>
+                                </button>
+                            </div>
+
+                            {
+                                if self.error_create_organization.is_some() {
+                                    html! {
+                                        <div class="alert alert-warning" role="alert">
+                                            <i class="bi bi-exclamation-triangle me-2"></i>
+                                            { self.error_create_organization.clone().unwrap() }
+                                        </div>
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                        </div>
+                    </div>
+                </div>
+            </>
+        }
+    }
+}
+
+impl OrganizationsHome {
+    fn view_content(&self) -> Html {
+        html! {
+            <div class="mt-5">
+                <table class="table">
+                    <thead>
+                        <tr>
+                            <th scope="col">{"Name"}</th>
+                            <th scope="col">{"Display Name"}</th>
+                            <th scope="col"></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { self.view_list() }
+                    </tbody>
+                </table>
+            </div>
+        }
+    }
+
+    fn view_list(&self) -> Vec<Html> {
+        type Anchor = RouterAnchor<AppRoute>;
+        let tenant_id = self.tenant_id.clone();
+        self.organizations.iter().map(|organization| {
+            let Organization {
+                id,
+                name,
+                display_name,
+                branding,
+            } = organization.clone();
+            html! {
+                <tr
+                    class="align-middle"
+                >
+                    <td>
+                        <div class="d-flex flex-row align-items-center">
+                            {
+                                if branding.logo_url.is_empty() {
+                                    html! {
+                                        <span
+                                            class="d-flex justify-content-center align-items-center rounded me-3"
+                                            style=format!("width: 32px; height: 32px; background-color: {}; color: white;", branding.colors.primary)
+                                        >
+                                            { name.chars().next().map(|c| c.to_ascii_uppercase().to_string()).unwrap_or_default() }
+                                        </span>
+                                    }
+                                } else {
+                                    html! {
+                                        <img
+                                            class="rounded me-3"
+                                            style="width: 32px; height: 32px; object-fit: contain;"
+                                            src=branding.logo_url.clone()
+                                        />
+                                    }
+                                }
+                            }
+                            <Anchor
+                                route=AppRoute::OrganizationSettings { tenant_id: tenant_id.clone(), org_id: id.clone() }
+                                classes="text-decoration-none fw-bold"
+                            >
+                                { name.clone() }
+                            </Anchor>
+                        </div>
+                    </td>
+                    <td>{ display_name.clone() }</td>
+                    <td class="text-end">
+                        <button
+                            type="button"
+                            style="flex: 0 0 auto; width: 30px; height: 30px;"
+                            class="btn d-flex justify-content-center align-items-center rounded border ms-auto"
+                            role="button"
+                            data-bs-toggle="dropdown"
+                            aria-expanded="false"
+                        >
+                            <i class="bi bi-three-dots"></i>
+                        </button>
+                        <ul class="dropdown-menu">
+                            <li>
+                                <Anchor route=AppRoute::OrganizationSettings { tenant_id: tenant_id.clone(), org_id: id.clone() } classes="dropdown-item fs-7">
+                                    {"View Details"}
+                                </Anchor>
+                            </li>
+                        </ul>
+                    </td>
+                </tr>
+            }
+        })
+        .collect()
+    }
+}
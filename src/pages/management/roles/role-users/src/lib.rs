@@ -10,14 +10,21 @@ use yew::{
 use configs::server::API_URL;
 use types::{
     roles::{ Role, RoleUser },
+    pagination::Page,
     ResponseMessage,
     LocalStorage,
     LOCALSTORAGE_KEY,
 };
 use yew_router::service::RouteService;
 use serde::Serialize;
-use loading::Loading;
 use role_modal_assign_users::ModalAssignUsers;
+use paginated_table::{
+    query::replace_query,
+    Column,
+    PaginatedTable,
+    Pagination,
+    Sort,
+};
 
 
 #[derive(Clone, Debug, Eq, PartialEq, Properties)]
@@ -38,11 +45,14 @@ pub struct RoleTabUsers {
     loading_delete_user: bool,
     error_delete_user: Option<String>,
     route_service: RouteService,
+    pagination: Pagination,
+    total_role_users: u32,
 }
 
 pub enum Msg {
     RequestRoleUsers,
-    GetRoleUsers(Vec<RoleUser>),
+    GetRoleUsers(Page<RoleUser>),
+    ChangePagination(Pagination),
     ShowModalDeleteUser(bool, Option<usize>),
     Delete,
     ResponseError(String, StateError),
@@ -83,6 +93,9 @@ impl Component for RoleTabUsers {
             
         }
 
+        let route_service = RouteService::new();
+        let pagination = Pagination::from_query(&route_service.get_query(), 50, Some(Sort::asc("email")), &["email"]);
+
         RoleTabUsers {
             role: props.role,
             access_token,
@@ -95,7 +108,9 @@ impl Component for RoleTabUsers {
             index_user_delete: None,
             loading_delete_user: false,
             error_delete_user: None,
-            route_service: RouteService::new(),
+            route_service,
+            pagination,
+            total_role_users: 0,
         }
     }
 
@@ -114,12 +129,12 @@ impl Component for RoleTabUsers {
                 self.show_modal_delete_user = false;
                 self.index_user_delete = None;
 
-                let request = Request::get(format!("{}/api/v2/roles/{}/users", API_URL, self.role.id.clone()))
+                let request = Request::get(format!("{}/api/v2/roles/{}/users?{}", API_URL, self.role.id.clone(), self.pagination.api_params()))
                     .header("access_token", self.access_token.clone())
                     .body(Nothing)
                     .expect("Could not build request.");
                 let callback = self.link.callback(
-                    |response: Response<Json<Result<Vec<RoleUser>, anyhow::Error>>>| {
+                    |response: Response<Json<Result<Page<RoleUser>, anyhow::Error>>>| {
                         let Json(data) = response.into_body();
                         match data {
                             Ok(dataok) => Msg::GetRoleUsers(dataok),
@@ -132,14 +147,22 @@ impl Component for RoleTabUsers {
                 let task = FetchService::fetch(request, callback).expect("failed to start request");
                 self.fetch_task = Some(task);
                 self.loading_get_role_users = true;
+                self.error_get_role_users = None;
                 true
             }
             Msg::GetRoleUsers(data) => {
-                self.role_users = data;
+                self.role_users = data.items;
+                self.total_role_users = data.total;
                 self.fetch_task = None;
                 self.loading_get_role_users = false;
                 true
             }
+            Msg::ChangePagination(pagination) => {
+                self.pagination = pagination;
+                replace_query(&mut self.route_service, &self.pagination.url_params());
+                self.link.send_message(Msg::RequestRoleUsers);
+                false
+            }
             Msg::ShowModalDeleteUser(state, index_selected) => {
                 self.show_modal_delete_user = state;
                 self.index_user_delete = index_selected;
@@ -265,48 +288,21 @@ impl Component for RoleTabUsers {
                     </div>
                 </div>
 
-            <div class="mt-4 table-responsive">
-                <table class="table">
-                    <thead>
-                        <tr>
-                            <th scope="col">{"Name"}</th>
-                            <th scope="col"></th>
-                            // <th scope="col"></th>
-                            // <th scope="col"></th>
-                        </tr>
-                    </thead>
-                    <tbody>
-                        {
-                            if !self.loading_get_role_users && !self.error_get_role_users.is_some() {
-                                html! { 
-                                    <>
-                                        { self.view_content() }
-                                    </>
-                                }
-                            } else {
-                                html! {}
-                            }
-                        }
-                    </tbody>
-                </table>
-                {
-                    if self.loading_get_role_users {
-                        html! {
-                            <div style="position: relative; margin-top:4rem;">
-                                <Loading width = 45 />
-                            </div>
-                        }
-                    } else if self.error_get_role_users.is_some() {
-                        html! {
-                            <div class="alert alert-warning mb-5" role="alert">
-                                <i class="bi bi-exclamation-triangle me-2"></i>
-                                { self.error_get_role_users.clone().unwrap() }
-                            </div>
-                        }
-                    } else {
-                        html! { }
-                    }
-                }
+            <div class="mt-2">
+                <PaginatedTable
+                    columns=vec![
+                        Column::sortable("Name", "email"),
+                        Column::new(""),
+                    ]
+                    pagination=self.pagination.clone()
+                    total=self.total_role_users
+                    loading=self.loading_get_role_users
+                    error=self.error_get_role_users.clone()
+                    empty_message="There are no users assigned to this role yet."
+                    on_change=self.link.callback(Msg::ChangePagination)
+                >
+                    { for self.view_content() }
+                </PaginatedTable>
             </div>
 
             // MODAL DELETE ROLE
@@ -7,17 +7,27 @@ use yew::{
         ConsoleService,
     },
 };
-use yew_router::components::RouterAnchor;
+use yew_router::{
+    components::RouterAnchor,
+    service::RouteService,
+};
 use serde::Serialize;
 use router::AppRoute;
 use types::{
     roles::Role,
+    pagination::Page,
     LocalStorage,
     LOCALSTORAGE_KEY,
 };
 use configs::server::API_URL;
-use loading::Loading;
 use developers_note::DevelopersNote;
+use paginated_table::{
+    query::replace_query,
+    Column,
+    PaginatedTable,
+    Pagination,
+    Sort,
+};
 
 pub enum StateError {
     RequestRoles,
@@ -39,11 +49,15 @@ pub struct RolesHome {
     new_role: Role,
     loading_create_role: bool,
     error_create_role: Option<String>,
+    route_service: RouteService,
+    pagination: Pagination,
+    total_roles: u32,
 }
 
 pub enum Msg {
     RequestRoles,
-    GetRoles(Vec<Role>),
+    GetRoles(Page<Role>),
+    ChangePagination(Pagination),
     CreateRole,
     InputRole(String, DataRole),
     ResponseError(String, StateError),
@@ -79,6 +93,9 @@ impl Component for RolesHome {
         } else {
         }
 
+        let route_service = RouteService::new();
+        let pagination = Pagination::from_query(&route_service.get_query(), 50, Some(Sort::asc("name")), &["name"]);
+
         RolesHome {
             access_token,
             roles: vec![],
@@ -89,6 +106,9 @@ impl Component for RolesHome {
             new_role: Role::new(),
             loading_create_role: false,
             error_create_role: None,
+            route_service,
+            pagination,
+            total_roles: 0,
         }
     }
 
@@ -101,12 +121,12 @@ impl Component for RolesHome {
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::RequestRoles => {
-                let request = Request::get(format!("{}/api/v2/roles", API_URL))
+                let request = Request::get(format!("{}/api/v2/roles?{}", API_URL, self.pagination.api_params()))
                     .header("access_token", self.access_token.clone())
                     .body(Nothing)
                     .expect("Could not build request.");
                 let callback = self.link.callback(
-                    |response: Response<Json<Result<Vec<Role>, anyhow::Error>>>| {
+                    |response: Response<Json<Result<Page<Role>, anyhow::Error>>>| {
                         let Json(data) = response.into_body();
                         match data {
                             Ok(dataok) => {
@@ -126,7 +146,8 @@ impl Component for RolesHome {
                 true
             }
             Msg::GetRoles(data) => {
-                self.roles = data;
+                self.roles = data.items;
+                self.total_roles = data.total;
                 self.fetch_task = None;
                 self.loading_request_roles = false;
                 self.error_request_roles = None;
@@ -134,6 +155,12 @@ impl Component for RolesHome {
                 self.error_create_role = None;
                 true
             }
+            Msg::ChangePagination(pagination) => {
+                self.pagination = pagination;
+                replace_query(&mut self.route_service, &self.pagination.url_params());
+                self.link.send_message(Msg::RequestRoles);
+                false
+            }
             Msg::InputRole(value, data) => {
                 match data {
                     DataRole::Name => {
@@ -217,29 +244,7 @@ impl Component for RolesHome {
                     </div>
 
 
-                    {
-                        if self.loading_request_roles {
-                            html! {
-                                <div
-                                    style="
-                                        position: relative;
-                                        margin-top: 8rem;
-                                    "
-                                >
-                                    <Loading width=45 />
-                                </div>
-                            }
-                        } else if self.error_request_roles.is_some() {
-                            html! {
-                                <div class="alert alert-warning mb-5" role="alert">
-                                    <i class="bi bi-exclamation-triangle me-2"></i>
-                                    { self.error_request_roles.clone().unwrap() }
-                                </div>
-                            }
-                        } else {
-                            html! { self.view_content() }
-                        }
-                    }
+                    { self.view_content() }
 
             </div>
 
@@ -329,17 +334,20 @@ impl RolesHome {
         html! {
             <div class="mt-5">
                 <DevelopersNote message="There is still bugs, work in progress"/>
-                <table class="table">
-                    <thead>
-                        <tr>
-                            <th scope="col">{"Name"}</th>
-                            <th scope="col">{"Description"}</th>
-                        </tr>
-                    </thead>
-                    <tbody>
-                        { self.view_list() }
-                    </tbody>
-                </table>
+                <PaginatedTable
+                    columns=vec![
+                        Column::sortable("Name", "name"),
+                        Column::new("Description"),
+                    ]
+                    pagination=self.pagination.clone()
+                    total=self.total_roles
+                    loading=self.loading_request_roles
+                    error=self.error_request_roles.clone()
+                    empty_message="There are no roles yet."
+                    on_change=self.link.callback(Msg::ChangePagination)
+                >
+                    { for self.view_list() }
+                </PaginatedTable>
             </div>
         }
     }
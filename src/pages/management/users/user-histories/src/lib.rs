@@ -1,20 +1,45 @@
 use yew::{
     format::{Json, Nothing},
     prelude::*,
-    services::fetch::{FetchService, FetchTask, Request, Response},
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{StorageService, Area},
+    },
 };
-use yew::services::ConsoleService;
-use loading::Loading;
+use yew_router::service::RouteService;
 use configs::server::API_URL;
-use types::users::{UserLogDetails, ResponseUserLogLists};
+use types::{
+    logs::{log_event_name, log_event_type},
+    users::UserLogDetails,
+    pagination::Page,
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use paginated_table::{
+    query::replace_query,
+    Column,
+    PaginatedTable,
+    Pagination,
+    Sort,
+};
+
 
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct UserTabHistoryProps {
+    pub user_id: String,
+}
 
 pub struct UserTabHistory {
+    user_id: String,
+    access_token: String,
     link: ComponentLink<Self>,
     fetch_task: Option<FetchTask>,
     loading_get_user_logs: bool,
     user_log_events: Vec<UserLogDetails>, 
     error_user_log_list: Option<String>,
+    route_service: RouteService,
+    pagination: Pagination,
+    total_user_logs: u32,
 }
 
 
@@ -25,18 +50,28 @@ pub enum StateError {
 pub enum Msg {
     DefaultState,
     RequestUserLogEvents,
-    GetUserLogEvents(Vec<UserLogDetails>),
+    GetUserLogEvents(Page<UserLogDetails>),
+    ChangePagination(Pagination),
     ResponseError(String, StateError),
 }
 
 impl UserTabHistory {
     fn view_user_log_history(&self) -> Vec<Html> {
         self.user_log_events.iter().map(|user_event|{
+            let event_type = log_event_type(&user_event.type_detail);
             html! {
                 <tr>
-                    <th scope="row"><svg xmlns="http://www.w3.org/2000/svg" width="16" height="16" viewBox="0 0 24 24" fill="none" stroke="#13a688" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"><path d="M22 11.08V12a10 10 0 1 1-5.93-9.14"></path><polyline points="22 4 12 14.01 9 11.01"></polyline></svg></th>
-                    <td><a href="">{&user_event.description}</a></td>
-                    <td>{"12 minutes ago"}</td>
+                    <th scope="row">
+                        <i
+                            class=classes!("bi", event_type.severity.icon(), event_type.severity.color())
+                            title=format!("{}, {}", event_type.category.label(), event_type.severity.label())
+                        ></i>
+                    </th>
+                    <td>
+                        <p class="m-0">{ log_event_name(&user_event.type_detail) }</p>
+                        <p class="text-muted m-0" style="font-size: 12px;">{&user_event.description}</p>
+                    </td>
+                    <td>{&user_event.date}</td>
                     <td>{&user_event.client_name}</td>
                     <td>{&user_event.connection}</td>
                     <td>
@@ -54,18 +89,40 @@ impl UserTabHistory {
 
 impl Component for UserTabHistory {
     type Message = Msg;
-    type Properties = ();
+    type Properties = UserTabHistoryProps;
 
-    fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
         
-        // let user_log_events = UserLogDetails::new();
+        // GET LOCALSTORAGE
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+        let access_token = localstorage_data.token.unwrap_or_default();
+
+        // NEWEST EVENTS FIRST
+        let route_service = RouteService::new();
+        let pagination = Pagination::from_query(&route_service.get_query(), 50, Some(Sort::desc("date")), &["date"]);
         
         UserTabHistory {
+            user_id: props.user_id,
+            access_token,
             link,
             fetch_task: None,
             loading_get_user_logs: false,
             user_log_events: Vec::new(),
             error_user_log_list: None,
+            route_service,
+            pagination,
+            total_user_logs: 0,
         }
     }
 
@@ -84,15 +141,13 @@ impl Component for UserTabHistory {
                 true
             }
             Msg::RequestUserLogEvents => {
-                ConsoleService::info("ini di request user logevent"); 
-                let request = Request::get(format!("{}/users/tenantid/users/:id/logs", API_URL))
-                    .header("access_token", "telkomidtelkomdomain")
+                let request = Request::get(format!("{}/api/v2/users/{}/logs?{}", API_URL, self.user_id.clone(), self.pagination.api_params()))
+                    .header("access_token", self.access_token.clone())
                     .body(Nothing)
                     .expect("Could not build request");
                 let callback = self.link.callback(
-                    |response: Response<Json<Result<Vec<UserLogDetails>, anyhow::Error>>>| {
+                    |response: Response<Json<Result<Page<UserLogDetails>, anyhow::Error>>>| {
                         let Json(dataok) = response.into_body();
-                        ConsoleService::info(&format!("{:?}", dataok));    
                         match dataok {
                             Ok(dataok) => Msg::GetUserLogEvents(dataok), 
                             Err(error) => {
@@ -108,14 +163,19 @@ impl Component for UserTabHistory {
                 true
             }
             Msg::GetUserLogEvents(data) => {
-                ConsoleService::info("ini di get user log events");
-                self.user_log_events = data;
+                self.user_log_events = data.items;
+                self.total_user_logs = data.total;
                 self.loading_get_user_logs = false;
                 self.fetch_task = None;
                 true
             }
+            Msg::ChangePagination(pagination) => {
+                self.pagination = pagination;
+                replace_query(&mut self.route_service, &self.pagination.url_params());
+                self.link.send_message(Msg::RequestUserLogEvents);
+                false
+            }
             Msg::ResponseError(message, state) => {
-                ConsoleService::info("ini di info response error ");
                 match state {
                     StateError::UserLogList => {
                         self.loading_get_user_logs = false;
@@ -141,78 +201,24 @@ impl Component for UserTabHistory {
                     </p>
                 </div>
                 
-                <div class="mt-4 table-responsive">
-                    <table class="table">
-                        <thead>
-                            <tr>
-                                <th scope="col"></th>
-                                <th scope="col">{"Event"}</th>
-                                <th scope="col">{"When"}</th>
-                                <th scope="col">{"App"}</th>
-                                <th scope="col">{"Identity Provider"}</th>
-                                <th scope="col">{"From"}</th>
-                            </tr>
-                        </thead>
-                        <tbody>
-
-                            {
-                                if !self.loading_get_user_logs && !self.error_user_log_list.is_some() {
-                                    html! {
-                                        <>
-                                            {self.view_user_log_history()}
-                                        </>
-                                    } 
-                                } else {
-                                    html! {}
-                                }
-                            }
-
-
-
-                        </tbody>     
-                    </table>
-                        
-                    {
-                        if self.loading_get_user_logs {
-                            html! {
-                                <div style="position: relative; margin-top:4rem;">
-                                    <Loading width = 45 />
-                                </div>
-                            }
-                        } else if self.error_user_log_list.is_some() {
-                            html!{
-                                <div class="alert alert-warning mb-5" role="alert">
-                                    <i class="bi bi-exclamation-triangle me-2"></i>
-                                    { self.error_user_log_list.clone().unwrap() }
-                                </div>
-                            }
-                        } else {
-                            html! {}
-                        }
-                    }
-                    
-                </div>
-                
-
-                <div class="mt-4">
-                        <div class="row">
-                            <div class="col d-flex justify-content-start">
-                                <button type="button" class="btn btn-primary" disabled=true>
-                                    <i class="bi bi-arrow-left"></i>
-                                    <span>{"NEWER"}</span>
-                                </button>
-                            </div>
-                            <div class="col d-flex justify-content-center">
-                                <p>{"Page 1"}</p>
-                            </div>
-                            <div class="col d-flex justify-content-end">
-                                <button type="button" class="btn btn-primary" disabled=true>
-                                    <i class="bi bi-arrow-right"></i>
-                                    <span>{"OLDER"}</span>
-                                </button>
-                            </div>
-                        </div>
-                </div>
+                <PaginatedTable
+                    columns=vec![
+                        Column::new(""),
+                        Column::new("Event"),
+                        Column::sortable("When", "date"),
+                        Column::new("App"),
+                        Column::new("Identity Provider"),
+                        Column::new("From"),
+                    ]
+                    pagination=self.pagination.clone()
+                    total=self.total_user_logs
+                    loading=self.loading_get_user_logs
+                    error=self.error_user_log_list.clone()
+                    empty_message="There are no log events for this user."
+                    on_change=self.link.callback(Msg::ChangePagination)
+                >
+                    { for self.view_user_log_history() }
+                </PaginatedTable>
 
             </>
         }
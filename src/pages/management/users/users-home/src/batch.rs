@@ -0,0 +1,194 @@
+use std::collections::VecDeque;
+
+// REQUESTS ARE STARTED ON A FIXED TICK SO A BULK ACTION NEVER
+// EXCEEDS THE MANAGEMENT API RATE LIMIT
+pub const TICK_MILLIS: u64 = 200;
+pub const MAX_IN_FLIGHT: usize = 3;
+// TICKS TO WAIT AFTER THE API ANSWERS 429 TOO MANY REQUESTS
+pub const THROTTLE_COOLDOWN: u32 = 10;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ItemStatus {
+    Pending,
+    Running,
+    Succeeded,
+    Failed(String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchItem {
+    pub user_id: String,
+    pub email: String,
+    pub status: ItemStatus,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Batch {
+    items: Vec<BatchItem>,
+    queue: VecDeque<usize>,
+    in_flight: usize,
+    cooldown: u32,
+}
+
+impl Batch {
+    pub fn new(users: Vec<(String, String)>) -> Batch {
+        let items: Vec<BatchItem> = users
+            .into_iter()
+            .map(|(user_id, email)| BatchItem { user_id, email, status: ItemStatus::Pending })
+            .collect();
+        let queue = (0..items.len()).collect();
+        Batch {
+            items,
+            queue,
+            in_flight: 0,
+            cooldown: 0,
+        }
+    }
+
+    pub fn items(&self) -> &[BatchItem] {
+        &self.items
+    }
+
+    // CALLED ON EVERY TICK, RETURNS THE ITEM TO START IF THE LIMITS ALLOW IT
+    pub fn tick(&mut self) -> Option<usize> {
+        if self.cooldown > 0 {
+            self.cooldown -= 1;
+            return None;
+        }
+        if self.in_flight >= MAX_IN_FLIGHT {
+            return None;
+        }
+        let index = self.queue.pop_front()?;
+        self.items[index].status = ItemStatus::Running;
+        self.in_flight += 1;
+        Some(index)
+    }
+
+    pub fn succeed(&mut self, index: usize) {
+        self.finish(index, ItemStatus::Succeeded);
+    }
+
+    pub fn fail(&mut self, index: usize, message: String) {
+        self.finish(index, ItemStatus::Failed(message));
+    }
+
+    // PUT THE ITEM BACK AT THE FRONT AND BACK OFF BEFORE STARTING ANYTHING ELSE
+    pub fn throttle(&mut self, index: usize) {
+        self.finish(index, ItemStatus::Pending);
+        self.queue.push_front(index);
+        self.cooldown = THROTTLE_COOLDOWN;
+    }
+
+    // ITEMS THAT HAVE NOT STARTED YET ARE MARKED AS FAILED SO THEY CAN BE RETRIED
+    pub fn cancel(&mut self) {
+        for index in self.queue.drain(..) {
+            self.items[index].status = ItemStatus::Failed(String::from("Cancelled"));
+        }
+    }
+
+    pub fn retry_failed(&mut self) {
+        for (index, item) in self.items.iter_mut().enumerate() {
+            if let ItemStatus::Failed(_) = item.status {
+                item.status = ItemStatus::Pending;
+                self.queue.push_back(index);
+            }
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.queue.is_empty() || self.in_flight > 0
+    }
+
+    pub fn total(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn completed(&self) -> usize {
+        self.succeeded() + self.failed()
+    }
+
+    pub fn succeeded(&self) -> usize {
+        self.items.iter().filter(|item| item.status == ItemStatus::Succeeded).count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item| matches!(item.status, ItemStatus::Failed(_)))
+            .count()
+    }
+
+    pub fn progress(&self) -> u32 {
+        if self.items.is_empty() {
+            100
+        } else {
+            (self.completed() * 100 / self.items.len()) as u32
+        }
+    }
+
+    fn finish(&mut self, index: usize, status: ItemStatus) {
+        if let Some(item) = self.items.get_mut(index) {
+            if item.status == ItemStatus::Running {
+                self.in_flight = self.in_flight.saturating_sub(1);
+            }
+            item.status = status;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch(count: usize) -> Batch {
+        Batch::new((0..count).map(|i| (format!("auth0|{}", i), format!("user{}@acme.com", i))).collect())
+    }
+
+    #[test]
+    fn never_runs_more_than_the_in_flight_limit() {
+        let mut batch = batch(5);
+        let started: Vec<usize> = (0..5).filter_map(|_| batch.tick()).collect();
+        assert_eq!(started, vec![0, 1, 2]);
+        batch.succeed(1);
+        assert_eq!(batch.tick(), Some(3));
+        assert_eq!(batch.tick(), None);
+    }
+
+    #[test]
+    fn throttle_requeues_first_and_waits_the_cooldown() {
+        let mut batch = batch(3);
+        assert_eq!(batch.tick(), Some(0));
+        batch.throttle(0);
+        assert_eq!(batch.items()[0].status, ItemStatus::Pending);
+        for _ in 0..THROTTLE_COOLDOWN {
+            assert_eq!(batch.tick(), None);
+        }
+        assert_eq!(batch.tick(), Some(0));
+    }
+
+    #[test]
+    fn cancel_fails_the_queued_items_and_retry_requeues_them() {
+        let mut batch = batch(3);
+        assert_eq!(batch.tick(), Some(0));
+        batch.cancel();
+        batch.succeed(0);
+        assert!(!batch.is_running());
+        assert_eq!((batch.succeeded(), batch.failed(), batch.progress()), (1, 2, 100));
+
+        batch.retry_failed();
+        assert!(batch.is_running());
+        assert_eq!(batch.completed(), 1);
+        assert_eq!(batch.tick(), Some(1));
+        batch.fail(1, String::from("Not found"));
+        assert_eq!(batch.items()[1].status, ItemStatus::Failed(String::from("Not found")));
+        assert_eq!(batch.progress(), 66);
+    }
+
+    #[test]
+    fn an_empty_batch_is_done() {
+        let mut batch = batch(0);
+        assert_eq!(batch.tick(), None);
+        assert!(!batch.is_running());
+        assert_eq!(batch.progress(), 100);
+    }
+}
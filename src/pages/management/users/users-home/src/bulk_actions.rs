@@ -0,0 +1,660 @@
+use std::{collections::HashMap, time::Duration};
+use yew::{
+    format::{Json, Nothing, Text},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        interval::{IntervalService, IntervalTask},
+        storage::{StorageService, Area},
+    },
+};
+use serde::{Deserialize, Serialize};
+use configs::server::API_URL;
+use types::{
+    roles::Role,
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use loading::Loading;
+use paginated_table::query::encode_uri_component;
+use crate::batch::{
+    Batch,
+    ItemStatus,
+    TICK_MILLIS,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BulkAction {
+    Block,
+    Unblock,
+    Delete,
+    AssignRoles,
+    RemoveRoles,
+}
+
+impl BulkAction {
+    fn label(&self) -> &'static str {
+        match self {
+            BulkAction::Block => "Block",
+            BulkAction::Unblock => "Unblock",
+            BulkAction::Delete => "Delete",
+            BulkAction::AssignRoles => "Assign Roles",
+            BulkAction::RemoveRoles => "Remove Roles",
+        }
+    }
+
+    fn needs_roles(&self) -> bool {
+        matches!(self, BulkAction::AssignRoles | BulkAction::RemoveRoles)
+    }
+}
+
+pub enum Outcome {
+    Success,
+    Throttled,
+    Failed(String),
+}
+
+#[derive(Serialize, Debug, Clone)]
+struct DataBlocked {
+    blocked: bool,
+}
+
+#[derive(Serialize, Debug, Clone)]
+struct DataRoles {
+    roles: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct ErrorBody {
+    message: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct BulkActionsProps {
+    // SELECTED USERS AS (user_id, email)
+    pub users: Vec<(String, String)>,
+    // EMITS THE ID OF EVERY USER THE ACTION SUCCEEDED FOR
+    pub on_finish: Callback<Vec<String>>,
+    pub on_clear: Callback<()>,
+}
+
+pub struct BulkActions {
+    props: BulkActionsProps,
+    link: ComponentLink<Self>,
+    access_token: String,
+    action: Option<BulkAction>,
+    roles: Vec<Role>,
+    selected_roles: Vec<String>,
+    loading_get_roles: bool,
+    error_get_roles: Option<String>,
+    fetch_task: Option<FetchTask>,
+    batch: Option<Batch>,
+    interval_task: Option<IntervalTask>,
+    request_tasks: HashMap<usize, FetchTask>,
+}
+
+pub enum StateError {
+    GetRoles,
+}
+
+pub enum Msg {
+    Open(BulkAction),
+    Close,
+    RequestRoles,
+    GetRoles(Vec<Role>),
+    ToggleRole(String),
+    Start,
+    Tick,
+    Finished(usize, Outcome),
+    Cancel,
+    RetryFailed,
+    ResponseError(String, StateError),
+}
+
+impl Component for BulkActions {
+    type Message = Msg;
+    type Properties = BulkActionsProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        // GET LOCALSTORAGE
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+        let access_token = localstorage_data.token.unwrap_or_default();
+
+        BulkActions {
+            props,
+            link,
+            access_token,
+            action: None,
+            roles: Vec::new(),
+            selected_roles: Vec::new(),
+            loading_get_roles: false,
+            error_get_roles: None,
+            fetch_task: None,
+            batch: None,
+            interval_task: None,
+            request_tasks: HashMap::new(),
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Open(action) => {
+                self.action = Some(action);
+                self.batch = None;
+                self.selected_roles = Vec::new();
+                if action.needs_roles() && self.roles.is_empty() {
+                    self.link.send_message(Msg::RequestRoles);
+                }
+                true
+            }
+            Msg::Close => {
+                if self.is_running() {
+                    return false;
+                }
+                let succeeded = self.succeeded_users();
+                self.action = None;
+                self.batch = None;
+                if !succeeded.is_empty() {
+                    self.props.on_finish.emit(succeeded);
+                }
+                true
+            }
+            Msg::RequestRoles => {
+                let request = Request::get(format!("{}/api/v2/roles", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<Role>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetRoles(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string(), StateError::GetRoles),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_get_roles = None;
+                self.loading_get_roles = true;
+                true
+            }
+            Msg::GetRoles(roles) => {
+                self.roles = roles;
+                self.loading_get_roles = false;
+                self.fetch_task = None;
+                true
+            }
+            Msg::ToggleRole(role_id) => {
+                if self.selected_roles.contains(&role_id) {
+                    self.selected_roles.retain(|id| *id != role_id);
+                } else {
+                    self.selected_roles.push(role_id);
+                }
+                true
+            }
+            Msg::Start => {
+                self.batch = Some(Batch::new(self.props.users.clone()));
+                self.start_interval();
+                true
+            }
+            Msg::Tick => {
+                let next = match self.batch.as_mut() {
+                    Some(batch) => {
+                        if !batch.is_running() {
+                            self.interval_task = None;
+                            return true;
+                        }
+                        batch.tick()
+                    }
+                    None => None,
+                };
+                match next {
+                    Some(index) => {
+                        self.send_request(index);
+                        true
+                    }
+                    None => false,
+                }
+            }
+            Msg::Finished(index, outcome) => {
+                self.request_tasks.remove(&index);
+                if let Some(batch) = self.batch.as_mut() {
+                    match outcome {
+                        Outcome::Success => batch.succeed(index),
+                        Outcome::Throttled => batch.throttle(index),
+                        Outcome::Failed(message) => batch.fail(index, message),
+                    }
+                    if !batch.is_running() {
+                        self.interval_task = None;
+                    }
+                }
+                true
+            }
+            Msg::Cancel => {
+                if let Some(batch) = self.batch.as_mut() {
+                    batch.cancel();
+                }
+                true
+            }
+            Msg::RetryFailed => {
+                if let Some(batch) = self.batch.as_mut() {
+                    batch.retry_failed();
+                }
+                self.start_interval();
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::GetRoles => {
+                        self.loading_get_roles = false;
+                        self.error_get_roles = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let count = self.props.users.len();
+        if count == 0 && self.action.is_none() {
+            return html! {};
+        }
+        html! {
+            <>
+                <div class="d-flex align-items-center flex-wrap p-2 mt-3 rounded" style="background-color: #eff0f2;">
+                    <span class="fw-bold me-3 ms-1">{ format!("{} selected", count) }</span>
+                    { self.view_action_button(BulkAction::Block, "bi bi-slash-circle") }
+                    { self.view_action_button(BulkAction::Unblock, "bi bi-check-circle") }
+                    { self.view_action_button(BulkAction::AssignRoles, "bi bi-person-check") }
+                    { self.view_action_button(BulkAction::RemoveRoles, "bi bi-person-dash") }
+                    <button
+                        type="button"
+                        class="btn btn-sm btn-outline-danger me-2"
+                        disabled={ count == 0 }
+                        onclick=self.link.callback(|_| Msg::Open(BulkAction::Delete))
+                    >
+                        <i class="bi bi-trash me-1"></i>
+                        { BulkAction::Delete.label() }
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-sm btn-link text-decoration-none ms-auto"
+                        onclick=self.props.on_clear.reform(|_| ())
+                    >
+                        {"Clear selection"}
+                    </button>
+                </div>
+                { self.view_modal() }
+                <div
+                    class=format!("modal-backdrop fade {}", if self.action.is_some() {"show"} else {""})
+                />
+            </>
+        }
+    }
+}
+
+impl BulkActions {
+    fn is_running(&self) -> bool {
+        self.batch.as_ref().map(|batch| batch.is_running()).unwrap_or(false)
+    }
+
+    fn succeeded_users(&self) -> Vec<String> {
+        match &self.batch {
+            Some(batch) => batch
+                .items()
+                .iter()
+                .filter(|item| item.status == ItemStatus::Succeeded)
+                .map(|item| item.user_id.clone())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn start_interval(&mut self) {
+        if self.interval_task.is_none() {
+            let task = IntervalService::spawn(
+                Duration::from_millis(TICK_MILLIS),
+                self.link.callback(|_| Msg::Tick),
+            );
+            self.interval_task = Some(task);
+        }
+    }
+
+    fn send_request(&mut self, index: usize) {
+        let (action, user_id) = match (self.action, &self.batch) {
+            (Some(action), Some(batch)) => (action, batch.items()[index].user_id.clone()),
+            _ => return,
+        };
+        let callback = self.link.callback(move |response: Response<Text>| {
+            let (meta, data) = response.into_parts();
+            if meta.status.is_success() {
+                return Msg::Finished(index, Outcome::Success);
+            }
+            if meta.status.as_u16() == 429 {
+                return Msg::Finished(index, Outcome::Throttled);
+            }
+            let message = data
+                .ok()
+                .and_then(|body| serde_json::from_str::<ErrorBody>(&body).ok())
+                .map(|body| body.message)
+                .unwrap_or_else(|| meta.status.to_string());
+            Msg::Finished(index, Outcome::Failed(message))
+        });
+        // IDS LIKE auth0|60f1... HAVE TO BE ENCODED
+        let user_url = format!("{}/api/v2/users/{}", API_URL, encode_uri_component(&user_id));
+        let blocked = DataBlocked { blocked: action == BulkAction::Block };
+        let roles = DataRoles { roles: self.selected_roles.clone() };
+        let task = match action {
+            BulkAction::Block | BulkAction::Unblock => {
+                let request = Request::patch(user_url)
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&blocked))
+                    .expect("Could not build request.");
+                FetchService::fetch(request, callback)
+            }
+            BulkAction::Delete => {
+                let request = Request::delete(user_url)
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                FetchService::fetch(request, callback)
+            }
+            BulkAction::AssignRoles => {
+                let request = Request::post(format!("{}/roles", user_url))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&roles))
+                    .expect("Could not build request.");
+                FetchService::fetch(request, callback)
+            }
+            BulkAction::RemoveRoles => {
+                let request = Request::delete(format!("{}/roles", user_url))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&roles))
+                    .expect("Could not build request.");
+                FetchService::fetch(request, callback)
+            }
+        };
+        match task {
+            Ok(task) => {
+                self.request_tasks.insert(index, task);
+            }
+            Err(error) => {
+                self.link.send_message(Msg::Finished(index, Outcome::Failed(error.to_string())));
+            }
+        }
+    }
+
+    fn view_action_button(&self, action: BulkAction, icon: &str) -> Html {
+        html! {
+            <button
+                type="button"
+                class="btn btn-sm btn-outline-secondary me-2"
+                disabled={ self.props.users.is_empty() }
+                onclick=self.link.callback(move |_| Msg::Open(action))
+            >
+                <i class=classes!(icon.to_string(), "me-1")></i>
+                { action.label() }
+            </button>
+        }
+    }
+
+    fn view_modal(&self) -> Html {
+        let action = match self.action {
+            Some(action) => action,
+            None => return html! { <div class="modal fade"></div> },
+        };
+        let count = self.props.users.len();
+        let title = format!("{} {} user{}", action.label(), count, if count == 1 { "" } else { "s" });
+        html! {
+            <div class="modal fade show">
+                <div class="modal-dialog modal-dialog-centered modal-dialog-scrollable modal-lg">
+                    <div class="modal-content">
+                        <div class="modal-header">
+                            <h5 class="modal-title">{ title }</h5>
+                            <button
+                                type="button"
+                                class="btn-close"
+                                aria-label="Close"
+                                disabled={ self.is_running() }
+                                onclick=self.link.callback(|_| Msg::Close)
+                            ></button>
+                        </div>
+                        <div class="modal-body">
+                            {
+                                match &self.batch {
+                                    Some(batch) => self.view_progress(batch),
+                                    None => self.view_confirm(action),
+                                }
+                            }
+                        </div>
+                        <div class="modal-footer">
+                            { self.view_footer(action) }
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    fn view_confirm(&self, action: BulkAction) -> Html {
+        let description = match action {
+            BulkAction::Block => "The selected users will be blocked for logging into your applications.",
+            BulkAction::Unblock => "The selected users will be unblocked for logging into your applications.",
+            BulkAction::Delete => "The selected users will be deleted. This cannot be undone!",
+            BulkAction::AssignRoles => "Select the roles to assign to every selected user.",
+            BulkAction::RemoveRoles => "Select the roles to remove from every selected user.",
+        };
+        html! {
+            <>
+                <p>{ description }</p>
+                {
+                    if action.needs_roles() {
+                        self.view_roles()
+                    } else {
+                        html! {
+                            <ul class="list-group" style="max-height: 240px; overflow-y: auto;">
+                                {
+                                    for self.props.users.iter().map(|(_, email)| html! {
+                                        <li class="list-group-item text-muted" style="font-size: 14px;">{ email }</li>
+                                    })
+                                }
+                            </ul>
+                        }
+                    }
+                }
+            </>
+        }
+    }
+
+    fn view_roles(&self) -> Html {
+        if self.loading_get_roles {
+            return html! {
+                <div style="position: relative; margin-top: 2rem; margin-bottom: 2rem;">
+                    <Loading width=45 />
+                </div>
+            };
+        }
+        if let Some(error) = &self.error_get_roles {
+            return html! {
+                <div class="alert alert-warning" role="alert">
+                    <i class="bi bi-exclamation-triangle me-2"></i>
+                    { error.clone() }
+                </div>
+            };
+        }
+        if self.roles.is_empty() {
+            return html! { <p class="text-muted">{"There is no role available"}</p> };
+        }
+        html! {
+            <ul class="list-group">
+                {
+                    for self.roles.iter().map(|role| {
+                        let role_id = role.id.clone();
+                        html! {
+                            <li class="list-group-item">
+                                <input
+                                    class="form-check-input me-2"
+                                    type="checkbox"
+                                    checked=self.selected_roles.contains(&role.id)
+                                    onclick=self.link.callback(move |_| Msg::ToggleRole(role_id.clone()))
+                                />
+                                <span class="fw-bold me-2">{ role.name.clone() }</span>
+                                <span class="text-muted">{ role.description.clone() }</span>
+                            </li>
+                        }
+                    })
+                }
+            </ul>
+        }
+    }
+
+    fn view_progress(&self, batch: &Batch) -> Html {
+        let progress = batch.progress();
+        html! {
+            <>
+                <div class="d-flex justify-content-between mb-1" style="font-size: 14px;">
+                    <span>{ format!("{} of {} processed", batch.completed(), batch.total()) }</span>
+                    <span>
+                        <span class="text-success me-3">{ format!("{} succeeded", batch.succeeded()) }</span>
+                        <span class="text-danger">{ format!("{} failed", batch.failed()) }</span>
+                    </span>
+                </div>
+                <div class="progress mb-3">
+                    <div
+                        class=classes!("progress-bar", if batch.is_running() { "progress-bar-striped progress-bar-animated" } else { "" })
+                        role="progressbar"
+                        style=format!("width: {}%;", progress)
+                        aria-valuenow=progress.to_string()
+                        aria-valuemin="0"
+                        aria-valuemax="100"
+                    ></div>
+                </div>
+                <table class="table table-sm">
+                    <thead>
+                        <tr>
+                            <th scope="col">{"User"}</th>
+                            <th scope="col">{"Result"}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {
+                            for batch.items().iter().map(|item| html! {
+                                <tr class="align-middle">
+                                    <td style="font-size: 14px;">{ item.email.clone() }</td>
+                                    <td style="font-size: 14px;">{ view_status(&item.status) }</td>
+                                </tr>
+                            })
+                        }
+                    </tbody>
+                </table>
+            </>
+        }
+    }
+
+    fn view_footer(&self, action: BulkAction) -> Html {
+        match &self.batch {
+            None => {
+                let disabled = self.props.users.is_empty()
+                    || (action.needs_roles() && self.selected_roles.is_empty());
+                let class = if action == BulkAction::Delete { "btn btn-danger" } else { "btn btn-primary" };
+                html! {
+                    <>
+                        <button
+                            type="button"
+                            class="btn btn-outline-secondary"
+                            onclick=self.link.callback(|_| Msg::Close)
+                        >
+                            {"Cancel"}
+                        </button>
+                        <button
+                            type="button"
+                            class=class
+                            disabled=disabled
+                            onclick=self.link.callback(|_| Msg::Start)
+                        >
+                            { format!("{} {}", action.label(), self.props.users.len()) }
+                        </button>
+                    </>
+                }
+            }
+            Some(batch) if batch.is_running() => html! {
+                <button
+                    type="button"
+                    class="btn btn-outline-secondary"
+                    onclick=self.link.callback(|_| Msg::Cancel)
+                >
+                    {"Stop"}
+                </button>
+            },
+            Some(batch) => html! {
+                <>
+                    {
+                        if batch.failed() > 0 {
+                            html! {
+                                <button
+                                    type="button"
+                                    class="btn btn-outline-secondary"
+                                    onclick=self.link.callback(|_| Msg::RetryFailed)
+                                >
+                                    <i class="bi bi-arrow-repeat me-1"></i>
+                                    { format!("Retry {} failed", batch.failed()) }
+                                </button>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    <button
+                        type="button"
+                        class="btn btn-primary"
+                        onclick=self.link.callback(|_| Msg::Close)
+                    >
+                        {"Done"}
+                    </button>
+                </>
+            },
+        }
+    }
+}
+
+fn view_status(status: &ItemStatus) -> Html {
+    match status {
+        ItemStatus::Pending => html! { <span class="badge bg-light text-dark">{"Pending"}</span> },
+        ItemStatus::Running => html! {
+            <span class="spinner-border spinner-border-sm text-secondary" role="status"></span>
+        },
+        ItemStatus::Succeeded => html! { <span class="badge bg-success">{"Done"}</span> },
+        ItemStatus::Failed(message) => html! {
+            <>
+                <span class="badge bg-danger me-2">{"Failed"}</span>
+                <span class="text-muted">{ message.clone() }</span>
+            </>
+        },
+    }
+}
@@ -0,0 +1,815 @@
+use std::time::Duration;
+use serde_json::{Map, Value};
+use wasm_bindgen::JsCast;
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{StorageService, Area},
+        timeout::{TimeoutService, TimeoutTask},
+    },
+};
+use configs::server::API_URL;
+use types::{
+    pagination::Page,
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use paginated_table::query::encode_uri_component;
+
+const PAGE_SIZE: u32 = 100;
+// THE FILE IS BUILT IN THE BROWSER, A BIGGER EXPORT HAS TO BE SPLIT WITH A SEARCH
+pub const MAX_EXPORT_USERS: u32 = 50_000;
+// THE USERS API DOESN'T PAGE PAST THE FIRST 1000 RESULTS, SO EVERY REQUEST
+// READS THE FIRST PAGE OF THE USERS CREATED SINCE THE LAST ONE EXPORTED
+// WAIT BEFORE ASKING AGAIN AFTER 429 TOO MANY REQUESTS
+const THROTTLE_MILLIS: u64 = 2000;
+
+pub struct ExportField {
+    pub path: &'static str,
+    pub label: &'static str,
+    pub group: &'static str,
+}
+
+// PATHS INTO THE USER JSON, A SEGMENT THAT HITS AN ARRAY IS APPLIED TO EVERY ITEM
+pub const EXPORT_FIELDS: &[ExportField] = &[
+    ExportField { path: "user_id", label: "User ID", group: "Profile" },
+    ExportField { path: "email", label: "Email", group: "Profile" },
+    ExportField { path: "email_verified", label: "Email Verified", group: "Profile" },
+    ExportField { path: "username", label: "Username", group: "Profile" },
+    ExportField { path: "name", label: "Name", group: "Profile" },
+    ExportField { path: "given_name", label: "Given Name", group: "Profile" },
+    ExportField { path: "family_name", label: "Family Name", group: "Profile" },
+    ExportField { path: "nickname", label: "Nickname", group: "Profile" },
+    ExportField { path: "phone_number", label: "Phone Number", group: "Profile" },
+    ExportField { path: "phone_verified", label: "Phone Verified", group: "Profile" },
+    ExportField { path: "picture", label: "Picture", group: "Profile" },
+    ExportField { path: "created_at", label: "Created At", group: "Activity" },
+    ExportField { path: "updated_at", label: "Updated At", group: "Activity" },
+    ExportField { path: "last_login", label: "Last Login", group: "Activity" },
+    ExportField { path: "last_ip", label: "Last IP", group: "Activity" },
+    ExportField { path: "logins_count", label: "Logins Count", group: "Activity" },
+    ExportField { path: "blocked", label: "Blocked", group: "Activity" },
+    ExportField { path: "multifactor", label: "Multifactor", group: "Activity" },
+    ExportField { path: "identities.connection", label: "Connection", group: "Identities" },
+    ExportField { path: "identities.provider", label: "Provider", group: "Identities" },
+    ExportField { path: "identities.user_id", label: "Identity User ID", group: "Identities" },
+    ExportField { path: "identities.is_social", label: "Is Social", group: "Identities" },
+    ExportField { path: "app_metadata", label: "App Metadata", group: "Metadata" },
+    ExportField { path: "user_metadata", label: "User Metadata", group: "Metadata" },
+];
+
+const GROUPS: [&str; 4] = ["Profile", "Activity", "Identities", "Metadata"];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    Csv,
+    Ndjson,
+}
+
+impl Format {
+    fn extension(&self) -> &'static str {
+        match self {
+            Format::Csv => "csv",
+            Format::Ndjson => "ndjson",
+        }
+    }
+
+    fn mime_type(&self) -> &'static str {
+        match self {
+            Format::Csv => "text/csv",
+            Format::Ndjson => "application/x-ndjson",
+        }
+    }
+}
+
+pub fn extract(value: &Value, path: &str) -> Value {
+    let mut segments = path.splitn(2, '.');
+    let key = segments.next().unwrap_or_default();
+    let rest = segments.next();
+    match value {
+        Value::Array(items) => Value::Array(items.iter().map(|item| extract(item, path)).collect()),
+        Value::Object(object) => match (object.get(key), rest) {
+            (Some(child), Some(rest)) => extract(child, rest),
+            (Some(child), None) => child.clone(),
+            (None, _) => Value::Null,
+        },
+        _ => Value::Null,
+    }
+}
+
+fn string_at(user: &Value, key: &str) -> String {
+    user.get(key).and_then(Value::as_str).unwrap_or_default().to_string()
+}
+
+fn csv_cell(value: &Value) -> String {
+    let text = match value {
+        Value::Null => String::from(""),
+        Value::String(text) => text.clone(),
+        Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::Array(items) if items.iter().all(|item| !item.is_array() && !item.is_object()) => items
+            .iter()
+            .map(csv_cell)
+            .collect::<Vec<String>>()
+            .join(";"),
+        _ => value.to_string(),
+    };
+    // KEEP SPREADSHEETS FROM EVALUATING CELLS AS FORMULAS
+    let text = if is_formula(&text) {
+        format!("'{}", text)
+    } else {
+        text
+    };
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text
+    }
+}
+
+// PHONE NUMBERS LIKE +62 812-3456 AND NEGATIVE NUMBERS ARE LEFT AS THEY ARE
+fn is_formula(text: &str) -> bool {
+    match text.chars().next() {
+        Some('=') | Some('@') | Some('\t') | Some('\r') => true,
+        Some('+') | Some('-') => {
+            let rest = &text[1..];
+            let is_number = rest.chars().any(|c| c.is_ascii_digit())
+                && rest.chars().all(|c| c.is_ascii_digit() || " .-()".contains(c));
+            !is_number
+        }
+        _ => false,
+    }
+}
+
+// USERS CREATED AT OR AFTER THE CHECKPOINT, WITHIN THE SEARCH
+pub fn checkpoint_query(query: &str, checkpoint: &str) -> String {
+    let since = format!("created_at:[\"{}\" TO *]", checkpoint);
+    match (query.is_empty(), checkpoint.is_empty()) {
+        (true, true) => String::from(""),
+        (false, true) => query.to_string(),
+        (true, false) => since,
+        (false, false) => format!("({}) AND {}", query, since),
+    }
+}
+
+pub fn csv_header(fields: &[String]) -> String {
+    let cells: Vec<String> = fields.iter().map(|field| csv_cell(&Value::String(field.clone()))).collect();
+    format!("{}\n", cells.join(","))
+}
+
+pub fn to_csv(users: &[Value], fields: &[String]) -> String {
+    users
+        .iter()
+        .map(|user| {
+            let cells: Vec<String> = fields.iter().map(|field| csv_cell(&extract(user, field))).collect();
+            format!("{}\n", cells.join(","))
+        })
+        .collect()
+}
+
+pub fn to_ndjson(users: &[Value], fields: &[String]) -> String {
+    users
+        .iter()
+        .map(|user| {
+            let mut object = Map::new();
+            for field in fields {
+                object.insert(field.clone(), extract(user, field));
+            }
+            format!("{}\n", Value::Object(object))
+        })
+        .collect()
+}
+
+// EVERY PAGE BECOMES A Blob AS SOON AS IT IS READ, SO THE TEXT IS NOT KEPT IN
+// RUST. THE WHOLE FILE STILL LIVES IN THE BROWSER UNTIL IT IS DOWNLOADED
+fn to_blob(chunk: &str, mime_type: &str) -> Result<web_sys::Blob, String> {
+    let parts = js_sys::Array::of1(&chunk.into());
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)
+        .map_err(|_| String::from("Could not create the file"))
+}
+
+fn download(parts: &js_sys::Array, file_name: &str, mime_type: &str) -> Result<(), String> {
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = web_sys::Blob::new_with_blob_sequence_and_options(parts, &options)
+        .map_err(|_| String::from("Could not create the file"))?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)
+        .map_err(|_| String::from("Could not create the file"))?;
+    let anchor = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.create_element("a").ok())
+        .and_then(|element| element.dyn_into::<web_sys::HtmlAnchorElement>().ok())
+        .ok_or_else(|| String::from("Could not start the download"))?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+    let _ = web_sys::Url::revoke_object_url(&url);
+    Ok(())
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct ExportUsersProps {
+    // LUCENE QUERY OF THE CURRENT SEARCH, EMPTY FOR ALL USERS
+    pub query: String,
+}
+
+pub struct ExportUsers {
+    props: ExportUsersProps,
+    link: ComponentLink<Self>,
+    access_token: String,
+    show_modal: bool,
+    format: Format,
+    fields: Vec<String>,
+    metadata_field: String,
+    // QUERY THE RUNNING EXPORT WAS STARTED WITH
+    query: String,
+    // ONE Blob PER PAGE
+    parts: js_sys::Array,
+    // created_at OF THE LAST USER EXPORTED, AND THE USERS EXPORTED WITH THAT
+    // SAME created_at SINCE THE NEXT PAGE STARTS AT IT AGAIN
+    checkpoint: String,
+    checkpoint_ids: Vec<String>,
+    exported: u32,
+    total: u32,
+    running: bool,
+    finished: bool,
+    file_name: String,
+    fetch_task: Option<FetchTask>,
+    timeout_task: Option<TimeoutTask>,
+    error_export: Option<String>,
+}
+
+pub enum Msg {
+    ShowModal(bool),
+    SelectFormat(Format),
+    ToggleField(String),
+    InputMetadataField(String),
+    AddMetadataField,
+    Start,
+    RequestPage,
+    GetPage(Page<Value>),
+    Throttled,
+    Cancel,
+    Download,
+    ResponseError(String),
+    Ignore,
+}
+
+impl Component for ExportUsers {
+    type Message = Msg;
+    type Properties = ExportUsersProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        // GET LOCALSTORAGE
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+        let access_token = localstorage_data.token.unwrap_or_default();
+
+        let fields = ["user_id", "email", "name", "identities.connection", "created_at", "last_login", "logins_count"]
+            .iter()
+            .map(|field| field.to_string())
+            .collect();
+
+        ExportUsers {
+            props,
+            link,
+            access_token,
+            show_modal: false,
+            format: Format::Csv,
+            fields,
+            metadata_field: String::from(""),
+            query: String::from(""),
+            parts: js_sys::Array::new(),
+            checkpoint: String::from(""),
+            checkpoint_ids: Vec::new(),
+            exported: 0,
+            total: 0,
+            running: false,
+            finished: false,
+            file_name: String::from(""),
+            fetch_task: None,
+            timeout_task: None,
+            error_export: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ShowModal(state) => {
+                if !state && self.running {
+                    return false;
+                }
+                self.show_modal = state;
+                if state {
+                    self.finished = false;
+                    self.parts = js_sys::Array::new();
+                    self.error_export = None;
+                }
+                true
+            }
+            Msg::SelectFormat(format) => {
+                self.format = format;
+                true
+            }
+            Msg::ToggleField(field) => {
+                if self.fields.contains(&field) {
+                    self.fields.retain(|selected| *selected != field);
+                } else {
+                    self.fields.push(field);
+                }
+                true
+            }
+            Msg::InputMetadataField(value) => {
+                self.metadata_field = value;
+                true
+            }
+            Msg::AddMetadataField => {
+                let field = self.metadata_field.trim().to_string();
+                if (field.starts_with("app_metadata.") || field.starts_with("user_metadata."))
+                    && !self.fields.contains(&field)
+                {
+                    self.fields.push(field);
+                    self.metadata_field = String::from("");
+                    self.error_export = None;
+                } else {
+                    self.error_export = Some(String::from("Metadata fields start with app_metadata. or user_metadata."));
+                }
+                true
+            }
+            Msg::Start => {
+                self.query = self.props.query.clone();
+                self.file_name = format!(
+                    "users-{}.{}",
+                    String::from(js_sys::Date::new_0().to_iso_string()).replace(':', "-"),
+                    self.format.extension()
+                );
+                self.parts = js_sys::Array::new();
+                if self.format == Format::Csv {
+                    match to_blob(&csv_header(&self.fields), self.format.mime_type()) {
+                        Ok(blob) => {
+                            self.parts.push(&blob);
+                        }
+                        Err(error) => {
+                            self.error_export = Some(error);
+                            return true;
+                        }
+                    }
+                }
+                self.checkpoint = String::from("");
+                self.checkpoint_ids = Vec::new();
+                self.exported = 0;
+                self.total = 0;
+                self.finished = false;
+                self.link.send_message(Msg::RequestPage);
+                true
+            }
+            Msg::RequestPage => {
+                // SORTED BY CREATION SO NEW SIGN UPS ARE READ LAST
+                let mut url = format!(
+                    "{}/api/v2/users?page=0&per_page={}&include_totals=true&sort=created_at:1",
+                    API_URL, PAGE_SIZE
+                );
+                let query = checkpoint_query(&self.query, &self.checkpoint);
+                if !query.is_empty() {
+                    url.push_str(&format!("&q={}&search_engine=v3", encode_uri_component(&query)));
+                }
+                let request = Request::get(url)
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Page<Value>, anyhow::Error>>>| {
+                        let (meta, Json(data)) = response.into_parts();
+                        if meta.status.as_u16() == 429 {
+                            return Msg::Throttled;
+                        }
+                        match data {
+                            Ok(dataok) => Msg::GetPage(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.timeout_task = None;
+                self.error_export = None;
+                self.running = true;
+                true
+            }
+            Msg::GetPage(data) => {
+                self.fetch_task = None;
+                // LATER PAGES ONLY COUNT THE USERS LEFT
+                if self.checkpoint.is_empty() {
+                    self.total = data.total;
+                }
+                let is_last = (data.items.len() as u32) < PAGE_SIZE;
+                let limit = self.total.min(MAX_EXPORT_USERS);
+                let items: Vec<Value> = data.items
+                    .into_iter()
+                    .filter(|user| !self.checkpoint_ids.contains(&string_at(user, "user_id")))
+                    .take(limit.saturating_sub(self.exported) as usize)
+                    .collect();
+                if items.is_empty() && !is_last && self.exported < limit {
+                    self.link.send_message(Msg::ResponseError(format!(
+                        "More than {} users were created at {}, the export can't go past them.",
+                        PAGE_SIZE, self.checkpoint
+                    )));
+                    return false;
+                }
+                let chunk = match self.format {
+                    Format::Csv => to_csv(&items, &self.fields),
+                    Format::Ndjson => to_ndjson(&items, &self.fields),
+                };
+                match to_blob(&chunk, self.format.mime_type()) {
+                    Ok(blob) => {
+                        self.parts.push(&blob);
+                    }
+                    Err(error) => {
+                        self.link.send_message(Msg::ResponseError(error));
+                        return false;
+                    }
+                }
+                self.exported += items.len() as u32;
+                for user in items.iter() {
+                    let created_at = string_at(user, "created_at");
+                    if created_at != self.checkpoint {
+                        self.checkpoint = created_at;
+                        self.checkpoint_ids = Vec::new();
+                    }
+                    self.checkpoint_ids.push(string_at(user, "user_id"));
+                }
+                if is_last || self.exported >= limit {
+                    self.running = false;
+                    self.finished = true;
+                    self.link.send_message(Msg::Download);
+                } else {
+                    self.link.send_message(Msg::RequestPage);
+                }
+                true
+            }
+            Msg::Throttled => {
+                self.fetch_task = None;
+                let task = TimeoutService::spawn(
+                    Duration::from_millis(THROTTLE_MILLIS),
+                    self.link.callback(|_| Msg::RequestPage),
+                );
+                self.timeout_task = Some(task);
+                false
+            }
+            Msg::Cancel => {
+                self.fetch_task = None;
+                self.timeout_task = None;
+                self.running = false;
+                true
+            }
+            Msg::Download => {
+                if let Err(error) = download(&self.parts, &self.file_name, self.format.mime_type()) {
+                    self.error_export = Some(error);
+                }
+                true
+            }
+            Msg::ResponseError(message) => {
+                // THE PAGES READ SO FAR ARE KEPT, RESUMING STARTS FROM THE CHECKPOINT
+                self.fetch_task = None;
+                self.running = false;
+                self.error_export = Some(message);
+                true
+            }
+            Msg::Ignore => false,
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <>
+                <button
+                    type="button"
+                    class="btn btn-outline-secondary d-flex align-items-center me-2"
+                    onclick=self.link.callback(|_| Msg::ShowModal(true))
+                >
+                    <i class="bi bi-download me-2"></i>
+                    <span>{"Export"}</span>
+                </button>
+                <div class=format!("modal fade {}", if self.show_modal {"show"} else {""})>
+                    <div class="modal-dialog modal-dialog-centered modal-dialog-scrollable modal-lg">
+                        <div class="modal-content">
+                            <div class="modal-header">
+                                <h5 class="modal-title">{"Export Users"}</h5>
+                                <button
+                                    type="button"
+                                    class="btn-close"
+                                    aria-label="Close"
+                                    disabled=self.running
+                                    onclick=self.link.callback(|_| Msg::ShowModal(false))
+                                ></button>
+                            </div>
+                            <div class="modal-body">
+                                {
+                                    if self.running || self.finished || self.parts.length() > 0 {
+                                        self.view_progress()
+                                    } else {
+                                        self.view_options()
+                                    }
+                                }
+                                {
+                                    match &self.error_export {
+                                        Some(error) => html! {
+                                            <div class="alert alert-warning mt-3 mb-0" role="alert">
+                                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                                { error.clone() }
+                                            </div>
+                                        },
+                                        None => html! {},
+                                    }
+                                }
+                            </div>
+                            <div class="modal-footer">
+                                { self.view_footer() }
+                            </div>
+                        </div>
+                    </div>
+                </div>
+                <div class=format!("modal-backdrop fade {}", if self.show_modal {"show"} else {""}) />
+            </>
+        }
+    }
+}
+
+impl ExportUsers {
+    fn view_options(&self) -> Html {
+        let custom_fields: Vec<String> = self.fields
+            .iter()
+            .filter(|field| !EXPORT_FIELDS.iter().any(|export_field| export_field.path == field.as_str()))
+            .cloned()
+            .collect();
+        html! {
+            <>
+                <p class="text-muted" style="font-size: 14px;">
+                    {
+                        if self.props.query.is_empty() {
+                            String::from("All users will be exported.")
+                        } else {
+                            format!("Users matching the current search will be exported: {}", self.props.query)
+                        }
+                    }
+                    { format!(" The file is built in the browser, up to {} users per file.", MAX_EXPORT_USERS) }
+                </p>
+                <div class="mb-3">
+                    <label class="form-label fw-bold">{"Format"}</label>
+                    <div>
+                        { self.view_format(Format::Csv, "CSV") }
+                        { self.view_format(Format::Ndjson, "NDJSON (one JSON object per line)") }
+                    </div>
+                </div>
+                <label class="form-label fw-bold">{"Fields"}</label>
+                <div class="row">
+                    {
+                        for GROUPS.iter().map(|group| html! {
+                            <div class="col-md-3 mb-2">
+                                <div class="text-muted mb-1" style="font-size: 13px;">{ group }</div>
+                                {
+                                    for EXPORT_FIELDS
+                                        .iter()
+                                        .filter(|field| field.group == *group)
+                                        .map(|field| self.view_field(field.path, field.label))
+                                }
+                            </div>
+                        })
+                    }
+                </div>
+                {
+                    for custom_fields.iter().map(|field| self.view_field(field, field))
+                }
+                <div class="input-group input-group-sm mt-2">
+                    <input
+                        type="text"
+                        class="form-control"
+                        placeholder="user_metadata.plan"
+                        value=self.metadata_field.clone()
+                        oninput=self.link.callback(|data: InputData| Msg::InputMetadataField(data.value))
+                        onkeypress=self.link.callback(|e: KeyboardEvent| {
+                            if e.key() == "Enter" { Msg::AddMetadataField } else { Msg::Ignore }
+                        })
+                    />
+                    <button
+                        type="button"
+                        class="btn btn-outline-secondary"
+                        onclick=self.link.callback(|_| Msg::AddMetadataField)
+                    >
+                        {"Add Metadata Field"}
+                    </button>
+                </div>
+            </>
+        }
+    }
+
+    fn view_format(&self, format: Format, label: &str) -> Html {
+        html! {
+            <div class="form-check form-check-inline">
+                <input
+                    class="form-check-input"
+                    type="radio"
+                    checked={ self.format == format }
+                    onclick=self.link.callback(move |_| Msg::SelectFormat(format))
+                />
+                <label class="form-check-label">{ label }</label>
+            </div>
+        }
+    }
+
+    fn view_field(&self, path: &str, label: &str) -> Html {
+        let field = path.to_string();
+        html! {
+            <div class="form-check" style="font-size: 14px;">
+                <input
+                    class="form-check-input"
+                    type="checkbox"
+                    checked=self.fields.iter().any(|selected| selected == path)
+                    onclick=self.link.callback(move |_| Msg::ToggleField(field.clone()))
+                />
+                <label class="form-check-label">{ label }</label>
+            </div>
+        }
+    }
+
+    fn view_progress(&self) -> Html {
+        let limit = self.total.min(MAX_EXPORT_USERS);
+        let progress = if limit == 0 {
+            if self.finished { 100 } else { 0 }
+        } else {
+            (self.exported as u64 * 100 / limit as u64) as u32
+        };
+        html! {
+            <>
+                <div class="d-flex justify-content-between mb-1" style="font-size: 14px;">
+                    <span>{ format!("{} of {} users exported", self.exported, limit) }</span>
+                    <span class="text-muted">{ &self.file_name }</span>
+                </div>
+                <div class="progress">
+                    <div
+                        class=classes!("progress-bar", if self.running { "progress-bar-striped progress-bar-animated" } else { "" })
+                        role="progressbar"
+                        style=format!("width: {}%;", progress)
+                        aria-valuenow=progress.to_string()
+                        aria-valuemin="0"
+                        aria-valuemax="100"
+                    ></div>
+                </div>
+                {
+                    if self.total > MAX_EXPORT_USERS {
+                        html! {
+                            <p class="text-muted mt-2 mb-0" style="font-size: 14px;">
+                                <i class="bi bi-info-circle me-2"></i>
+                                { format!("{} users match, only the first {} by creation date are exported. Narrow the search, eg. with a created_at range, to export the rest.", self.total, MAX_EXPORT_USERS) }
+                            </p>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+            </>
+        }
+    }
+
+    fn view_footer(&self) -> Html {
+        if self.running {
+            return html! {
+                <button type="button" class="btn btn-outline-secondary" onclick=self.link.callback(|_| Msg::Cancel)>
+                    {"Stop"}
+                </button>
+            };
+        }
+        if self.finished {
+            return html! {
+                <>
+                    <button type="button" class="btn btn-outline-secondary" onclick=self.link.callback(|_| Msg::Download)>
+                        <i class="bi bi-download me-1"></i>
+                        {"Download Again"}
+                    </button>
+                    <button type="button" class="btn btn-primary" onclick=self.link.callback(|_| Msg::ShowModal(false))>
+                        {"Done"}
+                    </button>
+                </>
+            };
+        }
+        html! {
+            <>
+                <button type="button" class="btn btn-outline-secondary" onclick=self.link.callback(|_| Msg::ShowModal(false))>
+                    {"Cancel"}
+                </button>
+                {
+                    // STOPPED OR FAILED HALFWAY, CONTINUE FROM THE CHECKPOINT
+                    if self.parts.length() > 0 {
+                        html! {
+                            <button type="button" class="btn btn-primary" onclick=self.link.callback(|_| Msg::RequestPage)>
+                                {"Resume"}
+                            </button>
+                        }
+                    } else {
+                        html! {
+                            <button
+                                type="button"
+                                class="btn btn-primary"
+                                disabled=self.fields.is_empty()
+                                onclick=self.link.callback(|_| Msg::Start)
+                            >
+                                {"Export"}
+                            </button>
+                        }
+                    }
+                }
+            </>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fields(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn extract_follows_nested_paths_through_arrays() {
+        let user = json!({
+            "email": "jane@acme.com",
+            "user_metadata": { "plan": "pro" },
+            "identities": [{ "provider": "auth0" }, { "provider": "google-oauth2" }]
+        });
+        assert_eq!(extract(&user, "user_metadata.plan"), json!("pro"));
+        assert_eq!(extract(&user, "identities.provider"), json!(["auth0", "google-oauth2"]));
+        assert_eq!(extract(&user, "app_metadata.roles"), Value::Null);
+    }
+
+    #[test]
+    fn csv_quotes_separators_and_neutralizes_formulas() {
+        assert_eq!(csv_cell(&json!("Doe, Jane")), "\"Doe, Jane\"");
+        assert_eq!(csv_cell(&json!("say \"hi\"")), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_cell(&json!("=SUM(A1)")), "'=SUM(A1)");
+        assert_eq!(csv_cell(&json!("@SUM(A1)")), "'@SUM(A1)");
+        assert_eq!(csv_cell(&json!("-2+3+cmd|' /C calc'!A0")), "'-2+3+cmd|' /C calc'!A0");
+        assert_eq!(csv_cell(&json!("+HYPERLINK(\"http://x\")")), "\"'+HYPERLINK(\"\"http://x\"\")\"");
+        assert_eq!(csv_cell(&json!("\t=1+1")), "'\t=1+1");
+        assert_eq!(csv_cell(&json!("\r=1+1")), "\"'\r=1+1\"");
+        assert_eq!(csv_cell(&json!("+62 812-3456-789")), "+62 812-3456-789");
+        assert_eq!(csv_cell(&json!(-12.5)), "-12.5");
+        assert_eq!(csv_cell(&json!("-")), "'-");
+        assert_eq!(csv_cell(&json!(["auth0", "github"])), "auth0;github");
+        assert_eq!(csv_cell(&json!(true)), "true");
+        assert_eq!(csv_cell(&Value::Null), "");
+    }
+
+    #[test]
+    fn later_pages_start_at_the_checkpoint() {
+        assert_eq!(checkpoint_query("", ""), "");
+        assert_eq!(checkpoint_query("blocked:true", ""), "blocked:true");
+        assert_eq!(
+            checkpoint_query("", "2021-09-07T07:17:53.901Z"),
+            "created_at:[\"2021-09-07T07:17:53.901Z\" TO *]"
+        );
+        assert_eq!(
+            checkpoint_query("name:jane OR blocked:true", "2021-09-07T07:17:53.901Z"),
+            "(name:jane OR blocked:true) AND created_at:[\"2021-09-07T07:17:53.901Z\" TO *]"
+        );
+    }
+
+    #[test]
+    fn every_user_is_one_line() {
+        let users = vec![
+            json!({ "email": "a@acme.com", "logins_count": 3 }),
+            json!({ "email": "b@acme.com" }),
+        ];
+        let fields = fields(&["email", "logins_count"]);
+        assert_eq!(csv_header(&fields), "email,logins_count\n");
+        assert_eq!(to_csv(&users, &fields), "a@acme.com,3\nb@acme.com,\n");
+        assert_eq!(
+            to_ndjson(&users, &fields),
+            "{\"email\":\"a@acme.com\",\"logins_count\":3}\n{\"email\":\"b@acme.com\",\"logins_count\":null}\n"
+        );
+    }
+}
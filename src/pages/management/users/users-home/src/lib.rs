@@ -7,18 +7,50 @@ use yew::{
         storage::{ StorageService, Area }
     },
 };
-use yew_router::components::RouterAnchor;
+use yew_router::{
+    components::RouterAnchor,
+    service::RouteService,
+};
 use router::AppRoute;
 use types::{
     users::{ResponseUsersList, UserCreate, UserTitle},
+    database::{DatabaseConnection, personal_info_from_email, DATABASE_STRATEGY},
+    pagination::Page,
     ResponseMessage,
     LocalStorage,
     LOCALSTORAGE_KEY,
 };
 
-use loading::Loading;
 use developers_note::DevelopersNote;
 use configs::server::API_URL;
+use paginated_table::{
+    query::{
+        encode_uri_component,
+        query_param,
+        replace_query,
+    },
+    Column,
+    PaginatedTable,
+    Pagination,
+    Sort,
+};
+
+mod batch;
+mod bulk_actions;
+mod export;
+mod lucene;
+mod query_builder;
+
+use lucene::{
+    field_query,
+    parse,
+    LuceneError,
+};
+use query_builder::QueryBuilder;
+use bulk_actions::BulkActions;
+use export::ExportUsers;
+
+pub const SEARCH_BY_LUCENE: &str = "lucene_syntax";
 
 #[derive(Clone, Debug, Eq, PartialEq, Properties)]
 pub struct UserProps {
@@ -28,6 +60,7 @@ pub struct UserProps {
 pub enum StateError {
     UserList,
     UserCreate,
+    ConnectionList,
 }
 
 pub enum DataUserCreate {
@@ -45,10 +78,23 @@ pub struct UsersHome {
     loading_get_user: bool,
     user_list: Vec<UserTitle>,
     error_user_list: Option<String>,
+    pagination: Pagination,
+    total_users: u32,
     show_modal_create: bool,
     loading_create_user: bool,
     user_create: UserCreate,
     error_user_create: Option<String>,
+    // DATABASE CONNECTIONS A USER CAN BE CREATED IN, WITH THEIR PASSWORD POLICY
+    database_connections: Vec<DatabaseConnection>,
+    connections_task: Option<FetchTask>,
+    route_service: RouteService,
+    search_text: String,
+    search_by: String,
+    query: String,
+    error_search: Option<(LuceneError, String)>,
+    show_query_builder: bool,
+    // (user_id, email), KEPT ACROSS PAGES
+    selected_users: Vec<(String, String)>,
 }
 
 pub enum Msg {
@@ -56,11 +102,25 @@ pub enum Msg {
     RequestUserList,
     LearnMore,
     HideDetails,
-    GetUserList(Vec<UserTitle>),
+    GetUserList(Page<UserTitle>),
+    RequestConnections,
+    GetConnections(Vec<DatabaseConnection>),
+    ChangePagination(Pagination),
     Input(String, DataUserCreate),
     ShowModalCreate(bool),
     Create,
+    InputSearch(String),
+    SelectSearchBy(String),
+    Search,
+    ApplyQuery(String),
+    ResetSearch,
+    ToggleQueryBuilder,
+    ToggleUser(String, String),
+    TogglePage(bool),
+    ClearSelection,
+    BulkFinished(Vec<String>),
     ResponseError(String, StateError),
+    Ignore,
 }
 
 impl Component for UsersHome {
@@ -104,6 +164,17 @@ impl Component for UsersHome {
 
         let user_create = UserCreate::new();
 
+        // SHARED SEARCHES ARRIVE AS ?q=<lucene query>
+        let route_service = RouteService::new();
+        let search_text = query_param(&route_service.get_query(), "q").unwrap_or_default().trim().to_string();
+        let pagination = Pagination::from_query(&route_service.get_query(), 50, Some(Sort::asc("name")), &["name", "logins_count", "last_login"]);
+        let search_by = if search_text.is_empty() { String::from("user") } else { String::from(SEARCH_BY_LUCENE) };
+        // A BROKEN LINK IS SHOWN IN THE SEARCH BOX WITH ITS ERROR, NOT SENT
+        let (query, error_search) = match parse(&search_text) {
+            Ok(_) => (search_text.clone(), None),
+            Err(error) => (String::from(""), Some((error, search_text.clone()))),
+        };
+
         UsersHome {
             tenant_id: props.tenant_id,
             access_token,
@@ -113,10 +184,21 @@ impl Component for UsersHome {
             loading_get_user: false,
             user_list: Vec::new(),
             error_user_list: None,
+            pagination,
+            total_users: 0,
             show_modal_create: false,
             loading_create_user: false,
             user_create,
             error_user_create: None,
+            database_connections: Vec::new(),
+            connections_task: None,
+            route_service,
+            search_text,
+            search_by,
+            query,
+            error_search,
+            show_query_builder: false,
+            selected_users: Vec::new(),
         }
     }
 
@@ -124,6 +206,7 @@ impl Component for UsersHome {
         if first_render {
             // ConsoleService::info("This is first render in user");
             self.link.send_message(Msg::RequestUserList);
+            self.link.send_message(Msg::RequestConnections);
         }
     }
 
@@ -141,13 +224,23 @@ impl Component for UsersHome {
                 true
             }
             Msg::RequestUserList => {
-                let request = Request::get(format!("{}/api/v2/users", API_URL))
+                let url = if self.query.is_empty() {
+                    format!("{}/api/v2/users?{}", API_URL, self.pagination.api_params())
+                } else {
+                    format!(
+                        "{}/api/v2/users?{}&q={}&search_engine=v3",
+                        API_URL,
+                        self.pagination.api_params(),
+                        encode_uri_component(&self.query)
+                    )
+                };
+                let request = Request::get(url)
                     .header("access_token", self.access_token.clone())
                     .body(Nothing)
                     .expect("Could not build request.");
 
                 let callback = self.link.callback(
-                    |response: Response<Json<Result<Vec<UserTitle>, anyhow::Error>>>| {
+                    |response: Response<Json<Result<Page<UserTitle>, anyhow::Error>>>| {
                         let Json(data) = response.into_body();
                         match data {
                             Ok(dataok) => Msg::GetUserList(dataok),
@@ -172,12 +265,37 @@ impl Component for UsersHome {
                 true
             }
             Msg::GetUserList(data) => {
-                ConsoleService::info(&format!("{:?}", data));
-                self.user_list = data;
+                self.user_list = data.items;
+                self.total_users = data.total;
                 self.loading_get_user = false;
                 self.fetch_task = None;
                 true
             }
+            Msg::RequestConnections => {
+                let request = Request::get(format!("{}/api/v2/connections?strategy={}", API_URL, DATABASE_STRATEGY))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<DatabaseConnection>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetConnections(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::ConnectionList)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.connections_task = Some(task);
+                false
+            }
+            Msg::GetConnections(data) => {
+                self.database_connections = data;
+                self.connections_task = None;
+                true
+            }
             Msg::Input(input, data) => {
                 match data {
                     DataUserCreate::Email => {
@@ -197,6 +315,31 @@ impl Component for UsersHome {
                 true
             }
             Msg::Create => {
+                // VALIDATION
+                // THE SERVER ENFORCES THE POLICY TOO, CHECKING HERE SAVES A ROUND TRIP
+                let connection = self.database_connections
+                    .iter()
+                    .find(|connection| connection.name == self.user_create.connection);
+                match connection {
+                    Some(connection) => {
+                        let violations = connection.options.password_policy.violations(
+                            &self.user_create.password,
+                            &personal_info_from_email(&self.user_create.email),
+                        );
+                        if !violations.is_empty() {
+                            self.link.send_message(Msg::ResponseError(
+                                format!("Password doesn't meet the policy: {}", violations.join(", ")),
+                                StateError::UserCreate,
+                            ));
+                            return false;
+                        }
+                    }
+                    None => {
+                        self.link.send_message(Msg::ResponseError(String::from("Connection is required"), StateError::UserCreate));
+                        return false;
+                    }
+                }
+
                 ConsoleService::info(&format!("{:?}", self.user_create));
                 let request = Request::post(format!("{}/api/v2/users", API_URL))
                     .header("Content-Type", "application/json")
@@ -223,6 +366,94 @@ impl Component for UsersHome {
                 self.fetch_task = Some(task);
                 true
             }
+            Msg::InputSearch(text) => {
+                self.search_text = text;
+                self.error_search = None;
+                true
+            }
+            Msg::SelectSearchBy(search_by) => {
+                self.search_by = search_by;
+                self.error_search = None;
+                true
+            }
+            Msg::Search => {
+                let query = if self.search_by == SEARCH_BY_LUCENE {
+                    self.search_text.trim().to_string()
+                } else {
+                    let field = if self.search_by == "user" { None } else { Some(self.search_by.as_str()) };
+                    field_query(field, &self.search_text)
+                        .map(|query| query.to_string())
+                        .unwrap_or_default()
+                };
+                match parse(&query) {
+                    Ok(_) => {
+                        self.error_search = None;
+                        self.query = query;
+                        self.link.send_message(Msg::ChangePagination(self.pagination.first_page()));
+                    }
+                    Err(error) => {
+                        self.error_search = Some((error, query));
+                    }
+                }
+                true
+            }
+            Msg::ChangePagination(pagination) => {
+                self.pagination = pagination;
+                let mut params = Vec::new();
+                if !self.query.is_empty() {
+                    params.push((String::from("q"), self.query.clone()));
+                }
+                params.extend(self.pagination.url_params());
+                replace_query(&mut self.route_service, &params);
+                self.link.send_message(Msg::RequestUserList);
+                false
+            }
+            Msg::ApplyQuery(query) => {
+                self.search_by = String::from(SEARCH_BY_LUCENE);
+                self.search_text = query;
+                self.link.send_message(Msg::Search);
+                true
+            }
+            Msg::ResetSearch => {
+                self.search_by = String::from("user");
+                self.search_text = String::from("");
+                self.link.send_message(Msg::Search);
+                true
+            }
+            Msg::ToggleQueryBuilder => {
+                self.show_query_builder = !self.show_query_builder;
+                true
+            }
+            Msg::ToggleUser(user_id, email) => {
+                if self.is_selected(&user_id) {
+                    self.selected_users.retain(|(id, _)| *id != user_id);
+                } else {
+                    self.selected_users.push((user_id, email));
+                }
+                true
+            }
+            Msg::TogglePage(select) => {
+                for user in self.user_list.iter() {
+                    let selected = self.selected_users.iter().any(|(id, _)| *id == user.user_id);
+                    if select && !selected {
+                        self.selected_users.push((user.user_id.clone(), user.email.clone()));
+                    }
+                }
+                if !select {
+                    let user_list = &self.user_list;
+                    self.selected_users.retain(|(id, _)| !user_list.iter().any(|user| user.user_id == *id));
+                }
+                true
+            }
+            Msg::ClearSelection => {
+                self.selected_users = Vec::new();
+                true
+            }
+            Msg::BulkFinished(user_ids) => {
+                self.selected_users.retain(|(id, _)| !user_ids.contains(id));
+                self.link.send_message(Msg::RequestUserList);
+                true
+            }
             Msg::ResponseError(message, state) => {
                 match state {
                     StateError::UserList => {
@@ -233,10 +464,16 @@ impl Component for UsersHome {
                         self.loading_create_user = false;
                         self.error_user_create = Some(message);
                     }
+                    StateError::ConnectionList => {
+                        self.connections_task = None;
+                        self.error_user_create = Some(message);
+                        return true;
+                    }
                 }
                 self.fetch_task = None;
                 true
             }
+            Msg::Ignore => false,
         }
     }
 
@@ -256,7 +493,8 @@ impl Component for UsersHome {
                     >
                         <div class=" d-flex flex-row">
                             <h2 class="flex-fill title">{"Users"}</h2>
-                            <div>
+                            <div class="d-flex">
+                                <ExportUsers query=self.query.clone() />
                                 <button
                                     type="button"
                                     class="btn btn-primary d-flex align-items-center"
@@ -389,84 +627,104 @@ impl Component for UsersHome {
 
 
                     <div class="mt-5">
-                        <DevelopersNote message="Feature Search and Filter are not yet implemented"/>
-                        <div class="row" style="opacity: .7;">
+                        <div class="row">
                             <div class="col-md col-lg">
                                 <div class="input-group flex-nowrap">
                                     <span class="input-group-text" id="addon-wrapping"><i class="bi bi-search"></i></span>
-                                    <input type="text" class="form-control" placeholder="Search for users" aria-label="Username" aria-describedby="addon-wrapping" />
+                                    <input
+                                        type="text"
+                                        class=classes!("form-control", if self.error_search.is_some() { "is-invalid" } else { "" })
+                                        placeholder={
+                                            if self.search_by == SEARCH_BY_LUCENE {
+                                                "email:\"john@example.com\" AND logins_count:[10 TO *]"
+                                            } else {
+                                                "Search for users"
+                                            }
+                                        }
+                                        aria-label="Username"
+                                        aria-describedby="addon-wrapping"
+                                        value=self.search_text.clone()
+                                        oninput=self.link.callback(|data: InputData| Msg::InputSearch(data.value))
+                                        onkeypress=self.link.callback(|e: KeyboardEvent| {
+                                            if e.key() == "Enter" { Msg::Search } else { Msg::Ignore }
+                                        })
+                                    />
+                                    <button type="button" class="btn btn-outline-secondary" onclick=self.link.callback(|_| Msg::Search)>
+                                        {"Search"}
+                                    </button>
                                 </div>
                             </div>
                             <div class="col-md-auto col-lg-auto">
                                 <div class="input-group mb-3">
                                     <label class="input-group-text" for="inputGroupSelect01">{"Search by"}</label>
-                                    <select class="form-select" id="inputGroupSelect01">
-                                    <option selected=true>{"User"}</option>
-                                    <option value="1">{"Email"}</option>
-                                    <option value="2">{"Identity Provider"}</option>
-                                    <option value="3">{"Connection"}</option>
-                                    <option value="3">{"Connection"}</option>
-                                    <option value="3">{"Login Count"}</option>
-                                    <option value="3">{"Last Login"}</option>
-                                    <option value="3">{"Phone Number"}</option>
-                                    <option value="lucene_syntax">{"Lucene Syntax ()"}</option>
+                                    <select
+                                        class="form-select"
+                                        id="inputGroupSelect01"
+                                        onchange=self.link.callback(|e| {
+                                            if let ChangeData::Select(select) = e {
+                                                Msg::SelectSearchBy(select.value())
+                                            } else {
+                                                Msg::Ignore
+                                            }
+                                        })
+                                    >
+                                        { self.view_search_by_option("user", "User") }
+                                        { self.view_search_by_option("email", "Email") }
+                                        { self.view_search_by_option("identities.provider", "Identity Provider") }
+                                        { self.view_search_by_option("identities.connection", "Connection") }
+                                        { self.view_search_by_option("logins_count", "Login Count") }
+                                        { self.view_search_by_option("last_login", "Last Login") }
+                                        { self.view_search_by_option("phone_number", "Phone Number") }
+                                        { self.view_search_by_option(SEARCH_BY_LUCENE, "Lucene Syntax") }
                                     </select>
                                 </div>
                             </div>
                             <div class="col-md-auto col-lg-auto">
-                                <button type="button" class="btn btn-outline-secondary">
+                                <button type="button" class="btn btn-outline-secondary me-2" onclick=self.link.callback(|_| Msg::ToggleQueryBuilder)>
+                                    <i class="bi bi-funnel me-1"></i>
+                                    <span>{"Query Builder"}</span>
+                                </button>
+                                <button type="button" class="btn btn-outline-secondary" onclick=self.link.callback(|_| Msg::ResetSearch)>
                                     <i class="bi bi-x"></i>
                                     <span>{"Reset"}</span>
                                 </button>
                             </div>
                         </div>
+                        { self.view_search_error() }
+                        {
+                            if self.show_query_builder {
+                                html! { <QueryBuilder on_apply=self.link.callback(Msg::ApplyQuery) /> }
+                            } else {
+                                html! {}
+                            }
+                        }
                     </div>
 
                     <DevelopersNote message="Error handling (when token is expired) is not yet implemented"/>
-                    {
-                        if self.loading_get_user {
-                        // if true {
-                            html! {
-                                <div
-                                    // class="d-flex align-items-center justify-content-center"
-                                    style="margin-top: 4rem;"
-                                >
-                                    <Loading width=45 />
-                                </div>
-                            }
-                        } else if self.error_user_list.is_some() {
-                            html! {
-                                <tr>
-                                    <div class="alert alert-warning mb-5" role="alert">
-                                        <i class="bi bi-exclamation-triangle me-2"></i>
-                                        { self.error_user_list.clone().unwrap() }
-                                    </div>
-                                </tr>
-                            }
-                        } else {
-                            html! {
-                                <>
-                                    <div class="mt-2 table-responsive-md table-responsive-lg">
-                                        <table class="table">
-                                            <thead>
-                                                <tr>
-                                                    <th scope="col">{"Name"}</th>
-                                                    <th scope="col-auto">{"Connection"}</th>
-                                                    <th scope="col-auto">{"Logins"}</th>
-                                                    <th scope="col-auto">{"Latest Login"}</th>
-                                                    <th></th>
-                                                </tr>
-                                            </thead>
-                                        
-                                            <tbody>
-                                                {self.view_user_list()}
-                                            </tbody>
-                                        </table>
-                                    </div>
-                                </>
-                            }
-                        }
-                    }
+                    { self.view_select_page() }
+                    <BulkActions
+                        users=self.selected_users.clone()
+                        on_finish=self.link.callback(Msg::BulkFinished)
+                        on_clear=self.link.callback(|_| Msg::ClearSelection)
+                    />
+                    <PaginatedTable
+                        columns=vec![
+                            Column::new(""),
+                            Column::sortable("Name", "name"),
+                            Column::new("Connection"),
+                            Column::sortable("Logins", "logins_count"),
+                            Column::sortable("Latest Login", "last_login"),
+                            Column::new(""),
+                        ]
+                        pagination=self.pagination.clone()
+                        total=self.total_users
+                        loading=self.loading_get_user
+                        error=self.error_user_list.clone()
+                        empty_message={ if self.query.is_empty() { "There are no users yet." } else { "No users match this search." } }
+                        on_change=self.link.callback(Msg::ChangePagination)
+                    >
+                        { for self.view_user_list() }
+                    </PaginatedTable>
 
                 </div>
 
@@ -498,6 +756,7 @@ impl Component for UsersHome {
                                     value={ self.user_create.password.clone() }
                                     oninput=self.link.callback(|data: InputData| Msg::Input(data.value, DataUserCreate::Password))
                                 />
+                                { self.view_password_policy() }
                             </div>
                             // <div class="mb-3">
                             //     <label for="recipient-name" class="col-form-label">{"Repeat Password"} <span class="text-danger">{"*"}</span></label>
@@ -524,12 +783,16 @@ impl Component for UsersHome {
                                     <option>
                                         {"Select"}
                                     </option>
-                                    <option
-                                        selected={ if self.user_create.connection == String::from("Username-Password-Authentication") {true} else {false} }
-                                        value={"Username-Password-Authentication"}
-                                    >
-                                        {"Username Password Authentication"}
-                                    </option>
+                                    {
+                                        for self.database_connections.iter().map(|connection| html! {
+                                            <option
+                                                selected={ self.user_create.connection == connection.name }
+                                                value={ connection.name.clone() }
+                                            >
+                                                { connection.name.clone() }
+                                            </option>
+                                        })
+                                    }
                                 </select>
                             </div>
 
@@ -758,15 +1021,107 @@ impl Component for UsersHome {
 
 
 impl UsersHome {
+    fn view_password_policy(&self) -> Html {
+        let connection = self.database_connections
+            .iter()
+            .find(|connection| connection.name == self.user_create.connection);
+        match connection {
+            Some(connection) if !self.user_create.password.is_empty() => {
+                let violations = connection.options.password_policy.violations(
+                    &self.user_create.password,
+                    &personal_info_from_email(&self.user_create.email),
+                );
+                if violations.is_empty() {
+                    html! {
+                        <div class="form-text text-success">
+                            <i class="bi bi-check-circle me-1"></i>
+                            {"Password meets the connection policy"}
+                        </div>
+                    }
+                } else {
+                    html! {
+                        <div class="form-text text-danger">
+                            {"Password must have:"}
+                            <ul class="mb-0 ps-3">
+                                { for violations.iter().map(|violation| html! { <li>{ violation }</li> }) }
+                            </ul>
+                        </div>
+                    }
+                }
+            }
+            _ => html! {},
+        }
+    }
+
+    fn view_search_by_option(&self, value: &str, label: &str) -> Html {
+        html! {
+            <option value=value.to_string() selected={ self.search_by == value }>{ label }</option>
+        }
+    }
+
+    fn view_search_error(&self) -> Html {
+        match &self.error_search {
+            Some((error, query)) => {
+                // POINT AT THE COLUMN THE PARSER STOPPED AT
+                let caret = format!("{}^", " ".repeat(error.position));
+                html! {
+                    <div class="alert alert-warning mt-2" role="alert">
+                        <i class="bi bi-exclamation-triangle me-2"></i>
+                        { error.to_string() }
+                        <pre class="mb-0 mt-2" style="font-size: 13px;">
+                            { format!("{}\n{}", query, caret) }
+                        </pre>
+                    </div>
+                }
+            }
+            None => html! {},
+        }
+    }
+
+    fn is_selected(&self, user_id: &str) -> bool {
+        self.selected_users.iter().any(|(id, _)| id == user_id)
+    }
+
+    fn view_select_page(&self) -> Html {
+        if self.user_list.is_empty() || self.loading_get_user {
+            return html! {};
+        }
+        let page_selected = self.user_list.iter().all(|user| self.is_selected(&user.user_id));
+        html! {
+            <div class="form-check mt-3">
+                <input
+                    class="form-check-input"
+                    type="checkbox"
+                    id="selectPageUsers"
+                    checked=page_selected
+                    onclick=self.link.callback(move |_| Msg::TogglePage(!page_selected))
+                />
+                <label class="form-check-label" for="selectPageUsers" style="font-size: 14px;">
+                    {"Select all users on this page"}
+                </label>
+            </div>
+        }
+    }
+
     fn view_user_list(&self) -> Vec<Html> {
         type Anchor = RouterAnchor<AppRoute>;
         let tenant_id = self.tenant_id.clone();
 
         self.user_list.iter().map(|user| {
+            let user_id = user.user_id.clone();
+            let email = user.email.clone();
             html! {
                 <tr
                     class="align-middle"
                 >
+                    <td style="width: 40px;">
+                        <input
+                            class="form-check-input"
+                            type="checkbox"
+                            checked=self.is_selected(&user.user_id)
+                            onclick=self.link.callback(move |_| Msg::ToggleUser(user_id.clone(), email.clone()))
+                        />
+                    </td>
                     <th scope="row">
                         <div
                             class="pt-2 pb-2"
@@ -0,0 +1,591 @@
+use std::fmt;
+
+// LUCENE QUERY SYNTAX FOR USER SEARCH
+// Queries are parsed and validated before being sent, so the user gets an
+// error pointing at the offending column instead of an empty result
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FieldKind {
+    Text,
+    Number,
+    Date,
+    Boolean,
+}
+
+pub struct SearchField {
+    pub name: &'static str,
+    pub label: &'static str,
+    pub kind: FieldKind,
+}
+
+pub const SEARCH_FIELDS: &[SearchField] = &[
+    SearchField { name: "name", label: "Name", kind: FieldKind::Text },
+    SearchField { name: "email", label: "Email", kind: FieldKind::Text },
+    SearchField { name: "email_verified", label: "Email Verified", kind: FieldKind::Boolean },
+    SearchField { name: "user_id", label: "User ID", kind: FieldKind::Text },
+    SearchField { name: "username", label: "Username", kind: FieldKind::Text },
+    SearchField { name: "nickname", label: "Nickname", kind: FieldKind::Text },
+    SearchField { name: "given_name", label: "Given Name", kind: FieldKind::Text },
+    SearchField { name: "family_name", label: "Family Name", kind: FieldKind::Text },
+    SearchField { name: "phone_number", label: "Phone Number", kind: FieldKind::Text },
+    SearchField { name: "identities.connection", label: "Connection", kind: FieldKind::Text },
+    SearchField { name: "identities.provider", label: "Identity Provider", kind: FieldKind::Text },
+    SearchField { name: "blocked", label: "Blocked", kind: FieldKind::Boolean },
+    SearchField { name: "logins_count", label: "Login Count", kind: FieldKind::Number },
+    SearchField { name: "created_at", label: "Created At", kind: FieldKind::Date },
+    SearchField { name: "last_login", label: "Last Login", kind: FieldKind::Date },
+    SearchField { name: "updated_at", label: "Updated At", kind: FieldKind::Date },
+];
+
+const METADATA_PREFIXES: &[&str] = &["app_metadata.", "user_metadata."];
+
+pub fn field_kind(name: &str) -> Option<FieldKind> {
+    if let Some(field) = SEARCH_FIELDS.iter().find(|field| field.name == name) {
+        return Some(field.kind);
+    }
+    if METADATA_PREFIXES.iter().any(|prefix| name.starts_with(prefix) && name.len() > prefix.len()) {
+        return Some(FieldKind::Text);
+    }
+    None
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Value {
+    Word(String),
+    Phrase(String),
+    Range {
+        lower: String,
+        upper: String,
+        include_lower: bool,
+        include_upper: bool,
+    },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Query {
+    Term { field: Option<String>, value: Value },
+    Not(Box<Query>),
+    And(Box<Query>, Box<Query>),
+    Or(Box<Query>, Box<Query>),
+    Group(Box<Query>),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Word(word) => write!(f, "{}", escape(word)),
+            Value::Phrase(phrase) => write!(f, "\"{}\"", phrase.replace('\\', "\\\\").replace('"', "\\\"")),
+            Value::Range { lower, upper, include_lower, include_upper } => write!(
+                f,
+                "{}{} TO {}{}",
+                if *include_lower { "[" } else { "{" },
+                escape(lower),
+                escape(upper),
+                if *include_upper { "]" } else { "}" },
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Query::Term { field: Some(field), value } => write!(f, "{}:{}", escape(field), value),
+            Query::Term { field: None, value } => write!(f, "{}", value),
+            Query::Not(query) => write!(f, "NOT {}", query),
+            Query::And(left, right) => write!(f, "{} AND {}", left, right),
+            Query::Or(left, right) => write!(f, "{} OR {}", left, right),
+            Query::Group(query) => write!(f, "({})", query),
+        }
+    }
+}
+
+// SPECIAL CHARACTERS AND WHITESPACE ARE ESCAPED, WILDCARDS ARE KEPT. USED
+// FOR FIELD NAMES TOO, AND A WORD SPELLING AN OPERATOR STAYS A WORD
+fn escape(word: &str) -> String {
+    let mut escaped = String::new();
+    if ["AND", "OR", "NOT", "TO", "&&", "||"].contains(&word) {
+        escaped.push('\\');
+    }
+    for (i, c) in word.chars().enumerate() {
+        if "\\():[]{}\"".contains(c) || c.is_whitespace() || (i == 0 && (c == '-' || c == '!')) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LuceneError {
+    pub message: String,
+    pub position: usize,
+}
+
+impl fmt::Display for LuceneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (column {})", self.message, self.position + 1)
+    }
+}
+
+fn error<T>(message: String, position: usize) -> Result<T, LuceneError> {
+    Err(LuceneError { message, position })
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Token {
+    LParen,
+    RParen,
+    Colon,
+    RangeStart(bool),
+    RangeEnd(bool),
+    And,
+    Or,
+    Not,
+    Word(String),
+    Phrase(String),
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, LuceneError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        match c {
+            c if c.is_whitespace() => {
+                i += 1;
+            }
+            '(' => { tokens.push((Token::LParen, start)); i += 1; }
+            ')' => { tokens.push((Token::RParen, start)); i += 1; }
+            ':' => { tokens.push((Token::Colon, start)); i += 1; }
+            '[' => { tokens.push((Token::RangeStart(true), start)); i += 1; }
+            '{' => { tokens.push((Token::RangeStart(false), start)); i += 1; }
+            ']' => { tokens.push((Token::RangeEnd(true), start)); i += 1; }
+            '}' => { tokens.push((Token::RangeEnd(false), start)); i += 1; }
+            '-' | '!' if i + 1 < chars.len() && !chars[i + 1].is_whitespace() => {
+                tokens.push((Token::Not, start));
+                i += 1;
+            }
+            '"' => {
+                i += 1;
+                let mut phrase = String::new();
+                let mut closed = false;
+                while i < chars.len() {
+                    match chars[i] {
+                        '\\' if i + 1 < chars.len() => {
+                            phrase.push(chars[i + 1]);
+                            i += 2;
+                        }
+                        '"' => {
+                            closed = true;
+                            i += 1;
+                            break;
+                        }
+                        other => {
+                            phrase.push(other);
+                            i += 1;
+                        }
+                    }
+                }
+                if !closed {
+                    return error(String::from("Missing closing quote"), start);
+                }
+                tokens.push((Token::Phrase(phrase), start));
+            }
+            _ => {
+                let mut word = String::new();
+                let mut escaped = false;
+                while i < chars.len() {
+                    let c = chars[i];
+                    if c == '\\' {
+                        if i + 1 >= chars.len() {
+                            return error(String::from("Nothing to escape after \\"), i);
+                        }
+                        word.push(chars[i + 1]);
+                        escaped = true;
+                        i += 2;
+                        continue;
+                    }
+                    if c.is_whitespace() || "():[]{}\"".contains(c) {
+                        break;
+                    }
+                    word.push(c);
+                    i += 1;
+                }
+                // \AND IS THE WORD, NOT THE OPERATOR
+                let token = match word.as_str() {
+                    "AND" | "&&" if !escaped => Token::And,
+                    "OR" | "||" if !escaped => Token::Or,
+                    "NOT" if !escaped => Token::Not,
+                    _ => Token::Word(word),
+                };
+                tokens.push((token, start));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    index: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|(token, _)| token)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens.get(self.index).map(|(_, position)| *position).unwrap_or(self.end)
+    }
+
+    fn next(&mut self) -> Option<(Token, usize)> {
+        let token = self.tokens.get(self.index).cloned();
+        self.index += 1;
+        token
+    }
+
+    // or := and (OR and)*, adjacent clauses without an operator are OR'ed as in Lucene
+    fn parse_or(&mut self) -> Result<Query, LuceneError> {
+        let mut query = self.parse_and()?;
+        loop {
+            match self.peek() {
+                Some(Token::Or) => {
+                    self.next();
+                    let right = self.parse_and()?;
+                    query = Query::Or(Box::new(query), Box::new(right));
+                }
+                Some(Token::RParen) | None => return Ok(query),
+                Some(_) => {
+                    let right = self.parse_and()?;
+                    query = Query::Or(Box::new(query), Box::new(right));
+                }
+            }
+        }
+    }
+
+    fn parse_and(&mut self) -> Result<Query, LuceneError> {
+        let mut query = self.parse_unary()?;
+        while let Some(Token::And) = self.peek() {
+            self.next();
+            let right = self.parse_unary()?;
+            query = Query::And(Box::new(query), Box::new(right));
+        }
+        Ok(query)
+    }
+
+    fn parse_unary(&mut self) -> Result<Query, LuceneError> {
+        if let Some(Token::Not) = self.peek() {
+            self.next();
+            let query = self.parse_unary()?;
+            return Ok(Query::Not(Box::new(query)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Query, LuceneError> {
+        let position = self.position();
+        match self.next() {
+            Some((Token::LParen, _)) => {
+                if let Some(Token::RParen) = self.peek() {
+                    return error(String::from("Empty group"), position);
+                }
+                let query = self.parse_or()?;
+                match self.next() {
+                    Some((Token::RParen, _)) => Ok(Query::Group(Box::new(query))),
+                    _ => error(String::from("Missing closing parenthesis"), position),
+                }
+            }
+            Some((Token::Word(word), _)) => {
+                if let Some(Token::Colon) = self.peek() {
+                    self.next();
+                    let field = word;
+                    let kind = match field_kind(&field) {
+                        Some(kind) => kind,
+                        None => return error(format!("Unknown field \"{}\"", field), position),
+                    };
+                    let value_position = self.position();
+                    let value = self.parse_value(&field)?;
+                    validate_value(&field, kind, &value, value_position)?;
+                    Ok(Query::Term { field: Some(field), value })
+                } else {
+                    validate_wildcard(&word, position)?;
+                    Ok(Query::Term { field: None, value: Value::Word(word) })
+                }
+            }
+            Some((Token::Phrase(phrase), _)) => Ok(Query::Term { field: None, value: Value::Phrase(phrase) }),
+            Some((Token::RParen, _)) => error(String::from("Unexpected closing parenthesis"), position),
+            Some((Token::And, _)) | Some((Token::Or, _)) => error(String::from("Operator is missing a left-hand clause"), position),
+            Some((Token::Colon, _)) => error(String::from("Missing field name before \":\""), position),
+            Some((Token::RangeStart(_), _)) | Some((Token::RangeEnd(_), _)) => error(String::from("Ranges must follow a field name"), position),
+            Some((Token::Not, _)) => error(String::from("Unexpected NOT"), position),
+            None => error(String::from("Query ends unexpectedly"), position),
+        }
+    }
+
+    fn parse_value(&mut self, field: &str) -> Result<Value, LuceneError> {
+        let position = self.position();
+        match self.next() {
+            Some((Token::Word(word), _)) => Ok(Value::Word(word)),
+            Some((Token::Phrase(phrase), _)) => Ok(Value::Phrase(phrase)),
+            Some((Token::RangeStart(include_lower), _)) => {
+                let lower = self.parse_bound()?;
+                let to_position = self.position();
+                match self.next() {
+                    Some((Token::Word(word), _)) if word == "TO" => {}
+                    _ => return error(String::from("Expected TO inside range"), to_position),
+                }
+                let upper = self.parse_bound()?;
+                let end_position = self.position();
+                let include_upper = match self.next() {
+                    Some((Token::RangeEnd(include_upper), _)) => include_upper,
+                    _ => return error(String::from("Missing closing ] or } for range"), end_position),
+                };
+                Ok(Value::Range { lower, upper, include_lower, include_upper })
+            }
+            Some((Token::LParen, _)) => error(format!("Field grouping is not supported, repeat \"{}:\" for each value", field), position),
+            _ => error(format!("Missing value for field \"{}\"", field), position),
+        }
+    }
+
+    fn parse_bound(&mut self) -> Result<String, LuceneError> {
+        let position = self.position();
+        match self.next() {
+            Some((Token::Word(word), _)) if word != "TO" => Ok(word),
+            Some((Token::Phrase(phrase), _)) => Ok(phrase),
+            _ => error(String::from("Missing range bound"), position),
+        }
+    }
+}
+
+fn validate_wildcard(word: &str, position: usize) -> Result<(), LuceneError> {
+    if word.starts_with('*') || word.starts_with('?') {
+        return error(String::from("Leading wildcards are not supported"), position);
+    }
+    Ok(())
+}
+
+fn validate_value(field: &str, kind: FieldKind, value: &Value, position: usize) -> Result<(), LuceneError> {
+    match value {
+        Value::Word(word) => {
+            match kind {
+                FieldKind::Text => validate_wildcard(word, position),
+                FieldKind::Number => {
+                    if word.parse::<u64>().is_err() {
+                        return error(format!("\"{}\" expects a number", field), position);
+                    }
+                    Ok(())
+                }
+                FieldKind::Date => {
+                    if !is_date(word) {
+                        return error(format!("\"{}\" expects a date formatted as YYYY-MM-DD", field), position);
+                    }
+                    Ok(())
+                }
+                FieldKind::Boolean => {
+                    if word != "true" && word != "false" {
+                        return error(format!("\"{}\" expects true or false", field), position);
+                    }
+                    Ok(())
+                }
+            }
+        }
+        Value::Phrase(_) => {
+            if kind == FieldKind::Text {
+                Ok(())
+            } else {
+                error(format!("\"{}\" can't be searched with a quoted phrase", field), position)
+            }
+        }
+        Value::Range { lower, upper, .. } => {
+            match kind {
+                FieldKind::Number => {
+                    let parse = |bound: &str| -> Result<Option<u64>, LuceneError> {
+                        if bound == "*" {
+                            return Ok(None);
+                        }
+                        match bound.parse::<u64>() {
+                            Ok(number) => Ok(Some(number)),
+                            Err(_) => error(format!("Range bound \"{}\" of \"{}\" is not a number", bound, field), position),
+                        }
+                    };
+                    if let (Some(lower), Some(upper)) = (parse(lower)?, parse(upper)?) {
+                        if lower > upper {
+                            return error(String::from("Range lower bound is greater than upper bound"), position);
+                        }
+                    }
+                    Ok(())
+                }
+                FieldKind::Date => {
+                    for bound in [lower, upper].iter() {
+                        if bound.as_str() != "*" && !is_date(bound) {
+                            return error(format!("Range bound \"{}\" of \"{}\" is not a date formatted as YYYY-MM-DD", bound, field), position);
+                        }
+                    }
+                    if lower.as_str() != "*" && upper.as_str() != "*" && lower[..10] > upper[..10] {
+                        return error(String::from("Range lower bound is greater than upper bound"), position);
+                    }
+                    Ok(())
+                }
+                _ => error(format!("Ranges are only supported on number and date fields, not \"{}\"", field), position),
+            }
+        }
+    }
+}
+
+// YYYY-MM-DD, OPTIONALLY FOLLOWED BY A TIME
+fn is_date(value: &str) -> bool {
+    let date = match value.get(..10) {
+        Some(date) => date,
+        None => return false,
+    };
+    if value.len() > 10 && !value[10..].starts_with('T') {
+        return false;
+    }
+    let parts: Vec<&str> = date.split('-').collect();
+    if parts.len() != 3 || parts[0].len() != 4 || parts[1].len() != 2 || parts[2].len() != 2 {
+        return false;
+    }
+    match (parts[0].parse::<u32>(), parts[1].parse::<u32>(), parts[2].parse::<u32>()) {
+        (Ok(_), Ok(month), Ok(day)) => (1..=12).contains(&month) && (1..=31).contains(&day),
+        _ => false,
+    }
+}
+
+pub fn parse(input: &str) -> Result<Option<Query>, LuceneError> {
+    let tokens = tokenize(input)?;
+    if tokens.is_empty() {
+        return Ok(None);
+    }
+    let mut parser = Parser {
+        tokens,
+        index: 0,
+        end: input.chars().count(),
+    };
+    let query = parser.parse_or()?;
+    if let Some(Token::RParen) = parser.peek() {
+        return error(String::from("Unexpected closing parenthesis"), parser.position());
+    }
+    Ok(Some(query))
+}
+
+// A SINGLE FIELD SEARCH, AS TYPED IN THE SIMPLE SEARCH BOX
+pub fn field_query(field: Option<&str>, text: &str) -> Option<Query> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    let is_operator = ["AND", "OR", "NOT", "&&", "||"].contains(&text);
+    let value = if is_operator || text.chars().any(char::is_whitespace) {
+        Value::Phrase(text.to_string())
+    } else {
+        Value::Word(text.to_string())
+    };
+    Some(Query::Term { field: field.map(String::from), value })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(input: &str) -> String {
+        parse(input).unwrap().unwrap().to_string()
+    }
+
+    #[test]
+    fn empty_query_is_none() {
+        assert_eq!(parse("   "), Ok(None));
+    }
+
+    #[test]
+    fn serializes_back_to_the_same_query() {
+        assert_eq!(round_trip("email:jane@acme.com"), "email:jane@acme.com");
+        assert_eq!(round_trip("name:\"Jane Doe\" AND blocked:false"), "name:\"Jane Doe\" AND blocked:false");
+        assert_eq!(round_trip("(name:jane* OR nickname:jd) AND NOT email_verified:true"), "(name:jane* OR nickname:jd) AND NOT email_verified:true");
+        assert_eq!(round_trip("logins_count:{10 TO *]"), "logins_count:{10 TO *]");
+        assert_eq!(round_trip("created_at:[2024-01-01 TO 2024-12-31]"), "created_at:[2024-01-01 TO 2024-12-31]");
+    }
+
+    #[test]
+    fn adjacent_clauses_are_ored_and_dash_is_not() {
+        assert_eq!(round_trip("jane doe"), "jane OR doe");
+        assert_eq!(round_trip("-blocked:true"), "NOT blocked:true");
+    }
+
+    #[test]
+    fn escaped_words_survive_a_round_trip() {
+        let query = Query::Term {
+            field: Some(String::from("name")),
+            value: Value::Word(String::from("john doe*")),
+        };
+        assert_eq!(query.to_string(), "name:john\\ doe*");
+        assert_eq!(parse(&query.to_string()), Ok(Some(query)));
+
+        let query = Query::Term {
+            field: Some(String::from("user_id")),
+            value: Value::Word(String::from("auth0|a:b")),
+        };
+        assert_eq!(parse(&query.to_string()), Ok(Some(query)));
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_column() {
+        let error = parse("nmae:jane").unwrap_err();
+        assert_eq!(error.position, 0);
+        assert!(error.message.contains("Unknown field"));
+
+        let error = parse("name:jane AND logins_count:many").unwrap_err();
+        assert_eq!(error.position, 27);
+
+        assert_eq!(parse("name:\"jane").unwrap_err().message, "Missing closing quote");
+        assert_eq!(parse("(name:jane").unwrap_err().message, "Missing closing parenthesis");
+        assert_eq!(parse("name:jane)").unwrap_err().message, "Unexpected closing parenthesis");
+        assert_eq!(parse("AND name:jane").unwrap_err().message, "Operator is missing a left-hand clause");
+        assert_eq!(parse("name:*ane").unwrap_err().message, "Leading wildcards are not supported");
+    }
+
+    #[test]
+    fn field_values_are_checked_against_the_field_kind() {
+        assert!(parse("blocked:yes").is_err());
+        assert!(parse("created_at:2024-13-01").is_err());
+        assert!(parse("created_at:[2024-02-01 TO 2024-01-01]").is_err());
+        assert!(parse("logins_count:[5 TO 1]").is_err());
+        assert!(parse("name:[a TO b]").is_err());
+        assert!(parse("logins_count:\"5\"").is_err());
+        assert!(parse("app_metadata.plan:pro").is_ok());
+        assert!(parse("app_metadata.:pro").is_err());
+    }
+
+    #[test]
+    fn field_query_quotes_phrases_and_operators() {
+        assert_eq!(field_query(Some("name"), " Jane Doe ").unwrap().to_string(), "name:\"Jane Doe\"");
+        assert_eq!(field_query(None, "OR").unwrap().to_string(), "\"OR\"");
+        assert_eq!(field_query(Some("email"), "jane@acme.com").unwrap().to_string(), "email:jane@acme.com");
+        assert_eq!(field_query(None, "  "), None);
+    }
+
+    #[test]
+    fn escaped_field_names_and_operator_words_survive_a_round_trip() {
+        for input in [
+            "app_metadata.x\\\":pro",
+            "app_metadata.plan\\:tier:pro",
+            "app_metadata.a\\(b\\ c:pro",
+            "name:\\AND",
+            "\\OR",
+            "app_metadata.x:\\&&",
+        ].iter() {
+            let query = parse(input).unwrap().unwrap();
+            assert_eq!(parse(&query.to_string()), Ok(Some(query)), "{}", input);
+        }
+        let query = Query::Term {
+            field: Some(String::from("app_metadata.x\"")),
+            value: Value::Word(String::from("NOT")),
+        };
+        assert_eq!(query.to_string(), "app_metadata.x\\\":\\NOT");
+        assert_eq!(parse(&query.to_string()), Ok(Some(query)));
+    }
+}
@@ -0,0 +1,440 @@
+use yew::prelude::*;
+use crate::lucene::{
+    field_kind,
+    parse,
+    FieldKind,
+    Query,
+    Value,
+    SEARCH_FIELDS,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operator {
+    Is,
+    IsNot,
+    StartsWith,
+    Between,
+    GreaterThan,
+    LessThan,
+}
+
+impl Operator {
+    fn label(&self) -> &'static str {
+        match self {
+            Operator::Is => "is",
+            Operator::IsNot => "is not",
+            Operator::StartsWith => "starts with",
+            Operator::Between => "between",
+            Operator::GreaterThan => "greater than",
+            Operator::LessThan => "less than",
+        }
+    }
+
+    fn for_kind(kind: FieldKind) -> Vec<Operator> {
+        match kind {
+            FieldKind::Text => vec![Operator::Is, Operator::IsNot, Operator::StartsWith],
+            FieldKind::Number | FieldKind::Date => vec![
+                Operator::Is,
+                Operator::IsNot,
+                Operator::Between,
+                Operator::GreaterThan,
+                Operator::LessThan,
+            ],
+            FieldKind::Boolean => vec![Operator::Is, Operator::IsNot],
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Condition {
+    field: String,
+    operator: Operator,
+    value: String,
+    value_to: String,
+}
+
+impl Condition {
+    fn new() -> Condition {
+        Condition {
+            field: String::from("email"),
+            operator: Operator::Is,
+            value: String::from(""),
+            value_to: String::from(""),
+        }
+    }
+
+    fn kind(&self) -> FieldKind {
+        field_kind(&self.field).unwrap_or(FieldKind::Text)
+    }
+
+    fn to_query(&self) -> Option<Query> {
+        let value = self.value.trim().to_string();
+        let value_to = self.value_to.trim().to_string();
+        if value.is_empty() && (self.operator != Operator::Between || value_to.is_empty()) {
+            return None;
+        }
+        let exact = || {
+            if value.chars().any(char::is_whitespace) {
+                Value::Phrase(value.clone())
+            } else {
+                Value::Word(value.clone())
+            }
+        };
+        let or_any = |bound: &String| if bound.is_empty() { String::from("*") } else { bound.clone() };
+        let value = match self.operator {
+            Operator::Is | Operator::IsNot => exact(),
+            Operator::StartsWith => Value::Word(format!("{}*", value)),
+            Operator::Between => Value::Range {
+                lower: or_any(&value),
+                upper: or_any(&value_to),
+                include_lower: true,
+                include_upper: true,
+            },
+            Operator::GreaterThan => Value::Range {
+                lower: value.clone(),
+                upper: String::from("*"),
+                include_lower: false,
+                include_upper: true,
+            },
+            Operator::LessThan => Value::Range {
+                lower: String::from("*"),
+                upper: value.clone(),
+                include_lower: true,
+                include_upper: false,
+            },
+        };
+        let term = Query::Term { field: Some(self.field.clone()), value };
+        if self.operator == Operator::IsNot {
+            Some(Query::Not(Box::new(term)))
+        } else {
+            Some(term)
+        }
+    }
+}
+
+pub enum Data {
+    Field,
+    Operator,
+    Value,
+    ValueTo,
+}
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct QueryBuilderProps {
+    pub on_apply: Callback<String>,
+}
+
+pub struct QueryBuilder {
+    props: QueryBuilderProps,
+    link: ComponentLink<Self>,
+    conditions: Vec<Condition>,
+    match_all: bool,
+    error: Option<String>,
+}
+
+pub enum Msg {
+    AddCondition,
+    RemoveCondition(usize),
+    Input(usize, Data, String),
+    MatchAll(bool),
+    Apply,
+}
+
+impl Component for QueryBuilder {
+    type Message = Msg;
+    type Properties = QueryBuilderProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        QueryBuilder {
+            props,
+            link,
+            conditions: vec![Condition::new()],
+            match_all: true,
+            error: None,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        self.error = None;
+        match msg {
+            Msg::AddCondition => {
+                self.conditions.push(Condition::new());
+            }
+            Msg::RemoveCondition(index) => {
+                if index < self.conditions.len() {
+                    self.conditions.remove(index);
+                }
+            }
+            Msg::Input(index, data, value) => {
+                if let Some(condition) = self.conditions.get_mut(index) {
+                    match data {
+                        Data::Field => {
+                            let previous_kind = condition.kind();
+                            condition.field = value;
+                            if !Operator::for_kind(condition.kind()).contains(&condition.operator) {
+                                condition.operator = Operator::Is;
+                            }
+                            if condition.kind() != previous_kind {
+                                condition.value = match condition.kind() {
+                                    FieldKind::Boolean => String::from("true"),
+                                    _ => String::from(""),
+                                };
+                                condition.value_to = String::from("");
+                            }
+                        }
+                        Data::Operator => {
+                            if let Some(operator) = Operator::for_kind(condition.kind())
+                                .into_iter()
+                                .find(|operator| operator.label() == value)
+                            {
+                                condition.operator = operator;
+                            }
+                        }
+                        Data::Value => condition.value = value,
+                        Data::ValueTo => condition.value_to = value,
+                    }
+                }
+            }
+            Msg::MatchAll(match_all) => {
+                self.match_all = match_all;
+            }
+            Msg::Apply => {
+                let query = self.build();
+                match parse(&query) {
+                    Ok(_) => self.props.on_apply.emit(query),
+                    Err(error) => self.error = Some(error.message),
+                }
+            }
+        }
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.props = props;
+        false
+    }
+
+    fn view(&self) -> Html {
+        let query = self.build();
+        html! {
+            <div class="card mt-3">
+                <div class="card-body">
+                    <div class="d-flex align-items-center mb-3">
+                        <span class="fw-bold me-3">{"Query Builder"}</span>
+                        <select
+                            class="form-select form-select-sm w-auto"
+                            onchange=self.link.callback(|e| {
+                                if let ChangeData::Select(select) = e {
+                                    Msg::MatchAll(select.value() == "all")
+                                } else {
+                                    Msg::MatchAll(true)
+                                }
+                            })
+                        >
+                            <option value="all" selected=self.match_all>{"Match all conditions (AND)"}</option>
+                            <option value="any" selected=!self.match_all>{"Match any condition (OR)"}</option>
+                        </select>
+                    </div>
+
+                    { for self.conditions.iter().enumerate().map(|(index, condition)| self.view_condition(index, condition)) }
+
+                    <button
+                        type="button"
+                        class="btn btn-sm btn-outline-secondary"
+                        onclick=self.link.callback(|_| Msg::AddCondition)
+                    >
+                        <i class="bi bi-plus me-1"></i>
+                        {"Add Condition"}
+                    </button>
+
+                    <div class="mt-3">
+                        <label class="form-label text-muted" style="font-size: 13px;">{"Lucene Query"}</label>
+                        <div class="d-flex align-items-center">
+                            <code class="flex-fill text-dark p-2 rounded" style="background-color: #eff0f2;">
+                                { if query.is_empty() { String::from("-") } else { query } }
+                            </code>
+                            <button
+                                type="button"
+                                class="btn btn-primary ms-3"
+                                onclick=self.link.callback(|_| Msg::Apply)
+                            >
+                                {"Search"}
+                            </button>
+                        </div>
+                        {
+                            match &self.error {
+                                Some(error) => html! { <div class="invalid-feedback d-block">{ error }</div> },
+                                None => html! {},
+                            }
+                        }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl QueryBuilder {
+    fn build(&self) -> String {
+        let mut queries = self.conditions
+            .iter()
+            .filter_map(|condition| condition.to_query());
+        let first = match queries.next() {
+            Some(first) => first,
+            None => return String::from(""),
+        };
+        let match_all = self.match_all;
+        queries
+            .fold(first, |left, right| {
+                if match_all {
+                    Query::And(Box::new(left), Box::new(right))
+                } else {
+                    Query::Or(Box::new(left), Box::new(right))
+                }
+            })
+            .to_string()
+    }
+
+    fn view_condition(&self, index: usize, condition: &Condition) -> Html {
+        let kind = condition.kind();
+        let input_type = match kind {
+            FieldKind::Number => "number",
+            FieldKind::Date => "date",
+            _ => "text",
+        };
+        html! {
+            <div class="row g-2 mb-2 align-items-center">
+                <div class="col-md-3">
+                    <select
+                        class="form-select form-select-sm"
+                        onchange=self.link.callback(move |e| {
+                            if let ChangeData::Select(select) = e {
+                                Msg::Input(index, Data::Field, select.value())
+                            } else {
+                                Msg::Input(index, Data::Field, String::from("email"))
+                            }
+                        })
+                    >
+                        {
+                            for SEARCH_FIELDS.iter().map(|field| html! {
+                                <option value=field.name selected={ field.name == condition.field }>{ field.label }</option>
+                            })
+                        }
+                    </select>
+                </div>
+                <div class="col-md-2">
+                    <select
+                        class="form-select form-select-sm"
+                        onchange=self.link.callback(move |e| {
+                            if let ChangeData::Select(select) = e {
+                                Msg::Input(index, Data::Operator, select.value())
+                            } else {
+                                Msg::Input(index, Data::Operator, String::from("is"))
+                            }
+                        })
+                    >
+                        {
+                            for Operator::for_kind(kind).into_iter().map(|operator| html! {
+                                <option value=operator.label() selected={ operator == condition.operator }>{ operator.label() }</option>
+                            })
+                        }
+                    </select>
+                </div>
+                <div class="col">
+                    {
+                        if kind == FieldKind::Boolean {
+                            html! {
+                                <select
+                                    class="form-select form-select-sm"
+                                    onchange=self.link.callback(move |e| {
+                                        if let ChangeData::Select(select) = e {
+                                            Msg::Input(index, Data::Value, select.value())
+                                        } else {
+                                            Msg::Input(index, Data::Value, String::from("true"))
+                                        }
+                                    })
+                                >
+                                    <option value="true" selected={ condition.value == "true" }>{"true"}</option>
+                                    <option value="false" selected={ condition.value == "false" }>{"false"}</option>
+                                </select>
+                            }
+                        } else if condition.operator == Operator::Between {
+                            html! {
+                                <div class="input-group input-group-sm">
+                                    <input
+                                        type=input_type
+                                        class="form-control"
+                                        placeholder="From"
+                                        value=condition.value.clone()
+                                        oninput=self.link.callback(move |data: InputData| Msg::Input(index, Data::Value, data.value))
+                                    />
+                                    <span class="input-group-text">{"to"}</span>
+                                    <input
+                                        type=input_type
+                                        class="form-control"
+                                        placeholder="To"
+                                        value=condition.value_to.clone()
+                                        oninput=self.link.callback(move |data: InputData| Msg::Input(index, Data::ValueTo, data.value))
+                                    />
+                                </div>
+                            }
+                        } else {
+                            html! {
+                                <input
+                                    type=input_type
+                                    class="form-control form-control-sm"
+                                    placeholder="Value"
+                                    value=condition.value.clone()
+                                    oninput=self.link.callback(move |data: InputData| Msg::Input(index, Data::Value, data.value))
+                                />
+                            }
+                        }
+                    }
+                </div>
+                <div class="col-auto">
+                    <button
+                        type="button"
+                        class="btn btn-sm btn-outline-secondary"
+                        disabled={ self.conditions.len() == 1 }
+                        onclick=self.link.callback(move |_| Msg::RemoveCondition(index))
+                    >
+                        <i class="bi bi-x"></i>
+                    </button>
+                </div>
+            </div>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lucene::parse;
+
+    fn condition(field: &str, operator: Operator, value: &str) -> Condition {
+        Condition {
+            field: String::from(field),
+            operator,
+            value: String::from(value),
+            value_to: String::from(""),
+        }
+    }
+
+    #[test]
+    fn starts_with_keeps_whitespace_in_the_value() {
+        let query = condition("name", Operator::StartsWith, "john doe").to_query().unwrap();
+        assert_eq!(query.to_string(), "name:john\\ doe*");
+        assert_eq!(parse(&query.to_string()), Ok(Some(query)));
+    }
+
+    #[test]
+    fn is_not_negates_and_phrases_are_quoted() {
+        let query = condition("name", Operator::IsNot, "Jane Doe").to_query().unwrap();
+        assert_eq!(query.to_string(), "NOT name:\"Jane Doe\"");
+    }
+
+    #[test]
+    fn empty_values_are_skipped() {
+        assert!(condition("email", Operator::Is, "  ").to_query().is_none());
+    }
+}
@@ -492,7 +492,7 @@ impl UserSettings {
                     match self.content {
                         Content::UserTabDetails => html! {<UserTabDetails user_details=self.user_details.clone()/>},
                         Content::UserTabDevices => html! {<UserTabDevices/>},
-                        Content::UserTabHistory => html! {<UserTabHistory/>},
+                        Content::UserTabHistory => html! {<UserTabHistory user_id=self.user_id.clone()/>},
                         Content::UserTabRawJson => html! {<UserTabRawJson/>},
                         Content::UserTabAuthorizedApp => html! {<UserTabAuthorizedApp/>},
                         Content::UserTabPermissions => html! {<UserTabPermissions user_id=self.user_details.user_id.clone()/>},
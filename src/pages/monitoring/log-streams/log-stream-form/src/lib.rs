@@ -0,0 +1,419 @@
+use yew::prelude::*;
+use types::{
+    logs::LogCategory,
+    log_streams::{
+        LogStream,
+        HTTP_FORMATS,
+        LOG_STREAM_SYSLOG,
+        MAX_BATCH_INTERVAL,
+        MAX_BATCH_SIZE,
+        SYSLOG_PROTOCOLS,
+    },
+};
+
+// NAME, SINK AND CATEGORY FILTERS OF A LOG STREAM, SHARED BY THE CREATE PAGE
+// AND THE SETTINGS PAGE. THE TYPE ITSELF CAN'T BE CHANGED ONCE CREATED
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct LogStreamFormProps {
+    pub stream: LogStream,
+    pub disabled: bool,
+    pub on_change: Callback<LogStream>,
+}
+
+pub enum Data {
+    Name,
+    HttpUrl,
+    HttpAuthorization,
+    HttpFormat,
+    HttpBatchSize,
+    HttpBatchInterval,
+    SyslogHost,
+    SyslogPort,
+    SyslogProtocol,
+}
+
+pub struct LogStreamForm {
+    props: LogStreamFormProps,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    Input(String, Data),
+    ToggleCategory(LogCategory),
+    ClearCategories,
+}
+
+impl Component for LogStreamForm {
+    type Message = Msg;
+    type Properties = LogStreamFormProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        LogStreamForm {
+            props,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        let mut stream = self.props.stream.clone();
+        match msg {
+            Msg::Input(value, data) => {
+                match data {
+                    Data::Name => stream.name = value,
+                    Data::HttpUrl => stream.http.url = value.trim().to_string(),
+                    Data::HttpAuthorization => stream.http.authorization = value,
+                    Data::HttpFormat => stream.http.format = value,
+                    // OUT OF RANGE VALUES ARE LEFT TO THE VALIDATION
+                    Data::HttpBatchSize => stream.http.batch_size = value.trim().parse().unwrap_or(0),
+                    Data::HttpBatchInterval => stream.http.batch_interval = value.trim().parse().unwrap_or(0),
+                    Data::SyslogHost => stream.syslog.host = value.trim().to_string(),
+                    Data::SyslogPort => stream.syslog.port = value.trim().parse().unwrap_or(0),
+                    Data::SyslogProtocol => stream.syslog.protocol = value,
+                }
+            }
+            Msg::ToggleCategory(category) => {
+                let key = category.key().to_string();
+                if stream.filters.contains(&key) {
+                    stream.filters.retain(|filter| *filter != key);
+                } else {
+                    stream.filters.push(key);
+                }
+            }
+            Msg::ClearCategories => stream.filters = vec![],
+        }
+        self.props.on_change.emit(stream);
+        false
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        let stream = &self.props.stream;
+        html! {
+            <>
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Stream Name"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="text"
+                            class="form-control bg-input-grey"
+                            placeholder="siem-production"
+                            value=stream.name.clone()
+                            disabled=self.props.disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::Name))
+                        />
+                    </div>
+                    <p
+                        class="text-color-disabled"
+                    >
+                        {"A name to recognize the stream, at most 50 characters."}
+                    </p>
+                </div>
+
+                {
+                    if stream.stream_type == LOG_STREAM_SYSLOG {
+                        self.view_syslog()
+                    } else {
+                        self.view_http()
+                    }
+                }
+
+                { self.view_filters() }
+            </>
+        }
+    }
+}
+
+impl LogStreamForm {
+    fn view_http(&self) -> Html {
+        let http = &self.props.stream.http;
+        let disabled = self.props.disabled;
+        html! {
+            <>
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Payload URL"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="text"
+                            class="form-control"
+                            placeholder="https://siem.example.com/ingest"
+                            value=http.url.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::HttpUrl))
+                        />
+                    </div>
+                    <p
+                        class="text-color-disabled"
+                    >
+                        {"Batches are posted to this URL. It must use https://, except for a collector on localhost."}
+                    </p>
+                </div>
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Authorization Token"}
+                    </p>
+                    <div class="input-group mb-2">
+                        <input
+                            type="password"
+                            class="form-control"
+                            autocomplete="new-password"
+                            placeholder="Bearer eyJ..."
+                            value=http.authorization.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::HttpAuthorization))
+                        />
+                    </div>
+                    <p
+                        class="text-color-disabled"
+                    >
+                        {"Optional, sent as the Authorization header of every request."}
+                    </p>
+                </div>
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Content Format"}
+                    </p>
+                    <select
+                        class="form-select"
+                        disabled=disabled
+                        onchange=self.link.callback(|e| {
+                            if let ChangeData::Select(select) = e {
+                                Msg::Input(select.value(), Data::HttpFormat)
+                            } else {
+                                Msg::Input(String::from(""), Data::HttpFormat)
+                            }
+                        })
+                    >
+                        {
+                            for HTTP_FORMATS.iter().map(|(value, label, content_type)| html! {
+                                <option value=*value selected={ http.format == *value }>
+                                    { format!("{} ({})", label, content_type) }
+                                </option>
+                            })
+                        }
+                    </select>
+                    <p
+                        class="text-color-disabled mt-2"
+                    >
+                        {"A JSON array holds the whole batch, JSON lines puts one event per line."}
+                    </p>
+                </div>
+
+                <div
+                    class="row mb-4"
+                >
+                    <div
+                        class="col"
+                    >
+                        <p class="mb-2 fw-bold">
+                            {"Batch Size"}
+                        </p>
+                        <input
+                            type="number"
+                            class="form-control"
+                            min="1"
+                            max=MAX_BATCH_SIZE.to_string()
+                            value=http.batch_size.to_string()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::HttpBatchSize))
+                        />
+                        <p
+                            class="text-color-disabled mt-2"
+                        >
+                            { format!("Events per request, up to {}.", MAX_BATCH_SIZE) }
+                        </p>
+                    </div>
+                    <div
+                        class="col"
+                    >
+                        <p class="mb-2 fw-bold">
+                            {"Batch Interval"}
+                        </p>
+                        <div class="input-group">
+                            <input
+                                type="number"
+                                class="form-control"
+                                min="1"
+                                max=MAX_BATCH_INTERVAL.to_string()
+                                value=http.batch_interval.to_string()
+                                disabled=disabled
+                                oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::HttpBatchInterval))
+                            />
+                            <span class="input-group-text">{"seconds"}</span>
+                        </div>
+                        <p
+                            class="text-color-disabled mt-2"
+                        >
+                            {"A batch that isn't full is sent after this delay."}
+                        </p>
+                    </div>
+                </div>
+            </>
+        }
+    }
+
+    fn view_syslog(&self) -> Html {
+        let syslog = &self.props.stream.syslog;
+        let disabled = self.props.disabled;
+        html! {
+            <>
+                <div
+                    class="row mb-4"
+                >
+                    <div
+                        class="col-8"
+                    >
+                        <p class="mb-2 fw-bold">
+                            {"Host"}
+                        </p>
+                        <input
+                            type="text"
+                            class="form-control"
+                            placeholder="logs.example.com"
+                            value=syslog.host.clone()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::SyslogHost))
+                        />
+                    </div>
+                    <div
+                        class="col-4"
+                    >
+                        <p class="mb-2 fw-bold">
+                            {"Port"}
+                        </p>
+                        <input
+                            type="number"
+                            class="form-control"
+                            min="1"
+                            max="65535"
+                            value=syslog.port.to_string()
+                            disabled=disabled
+                            oninput=self.link.callback(|data: InputData| Msg::Input(data.value, Data::SyslogPort))
+                        />
+                    </div>
+                </div>
+
+                <div
+                    class="mb-4"
+                >
+                    <p class="mb-2 fw-bold">
+                        {"Protocol"}
+                    </p>
+                    <select
+                        class="form-select"
+                        disabled=disabled
+                        onchange=self.link.callback(|e| {
+                            if let ChangeData::Select(select) = e {
+                                Msg::Input(select.value(), Data::SyslogProtocol)
+                            } else {
+                                Msg::Input(String::from(""), Data::SyslogProtocol)
+                            }
+                        })
+                    >
+                        {
+                            for SYSLOG_PROTOCOLS.iter().map(|(value, label)| html! {
+                                <option value=*value selected={ syslog.protocol == *value }>{ *label }</option>
+                            })
+                        }
+                    </select>
+                    <p
+                        class="text-color-disabled mt-2"
+                    >
+                        {"Every event is sent as one RFC 5424 message, with the log event as JSON."}
+                    </p>
+                </div>
+            </>
+        }
+    }
+
+    fn view_filters(&self) -> Html {
+        let filters = &self.props.stream.filters;
+        let disabled = self.props.disabled;
+        html! {
+            <div
+                class="mb-4"
+            >
+                <div class="d-flex align-items-center mb-2">
+                    <p class="mb-0 fw-bold flex-fill">
+                        {"Filter by Event Category"}
+                    </p>
+                    {
+                        if filters.is_empty() {
+                            html! {}
+                        } else {
+                            html! {
+                                <button
+                                    type="button"
+                                    class="btn btn-sm btn-link text-decoration-none"
+                                    disabled=disabled
+                                    onclick=self.link.callback(|_| Msg::ClearCategories)
+                                >
+                                    {"Send all events"}
+                                </button>
+                            }
+                        }
+                    }
+                </div>
+                <div>
+                    {
+                        for LogCategory::ALL.iter().map(|category| {
+                            let category = *category;
+                            html! {
+                                <div
+                                    class="form-check mb-2 d-flex align-items-center d-inline-flex"
+                                    style="width: 49%;"
+                                    title=category.codes().join(", ")
+                                >
+                                    <input
+                                        class="form-check-input me-2 mt-0"
+                                        style="font-size: 16px;"
+                                        type="checkbox"
+                                        checked=filters.iter().any(|filter| filter == category.key())
+                                        disabled=disabled
+                                        onclick=self.link.callback(move |_| Msg::ToggleCategory(category))
+                                    />
+                                    <label class="form-check-label" style="font-size: 14px;">
+                                        { category.label() }
+                                    </label>
+                                </div>
+                            }
+                        })
+                    }
+                </div>
+                <p
+                    class="text-color-disabled mt-2"
+                >
+                    {
+                        match filters.len() {
+                            0 => String::from("No category selected, every log event is sent."),
+                            1 => String::from("Only the events of the selected category are sent."),
+                            count => format!("Only the events of the {} selected categories are sent.", count),
+                        }
+                    }
+                </p>
+            </div>
+        }
+    }
+}
@@ -0,0 +1,836 @@
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        ConsoleService,
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+    },
+    agent::Bridged,
+    Bridge,
+};
+use yew_router::{
+    agent::RouteRequest::ChangeRoute,
+    components::RouterAnchor,
+    prelude::*,
+};
+use router::AppRoute;
+use configs::server::API_URL;
+use types::{
+    log_streams::{
+        log_stream_type_icon,
+        log_stream_type_label,
+        validate_log_stream,
+        validate_log_stream_test_target,
+        LogStream,
+        LogStreamStatusUpdate,
+        LogStreamTest,
+        LogStreamTestResult,
+        LogStreamUpdate,
+        LOG_STREAM_ACTIVE,
+        LOG_STREAM_PAUSED,
+        LOG_STREAM_SUSPENDED,
+        LOG_STREAM_SYSLOG,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use loading::Loading;
+use log_stream_form::LogStreamForm;
+
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct LogStreamSettingsProps {
+    pub tenant_id: String,
+    pub stream_id: String,
+}
+
+pub enum StateError {
+    RequestStream,
+    Update,
+    UpdateStatus,
+    Test,
+    Delete,
+}
+
+pub struct LogStreamSettings {
+    access_token: String,
+    link: ComponentLink<Self>,
+    tenant_id: String,
+    stream_id: String,
+    stream: LogStream,
+    fetch_task: Option<FetchTask>,
+    // A TEST CAN RUN WHILE THE SETTINGS ARE SAVED
+    fetch_task_test: Option<FetchTask>,
+    loading_request_stream: bool,
+    error_request_stream: Option<String>,
+    loading_update: bool,
+    error_update: Option<String>,
+    message_update: Option<String>,
+    loading_update_status: bool,
+    error_update_status: Option<String>,
+    test_target: String,
+    test_result: Option<LogStreamTestResult>,
+    loading_test: bool,
+    error_test: Option<String>,
+    loading_delete: bool,
+    error_delete: Option<String>,
+    route_agent: Box<dyn Bridge<RouteAgent>>,
+}
+
+pub enum Msg {
+    RequestStream,
+    GetStream(LogStream),
+    InputStream(LogStream),
+    Update,
+    GetUpdatedStream(LogStream),
+    UpdateStatus(String),
+    GetStatus(LogStream),
+    InputTestTarget(String),
+    SendTest,
+    GetTestResult(LogStreamTestResult),
+    Delete,
+    RedirectToStreams,
+    ResponseError(String, StateError),
+    Ignore,
+}
+
+impl Component for LogStreamSettings {
+    type Message = Msg;
+    type Properties = LogStreamSettingsProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        LogStreamSettings {
+            access_token,
+            tenant_id: props.tenant_id,
+            stream_id: props.stream_id,
+            stream: LogStream::new(""),
+            fetch_task: None,
+            fetch_task_test: None,
+            loading_request_stream: false,
+            error_request_stream: None,
+            loading_update: false,
+            error_update: None,
+            message_update: None,
+            loading_update_status: false,
+            error_update_status: None,
+            test_target: String::from(""),
+            test_result: None,
+            loading_test: false,
+            error_test: None,
+            loading_delete: false,
+            error_delete: None,
+            route_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            link,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestStream);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestStream => {
+                let request = Request::get(format!("{}/api/v2/log-streams/{}", API_URL, self.stream_id))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<LogStream, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetStream(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::RequestStream)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_stream = None;
+                self.loading_request_stream = true;
+                true
+            }
+            Msg::GetStream(data) => {
+                self.stream = data;
+                self.loading_request_stream = false;
+                self.fetch_task = None;
+                true
+            }
+            Msg::InputStream(stream) => {
+                self.stream = stream;
+                self.message_update = None;
+                true
+            }
+            Msg::Update => {
+                // VALIDATION
+                if let Err(message) = validate_log_stream(&self.stream) {
+                    self.link.send_message(Msg::ResponseError(message, StateError::Update));
+                    return false;
+                }
+
+                let data_update_stream = LogStreamUpdate::from(&self.stream);
+                let request = Request::patch(format!("{}/api/v2/log-streams/{}", API_URL, self.stream_id))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_update_stream))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<LogStream, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetUpdatedStream(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::Update)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_update = None;
+                self.message_update = None;
+                self.loading_update = true;
+                true
+            }
+            Msg::GetUpdatedStream(data) => {
+                ConsoleService::info(&format!("log stream = {:?}", data));
+                self.stream = data;
+                self.loading_update = false;
+                self.message_update = Some(String::from("Log stream settings have been saved"));
+                self.fetch_task = None;
+                true
+            }
+            Msg::UpdateStatus(status) => {
+                let data_status = LogStreamStatusUpdate { status };
+                let request = Request::patch(format!("{}/api/v2/log-streams/{}", API_URL, self.stream_id))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_status))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<LogStream, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetStatus(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::UpdateStatus)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_update_status = None;
+                self.loading_update_status = true;
+                true
+            }
+            Msg::GetStatus(data) => {
+                // SETTINGS BEING EDITED ARE KEPT
+                self.stream.status = data.status;
+                self.stream.health = data.health;
+                self.loading_update_status = false;
+                self.fetch_task = None;
+                true
+            }
+            Msg::InputTestTarget(value) => {
+                self.test_target = value.trim().to_string();
+                self.error_test = None;
+                true
+            }
+            Msg::SendTest => {
+                // VALIDATION
+                if let Err(message) = validate_log_stream_test_target(&self.stream.stream_type, &self.test_target) {
+                    self.link.send_message(Msg::ResponseError(message, StateError::Test));
+                    return false;
+                }
+
+                let data_test = LogStreamTest { target: self.test_target.clone() };
+                let request = Request::post(format!("{}/api/v2/log-streams/{}/test", API_URL, self.stream_id))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_test))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<LogStreamTestResult, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetTestResult(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::Test)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task_test = Some(task);
+                self.error_test = None;
+                self.test_result = None;
+                self.loading_test = true;
+                true
+            }
+            Msg::GetTestResult(data) => {
+                self.test_result = Some(data);
+                self.loading_test = false;
+                self.fetch_task_test = None;
+                true
+            }
+            Msg::Delete => {
+                let request = Request::delete(format!("{}/api/v2/log-streams/{}", API_URL, self.stream_id))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(|response: Response<Json<Result<(), anyhow::Error>>>| {
+                    let (meta, Json(data)) = response.into_parts();
+                    let status_number = meta.status.as_u16();
+
+                    match status_number {
+                        204 => {
+                            Msg::RedirectToStreams
+                        }
+                        _ => {
+                            match data {
+                                Ok(_) => {
+                                    Msg::RedirectToStreams
+                                }
+                                Err(error) => {
+                                    Msg::ResponseError(error.to_string(), StateError::Delete)
+                                }
+                            }
+                        }
+                    }
+                });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.loading_delete = true;
+                self.error_delete = None;
+                self.fetch_task = Some(task);
+                true
+            }
+            Msg::RedirectToStreams => {
+                self.loading_delete = false;
+                self.fetch_task = None;
+                self.route_agent.send(ChangeRoute(AppRoute::LogStreamsHome { tenant_id: self.tenant_id.clone() }.into()));
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::RequestStream => {
+                        self.loading_request_stream = false;
+                        self.error_request_stream = Some(message);
+                    }
+                    StateError::Update => {
+                        self.loading_update = false;
+                        self.error_update = Some(message);
+                    }
+                    StateError::UpdateStatus => {
+                        self.loading_update_status = false;
+                        self.error_update_status = Some(message);
+                    }
+                    StateError::Test => {
+                        self.loading_test = false;
+                        self.error_test = Some(message);
+                        self.fetch_task_test = None;
+                        return true;
+                    }
+                    StateError::Delete => {
+                        self.loading_delete = false;
+                        self.error_delete = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => {
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        self.tenant_id = props.tenant_id;
+        if self.stream_id != props.stream_id {
+            self.stream_id = props.stream_id;
+            self.test_target = String::from("");
+            self.test_result = None;
+            self.message_update = None;
+            self.link.send_message(Msg::RequestStream);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        html! {
+            <div
+                class="domain-content"
+            >
+                <Anchor
+                    route=AppRoute::LogStreamsHome { tenant_id: self.tenant_id.clone() }
+                    classes="text-decoration-none domain-link-dark"
+                >
+                    <i class="bi bi-arrow-left me-2"></i>
+                    {"Back to Log Streams"}
+                </Anchor>
+
+                {
+                    if self.loading_request_stream {
+                        html! {
+                            <div
+                                style="
+                                    position: relative;
+                                    margin-top: 8rem;
+                                "
+                            >
+                                <Loading width=45 />
+                            </div>
+                        }
+                    } else if self.error_request_stream.is_some() {
+                        html! {
+                            <div class="alert alert-warning mt-4 mb-5" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_request_stream.clone().unwrap() }
+                            </div>
+                        }
+                    } else if self.stream.id.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <>
+                                { self.view_header() }
+                                { self.view_health() }
+                                { self.view_settings() }
+                                { self.view_test() }
+                                { self.view_danger_zone() }
+                            </>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+}
+
+impl LogStreamSettings {
+    fn view_header(&self) -> Html {
+        html! {
+            <div
+                class="d-flex mb-5 mt-3 align-items-center"
+            >
+                <div
+                    style="flex: 0 0 auto; width: 64px; height: 64px;"
+                    class="d-flex justify-content-center align-items-center rounded me-3 border"
+                >
+                    <i class=classes!("bi", log_stream_type_icon(&self.stream.stream_type)) style="font-size: 32px;"></i>
+                </div>
+
+                <div
+                    class="d-flex flex-column"
+                >
+                    <h2
+                        class="title"
+                    >{ self.stream.name.clone() }</h2>
+                    <div class="text-muted" style="font-size: 14px;">
+                        { log_stream_type_label(&self.stream.stream_type) }
+                        <span class="ms-3">{"ID "}<code>{ self.stream.id.clone() }</code></span>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    fn view_health(&self) -> Html {
+        let health = &self.stream.health;
+        let status = self.stream.health_status();
+        let (action, next_status) = if self.stream.status == LOG_STREAM_ACTIVE {
+            ("Pause", LOG_STREAM_PAUSED)
+        } else {
+            ("Resume", LOG_STREAM_ACTIVE)
+        };
+        html! {
+            <div
+                class="container border rounded p-4 d-flex flex-column mb-5"
+                style="font-size: 14px;"
+            >
+                <div class="d-flex align-items-center mb-3">
+                    <i class=classes!("bi", status.icon(), status.color(), "me-2") style="font-size: 20px;"></i>
+                    <span class=classes!("fw-bold", "flex-fill", status.color()) style="font-size: 16px;">{ status.label() }</span>
+                    <button
+                        type="button"
+                        class="btn btn-sm btn-outline-secondary me-2"
+                        title="Reload the health status"
+                        onclick=self.link.callback(|_| Msg::RequestStream)
+                        disabled=self.loading_update_status
+                    >
+                        <i class="bi bi-arrow-clockwise"></i>
+                    </button>
+                    <button
+                        type="button"
+                        class=format!("btn btn-sm {} btn-outline-primary position-relative", if self.loading_update_status {"loading"} else {""} )
+                        onclick=self.link.callback(move |_| Msg::UpdateStatus(String::from(next_status)))
+                        disabled=self.loading_update_status
+                    >
+                        <div class="telkom-label">
+                            { action }
+                        </div>
+                        <div class="telkom-spinner telkom-center">
+                            <div class="spinner-border spinner-border-sm" role="status"/>
+                        </div>
+                    </button>
+                </div>
+                {
+                    if self.stream.status == LOG_STREAM_SUSPENDED {
+                        html! {
+                            <div class="alert alert-danger" role="alert">
+                                <i class="bi bi-x-octagon me-2"></i>
+                                {"The stream was suspended after too many failed deliveries. Fix the destination, send a test event, then resume it."}
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+                <div class="row">
+                    <div class="col">
+                        <p class="mb-1 text-muted">{"Delivered"}</p>
+                        <p class="mb-0 fw-bold">{ health.delivered }</p>
+                    </div>
+                    <div class="col">
+                        <p class="mb-1 text-muted">{"Failed"}</p>
+                        <p class=classes!("mb-0", "fw-bold", if health.failed > 0 { "text-danger" } else { "" })>{ health.failed }</p>
+                    </div>
+                    <div class="col">
+                        <p class="mb-1 text-muted">{"Last delivery"}</p>
+                        <p class="mb-0 fw-bold" title=health.last_delivery_at.clone()>
+                            {
+                                if health.last_delivery_at.is_empty() {
+                                    String::from("Never")
+                                } else {
+                                    time_ago(&health.last_delivery_at)
+                                }
+                            }
+                        </p>
+                    </div>
+                </div>
+                {
+                    if health.last_error.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <div class="alert alert-warning mt-3 mb-0" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { format!("{} ({})", health.last_error, time_ago(&health.last_error_at)) }
+                                {
+                                    if health.consecutive_failures > 1 {
+                                        html! { <span class="ms-1">{ format!(", {} times in a row", health.consecutive_failures) }</span> }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                            </div>
+                        }
+                    }
+                }
+                {
+                    if let Some(message) = &self.error_update_status {
+                        html! {
+                            <div class="alert alert-warning mt-3 mb-0" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { message.clone() }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+        }
+    }
+
+    fn view_settings(&self) -> Html {
+        html! {
+            <div
+                class="container border rounded p-4 d-flex flex-column mb-5"
+                style="font-size: 14px;"
+            >
+                <div
+                    class="row"
+                >
+                    <div
+                        class="col-lg-5 text-color-primary fw-bold mb-4"
+                        style="width: 40%;"
+                    >
+                        {"Settings"}
+                    </div>
+                    <div
+                        class="col-lg-7"
+                    >
+                        <LogStreamForm
+                            stream=self.stream.clone()
+                            disabled=self.loading_update
+                            on_change=self.link.callback(Msg::InputStream)
+                        />
+
+                        <button
+                            type="button"
+                            class=format!("btn {} btn-primary position-relative mb-4 mt-3", if self.loading_update {"loading"} else {""} )
+                            onclick=self.link.callback(|_| Msg::Update)
+                            disabled={ self.loading_update }
+                        >
+                            <div class="telkom-label">
+                                {"Save Changes"}
+                            </div>
+                            <div class="telkom-spinner telkom-center">
+                                <div class="spinner-border spinner-border-sm" role="status"/>
+                            </div>
+                        </button>
+                        {
+                            if self.error_update.is_some() {
+                                html! {
+                                    <div class="alert alert-warning mb-4" role="alert">
+                                        <i class="bi bi-exclamation-triangle me-2"></i>
+                                        { self.error_update.clone().unwrap() }
+                                    </div>
+                                }
+                            } else if self.message_update.is_some() {
+                                html! {
+                                    <div class="alert alert-success mb-4" role="alert">
+                                        <i class="bi bi-check-circle me-2"></i>
+                                        { self.message_update.clone().unwrap() }
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    fn view_test(&self) -> Html {
+        let placeholder = if self.stream.stream_type == LOG_STREAM_SYSLOG {
+            "127.0.0.1:5514"
+        } else {
+            "http://127.0.0.1:8080/log-sink"
+        };
+        html! {
+            <div
+                class="container border rounded p-4 d-flex flex-column mb-5"
+                style="font-size: 14px;"
+            >
+                <div
+                    class="row"
+                >
+                    <div
+                        class="col-lg-5 mb-4"
+                        style="width: 40%;"
+                    >
+                        <p class="text-color-primary fw-bold">{"Send Test Event"}</p>
+                        <p class="text-muted">
+                            {"Sends one sample log event in the stream's format, without waiting for a batch. It is not counted in the health status."}
+                        </p>
+                    </div>
+                    <div
+                        class="col-lg-7"
+                    >
+                        <p class="mb-2 fw-bold">
+                            {"Send To"}
+                        </p>
+                        <div class="input-group mb-2">
+                            <input
+                                type="text"
+                                class="form-control"
+                                placeholder=self.stream.destination()
+                                value=self.test_target.clone()
+                                disabled=self.loading_test
+                                oninput=self.link.callback(|data: InputData| Msg::InputTestTarget(data.value))
+                            />
+                            <button
+                                type="button"
+                                class=format!("btn {} btn-outline-primary position-relative", if self.loading_test {"loading"} else {""} )
+                                onclick=self.link.callback(|_| Msg::SendTest)
+                                disabled=self.loading_test
+                            >
+                                <div class="telkom-label">
+                                    <i class="bi bi-send me-2"></i>
+                                    {"Send"}
+                                </div>
+                                <div class="telkom-spinner telkom-center">
+                                    <div class="spinner-border spinner-border-sm" role="status"/>
+                                </div>
+                            </button>
+                        </div>
+                        <p
+                            class="text-color-disabled"
+                        >
+                            { format!("Leave empty to use the stream's destination, or try a local listener first, eg. {}", placeholder) }
+                        </p>
+                        {
+                            if let Some(message) = &self.error_test {
+                                html! {
+                                    <div class="alert alert-warning" role="alert">
+                                        <i class="bi bi-exclamation-triangle me-2"></i>
+                                        { message.clone() }
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                        { self.view_test_result() }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
+    fn view_test_result(&self) -> Html {
+        let result = match &self.test_result {
+            Some(result) => result,
+            None => return html! {},
+        };
+        html! {
+            <div class="border rounded p-3">
+                <div class="d-flex align-items-center mb-2">
+                    {
+                        if result.delivered {
+                            html! { <span class="text-success fw-bold flex-fill"><i class="bi bi-check-circle me-2"></i>{"Delivered"}</span> }
+                        } else {
+                            html! { <span class="text-danger fw-bold flex-fill"><i class="bi bi-x-circle me-2"></i>{"Not delivered"}</span> }
+                        }
+                    }
+                    <span class="text-muted">{ format!("{} ms", result.duration_ms) }</span>
+                </div>
+                <p class="mb-2 text-muted text-break">
+                    { result.target.clone() }
+                    {
+                        match result.status {
+                            Some(status) => html! { <span class="badge bg-light text-dark ms-2">{ format!("HTTP {}", status) }</span> },
+                            None => html! {},
+                        }
+                    }
+                </p>
+                {
+                    if let Some(error) = &result.error {
+                        html! { <p class="text-danger mb-2">{ error.clone() }</p> }
+                    } else {
+                        html! {}
+                    }
+                }
+                <p class="mb-1 fw-bold">{"Payload"}</p>
+                <pre class="bg-light rounded p-2 mb-2" style="max-height: 240px; overflow: auto; white-space: pre-wrap; word-break: break-all;">
+                    { result.payload.clone() }
+                </pre>
+                {
+                    if result.response.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <>
+                                <p class="mb-1 fw-bold">{"Response"}</p>
+                                <pre class="bg-light rounded p-2 mb-0" style="max-height: 160px; overflow: auto; white-space: pre-wrap; word-break: break-all;">
+                                    { result.response.clone() }
+                                </pre>
+                            </>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+
+    fn view_danger_zone(&self) -> Html {
+        html! {
+            <div
+                style="font-size: 14px;"
+            >
+                <p
+                    class="fw-bold"
+                >
+                    {"Danger Zone"}
+                </p>
+
+                <div class="alert alert-danger d-flex flex-row justify-content-between" role="alert">
+                    <div>
+                        <p
+                            class="fw-bold"
+                        >
+                            {"Delete Log Stream"}
+                        </p>
+                        {"Log events stop being sent at once. Once confirmed, this operation can't be undone!"}
+                    </div>
+                    <div>
+                        <button
+                            type="button"
+                            class=format!("btn {} btn-danger position-relative", if self.loading_delete {"loading"} else {""} )
+                            onclick=self.link.callback(|_| Msg::Delete)
+                            disabled={ self.loading_delete }
+                        >
+                            <div class="telkom-label">
+                                {"Delete"}
+                            </div>
+                            <div class="telkom-spinner telkom-center">
+                                <div class="spinner-border spinner-border-sm" role="status"/>
+                            </div>
+                        </button>
+                    </div>
+                </div>
+                {
+                    if self.error_delete.is_some() {
+                        html! {
+                            <div class="alert alert-warning" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_delete.clone().unwrap() }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+        }
+    }
+}
+
+fn time_ago(date: &str) -> String {
+    let time = js_sys::Date::parse(date);
+    if time.is_nan() {
+        return date.to_string();
+    }
+    let seconds = ((js_sys::Date::now() - time) / 1000.0).max(0.0) as u64;
+    let (value, unit) = match seconds {
+        0..=59 => return String::from("a few seconds ago"),
+        60..=3599 => (seconds / 60, "minute"),
+        3600..=86399 => (seconds / 3600, "hour"),
+        86400..=2_591_999 => (seconds / 86400, "day"),
+        2_592_000..=31_535_999 => (seconds / 2_592_000, "month"),
+        _ => (seconds / 31_536_000, "year"),
+    };
+    format!("{} {}{} ago", value, unit, if value == 1 { "" } else { "s" })
+}
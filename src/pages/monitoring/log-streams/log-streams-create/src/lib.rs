@@ -0,0 +1,295 @@
+use yew::{
+    prelude::*,
+    format::Json,
+    services::{
+        ConsoleService,
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{ Area, StorageService },
+    },
+    agent::Bridged,
+    Bridge,
+};
+use yew_router::{
+    agent::RouteRequest::ChangeRoute,
+    components::RouterAnchor,
+    prelude::*,
+};
+use router::AppRoute;
+use configs::server::API_URL;
+use types::{
+    log_streams::{
+        log_stream_type_icon,
+        validate_log_stream,
+        LogStream,
+        LogStreamCreate,
+        LOG_STREAM_HTTP,
+        LOG_STREAM_TYPES,
+    },
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use log_stream_form::LogStreamForm;
+
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct LogStreamsCreateProps {
+    pub tenant_id: String,
+}
+
+pub struct LogStreamsCreate {
+    access_token: String,
+    tenant_id: String,
+    link: ComponentLink<Self>,
+    new_stream: LogStream,
+    fetch_task: Option<FetchTask>,
+    loading_create_stream: bool,
+    error_create_stream: Option<String>,
+    route_agent: Box<dyn Bridge<RouteAgent>>,
+}
+
+pub enum Msg {
+    SelectType(&'static str),
+    InputStream(LogStream),
+    CreateStream,
+    RedirectToSettings(String),
+    ResponseError(String),
+    Ignore,
+}
+
+impl Component for LogStreamsCreate {
+    type Message = Msg;
+    type Properties = LogStreamsCreateProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        LogStreamsCreate {
+            access_token,
+            tenant_id: props.tenant_id,
+            new_stream: LogStream::new(LOG_STREAM_HTTP),
+            fetch_task: None,
+            loading_create_stream: false,
+            error_create_stream: None,
+            route_agent: RouteAgent::bridge(link.callback(|_| Msg::Ignore)),
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::SelectType(stream_type) => {
+                // THE SETTINGS OF THE OTHER TYPE ARE KEPT, ONLY THE SELECTED ONE IS SENT ALONG
+                self.new_stream.stream_type = String::from(stream_type);
+                self.error_create_stream = None;
+                true
+            }
+            Msg::InputStream(stream) => {
+                self.new_stream = stream;
+                true
+            }
+            Msg::CreateStream => {
+                // VALIDATION
+                if let Err(message) = validate_log_stream(&self.new_stream) {
+                    self.link.send_message(Msg::ResponseError(message));
+                    return false;
+                }
+
+                let data_create_stream = LogStreamCreate::from(&self.new_stream);
+                let request = Request::post(format!("{}/api/v2/log-streams", API_URL))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_create_stream))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<LogStream, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => {
+                                ConsoleService::info(&format!("{:?}", dataok));
+                                Msg::RedirectToSettings(dataok.id)
+                            }
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_create_stream = None;
+                self.loading_create_stream = true;
+                true
+            }
+            Msg::RedirectToSettings(stream_id) => {
+                self.loading_create_stream = false;
+                self.fetch_task = None;
+                self.route_agent.send(ChangeRoute(AppRoute::LogStreamSettings { tenant_id: self.tenant_id.clone(), stream_id }.into()));
+                true
+            }
+            Msg::ResponseError(message) => {
+                self.loading_create_stream = false;
+                self.error_create_stream = Some(message);
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => {
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.tenant_id != props.tenant_id {
+            self.tenant_id = props.tenant_id;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        html! {
+
+            <div
+                class="domain-content"
+            >
+                <Anchor
+                    route=AppRoute::LogStreamsHome { tenant_id: self.tenant_id.clone() }
+                    classes="text-decoration-none domain-link-dark"
+                >
+                    <i class="bi bi-arrow-left me-2"></i>
+                    {"Back to Log Streams"}
+                </Anchor>
+
+                <div
+                    class="d-flex mb-5 mt-3 align-items-center"
+                >
+                    <div
+                        style="flex: 0 0 auto; width: 64px; height: 64px;"
+                        class="d-flex justify-content-center align-items-center rounded me-3 border"
+                    >
+                        <i class=classes!("bi", log_stream_type_icon(&self.new_stream.stream_type)) style="font-size: 32px;"></i>
+                    </div>
+
+                    <div
+                        class="d-flex flex-column"
+                    >
+                        <h2
+                            class="title"
+                        >{"New Log Stream"}</h2>
+                    </div>
+                </div>
+
+                <div>
+                    <div
+                        class="container border rounded p-4 d-flex flex-column mb-5"
+                        style="font-size: 14px;"
+                    >
+                        <div
+                            class="row border-bottom"
+                        >
+                            <div
+                                class="col-lg-5 text-color-primary fw-bold mb-4"
+                            >
+                                {"Stream Type"}
+                            </div>
+                            <div
+                                class="col-lg-7 mb-4"
+                            >
+                                {
+                                    for LOG_STREAM_TYPES.iter().map(|(value, label, description)| {
+                                        let value: &'static str = value;
+                                        let selected = self.new_stream.stream_type == value;
+                                        html! {
+                                            <div
+                                                class=classes!("d-flex", "align-items-center", "border", "rounded", "p-3", "mb-2", if selected { "border-primary" } else { "" })
+                                                style="cursor: pointer;"
+                                                onclick=self.link.callback(move |_| Msg::SelectType(value))
+                                            >
+                                                <input
+                                                    class="form-check-input me-3 mt-0"
+                                                    type="radio"
+                                                    checked=selected
+                                                    disabled=self.loading_create_stream
+                                                />
+                                                <i class=classes!("bi", log_stream_type_icon(value), "me-3") style="font-size: 24px;"></i>
+                                                <div>
+                                                    <p class="mb-0 fw-bold">{ *label }</p>
+                                                    <p class="mb-0 text-muted">{ *description }</p>
+                                                </div>
+                                            </div>
+                                        }
+                                    })
+                                }
+                            </div>
+                        </div>
+
+                        <div
+                            class="row mt-5"
+                        >
+                            <div
+                                class="col-lg-5 text-color-primary fw-bold mb-4"
+                            >
+                                {"Settings"}
+                            </div>
+                            <div
+                                class="col-lg-7"
+                            >
+                                <LogStreamForm
+                                    stream=self.new_stream.clone()
+                                    disabled=self.loading_create_stream
+                                    on_change=self.link.callback(Msg::InputStream)
+                                />
+
+                                <button
+                                    type="button"
+                                    class=format!("btn {} btn-primary position-relative mb-5 mt-3", if self.loading_create_stream {"loading"} else {""} )
+                                    onclick=self.link.callback(|_| Msg::CreateStream)
+                                    disabled=self.loading_create_stream
+                                >
+                                    <div class="telkom-label">
+                                        {"Save"}
+                                    </div>
+                                    <div class="telkom-spinner telkom-center">
+                                        <div class="spinner-border spinner-border-sm" role="status"/>
+                                    </div>
+                                </button>
+                                {
+                                    if self.error_create_stream.is_some() {
+                                        html! {
+                                            <div class="alert alert-warning mb-5" role="alert">
+                                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                                { self.error_create_stream.clone().unwrap() }
+                                            </div>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+
+                            </div>
+                        </div>
+
+                    </div>
+                </div>
+
+            </div>
+        }
+    }
+}
@@ -0,0 +1,505 @@
+use yew::{
+    format::{Json, Nothing},
+    prelude::*,
+    services::{
+        ConsoleService,
+        fetch::{FetchService, FetchTask, Request, Response},
+        storage::{Area, StorageService},
+    },
+};
+use yew_router::components::RouterAnchor;
+use router::AppRoute;
+use types::{
+    log_streams::{
+        log_stream_type_icon,
+        log_stream_type_label,
+        LogStream,
+        LogStreamStatusUpdate,
+        LOG_STREAM_ACTIVE,
+        LOG_STREAM_PAUSED,
+    },
+    settings::TenantSettings,
+    LocalStorage,
+    LOCALSTORAGE_KEY,
+};
+use configs::server::API_URL;
+use loading::Loading;
+
+
+#[derive(Clone, Debug, Eq, PartialEq, Properties)]
+pub struct LogStreamsHomeProps {
+    pub tenant_id: String,
+}
+
+pub enum StateError {
+    RequestStreams,
+    UpdateStatus,
+}
+
+pub struct LogStreamsHome {
+    access_token: String,
+    tenant_id: String,
+    streams: Vec<LogStream>,
+    link: ComponentLink<Self>,
+    fetch_task: Option<FetchTask>,
+    fetch_task_settings: Option<FetchTask>,
+    loading_request_streams: bool,
+    error_request_streams: Option<String>,
+    // dashboard_log_streams_next, STREAMS CAN'T BE CREATED WHILE IT IS OFF
+    log_streams_enabled: bool,
+    // THE STREAM BEING PAUSED OR RESUMED
+    loading_update_status: Option<String>,
+    error_update_status: Option<String>,
+}
+
+pub enum Msg {
+    RequestStreams,
+    GetStreams(Vec<LogStream>),
+    RequestSettings,
+    GetLogStreamsFlag(bool),
+    UpdateStatus(String, String),
+    GetStream(LogStream),
+    ResponseError(String, StateError),
+    Ignore,
+}
+
+impl Component for LogStreamsHome {
+    type Message = Msg;
+    type Properties = LogStreamsHomeProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let storage = StorageService::new(Area::Local).expect("storage was disabled");
+        let localstorage_data = {
+            if let Json(Ok(data)) = storage.restore(LOCALSTORAGE_KEY) {
+                data
+            } else {
+                LocalStorage {
+                    username: None,
+                    email: None,
+                    token: None,
+                }
+            }
+        };
+
+        let mut access_token = String::from("");
+
+        if let Some(token) = localstorage_data.token {
+            access_token = token;
+        } else {}
+
+        LogStreamsHome {
+            access_token,
+            tenant_id: props.tenant_id,
+            streams: vec![],
+            link,
+            fetch_task: None,
+            fetch_task_settings: None,
+            loading_request_streams: false,
+            error_request_streams: None,
+            // UNTIL THE TENANT SETTINGS SAY OTHERWISE
+            log_streams_enabled: true,
+            loading_update_status: None,
+            error_update_status: None,
+        }
+    }
+
+    fn rendered(&mut self, first_render: bool) {
+        if first_render {
+            self.link.send_message(Msg::RequestStreams);
+            self.link.send_message(Msg::RequestSettings);
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::RequestStreams => {
+                let request = Request::get(format!("{}/api/v2/log-streams", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Vec<LogStream>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetStreams(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::RequestStreams)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_streams = None;
+                self.loading_request_streams = true;
+                true
+            }
+            Msg::GetStreams(data) => {
+                self.streams = data;
+                self.fetch_task = None;
+                self.loading_request_streams = false;
+                true
+            }
+            Msg::RequestSettings => {
+                let request = Request::get(format!("{}/tenant/v2/settings", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                // THE FLAG ONLY HIDES THE CREATE BUTTONS, THE LIST IS SHOWN WHEN IT CAN'T BE READ
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<TenantSettings, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetLogStreamsFlag(dataok.flags.dashboard_log_streams_next),
+                            Err(error) => {
+                                ConsoleService::error(&format!("tenant settings = {}", error));
+                                Msg::Ignore
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task_settings = Some(task);
+                false
+            }
+            Msg::GetLogStreamsFlag(enabled) => {
+                self.log_streams_enabled = enabled;
+                self.fetch_task_settings = None;
+                true
+            }
+            Msg::UpdateStatus(stream_id, status) => {
+                let data_status = LogStreamStatusUpdate { status };
+                let request = Request::patch(format!("{}/api/v2/log-streams/{}", API_URL, stream_id))
+                    .header("Content-Type", "application/json")
+                    .header("access_token", self.access_token.clone())
+                    .body(Json(&data_status))
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<LogStream, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetStream(dataok),
+                            Err(error) => {
+                                Msg::ResponseError(error.to_string(), StateError::UpdateStatus)
+                            }
+                        }
+                    },
+                );
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_update_status = None;
+                self.loading_update_status = Some(stream_id);
+                true
+            }
+            Msg::GetStream(data) => {
+                if let Some(stream) = self.streams.iter_mut().find(|stream| stream.id == data.id) {
+                    *stream = data;
+                }
+                self.loading_update_status = None;
+                self.fetch_task = None;
+                true
+            }
+            Msg::ResponseError(message, state) => {
+                match state {
+                    StateError::RequestStreams => {
+                        self.loading_request_streams = false;
+                        self.error_request_streams = Some(message);
+                    }
+                    StateError::UpdateStatus => {
+                        self.loading_update_status = None;
+                        self.error_update_status = Some(message);
+                    }
+                }
+                self.fetch_task = None;
+                true
+            }
+            Msg::Ignore => {
+                self.fetch_task_settings = None;
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.tenant_id != props.tenant_id {
+            self.tenant_id = props.tenant_id;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        html! {
+            <div
+                class="domain-content"
+            >
+                <div
+                    class="d-flex flex-row mb-4"
+                >
+                    <div
+                        class="flex-fill"
+                    >
+                        <h2>{"Log Streams"}</h2>
+                        <p class="text-muted" style="font-size: 14px;">
+                            {"Forward log events as they happen to a webhook or a syslog server, eg. your SIEM."}
+                        </p>
+                    </div>
+                    {
+                        if self.streams.is_empty() || !self.log_streams_enabled {
+                            html! {}
+                        } else {
+                            html! {
+                                <div>
+                                    <Anchor
+                                        route=AppRoute::LogStreamsCreate { tenant_id: self.tenant_id.clone() }
+                                        classes="btn btn-primary d-flex align-items-center"
+                                    >
+                                        <i class="bi bi-plus me-2" style="margin-left: -5px;"></i>
+                                        <span>{"Create Log Stream"}</span>
+                                    </Anchor>
+                                </div>
+                            }
+                        }
+                    }
+                </div>
+
+                {
+                    if self.log_streams_enabled {
+                        html! {}
+                    } else {
+                        html! {
+                            <div class="alert alert-info mb-4" role="alert" style="font-size: 14px;">
+                                <i class="bi bi-info-circle me-2"></i>
+                                {"Log streams are turned off for this tenant, existing streams keep running. Turn on Log Streams in "}
+                                <Anchor route=AppRoute::SettingsHome classes="alert-link">
+                                    {"the advanced tenant settings"}
+                                </Anchor>
+                                {" to create new ones."}
+                            </div>
+                        }
+                    }
+                }
+
+                {
+                    if let Some(message) = &self.error_update_status {
+                        html! {
+                            <div class="alert alert-warning mb-4" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { message.clone() }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+
+                {
+                    if self.loading_request_streams {
+                        html! {
+                            <div
+                                style="
+                                    position: relative;
+                                    margin-top: 8rem;
+                                "
+                            >
+                                <Loading width=45 />
+                            </div>
+                        }
+                    } else if self.error_request_streams.is_some() {
+                        html! {
+                            <div class="alert alert-warning mb-5" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_request_streams.clone().unwrap() }
+                            </div>
+                        }
+                    } else if self.streams.is_empty() {
+                        self.view_empty()
+                    } else {
+                        html! {
+                            <div>
+                                { self.view_list() }
+                            </div>
+                        }
+                    }
+                }
+            </div>
+        }
+    }
+}
+
+impl LogStreamsHome {
+    fn view_empty(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        html! {
+            <div style="
+                display: flex;
+                text-align: center;
+                align-items: center;
+                flex-direction: column;
+                padding: 40px;
+                border-radius: 6px;
+                border: 1px solid #e3e4e6;
+                "
+            >
+
+                <i class="bi bi-broadcast-pin text-color-secondary" style="font-size:150px; opacity:.5;"></i>
+
+                <div>
+                    {"No log streams yet. Create one to ship log events to your SIEM."}
+                </div>
+                {
+                    if self.log_streams_enabled {
+                        html! {
+                            <button
+                                class="btn btn-primary"
+                                style=" color: #fff;
+                                        background-color: #635dff;
+                                        box-shadow: none;
+                                        border-radius: 4px;
+                                        padding: 8px 16px;
+                                        margin: 20px"
+                            >
+                                <Anchor
+                                    route=AppRoute::LogStreamsCreate { tenant_id: self.tenant_id.clone() }
+                                    classes="text-decoration-none text-light px-2 link-primary pe-auto"
+                                    >
+                                        {"+ Create Log Stream"}
+                                </Anchor>
+                            </button>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+                <a
+                    href="https://auth0.com/docs/customize/log-streams"
+                    target="_blank"
+                    style="text-decoration: none;"
+                >{"Learn More"}</a>
+            </div>
+        }
+    }
+
+    fn view_list(&self) -> Vec<Html> {
+        type Anchor = RouterAnchor<AppRoute>;
+        self.streams.iter().map(|stream| {
+            let id = stream.id.clone();
+            let health = stream.health_status();
+            let loading = self.loading_update_status.as_ref() == Some(&stream.id);
+            // A SUSPENDED STREAM IS RESUMED LIKE A PAUSED ONE
+            let (action, next_status) = if stream.status == LOG_STREAM_ACTIVE {
+                ("Pause", LOG_STREAM_PAUSED)
+            } else {
+                ("Resume", LOG_STREAM_ACTIVE)
+            };
+            html! {
+                <div
+                    class="d-flex border-bottom border-1 list-hover"
+                >
+                    <div
+                        class="p-3 d-flex"
+                        style="width: 40%;"
+                    >
+                        <div
+                            style="flex: 0 0 auto; width: 40px; height: 40px;"
+                            class="d-flex justify-content-center align-items-center rounded me-3 border"
+                        >
+                            <i class=classes!("bi", log_stream_type_icon(&stream.stream_type)) style="font-size: 20px;"></i>
+                        </div>
+
+                        <div
+                            class="d-grid"
+                            style="min-width: 40px;"
+                        >
+                            <Anchor
+                                route=AppRoute::LogStreamSettings { tenant_id: self.tenant_id.clone(), stream_id: id.clone() }
+                                classes="text-decoration-none fw-bold mb-0"
+                            >
+                                <span
+                                    style="
+                                        white-space: nowrap;
+                                        text-overflow: ellipsis;
+                                        overflow: hidden;
+                                        font-size: 14px;
+                                        text-decoration: none;
+                                    "
+                                >
+                                    { stream.name.clone() }
+                                </span>
+                            </Anchor>
+                            <p
+                                class="mb-0 text-muted"
+                                style="
+                                    white-space: nowrap;
+                                    text-overflow: ellipsis;
+                                    overflow: hidden;
+                                    font-size: 14px;
+                                "
+                            >
+                                { format!("{} · {}", log_stream_type_label(&stream.stream_type), stream.destination()) }
+                            </p>
+                        </div>
+                    </div>
+
+                    <div
+                        class="p-3 d-flex flex-fill align-items-center"
+                        style="font-size: 14px;"
+                    >
+                        <i class=classes!("bi", health.icon(), health.color(), "me-2")></i>
+                        <span class=health.color()>{ health.label() }</span>
+                        <span class="text-muted ms-3">
+                            {
+                                match stream.filters.len() {
+                                    0 => String::from("All events"),
+                                    1 => String::from("1 category"),
+                                    count => format!("{} categories", count),
+                                }
+                            }
+                        </span>
+                    </div>
+
+                    <div
+                        class="p-3 d-flex align-items-center dropdown"
+                    >
+                        <button
+                            type="button"
+                            style="flex: 0 0 auto; width: 30px; height: 30px;"
+                            class="btn d-flex justify-content-center align-items-center rounded border"
+                            role="button"
+                            data-bs-toggle="dropdown"
+                            aria-expanded="false"
+                            disabled=loading
+                        >
+                            {
+                                if loading {
+                                    html! { <div class="spinner-border spinner-border-sm" role="status"/> }
+                                } else {
+                                    html! { <i class="bi bi-three-dots"></i> }
+                                }
+                            }
+                        </button>
+                        <ul class="dropdown-menu">
+                            <li>
+                                <Anchor route=AppRoute::LogStreamSettings { tenant_id: self.tenant_id.clone(), stream_id: id.clone() } classes="dropdown-item fs-7">
+                                    {"Settings"}
+                                </Anchor>
+                            </li>
+                            <li>
+                                <button
+                                    type="button"
+                                    class="dropdown-item fs-7"
+                                    onclick=self.link.callback(move |_| Msg::UpdateStatus(id.clone(), String::from(next_status)))
+                                >
+                                    { action }
+                                </button>
+                            </li>
+                        </ul>
+                    </div>
+                </div>
+            }
+        })
+        .collect()
+    }
+}
@@ -0,0 +1,141 @@
+use yew::prelude::*;
+use serde_json::Value;
+
+// COLLAPSIBLE JSON TREE, OBJECTS AND ARRAYS DEEPER THAN open_depth START CLOSED
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct JsonViewerProps {
+    pub value: Value,
+    #[prop_or(2)]
+    pub open_depth: usize,
+}
+
+pub struct JsonViewer {
+    props: JsonViewerProps,
+    link: ComponentLink<Self>,
+    // None KEEPS open_depth, Some(true) EXPANDS EVERYTHING, Some(false) COLLAPSES
+    expand_all: Option<bool>,
+    // BUMPED TO REBUILD THE TREE, <details> KEEP THEIR OWN OPEN STATE OTHERWISE
+    generation: u32,
+}
+
+pub enum Msg {
+    ExpandAll,
+    CollapseAll,
+}
+
+impl Component for JsonViewer {
+    type Message = Msg;
+    type Properties = JsonViewerProps;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        JsonViewer {
+            props,
+            link,
+            expand_all: None,
+            generation: 0,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::ExpandAll => self.expand_all = Some(true),
+            Msg::CollapseAll => self.expand_all = Some(false),
+        }
+        self.generation += 1;
+        true
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.props != props {
+            self.props = props;
+            self.expand_all = None;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div style="font-size: 14px; border-radius: 3px;">
+                <div class="d-flex justify-content-end mb-1">
+                    <button type="button" class="btn btn-sm btn-link text-decoration-none" onclick=self.link.callback(|_| Msg::ExpandAll)>
+                        {"Expand all"}
+                    </button>
+                    <button type="button" class="btn btn-sm btn-link text-decoration-none" onclick=self.link.callback(|_| Msg::CollapseAll)>
+                        {"Collapse all"}
+                    </button>
+                </div>
+                <div
+                    key=self.generation.to_string()
+                    class="p-3 text-light"
+                    style="
+                        background-color: rgb(47, 56, 61);
+                        max-height: 480px;
+                        overflow: auto;
+                        font-size: 14px;
+                        font-family: 'Roboto Mono', monospace;
+                        white-space: pre-wrap;
+                        word-break: break-all;
+                    "
+                >
+                    { self.view_value(None, &self.props.value, 0, true) }
+                </div>
+            </div>
+        }
+    }
+}
+
+impl JsonViewer {
+    fn is_open(&self, depth: usize) -> bool {
+        self.expand_all.unwrap_or(depth < self.props.open_depth)
+    }
+
+    fn view_value(&self, key: Option<&str>, value: &Value, depth: usize, last: bool) -> Html {
+        let comma = if last { "" } else { "," };
+        let label = match key {
+            Some(key) => html! { <><span class="code-number">{ format!("\"{}\"", key) }</span>{": "}</> },
+            None => html! {},
+        };
+        let (open, close, children): (&str, &str, Vec<(Option<&str>, &Value)>) = match value {
+            Value::Object(map) if !map.is_empty() => ("{", "}", map.iter().map(|(key, value)| (Some(key.as_str()), value)).collect()),
+            Value::Array(items) if !items.is_empty() => ("[", "]", items.iter().map(|value| (None, value)).collect()),
+            _ => {
+                return html! {
+                    <div>{ label }<span class=scalar_class(value)>{ value.to_string() }</span>{ comma }</div>
+                };
+            }
+        };
+        let count = children.len();
+        let summary = match value {
+            Value::Array(_) => format!("{} item{}", count, if count == 1 { "" } else { "s" }),
+            _ => format!("{} key{}", count, if count == 1 { "" } else { "s" }),
+        };
+        html! {
+            <details open=self.is_open(depth)>
+                <summary style="cursor: pointer;">
+                    { label }{ open }
+                    <span class="text-muted ms-1" style="font-size: 12px;">{ summary }</span>
+                </summary>
+                <div class="ms-3">
+                    {
+                        for children.iter().enumerate().map(|(index, (key, value))| {
+                            self.view_value(*key, value, depth + 1, index + 1 == count)
+                        })
+                    }
+                </div>
+                <div>{ close }{ comma }</div>
+            </details>
+        }
+    }
+}
+
+fn scalar_class(value: &Value) -> &'static str {
+    match value {
+        Value::String(_) => "text-warning",
+        Value::Number(_) => "text-info",
+        Value::Bool(_) => "text-success",
+        _ => "text-muted",
+    }
+}
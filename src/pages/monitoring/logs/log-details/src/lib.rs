@@ -1,5 +1,5 @@
 use yew::{
-    format::{Json, Nothing},
+    format::{Json, Nothing, Text},
     prelude::*,
     services::{
         fetch::{ FetchService, FetchTask, Request, Response },
@@ -9,8 +9,13 @@ use yew::{
 };
 use yew_router::components::RouterAnchor;
 use router::AppRoute;
+use serde_json::Value;
 use types::{
-    roles::Role,
+    database::DATABASE_STRATEGY,
+    enterprise::{AZURE_AD_STRATEGY, GOOGLE_APPS_STRATEGY, OIDC_STRATEGY, SAML_STRATEGY},
+    logs::log_event_type,
+    passwordless::{PASSWORDLESS_EMAIL_STRATEGY, PASSWORDLESS_SMS_STRATEGY},
+    users::UserLogDetails,
     LocalStorage,
     LOCALSTORAGE_KEY,
 };
@@ -18,6 +23,7 @@ use configs::server::API_URL;
 use loading::Loading;
 mod raw;
 mod context_data;
+mod json_viewer;
 use raw::Raw;
 use context_data::ContextData;
 
@@ -32,6 +38,7 @@ pub enum StateError {
     RequestLogDetails,
 }
 
+#[derive(Clone, Copy, PartialEq)]
 pub enum Content {
     Raw,
     ContextData,
@@ -43,7 +50,9 @@ pub struct LogDetails {
     tenant_id: String,
     log_id: String,
     fetch_task: Option<FetchTask>,
-    log: String,
+    // THE PAYLOAD AS RECEIVED FOR THE RAW TABS, PARSED FOR THE SUMMARY
+    log_raw: Value,
+    log: UserLogDetails,
     loading_request_log: bool,
     error_request_log: Option<String>,
     content: Content,
@@ -51,7 +60,7 @@ pub struct LogDetails {
 
 pub enum Msg {
     RequestLogDetails,
-    GetLogDetails(String),
+    GetLogDetails(Value),
     ChangeContent(Content),
     ResponseError(String, StateError),
 }
@@ -87,7 +96,8 @@ impl Component for LogDetails {
             tenant_id: props.tenant_id,
             log_id: props.log_id,
             fetch_task: None,
-            log: String::from("LOG"),
+            log_raw: Value::Null,
+            log: UserLogDetails::new(),
             loading_request_log: false,
             error_request_log: None,
             content: Content::Raw,
@@ -96,39 +106,55 @@ impl Component for LogDetails {
 
     fn rendered(&mut self, first_render: bool) {
         if first_render {
-            // self.link.send_message(Msg::RequestLogDetails);
+            self.link.send_message(Msg::RequestLogDetails);
         }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
             Msg::RequestLogDetails => {
-                // let request = Request::get(format!("{}/api/v2/roles/{}", API_URL, self.role_id.clone()))
-                //     .header("access_token", self.access_token.clone())
-                //     .body(Nothing)
-                //     .expect("Could not build request.");
-                // let callback =
-                //     self.link
-                //         .callback(|response: Response<Json<Result<String, anyhow::Error>>>| {
-                //             let Json(data) = response.into_body();
-                //             match data {
-                //                 Ok(dataok) => {
-                //                     Msg::GetLogDetails(dataok)
-                //                 }
-                //                 Err(error) => Msg::ResponseError(
-                //                     error.to_string(),
-                //                     StateError::RequestLogDetails,
-                //                 ),
-                //             }
-                //         });
-                // let task = FetchService::fetch(request, callback).expect("failed to start request");
-                // self.fetch_task = Some(task);
-                // self.error_request_log = None;
-                // self.loading_request_log = true;
+                let request = Request::get(format!("{}/api/v2/logs/{}", API_URL, self.log_id.clone()))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                // READ AS TEXT, FIELDS THE TYPED STRUCT DOES NOT KNOW STILL REACH THE RAW TAB
+                let callback =
+                    self.link
+                        .callback(|response: Response<Text>| {
+                            let (meta, body) = response.into_parts();
+                            let body = match body {
+                                Ok(body) => body,
+                                Err(error) => return Msg::ResponseError(error.to_string(), StateError::RequestLogDetails),
+                            };
+                            match serde_json::from_str::<Value>(&body) {
+                                Ok(data) if meta.status.is_success() => Msg::GetLogDetails(data),
+                                Ok(data) => Msg::ResponseError(
+                                    data.get("message")
+                                        .and_then(Value::as_str)
+                                        .map(String::from)
+                                        .unwrap_or_else(|| format!("The log could not be loaded ({})", meta.status)),
+                                    StateError::RequestLogDetails,
+                                ),
+                                Err(error) => Msg::ResponseError(error.to_string(), StateError::RequestLogDetails),
+                            }
+                        });
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                self.error_request_log = None;
+                self.loading_request_log = true;
                 true
             }
             Msg::GetLogDetails(data) => {
-                self.log = data;
+                match serde_json::from_value::<UserLogDetails>(data.clone()) {
+                    Ok(log) => {
+                        self.log = log;
+                        self.log_raw = data;
+                    }
+                    Err(error) => {
+                        ConsoleService::error(&format!("log {} = {:?}", self.log_id, data));
+                        self.error_request_log = Some(error.to_string());
+                    }
+                }
                 self.fetch_task = None;
                 self.loading_request_log = false;
                 true
@@ -150,8 +176,16 @@ impl Component for LogDetails {
         }
     }
 
-    fn change(&mut self, _: Self::Properties) -> ShouldRender {
-        false
+    // ANOTHER LOG OPENED FROM THE SAME PAGE, eg. WITH THE BROWSER BACK BUTTON
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        if self.log_id != props.log_id {
+            self.tenant_id = props.tenant_id;
+            self.log_id = props.log_id;
+            self.link.send_message(Msg::RequestLogDetails);
+            true
+        } else {
+            false
+        }
     }
 
     fn view(&self) -> Html {
@@ -204,6 +238,9 @@ impl Component for LogDetails {
 
 impl LogDetails {
     fn view_content(&self) -> Html {
+        type Anchor = RouterAnchor<AppRoute>;
+        let log = &self.log;
+        let event_type = log_event_type(&log.type_detail);
         html! {
             <>
                 <div class="card p-3 mt-3">
@@ -215,30 +252,100 @@ impl LogDetails {
                             <div class="row mt-3">
                                 <div class="col-4 col-md-4 col-lg-4">
                                     <p class="text-muted mb-1">{"Occured"}</p>
-                                    <p class="mb-1">{"4 hours ago"}</p>
-                                    <p class="mb-1">{"at 2022-01-31 12:35:50.341 UTC"}</p>
+                                    <p class="mb-1">{ time_ago(&log.date) }</p>
+                                    <p class="mb-1">{ format!("at {}", log.date) }</p>
                                 </div>
                                 <div class="col-4 col-md-4 col-lg-4">
                                     <p class="text-muted mb-1 ">{"Type"}</p>
-                                    <p class="mb-1">{"API Read Operation"}</p>
+                                    <p class="mb-1">
+                                        <i class=classes!("bi", "me-2", event_type.severity.icon(), event_type.severity.color())></i>
+                                        { if event_type.code.is_empty() { log.type_detail.clone() } else { event_type.name.to_string() } }
+                                    </p>
+                                    <p class="mb-1 text-muted" style="font-size: 13px;">
+                                        { format!("{} \u{00b7} {}", log.type_detail, event_type.category.label()) }
+                                    </p>
                                 </div>
                                 <div class="col-4 col-md-4 col-lg-4">
                                     <p class="text-muted mb-1">{"Description"}</p>
-                                    <p class="mb-1">{"Get a client"}</p>
+                                    <p class="mb-1">{ not_available(&log.description) }</p>
                                 </div>
                             </div>
                             <div class="row mt-3">
                                 <div class="col-4 col-md-4 col-lg-4">
                                     <p class="text-muted mb-1">{"Connection"}</p>
-                                    <p class="mb-1">{"N/A"}</p>
+                                    <p class="mb-1">
+                                        {
+                                            match connection_route(&log.strategy, &log.connection_id) {
+                                                Some(route) if !log.connection.is_empty() => html! {
+                                                    <Anchor route=route classes="text-decoration-none">{ log.connection.clone() }</Anchor>
+                                                },
+                                                _ => html! { not_available(&log.connection) },
+                                            }
+                                        }
+                                    </p>
                                 </div>
                                 <div class="col-4 col-md-4 col-lg-4 mb-1">
                                     <p class="text-muted mb-1">{"Application"}</p>
-                                    <p class="mb-1">{"MrlpRDQKGK9ENLgHd89jWTMkKvf0O7t9"}</p>
+                                    <p class="mb-1">
+                                        {
+                                            if log.client_id.is_empty() {
+                                                html! { "N/A" }
+                                            } else {
+                                                html! {
+                                                    <Anchor
+                                                        route=AppRoute::ApplicationSettings { tenant_id: self.tenant_id.clone(), app_id: log.client_id.clone() }
+                                                        classes="text-decoration-none"
+                                                    >
+                                                        { if log.client_name.is_empty() { log.client_id.clone() } else { log.client_name.clone() } }
+                                                    </Anchor>
+                                                }
+                                            }
+                                        }
+                                    </p>
                                 </div>
                                 <div class="col-4 col-md-4 col-lg-4">
                                     <p class="text-muted mb-1">{"User"}</p>
-                                    <p>{"N/A"}</p>
+                                    <p>
+                                        {
+                                            if log.user_id.is_empty() {
+                                                html! { "N/A" }
+                                            } else {
+                                                html! {
+                                                    <Anchor
+                                                        route=AppRoute::UserSettings { tenant_id: self.tenant_id.clone(), user_id: log.user_id.clone(), id: 1 }
+                                                        classes="text-decoration-none"
+                                                    >
+                                                        { if log.user_name.is_empty() { log.user_id.clone() } else { log.user_name.clone() } }
+                                                    </Anchor>
+                                                }
+                                            }
+                                        }
+                                    </p>
+                                </div>
+                            </div>
+                            <div class="row mt-3">
+                                <div class="col-4 col-md-4 col-lg-4">
+                                    <p class="text-muted mb-1">{"IP Address"}</p>
+                                    <p class="mb-1">{ not_available(&log.ip) }</p>
+                                </div>
+                                <div class="col-4 col-md-4 col-lg-4">
+                                    <p class="text-muted mb-1">{"Location"}</p>
+                                    <p class="mb-1">
+                                        {
+                                            not_available(
+                                                &[log.location_info.city_name.as_str(), log.location_info.country_name.as_str()]
+                                                    .iter()
+                                                    .filter(|part| !part.is_empty())
+                                                    .copied()
+                                                    .collect::<Vec<&str>>()
+                                                    .join(", ")
+                                            )
+                                        }
+                                    </p>
+                                </div>
+                                <div class="col-4 col-md-4 col-lg-4">
+                                    <p class="text-muted mb-1">{"Log ID"}</p>
+                                    <p class="mb-1 text-break" style="font-size: 13px;">{ log.log_id.clone() }</p>
                                 </div>
                             </div>
 
@@ -295,8 +402,8 @@ impl LogDetails {
 
                         {
                             match self.content {
-                                Content::Raw => html! {<Raw/>},
-                                Content::ContextData => html! {<ContextData/>},
+                                Content::Raw => html! {<Raw log=self.log_raw.clone() />},
+                                Content::ContextData => html! {<ContextData log=self.log_raw.clone() />},
                             }
                         }
                     </div>
@@ -305,3 +412,46 @@ impl LogDetails {
         }
     }
 }
+
+// LINKS TO THE SETTINGS PAGE OF THE CONNECTION, BY ITS STRATEGY
+fn connection_route(strategy: &str, connection_id: &str) -> Option<AppRoute> {
+    let connection_id = connection_id.to_string();
+    match strategy {
+        "" => None,
+        DATABASE_STRATEGY if !connection_id.is_empty() => Some(AppRoute::DatabaseSettings { connection_id }),
+        PASSWORDLESS_EMAIL_STRATEGY | PASSWORDLESS_SMS_STRATEGY => Some(AppRoute::AuthPasswordless),
+        GOOGLE_APPS_STRATEGY => Some(AppRoute::EnterpriseGoogle),
+        AZURE_AD_STRATEGY if !connection_id.is_empty() => Some(AppRoute::EnterpriseAzureSettings { connection_id }),
+        SAML_STRATEGY if !connection_id.is_empty() => Some(AppRoute::EnterpriseSamlSettings { connection_id }),
+        OIDC_STRATEGY if !connection_id.is_empty() => Some(AppRoute::EnterpriseOidcSettings { connection_id }),
+        // EVERY OTHER STRATEGY IS A SOCIAL PROVIDER
+        _ if !connection_id.is_empty() => Some(AppRoute::SocialSettings { connection_id }),
+        _ => None,
+    }
+}
+
+fn not_available(value: &str) -> String {
+    if value.is_empty() {
+        String::from("N/A")
+    } else {
+        value.to_string()
+    }
+}
+
+// "4 hours ago", THE DATE AS RECEIVED WHEN IT CAN NOT BE PARSED
+fn time_ago(date: &str) -> String {
+    let time = js_sys::Date::parse(date);
+    if time.is_nan() {
+        return date.to_string();
+    }
+    let seconds = ((js_sys::Date::now() - time) / 1000.0).max(0.0) as u64;
+    let (value, unit) = match seconds {
+        0..=59 => return String::from("a few seconds ago"),
+        60..=3599 => (seconds / 60, "minute"),
+        3600..=86399 => (seconds / 3600, "hour"),
+        86400..=2_591_999 => (seconds / 86400, "day"),
+        2_592_000..=31_535_999 => (seconds / 2_592_000, "month"),
+        _ => (seconds / 31_536_000, "year"),
+    };
+    format!("{} {}{} ago", value, unit, if value == 1 { "" } else { "s" })
+}
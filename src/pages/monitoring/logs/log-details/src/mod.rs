@@ -1,2 +1,3 @@
 pub mod raw;
-pub mod context_data;
\ No newline at end of file
+pub mod context_data;
+pub mod json_viewer;
\ No newline at end of file
@@ -0,0 +1,155 @@
+use paginated_table::query::query_params;
+
+// LOG SEARCH FILTERS, KEPT IN THE URL SO A SEARCH CAN BE SHARED
+// Every filter becomes one Lucene clause, the free text query is appended
+// as written, and all of them are ANDed into the q parameter of /api/v2/logs
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LogFilters {
+    // YYYY-MM-DD, BOTH ENDS INCLUDED
+    pub date_from: String,
+    pub date_to: String,
+    // ONE OR MORE TYPE CODES, COMMA SEPARATED
+    pub type_detail: String,
+    pub user_id: String,
+    pub client_id: String,
+    pub connection: String,
+    pub ip: String,
+    pub q: String,
+}
+
+// (url and lucene field, label, placeholder)
+pub const TEXT_FILTERS: [(&str, &str, &str); 4] = [
+    ("user_id", "User", "auth0|61fa7c1e5b2f"),
+    ("client_id", "Application", "Client ID"),
+    ("connection", "Connection", "Username-Password-Authentication"),
+    ("ip", "IP Address", "190.257.209.19"),
+];
+
+impl LogFilters {
+    pub fn from_query(query: &str) -> LogFilters {
+        let mut filters = LogFilters::default();
+        for (key, value) in query_params(query) {
+            filters.set(&key, value);
+        }
+        filters
+    }
+
+    pub fn get(&self, key: &str) -> &str {
+        match key {
+            "date_from" => &self.date_from,
+            "date_to" => &self.date_to,
+            "type_detail" => &self.type_detail,
+            "user_id" => &self.user_id,
+            "client_id" => &self.client_id,
+            "connection" => &self.connection,
+            "ip" => &self.ip,
+            "q" => &self.q,
+            _ => "",
+        }
+    }
+
+    pub fn set(&mut self, key: &str, value: String) {
+        let value = value.trim().to_string();
+        match key {
+            "date_from" => self.date_from = value,
+            "date_to" => self.date_to = value,
+            "type_detail" => self.type_detail = value,
+            "user_id" => self.user_id = value,
+            "client_id" => self.client_id = value,
+            "connection" => self.connection = value,
+            "ip" => self.ip = value,
+            "q" => self.q = value,
+            _ => {}
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == LogFilters::default()
+    }
+
+    // ONLY THE FILTERS IN USE ARE KEPT IN THE URL
+    pub fn url_params(&self) -> Vec<(String, String)> {
+        ["date_from", "date_to", "type_detail", "user_id", "client_id", "connection", "ip", "q"]
+            .iter()
+            .filter(|key| !self.get(key).is_empty())
+            .map(|key| (key.to_string(), self.get(key).to_string()))
+            .collect()
+    }
+
+    pub fn types(&self) -> Vec<String> {
+        self.type_detail
+            .split(',')
+            .map(|code| code.trim().to_string())
+            .filter(|code| !code.is_empty())
+            .collect()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for (label, date) in [("From", &self.date_from), ("To", &self.date_to)] {
+            if !date.is_empty() && !is_date(date) {
+                return Err(format!("{} date must be written as YYYY-MM-DD", label));
+            }
+        }
+        // ISO DATES COMPARE AS STRINGS
+        if !self.date_from.is_empty() && !self.date_to.is_empty() && self.date_from > self.date_to {
+            return Err(String::from("The From date is after the To date"));
+        }
+        if !self.ip.is_empty() && !self.ip.chars().all(|c| c.is_ascii_hexdigit() || c == '.' || c == ':' || c == '*') {
+            return Err(format!("{} is not an IP address", self.ip));
+        }
+        Ok(())
+    }
+
+    pub fn lucene(&self) -> String {
+        let mut clauses = Vec::new();
+        if !self.date_from.is_empty() || !self.date_to.is_empty() {
+            clauses.push(format!(
+                "date:[{} TO {}]",
+                if self.date_from.is_empty() { "*" } else { &self.date_from },
+                if self.date_to.is_empty() { "*" } else { &self.date_to },
+            ));
+        }
+        let types = self.types();
+        match types.len() {
+            0 => {}
+            1 => clauses.push(format!("type_detail:{}", quote(&types[0]))),
+            _ => {
+                let codes: Vec<String> = types.iter().map(|code| quote(code)).collect();
+                clauses.push(format!("type_detail:({})", codes.join(" OR ")));
+            }
+        }
+        for (field, _, _) in TEXT_FILTERS.iter() {
+            let value = self.get(field);
+            if !value.is_empty() {
+                // A TRAILING * KEEPS ITS MEANING, eg. ip:190.257.*
+                if value.ends_with('*') && !value.contains(char::is_whitespace) {
+                    clauses.push(format!("{}:{}", field, value));
+                } else {
+                    clauses.push(format!("{}:{}", field, quote(value)));
+                }
+            }
+        }
+        if !self.q.is_empty() {
+            if clauses.is_empty() {
+                clauses.push(self.q.clone());
+            } else {
+                clauses.push(format!("({})", self.q));
+            }
+        }
+        clauses.join(" AND ")
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn is_date(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+    parts.len() == 3
+        && parts[0].len() == 4
+        && parts[1].len() == 2
+        && parts[2].len() == 2
+        && parts.iter().all(|part| part.chars().all(|c| c.is_ascii_digit()))
+}
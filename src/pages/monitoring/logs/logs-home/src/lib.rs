@@ -4,21 +4,62 @@ use yew::{
     services::{
         ConsoleService,
         fetch::{FetchService, FetchTask, Request, Response},
-        storage::{ StorageService, Area }
+        storage::{ StorageService, Area },
+        timeout::{TimeoutService, TimeoutTask},
     },
 };
-use yew_router::components::RouterAnchor;
+use std::{
+    collections::{HashSet, VecDeque},
+    time::Duration,
+};
+use yew_router::{
+    components::RouterAnchor,
+    service::RouteService,
+};
 use router::AppRoute;
 use types::{
-    users::{ResponseUsersList, UserCreate, UserTitle},
-    ResponseMessage,
+    logs::{log_event_name, log_event_type, LogCategory, LOG_EVENT_TYPES},
+    users::UserLogDetails,
+    pagination::Page,
     LocalStorage,
     LOCALSTORAGE_KEY,
 };
 
 use loading::Loading;
-use developers_note::DevelopersNote;
 use configs::server::API_URL;
+use paginated_table::{
+    query::{
+        encode_uri_component,
+        replace_query,
+    },
+    Pagination,
+    Sort,
+};
+
+mod filters;
+mod stream;
+
+use filters::{LogFilters, TEXT_FILTERS};
+use stream::{LogStream, LogStreamTicket, StreamStatus};
+
+// THE NEXT PAGE IS REQUESTED WHEN THE LIST IS SCROLLED THIS CLOSE TO ITS END
+const SCROLL_THRESHOLD: i32 = 200;
+
+// LIVE MODE KEEPS AT MOST THIS MANY EVENTS, SHOWN AND PAUSED ALIKE
+const MAX_LIVE_EVENTS: usize = 500;
+
+// RECONNECT DELAYS DOUBLE UP TO THIS
+const MAX_RECONNECT_SECONDS: u64 = 30;
+
+// IDS REMEMBERED TO DROP REPLAYED EVENTS, THE OLDEST ARE FORGOTTEN FIRST
+const MAX_SEEN_LOG_IDS: usize = MAX_LIVE_EVENTS * 2;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LiveStatus {
+    Connecting,
+    Open,
+    Reconnecting(u64),
+}
 
 #[derive(Clone, Debug, Eq, PartialEq, Properties)]
 pub struct UserProps {
@@ -32,12 +73,59 @@ pub struct LogsHome {
     fetch_task: Option<FetchTask>,
     learn_more: bool,
     link: ComponentLink<Self>,
+    route_service: RouteService,
+    // APPLIED FILTERS AND THE ONES BEING EDITED
+    filters: LogFilters,
+    draft: LogFilters,
+    show_filters: bool,
+    error_filters: Option<String>,
+    pagination: Pagination,
+    logs: Vec<UserLogDetails>,
+    total_logs: u32,
+    // A SHORT PAGE MEANS THE OLDEST EVENT IS LISTED
+    end_of_logs: bool,
+    loading_logs: bool,
+    error_logs: Option<String>,
+    list_ref: NodeRef,
+    // LIVE TAIL
+    live: bool,
+    live_status: LiveStatus,
+    live_paused: bool,
+    // RECEIVED WHILE PAUSED, NEWEST FIRST
+    live_pending: Vec<UserLogDetails>,
+    live_dropped: u32,
+    // THE LIST IS CUT TO MAX_LIVE_EVENTS, THIS KEEPS COUNTING
+    live_received: u32,
+    ticket_task: Option<FetchTask>,
+    live_stream: Option<LogStream>,
+    reconnect_task: Option<TimeoutTask>,
+    reconnect_attempts: u32,
+    // THE STREAM RESUMES AFTER THIS ONE
+    last_log_id: String,
+    seen_log_ids: HashSet<String>,
+    seen_order: VecDeque<String>,
 }
 
 pub enum Msg {
     LearnMore,
-    InputDate(String),
     HideDetails,
+    ToggleFilters,
+    InputFilter(&'static str, String),
+    Search,
+    ResetFilters,
+    RequestLogs,
+    GetLogs(Page<UserLogDetails>),
+    LoadMore,
+    Scroll,
+    ResponseError(String),
+    ToggleLive,
+    ConnectLive,
+    GetStreamTicket(LogStreamTicket),
+    GetLiveStatus(StreamStatus),
+    GetLiveLog(String),
+    PauseLive,
+    ResumeLive,
+    Ignore,
 }
 
 impl Component for LogsHome {
@@ -78,8 +166,10 @@ impl Component for LogsHome {
             
         }
 
-
-        let user_create = UserCreate::new();
+        // SHARED SEARCHES ARRIVE AS ?date_from=..&type_detail=..&q=..
+        let route_service = RouteService::new();
+        let filters = LogFilters::from_query(&route_service.get_query());
+        let show_filters = filters.url_params().iter().any(|(key, _)| key != "q");
 
         LogsHome {
             tenant_id: props.tenant_id,
@@ -87,12 +177,38 @@ impl Component for LogsHome {
             fetch_task: None,
             learn_more: false,
             link,
+            route_service,
+            draft: filters.clone(),
+            filters,
+            show_filters,
+            error_filters: None,
+            // INFINITE SCROLL, THE PAGE IS NOT KEPT IN THE URL
+            pagination: Pagination::new(50, Some(Sort::desc("date"))),
+            logs: Vec::new(),
+            total_logs: 0,
+            end_of_logs: false,
+            loading_logs: false,
+            error_logs: None,
+            list_ref: NodeRef::default(),
+            live: false,
+            live_status: LiveStatus::Connecting,
+            live_paused: false,
+            live_pending: Vec::new(),
+            live_dropped: 0,
+            live_received: 0,
+            ticket_task: None,
+            live_stream: None,
+            reconnect_task: None,
+            reconnect_attempts: 0,
+            last_log_id: String::from(""),
+            seen_log_ids: HashSet::new(),
+            seen_order: VecDeque::new(),
         }
     }
 
     fn rendered(&mut self, first_render: bool) {
         if first_render {
-            // self.link.send_message(Msg::RequestUserList);
+            self.link.send_message(Msg::RequestLogs);
         }
     }
 
@@ -102,14 +218,258 @@ impl Component for LogsHome {
                 self.learn_more = true;
                 true
             }
-            Msg::InputDate(value) => {
-                ConsoleService::info(&format!("date is {}", value));
-                false
-            }
             Msg::HideDetails => {
                 self.learn_more = false;
                 true
             }
+            Msg::ToggleFilters => {
+                self.show_filters = !self.show_filters;
+                true
+            }
+            Msg::InputFilter(key, value) => {
+                self.draft.set(key, value);
+                self.error_filters = None;
+                true
+            }
+            Msg::Search => {
+                if let Err(message) = self.draft.validate() {
+                    self.error_filters = Some(message);
+                    return true;
+                }
+                self.filters = self.draft.clone();
+                self.pagination = self.pagination.first_page();
+                replace_query(&mut self.route_service, &self.filters.url_params());
+                self.link.send_message(Msg::RequestLogs);
+                false
+            }
+            Msg::ResetFilters => {
+                self.draft = LogFilters::default();
+                self.link.send_message(Msg::Search);
+                false
+            }
+            Msg::RequestLogs => {
+                // NEW EVENTS KEEP ARRIVING AT THE TOP, SO THE NEXT PAGES CONTINUE FROM
+                // THE LAST EVENT LISTED INSTEAD OF AN OFFSET THAT WOULD SHIFT
+                let mut url = match self.logs.last() {
+                    Some(last) if self.pagination.page > 0 => format!(
+                        "{}/api/v2/logs?from={}&take={}&sort=date:-1&include_totals=true",
+                        API_URL,
+                        encode_uri_component(&last.log_id),
+                        self.pagination.per_page
+                    ),
+                    _ => format!("{}/api/v2/logs?{}", API_URL, self.pagination.api_params()),
+                };
+                let query = self.filters.lucene();
+                if !query.is_empty() {
+                    url.push_str(&format!("&q={}", encode_uri_component(&query)));
+                }
+                let request = Request::get(url)
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<Page<UserLogDetails>, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetLogs(dataok),
+                            Err(error) => Msg::ResponseError(error.to_string()),
+                        }
+                    },
+                );
+                // A NEW SEARCH DROPS THE PAGE STILL LOADING FOR THE PREVIOUS ONE
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.fetch_task = Some(task);
+                if self.pagination.page == 0 {
+                    self.logs = Vec::new();
+                    self.total_logs = 0;
+                    self.end_of_logs = false;
+                    // THE STREAM FOLLOWS THE NEW SEARCH ONCE ITS FIRST PAGE IS IN
+                    self.ticket_task = None;
+                    self.live_stream = None;
+                    self.reconnect_task = None;
+                    self.live_pending = Vec::new();
+                    self.live_dropped = 0;
+                    self.live_received = 0;
+                }
+                self.error_logs = None;
+                self.loading_logs = true;
+                true
+            }
+            Msg::GetLogs(data) => {
+                self.end_of_logs = (data.items.len() as u32) < self.pagination.per_page;
+                if self.pagination.page == 0 {
+                    self.logs = data.items;
+                } else {
+                    let listed: HashSet<String> = self.logs.iter().map(|log| log.log_id.clone()).collect();
+                    self.logs.extend(data.items.into_iter().filter(|log| !listed.contains(&log.log_id)));
+                }
+                self.total_logs = data.total;
+                self.loading_logs = false;
+                self.fetch_task = None;
+                if self.live && self.pagination.page == 0 {
+                    self.last_log_id = self.logs.first().map(|log| log.log_id.clone()).unwrap_or_default();
+                    self.seen_log_ids = HashSet::new();
+                    self.seen_order = VecDeque::new();
+                    let log_ids: Vec<String> = self.logs.iter().map(|log| log.log_id.clone()).collect();
+                    for log_id in log_ids {
+                        self.remember_log_id(log_id);
+                    }
+                    self.reconnect_attempts = 0;
+                    self.link.send_message(Msg::ConnectLive);
+                }
+                true
+            }
+            Msg::LoadMore => {
+                if self.loading_logs || !self.has_more() || self.live {
+                    return false;
+                }
+                self.pagination.page += 1;
+                self.link.send_message(Msg::RequestLogs);
+                false
+            }
+            Msg::Scroll => {
+                if let Some(list) = self.list_ref.cast::<web_sys::Element>() {
+                    if list.scroll_top() + list.client_height() >= list.scroll_height() - SCROLL_THRESHOLD {
+                        self.link.send_message(Msg::LoadMore);
+                    }
+                }
+                false
+            }
+            Msg::ResponseError(message) => {
+                self.loading_logs = false;
+                self.error_logs = Some(message);
+                self.fetch_task = None;
+                true
+            }
+            Msg::ToggleLive => {
+                self.live = !self.live;
+                self.live_paused = false;
+                // A FRESH FIRST PAGE, THEN THE STREAM PICKS UP AFTER ITS NEWEST EVENT
+                self.pagination = self.pagination.first_page();
+                self.link.send_message(Msg::RequestLogs);
+                true
+            }
+            Msg::ConnectLive => {
+                if !self.live {
+                    return false;
+                }
+                // EventSource CAN'T SEND HEADERS, A SINGLE USE TICKET IS ASKED FOR
+                // FIRST SO THE access_token NEVER ENDS UP IN THE STREAM URL
+                let request = Request::post(format!("{}/api/v2/logs/stream-ticket", API_URL))
+                    .header("access_token", self.access_token.clone())
+                    .body(Nothing)
+                    .expect("Could not build request.");
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<LogStreamTicket, anyhow::Error>>>| {
+                        let Json(data) = response.into_body();
+                        match data {
+                            Ok(dataok) => Msg::GetStreamTicket(dataok),
+                            Err(error) => {
+                                ConsoleService::error(&format!("stream ticket = {}", error));
+                                Msg::GetLiveStatus(StreamStatus::Error)
+                            }
+                        }
+                    },
+                );
+                self.live_stream = None;
+                self.reconnect_task = None;
+                let task = FetchService::fetch(request, callback).expect("failed to start request");
+                self.ticket_task = Some(task);
+                self.live_status = LiveStatus::Connecting;
+                true
+            }
+            Msg::GetStreamTicket(data) => {
+                self.ticket_task = None;
+                if !self.live {
+                    return false;
+                }
+                let mut url = format!(
+                    "{}/api/v2/logs/stream?ticket={}",
+                    API_URL,
+                    encode_uri_component(&data.ticket)
+                );
+                let query = self.filters.lucene();
+                if !query.is_empty() {
+                    url.push_str(&format!("&q={}", encode_uri_component(&query)));
+                }
+                if !self.last_log_id.is_empty() {
+                    url.push_str(&format!("&from={}", encode_uri_component(&self.last_log_id)));
+                }
+                self.live_stream = None;
+                self.reconnect_task = None;
+                match LogStream::connect(&url, self.link.callback(Msg::GetLiveLog), self.link.callback(Msg::GetLiveStatus)) {
+                    Ok(stream) => {
+                        self.live_stream = Some(stream);
+                        self.live_status = LiveStatus::Connecting;
+                    }
+                    Err(message) => {
+                        ConsoleService::error(&message);
+                        self.link.send_message(Msg::GetLiveStatus(StreamStatus::Error));
+                    }
+                }
+                true
+            }
+            Msg::GetLiveStatus(status) => {
+                match status {
+                    StreamStatus::Open => {
+                        self.live_status = LiveStatus::Open;
+                        self.reconnect_attempts = 0;
+                    }
+                    // THE STREAM IS ALREADY CLOSED, IT IS REPLACED WHEN THE TIMEOUT FIRES
+                    StreamStatus::Error => {
+                        let seconds = 2u64.saturating_pow(self.reconnect_attempts).min(MAX_RECONNECT_SECONDS);
+                        self.reconnect_attempts += 1;
+                        self.live_status = LiveStatus::Reconnecting(seconds);
+                        let task = TimeoutService::spawn(
+                            Duration::from_secs(seconds),
+                            self.link.callback(|_| Msg::ConnectLive),
+                        );
+                        self.reconnect_task = Some(task);
+                    }
+                }
+                true
+            }
+            Msg::GetLiveLog(data) => {
+                let log = match serde_json::from_str::<UserLogDetails>(&data) {
+                    Ok(log) => log,
+                    Err(error) => {
+                        ConsoleService::error(&format!("live log = {} {}", error, data));
+                        return false;
+                    }
+                };
+                // A RECONNECT MAY REPLAY EVENTS ALREADY LISTED, THEY ARE NOT ADDED TWICE
+                if self.seen_log_ids.contains(&log.log_id) {
+                    return false;
+                }
+                self.remember_log_id(log.log_id.clone());
+                self.last_log_id = log.log_id.clone();
+                self.live_received += 1;
+                if self.live_paused {
+                    self.live_pending.insert(0, log);
+                    if self.live_pending.len() > MAX_LIVE_EVENTS {
+                        self.live_dropped += (self.live_pending.len() - MAX_LIVE_EVENTS) as u32;
+                        self.live_pending.truncate(MAX_LIVE_EVENTS);
+                    }
+                } else {
+                    self.logs.insert(0, log);
+                    self.logs.truncate(MAX_LIVE_EVENTS);
+                }
+                true
+            }
+            Msg::PauseLive => {
+                self.live_paused = true;
+                true
+            }
+            Msg::ResumeLive => {
+                self.live_paused = false;
+                let mut logs = std::mem::take(&mut self.live_pending);
+                logs.append(&mut self.logs);
+                logs.truncate(MAX_LIVE_EVENTS);
+                self.logs = logs;
+                self.live_dropped = 0;
+                true
+            }
+            Msg::Ignore => false,
         }
     }
 
@@ -118,8 +478,6 @@ impl Component for LogsHome {
     }
 
     fn view(&self) -> Html {
-        let tenant_id = self.tenant_id.clone();
-        type Anchor = RouterAnchor<AppRoute>;
         html! {
             <div>
                 <div class="container domain-content">
@@ -262,52 +620,55 @@ impl Component for LogsHome {
 
 
                     <div class="mt-5 mb-3">
-                        // <DevelopersNote message="Feature Search and Filter are not yet implemented"/>
-                        <div class="row" style="opacity: .7;">
-                            <div class="col-md col-lg">
+                        <div class="row">
+                            <div class="col">
                                 <div class="input-group flex-nowrap">
                                     <span class="input-group-text" id="addon-wrapping"><i class="bi bi-search"></i></span>
                                     <input
                                         type="text"
                                         class="form-control"
-                                        placeholder="Ex: type:'s' AND date:[2019-05-22 TO *]"
-                                        aria-label="Username"
+                                        placeholder="Ex: description:\"Get a client\" OR hostname:*.telkom.id"
+                                        aria-label="Search"
                                         aria-describedby="addon-wrapping"
+                                        value=self.draft.q.clone()
+                                        oninput=self.link.callback(|data: InputData| Msg::InputFilter("q", data.value))
+                                        onkeypress=self.on_enter()
                                     />
                                 </div>
                             </div>
-                            <div class="col-md-auto col-lg-auto">
-                                <div class="input-group mb-3">
-                                    <label class="input-group-text" for="inputGroupSelect01">{"Filter"}</label>
-                                    <select class="form-select" id="inputGroupSelect01">
-                                    <option selected=true>{"User"}</option>
-                                    <option value="1">{"Email"}</option>
-                                    <option value="2">{"Identity Provider"}</option>
-                                    <option value="3">{"Connection"}</option>
-                                    <option value="3">{"Connection"}</option>
-                                    <option value="3">{"Login Count"}</option>
-                                    <option value="3">{"Last Login"}</option>
-                                    <option value="3">{"Phone Number"}</option>
-                                    <option value="lucene_syntax">{"Lucene Syntax ()"}</option>
-                                    </select>
-                                </div>
+                            <div class="col-auto">
+                                <button
+                                    type="button"
+                                    class=classes!("btn", "btn-outline-secondary", if self.show_filters { "active" } else { "" })
+                                    onclick=self.link.callback(|_| Msg::ToggleFilters)
+                                >
+                                    <i class="bi bi-funnel me-2"></i>
+                                    {"Filters"}
+                                    {
+                                        match self.filters.url_params().iter().filter(|(key, _)| key != "q").count() {
+                                            0 => html! {},
+                                            count => html! { <span class="badge bg-primary ms-2">{ count }</span> },
+                                        }
+                                    }
+                                </button>
                             </div>
-                            <div class="col-md-auto col-lg-auto">
-                                // <button type="button" class="btn btn-outline-secondary">
-                                //     <i class="bi bi-calendar me-2"></i>
-                                //     <span>{"All Dates"}</span>
-                                // </button>
-                                <input
-                                    class="form-control"
-                                    type="date"
-                                    oninput=self.link.callback(|data: InputData| {
-                                        Msg::InputDate(data.value)
-                                    })
-                                />
+                            <div class="col-auto">
+                                <button
+                                    type="button"
+                                    class=classes!("btn", if self.live { "btn-danger" } else { "btn-outline-danger" })
+                                    title="Show new log events as they happen"
+                                    onclick=self.link.callback(|_| Msg::ToggleLive)
+                                >
+                                    <i class="bi bi-broadcast me-2"></i>
+                                    { if self.live { "Stop live" } else { "Live" } }
+                                </button>
                             </div>
                         </div>
 
-                        <div>
+                        { self.view_filters() }
+                        { self.view_live() }
+
+                        <div class="mt-1">
                             {"To perform your search, press"}
                             <code
                                 style="padding: 2px 6px; font-size: 11px;"
@@ -316,35 +677,59 @@ impl Component for LogsHome {
                                 {"enter"}
                             </code>
                         </div>
+                        {
+                            if self.error_filters.is_some() {
+                                html! {
+                                    <div class="alert alert-warning mt-2 mb-0" role="alert">
+                                        <i class="bi bi-exclamation-triangle me-2"></i>
+                                        { self.error_filters.clone().unwrap() }
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
                     </div>
 
-                    // <DevelopersNote message="Error handling (when token is expired) is not yet implemented"/>
                     {
-                        if false {
-                        // if true {
+                        if self.loading_logs && self.logs.is_empty() {
                             html! {
                                 <div
-                                    // class="d-flex align-items-center justify-content-center"
                                     style="margin-top: 4rem;"
                                 >
                                     <Loading width=45 />
                                 </div>
                             }
-                        } else if false {
+                        } else if self.error_logs.is_some() && self.logs.is_empty() {
                             html! {
-                                <tr>
-                                    <div class="alert alert-warning mb-5" role="alert">
-                                        <i class="bi bi-exclamation-triangle me-2"></i>
-                                        { "Error message" }
-                                    </div>
-                                </tr>
+                                <div class="alert alert-warning mb-5" role="alert">
+                                    <i class="bi bi-exclamation-triangle me-2"></i>
+                                    { self.error_logs.clone().unwrap() }
+                                </div>
+                            }
+                        } else if self.logs.is_empty() {
+                            html! {
+                                <div class="text-center text-muted p-5">
+                                    {
+                                        if self.filters.is_empty() {
+                                            "There are no log events yet."
+                                        } else {
+                                            "No log events match this search."
+                                        }
+                                    }
+                                </div>
                             }
                         } else {
                             html! {
                                 <>
-                                    <div class="mt-2 table-responsive-md table-responsive-lg">
+                                    <div
+                                        ref=self.list_ref.clone()
+                                        class="mt-2 table-responsive-md table-responsive-lg"
+                                        style="max-height: 70vh; overflow-y: auto;"
+                                        onscroll=self.link.callback(|_| Msg::Scroll)
+                                    >
                                         <table class="table">
-                                            <thead>
+                                            <thead class="sticky-top bg-white">
                                                 <tr>
                                                     <th scope="col-auto">{""}</th>
                                                     <th scope="col-auto">{"Type"}</th>
@@ -356,9 +741,10 @@ impl Component for LogsHome {
                                             </thead>
                                         
                                             <tbody>
-                                                {self.view_user_list()}
+                                                { for self.logs.iter().map(|log| self.view_log(log)) }
                                             </tbody>
                                         </table>
+                                        { self.view_list_end() }
                                     </div>
                                 </>
                             }
@@ -374,134 +760,304 @@ impl Component for LogsHome {
 
 
 impl LogsHome {
-    fn view_user_list(&self) -> Html {
+    fn has_more(&self) -> bool {
+        !self.live && !self.end_of_logs && (self.logs.len() as u32) < self.total_logs
+    }
+
+    fn remember_log_id(&mut self, log_id: String) {
+        if self.seen_log_ids.insert(log_id.clone()) {
+            self.seen_order.push_back(log_id);
+        }
+        while self.seen_order.len() > MAX_SEEN_LOG_IDS {
+            if let Some(oldest) = self.seen_order.pop_front() {
+                self.seen_log_ids.remove(&oldest);
+            }
+        }
+    }
+
+    fn on_enter(&self) -> Callback<KeyboardEvent> {
+        self.link.callback(|event: KeyboardEvent| {
+            if event.key() == "Enter" { Msg::Search } else { Msg::Ignore }
+        })
+    }
+
+    fn view_filters(&self) -> Html {
+        if !self.show_filters {
+            return html! {};
+        }
+        html! {
+            <div class="border rounded p-3 mt-2" style="font-size: 14px;">
+                <div class="row g-3">
+                    <div class="col-md-3">
+                        <label class="form-label text-muted">{"From"}</label>
+                        <input
+                            class="form-control"
+                            type="date"
+                            value=self.draft.date_from.clone()
+                            oninput=self.link.callback(|data: InputData| Msg::InputFilter("date_from", data.value))
+                        />
+                    </div>
+                    <div class="col-md-3">
+                        <label class="form-label text-muted">{"To"}</label>
+                        <input
+                            class="form-control"
+                            type="date"
+                            value=self.draft.date_to.clone()
+                            oninput=self.link.callback(|data: InputData| Msg::InputFilter("date_to", data.value))
+                        />
+                    </div>
+                    <div class="col-md-6">
+                        <label class="form-label text-muted">{"Event Type"}</label>
+                        { self.view_type_filter() }
+                    </div>
+                    {
+                        for TEXT_FILTERS.iter().map(|(key, label, placeholder)| {
+                            let key: &'static str = key;
+                            html! {
+                                <div class="col-md-6">
+                                    <label class="form-label text-muted">{ *label }</label>
+                                    <input
+                                        class="form-control"
+                                        type="text"
+                                        placeholder=placeholder.to_string()
+                                        value=self.draft.get(key).to_string()
+                                        oninput=self.link.callback(move |data: InputData| Msg::InputFilter(key, data.value))
+                                        onkeypress=self.on_enter()
+                                    />
+                                </div>
+                            }
+                        })
+                    }
+                </div>
+                <div class="d-flex justify-content-end mt-3">
+                    <button
+                        type="button"
+                        class="btn btn-outline-secondary me-2"
+                        onclick=self.link.callback(|_| Msg::ResetFilters)
+                    >
+                        {"Reset"}
+                    </button>
+                    <button
+                        type="button"
+                        class="btn btn-primary"
+                        onclick=self.link.callback(|_| Msg::Search)
+                    >
+                        {"Search"}
+                    </button>
+                </div>
+            </div>
+        }
+    }
+
+    // WHOLE CATEGORIES OR SINGLE TYPES, A HAND WRITTEN LIST FROM A SHARED URL IS KEPT AS IS
+    fn view_type_filter(&self) -> Html {
+        let selected = self.draft.types().join(",");
+        let known = selected.is_empty()
+            || LogCategory::ALL.iter().any(|category| category.codes().join(",") == selected)
+            || LOG_EVENT_TYPES.iter().any(|event_type| event_type.code == selected);
+        html! {
+            <select
+                class="form-select"
+                onchange=self.link.callback(|data: ChangeData| match data {
+                    ChangeData::Select(select) => Msg::InputFilter("type_detail", select.value()),
+                    _ => Msg::Ignore,
+                })
+            >
+                <option value="" selected={ selected.is_empty() }>{"All event types"}</option>
+                {
+                    if known {
+                        html! {}
+                    } else {
+                        html! { <option value=selected.clone() selected=true>{ format!("Custom: {}", selected) }</option> }
+                    }
+                }
+                {
+                    for LogCategory::ALL.iter().map(|category| {
+                        let codes = category.codes().join(",");
+                        html! {
+                            <optgroup label=category.label()>
+                                <option value=codes.clone() selected={ codes == selected }>
+                                    { format!("All {}", category.label().to_lowercase()) }
+                                </option>
+                                {
+                                    for LOG_EVENT_TYPES.iter().filter(|event_type| event_type.category == *category).map(|event_type| html! {
+                                        <option value=event_type.code selected={ event_type.code == selected }>
+                                            { format!("{} ({})", event_type.name, event_type.code) }
+                                        </option>
+                                    })
+                                }
+                            </optgroup>
+                        }
+                    })
+                }
+            </select>
+        }
+    }
+
+    fn view_log(&self, log: &UserLogDetails) -> Html {
         type Anchor = RouterAnchor<AppRoute>;
-        let tenant_id = self.tenant_id.clone();
-
-        // self.user_list.iter().map(|user| {
-            html! {
-                <tr
-                    class="align-middle"
-                >
-                    <td>
-                        <i class="bi bi-check2-circle text-success"></i>
-                    </td>
-                    <td scope="row">
-                        <div
-                            class="pt-2 pb-2"
+        let event_type = log_event_type(&log.type_detail);
+        html! {
+            <tr
+                class="align-middle"
+            >
+                <td>
+                    <i
+                        class=classes!("bi", event_type.severity.icon(), event_type.severity.color())
+                        title=format!("{}, {}", event_type.category.label(), event_type.severity.label())
+                    ></i>
+                </td>
+                <td scope="row">
+                    <div
+                        class="pt-2 pb-2"
+                    >
+                        <p
+                            class="m-0"
+                            style="
+                                white-space: nowrap;
+                                text-overflow: ellipsis;
+                                overflow: hidden;
+                                font-size: 14px;
+                                text-decoration: none;
+                            "
                         >
-                            <p
-                                class="m-0"
-                                style="
-                                    white-space: nowrap;
-                                    text-overflow: ellipsis;
-                                    overflow: hidden;
-                                    font-size: 14px;
-                                    text-decoration: none;
-                                "
+                            <Anchor
+                                route=AppRoute::LogDetails {tenant_id: self.tenant_id.clone(), log_id: log.log_id.clone() }
+                                classes="text-decoration-none fw-bold mb-0"
                             >
-                                <Anchor
-                                    route=AppRoute::LogDetails {tenant_id: tenant_id.clone(), log_id: String::from("log-id") }
-                                    classes="text-decoration-none fw-bold mb-0"
-                                >
-                                    { "API Read Operation" }
-                                </Anchor>
+                                { log_event_name(&log.type_detail) }
+                            </Anchor>
+                        </p>
+                        <p class="text-muted m-0" style="font-size: 12px;">{ log.type_detail.clone() }</p>
+                    </div>
+                </td>
+                <td>{ log.description.clone() }</td>
+                <td title=log.date.clone()>{ time_ago(&log.date) }</td>
+                <td>{ if log.connection.is_empty() { String::from("N/A") } else { log.connection.clone() } }</td>
+                <td>{ if log.client_name.is_empty() { String::from("N/A") } else { log.client_name.clone() } }</td>
+            </tr>
+        }
+    }
 
-                            </p>
-                            // <p class="text-muted overflow-hidden m-0">{"API Read Operation"}</p>
-                        </div>
-                    </td>
-                    <td>{"Get a client"}</td>
-                    <td>{"2 hours ago"}</td>
-                    <td>{"N/A"}</td>
-                    <td>
-                        {"N/A"}
-                        // <button type="button" style="flex: 0 0 auto; width: 30px; height: 30px;" class="btn d-flex justify-content-center align-items-center rounded border" role="button" id="dropdownMenuButton1" data-bs-toggle="dropdown" aria-expanded="false">
-                        //     <i class="bi bi-three-dots"></i>
-                        // </button>
-                        // <ul class="dropdown-menu pt-1" aria-labelledby="dropdownMenuButton1">
-                        //     <li class="p-1" style="font-size: 13px;">
-                        //         <DevelopersNote message="Not yet implemented"/>
-                        //     </li>
-                        //     <li class="p-1 text-muted" style="font-size:13px;">
-                        //         <Anchor route=AppRoute::UserSettings {tenant_id: tenant_id.clone(), user_id: user.user_id.clone(), id:1 } classes="dropdown-item">
-                        //             {"View Details"}
-                        //         </Anchor>
-                        //     </li>
-                        //     <li>
-                        //         <hr class="dropdown-divider"/>
-                        //     </li>
-                        //     <li class="p-1 text-muted">
-                        //                 <div class="ms-1 d-flex flex-row inline-block align-items-center" style="font-size:13px;" >
-                        //                     <i class="bi bi-person-check"></i>
-                        //                     <span data-bs-toggle="modal" data-bs-target="#assignRoles">
-                        //                     <a class="dropdown-item" href="#">
-                        //                         {"Assign Roles"}
-                        //                     </a>
-                        //                 </span>
-                        //                 </div>
-                        //     </li>
-                        //     <li class="p-1 text-muted" style="font-size:13px;">
-                        //                 <div class="ms-1 d-flex flex-row inline-block align-items-center">
-                        //                     <i class="bi bi-check2-square"></i>
-                        //                     <span data-bs-toggle="modal" data-bs-target="#assignPermissions">
-                        //                         <a class="dropdown-item" href="#" >
-                        //                             {"Assign Permissions"}
-                        //                         </a>
-                        //                     </span>
-                        //                 </div>
-                        //     </li>
-                        //     <li class="p-1 text-muted" style="font-size:13px;">
-                        //         <div class="ms-1 d-flex flex-row inline-block align-items-center">
-                        //             <i class="bi bi-envelope "></i>
-                        //             <span  data-bs-toggle="modal" data-bs-target="#resendConfirmation">
-                        //                 <a class="dropdown-item" href="#">
-                        //                     {"Send Verification Email "}
-                        //                 </a>
-                        //             </span>
-                        //         </div>
-                        //     </li>
-                        //     <li>
-                        //         <hr class="dropdown-divider"/>
-                        //     </li>
-                        //     <li class="p-1 text-muted" style="font-size:13px;" data-bs-toggle="modal" data-bs-target="#changeEmail">
-                        //         <a class="dropdown-item" href="#" >
-                        //             {"Change Email "}
-                        //         </a>
-                        //     </li>
-                        //     <li class="p-1 text-muted" style="font-size:13px;" data-bs-toggle="modal" data-bs-target="#changePassword">
-                        //         <a class="dropdown-item" href="#">
-                        //             {"Change Password "}
-                        //         </a>
-                        //     </li>
-                        //     <li>
-                        //         <hr class="dropdown-divider" />
-                        //     </li>
-                        //     <li class="p-1" style="font-size:13px;">
-                        //         <div class="ms-1 d-flex flex-row text-muted inline-block align-items-center">
-                        //             <svg xmlns="http://www.w3.org/2000/svg " width="13" height="13" viewBox="0 0 24 24 " fill="none " stroke="currentColor " stroke-width="2 " stroke-linecap="round " stroke-linejoin="round"><circle cx="12 " cy="12 " r="10 "></circle><line x1="4.93 " y1="4.93 " x2="19.07 " y2="19.07 "></line></svg>
-                        //             <span>
-                        //                 <a class="dropdown-item" href="#">
-                        //                     {"Block "}
-                        //                 </a>
-                        //             </span>
-                        //         </div>
-                        //     </li>
-                        //     <li class="p-1 text-danger " style="font-size:13px;">
-                        //         <div class="ms-1 d-flex flex-row align-items-center">
-                        //             <i class="bi bi-trash "></i>
-                        //             <span data-bs-toggle="modal" data-bs-target="#deleteUsers">
-                        //                 <a class="dropdown-item fs-7" href="#">
-                        //                     {"Delete "}
-                        //                 </a>
-                        //             </span>
-                        //         </div>
-                        //     </li>
-                        // </ul>
-                    </td>
-                </tr>
-            }
-        // })
-        // .collect()
+    fn view_live(&self) -> Html {
+        if !self.live {
+            return html! {};
+        }
+        let (dot, status) = match self.live_status {
+            LiveStatus::Connecting => ("text-warning", String::from("Connecting to the log stream...")),
+            LiveStatus::Open if self.live_paused => ("text-secondary", String::from("Paused, new events are kept aside")),
+            LiveStatus::Open => ("text-success", String::from("Live, new events appear at the top")),
+            LiveStatus::Reconnecting(seconds) => ("text-danger", format!("Connection lost, reconnecting in {}s", seconds)),
+        };
+        html! {
+            <div class="d-flex align-items-center border rounded px-3 py-2 mt-2" style="font-size: 14px;">
+                <i class=classes!("bi", "bi-circle-fill", "me-2", dot) style="font-size: 8px;"></i>
+                <span class="flex-fill">{ status }</span>
+                {
+                    if self.live_pending.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <span class="badge bg-primary me-2">
+                                { format!("{} new", self.live_pending.len() as u32 + self.live_dropped) }
+                            </span>
+                        }
+                    }
+                }
+                {
+                    if self.live_paused {
+                        html! {
+                            <button type="button" class="btn btn-sm btn-outline-primary" onclick=self.link.callback(|_| Msg::ResumeLive)>
+                                <i class="bi bi-play-fill me-1"></i>{"Resume"}
+                            </button>
+                        }
+                    } else {
+                        html! {
+                            <button type="button" class="btn btn-sm btn-outline-secondary" onclick=self.link.callback(|_| Msg::PauseLive)>
+                                <i class="bi bi-pause-fill me-1"></i>{"Pause"}
+                            </button>
+                        }
+                    }
+                }
+            </div>
+        }
     }
-}
\ No newline at end of file
+
+    fn view_list_end(&self) -> Html {
+        if self.live {
+            return html! {
+                <div class="text-center p-3 text-muted" style="font-size: 13px;">
+                    { format!("Live mode keeps the newest {} log events, stop it to browse older ones", MAX_LIVE_EVENTS) }
+                </div>
+            };
+        }
+        if self.loading_logs {
+            return html! {
+                <div class="d-flex justify-content-center p-3">
+                    <div class="spinner-border spinner-border-sm text-primary" role="status">
+                        <span class="visually-hidden">{"Loading..."}</span>
+                    </div>
+                </div>
+            };
+        }
+        html! {
+            <div class="d-flex flex-column align-items-center p-3 text-muted" style="font-size: 13px;">
+                {
+                    if self.error_logs.is_some() {
+                        html! {
+                            <div class="alert alert-warning w-100" role="alert">
+                                <i class="bi bi-exclamation-triangle me-2"></i>
+                                { self.error_logs.clone().unwrap() }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+                <span>
+                    {
+                        if self.live {
+                            format!("Showing the latest {} log events, {} received live", self.logs.len(), self.live_received)
+                        } else {
+                            format!("Showing {} of {} log events", self.logs.len(), self.total_logs)
+                        }
+                    }
+                </span>
+                {
+                    // THE LIST MAY BE TOO SHORT TO SCROLL
+                    if self.has_more() {
+                        html! {
+                            <button
+                                type="button"
+                                class="btn btn-sm btn-outline-primary mt-2"
+                                onclick=self.link.callback(|_| Msg::LoadMore)
+                            >
+                                {"Load more"}
+                            </button>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+        }
+    }
+}
+
+// "2 hours ago", THE DATE AS RECEIVED WHEN IT CAN NOT BE PARSED
+fn time_ago(date: &str) -> String {
+    let time = js_sys::Date::parse(date);
+    if time.is_nan() {
+        return date.to_string();
+    }
+    let seconds = ((js_sys::Date::now() - time) / 1000.0).max(0.0) as u64;
+    let (value, unit) = match seconds {
+        0..=59 => return String::from("a few seconds ago"),
+        60..=3599 => (seconds / 60, "minute"),
+        3600..=86399 => (seconds / 3600, "hour"),
+        86400..=2_591_999 => (seconds / 86400, "day"),
+        2_592_000..=31_535_999 => (seconds / 2_592_000, "month"),
+        _ => (seconds / 31_536_000, "year"),
+    };
+    format!("{} {}{} ago", value, unit, if value == 1 { "" } else { "s" })
+}
@@ -0,0 +1,75 @@
+use serde::Deserialize;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{Event, EventSource, MessageEvent};
+use yew::Callback;
+
+// SERVER-SENT EVENTS SUBSCRIPTION TO NEW LOG EVENTS, CLOSED WHEN DROPPED LIKE
+// A FetchTask. The browser's own reconnect is turned off: after an error the
+// page asks for a new ticket and reconnects with from=<last seen log_id> so
+// nothing is missed
+
+// EventSource CAN'T SEND THE access_token HEADER, THE STREAM IS OPENED WITH
+// ONE OF THESE INSTEAD, SINGLE USE AND VALID FOR expires_in SECONDS
+#[derive(Deserialize, Debug, Clone)]
+pub struct LogStreamTicket {
+    pub ticket: String,
+    pub expires_in: u32,
+}
+
+pub enum StreamStatus {
+    Open,
+    Error,
+}
+
+pub struct LogStream {
+    source: EventSource,
+    _on_log: Closure<dyn FnMut(MessageEvent)>,
+    _on_open: Closure<dyn FnMut(Event)>,
+    _on_error: Closure<dyn FnMut(Event)>,
+}
+
+impl LogStream {
+    // on_log RECEIVES THE JSON OF ONE LOG EVENT
+    pub fn connect(url: &str, on_log: Callback<String>, on_status: Callback<StreamStatus>) -> Result<LogStream, String> {
+        let source = EventSource::new(url).map_err(|_| String::from("Could not open the log stream"))?;
+
+        let on_log = Closure::wrap(Box::new(move |event: MessageEvent| {
+            if let Some(data) = event.data().as_string() {
+                on_log.emit(data);
+            }
+        }) as Box<dyn FnMut(MessageEvent)>);
+        source
+            .add_event_listener_with_callback("log", on_log.as_ref().unchecked_ref())
+            .map_err(|_| String::from("Could not listen to the log stream"))?;
+
+        let on_open = {
+            let on_status = on_status.clone();
+            Closure::wrap(Box::new(move |_: Event| on_status.emit(StreamStatus::Open)) as Box<dyn FnMut(Event)>)
+        };
+        source.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+
+        let on_error = {
+            let source = source.clone();
+            Closure::wrap(Box::new(move |_: Event| {
+                source.close();
+                on_status.emit(StreamStatus::Error);
+            }) as Box<dyn FnMut(Event)>)
+        };
+        source.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+        Ok(LogStream {
+            source,
+            _on_log: on_log,
+            _on_open: on_open,
+            _on_error: on_error,
+        })
+    }
+}
+
+impl Drop for LogStream {
+    fn drop(&mut self) {
+        self.source.set_onopen(None);
+        self.source.set_onerror(None);
+        self.source.close();
+    }
+}
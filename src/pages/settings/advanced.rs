@@ -44,6 +44,7 @@ pub enum Data {
     // FlagsEnablePublicSignupUserExistsError,
     // FlagsEnableAdfsWaadEmailVerification,
     FlagsRevokeRefreshTokenGrant,
+    FlagsDashboardLogStreamsNext,
     // Extensibility
     FlagsDisableClickjackProtectionHeaders,
     // Fixed length token
@@ -223,6 +224,10 @@ impl Component for SettingsAdvanced {
                         self.tenant_settings.flags.revoke_refresh_token_grant = !self.tenant_settings.flags.revoke_refresh_token_grant;
                         self.link.send_message(Msg::UpdateSettings);
                     }
+                    Data::FlagsDashboardLogStreamsNext => {
+                        self.tenant_settings.flags.dashboard_log_streams_next = !self.tenant_settings.flags.dashboard_log_streams_next;
+                        self.link.send_message(Msg::UpdateSettings);
+                    }
                     Data::FlagsDisableClickjackProtectionHeaders => {
                         self.tenant_settings.flags.disable_clickjack_protection_headers = !self.tenant_settings.flags.disable_clickjack_protection_headers;
                         self.link.send_message(Msg::UpdateSettings);
@@ -1080,6 +1085,29 @@ impl SettingsAdvanced {
                                 </p>
                             </div>
 
+                            <div
+                                class="mb-4"
+                            >
+                                <p class="mb-2 fw-bold">
+                                    {"Log Streams"}
+                                </p>
+                                <div class="form-check form-switch fs-4 mb-3">
+                                    <input
+                                        class="form-check-input"
+                                        type="checkbox"
+                                        id="flexSwitchCheckChecked"
+                                        checked={ flags.dashboard_log_streams_next.clone() }
+                                        onclick=self.link.callback(|_| Msg::InputString(String::from(""), Data::FlagsDashboardLogStreamsNext))
+                                        disabled={ self.loading_update_settings }
+                                    />
+                                </div>
+                                <p
+                                    class="text-color-disabled mb-0"
+                                >
+                                    {"Allow creating log streams, which forward log events to a webhook or a syslog server."}
+                                </p>
+                            </div>
+
                         </div>
                     </div>
                 </div>
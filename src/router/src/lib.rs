@@ -17,22 +17,26 @@ pub enum AppRoute {
     ApplicationSettings { tenant_id: String, app_id: String },
     #[to = "/{tenant_id}/applications"]
     ApplicationHome { tenant_id: String },
-    #[to = "/authentication/database/settings"]
-    DatabaseSettings,
+    #[to = "/authentication/database/{connection_id}/settings"]
+    DatabaseSettings { connection_id: String },
     #[to = "/authentication/database/create"]
     DbCreate,
     #[to = "/authentication/database"]
     DatabaseHome,
     #[to = "/authentication/passwordless"]
     AuthPasswordless,
+    #[to = "/sso/create-sso/{integration_id}"]
+    ConfigureSso { integration_id: String },
     #[to = "/sso/create-sso"]
     CreateSso,
+    #[to = "/sso/{sso_id}/settings"]
+    SsoSettings { sso_id: String },
     #[to = "/sso"]
     SsoHome,
     #[to = "/social/create"]
     SocialCreate,
-    #[to = "/social/settings"]
-    SocialSettings,
+    #[to = "/social/{connection_id}/settings"]
+    SocialSettings { connection_id: String },
     #[to = "/social"]
     SocialHome,
     #[to = "/user-management/roles/settings/{role_id}"]
@@ -47,14 +51,44 @@ pub enum AppRoute {
     },
     #[to = "/{tenant_id}/users"]
     UsersHome { tenant_id: String },
+    #[to = "/{tenant_id}/organizations/{org_id}/settings"]
+    OrganizationSettings { tenant_id: String, org_id: String },
+    #[to = "/{tenant_id}/organizations"]
+    OrganizationsHome { tenant_id: String },
     #[to = "/enterprise/google-app/create"]
     EnterpriseGoogleCreate,
+    #[to = "/enterprise/google-app/{connection_id}/settings"]
+    EnterpriseGoogleSettings { connection_id: String },
     #[to = "/enterprise/google-app"]
     EnterpriseGoogle,
+    #[to = "/enterprise/azure-ad/create"]
+    EnterpriseAzureCreate,
+    #[to = "/enterprise/azure-ad/{connection_id}/settings"]
+    EnterpriseAzureSettings { connection_id: String },
+    #[to = "/enterprise/azure-ad"]
+    EnterpriseAzure,
+    #[to = "/enterprise/saml/create"]
+    EnterpriseSamlCreate,
+    #[to = "/enterprise/saml/{connection_id}/settings"]
+    EnterpriseSamlSettings { connection_id: String },
+    #[to = "/enterprise/saml"]
+    EnterpriseSaml,
+    #[to = "/enterprise/oidc/create"]
+    EnterpriseOidcCreate,
+    #[to = "/enterprise/oidc/{connection_id}/settings"]
+    EnterpriseOidcSettings { connection_id: String },
+    #[to = "/enterprise/oidc"]
+    EnterpriseOidc,
     #[to = "/enterprise"]
     EnterpriseHome,
     #[to = "/tenant"]
     SettingsHome,
+    #[to = "/{tenant_id}/log-streams/create"]
+    LogStreamsCreate { tenant_id: String },
+    #[to = "/{tenant_id}/log-streams/{stream_id}"]
+    LogStreamSettings { tenant_id: String, stream_id: String },
+    #[to = "/{tenant_id}/log-streams"]
+    LogStreamsHome { tenant_id: String },
     #[to = "/{tenant_id}/logs/{log_id}"]
     LogDetails { tenant_id: String, log_id: String },
     #[to = "/{tenant_id}/logs"]
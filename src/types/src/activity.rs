@@ -0,0 +1,230 @@
+use std::collections::{HashMap, HashSet};
+use serde::Deserialize;
+use crate::logs::LogCategory;
+
+// ACTIVITY DASHBOARD
+// Every chart counts the events of one metric per UTC day. Logins, failed
+// logins and active users come from the logs API, signups from the creation
+// date of the users, so deleted users and expired logs are not counted
+
+// (DAYS, LABEL)
+pub const ACTIVITY_RANGES: [(u32, &str); 3] = [
+    (7, "Last 7 days"),
+    (14, "Last 14 days"),
+    (30, "Last 30 days"),
+];
+
+pub const DEFAULT_ACTIVITY_RANGE: u32 = 7;
+
+pub const ACTIVITY_PER_PAGE: u32 = 100;
+
+// NEITHER THE LOGS NOR THE USERS API PAGES PAST THE FIRST 1000 RESULTS,
+// NEWEST FIRST SO A BUSY RANGE LOSES ITS OLDEST DAYS
+pub const MAX_ACTIVITY_EVENTS: u32 = 1000;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ActivityMetric {
+    Logins,
+    Signups,
+    FailedLogins,
+    ActiveUsers,
+}
+
+impl ActivityMetric {
+    pub const ALL: [ActivityMetric; 4] = [
+        ActivityMetric::Logins,
+        ActivityMetric::Signups,
+        ActivityMetric::FailedLogins,
+        ActivityMetric::ActiveUsers,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ActivityMetric::Logins => "Daily Logins",
+            ActivityMetric::Signups => "Signups",
+            ActivityMetric::FailedLogins => "Failed Logins",
+            ActivityMetric::ActiveUsers => "Active Users",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            ActivityMetric::Logins => "Successful logins, silent authentication included.",
+            ActivityMetric::Signups => "Users created, from any connection.",
+            ActivityMetric::FailedLogins => "Wrong passwords, unknown users and other failed logins.",
+            ActivityMetric::ActiveUsers => "Different users with at least one successful login.",
+        }
+    }
+
+    // WHAT THE TOTAL OF THE RANGE COUNTS
+    pub fn unit(&self) -> &'static str {
+        match self {
+            ActivityMetric::Logins => "logins",
+            ActivityMetric::Signups => "signups",
+            ActivityMetric::FailedLogins => "failed logins",
+            ActivityMetric::ActiveUsers => "users",
+        }
+    }
+
+    // BOOTSTRAP ICON CLASS
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ActivityMetric::Logins => "bi-box-arrow-in-right",
+            ActivityMetric::Signups => "bi-person-plus",
+            ActivityMetric::FailedLogins => "bi-shield-exclamation",
+            ActivityMetric::ActiveUsers => "bi-people",
+        }
+    }
+
+    // STROKE AND FILL OF THE CHART
+    pub fn color(&self) -> &'static str {
+        match self {
+            ActivityMetric::Logins => "#0d6efd",
+            ActivityMetric::Signups => "#198754",
+            ActivityMetric::FailedLogins => "#dc3545",
+            ActivityMetric::ActiveUsers => "#6f42c1",
+        }
+    }
+
+    pub fn endpoint(&self) -> &'static str {
+        match self {
+            ActivityMetric::Signups => "/api/v2/users",
+            _ => "/api/v2/logs",
+        }
+    }
+
+    // SEARCH FOR THE EVENTS SINCE from, A YYYY-MM-DD DAY
+    pub fn lucene(&self, from: &str) -> String {
+        let category = match self {
+            ActivityMetric::Signups => return format!("created_at:[{} TO *]", from),
+            ActivityMetric::FailedLogins => LogCategory::LoginFailure,
+            ActivityMetric::Logins | ActivityMetric::ActiveUsers => LogCategory::LoginSuccess,
+        };
+        format!("type_detail:({}) AND date:[{} TO *]", category.codes().join(" OR "), from)
+    }
+
+    pub fn api_params(&self) -> &'static str {
+        match self {
+            ActivityMetric::Signups => "sort=created_at:-1&fields=user_id,created_at&search_engine=v3",
+            _ => "sort=date:-1&fields=date,user_id",
+        }
+    }
+
+    // ONE VALUE PER DAY, days ARE YYYY-MM-DD
+    pub fn daily_counts(&self, events: &[ActivityEvent], days: &[String]) -> Vec<u32> {
+        let mut counts: HashMap<&str, u32> = HashMap::new();
+        let mut seen: HashSet<(&str, &str)> = HashSet::new();
+        for event in events {
+            let day = event.day();
+            if *self == ActivityMetric::ActiveUsers && (event.user_id.is_empty() || !seen.insert((day, event.user_id.as_str()))) {
+                continue;
+            }
+            *counts.entry(day).or_insert(0) += 1;
+        }
+        days.iter().map(|day| counts.get(day.as_str()).copied().unwrap_or(0)).collect()
+    }
+
+    // ACTIVE USERS ARE COUNTED ONCE OVER THE WHOLE RANGE, NOT SUMMED
+    pub fn range_total(&self, events: &[ActivityEvent], days: &[String]) -> u32 {
+        let in_range = events.iter().filter(|event| days.iter().any(|day| day == event.day()));
+        if *self == ActivityMetric::ActiveUsers {
+            in_range
+                .filter(|event| !event.user_id.is_empty())
+                .map(|event| event.user_id.as_str())
+                .collect::<HashSet<&str>>()
+                .len() as u32
+        } else {
+            in_range.count() as u32
+        }
+    }
+}
+
+// A LOG ENTRY OR A USER, ONLY THE FIELDS THE CHARTS NEED
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ActivityEvent {
+    #[serde(alias = "created_at")]
+    pub date: String,
+    pub user_id: String,
+}
+
+impl ActivityEvent {
+    // UTC DAY OF AN ISO 8601 DATE
+    pub fn day(&self) -> &str {
+        self.date.get(..10).unwrap_or("")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(date: &str, user_id: &str) -> ActivityEvent {
+        ActivityEvent {
+            date: String::from(date),
+            user_id: String::from(user_id),
+        }
+    }
+
+    fn days(days: &[&str]) -> Vec<String> {
+        days.iter().map(|day| day.to_string()).collect()
+    }
+
+    #[test]
+    fn events_are_bucketed_by_utc_day() {
+        let events = vec![
+            event("2021-08-02T23:59:59.000Z", "a"),
+            event("2021-08-02T00:00:00.000Z", "b"),
+            event("2021-08-03T08:15:00.000Z", "a"),
+        ];
+        let range = days(&["2021-08-01", "2021-08-02", "2021-08-03"]);
+        assert_eq!(ActivityMetric::Logins.daily_counts(&events, &range), vec![0, 2, 1]);
+        assert_eq!(ActivityMetric::Logins.range_total(&events, &range), 3);
+    }
+
+    #[test]
+    fn active_users_are_counted_once_per_day_and_once_per_range() {
+        let events = vec![
+            event("2021-08-02T10:00:00.000Z", "a"),
+            event("2021-08-02T11:00:00.000Z", "a"),
+            event("2021-08-02T12:00:00.000Z", ""),
+            event("2021-08-03T10:00:00.000Z", "a"),
+            event("2021-08-03T10:00:00.000Z", "b"),
+        ];
+        let range = days(&["2021-08-02", "2021-08-03"]);
+        assert_eq!(ActivityMetric::ActiveUsers.daily_counts(&events, &range), vec![1, 2]);
+        assert_eq!(ActivityMetric::ActiveUsers.range_total(&events, &range), 2);
+    }
+
+    #[test]
+    fn events_outside_the_range_are_left_out() {
+        let events = vec![
+            event("2021-07-31T10:00:00.000Z", "a"),
+            event("2021-08-02T10:00:00.000Z", "b"),
+            event("2021-08-04T10:00:00.000Z", "c"),
+            event("", "d"),
+        ];
+        let range = days(&["2021-08-02", "2021-08-03"]);
+        assert_eq!(ActivityMetric::Logins.daily_counts(&events, &range), vec![1, 0]);
+        assert_eq!(ActivityMetric::Logins.range_total(&events, &range), 1);
+        assert_eq!(ActivityMetric::ActiveUsers.range_total(&events, &range), 1);
+    }
+
+    #[test]
+    fn an_empty_range_counts_nothing() {
+        let events = vec![event("2021-08-02T10:00:00.000Z", "a")];
+        assert!(ActivityMetric::Logins.daily_counts(&events, &[]).is_empty());
+        assert_eq!(ActivityMetric::Logins.range_total(&events, &[]), 0);
+        assert_eq!(ActivityMetric::ActiveUsers.range_total(&events, &[]), 0);
+    }
+
+    #[test]
+    fn searches_start_at_the_first_day() {
+        assert_eq!(ActivityMetric::Signups.lucene("2021-08-01"), "created_at:[2021-08-01 TO *]");
+        let logins = ActivityMetric::Logins.lucene("2021-08-01");
+        assert!(logins.starts_with("type_detail:(s OR "), "{}", logins);
+        assert!(logins.ends_with(") AND date:[2021-08-01 TO *]"), "{}", logins);
+        assert_eq!(ActivityMetric::ActiveUsers.lucene("2021-08-01"), logins);
+        assert!(ActivityMetric::FailedLogins.lucene("2021-08-01").contains(" OR fp OR "));
+    }
+}
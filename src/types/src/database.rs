@@ -0,0 +1,476 @@
+use std::collections::BTreeMap;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::Value;
+
+// DATABASE CONNECTIONS
+// Username / password stores, the strategy is always auth0
+
+pub const DATABASE_STRATEGY: &str = "auth0";
+
+pub const SYNC_ON_EACH_LOGIN: &str = "on_each_login";
+pub const SYNC_ON_FIRST_LOGIN: &str = "on_first_login";
+
+const NAME_MAX_LENGTH: usize = 35;
+
+pub const PASSWORD_MAX_LENGTH: u32 = 128;
+pub const PASSWORD_HISTORY_MAX_SIZE: u32 = 24;
+
+// ALWAYS REJECTED WHEN THE DICTIONARY IS ENABLED, ON TOP OF THE CUSTOM ENTRIES
+const COMMON_PASSWORDS: [&str; 20] = [
+    "123456",
+    "123456789",
+    "12345678",
+    "password",
+    "qwerty",
+    "qwerty123",
+    "1q2w3e4r",
+    "111111",
+    "1234567890",
+    "abc123",
+    "password1",
+    "iloveyou",
+    "admin",
+    "welcome",
+    "monkey",
+    "dragon",
+    "letmein",
+    "football",
+    "sunshine",
+    "princess",
+];
+
+// CUSTOM DATABASE ACTION SCRIPTS, (name, label, parameters before the callback)
+pub const DATABASE_SCRIPTS: [(&str, &str, &[&str]); 6] = [
+    ("login", "Login", &["email", "password"]),
+    ("create", "Create", &["user"]),
+    ("verify", "Verify", &["email"]),
+    ("change_password", "Change Password", &["email", "newPassword"]),
+    ("get_user", "Get User", &["email"]),
+    ("delete", "Delete", &["id"]),
+];
+
+// PERSONAL DATA SHORTER THAN THIS IS TOO COMMON TO REJECT, EG. "jo"
+const PERSONAL_INFO_MIN_LENGTH: usize = 3;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct UsernameValidation {
+    pub min: u32,
+    pub max: u32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct DatabaseValidation {
+    pub username: UsernameValidation,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct PasswordHistory {
+    pub enable: bool,
+    pub size: u32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct PasswordDictionary {
+    pub enable: bool,
+    pub dictionary: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct PasswordPolicy {
+    pub min_length: u32,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_number: bool,
+    pub require_special: bool,
+    pub history: PasswordHistory,
+    pub dictionary: PasswordDictionary,
+    pub no_personal_info: bool,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> PasswordPolicy {
+        PasswordPolicy {
+            min_length: 8,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_number: true,
+            require_special: false,
+            history: PasswordHistory {
+                enable: false,
+                size: 5,
+            },
+            dictionary: PasswordDictionary {
+                enable: false,
+                dictionary: vec![],
+            },
+            no_personal_info: false,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    // PASSWORD HISTORY CAN ONLY BE CHECKED BY THE SERVER
+    pub fn violations(&self, password: &str, personal_info: &[String]) -> Vec<String> {
+        let mut violations = vec![];
+        if (password.chars().count() as u32) < self.min_length {
+            violations.push(format!("At least {} characters in length", self.min_length));
+        }
+        if self.require_lowercase && !password.chars().any(|c| c.is_lowercase()) {
+            violations.push(String::from("Lower case letters (a-z)"));
+        }
+        if self.require_uppercase && !password.chars().any(|c| c.is_uppercase()) {
+            violations.push(String::from("Upper case letters (A-Z)"));
+        }
+        if self.require_number && !password.chars().any(|c| c.is_ascii_digit()) {
+            violations.push(String::from("Numbers (0-9)"));
+        }
+        if self.require_special && !password.chars().any(is_special_character) {
+            violations.push(String::from("Special characters (e.g. !@#$%^&*)"));
+        }
+        if self.dictionary.enable {
+            let lowercase = password.to_lowercase();
+            let in_dictionary = COMMON_PASSWORDS.iter().any(|common| *common == lowercase)
+                || self.dictionary.dictionary.iter().any(|entry| entry.trim().to_lowercase() == lowercase);
+            if in_dictionary {
+                violations.push(String::from("Not a commonly used or disallowed password"));
+            }
+        }
+        if self.no_personal_info && contains_personal_info(password, personal_info) {
+            violations.push(String::from("No personal data such as name or email"));
+        }
+        violations
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct DatabaseConnectionOptions {
+    pub requires_username: bool,
+    pub validation: DatabaseValidation,
+    pub disable_signup: bool,
+    pub import_mode: bool,
+    pub set_user_root_attributes: String,
+    // CONNECTIONS CREATED BEFORE PASSWORD POLICIES GET THE DEFAULT ONE
+    #[serde(default)]
+    pub password_policy: PasswordPolicy,
+    // WHEN ENABLED USERS LIVE IN THE LEGACY STORE AND THE SCRIPTS BELOW ARE USED
+    #[serde(default)]
+    pub enabled_database_customization: bool,
+    // SCRIPT NAME FROM DATABASE_SCRIPTS -> JAVASCRIPT SOURCE
+    #[serde(default)]
+    pub custom_scripts: BTreeMap<String, String>,
+}
+
+impl DatabaseConnectionOptions {
+    pub fn new() -> DatabaseConnectionOptions {
+        DatabaseConnectionOptions {
+            requires_username: false,
+            validation: DatabaseValidation {
+                username: UsernameValidation {
+                    min: 1,
+                    max: 15,
+                },
+            },
+            disable_signup: false,
+            import_mode: false,
+            set_user_root_attributes: String::from(SYNC_ON_EACH_LOGIN),
+            password_policy: PasswordPolicy::default(),
+            enabled_database_customization: false,
+            custom_scripts: BTreeMap::new(),
+        }
+    }
+}
+
+impl Default for DatabaseConnectionOptions {
+    fn default() -> Self {
+        DatabaseConnectionOptions::new()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct DatabaseConnection {
+    pub id: String,
+    pub name: String,
+    pub strategy: String,
+    pub enabled_clients: Vec<String>,
+    pub options: DatabaseConnectionOptions,
+}
+
+impl DatabaseConnection {
+    pub fn new() -> DatabaseConnection {
+        DatabaseConnection {
+            id: String::from(""),
+            name: String::from(""),
+            strategy: String::from(DATABASE_STRATEGY),
+            enabled_clients: vec![],
+            options: DatabaseConnectionOptions::new(),
+        }
+    }
+}
+
+impl Default for DatabaseConnection {
+    fn default() -> Self {
+        DatabaseConnection::new()
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct DatabaseConnectionCreate {
+    pub name: String,
+    pub strategy: String,
+    pub options: DatabaseConnectionOptions,
+}
+
+impl DatabaseConnectionCreate {
+    pub fn new() -> DatabaseConnectionCreate {
+        DatabaseConnectionCreate {
+            name: String::from(""),
+            strategy: String::from(DATABASE_STRATEGY),
+            options: DatabaseConnectionOptions::new(),
+        }
+    }
+}
+
+impl Default for DatabaseConnectionCreate {
+    fn default() -> Self {
+        DatabaseConnectionCreate::new()
+    }
+}
+
+// NAME AND STRATEGY CAN'T BE CHANGED AFTER CREATION
+#[derive(Serialize, Debug, Clone)]
+pub struct DatabaseConnectionUpdate {
+    pub enabled_clients: Vec<String>,
+    pub options: DatabaseConnectionOptions,
+}
+
+impl From<&DatabaseConnection> for DatabaseConnectionUpdate {
+    fn from(connection: &DatabaseConnection) -> DatabaseConnectionUpdate {
+        DatabaseConnectionUpdate {
+            enabled_clients: connection.enabled_clients.clone(),
+            options: connection.options.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ScriptTry {
+    pub script: String,
+    // ARGUMENTS PASSED TO THE SCRIPT, KEYED BY PARAMETER NAME
+    pub params: BTreeMap<String, Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ScriptTryResult {
+    // NULL WHEN THE SCRIPT CALLED BACK WITH AN ERROR
+    pub result: Value,
+    pub error: Option<String>,
+    #[serde(default)]
+    pub logs: Vec<String>,
+}
+
+pub fn validate_connection_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err(String::from("Name is required"));
+    }
+    if name.len() > NAME_MAX_LENGTH {
+        return Err(format!("Name can't have more than {} characters", NAME_MAX_LENGTH));
+    }
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(String::from("Name can only contain alphanumeric characters and '-'"));
+    }
+    if name.starts_with('-') || name.ends_with('-') {
+        return Err(String::from("Name must start and end with an alphanumeric character"));
+    }
+    Ok(())
+}
+
+pub fn validate_username_length(validation: &UsernameValidation) -> Result<(), String> {
+    if validation.min < 1 {
+        return Err(String::from("Username minimum length must be at least 1"));
+    }
+    if validation.min > validation.max {
+        return Err(String::from("Username minimum length can't be greater than the maximum"));
+    }
+    Ok(())
+}
+
+pub fn validate_password_policy(policy: &PasswordPolicy) -> Result<(), String> {
+    if policy.min_length < 1 || policy.min_length > PASSWORD_MAX_LENGTH {
+        return Err(format!("Password minimum length must be between 1 and {}", PASSWORD_MAX_LENGTH));
+    }
+    if policy.history.enable && (policy.history.size < 1 || policy.history.size > PASSWORD_HISTORY_MAX_SIZE) {
+        return Err(format!("Password history size must be between 1 and {}", PASSWORD_HISTORY_MAX_SIZE));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PasswordStrength {
+    VeryWeak,
+    Weak,
+    Fair,
+    Good,
+    Strong,
+}
+
+impl PasswordStrength {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PasswordStrength::VeryWeak => "Very weak",
+            PasswordStrength::Weak => "Weak",
+            PasswordStrength::Fair => "Fair",
+            PasswordStrength::Good => "Good",
+            PasswordStrength::Strong => "Strong",
+        }
+    }
+
+    // 0 - 100, FOR PROGRESS BARS
+    pub fn percent(&self) -> u32 {
+        match self {
+            PasswordStrength::VeryWeak => 10,
+            PasswordStrength::Weak => 30,
+            PasswordStrength::Fair => 55,
+            PasswordStrength::Good => 80,
+            PasswordStrength::Strong => 100,
+        }
+    }
+}
+
+pub fn password_strength(password: &str) -> PasswordStrength {
+    if password.is_empty() || COMMON_PASSWORDS.contains(&password.to_lowercase().as_str()) {
+        return PasswordStrength::VeryWeak;
+    }
+    let classes = [
+        password.chars().any(|c| c.is_lowercase()),
+        password.chars().any(|c| c.is_uppercase()),
+        password.chars().any(|c| c.is_ascii_digit()),
+        password.chars().any(is_special_character),
+    ]
+    .iter()
+    .filter(|present| **present)
+    .count();
+    let length = password.chars().count();
+    let mut score = 0;
+    if length >= 8 {
+        score += 1;
+    }
+    if length >= 12 {
+        score += 1;
+    }
+    if classes >= 3 {
+        score += 1;
+    }
+    if classes == 4 {
+        score += 1;
+    }
+    match score {
+        0 => PasswordStrength::VeryWeak,
+        1 => PasswordStrength::Weak,
+        2 => PasswordStrength::Fair,
+        3 => PasswordStrength::Good,
+        _ => PasswordStrength::Strong,
+    }
+}
+
+// "john.doe@mail.acme.com" GIVES "john", "doe" AND "acme", THE TLD AND
+// SUBDOMAINS ARE NOT PERSONAL
+pub fn personal_info_from_email(email: &str) -> Vec<String> {
+    let (local, domain) = email.rsplit_once('@').unwrap_or((email, ""));
+    let mut labels = domain.rsplit('.').skip(1);
+    local
+        .split(['.', '_', '-', '+'])
+        .chain(labels.next())
+        .filter(|part| part.len() >= PERSONAL_INFO_MIN_LENGTH)
+        .map(String::from)
+        .collect()
+}
+
+fn contains_personal_info(password: &str, personal_info: &[String]) -> bool {
+    let lowercase = password.to_lowercase();
+    personal_info
+        .iter()
+        .map(|info| info.trim().to_lowercase())
+        .filter(|info| info.len() >= PERSONAL_INFO_MIN_LENGTH)
+        .any(|info| lowercase.contains(&info))
+}
+
+fn is_special_character(c: char) -> bool {
+    !c.is_alphanumeric() && !c.is_whitespace()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> PasswordPolicy {
+        PasswordPolicy {
+            require_special: true,
+            dictionary: PasswordDictionary {
+                enable: true,
+                dictionary: vec![String::from(" Acme2024! ")],
+            },
+            no_personal_info: true,
+            ..PasswordPolicy::default()
+        }
+    }
+
+    #[test]
+    fn personal_info_skips_the_tld() {
+        assert_eq!(personal_info_from_email("john.doe@mail.acme.com"), vec!["john", "doe", "acme"]);
+        assert_eq!(personal_info_from_email("jo+news@computer1.io"), vec!["news", "computer1"]);
+        assert_eq!(personal_info_from_email("ann@acme.com"), vec!["ann", "acme"]);
+        assert!(personal_info_from_email("").is_empty());
+    }
+
+    #[test]
+    fn a_tld_in_the_password_is_not_personal_info() {
+        let personal_info = personal_info_from_email("jane@example.com");
+        assert!(policy().violations("Computer1!", &personal_info).is_empty());
+        assert_eq!(
+            policy().violations("Example1!", &personal_info),
+            vec!["No personal data such as name or email"]
+        );
+    }
+
+    #[test]
+    fn lists_every_rule_the_password_breaks() {
+        assert_eq!(
+            policy().violations("abc", &[]),
+            vec![
+                "At least 8 characters in length",
+                "Upper case letters (A-Z)",
+                "Numbers (0-9)",
+                "Special characters (e.g. !@#$%^&*)",
+            ]
+        );
+        assert_eq!(
+            policy().violations("ACME2024!", &[]),
+            vec!["Lower case letters (a-z)", "Not a commonly used or disallowed password"]
+        );
+        assert!(PasswordPolicy::default().violations("Password1", &[String::from("password")]).is_empty());
+    }
+
+    #[test]
+    fn policy_limits() {
+        assert!(validate_password_policy(&PasswordPolicy::default()).is_ok());
+        let policy = PasswordPolicy { min_length: 0, ..PasswordPolicy::default() };
+        assert!(validate_password_policy(&policy).is_err());
+        let policy = PasswordPolicy {
+            history: PasswordHistory { enable: true, size: PASSWORD_HISTORY_MAX_SIZE + 1 },
+            ..PasswordPolicy::default()
+        };
+        assert!(validate_password_policy(&policy).is_err());
+    }
+
+    #[test]
+    fn strength_grows_with_length_and_character_classes() {
+        assert_eq!(password_strength(""), PasswordStrength::VeryWeak);
+        assert_eq!(password_strength("abcdefgh"), PasswordStrength::Weak);
+        assert_eq!(password_strength("Abcdefg1"), PasswordStrength::Fair);
+        assert_eq!(password_strength("Abcdefgh1!"), PasswordStrength::Good);
+        assert_eq!(password_strength("Abcdefghij1!"), PasswordStrength::Strong);
+    }
+}
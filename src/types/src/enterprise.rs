@@ -0,0 +1,997 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+};
+use serde::{
+    de::DeserializeOwned,
+    Deserialize,
+    Serialize,
+};
+use serde_json::Value;
+use crate::{
+    database::SYNC_ON_EACH_LOGIN,
+    social::validate_https_url,
+};
+
+// ENTERPRISE CONNECTIONS
+
+// ONLY THE ENABLED APPLICATIONS, SHARED BY EVERY ENTERPRISE STRATEGY
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct EnabledClientsUpdate {
+    pub enabled_clients: Vec<String>,
+}
+
+// OPTIONS OF A STRATEGY, EVERYTHING ELSE OF THE CONNECTION IS THE SAME FOR
+// GOOGLE WORKSPACE, AZURE AD, SAML AND OPENID CONNECT
+pub trait StrategyOptions: Serialize + DeserializeOwned + Default + Debug + Clone + PartialEq + 'static {
+    const STRATEGY: &'static str;
+
+    // EVERYTHING THAT KEEPS THE CONNECTION FROM SIGNING USERS IN
+    fn problems(&self) -> Vec<String>;
+
+    fn set_user_root_attributes(&self) -> &str;
+
+    fn set_user_root_attributes_mut(&mut self) -> &mut String;
+
+    fn domain_aliases(&self) -> &[String];
+
+    fn domain_aliases_mut(&mut self) -> &mut Vec<String>;
+
+    fn validate(&self) -> Result<(), String> {
+        match self.problems().into_iter().next() {
+            Some(problem) => Err(problem),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct StrategyConnection<O> {
+    pub id: String,
+    pub name: String,
+    pub strategy: String,
+    pub enabled_clients: Vec<String>,
+    pub options: O,
+}
+
+impl<O: StrategyOptions> Default for StrategyConnection<O> {
+    fn default() -> Self {
+        StrategyConnection {
+            id: String::from(""),
+            name: String::from(""),
+            strategy: String::from(O::STRATEGY),
+            enabled_clients: vec![],
+            options: O::default(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct StrategyConnectionCreate<O> {
+    pub name: String,
+    pub strategy: String,
+    pub options: O,
+}
+
+impl<O: StrategyOptions> Default for StrategyConnectionCreate<O> {
+    fn default() -> Self {
+        StrategyConnectionCreate {
+            name: String::from(""),
+            strategy: String::from(O::STRATEGY),
+            options: O::default(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct StrategyConnectionUpdate<O> {
+    pub options: O,
+}
+
+impl<O: Clone> From<&StrategyConnection<O>> for StrategyConnectionUpdate<O> {
+    fn from(connection: &StrategyConnection<O>) -> Self {
+        StrategyConnectionUpdate {
+            options: connection.options.clone(),
+        }
+    }
+}
+
+// HOME REALM DISCOVERY
+
+pub const GOOGLE_APPS_STRATEGY: &str = "google-apps";
+
+// (strategy, label)
+pub const ENTERPRISE_STRATEGIES: [(&str, &str); 4] = [
+    (GOOGLE_APPS_STRATEGY, "Google Workspace"),
+    (AZURE_AD_STRATEGY, "Microsoft Azure AD"),
+    (SAML_STRATEGY, "SAML"),
+    (OIDC_STRATEGY, "OpenID Connect"),
+];
+
+pub fn enterprise_strategy_label(strategy: &str) -> &str {
+    ENTERPRISE_STRATEGIES
+        .iter()
+        .find(|(value, _)| *value == strategy)
+        .map(|(_, label)| *label)
+        .unwrap_or(strategy)
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct HomeRealmOptions {
+    #[serde(default)]
+    pub domain_aliases: Vec<String>,
+}
+
+// ANY ENTERPRISE CONNECTION, ONLY WITH WHAT HOME REALM DISCOVERY NEEDS
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct EnterpriseConnection {
+    pub id: String,
+    pub name: String,
+    pub strategy: String,
+    #[serde(default)]
+    pub enabled_clients: Vec<String>,
+    pub options: HomeRealmOptions,
+}
+
+pub fn normalize_domain(domain: &str) -> String {
+    domain.trim().trim_start_matches('@').trim_end_matches('.').to_lowercase()
+}
+
+pub fn email_domain(email: &str) -> Option<String> {
+    let (local, domain) = email.trim().rsplit_once('@')?;
+    if local.is_empty() || domain.is_empty() {
+        return None;
+    }
+    Some(normalize_domain(domain))
+}
+
+// "Acme.com" AND "acme.com." ARE THE SAME DOMAIN
+pub fn validate_domain_aliases(domains: &[String]) -> Result<(), String> {
+    let mut listed: Vec<String> = Vec::new();
+    for domain in domains {
+        let normalized = normalize_domain(domain);
+        if !validate_domain(&normalized) {
+            return Err(format!("{} is not a valid domain", domain.trim()));
+        }
+        if listed.contains(&normalized) {
+            return Err(format!("{} is listed twice", normalized));
+        }
+        listed.push(normalized);
+    }
+    Ok(())
+}
+
+// ANOTHER CONNECTION ALREADY ROUTING THE DOMAIN, A DOMAIN HAS ONE HOME REALM
+pub fn domain_claimed_by<'a>(domain: &str, connection_id: &str, connections: &'a [EnterpriseConnection]) -> Option<&'a EnterpriseConnection> {
+    let domain = normalize_domain(domain);
+    connections.iter().find(|connection| {
+        connection.id != connection_id
+            && connection.options.domain_aliases.iter().any(|claimed| normalize_domain(claimed) == domain)
+    })
+}
+
+// CHECKED AGAIN ON SAVE, ANOTHER CONNECTION MAY HAVE CLAIMED A DOMAIN SINCE IT WAS ADDED
+pub fn validate_unclaimed_domains(domains: &[String], connection_id: &str, connections: &[EnterpriseConnection]) -> Result<(), String> {
+    for domain in domains {
+        if let Some(connection) = domain_claimed_by(domain, connection_id, connections) {
+            return Err(format!("{} is already claimed by the {} connection", normalize_domain(domain), connection.name));
+        }
+    }
+    Ok(())
+}
+
+pub fn home_realm<'a>(email: &str, connections: &'a [EnterpriseConnection]) -> Option<&'a EnterpriseConnection> {
+    let domain = email_domain(email)?;
+    domain_claimed_by(&domain, "", connections)
+}
+
+// GOOGLE WORKSPACE
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct GoogleAppsOptions {
+    // PRIMARY DOMAIN OF THE WORKSPACE, EG. "acme.com"
+    pub domain: String,
+    // BOTH BLANK TO USE THE DEVELOPMENT KEYS
+    #[serde(default)]
+    pub client_id: String,
+    #[serde(default)]
+    pub client_secret: String,
+    // GROUP MEMBERSHIPS ARE SYNCED INTO THE USER PROFILE AT EACH LOGIN
+    #[serde(default)]
+    pub ext_groups: bool,
+    // SUSPENDED WORKSPACE USERS CAN'T LOG IN
+    #[serde(default)]
+    pub ext_is_suspended: bool,
+    #[serde(default)]
+    pub set_user_root_attributes: String,
+    // EMAIL DOMAINS ROUTED TO THIS CONNECTION BY HOME REALM DISCOVERY
+    #[serde(default)]
+    pub domain_aliases: Vec<String>,
+}
+
+impl Default for GoogleAppsOptions {
+    fn default() -> GoogleAppsOptions {
+        GoogleAppsOptions {
+            domain: String::from(""),
+            client_id: String::from(""),
+            client_secret: String::from(""),
+            ext_groups: false,
+            ext_is_suspended: true,
+            set_user_root_attributes: String::from(SYNC_ON_EACH_LOGIN),
+            domain_aliases: vec![],
+        }
+    }
+}
+
+impl StrategyOptions for GoogleAppsOptions {
+    const STRATEGY: &'static str = GOOGLE_APPS_STRATEGY;
+
+    fn problems(&self) -> Vec<String> {
+        validate_google_apps_options(self).err().into_iter().collect()
+    }
+
+    fn set_user_root_attributes(&self) -> &str {
+        &self.set_user_root_attributes
+    }
+
+    fn set_user_root_attributes_mut(&mut self) -> &mut String {
+        &mut self.set_user_root_attributes
+    }
+
+    fn domain_aliases(&self) -> &[String] {
+        &self.domain_aliases
+    }
+
+    fn domain_aliases_mut(&mut self) -> &mut Vec<String> {
+        &mut self.domain_aliases
+    }
+}
+
+pub type GoogleAppsConnection = StrategyConnection<GoogleAppsOptions>;
+
+pub fn validate_google_apps_options(options: &GoogleAppsOptions) -> Result<(), String> {
+    let domain = options.domain.trim();
+    if domain.is_empty() {
+        return Err(String::from("Google Workspace Domain is required"));
+    }
+    if domain.contains("://") || domain.contains('/') {
+        return Err(String::from("Google Workspace Domain must be a domain name, without https:// or a path"));
+    }
+    if !validate_domain(domain) {
+        return Err(format!("{} is not a valid domain", domain));
+    }
+    if options.client_id.trim().is_empty() != options.client_secret.trim().is_empty() {
+        return Err(String::from("Client ID and Client Secret are both required, or both blank to use the development keys"));
+    }
+    validate_domain_aliases(&options.domain_aliases)
+}
+
+// MICROSOFT AZURE AD
+
+pub const AZURE_AD_STRATEGY: &str = "waad";
+
+pub const AZURE_IDENTITY_API_V2: &str = "microsoft-identity-platform-v2.0";
+pub const AZURE_IDENTITY_API_V1: &str = "azure-active-directory-v1.0";
+
+// (value, label)
+pub const AZURE_IDENTITY_APIS: [(&str, &str); 2] = [
+    (AZURE_IDENTITY_API_V2, "Microsoft Identity Platform (v2.0)"),
+    (AZURE_IDENTITY_API_V1, "Azure Active Directory (v1)"),
+];
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct AzureAdOptions {
+    // TENANT DOMAIN, EG. "contoso.onmicrosoft.com"
+    pub domain: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub identity_api: String,
+    // EXTENDED PROFILE ATTRIBUTES (job title, department, manager, ...)
+    #[serde(default)]
+    pub ext_profile: bool,
+    // GROUP MEMBERSHIPS ARE SYNCED INTO THE USER PROFILE AT EACH LOGIN
+    #[serde(default)]
+    pub ext_groups: bool,
+    #[serde(default)]
+    pub ext_nested_groups: bool,
+    #[serde(default)]
+    pub set_user_root_attributes: String,
+    // EMAIL DOMAINS ROUTED TO THIS CONNECTION BY HOME REALM DISCOVERY
+    #[serde(default)]
+    pub domain_aliases: Vec<String>,
+}
+
+impl Default for AzureAdOptions {
+    fn default() -> AzureAdOptions {
+        AzureAdOptions {
+            domain: String::from(""),
+            client_id: String::from(""),
+            client_secret: String::from(""),
+            identity_api: String::from(AZURE_IDENTITY_API_V2),
+            ext_profile: true,
+            ext_groups: false,
+            ext_nested_groups: false,
+            set_user_root_attributes: String::from(SYNC_ON_EACH_LOGIN),
+            domain_aliases: vec![],
+        }
+    }
+}
+
+impl StrategyOptions for AzureAdOptions {
+    const STRATEGY: &'static str = AZURE_AD_STRATEGY;
+
+    fn problems(&self) -> Vec<String> {
+        validate_azure_ad_options(self).err().into_iter().collect()
+    }
+
+    fn set_user_root_attributes(&self) -> &str {
+        &self.set_user_root_attributes
+    }
+
+    fn set_user_root_attributes_mut(&mut self) -> &mut String {
+        &mut self.set_user_root_attributes
+    }
+
+    fn domain_aliases(&self) -> &[String] {
+        &self.domain_aliases
+    }
+
+    fn domain_aliases_mut(&mut self) -> &mut Vec<String> {
+        &mut self.domain_aliases
+    }
+}
+
+pub type AzureAdConnection = StrategyConnection<AzureAdOptions>;
+
+pub fn validate_azure_ad_options(options: &AzureAdOptions) -> Result<(), String> {
+    let domain = options.domain.trim();
+    if domain.is_empty() {
+        return Err(String::from("Microsoft Azure AD Domain is required"));
+    }
+    if domain.contains("://") || domain.contains('/') {
+        return Err(String::from("Microsoft Azure AD Domain must be a domain name, without https:// or a path"));
+    }
+    if !validate_domain(domain) {
+        return Err(format!("{} is not a valid domain", domain));
+    }
+    if !validate_guid(options.client_id.trim()) {
+        return Err(String::from("Client ID must be the Application (client) ID of the app registration, eg. 0b8a8f56-3d5c-4a1f-9d3e-7b5a1c2e4f60"));
+    }
+    if options.client_secret.trim().is_empty() {
+        return Err(String::from("Client Secret is required"));
+    }
+    if !AZURE_IDENTITY_APIS.iter().any(|(value, _)| *value == options.identity_api) {
+        return Err(String::from("Identity API is unknown"));
+    }
+    if options.ext_nested_groups && !options.ext_groups {
+        return Err(String::from("Nested groups can only be synced when groups are synced"));
+    }
+    validate_domain_aliases(&options.domain_aliases)
+}
+
+pub fn validate_domain(domain: &str) -> bool {
+    let labels: Vec<&str> = domain.split('.').collect();
+    labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+// 8-4-4-4-12 HEXADECIMAL DIGITS
+fn validate_guid(value: &str) -> bool {
+    let groups: Vec<&str> = value.split('-').collect();
+    groups.len() == 5
+        && groups
+            .iter()
+            .zip([8, 4, 4, 4, 12].iter())
+            .all(|(group, length)| group.len() == *length && group.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+// SAML
+
+pub const SAML_STRATEGY: &str = "samlp";
+
+pub const SAML_BINDING_REDIRECT: &str = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-Redirect";
+pub const SAML_BINDING_POST: &str = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST";
+
+// (value, label)
+pub const SAML_BINDINGS: [(&str, &str); 2] = [
+    (SAML_BINDING_REDIRECT, "HTTP-Redirect"),
+    (SAML_BINDING_POST, "HTTP-POST"),
+];
+
+pub const NAME_ID_FORMAT_UNSPECIFIED: &str = "urn:oasis:names:tc:SAML:1.1:nameid-format:unspecified";
+
+// (value, label)
+pub const NAME_ID_FORMATS: [(&str, &str); 5] = [
+    (NAME_ID_FORMAT_UNSPECIFIED, "Unspecified"),
+    ("urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress", "Email Address"),
+    ("urn:oasis:names:tc:SAML:2.0:nameid-format:persistent", "Persistent"),
+    ("urn:oasis:names:tc:SAML:2.0:nameid-format:transient", "Transient"),
+    ("urn:oasis:names:tc:SAML:1.1:nameid-format:X509SubjectName", "X.509 Subject Name"),
+];
+
+// (user attribute, label, required)
+pub const SAML_USER_ATTRIBUTES: [(&str, &str, bool); 6] = [
+    ("user_id", "User ID", true),
+    ("email", "Email", true),
+    ("name", "Name", false),
+    ("given_name", "Given Name", false),
+    ("family_name", "Family Name", false),
+    ("groups", "Groups", false),
+];
+
+// USER ATTRIBUTE -> SAML ATTRIBUTE, THE CLAIMS SENT BY ADFS AND AZURE AD
+pub fn default_fields_map() -> BTreeMap<String, String> {
+    [
+        ("user_id", "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/nameidentifier"),
+        ("email", "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/emailaddress"),
+        ("name", "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/name"),
+        ("given_name", "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/givenname"),
+        ("family_name", "http://schemas.xmlsoap.org/ws/2005/05/identity/claims/surname"),
+        ("groups", "http://schemas.xmlsoap.org/claims/Group"),
+    ]
+    .iter()
+    .map(|(attribute, claim)| (attribute.to_string(), claim.to_string()))
+    .collect()
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct SamlOptions {
+    pub sign_in_endpoint: String,
+    #[serde(default)]
+    pub sign_out_endpoint: String,
+    // PEM ENCODED X.509 CERTIFICATE OF THE IDENTITY PROVIDER
+    pub signing_cert: String,
+    #[serde(default)]
+    pub name_id_format: String,
+    #[serde(default)]
+    pub protocol_binding: String,
+    #[serde(default)]
+    pub sign_saml_request: bool,
+    #[serde(default)]
+    pub fields_map: BTreeMap<String, String>,
+    #[serde(default)]
+    pub set_user_root_attributes: String,
+    // EMAIL DOMAINS ROUTED TO THIS CONNECTION BY HOME REALM DISCOVERY
+    #[serde(default)]
+    pub domain_aliases: Vec<String>,
+}
+
+impl Default for SamlOptions {
+    fn default() -> SamlOptions {
+        SamlOptions {
+            sign_in_endpoint: String::from(""),
+            sign_out_endpoint: String::from(""),
+            signing_cert: String::from(""),
+            name_id_format: String::from(NAME_ID_FORMAT_UNSPECIFIED),
+            protocol_binding: String::from(SAML_BINDING_REDIRECT),
+            sign_saml_request: false,
+            fields_map: default_fields_map(),
+            set_user_root_attributes: String::from(SYNC_ON_EACH_LOGIN),
+            domain_aliases: vec![],
+        }
+    }
+}
+
+impl StrategyOptions for SamlOptions {
+    const STRATEGY: &'static str = SAML_STRATEGY;
+
+    fn problems(&self) -> Vec<String> {
+        saml_problems(self)
+    }
+
+    fn set_user_root_attributes(&self) -> &str {
+        &self.set_user_root_attributes
+    }
+
+    fn set_user_root_attributes_mut(&mut self) -> &mut String {
+        &mut self.set_user_root_attributes
+    }
+
+    fn domain_aliases(&self) -> &[String] {
+        &self.domain_aliases
+    }
+
+    fn domain_aliases_mut(&mut self) -> &mut Vec<String> {
+        &mut self.domain_aliases
+    }
+}
+
+pub type SamlConnection = StrategyConnection<SamlOptions>;
+
+pub fn saml_problems(options: &SamlOptions) -> Vec<String> {
+    let mut problems = Vec::new();
+    if options.sign_in_endpoint.trim().is_empty() {
+        problems.push(String::from("Sign In URL is required"));
+    } else if let Err(message) = validate_https_url(options.sign_in_endpoint.trim()) {
+        problems.push(format!("Sign In URL {}", message));
+    }
+    if !options.sign_out_endpoint.trim().is_empty() {
+        if let Err(message) = validate_https_url(options.sign_out_endpoint.trim()) {
+            problems.push(format!("Sign Out URL {}", message));
+        }
+    }
+    if let Err(message) = validate_certificate(&options.signing_cert) {
+        problems.push(message);
+    }
+    if !options.name_id_format.is_empty() && !options.name_id_format.starts_with("urn:oasis:names:tc:SAML:") {
+        problems.push(String::from("NameID Format must be a SAML NameID format URN"));
+    }
+    if !SAML_BINDINGS.iter().any(|(value, _)| *value == options.protocol_binding) {
+        problems.push(String::from("Protocol Binding is unknown"));
+    }
+    for (attribute, label, required) in SAML_USER_ATTRIBUTES.iter() {
+        let mapped = options.fields_map.get(*attribute).map(|claim| !claim.trim().is_empty()).unwrap_or(false);
+        if *required && !mapped {
+            problems.push(format!("{} must be mapped to a SAML attribute", label));
+        }
+    }
+    if let Err(message) = validate_domain_aliases(&options.domain_aliases) {
+        problems.push(message);
+    }
+    problems
+}
+
+const PEM_BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+const PEM_END: &str = "-----END CERTIFICATE-----";
+
+// ACCEPTS A PEM CERTIFICATE OR ITS BARE BASE64 BODY, AS FOUND IN METADATA
+pub fn validate_certificate(certificate: &str) -> Result<(), String> {
+    let certificate = certificate.trim();
+    if certificate.is_empty() {
+        return Err(String::from("Signing Certificate is required"));
+    }
+    let body = certificate
+        .strip_prefix(PEM_BEGIN)
+        .and_then(|rest| rest.trim_end().strip_suffix(PEM_END))
+        .unwrap_or(certificate);
+    let body: String = body.chars().filter(|c| !c.is_whitespace()).collect();
+    let base64 = body
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/' || c == '=');
+    if body.is_empty() || !base64 || !body.len().is_multiple_of(4) {
+        return Err(String::from("Signing Certificate must be a PEM encoded X.509 certificate"));
+    }
+    Ok(())
+}
+
+// WRAPS A BASE64 CERTIFICATE BODY INTO A PEM DOCUMENT
+pub fn certificate_to_pem(body: &str) -> String {
+    let body: Vec<char> = body.chars().filter(|c| !c.is_whitespace()).collect();
+    let lines: Vec<String> = body.chunks(64).map(|line| line.iter().collect()).collect();
+    format!("{}\n{}\n{}", PEM_BEGIN, lines.join("\n"), PEM_END)
+}
+
+// OPENID CONNECT
+
+pub const OIDC_STRATEGY: &str = "oidc";
+
+// FRONT CHANNEL GETS THE ID TOKEN FROM THE BROWSER (form_post), BACK CHANNEL
+// EXCHANGES A CODE AT THE TOKEN ENDPOINT AND NEEDS THE CLIENT SECRET
+pub const OIDC_FRONT_CHANNEL: &str = "front_channel";
+pub const OIDC_BACK_CHANNEL: &str = "back_channel";
+
+// (value, label, description)
+pub const OIDC_TYPES: [(&str, &str, &str); 2] = [
+    (OIDC_BACK_CHANNEL, "Back Channel", "Uses the authorization code flow, the client secret is required."),
+    (OIDC_FRONT_CHANNEL, "Front Channel", "Uses the implicit flow with form_post, no client secret is needed."),
+];
+
+// ALGORITHMS ID TOKENS CAN BE VERIFIED WITH, "none" IS NEVER ACCEPTED
+pub const OIDC_SIGNING_ALGORITHMS: [&str; 10] = [
+    "RS256", "RS384", "RS512", "PS256", "PS384", "PS512", "ES256", "ES384", "ES512", "HS256",
+];
+
+pub const OIDC_DISCOVERY_PATH: &str = "/.well-known/openid-configuration";
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct OidcOptions {
+    pub issuer: String,
+    #[serde(default)]
+    pub discovery_url: String,
+    #[serde(rename = "type")]
+    pub oidc_type: String,
+    pub client_id: String,
+    #[serde(default)]
+    pub client_secret: String,
+    pub authorization_endpoint: String,
+    #[serde(default)]
+    pub token_endpoint: String,
+    #[serde(default)]
+    pub userinfo_endpoint: String,
+    pub jwks_uri: String,
+    // SPACE SEPARATED, ALWAYS CONTAINS "openid"
+    pub scope: String,
+    #[serde(default)]
+    pub set_user_root_attributes: String,
+    // EMAIL DOMAINS ROUTED TO THIS CONNECTION BY HOME REALM DISCOVERY
+    #[serde(default)]
+    pub domain_aliases: Vec<String>,
+}
+
+impl Default for OidcOptions {
+    fn default() -> OidcOptions {
+        OidcOptions {
+            issuer: String::from(""),
+            discovery_url: String::from(""),
+            oidc_type: String::from(OIDC_BACK_CHANNEL),
+            client_id: String::from(""),
+            client_secret: String::from(""),
+            authorization_endpoint: String::from(""),
+            token_endpoint: String::from(""),
+            userinfo_endpoint: String::from(""),
+            jwks_uri: String::from(""),
+            scope: String::from("openid profile email"),
+            set_user_root_attributes: String::from(SYNC_ON_EACH_LOGIN),
+            domain_aliases: vec![],
+        }
+    }
+}
+
+impl StrategyOptions for OidcOptions {
+    const STRATEGY: &'static str = OIDC_STRATEGY;
+
+    fn problems(&self) -> Vec<String> {
+        validate_oidc_options(self).err().into_iter().collect()
+    }
+
+    fn set_user_root_attributes(&self) -> &str {
+        &self.set_user_root_attributes
+    }
+
+    fn set_user_root_attributes_mut(&mut self) -> &mut String {
+        &mut self.set_user_root_attributes
+    }
+
+    fn domain_aliases(&self) -> &[String] {
+        &self.domain_aliases
+    }
+
+    fn domain_aliases_mut(&mut self) -> &mut Vec<String> {
+        &mut self.domain_aliases
+    }
+}
+
+pub type OidcConnection = StrategyConnection<OidcOptions>;
+
+// THE PARTS OF AN OPENID PROVIDER CONFIGURATION THE CONNECTION USES
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct OidcDiscovery {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    #[serde(default)]
+    pub token_endpoint: String,
+    #[serde(default)]
+    pub userinfo_endpoint: String,
+    pub jwks_uri: String,
+    #[serde(default)]
+    pub scopes_supported: Vec<String>,
+    #[serde(default)]
+    pub response_types_supported: Vec<String>,
+    #[serde(default)]
+    pub response_modes_supported: Vec<String>,
+    #[serde(default)]
+    pub id_token_signing_alg_values_supported: Vec<String>,
+}
+
+impl OidcDiscovery {
+    pub fn parse(document: &str) -> Result<OidcDiscovery, String> {
+        let value: Value = serde_json::from_str(document)
+            .map_err(|error| format!("The discovery document is not valid JSON: {}", error))?;
+        if !value.is_object() {
+            return Err(String::from("The discovery document must be a JSON object"));
+        }
+        for key in ["issuer", "authorization_endpoint", "jwks_uri"].iter() {
+            if !value.get(*key).map(|field| field.is_string()).unwrap_or(false) {
+                return Err(format!("The discovery document has no {}", key));
+            }
+        }
+        serde_json::from_value(value).map_err(|error| format!("The discovery document is not valid: {}", error))
+    }
+
+    pub fn signing_algorithms(&self) -> Vec<String> {
+        self.id_token_signing_alg_values_supported
+            .iter()
+            .filter(|algorithm| OIDC_SIGNING_ALGORITHMS.contains(&algorithm.as_str()))
+            .cloned()
+            .collect()
+    }
+
+    // CONNECTION TYPES THE PROVIDER CAN SERVE, FROM ITS RESPONSE TYPES
+    pub fn supports(&self, oidc_type: &str) -> bool {
+        let response_type = |expected: &str| {
+            self.response_types_supported.is_empty()
+                || self.response_types_supported.iter().any(|response_type| {
+                    let mut parts: Vec<&str> = response_type.split_whitespace().collect();
+                    parts.sort_unstable();
+                    parts == vec![expected]
+                })
+        };
+        match oidc_type {
+            OIDC_BACK_CHANNEL => !self.token_endpoint.is_empty() && response_type("code"),
+            OIDC_FRONT_CHANNEL => {
+                response_type("id_token")
+                    && (self.response_modes_supported.is_empty()
+                        || self.response_modes_supported.iter().any(|mode| mode == "form_post"))
+            }
+            _ => false,
+        }
+    }
+
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let endpoints = [
+            ("Issuer", &self.issuer),
+            ("Authorization Endpoint", &self.authorization_endpoint),
+            ("JWKS URI", &self.jwks_uri),
+            ("Token Endpoint", &self.token_endpoint),
+            ("UserInfo Endpoint", &self.userinfo_endpoint),
+        ];
+        for (label, url) in endpoints.iter() {
+            if !url.is_empty() {
+                if let Err(message) = validate_https_url(url) {
+                    problems.push(format!("{} {}", label, message));
+                }
+            }
+        }
+        if !self.scopes_supported.is_empty() && !self.scopes_supported.iter().any(|scope| scope == "openid") {
+            problems.push(String::from("The provider doesn't support the openid scope"));
+        }
+        if self.signing_algorithms().is_empty() {
+            problems.push(format!(
+                "The provider signs ID tokens with {}, supported algorithms are {}",
+                if self.id_token_signing_alg_values_supported.is_empty() {
+                    String::from("no declared algorithm")
+                } else {
+                    self.id_token_signing_alg_values_supported.join(", ")
+                },
+                OIDC_SIGNING_ALGORITHMS.join(", "),
+            ));
+        }
+        if !self.supports(OIDC_BACK_CHANNEL) && !self.supports(OIDC_FRONT_CHANNEL) {
+            problems.push(String::from("The provider supports neither the code flow nor the id_token flow"));
+        }
+        problems
+    }
+
+    // KEEPS THE CLIENT CREDENTIALS, THE TYPE WHEN THE PROVIDER SUPPORTS IT AND
+    // THE CHOSEN SCOPES THE PROVIDER ADVERTISES
+    pub fn apply(&self, options: &OidcOptions, discovery_url: &str) -> OidcOptions {
+        let mut options = options.clone();
+        options.issuer = self.issuer.clone();
+        options.discovery_url = discovery_url.to_string();
+        options.authorization_endpoint = self.authorization_endpoint.clone();
+        options.token_endpoint = self.token_endpoint.clone();
+        options.userinfo_endpoint = self.userinfo_endpoint.clone();
+        options.jwks_uri = self.jwks_uri.clone();
+        if !self.supports(&options.oidc_type) {
+            if let Some((oidc_type, _, _)) = OIDC_TYPES.iter().find(|(oidc_type, _, _)| self.supports(oidc_type)) {
+                options.oidc_type = oidc_type.to_string();
+            }
+        }
+        let mut scopes = vec!["openid"];
+        for scope in options.scope.split_whitespace() {
+            if !scopes.contains(&scope)
+                && (self.scopes_supported.is_empty() || self.scopes_supported.iter().any(|supported| supported == scope))
+            {
+                scopes.push(scope);
+            }
+        }
+        options.scope = scopes.join(" ");
+        options
+    }
+}
+
+// "https://idp.example.com" -> "https://idp.example.com/.well-known/openid-configuration"
+pub fn oidc_discovery_url(issuer: &str) -> String {
+    let issuer = issuer.trim().trim_end_matches('/');
+    if issuer.ends_with(OIDC_DISCOVERY_PATH) {
+        issuer.to_string()
+    } else {
+        format!("{}{}", issuer, OIDC_DISCOVERY_PATH)
+    }
+}
+
+pub fn validate_oidc_options(options: &OidcOptions) -> Result<(), String> {
+    let endpoints = [
+        ("Issuer URL", &options.issuer, true),
+        ("Authorization Endpoint", &options.authorization_endpoint, true),
+        ("JWKS URI", &options.jwks_uri, true),
+        ("Token Endpoint", &options.token_endpoint, options.oidc_type == OIDC_BACK_CHANNEL),
+        ("UserInfo Endpoint", &options.userinfo_endpoint, false),
+    ];
+    for (label, url, required) in endpoints.iter() {
+        let url = url.trim();
+        if url.is_empty() {
+            if *required {
+                return Err(format!("{} is required", label));
+            }
+        } else if let Err(message) = validate_https_url(url) {
+            return Err(format!("{} {}", label, message));
+        }
+    }
+    if options.client_id.trim().is_empty() {
+        return Err(String::from("Client ID is required"));
+    }
+    match options.oidc_type.as_str() {
+        OIDC_BACK_CHANNEL => {
+            if options.client_secret.trim().is_empty() {
+                return Err(String::from("Client Secret is required for a back channel connection"));
+            }
+        }
+        OIDC_FRONT_CHANNEL => {}
+        _ => return Err(String::from("Type must be front channel or back channel")),
+    }
+    if !options.scope.split_whitespace().any(|scope| scope == "openid") {
+        return Err(String::from("Scopes must contain openid"));
+    }
+    validate_domain_aliases(&options.domain_aliases)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn domains(list: &[&str]) -> Vec<String> {
+        list.iter().map(|domain| String::from(*domain)).collect()
+    }
+
+    fn connection(id: &str, aliases: &[&str]) -> EnterpriseConnection {
+        EnterpriseConnection {
+            id: String::from(id),
+            name: format!("{}-connection", id),
+            strategy: String::from(SAML_STRATEGY),
+            enabled_clients: vec![],
+            options: HomeRealmOptions { domain_aliases: domains(aliases) },
+        }
+    }
+
+    #[test]
+    fn validate_domain_checks_every_label() {
+        for domain in ["acme.com", "mail.acme.co.uk", "x-1.io", "123.com"].iter() {
+            assert!(validate_domain(domain), "{} should be valid", domain);
+        }
+        let too_long = format!("{}.com", "a".repeat(64));
+        for domain in ["acme", "", "acme..com", ".acme.com", "-acme.com", "acme-.com", "ac me.com", "acme.com/", "Ãcme.com", &too_long].iter() {
+            assert!(!validate_domain(domain), "{} should be invalid", domain);
+        }
+    }
+
+    #[test]
+    fn domain_aliases_are_compared_normalized() {
+        assert_eq!(validate_domain_aliases(&domains(&["acme.com", " @Mail.Acme.com. "])), Ok(()));
+        assert_eq!(
+            validate_domain_aliases(&domains(&["acme.com", "ACME.com."])),
+            Err(String::from("acme.com is listed twice"))
+        );
+        assert_eq!(
+            validate_domain_aliases(&domains(&["acme.com", " acme "])),
+            Err(String::from("acme is not a valid domain"))
+        );
+    }
+
+    #[test]
+    fn a_domain_is_claimed_by_another_connection() {
+        let connections = vec![connection("con_1", &["acme.com"]), connection("con_2", &["Globex.com."])];
+        assert_eq!(domain_claimed_by("ACME.com", "con_2", &connections).map(|c| c.id.as_str()), Some("con_1"));
+        assert_eq!(domain_claimed_by("acme.com", "con_1", &connections), None);
+        assert_eq!(domain_claimed_by("globex.com", "", &connections).map(|c| c.id.as_str()), Some("con_2"));
+        assert_eq!(home_realm("Jane@Globex.COM", &connections).map(|c| c.id.as_str()), Some("con_2"));
+        assert_eq!(home_realm("jane@initech.com", &connections), None);
+        assert_eq!(home_realm("@acme.com", &connections), None);
+        assert_eq!(validate_unclaimed_domains(&domains(&["initech.com", "acme.com"]), "con_1", &connections), Ok(()));
+        assert_eq!(
+            validate_unclaimed_domains(&domains(&["initech.com", "GLOBEX.com"]), "", &connections),
+            Err(String::from("globex.com is already claimed by the con_2-connection connection"))
+        );
+    }
+
+    const DISCOVERY: &str = r#"{
+        "issuer": "https://idp.example.com",
+        "authorization_endpoint": "https://idp.example.com/authorize",
+        "token_endpoint": "https://idp.example.com/token",
+        "userinfo_endpoint": "https://idp.example.com/userinfo",
+        "jwks_uri": "https://idp.example.com/.well-known/jwks.json",
+        "scopes_supported": ["openid", "profile", "email", "groups"],
+        "response_types_supported": ["code", "id_token token", "token id_token"],
+        "response_modes_supported": ["query", "fragment"],
+        "id_token_signing_alg_values_supported": ["RS256", "none"]
+    }"#;
+
+    #[test]
+    fn a_valid_discovery_document_is_parsed() {
+        let discovery = OidcDiscovery::parse(DISCOVERY).unwrap();
+        assert_eq!(discovery.issuer, "https://idp.example.com");
+        assert_eq!(discovery.jwks_uri, "https://idp.example.com/.well-known/jwks.json");
+        assert_eq!(discovery.signing_algorithms(), vec![String::from("RS256")]);
+        assert!(discovery.problems().is_empty(), "{:?}", discovery.problems());
+        assert_eq!(oidc_discovery_url("https://idp.example.com/"), "https://idp.example.com/.well-known/openid-configuration");
+        assert_eq!(
+            oidc_discovery_url("https://idp.example.com/.well-known/openid-configuration"),
+            "https://idp.example.com/.well-known/openid-configuration"
+        );
+    }
+
+    #[test]
+    fn a_discovery_document_needs_an_issuer_and_jwks_uri() {
+        for key in ["issuer", "jwks_uri", "authorization_endpoint"].iter() {
+            let mut value: Value = serde_json::from_str(DISCOVERY).unwrap();
+            value.as_object_mut().unwrap().remove(*key);
+            assert_eq!(OidcDiscovery::parse(&value.to_string()), Err(format!("The discovery document has no {}", key)));
+        }
+        assert_eq!(
+            OidcDiscovery::parse(r#"{"issuer": 1, "authorization_endpoint": "https://a.com", "jwks_uri": "https://a.com"}"#),
+            Err(String::from("The discovery document has no issuer"))
+        );
+        assert_eq!(OidcDiscovery::parse("[]"), Err(String::from("The discovery document must be a JSON object")));
+        assert!(OidcDiscovery::parse("{").is_err());
+    }
+
+    #[test]
+    fn connection_types_follow_the_response_types() {
+        let discovery = OidcDiscovery::parse(DISCOVERY).unwrap();
+        assert!(discovery.supports(OIDC_BACK_CHANNEL));
+        // NO form_post RESPONSE MODE
+        assert!(!discovery.supports(OIDC_FRONT_CHANNEL));
+        assert!(!discovery.supports("password"));
+
+        let mut front_channel = discovery.clone();
+        front_channel.response_types_supported = vec![String::from("id_token")];
+        front_channel.response_modes_supported = vec![String::from("form_post")];
+        assert!(front_channel.supports(OIDC_FRONT_CHANNEL));
+        assert!(!front_channel.supports(OIDC_BACK_CHANNEL));
+
+        let mut unsupported = discovery;
+        unsupported.response_types_supported = vec![String::from("code id_token")];
+        assert!(!unsupported.supports(OIDC_BACK_CHANNEL));
+        assert!(!unsupported.supports(OIDC_FRONT_CHANNEL));
+        assert!(unsupported
+            .problems()
+            .contains(&String::from("The provider supports neither the code flow nor the id_token flow")));
+    }
+
+    #[test]
+    fn discovery_problems_are_listed() {
+        let mut discovery = OidcDiscovery::parse(DISCOVERY).unwrap();
+        discovery.token_endpoint = String::from("http://idp.example.com/token");
+        discovery.scopes_supported = vec![String::from("profile")];
+        discovery.id_token_signing_alg_values_supported = vec![String::from("none")];
+        let problems = discovery.problems();
+        assert_eq!(problems.len(), 3, "{:?}", problems);
+        assert!(problems[0].starts_with("Token Endpoint "));
+        assert_eq!(problems[1], "The provider doesn't support the openid scope");
+        assert!(problems[2].starts_with("The provider signs ID tokens with none,"));
+    }
+
+    #[test]
+    fn applying_a_discovery_keeps_what_the_user_set() {
+        let discovery = OidcDiscovery::parse(DISCOVERY).unwrap();
+        let options = OidcOptions {
+            client_id: String::from("client"),
+            client_secret: String::from("secret"),
+            scope: String::from("email groups offline_access"),
+            set_user_root_attributes: String::from("on_first_login"),
+            domain_aliases: domains(&["acme.com"]),
+            ..OidcOptions::default()
+        };
+        let applied = discovery.apply(&options, "https://idp.example.com/.well-known/openid-configuration");
+        assert_eq!(applied.client_id, "client");
+        assert_eq!(applied.client_secret, "secret");
+        assert_eq!(applied.oidc_type, OIDC_BACK_CHANNEL);
+        assert_eq!(applied.set_user_root_attributes, "on_first_login");
+        assert_eq!(applied.domain_aliases, domains(&["acme.com"]));
+        assert_eq!(applied.scope, "openid email groups");
+        assert_eq!(applied.issuer, "https://idp.example.com");
+        assert_eq!(applied.token_endpoint, "https://idp.example.com/token");
+        assert_eq!(applied.discovery_url, "https://idp.example.com/.well-known/openid-configuration");
+        assert_eq!(validate_oidc_options(&applied), Ok(()));
+
+        let front_channel = OidcOptions { oidc_type: String::from(OIDC_FRONT_CHANNEL), ..options };
+        assert_eq!(discovery.apply(&front_channel, "").oidc_type, OIDC_BACK_CHANNEL);
+    }
+}
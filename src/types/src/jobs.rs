@@ -0,0 +1,64 @@
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::Value;
+
+// BACKGROUND JOBS
+// Long running work such as user imports is submitted as a job and polled
+// until its status is completed or failed
+
+pub const JOB_PENDING: &str = "pending";
+pub const JOB_PROCESSING: &str = "processing";
+pub const JOB_COMPLETED: &str = "completed";
+pub const JOB_FAILED: &str = "failed";
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default, Eq, PartialEq)]
+pub struct JobSummary {
+    pub failed: u32,
+    pub updated: u32,
+    pub inserted: u32,
+    pub total: u32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct Job {
+    pub id: String,
+    pub status: String,
+    #[serde(rename = "type")]
+    pub job_type: String,
+    pub created_at: String,
+    #[serde(default)]
+    pub connection_id: String,
+    #[serde(default)]
+    pub summary: Option<JobSummary>,
+}
+
+impl Job {
+    pub fn is_finished(&self) -> bool {
+        self.status == JOB_COMPLETED || self.status == JOB_FAILED
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct JobErrorDetail {
+    pub code: String,
+    pub message: String,
+    #[serde(default)]
+    pub path: String,
+}
+
+// ONE ENTRY PER RECORD THE JOB REJECTED
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct JobError {
+    pub user: Value,
+    pub errors: Vec<JobErrorDetail>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct UsersImportCreate {
+    pub connection_id: String,
+    pub upsert: bool,
+    pub send_completion_email: bool,
+    pub users: Vec<Value>,
+}
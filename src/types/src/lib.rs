@@ -33,4 +33,15 @@ pub mod api;
 pub mod login;
 pub mod users;
 pub mod settings;
-pub mod roles;
\ No newline at end of file
+pub mod roles;
+pub mod organizations;
+pub mod sso;
+pub mod pagination;
+pub mod jobs;
+pub mod database;
+pub mod social;
+pub mod enterprise;
+pub mod passwordless;
+pub mod logs;
+pub mod log_streams;
+pub mod activity;
\ No newline at end of file
@@ -0,0 +1,504 @@
+use std::net::Ipv6Addr;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use crate::{
+    logs::LogCategory,
+    passwordless::validate_webhook_url,
+};
+
+// LOG STREAMS
+// A stream forwards new log events to an external sink, an HTTP webhook or a
+// syslog server. Every type keeps its settings, only the selected one is used
+
+pub const LOG_STREAM_HTTP: &str = "http";
+pub const LOG_STREAM_SYSLOG: &str = "syslog";
+
+// (value, label, description)
+pub const LOG_STREAM_TYPES: [(&str, &str, &str); 2] = [
+    (LOG_STREAM_HTTP, "Custom Webhook", "Post batches of log events to an HTTP endpoint, eg. a SIEM collector."),
+    (LOG_STREAM_SYSLOG, "Syslog", "Send every log event as an RFC 5424 message to a syslog server."),
+];
+
+pub const LOG_STREAM_ACTIVE: &str = "active";
+pub const LOG_STREAM_PAUSED: &str = "paused";
+// SET BY THE SERVER AFTER TOO MANY FAILED DELIVERIES, RESUMING CLEARS IT
+pub const LOG_STREAM_SUSPENDED: &str = "suspended";
+
+pub const HTTP_FORMAT_JSON_ARRAY: &str = "json_array";
+pub const HTTP_FORMAT_JSON_LINES: &str = "json_lines";
+
+// (value, label, content type)
+pub const HTTP_FORMATS: [(&str, &str, &str); 2] = [
+    (HTTP_FORMAT_JSON_ARRAY, "JSON array", "application/json"),
+    (HTTP_FORMAT_JSON_LINES, "JSON lines", "application/x-ndjson"),
+];
+
+// EVENTS PER REQUEST, AND SECONDS TO WAIT FOR A BATCH TO FILL UP
+pub const MAX_BATCH_SIZE: u32 = 500;
+pub const MAX_BATCH_INTERVAL: u32 = 60;
+
+pub const SYSLOG_PROTOCOL_UDP: &str = "udp";
+pub const SYSLOG_PROTOCOL_TCP: &str = "tcp";
+pub const SYSLOG_PROTOCOL_TLS: &str = "tls";
+
+// (value, label)
+pub const SYSLOG_PROTOCOLS: [(&str, &str); 3] = [
+    (SYSLOG_PROTOCOL_UDP, "UDP"),
+    (SYSLOG_PROTOCOL_TCP, "TCP"),
+    (SYSLOG_PROTOCOL_TLS, "TCP with TLS"),
+];
+
+pub const DEFAULT_SYSLOG_PORT: u16 = 514;
+
+const MAX_LOG_STREAM_NAME: usize = 50;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct HttpSink {
+    pub url: String,
+    // SENT AS THE Authorization HEADER, EG. "Bearer eyJ..."
+    #[serde(default)]
+    pub authorization: String,
+    pub format: String,
+    pub batch_size: u32,
+    pub batch_interval: u32,
+}
+
+impl HttpSink {
+    pub fn new() -> HttpSink {
+        HttpSink {
+            url: String::from(""),
+            authorization: String::from(""),
+            format: String::from(HTTP_FORMAT_JSON_ARRAY),
+            batch_size: 100,
+            batch_interval: 5,
+        }
+    }
+}
+
+impl Default for HttpSink {
+    fn default() -> Self {
+        HttpSink::new()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct SyslogSink {
+    pub host: String,
+    pub port: u16,
+    pub protocol: String,
+}
+
+impl SyslogSink {
+    pub fn new() -> SyslogSink {
+        SyslogSink {
+            host: String::from(""),
+            port: DEFAULT_SYSLOG_PORT,
+            protocol: String::from(SYSLOG_PROTOCOL_UDP),
+        }
+    }
+}
+
+impl Default for SyslogSink {
+    fn default() -> Self {
+        SyslogSink::new()
+    }
+}
+
+// KEPT BY THE SERVER, READ ONLY
+#[derive(Deserialize, Serialize, Debug, Clone, Default, Eq, PartialEq)]
+#[serde(default)]
+pub struct LogStreamHealth {
+    pub delivered: u64,
+    pub failed: u64,
+    pub consecutive_failures: u32,
+    pub last_delivery_at: String,
+    pub last_error: String,
+    pub last_error_at: String,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LogStreamHealthStatus {
+    Healthy,
+    Failing,
+    Waiting,
+    Paused,
+    Suspended,
+}
+
+impl LogStreamHealthStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogStreamHealthStatus::Healthy => "Healthy",
+            LogStreamHealthStatus::Failing => "Failing",
+            LogStreamHealthStatus::Waiting => "No deliveries yet",
+            LogStreamHealthStatus::Paused => "Paused",
+            LogStreamHealthStatus::Suspended => "Suspended",
+        }
+    }
+
+    // BOOTSTRAP ICON CLASS
+    pub fn icon(&self) -> &'static str {
+        match self {
+            LogStreamHealthStatus::Healthy => "bi-check-circle-fill",
+            LogStreamHealthStatus::Failing => "bi-exclamation-triangle-fill",
+            LogStreamHealthStatus::Waiting => "bi-hourglass-split",
+            LogStreamHealthStatus::Paused => "bi-pause-circle-fill",
+            LogStreamHealthStatus::Suspended => "bi-x-octagon-fill",
+        }
+    }
+
+    // BOOTSTRAP TEXT COLOR CLASS
+    pub fn color(&self) -> &'static str {
+        match self {
+            LogStreamHealthStatus::Healthy => "text-success",
+            LogStreamHealthStatus::Failing => "text-warning",
+            LogStreamHealthStatus::Waiting | LogStreamHealthStatus::Paused => "text-secondary",
+            LogStreamHealthStatus::Suspended => "text-danger",
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct LogStream {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub stream_type: String,
+    pub status: String,
+    #[serde(default)]
+    pub http: HttpSink,
+    #[serde(default)]
+    pub syslog: SyslogSink,
+    // CATEGORY KEYS, EMPTY FORWARDS EVERY EVENT
+    #[serde(default)]
+    pub filters: Vec<String>,
+    #[serde(default)]
+    pub health: LogStreamHealth,
+    #[serde(default)]
+    pub created_at: String,
+}
+
+impl LogStream {
+    pub fn new(stream_type: &str) -> LogStream {
+        LogStream {
+            id: String::from(""),
+            name: String::from(""),
+            stream_type: String::from(stream_type),
+            status: String::from(LOG_STREAM_ACTIVE),
+            http: HttpSink::new(),
+            syslog: SyslogSink::new(),
+            filters: vec![],
+            health: LogStreamHealth::default(),
+            created_at: String::from(""),
+        }
+    }
+
+    pub fn health_status(&self) -> LogStreamHealthStatus {
+        match self.status.as_str() {
+            LOG_STREAM_PAUSED => LogStreamHealthStatus::Paused,
+            LOG_STREAM_SUSPENDED => LogStreamHealthStatus::Suspended,
+            _ if self.health.consecutive_failures > 0 => LogStreamHealthStatus::Failing,
+            _ if self.health.delivered == 0 => LogStreamHealthStatus::Waiting,
+            _ => LogStreamHealthStatus::Healthy,
+        }
+    }
+
+    // WHERE THE EVENTS GO, SHOWN IN LISTS
+    pub fn destination(&self) -> String {
+        match self.stream_type.as_str() {
+            LOG_STREAM_SYSLOG => format!("{}://{}:{}", self.syslog.protocol, self.syslog.host, self.syslog.port),
+            _ => self.http.url.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct LogStreamCreate {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub stream_type: String,
+    pub http: HttpSink,
+    pub syslog: SyslogSink,
+    pub filters: Vec<String>,
+}
+
+impl From<&LogStream> for LogStreamCreate {
+    fn from(stream: &LogStream) -> Self {
+        LogStreamCreate {
+            name: stream.name.trim().to_string(),
+            stream_type: stream.stream_type.clone(),
+            http: stream.http.clone(),
+            syslog: stream.syslog.clone(),
+            filters: stream.filters.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct LogStreamUpdate {
+    pub name: String,
+    pub http: HttpSink,
+    pub syslog: SyslogSink,
+    pub filters: Vec<String>,
+}
+
+impl From<&LogStream> for LogStreamUpdate {
+    fn from(stream: &LogStream) -> Self {
+        LogStreamUpdate {
+            name: stream.name.trim().to_string(),
+            http: stream.http.clone(),
+            syslog: stream.syslog.clone(),
+            filters: stream.filters.clone(),
+        }
+    }
+}
+
+// PAUSE AND RESUME
+#[derive(Serialize, Debug, Clone)]
+pub struct LogStreamStatusUpdate {
+    pub status: String,
+}
+
+// SENDS ONE SAMPLE EVENT, TO THE STREAM'S OWN SINK WHEN target IS EMPTY,
+// OTHERWISE TO A URL (WEBHOOK) OR host:port (SYSLOG), EG. A LOCAL LISTENER
+#[derive(Serialize, Debug, Clone)]
+pub struct LogStreamTest {
+    pub target: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct LogStreamTestResult {
+    pub delivered: bool,
+    pub target: String,
+    // EXACTLY WHAT WAS SENT
+    pub payload: String,
+    // HTTP ONLY
+    pub status: Option<u16>,
+    #[serde(default)]
+    pub response: String,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+pub fn log_stream_type_label(stream_type: &str) -> &str {
+    LOG_STREAM_TYPES
+        .iter()
+        .find(|(value, _, _)| *value == stream_type)
+        .map(|(_, label, _)| *label)
+        .unwrap_or(stream_type)
+}
+
+pub fn log_stream_type_icon(stream_type: &str) -> &'static str {
+    match stream_type {
+        LOG_STREAM_SYSLOG => "bi-hdd-network",
+        _ => "bi-broadcast-pin",
+    }
+}
+
+pub fn validate_log_stream_name(name: &str) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(String::from("Stream Name is required"));
+    }
+    if name.chars().count() > MAX_LOG_STREAM_NAME {
+        return Err(format!("Stream Name can't have more than {} characters", MAX_LOG_STREAM_NAME));
+    }
+    Ok(())
+}
+
+pub fn validate_http_sink(sink: &HttpSink) -> Result<(), String> {
+    validate_webhook_url(sink.url.trim())?;
+    if sink.authorization.contains(['\r', '\n']) {
+        return Err(String::from("Authorization Token must be on a single line"));
+    }
+    if !HTTP_FORMATS.iter().any(|(value, _, _)| *value == sink.format) {
+        return Err(String::from("Content Format is unknown"));
+    }
+    if sink.batch_size == 0 || sink.batch_size > MAX_BATCH_SIZE {
+        return Err(format!("Batch Size must be between 1 and {}", MAX_BATCH_SIZE));
+    }
+    if sink.batch_interval == 0 || sink.batch_interval > MAX_BATCH_INTERVAL {
+        return Err(format!("Batch Interval must be between 1 and {} seconds", MAX_BATCH_INTERVAL));
+    }
+    Ok(())
+}
+
+// A HOSTNAME, AN IPV4 ADDRESS OR A BARE IPV6 ADDRESS
+fn validate_host(host: &str) -> bool {
+    if host.contains(':') {
+        return host.parse::<Ipv6Addr>().is_ok();
+    }
+    !host.is_empty() && host.chars().all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-')
+}
+
+// "syslog.example.com:514" -> ("syslog.example.com", 514), "[::1]:514" -> ("::1", 514)
+pub fn parse_host_port(target: &str) -> Result<(&str, u16), String> {
+    let (host, port) = match target.strip_prefix('[') {
+        Some(rest) => match rest.split_once("]:") {
+            Some((host, port)) if host.parse::<Ipv6Addr>().is_ok() => (host, port),
+            _ => return Err(format!("{} is not a valid [IPv6]:port", target)),
+        },
+        None => match target.rsplit_once(':') {
+            Some((host, _)) if host.contains(':') => {
+                return Err(String::from("IPv6 addresses must be written in brackets, eg. [::1]:514"))
+            }
+            Some((host, port)) if validate_host(host) => (host, port),
+            Some(_) => return Err(format!("{} is not a valid host:port", target)),
+            None => return Err(String::from("Test target must be written as host:port, eg. 127.0.0.1:5514")),
+        },
+    };
+    match port.parse::<u16>() {
+        Ok(port) if port > 0 => Ok((host, port)),
+        _ => Err(format!("{} is not a port between 1 and 65535", port)),
+    }
+}
+
+pub fn validate_syslog_sink(sink: &SyslogSink) -> Result<(), String> {
+    if !validate_host(sink.host.trim()) {
+        return Err(String::from("Syslog Host must be a hostname or an IP address"));
+    }
+    if sink.port == 0 {
+        return Err(String::from("Syslog Port must be between 1 and 65535"));
+    }
+    if !SYSLOG_PROTOCOLS.iter().any(|(value, _)| *value == sink.protocol) {
+        return Err(String::from("Syslog Protocol is unknown"));
+    }
+    Ok(())
+}
+
+pub fn validate_log_stream_filters(filters: &[String]) -> Result<(), String> {
+    for (index, key) in filters.iter().enumerate() {
+        if LogCategory::from_key(key).is_none() {
+            return Err(format!("{} is not a log category", key));
+        }
+        if filters[..index].contains(key) {
+            return Err(format!("{} is selected twice", key));
+        }
+    }
+    Ok(())
+}
+
+pub fn validate_log_stream(stream: &LogStream) -> Result<(), String> {
+    validate_log_stream_name(&stream.name)?;
+    match stream.stream_type.as_str() {
+        LOG_STREAM_HTTP => validate_http_sink(&stream.http)?,
+        LOG_STREAM_SYSLOG => validate_syslog_sink(&stream.syslog)?,
+        _ => return Err(String::from("Stream type is unknown")),
+    }
+    validate_log_stream_filters(&stream.filters)
+}
+
+// EMPTY KEEPS THE STREAM'S OWN SINK
+pub fn validate_log_stream_test_target(stream_type: &str, target: &str) -> Result<(), String> {
+    let target = target.trim();
+    if target.is_empty() {
+        return Ok(());
+    }
+    if stream_type != LOG_STREAM_SYSLOG {
+        return validate_webhook_url(target);
+    }
+    parse_host_port(target).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(list: &[&str]) -> Vec<String> {
+        list.iter().map(|key| String::from(*key)).collect()
+    }
+
+    #[test]
+    fn http_sinks_are_validated() {
+        let sink = HttpSink { url: String::from("https://siem.example.com/collect"), ..HttpSink::new() };
+        assert_eq!(validate_http_sink(&sink), Ok(()));
+        assert_eq!(validate_http_sink(&HttpSink { url: String::from("http://localhost:8080/logs"), ..sink.clone() }), Ok(()));
+        assert!(validate_http_sink(&HttpSink { url: String::from("http://siem.example.com"), ..sink.clone() }).is_err());
+        assert_eq!(
+            validate_http_sink(&HttpSink { authorization: String::from("Bearer a\r\nX-Injected: 1"), ..sink.clone() }),
+            Err(String::from("Authorization Token must be on a single line"))
+        );
+        assert_eq!(
+            validate_http_sink(&HttpSink { format: String::from("xml"), ..sink.clone() }),
+            Err(String::from("Content Format is unknown"))
+        );
+        assert_eq!(validate_http_sink(&HttpSink { batch_size: MAX_BATCH_SIZE, batch_interval: MAX_BATCH_INTERVAL, ..sink.clone() }), Ok(()));
+        assert!(validate_http_sink(&HttpSink { batch_size: 0, ..sink.clone() }).is_err());
+        assert!(validate_http_sink(&HttpSink { batch_size: MAX_BATCH_SIZE + 1, ..sink.clone() }).is_err());
+        assert!(validate_http_sink(&HttpSink { batch_interval: 0, ..sink.clone() }).is_err());
+        assert!(validate_http_sink(&HttpSink { batch_interval: MAX_BATCH_INTERVAL + 1, ..sink }).is_err());
+    }
+
+    #[test]
+    fn syslog_sinks_are_validated() {
+        let sink = SyslogSink { host: String::from("syslog.example.com"), ..SyslogSink::new() };
+        assert_eq!(validate_syslog_sink(&sink), Ok(()));
+        for host in ["10.0.0.5", "::1", "fe80::1", " logs-1.example.com "].iter() {
+            assert_eq!(validate_syslog_sink(&SyslogSink { host: String::from(*host), ..sink.clone() }), Ok(()), "{}", host);
+        }
+        for host in ["", "syslog example.com", "[::1]", ":::1", "host:514", "syslog.example.com/"].iter() {
+            assert!(validate_syslog_sink(&SyslogSink { host: String::from(*host), ..sink.clone() }).is_err(), "{}", host);
+        }
+        assert!(validate_syslog_sink(&SyslogSink { port: 0, ..sink.clone() }).is_err());
+        assert_eq!(
+            validate_syslog_sink(&SyslogSink { protocol: String::from("http"), ..sink }),
+            Err(String::from("Syslog Protocol is unknown"))
+        );
+    }
+
+    #[test]
+    fn filters_are_known_categories_listed_once() {
+        assert_eq!(validate_log_stream_filters(&[]), Ok(()));
+        assert_eq!(validate_log_stream_filters(&keys(&["login_success", "logout"])), Ok(()));
+        assert_eq!(
+            validate_log_stream_filters(&keys(&["login_success", "nope"])),
+            Err(String::from("nope is not a log category"))
+        );
+        assert_eq!(
+            validate_log_stream_filters(&keys(&["logout", "signup", "logout"])),
+            Err(String::from("logout is selected twice"))
+        );
+    }
+
+    #[test]
+    fn host_and_port_are_parsed() {
+        assert_eq!(parse_host_port("syslog.example.com:514"), Ok(("syslog.example.com", 514)));
+        assert_eq!(parse_host_port("127.0.0.1:5514"), Ok(("127.0.0.1", 5514)));
+        assert_eq!(parse_host_port("[::1]:514"), Ok(("::1", 514)));
+        assert_eq!(parse_host_port("[2001:db8::7]:65535"), Ok(("2001:db8::7", 65535)));
+        for target in [
+            "syslog.example.com",
+            "syslog.example.com:",
+            "syslog.example.com:syslog",
+            "syslog.example.com:0",
+            "syslog.example.com:65536",
+            "syslog.example.com:-1",
+            ":514",
+            "::1:514",
+            "[::1]",
+            "[::1]514",
+            "[nope]:514",
+            "[::1]:abc",
+        ]
+        .iter()
+        {
+            assert!(parse_host_port(target).is_err(), "{} should be rejected", target);
+        }
+        assert_eq!(
+            parse_host_port("::1:514"),
+            Err(String::from("IPv6 addresses must be written in brackets, eg. [::1]:514"))
+        );
+    }
+
+    #[test]
+    fn test_targets_follow_the_stream_type() {
+        assert_eq!(validate_log_stream_test_target(LOG_STREAM_SYSLOG, "  "), Ok(()));
+        assert_eq!(validate_log_stream_test_target(LOG_STREAM_HTTP, ""), Ok(()));
+        assert_eq!(validate_log_stream_test_target(LOG_STREAM_SYSLOG, " [::1]:514 "), Ok(()));
+        assert!(validate_log_stream_test_target(LOG_STREAM_SYSLOG, "https://siem.example.com").is_err());
+        assert_eq!(validate_log_stream_test_target(LOG_STREAM_HTTP, "https://siem.example.com/collect"), Ok(()));
+        assert!(validate_log_stream_test_target(LOG_STREAM_HTTP, "127.0.0.1:5514").is_err());
+    }
+}
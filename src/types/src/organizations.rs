@@ -0,0 +1,188 @@
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct OrganizationColors {
+    pub primary: String,
+    pub page_background: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct OrganizationBranding {
+    pub logo_url: String,
+    pub colors: OrganizationColors,
+}
+
+impl OrganizationBranding {
+    pub fn new() -> OrganizationBranding {
+        OrganizationBranding {
+            logo_url: String::from(""),
+            colors: OrganizationColors {
+                primary: String::from("#0059d6"),
+                page_background: String::from("#000000"),
+            },
+        }
+    }
+}
+
+impl Default for OrganizationBranding {
+    fn default() -> Self {
+        OrganizationBranding::new()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct Organization {
+    pub id: String,
+    pub name: String,
+    pub display_name: String,
+    pub branding: OrganizationBranding,
+}
+
+impl Organization {
+    pub fn new() -> Organization {
+        Organization {
+            id: String::from(""),
+            name: String::from(""),
+            display_name: String::from(""),
+            branding: OrganizationBranding::new(),
+        }
+    }
+}
+
+impl Default for Organization {
+    fn default() -> Self {
+        Organization::new()
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct OrganizationCreate {
+    pub name: String,
+    pub display_name: String,
+}
+
+impl OrganizationCreate {
+    pub fn new() -> OrganizationCreate {
+        OrganizationCreate {
+            name: String::from(""),
+            display_name: String::from(""),
+        }
+    }
+}
+
+impl Default for OrganizationCreate {
+    fn default() -> Self {
+        OrganizationCreate::new()
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct OrganizationUpdate {
+    pub name: String,
+    pub display_name: String,
+    pub branding: OrganizationBranding,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct OrganizationMember {
+    pub user_id: String,
+    pub email: String,
+    pub picture: String,
+    pub name: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct OrganizationMemberRole {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct InvitationInviter {
+    pub name: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct InvitationInvitee {
+    pub email: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct OrganizationInvitation {
+    pub id: String,
+    pub organization_id: String,
+    pub inviter: InvitationInviter,
+    pub invitee: InvitationInvitee,
+    pub invitation_url: String,
+    pub created_at: String,
+    pub expires_at: String,
+    pub client_id: String,
+    pub connection_id: String,
+    pub roles: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct InvitationCreate {
+    pub inviter: InvitationInviter,
+    pub invitee: InvitationInvitee,
+    pub client_id: String,
+    pub connection_id: String,
+    pub ttl_sec: u64,
+    pub roles: Vec<String>,
+    pub send_invitation_email: bool,
+}
+
+impl InvitationCreate {
+    pub fn new() -> InvitationCreate {
+        InvitationCreate {
+            inviter: InvitationInviter {
+                name: String::from(""),
+            },
+            invitee: InvitationInvitee {
+                email: String::from(""),
+            },
+            client_id: String::from(""),
+            connection_id: String::from(""),
+            // 7 days, the default invitation lifetime
+            ttl_sec: 604800,
+            roles: vec![],
+            send_invitation_email: true,
+        }
+    }
+}
+
+impl Default for InvitationCreate {
+    fn default() -> Self {
+        InvitationCreate::new()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct ConnectionInfo {
+    pub name: String,
+    pub strategy: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct OrganizationConnection {
+    pub connection_id: String,
+    pub assign_membership_on_login: bool,
+    pub connection: ConnectionInfo,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct ConnectionOption {
+    pub id: String,
+    pub name: String,
+    pub strategy: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct OrganizationConnectionCreate {
+    pub connection_id: String,
+    pub assign_membership_on_login: bool,
+}
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+// LIST RESPONSE WHEN REQUESTED WITH include_totals=true
+#[derive(Deserialize, Debug, Clone)]
+pub struct Page<T> {
+    pub start: u32,
+    pub limit: u32,
+    pub length: u32,
+    pub total: u32,
+    #[serde(alias = "users", alias = "roles", alias = "logs")]
+    pub items: Vec<T>,
+}
@@ -0,0 +1,889 @@
+use std::collections::BTreeMap;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::Value;
+use crate::{
+    enterprise::email_domain,
+    social::validate_https_url,
+};
+
+// PASSWORDLESS CONNECTIONS
+
+pub const PASSWORDLESS_EMAIL_STRATEGY: &str = "email";
+
+pub const TEMPLATE_SYNTAX_LIQUID: &str = "liquid";
+
+// (name, description, sample value rendered by the preview)
+pub type TemplateVariable = (&'static str, &'static str, &'static str);
+
+pub const EMAIL_TEMPLATE_VARIABLES: [TemplateVariable; 7] = [
+    ("code", "The one-time password", "482913"),
+    ("link", "The magic link, only sent when the login uses links", "https://telkom-domain.example.com/passwordless/verify_redirect?verification_code=482913"),
+    ("email", "The recipient email", "jane@example.com"),
+    ("send", "What is sent, code or link", "code"),
+    ("application.name", "The name of the application the user logs in to", "My App"),
+    ("application.logo", "The logo of the application the user logs in to", "https://cdn.example.com/logo.png"),
+    ("request.language", "The language requested by the browser", "en-US"),
+];
+
+pub const OTP_LENGTH_MIN: u32 = 4;
+pub const OTP_LENGTH_MAX: u32 = 10;
+
+pub const DEFAULT_EMAIL_FROM: &str = "{{ application.name }} <root@telkom-domain.example.com>";
+pub const DEFAULT_EMAIL_SUBJECT: &str = "Welcome to {{ application.name }}";
+pub const DEFAULT_EMAIL_BODY: &str = r#"<html>
+  <body style="font-family: sans-serif; background-color: #f5f5f5; padding: 24px;">
+    <div style="max-width: 480px; margin: 0 auto; background-color: #ffffff; border-radius: 6px; padding: 32px;">
+      <img src="{{ application.logo }}" alt="{{ application.name }}" style="max-height: 48px;"/>
+      <h2>Welcome to {{ application.name }}</h2>
+      <p>Your verification code is:</p>
+      <p style="font-size: 28px; font-weight: bold; letter-spacing: 4px;">{{ code }}</p>
+      <p>This code will expire in five minutes. If you did not request it, you can ignore this email.</p>
+    </div>
+  </body>
+</html>"#;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct EmailTemplate {
+    pub from: String,
+    pub subject: String,
+    pub body: String,
+    pub syntax: String,
+}
+
+impl EmailTemplate {
+    pub fn new() -> EmailTemplate {
+        EmailTemplate {
+            from: String::from(DEFAULT_EMAIL_FROM),
+            subject: String::from(DEFAULT_EMAIL_SUBJECT),
+            body: String::from(DEFAULT_EMAIL_BODY),
+            syntax: String::from(TEMPLATE_SYNTAX_LIQUID),
+        }
+    }
+}
+
+impl Default for EmailTemplate {
+    fn default() -> Self {
+        EmailTemplate::new()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct Totp {
+    // SECONDS BEFORE THE ONE-TIME PASSWORD EXPIRES
+    pub time_step: u32,
+    pub length: u32,
+}
+
+impl Totp {
+    pub fn new() -> Totp {
+        Totp {
+            time_step: 300,
+            length: 6,
+        }
+    }
+}
+
+impl Default for Totp {
+    fn default() -> Self {
+        Totp::new()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct PasswordlessEmailOptions {
+    pub email: EmailTemplate,
+    pub totp: Totp,
+    // QUERY STRING PARAMETERS ADDED TO THE MAGIC LINK
+    #[serde(rename = "authParams", default)]
+    pub auth_params: BTreeMap<String, String>,
+    #[serde(default)]
+    pub disable_signup: bool,
+}
+
+impl PasswordlessEmailOptions {
+    pub fn new() -> PasswordlessEmailOptions {
+        PasswordlessEmailOptions {
+            email: EmailTemplate::new(),
+            totp: Totp::new(),
+            auth_params: BTreeMap::new(),
+            disable_signup: false,
+        }
+    }
+}
+
+impl Default for PasswordlessEmailOptions {
+    fn default() -> Self {
+        PasswordlessEmailOptions::new()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct PasswordlessEmailConnection {
+    pub id: String,
+    pub name: String,
+    pub strategy: String,
+    pub enabled_clients: Vec<String>,
+    pub options: PasswordlessEmailOptions,
+}
+
+impl PasswordlessEmailConnection {
+    pub fn new() -> PasswordlessEmailConnection {
+        PasswordlessEmailConnection {
+            id: String::from(""),
+            name: String::from(PASSWORDLESS_EMAIL_STRATEGY),
+            strategy: String::from(PASSWORDLESS_EMAIL_STRATEGY),
+            enabled_clients: vec![],
+            options: PasswordlessEmailOptions::new(),
+        }
+    }
+}
+
+impl Default for PasswordlessEmailConnection {
+    fn default() -> Self {
+        PasswordlessEmailConnection::new()
+    }
+}
+
+// THE TENANT HAS AT MOST ONE EMAIL CONNECTION, IT IS CREATED ON THE FIRST SAVE
+#[derive(Serialize, Debug, Clone)]
+pub struct PasswordlessEmailConnectionCreate {
+    pub name: String,
+    pub strategy: String,
+    pub options: PasswordlessEmailOptions,
+}
+
+impl From<&PasswordlessEmailConnection> for PasswordlessEmailConnectionCreate {
+    fn from(connection: &PasswordlessEmailConnection) -> Self {
+        PasswordlessEmailConnectionCreate {
+            name: connection.name.clone(),
+            strategy: String::from(PASSWORDLESS_EMAIL_STRATEGY),
+            options: connection.options.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PasswordlessEmailConnectionUpdate {
+    pub options: PasswordlessEmailOptions,
+}
+
+impl From<&PasswordlessEmailConnection> for PasswordlessEmailConnectionUpdate {
+    fn from(connection: &PasswordlessEmailConnection) -> Self {
+        PasswordlessEmailConnectionUpdate {
+            options: connection.options.clone(),
+        }
+    }
+}
+
+// TEMPLATES
+
+// EVERY "{{ name }}" OF THE TEMPLATE IN ORDER, FILTERS LIKE "{{ code | upcase }}" ARE DROPPED
+pub fn template_variables(template: &str) -> Result<Vec<String>, String> {
+    let mut variables = vec![];
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let end = match after.find("}}") {
+            Some(end) => end,
+            None => return Err(String::from("A {{ is never closed with }}")),
+        };
+        let name = after[..end].split('|').next().unwrap_or("").trim();
+        if name.is_empty() {
+            return Err(String::from("A {{ }} has no variable"));
+        }
+        variables.push(name.to_string());
+        rest = &after[end + 2..];
+    }
+    Ok(variables)
+}
+
+pub fn unknown_template_variables(template: &str, variables: &[TemplateVariable]) -> Vec<String> {
+    let mut unknown: Vec<String> = vec![];
+    for name in template_variables(template).unwrap_or_default() {
+        if !variables.iter().any(|(known, _, _)| *known == name) && !unknown.contains(&name) {
+            unknown.push(name);
+        }
+    }
+    unknown
+}
+
+pub fn template_samples(variables: &[TemplateVariable]) -> BTreeMap<String, String> {
+    variables
+        .iter()
+        .map(|(name, _, sample)| (name.to_string(), sample.to_string()))
+        .collect()
+}
+
+// HOW VALUES ARE WRITTEN INTO THE TEMPLATE
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Escape {
+    None,
+    Html,
+    // INSIDE A JSON STRING, THE QUOTES BELONG TO THE TEMPLATE
+    Json,
+    // A FORM URLENCODED VALUE
+    Url,
+}
+
+// UNKNOWN VARIABLES ARE KEPT AS WRITTEN SO THEY STAND OUT IN THE PREVIEW
+pub fn render_template(template: &str, values: &BTreeMap<String, String>, escape: Escape) -> String {
+    let mut rendered = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let end = match after.find("}}") {
+            Some(end) => end,
+            None => break,
+        };
+        rendered.push_str(&rest[..start]);
+        let name = after[..end].split('|').next().unwrap_or("").trim();
+        match values.get(name) {
+            Some(value) => match escape {
+                Escape::None => rendered.push_str(value),
+                Escape::Html => rendered.push_str(&escape_html(value)),
+                Escape::Json => rendered.push_str(&escape_json(value)),
+                Escape::Url => rendered.push_str(&escape_url(value)),
+            },
+            None => rendered.push_str(&rest[start..start + end + 4]),
+        }
+        rest = &after[end + 2..];
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+pub fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+pub fn escape_json(value: &str) -> String {
+    let quoted = Value::String(value.to_string()).to_string();
+    quoted[1..quoted.len() - 1].to_string()
+}
+
+pub fn escape_url(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn validate_template(label: &str, template: &str, variables: &[TemplateVariable]) -> Result<(), String> {
+    if let Err(message) = template_variables(template) {
+        return Err(format!("{}: {}", label, message));
+    }
+    let unknown = unknown_template_variables(template, variables);
+    if !unknown.is_empty() {
+        return Err(format!("{} uses unknown variables: {}", label, unknown.join(", ")));
+    }
+    Ok(())
+}
+
+// "Name <address>" OR A BARE ADDRESS, THE NAME MAY USE VARIABLES
+pub fn validate_email_from(from: &str) -> Result<(), String> {
+    let from = from.trim();
+    let address = match (from.rfind('<'), from.ends_with('>')) {
+        (Some(start), true) => &from[start + 1..from.len() - 1],
+        (None, false) => from,
+        _ => return Err(String::from("From must be an address or Name <address>")),
+    };
+    if address.contains("{{") || address.contains(char::is_whitespace) || email_domain(address).is_none() {
+        return Err(format!("From address {} is not a valid email", address));
+    }
+    Ok(())
+}
+
+pub fn validate_passwordless_email_options(options: &PasswordlessEmailOptions) -> Result<(), String> {
+    let email = &options.email;
+    validate_email_from(&email.from)?;
+    validate_template("From", &email.from, &EMAIL_TEMPLATE_VARIABLES)?;
+    if email.subject.trim().is_empty() {
+        return Err(String::from("Subject is required"));
+    }
+    validate_template("Subject", &email.subject, &EMAIL_TEMPLATE_VARIABLES)?;
+    if email.body.trim().is_empty() {
+        return Err(String::from("Body is required"));
+    }
+    validate_template("Body", &email.body, &EMAIL_TEMPLATE_VARIABLES)?;
+    let variables = template_variables(&email.body).unwrap_or_default();
+    if !variables.iter().any(|name| name == "code" || name == "link") {
+        return Err(String::from("Body must contain {{ code }} or {{ link }}, otherwise users can't log in"));
+    }
+    validate_totp(&options.totp)?;
+    if options.auth_params.keys().any(|key| key.trim().is_empty()) {
+        return Err(String::from("Authentication Parameter names can't be empty"));
+    }
+    Ok(())
+}
+
+pub fn validate_totp(totp: &Totp) -> Result<(), String> {
+    if totp.time_step == 0 {
+        return Err(String::from("OTP Expiry must be at least one second"));
+    }
+    if totp.length < OTP_LENGTH_MIN || totp.length > OTP_LENGTH_MAX {
+        return Err(format!("OTP Length must be between {} and {}", OTP_LENGTH_MIN, OTP_LENGTH_MAX));
+    }
+    Ok(())
+}
+
+// SMS
+
+pub const PASSWORDLESS_SMS_STRATEGY: &str = "sms";
+
+pub const SMS_PROVIDER_TWILIO: &str = "twilio";
+pub const SMS_PROVIDER_WEBHOOK: &str = "webhook";
+pub const SMS_PROVIDER_CUSTOM: &str = "custom";
+
+// (value, label, description)
+pub const SMS_PROVIDERS: [(&str, &str, &str); 3] = [
+    (SMS_PROVIDER_TWILIO, "Twilio", "Send through a Twilio account."),
+    (SMS_PROVIDER_WEBHOOK, "HTTP Webhook", "Post every message to an SMS gateway, eg. a local operator API."),
+    (SMS_PROVIDER_CUSTOM, "Custom Code", "Send from your own JavaScript function."),
+];
+
+pub const TWILIO_SOURCE_FROM: &str = "from";
+pub const TWILIO_SOURCE_MESSAGING_SERVICE: &str = "messaging_service";
+
+// (value, label)
+pub const TWILIO_SOURCES: [(&str, &str); 2] = [
+    (TWILIO_SOURCE_MESSAGING_SERVICE, "Use Messaging Service"),
+    (TWILIO_SOURCE_FROM, "Use From"),
+];
+
+pub const SMS_SYNTAX_MARKDOWN: &str = "md_with_macros";
+pub const SMS_PASSWORD_MACRO: &str = "@@password@@";
+
+// (value, label)
+pub const SMS_SYNTAXES: [(&str, &str); 2] = [
+    (TEMPLATE_SYNTAX_LIQUID, "Liquid"),
+    (SMS_SYNTAX_MARKDOWN, "Markdown with @@password@@"),
+];
+
+pub const SMS_TEMPLATE_VARIABLES: [TemplateVariable; 4] = [
+    ("code", "The one-time password", "482913"),
+    ("phone_number", "The recipient phone number", "+6281234567890"),
+    ("application.name", "The name of the application the user logs in to", "My App"),
+    ("request.language", "The language requested by the browser", "en-US"),
+];
+
+// VARIABLES OF THE PAYLOAD POSTED TO THE WEBHOOK
+pub const WEBHOOK_PAYLOAD_VARIABLES: [TemplateVariable; 3] = [
+    ("recipient", "The recipient phone number", "+6281234567890"),
+    ("message", "The rendered SMS message", "Your verification code is: 482913"),
+    ("code", "The one-time password", "482913"),
+];
+
+pub const WEBHOOK_METHODS: [&str; 2] = ["POST", "PUT"];
+
+pub const WEBHOOK_CONTENT_TYPE_JSON: &str = "application/json";
+pub const WEBHOOK_CONTENT_TYPE_FORM: &str = "application/x-www-form-urlencoded";
+
+pub const WEBHOOK_CONTENT_TYPES: [&str; 2] = [WEBHOOK_CONTENT_TYPE_JSON, WEBHOOK_CONTENT_TYPE_FORM];
+
+pub const DEFAULT_SMS_MESSAGE: &str = "Your verification code is: {{ code }}";
+pub const DEFAULT_WEBHOOK_PAYLOAD: &str = r#"{
+  "to": "{{ recipient }}",
+  "text": "{{ message }}"
+}"#;
+pub const DEFAULT_CUSTOM_SMS_SCRIPT: &str = r#"function sendSms(recipient, text, context, callback) {
+  // context.code IS THE ONE-TIME PASSWORD, context.application.name THE APPLICATION
+  console.log(`Sending "${text}" to ${recipient}`);
+
+  // CALL YOUR GATEWAY HERE, THEN callback(error) OR callback(null, { id: 'message id' })
+  callback(null, { id: 'local-' + Date.now() });
+}"#;
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct TwilioSettings {
+    pub twilio_sid: String,
+    pub twilio_token: String,
+    pub source: String,
+    #[serde(default)]
+    pub messaging_service_sid: String,
+    #[serde(default)]
+    pub from: String,
+}
+
+impl TwilioSettings {
+    pub fn new() -> TwilioSettings {
+        TwilioSettings {
+            twilio_sid: String::from(""),
+            twilio_token: String::from(""),
+            source: String::from(TWILIO_SOURCE_FROM),
+            messaging_service_sid: String::from(""),
+            from: String::from(""),
+        }
+    }
+}
+
+impl Default for TwilioSettings {
+    fn default() -> Self {
+        TwilioSettings::new()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct WebhookHeader {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct WebhookSettings {
+    pub url: String,
+    pub method: String,
+    pub content_type: String,
+    // EG. Authorization, SENT WITH EVERY MESSAGE
+    #[serde(default)]
+    pub headers: Vec<WebhookHeader>,
+    pub payload: String,
+}
+
+impl WebhookSettings {
+    pub fn new() -> WebhookSettings {
+        WebhookSettings {
+            url: String::from(""),
+            method: String::from(WEBHOOK_METHODS[0]),
+            content_type: String::from(WEBHOOK_CONTENT_TYPE_JSON),
+            headers: vec![],
+            payload: String::from(DEFAULT_WEBHOOK_PAYLOAD),
+        }
+    }
+}
+
+impl Default for WebhookSettings {
+    fn default() -> Self {
+        WebhookSettings::new()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct CustomCodeSettings {
+    pub script: String,
+}
+
+impl CustomCodeSettings {
+    pub fn new() -> CustomCodeSettings {
+        CustomCodeSettings {
+            script: String::from(DEFAULT_CUSTOM_SMS_SCRIPT),
+        }
+    }
+}
+
+impl Default for CustomCodeSettings {
+    fn default() -> Self {
+        CustomCodeSettings::new()
+    }
+}
+
+// EVERY PROVIDER KEEPS ITS SETTINGS, ONLY THE SELECTED ONE IS USED
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct PasswordlessSmsOptions {
+    pub provider: String,
+    #[serde(default)]
+    pub twilio: TwilioSettings,
+    #[serde(default)]
+    pub webhook: WebhookSettings,
+    #[serde(default)]
+    pub custom: CustomCodeSettings,
+    pub syntax: String,
+    pub template: String,
+    pub totp: Totp,
+    #[serde(default)]
+    pub disable_signup: bool,
+}
+
+impl PasswordlessSmsOptions {
+    pub fn new() -> PasswordlessSmsOptions {
+        PasswordlessSmsOptions {
+            provider: String::from(SMS_PROVIDER_TWILIO),
+            twilio: TwilioSettings::new(),
+            webhook: WebhookSettings::new(),
+            custom: CustomCodeSettings::new(),
+            syntax: String::from(TEMPLATE_SYNTAX_LIQUID),
+            template: String::from(DEFAULT_SMS_MESSAGE),
+            totp: Totp::new(),
+            disable_signup: false,
+        }
+    }
+}
+
+impl Default for PasswordlessSmsOptions {
+    fn default() -> Self {
+        PasswordlessSmsOptions::new()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct PasswordlessSmsConnection {
+    pub id: String,
+    pub name: String,
+    pub strategy: String,
+    pub enabled_clients: Vec<String>,
+    pub options: PasswordlessSmsOptions,
+}
+
+impl PasswordlessSmsConnection {
+    pub fn new() -> PasswordlessSmsConnection {
+        PasswordlessSmsConnection {
+            id: String::from(""),
+            name: String::from(PASSWORDLESS_SMS_STRATEGY),
+            strategy: String::from(PASSWORDLESS_SMS_STRATEGY),
+            enabled_clients: vec![],
+            options: PasswordlessSmsOptions::new(),
+        }
+    }
+}
+
+impl Default for PasswordlessSmsConnection {
+    fn default() -> Self {
+        PasswordlessSmsConnection::new()
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PasswordlessSmsConnectionCreate {
+    pub name: String,
+    pub strategy: String,
+    pub options: PasswordlessSmsOptions,
+}
+
+impl From<&PasswordlessSmsConnection> for PasswordlessSmsConnectionCreate {
+    fn from(connection: &PasswordlessSmsConnection) -> Self {
+        PasswordlessSmsConnectionCreate {
+            name: connection.name.clone(),
+            strategy: String::from(PASSWORDLESS_SMS_STRATEGY),
+            options: connection.options.clone(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PasswordlessSmsConnectionUpdate {
+    pub options: PasswordlessSmsOptions,
+}
+
+impl From<&PasswordlessSmsConnection> for PasswordlessSmsConnectionUpdate {
+    fn from(connection: &PasswordlessSmsConnection) -> Self {
+        PasswordlessSmsConnectionUpdate {
+            options: connection.options.clone(),
+        }
+    }
+}
+
+// SENDS ONE MESSAGE THROUGH THE PROVIDER, WITHOUT STARTING A LOGIN
+#[derive(Serialize, Debug, Clone)]
+pub struct SmsTry {
+    pub recipient: String,
+    pub options: PasswordlessSmsOptions,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct SmsTryRequest {
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct SmsTryResult {
+    pub provider: String,
+    // THE MESSAGE AS THE RECIPIENT READS IT
+    pub message: String,
+    // NONE FOR CUSTOM CODE, THE SCRIPT DOES ITS OWN REQUESTS
+    pub request: Option<SmsTryRequest>,
+    pub status: Option<u16>,
+    pub response: Value,
+    pub error: Option<String>,
+    #[serde(default)]
+    pub logs: Vec<String>,
+}
+
+pub fn sms_provider_label(provider: &str) -> &str {
+    SMS_PROVIDERS
+        .iter()
+        .find(|(value, _, _)| *value == provider)
+        .map(|(_, label, _)| *label)
+        .unwrap_or(provider)
+}
+
+// E.164, EG. +6281234567890
+pub fn validate_phone_number(phone_number: &str) -> Result<(), String> {
+    let digits = match phone_number.strip_prefix('+') {
+        Some(digits) => digits,
+        None => return Err(format!("{} must start with + and the country code", phone_number)),
+    };
+    if digits.len() < 8 || digits.len() > 15 || !digits.chars().all(|c| c.is_ascii_digit()) || digits.starts_with('0') {
+        return Err(format!("{} is not a valid phone number, eg. +6281234567890", phone_number));
+    }
+    Ok(())
+}
+
+// PREFIX FOLLOWED BY 32 HEXADECIMAL DIGITS, EG. AC0123...
+fn validate_twilio_sid(sid: &str, prefix: &str) -> bool {
+    sid.len() == 34 && sid.starts_with(prefix) && sid[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+pub fn validate_twilio_settings(settings: &TwilioSettings) -> Result<(), String> {
+    if !validate_twilio_sid(settings.twilio_sid.trim(), "AC") {
+        return Err(String::from("Twilio SID must be the Account SID, AC followed by 32 hexadecimal digits"));
+    }
+    if settings.twilio_token.trim().is_empty() {
+        return Err(String::from("Twilio Auth Token is required"));
+    }
+    match settings.source.as_str() {
+        TWILIO_SOURCE_MESSAGING_SERVICE => {
+            if !validate_twilio_sid(settings.messaging_service_sid.trim(), "MG") {
+                return Err(String::from("Messaging Service SID must be MG followed by 32 hexadecimal digits"));
+            }
+        }
+        TWILIO_SOURCE_FROM => {
+            validate_phone_number(settings.from.trim()).map_err(|message| format!("From: {}", message))?;
+        }
+        _ => return Err(String::from("SMS Source is unknown")),
+    }
+    Ok(())
+}
+
+// HTTPS, PLAIN HTTP IS ONLY ACCEPTED FOR A GATEWAY RUNNING ON THIS MACHINE
+pub fn validate_webhook_url(url: &str) -> Result<(), String> {
+    if let Some(rest) = url.strip_prefix("http://") {
+        let host = rest.split(['/', ':', '?']).next().unwrap_or("");
+        if host == "localhost" || host == "127.0.0.1" {
+            return Ok(());
+        }
+        return Err(String::from("Webhook URL must use https://, plain http:// is only allowed for localhost"));
+    }
+    validate_https_url(url).map_err(|message| format!("Webhook URL {}", message))
+}
+
+pub fn validate_webhook_settings(settings: &WebhookSettings) -> Result<(), String> {
+    validate_webhook_url(settings.url.trim())?;
+    if !WEBHOOK_METHODS.contains(&settings.method.as_str()) {
+        return Err(String::from("Method must be POST or PUT"));
+    }
+    if !WEBHOOK_CONTENT_TYPES.contains(&settings.content_type.as_str()) {
+        return Err(String::from("Content Type is unknown"));
+    }
+    for (index, header) in settings.headers.iter().enumerate() {
+        let name = header.name.trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(format!("Header name \"{}\" is not valid", header.name));
+        }
+        if name.eq_ignore_ascii_case("content-type") {
+            return Err(String::from("Content-Type is set by Content Type, remove the header"));
+        }
+        if settings.headers[..index].iter().any(|other| other.name.trim().eq_ignore_ascii_case(name)) {
+            return Err(format!("Header {} is listed twice", name));
+        }
+    }
+    validate_template("Payload", &settings.payload, &WEBHOOK_PAYLOAD_VARIABLES)?;
+    if settings.content_type == WEBHOOK_CONTENT_TYPE_JSON {
+        let rendered = render_template(&settings.payload, &template_samples(&WEBHOOK_PAYLOAD_VARIABLES), webhook_escape(&settings.content_type));
+        if let Err(error) = serde_json::from_str::<Value>(&rendered) {
+            return Err(format!("Payload is not valid JSON: {}", error));
+        }
+    }
+    Ok(())
+}
+
+pub fn webhook_escape(content_type: &str) -> Escape {
+    if content_type == WEBHOOK_CONTENT_TYPE_JSON {
+        Escape::Json
+    } else {
+        Escape::Url
+    }
+}
+
+pub fn validate_custom_code_settings(settings: &CustomCodeSettings) -> Result<(), String> {
+    let script = settings.script.trim();
+    if !script.starts_with("function") {
+        return Err(String::from("The script must be a function(recipient, text, context, callback)"));
+    }
+    if !script.contains("callback(") {
+        return Err(String::from("The script must call callback, otherwise the login never continues"));
+    }
+    Ok(())
+}
+
+pub fn validate_sms_template(syntax: &str, template: &str) -> Result<(), String> {
+    if template.trim().is_empty() {
+        return Err(String::from("Message is required"));
+    }
+    match syntax {
+        TEMPLATE_SYNTAX_LIQUID => {
+            validate_template("Message", template, &SMS_TEMPLATE_VARIABLES)?;
+            if !template_variables(template).unwrap_or_default().iter().any(|name| name == "code") {
+                return Err(String::from("Message must contain {{ code }}, otherwise users can't log in"));
+            }
+        }
+        SMS_SYNTAX_MARKDOWN => {
+            if !template.contains(SMS_PASSWORD_MACRO) {
+                return Err(format!("Message must contain {}, otherwise users can't log in", SMS_PASSWORD_MACRO));
+            }
+        }
+        _ => return Err(String::from("SMS Syntax is unknown")),
+    }
+    Ok(())
+}
+
+pub fn render_sms_template(syntax: &str, template: &str, values: &BTreeMap<String, String>) -> String {
+    if syntax == SMS_SYNTAX_MARKDOWN {
+        template.replace(SMS_PASSWORD_MACRO, values.get("code").map(String::as_str).unwrap_or(""))
+    } else {
+        render_template(template, values, Escape::None)
+    }
+}
+
+pub fn validate_passwordless_sms_options(options: &PasswordlessSmsOptions) -> Result<(), String> {
+    match options.provider.as_str() {
+        SMS_PROVIDER_TWILIO => validate_twilio_settings(&options.twilio)?,
+        SMS_PROVIDER_WEBHOOK => validate_webhook_settings(&options.webhook)?,
+        SMS_PROVIDER_CUSTOM => validate_custom_code_settings(&options.custom)?,
+        _ => return Err(String::from("SMS Provider is unknown")),
+    }
+    validate_sms_template(&options.syntax, &options.template)?;
+    validate_totp(&options.totp)
+}
+
+// TRY, A REAL PASSWORDLESS LOGIN STARTED FROM THE DASHBOARD
+
+pub const PASSWORDLESS_SEND_CODE: &str = "code";
+pub const PASSWORDLESS_SEND_LINK: &str = "link";
+
+// (value, label)
+pub const PASSWORDLESS_SEND_MODES: [(&str, &str); 2] = [
+    (PASSWORDLESS_SEND_CODE, "code"),
+    (PASSWORDLESS_SEND_LINK, "magic link"),
+];
+
+pub const PASSWORDLESS_OTP_GRANT_TYPE: &str = "http://auth0.com/oauth/grant-type/passwordless/otp";
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PasswordlessStart {
+    pub client_id: String,
+    pub connection: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    pub send: String,
+}
+
+impl PasswordlessStart {
+    // THE RECIPIENT GOES TO email OR phone_number DEPENDING ON THE STRATEGY
+    pub fn new(client_id: &str, strategy: &str, connection: &str, recipient: &str, send: &str) -> PasswordlessStart {
+        let sms = strategy == PASSWORDLESS_SMS_STRATEGY;
+        PasswordlessStart {
+            client_id: client_id.to_string(),
+            connection: connection.to_string(),
+            email: if sms { None } else { Some(recipient.to_string()) },
+            phone_number: if sms { Some(recipient.to_string()) } else { None },
+            send: send.to_string(),
+        }
+    }
+}
+
+// EXCHANGES THE RECEIVED CODE FOR TOKENS
+#[derive(Serialize, Debug, Clone)]
+pub struct PasswordlessVerify {
+    pub grant_type: String,
+    pub client_id: String,
+    pub username: String,
+    pub otp: String,
+    pub realm: String,
+    pub scope: String,
+}
+
+impl PasswordlessVerify {
+    pub fn new(client_id: &str, connection: &str, recipient: &str, otp: &str) -> PasswordlessVerify {
+        PasswordlessVerify {
+            grant_type: String::from(PASSWORDLESS_OTP_GRANT_TYPE),
+            client_id: client_id.to_string(),
+            username: recipient.to_string(),
+            otp: otp.to_string(),
+            realm: connection.to_string(),
+            scope: String::from("openid profile email"),
+        }
+    }
+}
+
+pub fn validate_passwordless_recipient(strategy: &str, recipient: &str) -> Result<(), String> {
+    if strategy == PASSWORDLESS_SMS_STRATEGY {
+        validate_phone_number(recipient)
+    } else if recipient.contains(char::is_whitespace) || email_domain(recipient).is_none() {
+        Err(format!("{} is not a valid email", recipient))
+    } else {
+        Ok(())
+    }
+}
+
+pub fn validate_otp(otp: &str) -> Result<(), String> {
+    if otp.is_empty() {
+        return Err(String::from("Type the code you received"));
+    }
+    if !otp.chars().all(|c| c.is_ascii_digit()) {
+        return Err(String::from("The code only has digits"));
+    }
+    if (otp.len() as u32) < OTP_LENGTH_MIN || (otp.len() as u32) > OTP_LENGTH_MAX {
+        return Err(format!("The code has between {} and {} digits", OTP_LENGTH_MIN, OTP_LENGTH_MAX));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn render_template_fills_known_variables() {
+        let values = values(&[("code", "123456"), ("application.name", "My App")]);
+        assert_eq!(
+            render_template("{{ application.name }}: {{code | upcase}} {{ unknown }}", &values, Escape::None),
+            "My App: 123456 {{ unknown }}"
+        );
+        assert_eq!(render_template("no variables", &values, Escape::None), "no variables");
+        // AN UNCLOSED {{ ENDS THE RENDERING, THE REST IS KEPT
+        assert_eq!(render_template("{{ code }} and {{ code", &values, Escape::None), "123456 and {{ code");
+    }
+
+    #[test]
+    fn render_template_escapes_values_not_the_template() {
+        let values = values(&[("name", "Tom & \"Jerry\" <3\n")]);
+        assert_eq!(render_template("<b>{{ name }}</b>", &values, Escape::Html), "<b>Tom &amp; &quot;Jerry&quot; &lt;3\n</b>");
+        assert_eq!(render_template("{\"text\": \"{{ name }}\"}", &values, Escape::Json), "{\"text\": \"Tom & \\\"Jerry\\\" <3\\n\"}");
+        assert_eq!(render_template("text={{ name }}", &values, Escape::Url), "text=Tom%20%26%20%22Jerry%22%20%3C3%0A");
+        assert_eq!(escape_url("caf\u{e9}~"), "caf%C3%A9~");
+    }
+
+    #[test]
+    fn template_variables_are_read_in_order() {
+        assert_eq!(
+            template_variables("{{ link }} {{code|upcase}} {{ link }}"),
+            Ok(vec![String::from("link"), String::from("code"), String::from("link")])
+        );
+        assert_eq!(template_variables("{{ code"), Err(String::from("A {{ is never closed with }}")));
+        assert_eq!(template_variables("{{ | upcase }}"), Err(String::from("A {{ }} has no variable")));
+        assert_eq!(
+            unknown_template_variables("{{ code }} {{ nope }} {{ nope }}", &EMAIL_TEMPLATE_VARIABLES),
+            vec![String::from("nope")]
+        );
+    }
+}
@@ -48,7 +48,9 @@ pub struct Flags {
     // pub enforce_client_authentication_on_passwordless_start: bool,
     // pub enable_adfs_waad_email_verification: bool,
     pub revoke_refresh_token_grant: bool,
-    // pub dashboard_log_streams_next: bool,
+    // OLDER TENANTS DON'T SEND IT
+    #[serde(default)]
+    pub dashboard_log_streams_next: bool,
     // pub dashboard_insights_view: bool,
 }
 // #[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
@@ -121,7 +123,7 @@ impl TenantSettings {
                 // enforce_client_authentication_on_passwordless_start: false,
                 // enable_adfs_waad_email_verification: false,
                 revoke_refresh_token_grant: false,
-                // dashboard_log_streams_next: false,
+                dashboard_log_streams_next: false,
                 // dashboard_insights_view: false,
             },
             // friendly_name: String::from(""),
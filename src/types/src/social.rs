@@ -0,0 +1,369 @@
+use std::collections::BTreeMap;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::Value;
+use crate::database::SYNC_ON_EACH_LOGIN;
+
+// SOCIAL PROVIDER CATALOG
+// Loaded from the catalog data file, a provider lists the credentials it
+// needs, the profile attributes it can return and the extra permissions
+// (scopes) that can be requested from the user
+
+const CATALOG: &str = include_str!("social_catalog.json");
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct SocialField {
+    pub key: String,
+    pub label: String,
+    pub description: String,
+    // text, url, secret or textarea
+    pub field_type: String,
+    pub required: bool,
+    #[serde(default)]
+    pub default_value: String,
+    #[serde(default)]
+    pub help_url: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct SocialPermission {
+    pub key: String,
+    pub label: String,
+    pub description: String,
+    #[serde(default)]
+    pub required: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct SocialProvider {
+    pub strategy: String,
+    pub name: String,
+    pub description: String,
+    // IMAGE UNDER /assets/icons, FALLS BACK TO THE BOOTSTRAP ICON
+    #[serde(default)]
+    pub logo: String,
+    pub icon: String,
+    pub fields: Vec<SocialField>,
+    pub attributes: Vec<SocialPermission>,
+    pub scopes: Vec<SocialPermission>,
+}
+
+impl SocialProvider {
+    pub fn default_options(&self) -> SocialConnectionOptions {
+        SocialConnectionOptions {
+            settings: self.fields
+                .iter()
+                .map(|field| (field.key.clone(), field.default_value.clone()))
+                .collect(),
+            attributes: self.attributes
+                .iter()
+                .filter(|attribute| attribute.required)
+                .map(|attribute| attribute.key.clone())
+                .collect(),
+            scopes: vec![],
+            set_user_root_attributes: String::from(SYNC_ON_EACH_LOGIN),
+            profile_mapping: if self.strategy == CUSTOM_STRATEGY {
+                default_profile_mapping()
+            } else {
+                BTreeMap::new()
+            },
+        }
+    }
+
+    // EVERY PROBLEM THAT KEEPS THE CONNECTION FROM BEING ENABLED
+    pub fn problems(&self, options: &SocialConnectionOptions) -> Vec<String> {
+        let mut problems = Vec::new();
+        for field in self.fields.iter() {
+            let value = options.settings.get(&field.key).cloned().unwrap_or_default();
+            let value = value.trim();
+            if value.is_empty() {
+                if field.required {
+                    problems.push(format!("{} is required", field.label));
+                }
+                continue;
+            }
+            if field.field_type == "url" {
+                if let Err(message) = validate_https_url(value) {
+                    problems.push(format!("{} {}", field.label, message));
+                }
+            }
+        }
+        if self.strategy == CUSTOM_STRATEGY {
+            problems.extend(validate_profile_mapping(&options.profile_mapping));
+        }
+        problems
+    }
+
+    pub fn validate(&self, options: &SocialConnectionOptions) -> Result<(), String> {
+        match self.problems(options).into_iter().next() {
+            Some(problem) => Err(problem),
+            None => Ok(()),
+        }
+    }
+
+    // REQUIRED ATTRIBUTES CAN'T BE UNCHECKED, SO THEY ARE ALWAYS REQUESTED
+    pub fn with_required_attributes(&self, options: &SocialConnectionOptions) -> SocialConnectionOptions {
+        let mut options = options.clone();
+        for attribute in self.attributes.iter().filter(|attribute| attribute.required) {
+            if !options.attributes.contains(&attribute.key) {
+                options.attributes.push(attribute.key.clone());
+            }
+        }
+        options
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct SocialCatalog {
+    pub providers: Vec<SocialProvider>,
+}
+
+impl SocialCatalog {
+    pub fn load() -> SocialCatalog {
+        serde_json::from_str(CATALOG).expect("social catalog is not valid")
+    }
+
+    pub fn find(&self, strategy: &str) -> Option<SocialProvider> {
+        self.providers
+            .iter()
+            .find(|provider| provider.strategy == strategy)
+            .cloned()
+    }
+
+    pub fn search(&self, search: &str) -> Vec<SocialProvider> {
+        let search = search.trim().to_lowercase();
+        self.providers
+            .iter()
+            .filter(|provider| {
+                search.is_empty()
+                    || provider.name.to_lowercase().contains(&search)
+                    || provider.strategy.to_lowercase().contains(&search)
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn strategies(&self) -> Vec<String> {
+        self.providers
+            .iter()
+            .map(|provider| provider.strategy.clone())
+            .collect()
+    }
+}
+
+// SOCIAL CONNECTIONS
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct SocialConnectionOptions {
+    // CREDENTIALS, KEYED BY THE PROVIDER FIELD KEY
+    #[serde(default)]
+    pub settings: BTreeMap<String, String>,
+    #[serde(default)]
+    pub attributes: Vec<String>,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    #[serde(default)]
+    pub set_user_root_attributes: String,
+    // USER ATTRIBUTE -> PATH IN THE PROVIDER PROFILE, CUSTOM CONNECTIONS ONLY
+    #[serde(default)]
+    pub profile_mapping: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct SocialConnection {
+    pub id: String,
+    pub name: String,
+    pub strategy: String,
+    pub enabled_clients: Vec<String>,
+    pub options: SocialConnectionOptions,
+}
+
+impl SocialConnection {
+    pub fn new() -> SocialConnection {
+        SocialConnection {
+            id: String::from(""),
+            name: String::from(""),
+            strategy: String::from(""),
+            enabled_clients: vec![],
+            options: SocialConnectionOptions {
+                settings: BTreeMap::new(),
+                attributes: vec![],
+                scopes: vec![],
+                set_user_root_attributes: String::from(SYNC_ON_EACH_LOGIN),
+                profile_mapping: BTreeMap::new(),
+            },
+        }
+    }
+}
+
+impl Default for SocialConnection {
+    fn default() -> Self {
+        SocialConnection::new()
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SocialConnectionCreate {
+    pub name: String,
+    pub strategy: String,
+    pub options: SocialConnectionOptions,
+}
+
+impl From<&SocialProvider> for SocialConnectionCreate {
+    // THE CONNECTION IS NAMED AFTER ITS STRATEGY, EG. "google-oauth2",
+    // CUSTOM CONNECTIONS ARE RENAMED BEFORE BEING CREATED
+    fn from(provider: &SocialProvider) -> Self {
+        SocialConnectionCreate {
+            name: provider.strategy.clone(),
+            strategy: provider.strategy.clone(),
+            options: provider.default_options(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SocialConnectionUpdate {
+    pub enabled_clients: Vec<String>,
+    pub options: SocialConnectionOptions,
+}
+
+impl From<&SocialConnection> for SocialConnectionUpdate {
+    fn from(connection: &SocialConnection) -> Self {
+        SocialConnectionUpdate {
+            enabled_clients: connection.enabled_clients.clone(),
+            options: connection.options.clone(),
+        }
+    }
+}
+
+// CUSTOM OAUTH2 / OIDC CONNECTIONS
+// Any provider that isn't in the catalog, the user profile returned by its
+// userinfo endpoint is mapped to the user attributes
+
+pub const CUSTOM_STRATEGY: &str = "oauth2";
+
+// (user attribute, label, required)
+pub const PROFILE_ATTRIBUTES: [(&str, &str, bool); 9] = [
+    ("user_id", "User ID", true),
+    ("email", "Email", true),
+    ("email_verified", "Email Verified", false),
+    ("name", "Name", false),
+    ("given_name", "Given Name", false),
+    ("family_name", "Family Name", false),
+    ("nickname", "Nickname", false),
+    ("username", "Username", false),
+    ("picture", "Picture", false),
+];
+
+// STANDARD OPENID CONNECT CLAIMS
+pub fn default_profile_mapping() -> BTreeMap<String, String> {
+    [
+        ("user_id", "sub"),
+        ("email", "email"),
+        ("email_verified", "email_verified"),
+        ("name", "name"),
+        ("given_name", "given_name"),
+        ("family_name", "family_name"),
+        ("nickname", "nickname"),
+        ("username", "preferred_username"),
+        ("picture", "picture"),
+    ]
+    .iter()
+    .map(|(attribute, path)| (attribute.to_string(), path.to_string()))
+    .collect()
+}
+
+pub fn validate_https_url(url: &str) -> Result<(), String> {
+    let rest = match url.strip_prefix("https://") {
+        Some(rest) => rest,
+        None => return Err(String::from("must start with https://")),
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+    let hostname = host.rsplitn(2, ':').last().unwrap_or("");
+    if hostname.is_empty() || url.contains(char::is_whitespace) {
+        return Err(String::from("is not a valid URL"));
+    }
+    if host.len() != hostname.len() && host[hostname.len() + 1..].parse::<u16>().is_err() {
+        return Err(String::from("has an invalid port"));
+    }
+    Ok(())
+}
+
+// A PATH IS A DOT SEPARATED LIST OF KEYS, ARRAY ITEMS BY INDEX, EG. "emails.0.value"
+fn validate_profile_path(path: &str) -> bool {
+    path.split('.').all(|segment| {
+        !segment.is_empty()
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == ':')
+    })
+}
+
+pub fn validate_profile_mapping(mapping: &BTreeMap<String, String>) -> Vec<String> {
+    let mut problems = Vec::new();
+    for (attribute, label, required) in PROFILE_ATTRIBUTES.iter() {
+        let path = mapping.get(*attribute).map(|path| path.trim()).unwrap_or("");
+        if path.is_empty() {
+            if *required {
+                problems.push(format!("{} must be mapped to a profile field", label));
+            }
+        } else if !validate_profile_path(path) {
+            problems.push(format!("{} mapping \"{}\" is not a valid profile path", label, path));
+        }
+    }
+    problems
+}
+
+pub fn lookup_profile<'a>(profile: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(profile, |value, segment| match value {
+        Value::Array(items) => segment.parse::<usize>().ok().and_then(|index| items.get(index)),
+        _ => value.get(segment),
+    })
+}
+
+// MAPPED ATTRIBUTES OF A SAMPLE PROFILE, None WHEN THE PATH ISN'T IN THE PROFILE
+pub fn map_profile(mapping: &BTreeMap<String, String>, profile: &Value) -> Vec<(String, Option<Value>)> {
+    PROFILE_ATTRIBUTES
+        .iter()
+        .filter_map(|(attribute, _, _)| {
+            let path = mapping.get(*attribute)?.trim();
+            if path.is_empty() {
+                return None;
+            }
+            Some((attribute.to_string(), lookup_profile(profile, path).cloned()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_https_url_accepts_https_hosts() {
+        for url in ["https://acme.com", "https://acme.com/", "https://login.acme.com:8443/path?query=1#top", "https://127.0.0.1"].iter() {
+            assert_eq!(validate_https_url(url), Ok(()), "{}", url);
+        }
+    }
+
+    #[test]
+    fn validate_https_url_explains_what_is_wrong() {
+        let cases = [
+            ("http://acme.com", "must start with https://"),
+            ("acme.com", "must start with https://"),
+            ("HTTPS://acme.com", "must start with https://"),
+            ("https://", "is not a valid URL"),
+            ("https:///path", "is not a valid URL"),
+            ("https://:443", "is not a valid URL"),
+            ("https://acme.com/a path", "is not a valid URL"),
+            ("https://acme.com:", "has an invalid port"),
+            ("https://acme.com:http", "has an invalid port"),
+            ("https://acme.com:65536", "has an invalid port"),
+        ];
+        for (url, message) in cases.iter() {
+            assert_eq!(validate_https_url(url), Err(String::from(*message)), "{}", url);
+        }
+    }
+}
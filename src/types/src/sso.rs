@@ -0,0 +1,266 @@
+use std::collections::BTreeMap;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use crate::social::validate_https_url;
+
+// SSO CATALOG
+// Loaded from the catalog data file, every integration is configured with
+// the fields of its protocol followed by its own fields
+
+const CATALOG: &str = include_str!("sso_catalog.json");
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct SsoField {
+    pub key: String,
+    pub label: String,
+    pub description: String,
+    pub field_type: String,
+    pub required: bool,
+    pub options: Vec<String>,
+    pub default_value: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct SsoCatalogItem {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub logo: String,
+    pub category: String,
+    pub protocol: String,
+    pub fields: Vec<SsoField>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct SsoProtocol {
+    pub id: String,
+    pub name: String,
+    pub fields: Vec<SsoField>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct SsoCatalog {
+    pub protocols: Vec<SsoProtocol>,
+    pub integrations: Vec<SsoCatalogItem>,
+}
+
+impl SsoCatalog {
+    pub fn load() -> SsoCatalog {
+        serde_json::from_str(CATALOG).expect("sso catalog is not valid")
+    }
+
+    pub fn search(&self, search: &str, category: &Option<String>) -> Vec<SsoCatalogItem> {
+        let search = search.trim().to_lowercase();
+        self.integrations
+            .iter()
+            .filter(|integration| {
+                match category {
+                    Some(category) => &integration.category == category,
+                    None => true,
+                }
+            })
+            .filter(|integration| {
+                search.is_empty()
+                    || integration.name.to_lowercase().contains(&search)
+                    || integration.description.to_lowercase().contains(&search)
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn find(&self, integration_id: &str) -> Option<SsoCatalogItem> {
+        self.integrations
+            .iter()
+            .find(|integration| integration.id == integration_id)
+            .cloned()
+    }
+
+    pub fn categories(&self) -> Vec<String> {
+        let mut categories: Vec<String> = Vec::new();
+        for integration in self.integrations.iter() {
+            if !categories.contains(&integration.category) {
+                categories.push(integration.category.clone());
+            }
+        }
+        categories.sort();
+        categories
+    }
+
+    // PROTOCOL FIELDS FIRST, THEN THE INTEGRATION SPECIFIC ONES
+    pub fn schema(&self, integration: &SsoCatalogItem) -> Vec<SsoField> {
+        let mut fields = self.protocols
+            .iter()
+            .find(|protocol| protocol.id == integration.protocol)
+            .map(|protocol| protocol.fields.clone())
+            .unwrap_or_default();
+        fields.extend(integration.fields.clone());
+        fields
+    }
+
+    pub fn protocol_name(&self, protocol_id: &str) -> String {
+        self.protocols
+            .iter()
+            .find(|protocol| protocol.id == protocol_id)
+            .map(|protocol| protocol.name.clone())
+            .unwrap_or_else(|| protocol_id.to_string())
+    }
+}
+
+pub fn default_settings(schema: &[SsoField]) -> BTreeMap<String, String> {
+    schema
+        .iter()
+        .map(|field| (field.key.clone(), field.default_value.clone()))
+        .collect()
+}
+
+// RETURNS THE ERROR MESSAGE OF EVERY INVALID FIELD, KEYED BY FIELD KEY
+pub fn validate_settings(schema: &[SsoField], settings: &BTreeMap<String, String>) -> BTreeMap<String, String> {
+    let mut errors = BTreeMap::new();
+    for field in schema.iter() {
+        let value = settings.get(&field.key).cloned().unwrap_or_default();
+        let value = value.trim();
+        if value.is_empty() {
+            if field.required {
+                errors.insert(field.key.clone(), format!("{} is required", field.label));
+            }
+            continue;
+        }
+        match field.field_type.as_str() {
+            "url" => {
+                if let Err(message) = validate_https_url(value) {
+                    errors.insert(field.key.clone(), format!("{} {}", field.label, message));
+                }
+            }
+            "number" if value.parse::<u32>().is_err() => {
+                errors.insert(field.key.clone(), format!("{} must be a positive number", field.label));
+            }
+            "select" if !field.options.iter().any(|option| option == value) => {
+                errors.insert(field.key.clone(), format!("{} has an unknown value", field.label));
+            }
+            _ => {}
+        }
+    }
+    errors
+}
+
+// SSO INTEGRATIONS
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct SsoIntegration {
+    pub id: String,
+    pub integration_id: String,
+    pub name: String,
+    pub protocol: String,
+    pub status: String,
+    pub settings: BTreeMap<String, String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl SsoIntegration {
+    pub fn new() -> SsoIntegration {
+        SsoIntegration {
+            id: String::from(""),
+            integration_id: String::from(""),
+            name: String::from(""),
+            protocol: String::from(""),
+            status: String::from("inactive"),
+            settings: BTreeMap::new(),
+            created_at: String::from(""),
+            updated_at: String::from(""),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == "active"
+    }
+}
+
+impl Default for SsoIntegration {
+    fn default() -> Self {
+        SsoIntegration::new()
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SsoIntegrationCreate {
+    pub integration_id: String,
+    pub name: String,
+    pub protocol: String,
+    pub status: String,
+    pub settings: BTreeMap<String, String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SsoIntegrationUpdate {
+    pub name: String,
+    pub status: String,
+    pub settings: BTreeMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_catalog_parses_and_every_protocol_exists() {
+        let catalog = SsoCatalog::load();
+        assert!(!catalog.integrations.is_empty());
+        for (index, integration) in catalog.integrations.iter().enumerate() {
+            assert!(
+                catalog.protocols.iter().any(|protocol| protocol.id == integration.protocol),
+                "{} uses the unknown protocol {}",
+                integration.id,
+                integration.protocol
+            );
+            assert!(
+                !catalog.integrations[..index].iter().any(|other| other.id == integration.id),
+                "{} is listed twice",
+                integration.id
+            );
+        }
+    }
+
+    #[test]
+    fn the_catalog_defaults_are_valid() {
+        let catalog = SsoCatalog::load();
+        for integration in catalog.integrations.iter() {
+            let schema = catalog.schema(integration);
+            let mut settings = default_settings(&schema);
+            for field in schema.iter().filter(|field| field.required && field.default_value.is_empty()) {
+                let value = match field.field_type.as_str() {
+                    "url" => "https://acme.com",
+                    "number" => "1",
+                    "select" => field.options.first().map(String::as_str).unwrap_or_default(),
+                    _ => "value",
+                };
+                settings.insert(field.key.clone(), value.to_string());
+            }
+            assert_eq!(validate_settings(&schema, &settings), BTreeMap::new(), "{}", integration.id);
+        }
+    }
+
+    #[test]
+    fn url_settings_must_be_https() {
+        let schema = vec![SsoField {
+            key: String::from("callback"),
+            label: String::from("Callback URL"),
+            description: String::from(""),
+            field_type: String::from("url"),
+            required: true,
+            options: vec![],
+            default_value: String::from(""),
+        }];
+        let errors = |value: &str| {
+            let mut settings = BTreeMap::new();
+            settings.insert(String::from("callback"), value.to_string());
+            validate_settings(&schema, &settings).get("callback").cloned()
+        };
+        assert_eq!(errors("https://acme.com/callback"), None);
+        assert_eq!(errors(""), Some(String::from("Callback URL is required")));
+        assert_eq!(errors("https://"), Some(String::from("Callback URL is not a valid URL")));
+        assert_eq!(errors("http://acme.com"), Some(String::from("Callback URL must start with https://")));
+    }
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 pub struct GetUsersByIdResponseAppMetadata {}
@@ -164,7 +165,9 @@ pub struct ResponseUserLogLists {
 }
 
 
+// FIELDS VARY BY EVENT TYPE, THE MISSING ONES ARE LEFT EMPTY
 #[derive(Deserialize, Debug, Clone)]
+#[serde(default)]
 pub struct UserLogDetails {
     pub date: String,
     pub type_detail: String,
@@ -182,7 +185,8 @@ pub struct UserLogDetails {
     pub strategy: String,
     pub log_id: String,
     pub is_mobile: bool,
-    pub details: GetLogsByUserResponseDetails,
+    // EVENT SPECIFIC PAYLOAD, eg. THE REQUEST OF A MANAGEMENT API CALL
+    pub details: Value,
     pub user_agent: String,
     pub location_info: GetLogsByUserLocationInfo,
 }
@@ -206,7 +210,7 @@ impl UserLogDetails {
             strategy: String::from(""),
             log_id: String::from(""),
             is_mobile: false,
-            details: GetLogsByUserResponseDetails {},
+            details: Value::Object(Map::new()),
             user_agent: String::from(""),
             location_info: GetLogsByUserLocationInfo {
                 country_code: String::from(""),
@@ -222,8 +226,11 @@ impl UserLogDetails {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
-pub struct GetLogsByUserResponseDetails {}
+impl Default for UserLogDetails {
+    fn default() -> Self {
+        UserLogDetails::new()
+    }
+}
 
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 pub struct GetLogsByUserLocationInfo {